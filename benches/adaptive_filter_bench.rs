@@ -0,0 +1,66 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::collections::HashMap;
+use veloxx::conditions::Condition;
+use veloxx::dataframe::DataFrame;
+use veloxx::query::{QueryBuilder, UltraFastQueryEngine};
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+fn create_test_dataframe(size: usize) -> DataFrame {
+    let ids: Vec<Option<i32>> = (0..size).map(|i| Some(i as i32)).collect();
+    let buckets: Vec<Option<i32>> = (0..size).map(|i| Some((i % 1000) as i32)).collect();
+    let prices: Vec<Option<f64>> = (0..size).map(|i| Some((i % 500) as f64 * 0.5)).collect();
+
+    let mut columns = HashMap::new();
+    columns.insert("id".to_string(), Series::new_i32("id", ids));
+    columns.insert("bucket".to_string(), Series::new_i32("bucket", buckets));
+    columns.insert("price".to_string(), Series::new_f64("price", prices));
+    DataFrame::new(columns).unwrap()
+}
+
+/// Two broad predicates followed by a very selective one
+fn predicates() -> Vec<Condition> {
+    vec![
+        Condition::Gt("id".to_string(), Value::I32(10)),
+        Condition::Lt("price".to_string(), Value::F64(240.0)),
+        Condition::Eq("bucket".to_string(), Value::I32(42)),
+    ]
+}
+
+fn adaptive_filter_benchmark(c: &mut Criterion) {
+    let df = create_test_dataframe(1_000_000);
+    let [broad, medium, selective]: [Condition; 3] = predicates().try_into().unwrap();
+
+    // With adaptive ordering both spellings should cost about the same
+    let selective_last = Condition::And(
+        Box::new(Condition::And(
+            Box::new(broad.clone()),
+            Box::new(medium.clone()),
+        )),
+        Box::new(selective.clone()),
+    );
+    let selective_first = Condition::And(
+        Box::new(selective),
+        Box::new(Condition::And(Box::new(broad), Box::new(medium))),
+    );
+
+    c.bench_function("filter_and_selective_last_1m", |b| {
+        b.iter(|| df.filter(&selective_last).unwrap());
+    });
+    c.bench_function("filter_and_selective_first_1m", |b| {
+        b.iter(|| df.filter(&selective_first).unwrap());
+    });
+
+    let engine = UltraFastQueryEngine::new();
+    c.bench_function("query_engine_three_predicates_1m", |b| {
+        b.iter(|| {
+            let query = predicates()
+                .into_iter()
+                .fold(QueryBuilder::new(), QueryBuilder::where_condition);
+            engine.query(&df, query).unwrap()
+        });
+    });
+}
+
+criterion_group!(benches, adaptive_filter_benchmark);
+criterion_main!(benches);
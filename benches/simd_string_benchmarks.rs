@@ -0,0 +1,71 @@
+// benches/simd_string_benchmarks.rs
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use veloxx::performance::simd_string::simd_find;
+use veloxx::series::Series;
+
+fn log_lines(count: usize) -> Series {
+    let levels = ["INFO", "WARN", "ERROR", "DEBUG"];
+    Series::new_string(
+        "line",
+        (0..count)
+            .map(|i| {
+                Some(format!(
+                    "2024-01-15T10:{:02}:{:02}Z {} [worker-{}] request id={} completed in {}ms",
+                    i % 60,
+                    i % 59,
+                    levels[i % levels.len()],
+                    i % 16,
+                    i,
+                    i % 997
+                ))
+            })
+            .collect(),
+    )
+}
+
+fn bench_string_operations(c: &mut Criterion) {
+    let lines = log_lines(100_000);
+    let Series::String(_, values, _) = &lines else {
+        unreachable!()
+    };
+
+    c.bench_function("str_contains_simd", |b| {
+        b.iter(|| lines.str_contains(black_box("ERROR")).unwrap())
+    });
+    c.bench_function("str_contains_std", |b| {
+        b.iter(|| {
+            values
+                .iter()
+                .map(|v| v.contains(black_box("ERROR")))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("find_simd", |b| {
+        b.iter(|| {
+            values
+                .iter()
+                .map(|v| simd_find(v, black_box("id=4")))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("find_std", |b| {
+        b.iter(|| {
+            values
+                .iter()
+                .map(|v| v.find(black_box("id=4")))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("str_to_lowercase_simd", |b| {
+        b.iter(|| lines.str_to_lowercase().unwrap())
+    });
+    c.bench_function("str_to_lowercase_std", |b| {
+        b.iter(|| values.iter().map(|v| v.to_lowercase()).collect::<Vec<_>>())
+    });
+    c.bench_function("str_starts_with_simd", |b| {
+        b.iter(|| lines.str_starts_with(black_box("2024-01-15T10:3")).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_string_operations);
+criterion_main!(benches);
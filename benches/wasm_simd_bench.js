@@ -0,0 +1,50 @@
+// Compares the wasm SIMD128 kernels against the scalar build.
+//
+//   npm run build:node && npm run build:node-simd
+//   node benches/wasm_simd_bench.js [scalar-pkg-dir] [simd-pkg-dir]
+
+const path = require('path');
+
+const scalarDir = path.resolve(process.argv[2] || 'pkg-node');
+const simdDir = path.resolve(process.argv[3] || 'pkg-node-simd');
+const scalar = require(path.join(scalarDir, 'veloxx.js'));
+const simd = require(path.join(simdDir, 'veloxx.js'));
+
+if (scalar.simdSupported() || !simd.simdSupported()) {
+  console.error('Expected a scalar build and a SIMD128 build, in that order');
+  process.exit(1);
+}
+
+const N = 1_000_000;
+const a = Float64Array.from({ length: N }, () => Math.random() * 100);
+const b = Float64Array.from({ length: N }, () => Math.random() * 100 + 1);
+
+const kernels = {
+  simdSumF64: (m) => m.simdSumF64(a),
+  simdMinF64: (m) => m.simdMinF64(a),
+  simdMaxF64: (m) => m.simdMaxF64(a),
+  simdAddF64: (m) => m.simdAddF64(a, b),
+  simdMulF64: (m) => m.simdMulF64(a, b),
+  simdDivF64: (m) => m.simdDivF64(a, b),
+  simdGtF64: (m) => m.simdGtF64(a, 50),
+};
+
+function timeIt(fn, iterations = 50) {
+  for (let i = 0; i < 5; i++) fn();
+  const start = process.hrtime.bigint();
+  for (let i = 0; i < iterations; i++) fn();
+  return Number(process.hrtime.bigint() - start) / 1e6 / iterations;
+}
+
+console.log(`${N.toLocaleString()} f64 elements, mean ms per call`);
+console.log('kernel'.padEnd(12), 'scalar'.padStart(9), 'simd128'.padStart(9), 'speedup'.padStart(9));
+for (const [name, run] of Object.entries(kernels)) {
+  const s = timeIt(() => run(scalar));
+  const v = timeIt(() => run(simd));
+  console.log(
+    name.padEnd(12),
+    s.toFixed(3).padStart(9),
+    v.toFixed(3).padStart(9),
+    `${(s / v).toFixed(2)}x`.padStart(9)
+  );
+}
@@ -17,7 +17,6 @@ release = '0.2.4'
 # -- General configuration ---------------------------------------------------
 # https://www.sphinx-doc.org/en/master/usage/configuration.html#general-configuration
 
-sys.path.insert(0, os.path.abspath('../../bindings/python'))
 
 extensions = [
     'sphinx.ext.autodoc',
@@ -0,0 +1,43 @@
+import pytest
+import veloxx
+
+pa = pytest.importorskip("pyarrow")
+
+
+@pytest.fixture
+def sample_dataframe():
+    return veloxx.PyDataFrame(
+        {
+            "a": veloxx.PySeries("a", [1, None, 3]),
+            "b": veloxx.PySeries("b", ["x", "y", "z"]),
+        }
+    )
+
+
+def test_dataframe_exports_stream(sample_dataframe):
+    table = pa.table(sample_dataframe)
+    assert table.column_names == ["a", "b"]
+    assert table.column("a").to_pylist() == [1, None, 3]
+
+
+def test_dataframe_exports_schema(sample_dataframe):
+    schema = pa.schema(sample_dataframe)
+    assert schema.field("b").type == pa.string()
+
+
+def test_series_exports_array():
+    arr = pa.array(veloxx.PySeries("x", [1.5, None]))
+    assert arr.type == pa.float64()
+    assert arr.to_pylist() == [1.5, None]
+
+
+def test_import_from_pyarrow():
+    table = pa.table({"n": pa.array([1, 2, 3], pa.int64()), "s": ["a", None, "c"]})
+    df = veloxx.PyDataFrame.from_arrow(table)
+    assert df.row_count() == 3
+    assert df.get_column("n").get_value(0) == 1
+    assert df.get_column("s").get_value(1) is None
+
+    s = veloxx.PySeries.from_arrow(pa.array([True, False]), name="flag")
+    assert s.name() == "flag"
+    assert s.get_value(1) is False
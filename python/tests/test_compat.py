@@ -0,0 +1,51 @@
+import pytest
+import veloxx
+
+
+def test_unprefixed_aliases_are_the_same_classes():
+    assert veloxx.DataFrame is veloxx.PyDataFrame
+    assert veloxx.Series is veloxx.PySeries
+    assert veloxx.Condition is veloxx.PyCondition
+    assert veloxx.Expr is veloxx.PyExpr
+    assert veloxx.Value is veloxx.PyValue
+
+
+def test_aliases_construct_frames():
+    from veloxx import DataFrame, Series
+
+    df = DataFrame({"a": Series("a", [1, 2, 3])})
+    assert isinstance(df, veloxx.PyDataFrame)
+    assert df.row_count() == 3
+
+
+def test_legacy_helpers():
+    assert veloxx.PyValue.from_str("x").get_type() == "string"
+    assert veloxx.PyValue.from_datetime(0).get_type() == "datetime"
+    assert veloxx.PyDataType.F64.name() == "F64"
+    assert veloxx.PySeries("s", [1, 1, 2]).unique_count() == 2
+
+    df = veloxx.PyDataFrame({"a": veloxx.PySeries("a", [1, 2, 3])})
+    gt = veloxx.PyCondition.gt("a", 1)
+    lt = veloxx.PyCondition.lt("a", 3)
+    both = getattr(veloxx.PyCondition, "and")(gt, lt)
+    assert df.filter(both).row_count() == 1
+
+
+def test_missing_column_raises_key_error():
+    df = veloxx.PyDataFrame({"a": veloxx.PySeries("a", [1, 2])})
+    with pytest.raises(KeyError):
+        df.select_columns(["missing"])
+
+
+def test_dtype_mismatch_raises_type_error():
+    a = veloxx.PySeries("a", [1, 2])
+    b = veloxx.PySeries("b", ["x", "y"])
+    with pytest.raises(TypeError):
+        a.append(b)
+
+
+def test_repr():
+    s = veloxx.PySeries("s", [1, None])
+    assert repr(s) == "Series('s', I32, [1, null])"
+    df = veloxx.PyDataFrame({"s": s})
+    assert repr(df).startswith("s ")
@@ -0,0 +1,26 @@
+from concurrent.futures import ThreadPoolExecutor
+
+import veloxx
+
+
+def make_frame(n):
+    return veloxx.PyDataFrame(
+        {
+            "key": veloxx.PySeries("key", [(i * 7919) % 1000 for i in range(n)]),
+            "value": veloxx.PySeries("value", [float(i) for i in range(n)]),
+        }
+    )
+
+
+def test_concurrent_sorts_and_group_bys():
+    df = make_frame(5_000)
+
+    def work(i):
+        if i % 2:
+            return df.sort(["key"], True)["key"][0]
+        return len(df.group_by("key").agg([("value", "sum")]))
+
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        results = list(pool.map(work, range(8)))
+    assert results == [1000, 0] * 4
+
@@ -0,0 +1,40 @@
+import pytest
+import veloxx
+from veloxx import col, count, mean, sum
+
+
+@pytest.fixture
+def sales():
+    return veloxx.PyDataFrame(
+        {
+            "city": veloxx.PySeries("city", ["a", "b", "a"]),
+            "sales": veloxx.PySeries("sales", [10.0, 20.0, 30.0]),
+            "margin": veloxx.PySeries("margin", [1.0, 2.0, 3.0]),
+        }
+    )
+
+
+def test_named_aggregations(sales):
+    result = (
+        sales.group_by("city")
+        .agg(sum("sales").alias("total"), mean("margin"), col("sales").count().alias("n"))
+        .sort(["city"], True)
+    )
+    assert sorted(result.column_names()) == ["city", "margin_mean", "n", "total"]
+    assert list(result["total"]) == [40.0, 20.0]
+    assert list(result["n"]) == [2, 1]
+
+
+def test_tuple_aggregations_still_supported(sales):
+    result = sales.group_by(["city"]).agg([("sales", "sum")])
+    assert sorted(result.column_names()) == ["city", "sales_sum"]
+
+
+def test_duplicate_output_names_rejected(sales):
+    with pytest.raises(ValueError):
+        sales.group_by("city").agg(sum("sales"), mean("margin").alias("sales_sum"))
+
+
+def test_aggregation_requires_column_expression():
+    with pytest.raises(ValueError):
+        (col("a") + 1).sum()
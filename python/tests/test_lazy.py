@@ -0,0 +1,57 @@
+import pytest
+import veloxx
+
+
+@pytest.fixture
+def sales():
+    return veloxx.PyDataFrame(
+        {
+            "city": veloxx.PySeries("city", ["a", "b", "a", "c"]),
+            "sales": veloxx.PySeries("sales", [10, 20, 30, 40]),
+            "price": veloxx.PySeries("price", [1.0, 2.0, 3.0, 4.0]),
+        }
+    )
+
+
+def test_lazy_filter_select(sales):
+    predicate = veloxx.PyExpr.greater_than(
+        veloxx.PyExpr.column("sales"), veloxx.PyExpr.literal(15)
+    )
+    result = sales.lazy().filter(predicate).select(["sales"]).collect()
+    assert result.row_count() == 3
+    assert result.column_names() == ["sales"]
+
+
+def test_lazy_with_column_sort(sales):
+    revenue = veloxx.PyExpr.column("price").multiply(veloxx.PyExpr.literal(2.0))
+    result = sales.lazy().with_column("revenue", revenue).sort(["revenue"], False).collect()
+    assert result.get_column("revenue").get_value(0) == 8.0
+
+
+def test_lazy_group_by_agg(sales):
+    result = (
+        sales.lazy()
+        .group_by(["city"])
+        .agg([("sales", "sum")])
+        .sort(["city"])
+        .collect()
+    )
+    assert result.row_count() == 3
+    assert result.get_column("sales_sum").get_value(0) == 40
+
+
+def test_lazy_group_by_rejects_unknown_aggregation(sales):
+    with pytest.raises(ValueError):
+        sales.lazy().group_by(["city"]).agg([("sales", "median")])
+
+
+def test_lazy_join_and_explain(sales):
+    regions = veloxx.PyDataFrame(
+        {
+            "city": veloxx.PySeries("city", ["a", "b"]),
+            "region": veloxx.PySeries("region", ["north", "south"]),
+        }
+    )
+    query = sales.lazy().join(regions.lazy(), "city", veloxx.PyJoinType.Inner)
+    assert "JOIN" in query.explain()
+    assert query.collect().row_count() == 3
@@ -0,0 +1,45 @@
+import pytest
+import veloxx
+
+np = pytest.importorskip("numpy")
+
+
+def test_from_numpy_dtypes():
+    df = veloxx.PyDataFrame.from_numpy(
+        {
+            "a": np.array([1, 2, 3], dtype=np.int64),
+            "b": np.array([1.5, 2.5, 3.5]),
+            "c": np.array([True, False, True]),
+        }
+    )
+    assert df.row_count() == 3
+    assert df.get_column("a").data_type() == "I32"
+    assert df.get_column("b").get_value(1) == 2.5
+    assert df.get_column("c").get_value(1) is False
+
+
+def test_from_numpy_masked_array():
+    data = np.ma.masked_array([1.0, 2.0, 3.0], mask=[False, True, False])
+    df = veloxx.PyDataFrame.from_numpy({"x": data})
+    assert df.get_column("x").get_value(1) is None
+    assert df.get_column("x").count() == 2
+
+
+def test_from_numpy_rejects_unsupported_dtype():
+    with pytest.raises(TypeError):
+        veloxx.PyDataFrame.from_numpy({"x": np.array(["a", "b"])})
+
+
+def test_to_numpy_round_trip():
+    s = veloxx.PySeries("x", [1.0, 2.0, 3.0])
+    arr = s.to_numpy()
+    assert isinstance(arr, np.ndarray)
+    assert arr.dtype == np.float64
+    assert arr.tolist() == [1.0, 2.0, 3.0]
+
+
+def test_to_numpy_with_nulls_is_masked():
+    s = veloxx.PySeries("x", [1, None, 3])
+    arr = s.to_numpy()
+    assert np.ma.isMaskedArray(arr)
+    assert arr.mask.tolist() == [False, True, False]
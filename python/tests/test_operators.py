@@ -0,0 +1,66 @@
+import pytest
+import veloxx
+from veloxx import col
+
+
+@pytest.fixture
+def df():
+    return veloxx.PyDataFrame(
+        {
+            "a": veloxx.PySeries("a", [1, 2, 3, 4]),
+            "b": veloxx.PySeries("b", [0.5, None, 1.5, 2.0]),
+            "name": veloxx.PySeries("name", ["w", "x", "y", "z"]),
+        }
+    )
+
+
+def test_expression_operators(df):
+    result = df[col("a") + 1 > 3]
+    assert len(result) == 2
+    assert repr(col("a") * 2) == '(col("a") * 2)'
+
+
+def test_expression_with_column(df):
+    result = df.with_column("double", col("a") * 2)
+    assert list(result["double"]) == [2, 4, 6, 8]
+
+
+def test_series_arithmetic_and_comparison(df):
+    a = df["a"]
+    assert list(a + 1) == [2, 3, 4, 5]
+    assert list(10 - a) == [9, 8, 7, 6]
+    assert list(a * df["b"]) == [0.5, None, 4.5, 8.0]
+    assert list(a > 2) == [False, False, True, True]
+
+
+def test_boolean_mask_indexing(df):
+    filtered = df[df["a"] > 2]
+    assert len(filtered) == 2
+    assert list(filtered["name"]) == ["y", "z"]
+    assert len(df[~(df["a"] > 2)]) == 2
+
+
+def test_condition_operators(df):
+    cond = veloxx.PyCondition.gt("a", 1) & veloxx.PyCondition.lt("a", 4)
+    assert len(df[cond]) == 2
+    assert len(df[~cond]) == 2
+
+
+def test_getitem_variants(df):
+    assert sorted(df[["a", "name"]].column_names()) == ["a", "name"]
+    assert len(df[1:3]) == 2
+    assert df["a"][-1] == 4
+    assert list(df["a"][::2]) == [1, 3]
+    with pytest.raises(KeyError):
+        df["missing"]
+    with pytest.raises(IndexError):
+        df["a"][10]
+
+
+def test_len_iter_head_tail(df):
+    assert len(df) == 4
+    assert sorted(df) == ["a", "b", "name"]
+    assert list(df["a"].head(2)) == [1, 2]
+    assert list(df["a"].tail(1)) == [4]
+    assert len(df.head(3)) == 3
+    assert len(df.tail(10)) == 4
@@ -0,0 +1,56 @@
+import sys
+
+import pytest
+import veloxx
+
+pd = pytest.importorskip("pandas")
+
+
+@pytest.fixture
+def pdf():
+    return pd.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "score": [1.5, None, 3.5],
+            "name": ["a", "b", None],
+            "flag": [True, False, True],
+        }
+    )
+
+
+def test_from_pandas(pdf):
+    df = veloxx.PyDataFrame.from_pandas(pdf)
+    assert df.row_count() == 3
+    assert df.get_column("id").get_value(2) == 3
+    assert df.get_column("score").get_value(1) is None
+    assert df.get_column("name").get_value(2) is None
+    assert df.get_column("flag").get_value(1) is False
+
+
+def test_pandas_round_trip(pdf):
+    out = veloxx.PyDataFrame.from_pandas(pdf).to_pandas()
+    assert sorted(out.columns) == sorted(pdf.columns)
+    assert out["id"].tolist() == [1, 2, 3]
+    assert out["score"].isna().tolist() == [False, True, False]
+    assert out["name"].tolist()[:2] == ["a", "b"]
+
+
+@pytest.fixture(params=["pyarrow", "numpy"])
+def conversion_path(request, monkeypatch):
+    if request.param == "pyarrow":
+        pytest.importorskip("pyarrow")
+    else:
+        # A None entry makes `import pyarrow` fail, forcing the NumPy fallback
+        monkeypatch.setitem(sys.modules, "pyarrow", None)
+    return request.param
+
+
+def test_pandas_datetimes_are_unix_seconds(conversion_path):
+    pdf = pd.DataFrame({"when": [pd.Timestamp("2024-01-01"), pd.NaT]})
+    df = veloxx.PyDataFrame.from_pandas(pdf)
+    assert df.get_column("when").get_value(0) == 1704067200
+    assert df.get_column("when").get_value(1) is None
+
+    out = df.to_pandas()
+    assert out["when"].iloc[0] == pd.Timestamp("2024-01-01")
+    assert out["when"].isna().tolist() == [False, True]
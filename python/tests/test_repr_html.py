@@ -0,0 +1,23 @@
+import veloxx
+
+
+def test_repr_html_renders_table():
+    df = veloxx.PyDataFrame(
+        {
+            "a": veloxx.PySeries("a", [1, 2]),
+            "b": veloxx.PySeries("b", ["<x>", None]),
+        }
+    )
+    html = df._repr_html_()
+    assert html.startswith('<div class="veloxx-dataframe">')
+    assert "<th>I32</th><th>String</th>" in html
+    assert "&lt;x&gt;" in html
+    assert "2 rows × 2 columns" in html
+
+
+def test_to_html_truncates_rows():
+    df = veloxx.PyDataFrame({"a": veloxx.PySeries("a", list(range(100)))})
+    html = df.to_html(max_rows=6)
+    assert "<tr><th>2</th>" in html
+    assert "<tr><th>50</th>" not in html
+    assert "<tr><th>99</th>" in html
@@ -0,0 +1,40 @@
+import pytest
+import veloxx
+
+
+@pytest.fixture
+def df():
+    return veloxx.PyDataFrame(
+        {
+            "id": veloxx.PySeries("id", [1, 2, 3]),
+            "name": veloxx.PySeries("name", ["a", None, "c"]),
+        }
+    )
+
+
+def test_to_dict_list(df):
+    assert df.to_dict() == {"id": [1, 2, 3], "name": ["a", None, "c"]}
+
+
+def test_to_dict_records(df):
+    assert df.to_dict(orient="records") == [
+        {"id": 1, "name": "a"},
+        {"id": 2, "name": None},
+        {"id": 3, "name": "c"},
+    ]
+
+
+def test_to_dict_rejects_unknown_orient(df):
+    with pytest.raises(ValueError):
+        df.to_dict(orient="index")
+
+
+def test_to_records(df):
+    assert df.to_records() == [(1, "a"), (2, None), (3, "c")]
+
+
+def test_iter_rows(df):
+    rows = df.iter_rows()
+    assert next(rows) == (1, "a")
+    assert list(rows) == [(2, None), (3, "c")]
+    assert list(df.iter_rows(named=True))[0] == {"id": 1, "name": "a"}
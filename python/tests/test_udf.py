@@ -0,0 +1,47 @@
+import pytest
+import veloxx
+
+
+@pytest.fixture
+def df():
+    return veloxx.PyDataFrame(
+        {
+            "a": veloxx.PySeries("a", [1, 2, None]),
+            "b": veloxx.PySeries("b", [0.5, 1.5, 2.5]),
+        }
+    )
+
+
+def test_series_map(df):
+    assert list(df["a"].map(lambda v: v * 10)) == [10, 20, None]
+    assert list(df["a"].map(lambda v: v is None, skip_nulls=False)) == [False, False, True]
+    assert list(df["b"].map(str)) == ["0.5", "1.5", "2.5"]
+
+
+def test_series_map_rejects_mixed_result_types(df):
+    with pytest.raises(TypeError):
+        df["b"].map(lambda v: "big" if v > 1 else v)
+
+
+def test_apply_columns(df):
+    doubled = df.apply(lambda s: s * 2)
+    assert list(doubled["b"]) == [1.0, 3.0, 5.0]
+
+    counts = df.apply(lambda s: s.count())
+    assert counts.to_dict() == {"a": [2], "b": [3]}
+
+
+def test_apply_rows(df):
+    result = df.apply(lambda row: (row["a"] or 0) + row["b"], axis=1)
+    assert list(result) == [1.5, 3.5, 2.5]
+
+
+def test_apply_rejects_bad_axis(df):
+    with pytest.raises(ValueError):
+        df.apply(len, axis=2)
+
+
+def test_batched_map():
+    np = pytest.importorskip("numpy")
+    s = veloxx.PySeries("x", [1.0, 4.0, 9.0])
+    assert list(s.map(np.sqrt, batched=True)) == [1.0, 2.0, 3.0]
@@ -5,6 +5,12 @@
 //! - Streaming JSON processing for large datasets
 //! - Database connectivity (SQLite, PostgreSQL, MySQL)
 //! - Asynchronous I/O operations
+//! - Delta Lake table snapshots and time travel ([`delta`])
+//! - Iceberg table snapshots with partition and statistics pruning ([`iceberg`])
+//! - Arrow Flight transfers, with the `flight` feature (`flight`)
+//! - ORC files with column projection and stripe pruning ([`orc`])
+//! - Warehouse queries over ODBC, with the `odbc` feature (`odbc`)
+//! - Batched writes of DataFrames to database tables ([`sql`])
 //!
 //! # Features
 //!
@@ -53,8 +59,30 @@ use crate::VeloxxError;
 use std::collections::HashMap;
 use std::path::Path;
 
+#[cfg(feature = "advanced_io")]
+use crate::types::hex_string;
+#[cfg(feature = "advanced_io")]
+use parquet::data_type::{ByteArray, Int96};
+#[cfg(feature = "advanced_io")]
+use parquet::file::metadata::ParquetMetaData as ParquetFileMetaData;
 #[cfg(feature = "advanced_io")]
 use parquet::file::reader::{FileReader, SerializedFileReader};
+#[cfg(feature = "advanced_io")]
+use parquet::file::statistics::Statistics;
+
+#[cfg(all(feature = "advanced_io", feature = "arrow"))]
+pub mod delta;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(all(feature = "advanced_io", feature = "arrow"))]
+pub mod iceberg;
+#[cfg(all(feature = "odbc", not(target_arch = "wasm32")))]
+pub mod odbc;
+#[cfg(feature = "advanced_io")]
+pub mod orc;
+pub mod sql;
+#[cfg(feature = "advanced_io")]
+pub(crate) mod statistics;
 
 /// Parquet file reader for high-performance columnar data access
 pub struct ParquetReader {
@@ -166,6 +194,48 @@ impl ParquetReader {
             "Advanced I/O feature is not enabled. Enable with --features advanced_io".to_string(),
         ))
     }
+
+    /// Read the footer of a Parquet file without loading any of its data
+    ///
+    /// Lets callers look at the schema, the size of each row group and the
+    /// statistics of each column chunk before deciding what to read.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the Parquet file
+    ///
+    /// # Returns
+    ///
+    /// The file's [`ParquetMetadata`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use veloxx::advanced_io::ParquetReader;
+    ///
+    /// let metadata = ParquetReader::new().metadata("data.parquet").unwrap();
+    /// println!("{} rows", metadata.num_rows);
+    /// println!("{}", metadata.statistics);
+    /// ```
+    #[cfg(feature = "advanced_io")]
+    pub fn metadata<P: AsRef<Path>>(&self, path: P) -> Result<ParquetMetadata, VeloxxError> {
+        let file = std::fs::File::open(path.as_ref()).map_err(|e| {
+            VeloxxError::InvalidOperation(format!("Failed to open Parquet file: {}", e))
+        })?;
+
+        let reader = SerializedFileReader::new(file).map_err(|e| {
+            VeloxxError::InvalidOperation(format!("Failed to create Parquet reader: {}", e))
+        })?;
+
+        parquet_metadata(reader.metadata())
+    }
+
+    #[cfg(not(feature = "advanced_io"))]
+    pub fn metadata<P: AsRef<Path>>(&self, _path: P) -> Result<ParquetMetadata, VeloxxError> {
+        Err(VeloxxError::InvalidOperation(
+            "Advanced I/O feature is not enabled. Enable with --features advanced_io".to_string(),
+        ))
+    }
 }
 
 impl Default for ParquetReader {
@@ -174,6 +244,173 @@ impl Default for ParquetReader {
     }
 }
 
+/// Footer metadata of a Parquet file, as returned by [`ParquetReader::metadata`]
+#[derive(Debug, Clone)]
+pub struct ParquetMetadata {
+    /// Total number of rows in the file
+    pub num_rows: i64,
+    /// One row per leaf column: `column`, `physical_type`, `logical_type` and `nullable`
+    pub schema: DataFrame,
+    /// One row per row group: `row_group`, `num_rows`, `total_byte_size` and
+    /// `compressed_size`
+    pub row_groups: DataFrame,
+    /// One row per column chunk: `row_group`, `column`, `min`, `max`, `null_count`
+    /// and `distinct_count`; values are null where the writer stored no statistics.
+    /// `min` and `max` are text, since the columns have different types
+    pub statistics: DataFrame,
+}
+
+/// Builds the [`ParquetMetadata`] frames from a file footer
+#[cfg(feature = "advanced_io")]
+fn parquet_metadata(metadata: &ParquetFileMetaData) -> Result<ParquetMetadata, VeloxxError> {
+    let frame = |series: Vec<Series>| {
+        DataFrame::new(
+            series
+                .into_iter()
+                .map(|s| (s.name().to_string(), s))
+                .collect(),
+        )
+    };
+
+    let columns = metadata.file_metadata().schema_descr().columns();
+    let schema = frame(vec![
+        Series::new_string(
+            "column",
+            columns.iter().map(|c| Some(c.path().string())).collect(),
+        ),
+        Series::new_string(
+            "physical_type",
+            columns
+                .iter()
+                .map(|c| Some(c.physical_type().to_string()))
+                .collect(),
+        ),
+        Series::new_string(
+            "logical_type",
+            columns
+                .iter()
+                .map(|c| c.logical_type().map(|t| format!("{:?}", t)))
+                .collect(),
+        ),
+        Series::new_bool(
+            "nullable",
+            columns
+                .iter()
+                .map(|c| Some(c.max_def_level() > 0))
+                .collect(),
+        ),
+    ])?;
+
+    let groups = metadata.row_groups();
+    let row_groups = frame(vec![
+        Series::new_i32(
+            "row_group",
+            (0..groups.len()).map(|i| Some(i as i32)).collect(),
+        ),
+        Series::new_f64(
+            "num_rows",
+            groups.iter().map(|g| Some(g.num_rows() as f64)).collect(),
+        ),
+        Series::new_f64(
+            "total_byte_size",
+            groups
+                .iter()
+                .map(|g| Some(g.total_byte_size() as f64))
+                .collect(),
+        ),
+        Series::new_f64(
+            "compressed_size",
+            groups
+                .iter()
+                .map(|g| Some(g.compressed_size() as f64))
+                .collect(),
+        ),
+    ])?;
+
+    let mut row_group = Vec::new();
+    let mut column = Vec::new();
+    let mut min = Vec::new();
+    let mut max = Vec::new();
+    let mut null_count = Vec::new();
+    let mut distinct_count = Vec::new();
+    for (index, group) in groups.iter().enumerate() {
+        for chunk in group.columns() {
+            let statistics = chunk.statistics();
+            row_group.push(Some(index as i32));
+            column.push(Some(chunk.column_path().string()));
+            let (low, high) = statistics.map(statistic_text).unwrap_or_default();
+            min.push(low);
+            max.push(high);
+            null_count.push(
+                statistics
+                    .and_then(|s| s.null_count_opt())
+                    .map(|n| n as f64),
+            );
+            distinct_count.push(
+                statistics
+                    .and_then(|s| s.distinct_count_opt())
+                    .map(|n| n as f64),
+            );
+        }
+    }
+    let statistics = frame(vec![
+        Series::new_i32("row_group", row_group),
+        Series::new_string("column", column),
+        Series::new_string("min", min),
+        Series::new_string("max", max),
+        Series::new_f64("null_count", null_count),
+        Series::new_f64("distinct_count", distinct_count),
+    ])?;
+
+    Ok(ParquetMetadata {
+        num_rows: metadata.file_metadata().num_rows(),
+        schema,
+        row_groups,
+        statistics,
+    })
+}
+
+/// The minimum and maximum of a column chunk as text; byte arrays that are not
+/// UTF-8 are written as hex
+#[cfg(feature = "advanced_io")]
+fn statistic_text(statistics: &Statistics) -> (Option<String>, Option<String>) {
+    let bytes = |v: &ByteArray| match v.as_utf8() {
+        Ok(text) => text.to_string(),
+        Err(_) => hex_string(v.data()),
+    };
+    match statistics {
+        Statistics::Boolean(s) => (
+            s.min_opt().map(bool::to_string),
+            s.max_opt().map(bool::to_string),
+        ),
+        Statistics::Int32(s) => (
+            s.min_opt().map(i32::to_string),
+            s.max_opt().map(i32::to_string),
+        ),
+        Statistics::Int64(s) => (
+            s.min_opt().map(i64::to_string),
+            s.max_opt().map(i64::to_string),
+        ),
+        Statistics::Int96(s) => (
+            s.min_opt().map(Int96::to_string),
+            s.max_opt().map(Int96::to_string),
+        ),
+        Statistics::Float(s) => (
+            s.min_opt().map(f32::to_string),
+            s.max_opt().map(f32::to_string),
+        ),
+        Statistics::Double(s) => (
+            s.min_opt().map(f64::to_string),
+            s.max_opt().map(f64::to_string),
+        ),
+        Statistics::ByteArray(s) => (s.min_opt().map(bytes), s.max_opt().map(bytes)),
+        Statistics::FixedLenByteArray(s) => (
+            s.min_opt().map(|v| hex_string(v.data())),
+            s.max_opt().map(|v| hex_string(v.data())),
+        ),
+    }
+}
+
 /// Parquet file writer for high-performance columnar data storage
 pub struct ParquetWriter {
     #[cfg(not(feature = "advanced_io"))]
@@ -560,6 +797,7 @@ impl DatabaseConnector {
                     DataType::Bool => "BOOLEAN",
                     DataType::String => "TEXT",
                     DataType::DateTime => "DATETIME",
+                    DataType::Binary => "BLOB",
                 };
 
                 create_sql.push_str(&format!("{} {}", column_name, sql_type));
@@ -690,6 +928,7 @@ impl AsyncFileOps {
                     Some(crate::types::Value::Bool(v)) => v.to_string(),
                     Some(crate::types::Value::String(v)) => v,
                     Some(crate::types::Value::DateTime(v)) => v.to_string(),
+                    Some(v @ crate::types::Value::Binary(_)) => v.to_string(),
                     Some(crate::types::Value::Null) => String::new(),
                     None => String::new(),
                 };
@@ -785,6 +1024,9 @@ impl AsyncFileOps {
                         json_content.push_str(&format!("\"{}\"", v))
                     }
                     Some(crate::types::Value::DateTime(v)) => json_content.push_str(&v.to_string()),
+                    Some(v @ crate::types::Value::Binary(_)) => {
+                        json_content.push_str(&format!("\"{}\"", v))
+                    }
                     Some(crate::types::Value::Null) => json_content.push_str("null"),
                     None => json_content.push_str("null"),
                 }
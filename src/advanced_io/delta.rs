@@ -0,0 +1,593 @@
+//! Reader for Delta Lake tables stored on the local filesystem.
+//!
+//! A table snapshot is rebuilt from the transaction log in `_delta_log/`: the newest
+//! Parquet checkpoint at or before the requested version, followed by the JSON
+//! commits after it. The snapshot's `add` actions name the Parquet data files and
+//! their partition values, which become columns of the resulting `DataFrame`.
+//!
+//! Tables using deletion vectors or column mapping are rejected with
+//! [`VeloxxError::Unsupported`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use veloxx::advanced_io::delta::DeltaTable;
+//! use veloxx::conditions::Condition;
+//! use veloxx::types::Value;
+//!
+//! # fn main() -> Result<(), veloxx::VeloxxError> {
+//! let table = DeltaTable::open("warehouse/events")?;
+//! let latest = table.to_dataframe()?;
+//!
+//! // Only the files under date=2024-01-01 are read
+//! let day = Condition::Eq("date".to_string(), Value::String("2024-01-01".to_string()));
+//! let pruned = table.to_dataframe_filtered(&day)?;
+//!
+//! // Time travel
+//! let first = DeltaTable::open_version("warehouse/events", 0)?.to_dataframe()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::conditions::Condition;
+use crate::dataframe::DataFrame;
+use crate::io::datetime::DateTimeFormat;
+use crate::io::partitioned::{concat_frames, unescape};
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::record::{Field, Row};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Highest `minReaderVersion` understood by this reader
+const MAX_READER_VERSION: i32 = 3;
+
+/// A data file in a table snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaFile {
+    /// Path relative to the table root, as recorded in the log (percent-decoded)
+    pub path: String,
+    /// Partition column values; `None` is a null partition
+    pub partition_values: HashMap<String, Option<String>>,
+    /// File size in bytes
+    pub size: i64,
+}
+
+/// A snapshot of a Delta Lake table at one version
+#[derive(Debug, Clone)]
+pub struct DeltaTable {
+    root: PathBuf,
+    version: u64,
+    schema: Vec<(String, DataType)>,
+    partition_columns: Vec<String>,
+    files: Vec<DeltaFile>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddAction {
+    path: String,
+    #[serde(default)]
+    partition_values: HashMap<String, Option<String>>,
+    #[serde(default)]
+    size: i64,
+    #[serde(default)]
+    deletion_vector: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct RemoveAction {
+    path: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MetaDataAction {
+    schema_string: String,
+    #[serde(default)]
+    partition_columns: Vec<String>,
+    #[serde(default)]
+    configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProtocolAction {
+    min_reader_version: i32,
+}
+
+/// One line of a commit file; every other action kind is ignored
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<AddAction>,
+    remove: Option<RemoveAction>,
+    meta_data: Option<MetaDataAction>,
+    protocol: Option<ProtocolAction>,
+}
+
+/// Snapshot state while replaying the log
+#[derive(Default)]
+struct Replay {
+    metadata: Option<MetaDataAction>,
+    files: BTreeMap<String, DeltaFile>,
+}
+
+impl Replay {
+    fn apply(&mut self, action: Action) -> Result<(), VeloxxError> {
+        if let Some(protocol) = action.protocol {
+            if protocol.min_reader_version > MAX_READER_VERSION {
+                return Err(VeloxxError::Unsupported(format!(
+                    "Delta reader version {} is not supported",
+                    protocol.min_reader_version
+                )));
+            }
+        }
+        if let Some(metadata) = action.meta_data {
+            let mapping = metadata
+                .configuration
+                .get("delta.columnMapping.mode")
+                .cloned()
+                .flatten();
+            if mapping.is_some_and(|mode| mode != "none") {
+                return Err(VeloxxError::Unsupported(
+                    "Delta tables with column mapping are not supported".to_string(),
+                ));
+            }
+            self.metadata = Some(metadata);
+        }
+        if let Some(remove) = action.remove {
+            self.files.remove(&unescape(&remove.path));
+        }
+        if let Some(add) = action.add {
+            if add.deletion_vector.is_some_and(|dv| !dv.is_null()) {
+                return Err(VeloxxError::Unsupported(
+                    "Delta tables with deletion vectors are not supported".to_string(),
+                ));
+            }
+            let path = unescape(&add.path);
+            self.files.insert(
+                path.clone(),
+                DeltaFile {
+                    path,
+                    partition_values: add.partition_values,
+                    size: add.size,
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+impl DeltaTable {
+    /// Opens the latest version of the table at `path`.
+    pub fn open(path: &str) -> Result<Self, VeloxxError> {
+        Self::load(path, None)
+    }
+
+    /// Opens the table at `path` as of `version` (time travel).
+    pub fn open_version(path: &str, version: u64) -> Result<Self, VeloxxError> {
+        Self::load(path, Some(version))
+    }
+
+    fn load(path: &str, version: Option<u64>) -> Result<Self, VeloxxError> {
+        let root = PathBuf::from(path);
+        let log = root.join("_delta_log");
+        let (commits, checkpoints) = list_log(&log)?;
+        let latest = commits
+            .iter()
+            .copied()
+            .chain(checkpoints.keys().copied())
+            .max()
+            .ok_or_else(|| {
+                VeloxxError::FileIO(format!("'{}' is not a Delta table", root.display()))
+            })?;
+        let version = version.unwrap_or(latest);
+        if version > latest {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Delta table version {} does not exist (latest is {})",
+                version, latest
+            )));
+        }
+
+        let mut replay = Replay::default();
+        let checkpoint = checkpoints.range(..=version).next_back();
+        let start = match checkpoint {
+            Some((&checkpoint_version, parts)) => {
+                for part in parts {
+                    read_checkpoint(part, &mut replay)?;
+                }
+                checkpoint_version + 1
+            }
+            None => 0,
+        };
+        for commit in start..=version {
+            if !commits.contains(&commit) {
+                return Err(VeloxxError::FileIO(format!(
+                    "Delta log is missing commit {} needed to read version {}",
+                    commit, version
+                )));
+            }
+            let text = std::fs::read_to_string(log.join(format!("{:020}.json", commit)))?;
+            for line in text.lines().filter(|line| !line.trim().is_empty()) {
+                let action: Action = serde_json::from_str(line).map_err(|e| {
+                    VeloxxError::Parsing(format!("Invalid Delta commit {}: {}", commit, e))
+                })?;
+                replay.apply(action)?;
+            }
+        }
+
+        let metadata = replay
+            .metadata
+            .ok_or_else(|| VeloxxError::Parsing("Delta log has no metaData action".to_string()))?;
+        Ok(Self {
+            root,
+            version,
+            schema: parse_schema(&metadata.schema_string)?,
+            partition_columns: metadata.partition_columns,
+            files: replay.files.into_values().collect(),
+        })
+    }
+
+    /// The version this snapshot was read at
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Table columns and their Veloxx types, in schema order
+    pub fn schema(&self) -> &[(String, DataType)] {
+        &self.schema
+    }
+
+    pub fn partition_columns(&self) -> &[String] {
+        &self.partition_columns
+    }
+
+    /// Data files in the snapshot, sorted by path
+    pub fn files(&self) -> &[DeltaFile] {
+        &self.files
+    }
+
+    /// Reads every data file of the snapshot.
+    pub fn to_dataframe(&self) -> Result<DataFrame, VeloxxError> {
+        self.read_files(self.files.iter().collect())
+    }
+
+    /// Reads the rows matching `condition`, skipping files whose partition values
+    /// cannot satisfy it.
+    pub fn to_dataframe_filtered(&self, condition: &Condition) -> Result<DataFrame, VeloxxError> {
+        let mut files = Vec::new();
+        for file in &self.files {
+            let values = self.partition_row(file)?;
+            if prune(condition, &values) != Some(false) {
+                files.push(file);
+            }
+        }
+        let df = self.read_files(files)?;
+        if df.row_count() == 0 {
+            return Ok(df);
+        }
+        df.filter(condition)
+    }
+
+    /// Typed partition values of `file`
+    fn partition_row(&self, file: &DeltaFile) -> Result<HashMap<&str, Option<Value>>, VeloxxError> {
+        let mut row = HashMap::with_capacity(self.partition_columns.len());
+        for name in &self.partition_columns {
+            let raw = file.partition_values.get(name).cloned().flatten();
+            let value = match raw {
+                Some(text) => Some(parse_partition_value(&text, self.column_type(name))?),
+                None => None,
+            };
+            row.insert(name.as_str(), value);
+        }
+        Ok(row)
+    }
+
+    fn column_type(&self, name: &str) -> DataType {
+        self.schema
+            .iter()
+            .find(|(column, _)| column == name)
+            .map_or(DataType::String, |(_, data_type)| data_type.clone())
+    }
+
+    fn read_files(&self, files: Vec<&DeltaFile>) -> Result<DataFrame, VeloxxError> {
+        if files.is_empty() {
+            return self.empty_frame();
+        }
+        let mut frames = Vec::with_capacity(files.len());
+        for file in files {
+            let location = self.resolve(&file.path)?;
+            let path = location.to_str().ok_or_else(|| {
+                VeloxxError::FileIO(format!("Non UTF-8 path '{}'", location.display()))
+            })?;
+            let mut df = crate::io::arrow::read_parquet_to_dataframe(path)?;
+            let rows = df.row_count();
+            for (name, value) in self.partition_row(file)? {
+                let series = constant_series(name, self.column_type(name), value, rows);
+                df.columns.insert(name.to_string(), series);
+            }
+            // Columns added to the schema after this file was written read as null
+            for (name, data_type) in &self.schema {
+                if !df.columns.contains_key(name) {
+                    let series = constant_series(name, data_type.clone(), None, rows);
+                    df.columns.insert(name.clone(), series);
+                }
+            }
+            frames.push(df);
+        }
+        concat_frames(frames)
+    }
+
+    fn resolve(&self, path: &str) -> Result<PathBuf, VeloxxError> {
+        if let Some(absolute) = path.strip_prefix("file://") {
+            return Ok(PathBuf::from(absolute));
+        }
+        if path.contains("://") {
+            return Err(VeloxxError::Unsupported(format!(
+                "Only local Delta data files can be read, not '{}'",
+                path
+            )));
+        }
+        Ok(self.root.join(path))
+    }
+
+    fn empty_frame(&self) -> Result<DataFrame, VeloxxError> {
+        let columns = self
+            .schema
+            .iter()
+            .map(|(name, data_type)| {
+                (
+                    name.clone(),
+                    constant_series(name, data_type.clone(), None, 0),
+                )
+            })
+            .collect();
+        DataFrame::new(columns)
+    }
+}
+
+/// Commit versions and checkpoint parts (by version) found in the log directory
+type LogListing = (Vec<u64>, BTreeMap<u64, Vec<PathBuf>>);
+
+fn list_log(log: &Path) -> Result<LogListing, VeloxxError> {
+    let mut commits = Vec::new();
+    let mut checkpoints: BTreeMap<u64, Vec<PathBuf>> = BTreeMap::new();
+    let mut expected_parts: HashMap<u64, usize> = HashMap::new();
+    for entry in std::fs::read_dir(log)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some((version, rest)) = name.split_once('.') else {
+            continue;
+        };
+        let Ok(version) = version.parse::<u64>() else {
+            continue;
+        };
+        if rest == "json" {
+            commits.push(version);
+        } else if rest == "checkpoint.parquet" {
+            checkpoints.entry(version).or_default().push(path.clone());
+            expected_parts.insert(version, 1);
+        } else if let Some(parts) = rest
+            .strip_prefix("checkpoint.")
+            .and_then(|rest| rest.strip_suffix(".parquet"))
+        {
+            // Multi-part checkpoint: <version>.checkpoint.<part>.<parts>.parquet
+            if let Some(total) = parts.split_once('.').and_then(|(_, n)| n.parse().ok()) {
+                checkpoints.entry(version).or_default().push(path.clone());
+                expected_parts.insert(version, total);
+            }
+        }
+    }
+    // Incomplete multi-part checkpoints are ignored
+    checkpoints.retain(|version, parts| Some(&parts.len()) == expected_parts.get(version));
+    for parts in checkpoints.values_mut() {
+        parts.sort();
+    }
+    commits.sort_unstable();
+    Ok((commits, checkpoints))
+}
+
+/// Applies the `add`, `metaData` and `protocol` actions of one checkpoint part; the
+/// `remove` tombstones it carries only matter for vacuuming and are skipped.
+fn read_checkpoint(path: &Path, replay: &mut Replay) -> Result<(), VeloxxError> {
+    let reader = SerializedFileReader::new(std::fs::File::open(path)?)?;
+    for row in reader.get_row_iter(None)? {
+        let row = row?;
+        let mut action = Action::default();
+        for (name, field) in row.get_column_iter() {
+            let Field::Group(group) = field else {
+                continue;
+            };
+            match name.as_str() {
+                "add" => {
+                    action.add = Some(AddAction {
+                        path: string_field(group, "path").unwrap_or_default(),
+                        partition_values: map_field(group, "partitionValues"),
+                        size: match get(group, "size") {
+                            Some(Field::Long(size)) => *size,
+                            _ => 0,
+                        },
+                        deletion_vector: matches!(
+                            get(group, "deletionVector"),
+                            Some(Field::Group(_))
+                        )
+                        .then_some(serde_json::Value::Bool(true)),
+                    })
+                }
+                "metaData" => {
+                    action.meta_data = Some(MetaDataAction {
+                        schema_string: string_field(group, "schemaString").unwrap_or_default(),
+                        partition_columns: match get(group, "partitionColumns") {
+                            Some(Field::ListInternal(list)) => list
+                                .elements()
+                                .iter()
+                                .filter_map(|element| match element {
+                                    Field::Str(s) => Some(s.clone()),
+                                    _ => None,
+                                })
+                                .collect(),
+                            _ => Vec::new(),
+                        },
+                        configuration: map_field(group, "configuration"),
+                    })
+                }
+                "protocol" => {
+                    action.protocol = Some(ProtocolAction {
+                        min_reader_version: match get(group, "minReaderVersion") {
+                            Some(Field::Int(version)) => *version,
+                            _ => 1,
+                        },
+                    })
+                }
+                _ => {}
+            }
+        }
+        replay.apply(action)?;
+    }
+    Ok(())
+}
+
+fn get<'a>(row: &'a Row, name: &str) -> Option<&'a Field> {
+    row.get_column_iter()
+        .find(|(column, _)| column.as_str() == name)
+        .map(|(_, field)| field)
+}
+
+fn string_field(row: &Row, name: &str) -> Option<String> {
+    match get(row, name)? {
+        Field::Str(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+fn map_field(row: &Row, name: &str) -> HashMap<String, Option<String>> {
+    let Some(Field::MapInternal(map)) = get(row, name) else {
+        return HashMap::new();
+    };
+    map.entries()
+        .iter()
+        .filter_map(|(key, value)| match (key, value) {
+            (Field::Str(key), Field::Str(value)) => Some((key.clone(), Some(value.clone()))),
+            (Field::Str(key), _) => Some((key.clone(), None)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Maps the top-level fields of a Delta schema string to Veloxx types; nested and
+/// unrecognized types are read as `String`.
+fn parse_schema(schema: &str) -> Result<Vec<(String, DataType)>, VeloxxError> {
+    let schema: serde_json::Value = serde_json::from_str(schema)
+        .map_err(|e| VeloxxError::Parsing(format!("Invalid Delta schema: {}", e)))?;
+    let fields = schema["fields"]
+        .as_array()
+        .ok_or_else(|| VeloxxError::Parsing("Delta schema has no fields".to_string()))?;
+    Ok(fields
+        .iter()
+        .filter_map(|field| {
+            let name = field["name"].as_str()?.to_string();
+            let data_type = match field["type"].as_str() {
+                Some("byte" | "short" | "integer" | "long") => DataType::I32,
+                Some("float" | "double") => DataType::F64,
+                Some(t) if t.starts_with("decimal") => DataType::F64,
+                Some("boolean") => DataType::Bool,
+                Some("date" | "timestamp" | "timestamp_ntz") => DataType::DateTime,
+                Some("binary") => DataType::Binary,
+                _ => DataType::String,
+            };
+            Some((name, data_type))
+        })
+        .collect())
+}
+
+/// Parses a partition value as written in the log; dates and timestamps become
+/// seconds since the Unix epoch.
+fn parse_partition_value(text: &str, data_type: DataType) -> Result<Value, VeloxxError> {
+    let invalid = || {
+        VeloxxError::Parsing(format!(
+            "Invalid {:?} partition value '{}'",
+            data_type, text
+        ))
+    };
+    Ok(match data_type {
+        DataType::I32 => {
+            let value: i64 = text.parse().map_err(|_| invalid())?;
+            Value::I32(i32::try_from(value).map_err(|_| invalid())?)
+        }
+        DataType::F64 => Value::F64(text.parse().map_err(|_| invalid())?),
+        DataType::Bool => Value::Bool(text.parse().map_err(|_| invalid())?),
+        DataType::DateTime => {
+            Value::DateTime(DateTimeFormat::Auto.parse(text).ok_or_else(invalid)?)
+        }
+        DataType::String => Value::String(text.to_string()),
+        DataType::Binary => Value::Binary(text.as_bytes().to_vec()),
+    })
+}
+
+/// `len` copies of `value` typed as `data_type`; nulls when the value is missing or
+/// of another type
+pub(crate) fn constant_series(
+    name: &str,
+    data_type: DataType,
+    value: Option<Value>,
+    len: usize,
+) -> Series {
+    match (data_type, value) {
+        (DataType::I32, Some(Value::I32(v))) => Series::new_i32(name, vec![Some(v); len]),
+        (DataType::I32, _) => Series::new_i32(name, vec![None; len]),
+        (DataType::F64, Some(Value::F64(v))) => Series::new_f64(name, vec![Some(v); len]),
+        (DataType::F64, _) => Series::new_f64(name, vec![None; len]),
+        (DataType::Bool, Some(Value::Bool(v))) => Series::new_bool(name, vec![Some(v); len]),
+        (DataType::Bool, _) => Series::new_bool(name, vec![None; len]),
+        (DataType::DateTime, Some(Value::DateTime(v))) => {
+            Series::new_datetime(name, vec![Some(v); len])
+        }
+        (DataType::DateTime, _) => Series::new_datetime(name, vec![None; len]),
+        (DataType::String, Some(Value::String(v))) => Series::new_string(name, vec![Some(v); len]),
+        (DataType::String, _) => Series::new_string(name, vec![None; len]),
+        (DataType::Binary, Some(Value::Binary(v))) => Series::new_binary(name, vec![Some(v); len]),
+        (DataType::Binary, _) => Series::new_binary(name, vec![None; len]),
+    }
+}
+
+/// Evaluates `condition` against one file's partition values: `Some(false)` means no
+/// row of the file can match, `None` that it depends on non-partition columns.
+fn prune(condition: &Condition, partition: &HashMap<&str, Option<Value>>) -> Option<bool> {
+    let compare = |column: &str, value: &Value| {
+        let cell = partition.get(column)?.as_ref()?;
+        match (cell, value) {
+            (Value::I32(a), Value::I32(b)) => a.partial_cmp(b),
+            (Value::F64(a), Value::F64(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    };
+    match condition {
+        Condition::Eq(column, value) => {
+            let cell = partition.get(column.as_str())?;
+            Some(cell.as_ref() == Some(value))
+        }
+        Condition::Gt(column, value) => compare(column, value).map(|o| o.is_gt()),
+        Condition::Lt(column, value) => compare(column, value).map(|o| o.is_lt()),
+        Condition::And(left, right) => match (prune(left, partition), prune(right, partition)) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        },
+        Condition::Or(left, right) => match (prune(left, partition), prune(right, partition)) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        },
+        Condition::Not(inner) => prune(inner, partition).map(|matched| !matched),
+        Condition::InSubnet(column, network) => match partition.get(column.as_str())? {
+            Some(Value::String(address)) => Some(network.contains_str(address)),
+            _ => Some(false),
+        },
+    }
+}
@@ -0,0 +1,388 @@
+//! Arrow Flight client and server, enabled with the `flight` feature.
+//!
+//! [`FlightClient`] fetches record batches from a Flight service, such as a feature
+//! store, a query engine or another Veloxx process, straight into a DataFrame.
+//! [`FlightServer`] serves named DataFrames: each name is both the `DoGet` ticket
+//! and the descriptor path that `GetFlightInfo` answers for.
+//!
+//! Connections are plaintext gRPC; TLS endpoints are rejected with
+//! [`VeloxxError::Unsupported`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use veloxx::advanced_io::flight::{FlightClient, FlightServer};
+//! # use veloxx::dataframe::DataFrame;
+//!
+//! # async fn run(features: DataFrame) -> Result<(), veloxx::VeloxxError> {
+//! // Serve a frame under the ticket "features/daily"
+//! let server = FlightServer::new();
+//! server.insert("features/daily", features);
+//! tokio::spawn(server.clone().serve("0.0.0.0:50051".parse().unwrap()));
+//!
+//! // Fetch it, or a flight from any other service
+//! let mut client = FlightClient::connect("grpc://feature-store:50051").await?;
+//! client.add_header("authorization", "Bearer secret")?;
+//! let daily = client.do_get("features/daily").await?;
+//! let same = client.fetch_path(&["features", "daily"]).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Service helpers return `tonic::Status` like the trait methods they serve
+#![allow(clippy::result_large_err)]
+
+use crate::dataframe::DataFrame;
+use crate::io::arrow::{dataframe_to_record_batch, record_batches_to_dataframe};
+use crate::VeloxxError;
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaAsIpc, SchemaResult, Ticket,
+};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::{Arc, PoisonError, RwLock};
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Endpoint, Server};
+use tonic::{Request, Response, Status, Streaming};
+
+/// Endpoint location meaning "fetch from the service that described the flight"
+const REUSE_CONNECTION: &str = "arrow-flight-reuse-connection://?";
+
+/// A connection to an Arrow Flight service
+pub struct FlightClient {
+    inner: arrow_flight::FlightClient,
+}
+
+impl FlightClient {
+    /// Connects to `uri`, such as `grpc://localhost:50051` (`grpc+tcp://` and
+    /// `http://` are accepted too).
+    pub async fn connect(uri: &str) -> Result<Self, VeloxxError> {
+        let failed = |e: &dyn std::fmt::Display| {
+            VeloxxError::FileIO(format!("Failed to connect to '{}': {}", uri, e))
+        };
+        let channel = Endpoint::from_shared(channel_uri(uri)?)
+            .map_err(|e| failed(&e))?
+            .connect()
+            .await
+            .map_err(|e| failed(&e))?;
+        Ok(Self {
+            inner: arrow_flight::FlightClient::new(channel),
+        })
+    }
+
+    /// Sends `value` as the `key` header with every request, e.g. an authorization
+    /// token.
+    pub fn add_header(&mut self, key: &str, value: &str) -> Result<(), VeloxxError> {
+        self.inner.add_header(key, value).map_err(|e| {
+            VeloxxError::InvalidOperation(format!("Invalid Flight header '{}': {}", key, e))
+        })
+    }
+
+    /// Fetches the stream behind one `DoGet` ticket.
+    pub async fn do_get(
+        &mut self,
+        ticket: impl Into<bytes::Bytes>,
+    ) -> Result<DataFrame, VeloxxError> {
+        let batches = self.read_ticket(Ticket::new(ticket)).await?;
+        record_batches_to_dataframe(&batches)
+    }
+
+    /// Describes the flight for an opaque `command`, such as a query, and fetches
+    /// every endpoint of it.
+    pub async fn fetch_command(
+        &mut self,
+        command: impl Into<bytes::Bytes>,
+    ) -> Result<DataFrame, VeloxxError> {
+        self.fetch(FlightDescriptor::new_cmd(command)).await
+    }
+
+    /// Describes the flight at `path` and fetches every endpoint of it.
+    pub async fn fetch_path(&mut self, path: &[&str]) -> Result<DataFrame, VeloxxError> {
+        let path = path.iter().map(|part| part.to_string()).collect();
+        self.fetch(FlightDescriptor::new_path(path)).await
+    }
+
+    /// Endpoints are read in order; those located on another service are fetched
+    /// over a new connection carrying the same headers.
+    async fn fetch(&mut self, descriptor: FlightDescriptor) -> Result<DataFrame, VeloxxError> {
+        let mut info = self
+            .inner
+            .get_flight_info(descriptor)
+            .await
+            .map_err(request_failed)?;
+        let mut batches = Vec::new();
+        for endpoint in std::mem::take(&mut info.endpoint) {
+            let ticket = endpoint
+                .ticket
+                .ok_or_else(|| VeloxxError::Parsing("Flight endpoint has no ticket".to_string()))?;
+            let elsewhere = endpoint
+                .location
+                .iter()
+                .all(|location| location.uri != REUSE_CONNECTION)
+                .then(|| endpoint.location.first())
+                .flatten();
+            match elsewhere {
+                Some(location) => {
+                    let mut client = Self::connect(&location.uri).await?;
+                    *client.inner.metadata_mut() = self.inner.metadata().clone();
+                    batches.extend(client.read_ticket(ticket).await?);
+                }
+                None => batches.extend(self.read_ticket(ticket).await?),
+            }
+        }
+        if batches.is_empty() {
+            let schema = info.try_decode_schema()?;
+            batches.push(RecordBatch::new_empty(Arc::new(schema)));
+        }
+        record_batches_to_dataframe(&batches)
+    }
+
+    /// Record batches of one ticket; an empty stream yields one empty batch with the
+    /// stream's schema
+    async fn read_ticket(&mut self, ticket: Ticket) -> Result<Vec<RecordBatch>, VeloxxError> {
+        let mut stream = self.inner.do_get(ticket).await.map_err(request_failed)?;
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.try_next().await.map_err(request_failed)? {
+            batches.push(batch);
+        }
+        if batches.is_empty() {
+            if let Some(schema) = stream.schema() {
+                batches.push(RecordBatch::new_empty(schema.clone()));
+            }
+        }
+        Ok(batches)
+    }
+}
+
+/// Maps `grpc://` and `grpc+tcp://` locations to the `http://` URIs gRPC channels
+/// connect to
+fn channel_uri(uri: &str) -> Result<String, VeloxxError> {
+    if let Some(address) = uri
+        .strip_prefix("grpc://")
+        .or_else(|| uri.strip_prefix("grpc+tcp://"))
+    {
+        return Ok(format!("http://{}", address));
+    }
+    if uri.starts_with("http://") {
+        return Ok(uri.to_string());
+    }
+    Err(VeloxxError::Unsupported(format!(
+        "Only plaintext Flight endpoints (grpc://, grpc+tcp:// or http://) are supported, not '{}'",
+        uri
+    )))
+}
+
+fn request_failed(e: impl std::fmt::Display) -> VeloxxError {
+    VeloxxError::FileIO(format!("Flight request failed: {}", e))
+}
+
+/// A Flight service serving named DataFrames
+///
+/// Clones share their frames, so frames can be inserted or removed while a clone
+/// is serving.
+#[derive(Clone, Default)]
+pub struct FlightServer {
+    frames: Arc<RwLock<HashMap<String, DataFrame>>>,
+}
+
+impl FlightServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `df` under `name`, replacing any frame of that name.
+    pub fn insert(&self, name: &str, df: DataFrame) {
+        self.frames
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.to_string(), df);
+    }
+
+    /// Stops serving the frame named `name` and returns it.
+    pub fn remove(&self, name: &str) -> Option<DataFrame> {
+        self.frames
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(name)
+    }
+
+    /// Names of the frames being served, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .frames
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Serves on `addr` until the task is dropped.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), VeloxxError> {
+        Server::builder()
+            .add_service(FlightServiceServer::new(self))
+            .serve(addr)
+            .await
+            .map_err(|e| VeloxxError::FileIO(format!("Flight server failed: {}", e)))
+    }
+
+    /// Serves on an already bound `listener` until `shutdown` completes; binding to
+    /// port 0 lets the caller learn the port before serving.
+    pub async fn serve_with_listener<F>(
+        self,
+        listener: tokio::net::TcpListener,
+        shutdown: F,
+    ) -> Result<(), VeloxxError>
+    where
+        F: Future<Output = ()>,
+    {
+        let failed =
+            |e: &dyn std::fmt::Display| VeloxxError::FileIO(format!("Flight server failed: {}", e));
+        let incoming = TcpIncoming::from_listener(listener, true, None).map_err(|e| failed(&e))?;
+        Server::builder()
+            .add_service(FlightServiceServer::new(self))
+            .serve_with_incoming_shutdown(incoming, shutdown)
+            .await
+            .map_err(|e| failed(&e))
+    }
+
+    /// The frame named `name` as a single record batch
+    fn batch(&self, name: &str) -> Result<RecordBatch, Status> {
+        let frames = self.frames.read().unwrap_or_else(PoisonError::into_inner);
+        let df = frames
+            .get(name)
+            .ok_or_else(|| Status::not_found(format!("No DataFrame named '{}'", name)))?;
+        dataframe_to_record_batch(df).map_err(|e| Status::internal(e.to_string()))
+    }
+
+    fn flight_info(&self, name: &str) -> Result<FlightInfo, Status> {
+        let batch = self.batch(name)?;
+        let info = FlightInfo::new()
+            .try_with_schema(&batch.schema())
+            .map_err(|e| Status::internal(e.to_string()))?
+            .with_descriptor(FlightDescriptor::new_path(
+                name.split('/').map(str::to_string).collect(),
+            ))
+            .with_endpoint(FlightEndpoint::new().with_ticket(Ticket::new(name.to_string())))
+            .with_total_records(batch.num_rows() as i64);
+        Ok(info)
+    }
+}
+
+/// The frame name a descriptor refers to: its path joined with `/`, or its command
+fn descriptor_name(descriptor: &FlightDescriptor) -> Result<String, Status> {
+    if !descriptor.path.is_empty() {
+        return Ok(descriptor.path.join("/"));
+    }
+    String::from_utf8(descriptor.cmd.to_vec())
+        .map_err(|_| Status::invalid_argument("Flight command is not a UTF-8 frame name"))
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightServer {
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("Handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        let infos = self
+            .names()
+            .iter()
+            .map(|name| self.flight_info(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Response::new(
+            stream::iter(infos.into_iter().map(Ok)).boxed(),
+        ))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let name = descriptor_name(request.get_ref())?;
+        Ok(Response::new(self.flight_info(&name)?))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("PollFlightInfo is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let name = descriptor_name(request.get_ref())?;
+        let batch = self.batch(&name)?;
+        let schema = SchemaAsIpc::new(&batch.schema(), &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e: arrow::error::ArrowError| Status::internal(e.to_string()))?;
+        Ok(Response::new(schema))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let name = String::from_utf8(request.get_ref().ticket.to_vec())
+            .map_err(|_| Status::invalid_argument("Flight ticket is not a UTF-8 frame name"))?;
+        let batch = self.batch(&name)?;
+        let stream = FlightDataEncoderBuilder::new()
+            .build(stream::iter([Ok(batch)]))
+            .map_err(|e| Status::internal(e.to_string()));
+        Ok(Response::new(stream.boxed()))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("DoPut is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("DoExchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("DoAction is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(stream::empty().boxed()))
+    }
+}
@@ -0,0 +1,800 @@
+//! Reader for Apache Iceberg tables stored on the local filesystem.
+//!
+//! The table metadata in `metadata/` (the version named by `version-hint.text`, or
+//! else the newest `*.metadata.json`) points each snapshot at a manifest list. The
+//! manifest list and the manifests it names are Avro files that record every data
+//! file of the snapshot with its partition values and per-column statistics.
+//! Filtered reads skip each file whose identity partitions or column bounds show it
+//! holds no matching row, and the remaining Parquet files are read and stacked.
+//!
+//! Columns are matched to data files by name, so a renamed column reads as null in
+//! files written before the rename. Tables with row-level deletes and data files in
+//! formats other than Parquet are rejected with [`VeloxxError::Unsupported`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use veloxx::advanced_io::iceberg::IcebergTable;
+//! use veloxx::conditions::Condition;
+//! use veloxx::types::Value;
+//!
+//! # fn main() -> Result<(), veloxx::VeloxxError> {
+//! let table = IcebergTable::open("lakehouse/events")?;
+//! let latest = table.to_dataframe()?;
+//!
+//! // Only files whose partitions or statistics allow region = 'EU' are read
+//! let eu = Condition::Eq("region".to_string(), Value::String("EU".to_string()));
+//! let pruned = table.to_dataframe_filtered(&eu)?;
+//!
+//! // Time travel
+//! let first = table.snapshots()[0];
+//! let old = IcebergTable::open_snapshot("lakehouse/events", first)?.to_dataframe()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::advanced_io::delta::constant_series;
+use crate::advanced_io::statistics::{prune, ColumnStatistics};
+use crate::conditions::Condition;
+use crate::dataframe::DataFrame;
+use crate::io::partitioned::concat_frames;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use apache_avro::types::Value as Avro;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Highest table format version understood by this reader
+const MAX_FORMAT_VERSION: i32 = 2;
+
+/// Manifest entry status of a file removed in the manifest's snapshot
+const STATUS_DELETED: i64 = 2;
+
+/// A data file in a table snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcebergFile {
+    /// Location as recorded in the manifest
+    pub path: String,
+    /// File format as recorded in the manifest, such as `PARQUET`
+    pub format: String,
+    /// Partition values by partition field name; `None` is a null partition
+    pub partition_values: HashMap<String, Option<Value>>,
+    pub record_count: i64,
+    /// File size in bytes
+    pub size: i64,
+    /// Statistics by column name, from the manifest and identity partitions
+    statistics: HashMap<String, ColumnStatistics>,
+}
+
+/// A snapshot of an Iceberg table
+#[derive(Debug, Clone)]
+pub struct IcebergTable {
+    root: PathBuf,
+    /// Table location recorded in the metadata, which prefixes its file paths
+    location: String,
+    snapshot_id: Option<i64>,
+    snapshots: Vec<i64>,
+    schema: Vec<Column>,
+    partition_fields: Vec<String>,
+    files: Vec<IcebergFile>,
+}
+
+/// A top-level column of the table schema
+#[derive(Debug, Clone)]
+struct Column {
+    id: i64,
+    name: String,
+    /// Iceberg type name; empty for nested types
+    kind: String,
+    data_type: DataType,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct TableMetadata {
+    format_version: i32,
+    #[serde(default)]
+    location: String,
+    current_schema_id: Option<i32>,
+    #[serde(default)]
+    schemas: Vec<SchemaJson>,
+    /// Format v1 tables may record a single schema
+    schema: Option<SchemaJson>,
+    default_spec_id: Option<i32>,
+    #[serde(default)]
+    partition_specs: Vec<PartitionSpec>,
+    /// Format v1 tables may record a single partition spec
+    #[serde(default)]
+    partition_spec: Vec<PartitionField>,
+    current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    snapshots: Vec<Snapshot>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct SchemaJson {
+    #[serde(default)]
+    schema_id: i32,
+    fields: Vec<FieldJson>,
+}
+
+#[derive(Deserialize)]
+struct FieldJson {
+    id: i64,
+    name: String,
+    #[serde(rename = "type")]
+    field_type: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PartitionSpec {
+    #[serde(default)]
+    spec_id: i32,
+    #[serde(default)]
+    fields: Vec<PartitionField>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct PartitionField {
+    source_id: i64,
+    name: String,
+    transform: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Snapshot {
+    snapshot_id: i64,
+    manifest_list: Option<String>,
+    /// Format v1 snapshots may list their manifests instead of a manifest list
+    #[serde(default)]
+    manifests: Vec<String>,
+    schema_id: Option<i32>,
+}
+
+impl IcebergTable {
+    /// Opens the current snapshot of the table at `path`.
+    pub fn open(path: &str) -> Result<Self, VeloxxError> {
+        Self::load(path, None)
+    }
+
+    /// Opens the table at `path` as of the snapshot `snapshot_id` (time travel).
+    pub fn open_snapshot(path: &str, snapshot_id: i64) -> Result<Self, VeloxxError> {
+        Self::load(path, Some(snapshot_id))
+    }
+
+    fn load(path: &str, snapshot_id: Option<i64>) -> Result<Self, VeloxxError> {
+        let root = PathBuf::from(path);
+        let metadata_path = latest_metadata(&root)?;
+        let text = std::fs::read_to_string(&metadata_path)?;
+        let metadata: TableMetadata = serde_json::from_str(&text).map_err(|e| {
+            VeloxxError::Parsing(format!(
+                "Invalid Iceberg metadata '{}': {}",
+                metadata_path.display(),
+                e
+            ))
+        })?;
+        if metadata.format_version > MAX_FORMAT_VERSION {
+            return Err(VeloxxError::Unsupported(format!(
+                "Iceberg format version {} is not supported",
+                metadata.format_version
+            )));
+        }
+
+        let snapshot_id = snapshot_id.or(metadata.current_snapshot_id.filter(|&id| id != -1));
+        let snapshot = match snapshot_id {
+            Some(id) => Some(
+                metadata
+                    .snapshots
+                    .iter()
+                    .find(|snapshot| snapshot.snapshot_id == id)
+                    .ok_or_else(|| {
+                        VeloxxError::InvalidOperation(format!(
+                            "Iceberg snapshot {} does not exist",
+                            id
+                        ))
+                    })?,
+            ),
+            None => None,
+        };
+
+        let schema_id = snapshot
+            .and_then(|snapshot| snapshot.schema_id)
+            .or(metadata.current_schema_id);
+        let schema = metadata
+            .schemas
+            .iter()
+            .find(|schema| Some(schema.schema_id) == schema_id)
+            .or(metadata.schema.as_ref())
+            .or(metadata.schemas.first())
+            .ok_or_else(|| VeloxxError::Parsing("Iceberg metadata has no schema".to_string()))?;
+        let schema: Vec<Column> = schema.fields.iter().map(Column::new).collect();
+
+        let mut specs: HashMap<i32, Vec<PartitionField>> = metadata
+            .partition_specs
+            .into_iter()
+            .map(|spec| (spec.spec_id, spec.fields))
+            .collect();
+        if specs.is_empty() {
+            specs.insert(0, metadata.partition_spec);
+        }
+        let default_spec = metadata.default_spec_id.unwrap_or_default();
+        let partition_fields = specs
+            .get(&default_spec)
+            .map(|fields| fields.iter().map(|field| field.name.clone()).collect())
+            .unwrap_or_default();
+
+        let mut table = Self {
+            root,
+            location: metadata.location,
+            snapshot_id,
+            snapshots: metadata
+                .snapshots
+                .iter()
+                .map(|snapshot| snapshot.snapshot_id)
+                .collect(),
+            schema,
+            partition_fields,
+            files: Vec::new(),
+        };
+        if let Some(snapshot) = snapshot {
+            let manifests = match &snapshot.manifest_list {
+                Some(list) => table.read_manifest_list(list)?,
+                None => snapshot
+                    .manifests
+                    .iter()
+                    .map(|path| (path.clone(), None, 0))
+                    .collect(),
+            };
+            for (path, spec_id, content) in manifests {
+                table.read_manifest(&path, spec_id, content, &specs)?;
+            }
+            table.files.sort_by(|a, b| a.path.cmp(&b.path));
+        }
+        Ok(table)
+    }
+
+    /// The snapshot this table was read at; `None` for a table without snapshots
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    /// Ids of every snapshot in the table metadata, oldest first
+    pub fn snapshots(&self) -> &[i64] {
+        &self.snapshots
+    }
+
+    /// Table columns and their Veloxx types, in schema order
+    pub fn schema(&self) -> Vec<(String, DataType)> {
+        self.schema
+            .iter()
+            .map(|column| (column.name.clone(), column.data_type.clone()))
+            .collect()
+    }
+
+    /// Partition field names of the table's default partition spec
+    pub fn partition_fields(&self) -> &[String] {
+        &self.partition_fields
+    }
+
+    /// Data files in the snapshot, sorted by path
+    pub fn files(&self) -> &[IcebergFile] {
+        &self.files
+    }
+
+    /// Reads every data file of the snapshot.
+    pub fn to_dataframe(&self) -> Result<DataFrame, VeloxxError> {
+        self.read_files(self.files.iter().collect())
+    }
+
+    /// Reads the rows matching `condition`, skipping files whose partition values or
+    /// column statistics show they hold no matching row.
+    pub fn to_dataframe_filtered(&self, condition: &Condition) -> Result<DataFrame, VeloxxError> {
+        let files = self
+            .files
+            .iter()
+            .filter(|file| {
+                let statistics = file
+                    .statistics
+                    .iter()
+                    .map(|(name, stats)| (name.as_str(), stats))
+                    .collect();
+                prune(condition, &statistics) != Some(false)
+            })
+            .collect();
+        let df = self.read_files(files)?;
+        if df.row_count() == 0 {
+            return Ok(df);
+        }
+        df.filter(condition)
+    }
+
+    /// Manifest paths with their partition spec ids and content kinds
+    fn read_manifest_list(&self, path: &str) -> Result<Vec<ManifestRef>, VeloxxError> {
+        let (_, records) = read_avro(&self.resolve(path)?)?;
+        records
+            .iter()
+            .map(|record| {
+                let path = field(record, "manifest_path")
+                    .and_then(string)
+                    .ok_or_else(|| {
+                        VeloxxError::Parsing("Iceberg manifest list entry has no path".to_string())
+                    })?;
+                let spec_id = field(record, "partition_spec_id").and_then(long);
+                let content = field(record, "content").and_then(long).unwrap_or(0);
+                Ok((path.to_string(), spec_id, content))
+            })
+            .collect()
+    }
+
+    /// Adds the live data files of one manifest
+    fn read_manifest(
+        &mut self,
+        path: &str,
+        spec_id: Option<i64>,
+        content: i64,
+        specs: &HashMap<i32, Vec<PartitionField>>,
+    ) -> Result<(), VeloxxError> {
+        let (metadata, entries) = read_avro(&self.resolve(path)?)?;
+        let spec_id = spec_id.or_else(|| {
+            let id = metadata.get("partition-spec-id")?;
+            std::str::from_utf8(id).ok()?.parse().ok()
+        });
+        let spec = specs
+            .get(&(spec_id.unwrap_or(0) as i32))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        for entry in &entries {
+            if field(entry, "status").and_then(long) == Some(STATUS_DELETED) {
+                continue;
+            }
+            let file = field(entry, "data_file").ok_or_else(|| {
+                VeloxxError::Parsing(format!("Iceberg manifest '{}' has no data_file", path))
+            })?;
+            if content != 0 || field(file, "content").and_then(long).unwrap_or(0) != 0 {
+                return Err(VeloxxError::Unsupported(
+                    "Iceberg tables with row-level deletes are not supported".to_string(),
+                ));
+            }
+            let file = self.data_file(file, spec).ok_or_else(|| {
+                VeloxxError::Parsing(format!(
+                    "Invalid data file entry in Iceberg manifest '{}'",
+                    path
+                ))
+            })?;
+            self.files.push(file);
+        }
+        Ok(())
+    }
+
+    fn data_file(&self, file: &Avro, spec: &[PartitionField]) -> Option<IcebergFile> {
+        let record_count = long(field(file, "record_count")?)?;
+        let mut statistics = HashMap::new();
+        let value_counts = by_field_id(field(file, "value_counts"));
+        let null_counts = by_field_id(field(file, "null_value_counts"));
+        let lower_bounds = by_field_id(field(file, "lower_bounds"));
+        let upper_bounds = by_field_id(field(file, "upper_bounds"));
+        for column in &self.schema {
+            let bound = |bounds: &HashMap<i64, &Avro>| match bounds.get(&column.id) {
+                Some(Avro::Bytes(bytes)) | Some(Avro::Fixed(_, bytes)) => {
+                    decode_bound(bytes, &column.kind)
+                }
+                _ => None,
+            };
+            let count = |counts: &HashMap<i64, &Avro>| long(counts.get(&column.id)?);
+            let nulls = count(&null_counts);
+            let stats = ColumnStatistics {
+                values: count(&value_counts)
+                    .zip(nulls)
+                    .map(|(values, nulls)| (values - nulls).max(0) as u64),
+                has_null: nulls.map(|nulls| nulls > 0),
+                min: bound(&lower_bounds),
+                max: bound(&upper_bounds),
+            };
+            if stats != ColumnStatistics::default() {
+                statistics.insert(column.name.clone(), stats);
+            }
+        }
+
+        let mut partition_values = HashMap::with_capacity(spec.len());
+        let partition = field(file, "partition");
+        for field in spec {
+            let source = self.schema.iter().find(|c| c.id == field.source_id);
+            let kind = match field.transform.as_str() {
+                "identity" => source.map_or("", |c| c.kind.as_str()),
+                t if t.starts_with("truncate") => source.map_or("", |c| c.kind.as_str()),
+                "day" => "date",
+                _ => "int",
+            };
+            let value = partition
+                .and_then(|partition| self::field(partition, &field.name))
+                .and_then(|value| partition_value(value, kind));
+            // Identity partitions pin the source column to a single value
+            if let (Some(source), "identity") = (source, field.transform.as_str()) {
+                let stats = match &value {
+                    Some(value) => ColumnStatistics {
+                        values: Some(record_count.max(0) as u64),
+                        has_null: Some(false),
+                        min: Some(value.clone()),
+                        max: Some(value.clone()),
+                    },
+                    None => ColumnStatistics {
+                        values: Some(0),
+                        has_null: Some(record_count > 0),
+                        min: None,
+                        max: None,
+                    },
+                };
+                statistics.insert(source.name.clone(), stats);
+            }
+            partition_values.insert(field.name.clone(), value);
+        }
+
+        Some(IcebergFile {
+            path: string(field(file, "file_path")?)?.to_string(),
+            format: string(field(file, "file_format")?)?.to_string(),
+            partition_values,
+            record_count,
+            size: field(file, "file_size_in_bytes")
+                .and_then(long)
+                .unwrap_or(0),
+            statistics,
+        })
+    }
+
+    fn read_files(&self, files: Vec<&IcebergFile>) -> Result<DataFrame, VeloxxError> {
+        if files.is_empty() {
+            return self.empty_frame();
+        }
+        let mut frames = Vec::with_capacity(files.len());
+        for file in files {
+            if !file.format.eq_ignore_ascii_case("parquet") {
+                return Err(VeloxxError::Unsupported(format!(
+                    "Only Parquet data files can be read, not {} file '{}'",
+                    file.format, file.path
+                )));
+            }
+            let location = self.resolve(&file.path)?;
+            let path = location.to_str().ok_or_else(|| {
+                VeloxxError::FileIO(format!("Non UTF-8 path '{}'", location.display()))
+            })?;
+            let mut df = crate::io::arrow::read_parquet_to_dataframe(path)?;
+            let rows = df.row_count();
+            // Columns dropped from the schema are left out; columns added after this
+            // file was written read as null
+            let mut columns = HashMap::with_capacity(self.schema.len());
+            for column in &self.schema {
+                let series = match df.columns.remove(&column.name) {
+                    Some(series) => series,
+                    None => constant_series(&column.name, column.data_type.clone(), None, rows),
+                };
+                columns.insert(column.name.clone(), series);
+            }
+            frames.push(DataFrame::new(columns)?);
+        }
+        concat_frames(frames)
+    }
+
+    /// Files under the recorded table location are read relative to the opened root,
+    /// so a table can be copied or mounted elsewhere.
+    fn resolve(&self, path: &str) -> Result<PathBuf, VeloxxError> {
+        let location = self.location.trim_end_matches('/');
+        if let Some(relative) = path
+            .strip_prefix(location)
+            .filter(|rest| !location.is_empty() && rest.starts_with('/'))
+        {
+            return Ok(self.root.join(relative.trim_start_matches('/')));
+        }
+        if let Some(local) = path.strip_prefix("file://") {
+            return Ok(PathBuf::from(local));
+        }
+        if let Some(local) = path.strip_prefix("file:") {
+            return Ok(PathBuf::from(local));
+        }
+        if path.contains("://") {
+            return Err(VeloxxError::Unsupported(format!(
+                "Only local Iceberg files can be read, not '{}'",
+                path
+            )));
+        }
+        Ok(self.root.join(path))
+    }
+
+    fn empty_frame(&self) -> Result<DataFrame, VeloxxError> {
+        let columns = self
+            .schema
+            .iter()
+            .map(|column| {
+                (
+                    column.name.clone(),
+                    constant_series(&column.name, column.data_type.clone(), None, 0),
+                )
+            })
+            .collect();
+        DataFrame::new(columns)
+    }
+}
+
+/// Manifest path, partition spec id and content kind (0 data, 1 deletes)
+type ManifestRef = (String, Option<i64>, i64);
+
+impl Column {
+    /// Maps a schema field to a Veloxx type; nested and unrecognized types are read
+    /// as `String`.
+    fn new(field: &FieldJson) -> Self {
+        let kind = field.field_type.as_str().unwrap_or_default().to_string();
+        let data_type = match kind.as_str() {
+            "int" | "long" => DataType::I32,
+            "float" | "double" => DataType::F64,
+            k if k.starts_with("decimal") => DataType::F64,
+            "boolean" => DataType::Bool,
+            "date" | "timestamp" | "timestamptz" | "timestamp_ns" | "timestamptz_ns" => {
+                DataType::DateTime
+            }
+            "binary" => DataType::Binary,
+            k if k.starts_with("fixed") => DataType::Binary,
+            _ => DataType::String,
+        };
+        Self {
+            id: field.id,
+            name: field.name.clone(),
+            kind,
+            data_type,
+        }
+    }
+}
+
+/// The metadata file named by `metadata/version-hint.text`, or else the one with the
+/// highest version number
+fn latest_metadata(root: &Path) -> Result<PathBuf, VeloxxError> {
+    let directory = root.join("metadata");
+    if let Ok(hint) = std::fs::read_to_string(directory.join("version-hint.text")) {
+        let version = hint.trim();
+        for name in [
+            format!("v{}.metadata.json", version),
+            format!("{}.metadata.json", version),
+        ] {
+            let path = directory.join(name);
+            if path.is_file() {
+                return Ok(path);
+            }
+        }
+    }
+    let not_a_table =
+        || VeloxxError::FileIO(format!("'{}' is not an Iceberg table", root.display()));
+    let mut latest: Option<(u64, PathBuf)> = None;
+    for entry in std::fs::read_dir(&directory).map_err(|_| not_a_table())? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !name.ends_with(".metadata.json") {
+            continue;
+        }
+        // v3.metadata.json or 00003-<uuid>.metadata.json
+        let digits: String = name
+            .trim_start_matches('v')
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        let Ok(version) = digits.parse::<u64>() else {
+            continue;
+        };
+        if latest.as_ref().is_none_or(|(newest, _)| version > *newest) {
+            latest = Some((version, path));
+        }
+    }
+    latest.map(|(_, path)| path).ok_or_else(not_a_table)
+}
+
+/// Entries of a manifest `map<int, _>`, stored as an array of key/value records
+fn by_field_id(map: Option<&Avro>) -> HashMap<i64, &Avro> {
+    match map {
+        Some(Avro::Array(entries)) => entries
+            .iter()
+            .filter_map(|entry| Some((long(field(entry, "key")?)?, field(entry, "value")?)))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// A partition value of Iceberg type `kind`; dates and timestamps become seconds
+/// since the Unix epoch
+fn partition_value(value: &Avro, kind: &str) -> Option<Value> {
+    if let Some(value) = long(value) {
+        return match kind {
+            "date" => Some(Value::DateTime(value * 86_400)),
+            "timestamp" | "timestamptz" => Some(Value::DateTime(value.div_euclid(1_000_000))),
+            k if k.starts_with("timestamp") => {
+                Some(Value::DateTime(value.div_euclid(1_000_000_000)))
+            }
+            _ => i32::try_from(value).ok().map(Value::I32),
+        };
+    }
+    match union_value(value) {
+        Avro::Float(value) => Some(Value::F64(*value as f64)),
+        Avro::Double(value) => Some(Value::F64(*value)),
+        Avro::Boolean(value) => Some(Value::Bool(*value)),
+        Avro::String(value) => Some(Value::String(value.clone())),
+        Avro::Uuid(value) => Some(Value::String(value.to_string())),
+        Avro::Decimal(value) => decimal(&Vec::<u8>::try_from(value).ok()?, kind),
+        Avro::Bytes(bytes) | Avro::Fixed(_, bytes) if kind.starts_with("decimal") => {
+            decimal(bytes, kind)
+        }
+        Avro::Bytes(bytes) | Avro::Fixed(_, bytes) => Some(Value::Binary(bytes.clone())),
+        _ => None,
+    }
+}
+
+/// Decodes a column bound in Iceberg's single-value binary serialization; bounds of
+/// types that cannot be compared are skipped
+fn decode_bound(bytes: &[u8], kind: &str) -> Option<Value> {
+    let int = || Some(i32::from_le_bytes(bytes.try_into().ok()?));
+    let long = || Some(i64::from_le_bytes(bytes.try_into().ok()?));
+    Some(match kind {
+        "boolean" => Value::Bool(*bytes.first()? != 0),
+        "int" => Value::I32(int()?),
+        "long" => Value::I32(i32::try_from(long()?).ok()?),
+        "float" => Value::F64(f32::from_le_bytes(bytes.try_into().ok()?) as f64),
+        "double" => Value::F64(f64::from_le_bytes(bytes.try_into().ok()?)),
+        "date" => Value::DateTime(int()? as i64 * 86_400),
+        "timestamp" | "timestamptz" => Value::DateTime(long()?.div_euclid(1_000_000)),
+        "timestamp_ns" | "timestamptz_ns" => Value::DateTime(long()?.div_euclid(1_000_000_000)),
+        "string" => Value::String(String::from_utf8(bytes.to_vec()).ok()?),
+        k if k.starts_with("decimal") => decimal(bytes, k)?,
+        _ => return None,
+    })
+}
+
+/// A big-endian two's complement unscaled value of type `decimal(P, S)`
+fn decimal(bytes: &[u8], kind: &str) -> Option<Value> {
+    if bytes.is_empty() || bytes.len() > 16 {
+        return None;
+    }
+    let scale: i32 = kind
+        .split(',')
+        .nth(1)?
+        .trim()
+        .trim_end_matches(')')
+        .parse()
+        .ok()?;
+    let mut unscaled = if bytes[0] & 0x80 != 0 { -1i128 } else { 0 };
+    for byte in bytes {
+        unscaled = (unscaled << 8) | i128::from(*byte);
+    }
+    Some(Value::F64(unscaled as f64 / 10f64.powi(scale)))
+}
+
+/// Field `name` of an Avro record
+fn field<'a>(record: &'a Avro, name: &str) -> Option<&'a Avro> {
+    match union_value(record) {
+        Avro::Record(fields) => fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| union_value(value)),
+        _ => None,
+    }
+}
+
+/// The value held by an optional (union) field
+fn union_value(value: &Avro) -> &Avro {
+    match value {
+        Avro::Union(_, value) => union_value(value),
+        value => value,
+    }
+}
+
+/// An `int` or `long`, including dates and timestamps in their Iceberg units
+fn long(value: &Avro) -> Option<i64> {
+    match union_value(value) {
+        Avro::Int(value) | Avro::Date(value) => Some(*value as i64),
+        Avro::Long(value)
+        | Avro::TimestampMicros(value)
+        | Avro::LocalTimestampMicros(value)
+        | Avro::TimestampNanos(value)
+        | Avro::LocalTimestampNanos(value) => Some(*value),
+        _ => None,
+    }
+}
+
+/// A string or enum symbol
+fn string(value: &Avro) -> Option<&str> {
+    match union_value(value) {
+        Avro::String(value) | Avro::Enum(_, value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Metadata and records of an Avro object container file
+type AvroFile = (HashMap<String, Vec<u8>>, Vec<Avro>);
+
+/// Reads every record of an Avro object container file, along with the file's
+/// metadata
+fn read_avro(path: &Path) -> Result<AvroFile, VeloxxError> {
+    let invalid = |e: apache_avro::Error| {
+        VeloxxError::Parsing(format!("Invalid Avro file '{}': {}", path.display(), e))
+    };
+    let file = BufReader::new(File::open(path)?);
+    let reader = apache_avro::Reader::new(file).map_err(invalid)?;
+    let metadata = reader.user_metadata().clone();
+    let records = reader.collect::<Result<_, _>>().map_err(invalid)?;
+    Ok((metadata, records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_values() {
+        let optional = |value| Avro::Union(1, Box::new(value));
+        assert_eq!(
+            partition_value(&optional(Avro::Int(2024)), "int"),
+            Some(Value::I32(2024))
+        );
+        assert_eq!(
+            partition_value(&optional(Avro::Date(2)), "date"),
+            Some(Value::DateTime(172_800))
+        );
+        assert_eq!(
+            partition_value(&Avro::TimestampMicros(1_500_000), "timestamptz"),
+            Some(Value::DateTime(1))
+        );
+        assert_eq!(partition_value(&Avro::Long(i64::MAX), "long"), None);
+        assert_eq!(
+            partition_value(&Avro::Enum(0, "EU".to_string()), "string"),
+            None
+        );
+        assert_eq!(
+            partition_value(&optional(Avro::String("EU".to_string())), "string"),
+            Some(Value::String("EU".to_string()))
+        );
+        // -12.345 as decimal(5, 3)
+        assert_eq!(
+            partition_value(
+                &Avro::Fixed(2, (-12_345i16).to_be_bytes().to_vec()),
+                "decimal(5, 3)"
+            ),
+            Some(Value::F64(-12.345))
+        );
+        assert_eq!(partition_value(&optional(Avro::Null), "int"), None);
+    }
+
+    #[test]
+    fn test_bound_decoding() {
+        assert_eq!(
+            decode_bound(&7i32.to_le_bytes(), "int"),
+            Some(Value::I32(7))
+        );
+        assert_eq!(
+            decode_bound(&7i64.to_le_bytes(), "long"),
+            Some(Value::I32(7))
+        );
+        assert_eq!(decode_bound(&i64::MAX.to_le_bytes(), "long"), None);
+        assert_eq!(
+            decode_bound(&1_500_000i64.to_le_bytes(), "timestamptz"),
+            Some(Value::DateTime(1))
+        );
+        assert_eq!(
+            decode_bound(&2i32.to_le_bytes(), "date"),
+            Some(Value::DateTime(172_800))
+        );
+        assert_eq!(
+            decode_bound(b"abc", "string"),
+            Some(Value::String("abc".to_string()))
+        );
+        // -12.345 as decimal(5, 3)
+        assert_eq!(
+            decode_bound(&(-12_345i16).to_be_bytes(), "decimal(5, 3)"),
+            Some(Value::F64(-12.345))
+        );
+        assert_eq!(decode_bound(&[1, 2], "binary"), None);
+    }
+}
@@ -0,0 +1,625 @@
+//! ODBC connector for querying warehouses such as Snowflake and SQL Server, enabled
+//! with the `odbc` feature.
+//!
+//! The connector is built on [`odbc_api`] and talks to the system driver manager
+//! (unixODBC or iODBC on Unix, `odbc32` on Windows), so any warehouse with an
+//! installed ODBC driver can be queried. Results are fetched in blocks of
+//! `batch_size` rows into columnar buffers. Result columns are typed from the SQL
+//! types the driver reports (see [`default_data_type`]), unless overridden per column
+//! with [`OdbcOptions`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use veloxx::advanced_io::odbc::{OdbcConnection, OdbcOptions};
+//! use veloxx::types::DataType;
+//!
+//! # fn main() -> Result<(), veloxx::VeloxxError> {
+//! let options = OdbcOptions::new()
+//!     .batch_size(50_000)
+//!     .column_type("account_id", DataType::String);
+//! let connection = OdbcConnection::connect_with_options(
+//!     "Driver={SnowflakeDSIIDriver};Server=acme.snowflakecomputing.com;UID=etl;PWD=secret",
+//!     options,
+//! )?;
+//!
+//! let summary = connection.query("SELECT region, SUM(amount) AS total FROM sales GROUP BY region")?;
+//!
+//! // Large results are fetched in batches of `batch_size` rows
+//! connection.query_batches("SELECT * FROM events", |batch| {
+//!     println!("{} rows", batch.row_count());
+//!     Ok(true)
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::advanced_io::sql::SqlConnection;
+use crate::dataframe::DataFrame;
+use crate::io::datetime::{civil_from_days, days_from_civil};
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use odbc_api::buffers::{BufferDesc, ColumnarDynBuffer};
+use odbc_api::sys::Timestamp;
+use odbc_api::{
+    BindParamDesc, Bit, ColumnDescription, Connection, ConnectionOptions, Cursor, Environment,
+    ResultSetMetadata,
+};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Column type for a SQL type code reported by the driver.
+///
+/// | SQL types | Veloxx |
+/// |-----------|--------|
+/// | `BIT` | `Bool` |
+/// | `TINYINT`, `SMALLINT`, `INTEGER` | `I32` |
+/// | `BIGINT`, `REAL`, `FLOAT`, `DOUBLE`, `DECIMAL`, `NUMERIC` | `F64` |
+/// | `DATE`, `TIMESTAMP` | `DateTime` (Unix seconds) |
+/// | `BINARY`, `VARBINARY`, `LONGVARBINARY` | `Binary` |
+/// | anything else | `String` |
+pub fn default_data_type(sql_type: i16) -> DataType {
+    match sql_type {
+        -7 => DataType::Bool,
+        -6 | 4 | 5 => DataType::I32,
+        -5 | 2 | 3 | 6 | 7 | 8 => DataType::F64,
+        9 | 11 | 91 | 93 => DataType::DateTime,
+        -4..=-2 => DataType::Binary,
+        _ => DataType::String,
+    }
+}
+
+/// Fetch and type-mapping settings for [`OdbcConnection`]
+#[derive(Debug, Clone)]
+pub struct OdbcOptions {
+    batch_size: usize,
+    max_value_size: usize,
+    column_types: HashMap<String, DataType>,
+}
+
+impl Default for OdbcOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 10_000,
+            max_value_size: 4_096,
+            column_types: HashMap::new(),
+        }
+    }
+}
+
+impl OdbcOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rows fetched per block, and per DataFrame passed to
+    /// [`OdbcConnection::query_batches`] (default 10,000)
+    pub fn batch_size(mut self, rows: usize) -> Self {
+        self.batch_size = rows.max(1);
+        self
+    }
+
+    /// Largest text or binary value, in bytes, that can be fetched (default 4,096).
+    ///
+    /// Each text and binary column gets a buffer of `batch_size` slots of the
+    /// column's declared size, capped at this limit. A longer value fails the query
+    /// rather than being cut short.
+    pub fn max_value_size(mut self, bytes: usize) -> Self {
+        self.max_value_size = bytes.max(1);
+        self
+    }
+
+    /// Reads `column` as `data_type` instead of the type mapped from its SQL type;
+    /// the driver converts the values.
+    pub fn column_type(mut self, column: &str, data_type: DataType) -> Self {
+        self.column_types.insert(column.to_string(), data_type);
+        self
+    }
+
+    /// Column type for result column `name` of SQL type `sql_type`
+    pub fn data_type(&self, name: &str, sql_type: i16) -> DataType {
+        self.column_types
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| default_data_type(sql_type))
+    }
+}
+
+/// The driver manager environment, shared by every connection of the process
+fn environment() -> Result<&'static Environment, VeloxxError> {
+    static ENVIRONMENT: OnceLock<Environment> = OnceLock::new();
+    if let Some(environment) = ENVIRONMENT.get() {
+        return Ok(environment);
+    }
+    let environment = Environment::new().map_err(odbc_error)?;
+    Ok(ENVIRONMENT.get_or_init(|| environment))
+}
+
+/// An open connection to an ODBC data source
+pub struct OdbcConnection {
+    connection: Connection<'static>,
+    options: OdbcOptions,
+}
+
+impl OdbcConnection {
+    /// Connects with a driver connection string, such as
+    /// `"DSN=warehouse;UID=etl;PWD=secret"`.
+    pub fn connect(connection_string: &str) -> Result<Self, VeloxxError> {
+        Self::connect_with_options(connection_string, OdbcOptions::default())
+    }
+
+    pub fn connect_with_options(
+        connection_string: &str,
+        options: OdbcOptions,
+    ) -> Result<Self, VeloxxError> {
+        let connection = environment()?
+            .connect_with_connection_string(connection_string, ConnectionOptions::default())
+            .map_err(odbc_error)?;
+        Ok(Self {
+            connection,
+            options,
+        })
+    }
+
+    /// Runs `sql` and returns its whole result.
+    ///
+    /// Statements without a result set return an empty DataFrame.
+    pub fn query(&self, sql: &str) -> Result<DataFrame, VeloxxError> {
+        let mut batch = None;
+        self.fetch(sql, &mut |rows| {
+            batch.get_or_insert_with(|| rows.empty()).append(rows)?;
+            Ok(true)
+        })?;
+        match batch {
+            Some(mut batch) => batch.take(),
+            None => DataFrame::new(HashMap::new()),
+        }
+    }
+
+    /// Runs `sql` and passes its result to `on_batch` in DataFrames of the
+    /// configured batch size; return `Ok(false)` from `on_batch` to stop fetching.
+    pub fn query_batches<F>(&self, sql: &str, mut on_batch: F) -> Result<(), VeloxxError>
+    where
+        F: FnMut(DataFrame) -> Result<bool, VeloxxError>,
+    {
+        self.fetch(sql, &mut |rows| {
+            let mut batch = rows.empty();
+            batch.append(rows)?;
+            on_batch(batch.take()?)
+        })
+    }
+
+    /// Runs `sql` and passes each fetched block of rows to `on_rows` until it
+    /// returns `false`. Blocks are passed even when empty, so callers see the columns.
+    fn fetch(
+        &self,
+        sql: &str,
+        on_rows: &mut dyn FnMut(&Rows) -> Result<bool, VeloxxError>,
+    ) -> Result<(), VeloxxError> {
+        let Some(mut cursor) = self.connection.execute(sql, (), None).map_err(odbc_error)? else {
+            return Ok(());
+        };
+        let columns = result_columns(&mut cursor, &self.options)?;
+        if columns.is_empty() {
+            return Ok(());
+        }
+        let buffer = ColumnarDynBuffer::try_from_descs(
+            self.options.batch_size,
+            columns.iter().map(|(_, _, desc)| *desc),
+        )
+        .map_err(odbc_error)?;
+        let mut cursor = cursor.bind_buffer(buffer).map_err(odbc_error)?;
+
+        let mut fetched = false;
+        while let Some(buffer) = cursor
+            .fetch_with_truncation_check(true)
+            .map_err(|e| self.fetch_error(e))?
+        {
+            fetched = true;
+            if !on_rows(&Rows {
+                columns: &columns,
+                buffer: Some(buffer),
+            })? {
+                return Ok(());
+            }
+        }
+        if !fetched {
+            on_rows(&Rows {
+                columns: &columns,
+                buffer: None,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn fetch_error(&self, error: odbc_api::Error) -> VeloxxError {
+        match error {
+            odbc_api::Error::TooLargeValueForBuffer { .. } => {
+                VeloxxError::InvalidOperation(format!(
+                    "ODBC error: a text or binary value is longer than {} bytes; raise \
+                 OdbcOptions::max_value_size",
+                    self.options.max_value_size
+                ))
+            }
+            other => odbc_error(other),
+        }
+    }
+}
+
+/// Names, column types and fetch buffers of a result set
+fn result_columns(
+    cursor: &mut impl ResultSetMetadata,
+    options: &OdbcOptions,
+) -> Result<Vec<(String, DataType, BufferDesc)>, VeloxxError> {
+    let count = cursor.num_result_cols().map_err(odbc_error)?;
+    (1..=count.max(0) as u16)
+        .map(|column| {
+            let mut description = ColumnDescription::default();
+            cursor
+                .describe_col(column, &mut description)
+                .map_err(odbc_error)?;
+            let mut name = description.name_to_string().unwrap_or_default();
+            if name.is_empty() {
+                name = format!("column_{}", column);
+            }
+            let sql_type = description.data_type.data_type().0;
+            let data_type = options.data_type(&name, sql_type);
+            // Unbounded types such as `VARCHAR(MAX)` report no size
+            let size = description
+                .data_type
+                .utf8_len()
+                .or(description.data_type.column_size())
+                .map_or(options.max_value_size, |size| {
+                    size.get().min(options.max_value_size)
+                });
+            let desc = match data_type {
+                DataType::I32 => BufferDesc::I32 { nullable: true },
+                DataType::F64 => BufferDesc::F64 { nullable: true },
+                DataType::Bool => BufferDesc::Bit { nullable: true },
+                DataType::DateTime => BufferDesc::Timestamp { nullable: true },
+                DataType::String => BufferDesc::Text { max_str_len: size },
+                DataType::Binary => BufferDesc::Binary { max_bytes: size },
+            };
+            Ok((name, data_type, desc))
+        })
+        .collect()
+}
+
+fn odbc_error(error: odbc_api::Error) -> VeloxxError {
+    VeloxxError::InvalidOperation(format!("ODBC error: {}", error))
+}
+
+impl OdbcConnection {
+    fn end_transaction(&self, commit: bool) -> Result<(), VeloxxError> {
+        if commit {
+            self.connection.commit().map_err(odbc_error)?;
+        } else {
+            self.connection.rollback().map_err(odbc_error)?;
+        }
+        self.connection.set_autocommit(true).map_err(odbc_error)
+    }
+}
+
+/// Writes through the driver: inserts bind all rows of a batch as column-wise
+/// parameter arrays and run in one execution, and transactions switch autocommit
+/// off until they end.
+impl SqlConnection for OdbcConnection {
+    fn execute(&self, sql: &str) -> Result<(), VeloxxError> {
+        self.connection.execute(sql, (), None).map_err(odbc_error)?;
+        Ok(())
+    }
+
+    fn execute_batch(&self, sql: &str, rows: &[Vec<Value>]) -> Result<(), VeloxxError> {
+        let Some(first) = rows.first() else {
+            return Ok(());
+        };
+        let types = (0..first.len())
+            .map(|column| parameter_type(rows, column))
+            .collect::<Result<Vec<_>, _>>()?;
+        let descriptions = types
+            .iter()
+            .enumerate()
+            .map(|(column, data_type)| parameter_description(rows, column, data_type));
+        let mut inserter = self
+            .connection
+            .prepare(sql)
+            .map_err(odbc_error)?
+            .into_column_inserter(rows.len(), descriptions)
+            .map_err(odbc_error)?;
+        inserter.set_num_rows(rows.len());
+        for (column, data_type) in types.iter().enumerate() {
+            let buffer = inserter.column_mut(column);
+            let mismatch = || {
+                VeloxxError::InvalidOperation(format!(
+                    "ODBC buffer of parameter {} does not match its type",
+                    column + 1
+                ))
+            };
+            let cells = rows
+                .iter()
+                .map(|row| row.get(column).unwrap_or(&Value::Null))
+                .enumerate();
+            match data_type {
+                DataType::I32 => {
+                    let mut values = buffer.as_nullable_slice::<i32>().ok_or_else(mismatch)?;
+                    for (row, cell) in cells {
+                        values.set_cell(row, cell.as_i32());
+                    }
+                }
+                DataType::F64 => {
+                    let mut values = buffer.as_nullable_slice::<f64>().ok_or_else(mismatch)?;
+                    for (row, cell) in cells {
+                        values.set_cell(row, cell.as_f64());
+                    }
+                }
+                DataType::Bool => {
+                    let mut values = buffer.as_nullable_slice::<Bit>().ok_or_else(mismatch)?;
+                    for (row, cell) in cells {
+                        values.set_cell(row, cell.as_bool().map(Bit::from_bool));
+                    }
+                }
+                DataType::DateTime => {
+                    let mut values = buffer
+                        .as_nullable_slice::<Timestamp>()
+                        .ok_or_else(mismatch)?;
+                    for (row, cell) in cells {
+                        let value = match cell {
+                            Value::DateTime(seconds) => Some(timestamp(*seconds)),
+                            _ => None,
+                        };
+                        values.set_cell(row, value);
+                    }
+                }
+                DataType::String => {
+                    let mut values = buffer.as_text().ok_or_else(mismatch)?;
+                    for (row, cell) in cells {
+                        let value = match cell {
+                            Value::String(text) => Some(text.as_bytes()),
+                            _ => None,
+                        };
+                        values.set_cell(row, value);
+                    }
+                }
+                DataType::Binary => {
+                    let mut values = buffer.as_binary().ok_or_else(mismatch)?;
+                    for (row, cell) in cells {
+                        let value = match cell {
+                            Value::Binary(bytes) => Some(bytes.as_slice()),
+                            _ => None,
+                        };
+                        values.set_cell(row, value);
+                    }
+                }
+            }
+        }
+        inserter.execute().map_err(odbc_error)?;
+        Ok(())
+    }
+
+    fn table_exists(&self, table: &str) -> Result<bool, VeloxxError> {
+        let (schema, name) = table.rsplit_once('.').unwrap_or(("", table));
+        let mut tables = self
+            .connection
+            .tables("", schema, name, "")
+            .map_err(odbc_error)?;
+        Ok(tables.next().transpose().map_err(odbc_error)?.is_some())
+    }
+
+    fn begin(&self) -> Result<(), VeloxxError> {
+        self.connection.set_autocommit(false).map_err(odbc_error)
+    }
+
+    fn commit(&self) -> Result<(), VeloxxError> {
+        self.end_transaction(true)
+    }
+
+    fn rollback(&self) -> Result<(), VeloxxError> {
+        self.end_transaction(false)
+    }
+}
+
+/// Column type of parameter `column`, taken from its first non-null value;
+/// parameters that are null in every row bind as text
+fn parameter_type(rows: &[Vec<Value>], column: usize) -> Result<DataType, VeloxxError> {
+    let mut cells = rows
+        .iter()
+        .filter_map(|row| row.get(column))
+        .filter(|cell| !matches!(cell, Value::Null));
+    let Some(first) = cells.next() else {
+        return Ok(DataType::String);
+    };
+    let data_type = first.data_type();
+    match cells.find(|cell| cell.data_type() != data_type) {
+        Some(other) => Err(VeloxxError::DataTypeMismatch(format!(
+            "Parameter {} mixes {:?} with other types",
+            column + 1,
+            other
+        ))),
+        None => Ok(data_type),
+    }
+}
+
+/// Buffer and SQL type binding parameter `column`; text and binary buffers hold
+/// the longest value of the column
+fn parameter_description(
+    rows: &[Vec<Value>],
+    column: usize,
+    data_type: &DataType,
+) -> BindParamDesc {
+    let longest = || {
+        rows.iter()
+            .filter_map(|row| match row.get(column) {
+                Some(Value::String(text)) => Some(text.len()),
+                Some(Value::Binary(bytes)) => Some(bytes.len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    };
+    match data_type {
+        DataType::I32 => BindParamDesc::i32(true),
+        DataType::F64 => BindParamDesc::f64(true),
+        DataType::Bool => BindParamDesc {
+            buffer_desc: BufferDesc::Bit { nullable: true },
+            data_type: odbc_api::DataType::Bit,
+        },
+        DataType::DateTime => BindParamDesc::timestamp(true, 0),
+        DataType::String => BindParamDesc::text(longest()),
+        DataType::Binary => BindParamDesc::binary(longest()),
+    }
+}
+
+/// `SQL_TIMESTAMP_STRUCT` for seconds since the Unix epoch
+fn timestamp(seconds: i64) -> Timestamp {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time = seconds.rem_euclid(86_400);
+    Timestamp {
+        year: year as i16,
+        month: month as u16,
+        day: day as u16,
+        hour: (time / 3_600) as u16,
+        minute: (time / 60 % 60) as u16,
+        second: (time % 60) as u16,
+        fraction: 0,
+    }
+}
+
+/// Seconds since the Unix epoch of a `SQL_TIMESTAMP_STRUCT`, dropping fractions
+fn seconds(timestamp: &Timestamp) -> i64 {
+    let days = days_from_civil(
+        timestamp.year as i64,
+        timestamp.month as u32,
+        timestamp.day as u32,
+    );
+    days * 86_400
+        + timestamp.hour as i64 * 3_600
+        + timestamp.minute as i64 * 60
+        + timestamp.second as i64
+}
+
+/// One fetched block of rows, or none for an empty result
+struct Rows<'a> {
+    columns: &'a [(String, DataType, BufferDesc)],
+    buffer: Option<&'a ColumnarDynBuffer>,
+}
+
+impl Rows<'_> {
+    /// A batch with the columns of these rows and no values
+    fn empty(&self) -> Batch {
+        Batch {
+            columns: self
+                .columns
+                .iter()
+                .map(|(name, data_type, _)| {
+                    let values = match data_type {
+                        DataType::I32 => Values::I32(Vec::new()),
+                        DataType::F64 => Values::F64(Vec::new()),
+                        DataType::Bool => Values::Bool(Vec::new()),
+                        DataType::String => Values::String(Vec::new()),
+                        DataType::DateTime => Values::DateTime(Vec::new()),
+                        DataType::Binary => Values::Binary(Vec::new()),
+                    };
+                    (name.clone(), values)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Values of one result column
+enum Values {
+    I32(Vec<Option<i32>>),
+    F64(Vec<Option<f64>>),
+    Bool(Vec<Option<bool>>),
+    String(Vec<Option<String>>),
+    DateTime(Vec<Option<i64>>),
+    Binary(Vec<Option<Vec<u8>>>),
+}
+
+/// Columns of the rows fetched since the last DataFrame was built
+struct Batch {
+    columns: Vec<(String, Values)>,
+}
+
+impl Batch {
+    /// Copies the values of a fetched block out of the bound buffers
+    fn append(&mut self, rows: &Rows) -> Result<(), VeloxxError> {
+        let Some(buffer) = rows.buffer else {
+            return Ok(());
+        };
+        let mismatch = |name: &str| {
+            VeloxxError::InvalidOperation(format!(
+                "ODBC buffer of column '{}' does not match its type",
+                name
+            ))
+        };
+        for (index, (name, values)) in self.columns.iter_mut().enumerate() {
+            let column = buffer.column(index);
+            match values {
+                Values::I32(values) => values.extend(
+                    column
+                        .as_nullable_slice::<i32>()
+                        .ok_or_else(|| mismatch(name))?
+                        .map(|v| v.copied()),
+                ),
+                Values::F64(values) => values.extend(
+                    column
+                        .as_nullable_slice::<f64>()
+                        .ok_or_else(|| mismatch(name))?
+                        .map(|v| v.copied()),
+                ),
+                Values::Bool(values) => values.extend(
+                    column
+                        .as_nullable_slice::<Bit>()
+                        .ok_or_else(|| mismatch(name))?
+                        .map(|v| v.map(|bit| bit.as_bool())),
+                ),
+                Values::DateTime(values) => values.extend(
+                    column
+                        .as_nullable_slice::<Timestamp>()
+                        .ok_or_else(|| mismatch(name))?
+                        .map(|v| v.map(seconds)),
+                ),
+                Values::String(values) => values.extend(
+                    column
+                        .as_text()
+                        .ok_or_else(|| mismatch(name))?
+                        .iter()
+                        .map(|v| v.map(|bytes| String::from_utf8_lossy(bytes).into_owned())),
+                ),
+                Values::Binary(values) => values.extend(
+                    column
+                        .as_binary()
+                        .ok_or_else(|| mismatch(name))?
+                        .iter()
+                        .map(|v| v.map(<[u8]>::to_vec)),
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a DataFrame from the fetched rows and starts a new batch
+    fn take(&mut self) -> Result<DataFrame, VeloxxError> {
+        let mut series = HashMap::with_capacity(self.columns.len());
+        for (name, values) in &mut self.columns {
+            let column = match values {
+                Values::I32(values) => Series::new_i32(name, std::mem::take(values)),
+                Values::F64(values) => Series::new_f64(name, std::mem::take(values)),
+                Values::Bool(values) => Series::new_bool(name, std::mem::take(values)),
+                Values::String(values) => Series::new_string(name, std::mem::take(values)),
+                Values::DateTime(values) => Series::new_datetime(name, std::mem::take(values)),
+                Values::Binary(values) => Series::new_binary(name, std::mem::take(values)),
+            };
+            if series.insert(name.clone(), column).is_some() {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Query returns column '{}' more than once",
+                    name
+                )));
+            }
+        }
+        DataFrame::new(series)
+    }
+}
@@ -0,0 +1,1328 @@
+//! Reader for Apache ORC files, as written by Hive, Spark and other Hadoop jobs.
+//!
+//! [`OrcFile::open`] reads only the file tail: the schema, the stripe layout and the
+//! per-stripe column statistics. Reads then decode just the requested columns, and
+//! a filter skips every stripe whose min/max statistics show it has no matching row.
+//!
+//! ORC types map to Veloxx types as follows:
+//!
+//! | ORC | Veloxx |
+//! |-----|--------|
+//! | `boolean` | `Bool` |
+//! | `tinyint`, `smallint`, `int` | `I32` |
+//! | `bigint`, `float`, `double`, `decimal` | `F64` |
+//! | `string`, `varchar`, `char` | `String` |
+//! | `binary` | `Binary` |
+//! | `date`, `timestamp` | `DateTime` (Unix seconds) |
+//!
+//! `F64` holds integers exactly only up to 2^53, so reading a `bigint` column
+//! fails with `VeloxxError::Unsupported` when a value is beyond ±2^53, instead of
+//! rounding it. Top-level columns of nested types (`struct`, `list`, `map`,
+//! `uniontype`) are left out of the schema. Timestamps are read as UTC and
+//! truncated to whole seconds. Files compressed with ZLIB, Snappy, LZ4, zstd or no
+//! compression can be read.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use veloxx::advanced_io::orc::OrcFile;
+//! use veloxx::conditions::Condition;
+//! use veloxx::types::Value;
+//!
+//! # fn main() -> Result<(), veloxx::VeloxxError> {
+//! let file = OrcFile::open("warehouse/events.orc")?;
+//! println!("{} rows in {} stripes", file.num_rows(), file.stripe_count());
+//!
+//! // Only the `user` and `status` streams of stripes that may hold a 500 are read
+//! let errors = Condition::Eq("status".to_string(), Value::I32(500));
+//! let df = file.read(&["user", "status"], Some(&errors))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::advanced_io::statistics::{prune, ColumnStatistics};
+use crate::conditions::Condition;
+use crate::dataframe::DataFrame;
+use crate::io::partitioned::concat_frames;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use prost::Message;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Seconds from the Unix epoch to 2015-01-01, the base of ORC timestamps
+const ORC_EPOCH: i64 = 1_420_070_400;
+
+/// Largest `bigint` magnitude that `F64` represents exactly
+const MAX_EXACT_BIGINT: u64 = 1 << 53;
+
+/// Stream kinds used by the supported column types
+const PRESENT: u64 = 0;
+const DATA: u64 = 1;
+const LENGTH: u64 = 2;
+const DICTIONARY_DATA: u64 = 3;
+const SECONDARY: u64 = 5;
+
+/// An ORC file whose tail has been read
+#[derive(Debug, Clone)]
+pub struct OrcFile {
+    path: PathBuf,
+    compression: Compression,
+    block_size: usize,
+    rows: u64,
+    stripes: Vec<Stripe>,
+    columns: Vec<OrcColumn>,
+    schema: Vec<(String, DataType)>,
+    /// Top-level columns of types that cannot be read
+    nested: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    None,
+    Zlib,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Debug, Clone)]
+struct Stripe {
+    offset: u64,
+    index_length: u64,
+    data_length: u64,
+    footer_length: u64,
+    rows: u64,
+    /// Indexed by column id; empty when the file has no stripe statistics
+    statistics: Vec<ColumnStatistics>,
+}
+
+/// A readable top-level column
+#[derive(Debug, Clone)]
+struct OrcColumn {
+    name: String,
+    /// Position of the column's type in the footer, which identifies its streams
+    id: usize,
+    kind: Kind,
+    data_type: DataType,
+}
+
+/// ORC type kinds, numbered as in the file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl Kind {
+    fn from_code(code: u64) -> Option<Self> {
+        const KINDS: [Kind; 19] = [
+            Kind::Boolean,
+            Kind::Byte,
+            Kind::Short,
+            Kind::Int,
+            Kind::Long,
+            Kind::Float,
+            Kind::Double,
+            Kind::String,
+            Kind::Binary,
+            Kind::Timestamp,
+            Kind::List,
+            Kind::Map,
+            Kind::Struct,
+            Kind::Union,
+            Kind::Decimal,
+            Kind::Date,
+            Kind::Varchar,
+            Kind::Char,
+            Kind::TimestampInstant,
+        ];
+        KINDS.get(code as usize).copied()
+    }
+
+    /// Veloxx type of the column, or `None` for nested types
+    fn data_type(self) -> Option<DataType> {
+        Some(match self {
+            Kind::Boolean => DataType::Bool,
+            Kind::Byte | Kind::Short | Kind::Int => DataType::I32,
+            Kind::Long | Kind::Float | Kind::Double | Kind::Decimal => DataType::F64,
+            Kind::String | Kind::Varchar | Kind::Char => DataType::String,
+            Kind::Binary => DataType::Binary,
+            Kind::Timestamp | Kind::TimestampInstant | Kind::Date => DataType::DateTime,
+            Kind::List | Kind::Map | Kind::Struct | Kind::Union => return None,
+        })
+    }
+}
+
+/// How the values of a column are encoded in one stripe
+#[derive(Debug, Clone, Copy, Default)]
+struct Encoding {
+    dictionary: bool,
+    v2: bool,
+    dictionary_size: usize,
+}
+
+/// Stream locations and column encodings of one stripe
+struct StripeFooter {
+    /// (kind, column, byte range within the stripe)
+    streams: Vec<(u64, usize, Range<usize>)>,
+    encodings: Vec<Encoding>,
+}
+
+impl OrcFile {
+    /// Opens the ORC file at `path` and reads its schema, stripe layout and
+    /// statistics.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, VeloxxError> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = File::open(&path)?;
+        let length = file.metadata()?.len();
+        let tail_length = length.min(256);
+        let tail = read_at(&mut file, length - tail_length, tail_length as usize)?;
+        let postscript_length = *tail.last().ok_or_else(|| orc_error("file is empty"))? as usize;
+        let postscript = tail
+            .len()
+            .checked_sub(postscript_length + 1)
+            .map(|start| &tail[start..tail.len() - 1])
+            .ok_or_else(|| orc_error("file is too short"))?;
+        let postscript = proto::PostScript::decode(postscript)
+            .ok()
+            .filter(|postscript| postscript.magic == b"ORC")
+            .ok_or_else(|| orc_error(&format!("'{}' is not an ORC file", path.display())))?;
+        let compression = match postscript.compression {
+            0 => Compression::None,
+            1 => Compression::Zlib,
+            2 => Compression::Snappy,
+            4 => Compression::Lz4,
+            5 => Compression::Zstd,
+            3 => {
+                return Err(VeloxxError::Unsupported(
+                    "LZO-compressed ORC files are not supported".to_string(),
+                ))
+            }
+            other => return Err(orc_error(&format!("unknown compression {}", other))),
+        };
+
+        // Chunk headers store lengths in 23 bits, which bounds the block size
+        let block_size = postscript.compression_block_size.unwrap_or(256 * 1024);
+        if block_size >= 1 << 23 {
+            return Err(orc_error(&format!(
+                "compression block size {} is too large",
+                block_size
+            )));
+        }
+
+        let postscript_start = length - 1 - postscript_length as u64;
+        let tail_start = postscript
+            .footer_length
+            .checked_add(postscript.metadata_length)
+            .and_then(|tail_length| postscript_start.checked_sub(tail_length))
+            .ok_or_else(|| orc_error("footer is larger than the file"))?;
+        let bytes = read_at(
+            &mut file,
+            tail_start,
+            (postscript_start - tail_start) as usize,
+        )?;
+        let (metadata, footer) = bytes.split_at(postscript.metadata_length as usize);
+
+        let mut reader = Self {
+            path,
+            compression,
+            block_size: block_size as usize,
+            rows: 0,
+            stripes: Vec::new(),
+            columns: Vec::new(),
+            schema: Vec::new(),
+            nested: Vec::new(),
+        };
+        let footer = proto::Footer::decode(reader.decompress(footer)?.as_slice())
+            .map_err(|e| tail_error("footer", e))?;
+        reader.rows = footer.number_of_rows;
+        for stripe in &footer.stripes {
+            let end = [
+                stripe.index_length,
+                stripe.data_length,
+                stripe.footer_length,
+            ]
+            .into_iter()
+            .try_fold(stripe.offset, u64::checked_add);
+            if !matches!(end, Some(end) if end <= tail_start) {
+                return Err(orc_error("stripe lies outside the file"));
+            }
+            reader.stripes.push(Stripe {
+                offset: stripe.offset,
+                index_length: stripe.index_length,
+                data_length: stripe.data_length,
+                footer_length: stripe.footer_length,
+                rows: stripe.number_of_rows,
+                statistics: Vec::new(),
+            });
+        }
+        reader.map_schema(&footer.types)?;
+
+        if !metadata.is_empty() {
+            let kinds: Vec<Option<Kind>> = footer
+                .types
+                .iter()
+                .map(|t| Kind::from_code(t.kind))
+                .collect();
+            let metadata = proto::Metadata::decode(reader.decompress(metadata)?.as_slice())
+                .map_err(|e| tail_error("metadata", e))?;
+            for (stripe, statistics) in reader.stripes.iter_mut().zip(metadata.stripe_stats) {
+                stripe.statistics = statistics
+                    .col_stats
+                    .into_iter()
+                    .enumerate()
+                    .map(|(id, stats)| column_statistics(stats, kinds.get(id).copied().flatten()))
+                    .collect();
+            }
+        }
+        Ok(reader)
+    }
+
+    /// Readable columns and their Veloxx types, in file order
+    pub fn schema(&self) -> &[(String, DataType)] {
+        &self.schema
+    }
+
+    /// Total number of rows in the file
+    pub fn num_rows(&self) -> u64 {
+        self.rows
+    }
+
+    pub fn stripe_count(&self) -> usize {
+        self.stripes.len()
+    }
+
+    /// Reads every column of the schema.
+    pub fn to_dataframe(&self) -> Result<DataFrame, VeloxxError> {
+        let names: Vec<&str> = self.schema.iter().map(|(name, _)| name.as_str()).collect();
+        self.read(&names, None)
+    }
+
+    /// Reads the rows matching `condition`, skipping stripes whose statistics show
+    /// that none of their rows can match it.
+    pub fn to_dataframe_filtered(&self, condition: &Condition) -> Result<DataFrame, VeloxxError> {
+        let names: Vec<&str> = self.schema.iter().map(|(name, _)| name.as_str()).collect();
+        self.read(&names, Some(condition))
+    }
+
+    /// Reads `columns` only, from the rows matching `condition` if one is given.
+    ///
+    /// Columns used by the condition are decoded for filtering even when they are
+    /// not in `columns`, then dropped.
+    pub fn read(
+        &self,
+        columns: &[&str],
+        condition: Option<&Condition>,
+    ) -> Result<DataFrame, VeloxxError> {
+        let mut names = columns.to_vec();
+        if let Some(condition) = condition {
+            condition_columns(condition, &mut names);
+        }
+        let selected = names
+            .iter()
+            .map(|name| self.column(name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut file = File::open(&self.path)?;
+        let mut frames = Vec::new();
+        for stripe in &self.stripes {
+            if let Some(condition) = condition {
+                if self.prune(condition, stripe) == Some(false) {
+                    continue;
+                }
+            }
+            frames.push(self.read_stripe(&mut file, stripe, &selected)?);
+        }
+        let df = if frames.is_empty() {
+            DataFrame::new(
+                selected
+                    .iter()
+                    .map(|c| (c.name.clone(), empty_series(&c.name, c.data_type.clone())))
+                    .collect(),
+            )?
+        } else {
+            concat_frames(frames)?
+        };
+        let df = match condition {
+            Some(condition) if df.row_count() > 0 => df.filter(condition)?,
+            _ => df,
+        };
+        if names.len() > columns.len() {
+            return df.select_columns(columns.iter().map(|c| c.to_string()).collect());
+        }
+        Ok(df)
+    }
+
+    /// Maps the top-level fields of the root struct to columns
+    fn map_schema(&mut self, types: &[proto::Type]) -> Result<(), VeloxxError> {
+        let root = types
+            .first()
+            .filter(|t| Kind::from_code(t.kind) == Some(Kind::Struct))
+            .ok_or_else(|| orc_error("root type is not a struct"))?;
+        for (name, &id) in root.field_names.iter().zip(&root.subtypes) {
+            let id = id as usize;
+            let kind = types.get(id).and_then(|t| Kind::from_code(t.kind));
+            match kind.and_then(|kind| Some((kind, kind.data_type()?))) {
+                Some((kind, data_type)) => {
+                    self.schema.push((name.clone(), data_type.clone()));
+                    self.columns.push(OrcColumn {
+                        name: name.clone(),
+                        id,
+                        kind,
+                        data_type,
+                    });
+                }
+                None => self.nested.push(name.clone()),
+            }
+        }
+        Ok(())
+    }
+
+    fn column(&self, name: &str) -> Result<&OrcColumn, VeloxxError> {
+        if self.nested.iter().any(|n| n == name) {
+            return Err(VeloxxError::Unsupported(format!(
+                "ORC column '{}' has a nested type, which cannot be read",
+                name
+            )));
+        }
+        self.columns
+            .iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+    }
+
+    /// Evaluates `condition` against the statistics of `stripe`; see
+    /// [`prune`](crate::advanced_io::statistics::prune)
+    fn prune(&self, condition: &Condition, stripe: &Stripe) -> Option<bool> {
+        let statistics = self
+            .columns
+            .iter()
+            .filter_map(|c| Some((c.name.as_str(), stripe.statistics.get(c.id)?)))
+            .collect();
+        prune(condition, &statistics)
+    }
+
+    /// Decodes `columns` from one stripe
+    fn read_stripe(
+        &self,
+        file: &mut File,
+        stripe: &Stripe,
+        columns: &[&OrcColumn],
+    ) -> Result<DataFrame, VeloxxError> {
+        let footer_start = (stripe.index_length + stripe.data_length) as usize;
+        let bytes = read_at(
+            file,
+            stripe.offset,
+            footer_start + stripe.footer_length as usize,
+        )?;
+        let footer = stripe_footer(&self.decompress(&bytes[footer_start..])?)?;
+        let rows = stripe.rows as usize;
+
+        let mut series = HashMap::with_capacity(columns.len());
+        for column in columns {
+            let stream = |kind: u64| -> Result<Option<Vec<u8>>, VeloxxError> {
+                let Some((_, _, range)) = footer
+                    .streams
+                    .iter()
+                    .find(|(k, c, _)| *k == kind && *c == column.id)
+                else {
+                    return Ok(None);
+                };
+                let data = bytes
+                    .get(range.clone())
+                    .ok_or_else(|| orc_error("stream lies outside its stripe"))?;
+                self.decompress(data).map(Some)
+            };
+            let required = |kind: u64| {
+                stream(kind)?.ok_or_else(|| {
+                    orc_error(&format!(
+                        "column '{}' is missing stream {}",
+                        column.name, kind
+                    ))
+                })
+            };
+            let encoding = footer.encodings.get(column.id).copied().unwrap_or_default();
+            let present = stream(PRESENT)?
+                .map(|data| booleans(&data, rows))
+                .transpose()?;
+            let count = present
+                .as_ref()
+                .map_or(rows, |p| p.iter().filter(|&&set| set).count());
+            let present = present.as_deref();
+            let name = column.name.as_str();
+            let v2 = encoding.v2;
+
+            let values = match column.kind {
+                Kind::Boolean => {
+                    Series::new_bool(name, spread(booleans(&required(DATA)?, count)?, present))
+                }
+                Kind::Byte => {
+                    let bytes = byte_rle(&required(DATA)?, count)?;
+                    let values = bytes.into_iter().map(|b| b as i8 as i32).collect();
+                    Series::new_i32(name, spread(values, present))
+                }
+                Kind::Short | Kind::Int => {
+                    let values = integers(&required(DATA)?, count, true, v2)?;
+                    let values = values.into_iter().map(|v| v as i32).collect();
+                    Series::new_i32(name, spread(values, present))
+                }
+                Kind::Long => {
+                    let values = integers(&required(DATA)?, count, true, v2)?
+                        .into_iter()
+                        .map(|v| {
+                            if v.unsigned_abs() > MAX_EXACT_BIGINT {
+                                return Err(VeloxxError::Unsupported(format!(
+                                    "ORC bigint column '{}' holds {}, which F64 cannot \
+                                     represent exactly",
+                                    name, v
+                                )));
+                            }
+                            Ok(v as f64)
+                        })
+                        .collect::<Result<_, _>>()?;
+                    Series::new_f64(name, spread(values, present))
+                }
+                Kind::Float | Kind::Double => {
+                    let values = floats(&required(DATA)?, count, column.kind == Kind::Float)?;
+                    Series::new_f64(name, spread(values, present))
+                }
+                Kind::Decimal => {
+                    let values = decimals(&required(DATA)?, &required(SECONDARY)?, count, v2)?;
+                    Series::new_f64(name, spread(values, present))
+                }
+                Kind::String | Kind::Varchar | Kind::Char => {
+                    let values = byte_values(&stream, encoding, count)?
+                        .into_iter()
+                        .map(|v| String::from_utf8_lossy(&v).into_owned())
+                        .collect();
+                    Series::new_string(name, spread(values, present))
+                }
+                Kind::Binary => Series::new_binary(
+                    name,
+                    spread(byte_values(&stream, encoding, count)?, present),
+                ),
+                Kind::Date => {
+                    let days = integers(&required(DATA)?, count, true, v2)?;
+                    let values = days.into_iter().map(|d| d * 86_400).collect();
+                    Series::new_datetime(name, spread(values, present))
+                }
+                Kind::Timestamp | Kind::TimestampInstant => {
+                    let values = timestamps(&required(DATA)?, &required(SECONDARY)?, count, v2)?;
+                    Series::new_datetime(name, spread(values, present))
+                }
+                Kind::List | Kind::Map | Kind::Struct | Kind::Union => {
+                    return Err(VeloxxError::Unsupported(format!(
+                        "ORC column '{}' has a nested type, which cannot be read",
+                        name
+                    )))
+                }
+            };
+            series.insert(column.name.clone(), values);
+        }
+        DataFrame::new(series)
+    }
+
+    /// Joins the compression chunks of a stream or footer
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, VeloxxError> {
+        if self.compression == Compression::None {
+            return Ok(bytes.to_vec());
+        }
+        let mut output = Vec::with_capacity(bytes.len() * 2);
+        let mut position = 0;
+        while position < bytes.len() {
+            let header = bytes_at(bytes, position, 3)
+                .ok_or_else(|| orc_error("compression chunk header is truncated"))?;
+            let header = u32::from_le_bytes([header[0], header[1], header[2], 0]);
+            position += 3;
+            let length = (header >> 1) as usize;
+            let chunk = bytes_at(bytes, position, length)
+                .ok_or_else(|| orc_error("compression chunk is truncated"))?;
+            position += length;
+            // The low bit marks a chunk that was stored uncompressed
+            if header & 1 == 1 {
+                output.extend_from_slice(chunk);
+                continue;
+            }
+            let failed = |e: &dyn std::fmt::Display| {
+                orc_error(&format!(
+                    "failed to decompress {:?} chunk: {}",
+                    self.compression, e
+                ))
+            };
+            match self.compression {
+                Compression::None => output.extend_from_slice(chunk),
+                Compression::Zlib => {
+                    flate2::read::DeflateDecoder::new(chunk)
+                        .read_to_end(&mut output)
+                        .map_err(|e| failed(&e))?;
+                }
+                Compression::Snappy => output.extend(
+                    snap::raw::Decoder::new()
+                        .decompress_vec(chunk)
+                        .map_err(|e| failed(&e))?,
+                ),
+                Compression::Lz4 => output.extend(
+                    lz4_flex::block::decompress(chunk, self.block_size).map_err(|e| failed(&e))?,
+                ),
+                Compression::Zstd => {
+                    output.extend(zstd::stream::decode_all(chunk).map_err(|e| failed(&e))?)
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+fn orc_error(problem: &str) -> VeloxxError {
+    VeloxxError::Parsing(format!("Invalid ORC file: {}", problem))
+}
+
+fn truncated() -> VeloxxError {
+    orc_error("stream is truncated")
+}
+
+fn tail_error(part: &str, error: prost::DecodeError) -> VeloxxError {
+    orc_error(&format!("{} cannot be decoded: {}", part, error))
+}
+
+/// The `length` bytes of `data` from `position`, if they lie within it
+fn bytes_at(data: &[u8], position: usize, length: usize) -> Option<&[u8]> {
+    data.get(position..position.checked_add(length)?)
+}
+
+fn read_at(file: &mut File, offset: u64, length: usize) -> Result<Vec<u8>, VeloxxError> {
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buffer = vec![0; length];
+    file.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+fn empty_series(name: &str, data_type: DataType) -> Series {
+    match data_type {
+        DataType::I32 => Series::new_i32(name, Vec::new()),
+        DataType::F64 => Series::new_f64(name, Vec::new()),
+        DataType::Bool => Series::new_bool(name, Vec::new()),
+        DataType::String => Series::new_string(name, Vec::new()),
+        DataType::DateTime => Series::new_datetime(name, Vec::new()),
+        DataType::Binary => Series::new_binary(name, Vec::new()),
+    }
+}
+
+/// Places the non-null `values` of a stripe at the rows marked in `present`
+fn spread<T>(values: Vec<T>, present: Option<&[bool]>) -> Vec<Option<T>> {
+    match present {
+        None => values.into_iter().map(Some).collect(),
+        Some(present) => {
+            let mut values = values.into_iter();
+            present
+                .iter()
+                .map(|&set| if set { values.next() } else { None })
+                .collect()
+        }
+    }
+}
+
+/// Adds the columns named in `condition` to `columns`
+fn condition_columns<'a>(condition: &'a Condition, columns: &mut Vec<&'a str>) {
+    match condition {
+        Condition::Eq(column, _)
+        | Condition::Gt(column, _)
+        | Condition::Lt(column, _)
+        | Condition::InSubnet(column, _) => {
+            if !columns.contains(&column.as_str()) {
+                columns.push(column);
+            }
+        }
+        Condition::And(left, right) | Condition::Or(left, right) => {
+            condition_columns(left, columns);
+            condition_columns(right, columns);
+        }
+        Condition::Not(inner) => condition_columns(inner, columns),
+    }
+}
+
+/// Messages of the ORC file tail, as declared in the format's `orc_proto.proto`.
+/// Only the fields the reader uses are declared; prost skips the others.
+mod proto {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct PostScript {
+        #[prost(uint64, tag = "1")]
+        pub(super) footer_length: u64,
+        #[prost(uint64, tag = "2")]
+        pub(super) compression: u64,
+        #[prost(uint64, optional, tag = "3")]
+        pub(super) compression_block_size: Option<u64>,
+        #[prost(uint64, tag = "5")]
+        pub(super) metadata_length: u64,
+        #[prost(bytes = "vec", tag = "8000")]
+        pub(super) magic: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct Footer {
+        #[prost(message, repeated, tag = "3")]
+        pub(super) stripes: Vec<StripeInformation>,
+        #[prost(message, repeated, tag = "4")]
+        pub(super) types: Vec<Type>,
+        #[prost(uint64, tag = "6")]
+        pub(super) number_of_rows: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct StripeInformation {
+        #[prost(uint64, tag = "1")]
+        pub(super) offset: u64,
+        #[prost(uint64, tag = "2")]
+        pub(super) index_length: u64,
+        #[prost(uint64, tag = "3")]
+        pub(super) data_length: u64,
+        #[prost(uint64, tag = "4")]
+        pub(super) footer_length: u64,
+        #[prost(uint64, tag = "5")]
+        pub(super) number_of_rows: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct Type {
+        #[prost(uint64, tag = "1")]
+        pub(super) kind: u64,
+        #[prost(uint32, repeated, tag = "2")]
+        pub(super) subtypes: Vec<u32>,
+        #[prost(string, repeated, tag = "3")]
+        pub(super) field_names: Vec<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct Metadata {
+        #[prost(message, repeated, tag = "1")]
+        pub(super) stripe_stats: Vec<StripeStatistics>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct StripeStatistics {
+        #[prost(message, repeated, tag = "1")]
+        pub(super) col_stats: Vec<ColumnStatistics>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct ColumnStatistics {
+        #[prost(uint64, optional, tag = "1")]
+        pub(super) number_of_values: Option<u64>,
+        #[prost(message, optional, tag = "2")]
+        pub(super) int_statistics: Option<IntegerStatistics>,
+        #[prost(message, optional, tag = "3")]
+        pub(super) double_statistics: Option<DoubleStatistics>,
+        #[prost(message, optional, tag = "4")]
+        pub(super) string_statistics: Option<StringStatistics>,
+        #[prost(message, optional, tag = "6")]
+        pub(super) decimal_statistics: Option<StringStatistics>,
+        #[prost(message, optional, tag = "7")]
+        pub(super) date_statistics: Option<DateStatistics>,
+        #[prost(message, optional, tag = "9")]
+        pub(super) timestamp_statistics: Option<TimestampStatistics>,
+        #[prost(bool, optional, tag = "10")]
+        pub(super) has_null: Option<bool>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct IntegerStatistics {
+        #[prost(sint64, optional, tag = "1")]
+        pub(super) minimum: Option<i64>,
+        #[prost(sint64, optional, tag = "2")]
+        pub(super) maximum: Option<i64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct DoubleStatistics {
+        #[prost(double, optional, tag = "1")]
+        pub(super) minimum: Option<f64>,
+        #[prost(double, optional, tag = "2")]
+        pub(super) maximum: Option<f64>,
+    }
+
+    /// String bounds, also used for the decimal statistics, whose bounds are
+    /// decimal strings. Kept as bytes so that a bound that is not UTF-8 is
+    /// dropped rather than failing the whole tail.
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct StringStatistics {
+        #[prost(bytes = "vec", optional, tag = "1")]
+        pub(super) minimum: Option<Vec<u8>>,
+        #[prost(bytes = "vec", optional, tag = "2")]
+        pub(super) maximum: Option<Vec<u8>>,
+    }
+
+    /// Bounds in days since the Unix epoch
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct DateStatistics {
+        #[prost(sint32, optional, tag = "1")]
+        pub(super) minimum: Option<i32>,
+        #[prost(sint32, optional, tag = "2")]
+        pub(super) maximum: Option<i32>,
+    }
+
+    /// Bounds in milliseconds since the Unix epoch, in local time and in UTC
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct TimestampStatistics {
+        #[prost(sint64, optional, tag = "1")]
+        pub(super) minimum: Option<i64>,
+        #[prost(sint64, optional, tag = "2")]
+        pub(super) maximum: Option<i64>,
+        #[prost(sint64, optional, tag = "3")]
+        pub(super) minimum_utc: Option<i64>,
+        #[prost(sint64, optional, tag = "4")]
+        pub(super) maximum_utc: Option<i64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct StripeFooter {
+        #[prost(message, repeated, tag = "1")]
+        pub(super) streams: Vec<Stream>,
+        #[prost(message, repeated, tag = "2")]
+        pub(super) columns: Vec<ColumnEncoding>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct Stream {
+        #[prost(uint64, tag = "1")]
+        pub(super) kind: u64,
+        #[prost(uint32, tag = "2")]
+        pub(super) column: u32,
+        #[prost(uint64, tag = "3")]
+        pub(super) length: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct ColumnEncoding {
+        #[prost(uint64, tag = "1")]
+        pub(super) kind: u64,
+        #[prost(uint32, tag = "2")]
+        pub(super) dictionary_size: u32,
+    }
+}
+
+/// Locates each stream of a stripe and reads the column encodings
+fn stripe_footer(message: &[u8]) -> Result<StripeFooter, VeloxxError> {
+    let footer =
+        proto::StripeFooter::decode(message).map_err(|e| tail_error("stripe footer", e))?;
+    // Streams are stored back to back from the start of the stripe, in footer order
+    let mut offset = 0usize;
+    let mut streams = Vec::with_capacity(footer.streams.len());
+    for stream in &footer.streams {
+        let end = usize::try_from(stream.length)
+            .ok()
+            .and_then(|length| offset.checked_add(length))
+            .ok_or_else(|| orc_error("stream lies outside its stripe"))?;
+        streams.push((stream.kind, stream.column as usize, offset..end));
+        offset = end;
+    }
+    let encodings = footer
+        .columns
+        .iter()
+        .map(|encoding| Encoding {
+            dictionary: encoding.kind == 1 || encoding.kind == 3,
+            v2: encoding.kind >= 2,
+            dictionary_size: encoding.dictionary_size as usize,
+        })
+        .collect();
+    Ok(StripeFooter { streams, encodings })
+}
+
+/// Converts a column's statistics, typed to match how the column is read
+fn column_statistics(statistics: proto::ColumnStatistics, kind: Option<Kind>) -> ColumnStatistics {
+    fn bounds<T>(
+        min: Option<T>,
+        max: Option<T>,
+        value: impl Fn(T) -> Option<Value>,
+    ) -> (Option<Value>, Option<Value>) {
+        (min.and_then(&value), max.and_then(&value))
+    }
+    let text = |bound: Vec<u8>| String::from_utf8(bound).ok();
+    let (min, max) = match kind {
+        Some(Kind::Byte | Kind::Short | Kind::Int) => statistics
+            .int_statistics
+            .map(|s| bounds(s.minimum, s.maximum, |v| Some(Value::I32(v as i32)))),
+        Some(Kind::Long) => statistics
+            .int_statistics
+            .map(|s| bounds(s.minimum, s.maximum, |v| Some(Value::F64(v as f64)))),
+        Some(Kind::Float | Kind::Double) => statistics
+            .double_statistics
+            .map(|s| bounds(s.minimum, s.maximum, |v| Some(Value::F64(v)))),
+        Some(Kind::Decimal) => statistics.decimal_statistics.map(|s| {
+            bounds(s.minimum, s.maximum, |v| {
+                text(v)?.parse().ok().map(Value::F64)
+            })
+        }),
+        Some(Kind::String | Kind::Varchar | Kind::Char) => statistics
+            .string_statistics
+            .map(|s| bounds(s.minimum, s.maximum, |v| text(v).map(Value::String))),
+        Some(Kind::Date) => statistics.date_statistics.map(|s| {
+            bounds(s.minimum, s.maximum, |v| {
+                Some(Value::DateTime(i64::from(v) * 86_400))
+            })
+        }),
+        // Prefer the UTC bounds, which writers since ORC 1.5 add to the local ones
+        Some(Kind::Timestamp | Kind::TimestampInstant) => {
+            statistics.timestamp_statistics.map(|s| {
+                bounds(
+                    s.minimum_utc.or(s.minimum),
+                    s.maximum_utc.or(s.maximum),
+                    |v| Some(Value::DateTime(v.div_euclid(1000))),
+                )
+            })
+        }
+        _ => None,
+    }
+    .unwrap_or_default();
+    ColumnStatistics {
+        values: statistics.number_of_values,
+        has_null: statistics.has_null,
+        min,
+        max,
+    }
+}
+
+fn varint(bytes: &[u8], position: &mut usize) -> Result<u64, VeloxxError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*position).ok_or_else(truncated)?;
+        *position += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(orc_error("varint is too long"))
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+fn next_byte(data: &[u8], position: &mut usize) -> Result<u8, VeloxxError> {
+    let byte = *data.get(*position).ok_or_else(truncated)?;
+    *position += 1;
+    Ok(byte)
+}
+
+/// Decodes `count` bytes of byte run-length encoding
+fn byte_rle(data: &[u8], count: usize) -> Result<Vec<u8>, VeloxxError> {
+    let mut values = Vec::with_capacity(count);
+    let mut position = 0;
+    while values.len() < count {
+        let header = next_byte(data, &mut position)? as i8;
+        if header >= 0 {
+            let value = next_byte(data, &mut position)?;
+            values.extend(std::iter::repeat_n(value, header as usize + 3));
+        } else {
+            let length = header.unsigned_abs() as usize;
+            let literals = bytes_at(data, position, length).ok_or_else(truncated)?;
+            values.extend_from_slice(literals);
+            position += length;
+        }
+    }
+    values.truncate(count);
+    Ok(values)
+}
+
+/// Decodes `count` booleans, stored as most-significant-bit-first byte RLE
+fn booleans(data: &[u8], count: usize) -> Result<Vec<bool>, VeloxxError> {
+    let bytes = byte_rle(data, count.div_ceil(8))?;
+    Ok((0..count)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+fn floats(data: &[u8], count: usize, single: bool) -> Result<Vec<f64>, VeloxxError> {
+    let width = if single { 4 } else { 8 };
+    let data = count
+        .checked_mul(width)
+        .and_then(|length| data.get(..length))
+        .ok_or_else(truncated)?;
+    Ok(data
+        .chunks_exact(width)
+        .map(|b| match single {
+            true => f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            false => f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]),
+        })
+        .collect())
+}
+
+/// Decodes decimals from unbounded varints and their per-value scales
+fn decimals(data: &[u8], scales: &[u8], count: usize, v2: bool) -> Result<Vec<f64>, VeloxxError> {
+    let scales = integers(scales, count, true, v2)?;
+    let mut position = 0;
+    let mut values = Vec::with_capacity(count);
+    for scale in scales {
+        let mut unscaled = 0u128;
+        let mut shift = 0;
+        loop {
+            let byte = next_byte(data, &mut position)?;
+            if shift < 128 {
+                unscaled |= u128::from(byte & 0x7f) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        let unscaled = (unscaled >> 1) as i128 ^ -((unscaled & 1) as i128);
+        values.push(unscaled as f64 / 10f64.powi(scale as i32));
+    }
+    Ok(values)
+}
+
+/// Decodes timestamps from seconds since 2015 and encoded nanoseconds
+fn timestamps(
+    seconds: &[u8],
+    nanos: &[u8],
+    count: usize,
+    v2: bool,
+) -> Result<Vec<i64>, VeloxxError> {
+    let seconds = integers(seconds, count, true, v2)?;
+    let nanos = integers(nanos, count, false, v2)?;
+    Ok(seconds
+        .into_iter()
+        .zip(nanos)
+        .map(|(seconds, encoded)| {
+            // The low three bits count trailing decimal zeros dropped from the value
+            let zeros = (encoded & 7) as u32;
+            let nanos = if zeros == 0 {
+                encoded >> 3
+            } else {
+                (encoded >> 3) * 10i64.pow(zeros + 1)
+            };
+            let seconds = seconds + ORC_EPOCH;
+            if seconds < 0 && nanos > 999_999 {
+                seconds - 1
+            } else {
+                seconds
+            }
+        })
+        .collect())
+}
+
+/// Decodes the byte strings of a string or binary column
+fn byte_values(
+    stream: &dyn Fn(u64) -> Result<Option<Vec<u8>>, VeloxxError>,
+    encoding: Encoding,
+    count: usize,
+) -> Result<Vec<Vec<u8>>, VeloxxError> {
+    let required = |kind: u64| stream(kind)?.ok_or_else(|| orc_error("string stream is missing"));
+    let slices = |data: &[u8], lengths: Vec<i64>| -> Result<Vec<Vec<u8>>, VeloxxError> {
+        let mut position = 0;
+        lengths
+            .into_iter()
+            .map(|length| {
+                let value = usize::try_from(length)
+                    .ok()
+                    .and_then(|length| bytes_at(data, position, length))
+                    .ok_or_else(truncated)?;
+                position += value.len();
+                Ok(value.to_vec())
+            })
+            .collect()
+    };
+    if !encoding.dictionary {
+        let lengths = integers(&required(LENGTH)?, count, false, encoding.v2)?;
+        return slices(&required(DATA)?, lengths);
+    }
+    let size = encoding.dictionary_size;
+    let dictionary = match size {
+        0 => Vec::new(),
+        _ => {
+            let lengths = integers(&required(LENGTH)?, size, false, encoding.v2)?;
+            let data = stream(DICTIONARY_DATA)?.unwrap_or_default();
+            slices(&data, lengths)?
+        }
+    };
+    integers(&required(DATA)?, count, false, encoding.v2)?
+        .into_iter()
+        .map(|index| {
+            dictionary
+                .get(index as usize)
+                .cloned()
+                .ok_or_else(|| orc_error("dictionary index is out of range"))
+        })
+        .collect()
+}
+
+/// Decodes `count` integers of run-length encoding version 1 or 2
+fn integers(data: &[u8], count: usize, signed: bool, v2: bool) -> Result<Vec<i64>, VeloxxError> {
+    let mut values = Vec::with_capacity(count);
+    let mut position = 0;
+    let decode = |raw: u64| if signed { unzigzag(raw) } else { raw as i64 };
+    while values.len() < count {
+        if v2 {
+            rle_v2_run(data, &mut position, &decode, &mut values)?;
+        } else {
+            let header = next_byte(data, &mut position)? as i8;
+            if header >= 0 {
+                let delta = next_byte(data, &mut position)? as i8 as i64;
+                let base = decode(varint(data, &mut position)?);
+                values.extend((0..header as i64 + 3).map(|i| base.wrapping_add(i * delta)));
+            } else {
+                for _ in 0..header.unsigned_abs() {
+                    values.push(decode(varint(data, &mut position)?));
+                }
+            }
+        }
+    }
+    values.truncate(count);
+    Ok(values)
+}
+
+/// Decodes one run of integer RLE version 2
+fn rle_v2_run(
+    data: &[u8],
+    position: &mut usize,
+    decode: &dyn Fn(u64) -> i64,
+    values: &mut Vec<i64>,
+) -> Result<(), VeloxxError> {
+    let header = next_byte(data, position)?;
+    // All encodings but short repeat store a 9-bit run length after the header
+    let run_length = |position: &mut usize| -> Result<usize, VeloxxError> {
+        Ok((usize::from(header & 1) << 8 | usize::from(next_byte(data, position)?)) + 1)
+    };
+    match header >> 6 {
+        // Short repeat
+        0 => {
+            let width = usize::from((header >> 3) & 7) + 1;
+            let repeat = usize::from(header & 7) + 3;
+            let value = decode(big_endian(data, position, width)?);
+            values.extend(std::iter::repeat_n(value, repeat));
+        }
+        // Direct
+        1 => {
+            let width = bit_width(header >> 1);
+            let length = run_length(position)?;
+            let mut bits = BitReader::new(data, *position);
+            for _ in 0..length {
+                values.push(decode(bits.read(width)?));
+            }
+            *position = bits.end();
+        }
+        // Patched base
+        2 => {
+            let width = bit_width(header >> 1);
+            let length = run_length(position)?;
+            let third = next_byte(data, position)?;
+            let fourth = next_byte(data, position)?;
+            let base_width = usize::from(third >> 5) + 1;
+            let patch_width = bit_width(third);
+            let gap_width = usize::from(fourth >> 5) + 1;
+            let patch_count = usize::from(fourth & 0x1f);
+
+            // The base is sign-magnitude, with the sign in its top bit
+            let base = big_endian(data, position, base_width)?;
+            let sign = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign != 0 {
+                -((base & !sign) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut bits = BitReader::new(data, *position);
+            let mut run = (0..length)
+                .map(|_| bits.read(width))
+                .collect::<Result<Vec<u64>, _>>()?;
+            *position = bits.end();
+
+            let mut bits = BitReader::new(data, *position);
+            let entry_width = closest_fixed_bits(gap_width + patch_width);
+            let mut index = 0;
+            for _ in 0..patch_count {
+                let entry = bits.read(entry_width)?;
+                index += (entry >> patch_width) as usize;
+                let patch = entry & (u64::MAX >> (64 - patch_width));
+                let value = run
+                    .get_mut(index)
+                    .ok_or_else(|| orc_error("patch lies outside its run"))?;
+                *value |= patch << width;
+            }
+            *position = bits.end();
+            values.extend(run.into_iter().map(|v| base.wrapping_add(v as i64)));
+        }
+        // Delta
+        _ => {
+            let code = (header >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { bit_width(code) };
+            let length = run_length(position)?;
+            let mut value = decode(varint(data, position)?);
+            let delta = unzigzag(varint(data, position)?);
+            values.push(value);
+            if width == 0 {
+                for _ in 1..length {
+                    value = value.wrapping_add(delta);
+                    values.push(value);
+                }
+            } else if length > 1 {
+                value = value.wrapping_add(delta);
+                values.push(value);
+                // Later deltas are magnitudes with the sign of the first one
+                let mut bits = BitReader::new(data, *position);
+                for _ in 2..length {
+                    let step = bits.read(width)? as i64;
+                    value = if delta < 0 {
+                        value.wrapping_sub(step)
+                    } else {
+                        value.wrapping_add(step)
+                    };
+                    values.push(value);
+                }
+                *position = bits.end();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Bit width for a 5-bit width code of integer RLE version 2
+fn bit_width(code: u8) -> usize {
+    match code & 0x1f {
+        code @ 0..=23 => usize::from(code) + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// Smallest width of the RLE version 2 width table that holds `bits`
+fn closest_fixed_bits(bits: usize) -> usize {
+    match bits {
+        0 => 1,
+        1..=24 => bits,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn big_endian(data: &[u8], position: &mut usize, width: usize) -> Result<u64, VeloxxError> {
+    let bytes = bytes_at(data, *position, width).ok_or_else(truncated)?;
+    *position += width;
+    Ok(bytes.iter().fold(0, |value, &b| value << 8 | u64::from(b)))
+}
+
+/// Reads big-endian bit-packed values
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    /// Bits already consumed from the byte at `position`
+    bit: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Self {
+            data,
+            position,
+            bit: 0,
+        }
+    }
+
+    fn read(&mut self, width: usize) -> Result<u64, VeloxxError> {
+        let mut value = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let byte = u64::from(*self.data.get(self.position).ok_or_else(truncated)?);
+            let available = 8 - self.bit;
+            let take = remaining.min(available);
+            let bits = (byte >> (available - take)) & ((1 << take) - 1);
+            value = value << take | bits;
+            remaining -= take;
+            self.bit += take;
+            if self.bit == 8 {
+                self.bit = 0;
+                self.position += 1;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Position of the first byte after the values read, which end byte-aligned
+    fn end(&self) -> usize {
+        self.position + usize::from(self.bit > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples from the ORC specification, decoded as unsigned integers
+    #[test]
+    fn test_rle_v2_encodings() {
+        let short_repeat = [0x0a, 0x27, 0x10];
+        assert_eq!(
+            integers(&short_repeat, 5, false, true).unwrap(),
+            vec![10000; 5]
+        );
+
+        let direct = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(
+            integers(&direct, 4, false, true).unwrap(),
+            vec![23713, 43806, 57005, 48879]
+        );
+
+        let patched_base = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let mut expected = vec![2030, 2000, 2020, 1000000];
+        expected.extend((2040..=2190).step_by(10));
+        assert_eq!(integers(&patched_base, 20, false, true).unwrap(), expected);
+
+        let delta = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        assert_eq!(
+            integers(&delta, 10, false, true).unwrap(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    #[test]
+    fn test_rle_v1_and_booleans() {
+        // A run of 100 values from 7 stepping by -1, then literals -1 and 2
+        let data = [0x61, 0xff, 0x0e, 0xfe, 0x01, 0x04];
+        let values = integers(&data, 102, true, false).unwrap();
+        assert_eq!(values[..3], [7, 6, 5]);
+        assert_eq!(values[99..], [-92, -1, 2]);
+
+        // Byte RLE: a run of three 0xff, then a literal 0x80
+        let data = [0x00, 0xff, 0xff, 0x80];
+        let bits = booleans(&data, 25).unwrap();
+        assert_eq!(bits.iter().filter(|&&b| b).count(), 25);
+        assert_eq!(byte_rle(&data, 4).unwrap(), vec![0xff, 0xff, 0xff, 0x80]);
+    }
+}
@@ -0,0 +1,246 @@
+//! Writing DataFrames to database tables with
+//! [`DataFrame::to_sql`](crate::dataframe::DataFrame::to_sql).
+//!
+//! Databases plug in through [`SqlConnection`]. With the `odbc` feature,
+//! `advanced_io::odbc::OdbcConnection` implements it, so any warehouse with an ODBC
+//! driver can be written to.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "odbc")]
+//! # fn main() -> Result<(), veloxx::VeloxxError> {
+//! use veloxx::advanced_io::odbc::OdbcConnection;
+//! use veloxx::advanced_io::sql::{SqlWriteMode, SqlWriteOptions};
+//! use veloxx::dataframe::DataFrame;
+//!
+//! let df = DataFrame::from_csv("daily_totals.csv")?;
+//! let connection = OdbcConnection::connect("DSN=warehouse")?;
+//! df.to_sql(&connection, "reporting.daily_totals", SqlWriteMode::Replace)?;
+//!
+//! // Larger batches, and a column type the defaults do not cover
+//! let options = SqlWriteOptions::new()
+//!     .batch_size(5_000)
+//!     .column_type("region", "VARCHAR(32)");
+//! df.to_sql_with_options(&connection, "reporting.daily_totals", SqlWriteMode::Append, &options)?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "odbc"))]
+//! # fn main() {}
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// A database connection that DataFrames can be written to
+pub trait SqlConnection {
+    /// Runs a statement that takes no parameters and returns no rows.
+    fn execute(&self, sql: &str) -> Result<(), VeloxxError>;
+
+    /// Runs the parameterized `sql` once for every row of `rows`, binding the row's
+    /// values to the `?` markers in order; `Value::Null` binds NULL.
+    fn execute_batch(&self, sql: &str, rows: &[Vec<Value>]) -> Result<(), VeloxxError>;
+
+    /// Whether `table`, which may be schema-qualified, exists.
+    fn table_exists(&self, table: &str) -> Result<bool, VeloxxError>;
+
+    /// Starts a transaction covering the following statements.
+    fn begin(&self) -> Result<(), VeloxxError>;
+
+    fn commit(&self) -> Result<(), VeloxxError>;
+
+    fn rollback(&self) -> Result<(), VeloxxError>;
+
+    /// Column type used by `CREATE TABLE` for `data_type`.
+    fn sql_type(&self, data_type: &DataType) -> String {
+        default_sql_type(data_type).to_string()
+    }
+
+    /// Quotes a table or column name; the default uses ANSI double quotes.
+    fn quote_identifier(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+/// ANSI column type for `data_type`
+///
+/// | Veloxx | SQL |
+/// |--------|-----|
+/// | `I32` | `INTEGER` |
+/// | `F64` | `DOUBLE PRECISION` |
+/// | `Bool` | `BOOLEAN` |
+/// | `String` | `VARCHAR(4000)` |
+/// | `DateTime` | `TIMESTAMP` |
+/// | `Binary` | `VARBINARY(8000)` |
+pub fn default_sql_type(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::I32 => "INTEGER",
+        DataType::F64 => "DOUBLE PRECISION",
+        DataType::Bool => "BOOLEAN",
+        DataType::String => "VARCHAR(4000)",
+        DataType::DateTime => "TIMESTAMP",
+        DataType::Binary => "VARBINARY(8000)",
+    }
+}
+
+/// What [`DataFrame::to_sql`] does when the table already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlWriteMode {
+    /// Return an error and leave the table untouched
+    #[default]
+    Fail,
+    /// Drop the table and create it again from the DataFrame's columns
+    Replace,
+    /// Insert the rows into the existing table
+    Append,
+}
+
+/// Batching, transaction and type settings for [`DataFrame::to_sql_with_options`]
+#[derive(Debug, Clone)]
+pub struct SqlWriteOptions {
+    batch_size: usize,
+    transaction: bool,
+    column_types: HashMap<String, String>,
+}
+
+impl Default for SqlWriteOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 1_000,
+            transaction: true,
+            column_types: HashMap::new(),
+        }
+    }
+}
+
+impl SqlWriteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rows bound per `INSERT` execution (default 1,000)
+    pub fn batch_size(mut self, rows: usize) -> Self {
+        self.batch_size = rows.max(1);
+        self
+    }
+
+    /// Whether the whole write runs in one transaction that is rolled back on error
+    /// (the default). Databases that commit DDL implicitly keep a replaced table
+    /// dropped even after a rollback.
+    pub fn transaction(mut self, transaction: bool) -> Self {
+        self.transaction = transaction;
+        self
+    }
+
+    /// Creates `column` with `sql_type` instead of the connection's default type.
+    pub fn column_type(mut self, column: &str, sql_type: &str) -> Self {
+        self.column_types
+            .insert(column.to_string(), sql_type.to_string());
+        self
+    }
+}
+
+/// Writes `df` to `table`, returning the number of rows inserted
+pub(crate) fn write_table(
+    df: &DataFrame,
+    connection: &dyn SqlConnection,
+    table: &str,
+    mode: SqlWriteMode,
+    options: &SqlWriteOptions,
+) -> Result<usize, VeloxxError> {
+    if df.column_count() == 0 {
+        return Err(VeloxxError::InvalidOperation(
+            "Cannot write a DataFrame without columns to a table".to_string(),
+        ));
+    }
+    let exists = connection.table_exists(table)?;
+    if exists && mode == SqlWriteMode::Fail {
+        return Err(VeloxxError::InvalidOperation(format!(
+            "Table '{}' already exists",
+            table
+        )));
+    }
+    if !options.transaction {
+        return write_rows(df, connection, table, mode, exists, options);
+    }
+    connection.begin()?;
+    match write_rows(df, connection, table, mode, exists, options) {
+        Ok(rows) => {
+            connection.commit()?;
+            Ok(rows)
+        }
+        Err(e) => {
+            // The write error is more useful than a failed rollback
+            let _ = connection.rollback();
+            Err(e)
+        }
+    }
+}
+
+fn write_rows(
+    df: &DataFrame,
+    connection: &dyn SqlConnection,
+    table: &str,
+    mode: SqlWriteMode,
+    exists: bool,
+    options: &SqlWriteOptions,
+) -> Result<usize, VeloxxError> {
+    let quoted_table = table
+        .split('.')
+        .map(|part| connection.quote_identifier(part))
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut names = df.column_names();
+    names.sort();
+    let quoted: Vec<String> = names
+        .iter()
+        .map(|name| connection.quote_identifier(name))
+        .collect();
+
+    if exists && mode == SqlWriteMode::Replace {
+        connection.execute(&format!("DROP TABLE {}", quoted_table))?;
+    }
+    if !exists || mode == SqlWriteMode::Replace {
+        let columns: Vec<String> = names
+            .iter()
+            .zip(&quoted)
+            .map(|(name, quoted)| {
+                let sql_type = match options.column_types.get(*name) {
+                    Some(sql_type) => sql_type.clone(),
+                    None => connection.sql_type(&df.columns[*name].data_type()),
+                };
+                format!("{} {}", quoted, sql_type)
+            })
+            .collect();
+        connection.execute(&format!(
+            "CREATE TABLE {} ({})",
+            quoted_table,
+            columns.join(", ")
+        ))?;
+    }
+
+    let insert = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        quoted_table,
+        quoted.join(", "),
+        vec!["?"; names.len()].join(", ")
+    );
+    let series: Vec<_> = names.iter().map(|name| &df.columns[*name]).collect();
+    let mut start = 0;
+    while start < df.row_count() {
+        let end = (start + options.batch_size).min(df.row_count());
+        let rows: Vec<Vec<Value>> = (start..end)
+            .map(|row| {
+                series
+                    .iter()
+                    .map(|s| s.get_value(row).unwrap_or(Value::Null))
+                    .collect()
+            })
+            .collect();
+        connection.execute_batch(&insert, &rows)?;
+        start = end;
+    }
+    Ok(df.row_count())
+}
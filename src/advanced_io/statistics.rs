@@ -0,0 +1,90 @@
+//! Column statistics and the pruning check shared by the ORC and Iceberg readers,
+//! which skip every stripe or data file whose min/max bounds rule out a filter.
+
+use crate::conditions::Condition;
+use crate::types::Value;
+use std::collections::HashMap;
+
+/// Min/max statistics of one column in one ORC stripe or Iceberg data file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ColumnStatistics {
+    /// Number of non-null values
+    pub(crate) values: Option<u64>,
+    pub(crate) has_null: Option<bool>,
+    pub(crate) min: Option<Value>,
+    pub(crate) max: Option<Value>,
+}
+
+/// Evaluates `condition` against the statistics of one stripe or file: `Some(false)`
+/// means no row can match, `Some(true)` that every row does, and `None` that the
+/// statistics cannot tell.
+pub(crate) fn prune(
+    condition: &Condition,
+    statistics: &HashMap<&str, &ColumnStatistics>,
+) -> Option<bool> {
+    let compare = |bound: &Value, value: &Value| match (bound, value) {
+        (Value::I32(a), Value::I32(b)) => a.partial_cmp(b),
+        (Value::F64(a), Value::F64(b)) => a.partial_cmp(b),
+        (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+        (Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(b),
+        _ => None,
+    };
+    let bounds = |column: &str| {
+        let stats = statistics.get(column)?;
+        Some((stats, stats.min.as_ref(), stats.max.as_ref()))
+    };
+    match condition {
+        Condition::Eq(column, value) => {
+            let (stats, min, max) = bounds(column)?;
+            if stats.values == Some(0) {
+                return Some(false);
+            }
+            let (min, max) = (min?, max?);
+            if compare(min, value)?.is_gt() || compare(max, value)?.is_lt() {
+                Some(false)
+            } else if min == value && max == value && stats.has_null == Some(false) {
+                Some(true)
+            } else {
+                None
+            }
+        }
+        Condition::Gt(column, value) => {
+            let (stats, min, max) = bounds(column)?;
+            if stats.values == Some(0) {
+                return Some(false);
+            }
+            if compare(max?, value)?.is_le() {
+                Some(false)
+            } else if compare(min?, value)?.is_gt() && stats.has_null == Some(false) {
+                Some(true)
+            } else {
+                None
+            }
+        }
+        Condition::Lt(column, value) => {
+            let (stats, min, max) = bounds(column)?;
+            if stats.values == Some(0) {
+                return Some(false);
+            }
+            if compare(min?, value)?.is_ge() {
+                Some(false)
+            } else if compare(max?, value)?.is_lt() && stats.has_null == Some(false) {
+                Some(true)
+            } else {
+                None
+            }
+        }
+        Condition::And(left, right) => match (prune(left, statistics), prune(right, statistics)) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        },
+        Condition::Or(left, right) => match (prune(left, statistics), prune(right, statistics)) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        },
+        Condition::Not(inner) => prune(inner, statistics).map(|matched| !matched),
+        Condition::InSubnet(_, _) => None,
+    }
+}
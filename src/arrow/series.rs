@@ -28,7 +28,7 @@ impl ArrowSeries {
                 // Check alignment (debug only)
                 let ptr = result_slice.as_ptr() as usize;
                 debug_assert!(
-                    ptr % 64 == 0,
+                    ptr.is_multiple_of(64),
                     "Result buffer is not 64-byte aligned: {ptr:x}"
                 );
 
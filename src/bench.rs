@@ -0,0 +1,646 @@
+//! Benchmarks over reference datasets, with reports comparable across versions.
+//!
+//! A [`Dataset`] is generated deterministically from a seed (or, for the NYC
+//! taxi data, downloaded with the `http` feature) and [`run_suite`] times a fixed
+//! set of operations on it: filters, sorts, group-bys, joins, derived columns
+//! and CSV IO. The resulting [`BenchReport`] can be saved as CSV and compared
+//! with the report of another version via [`BenchReport::compare`], so that
+//! regressions in the optimized code paths show up as numbers rather than
+//! impressions.
+//!
+//! The generated datasets follow the schemas of the real ones: the
+//! [TLC yellow taxi trip records](https://www.nyc.gov/site/tlc/about/tlc-trip-record-data.page)
+//! and the TPC-H `lineitem` table, with values drawn from similar ranges
+//! (`Dataset::TpchLineitem.reference_rows()` is the row count at scale factor 1).
+//!
+//! The same command line tool runs the suite: `veloxx bench tpch -o new.csv
+//! --baseline old.csv`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use veloxx::bench::{run_suite, BenchOptions, Dataset};
+//!
+//! let lineitem = Dataset::TpchLineitem.generate(2_000, 42).unwrap();
+//! let options = BenchOptions { iterations: 1, ..BenchOptions::default() };
+//! let baseline = run_suite(Dataset::TpchLineitem, &lineitem, &options).unwrap();
+//! let current = run_suite(Dataset::TpchLineitem, &lineitem, &options).unwrap();
+//!
+//! let comparison = current.compare(&baseline, 1000.0).unwrap();
+//! assert_eq!(comparison.row_count(), current.results.len());
+//! ```
+
+use crate::conditions::Condition;
+use crate::dataframe::join::JoinType;
+use crate::dataframe::DataFrame;
+use crate::expressions::Expr;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+/// Monthly TLC yellow taxi files, with `{month}` as `YYYY-MM`
+pub const NYC_TAXI_URL: &str =
+    "https://d37ci6vzurychx.cloudfront.net/trip-data/yellow_tripdata_{month}.parquet";
+
+/// Rows of TPC-H `lineitem` at scale factor 1
+const TPCH_SF1_LINEITEM_ROWS: usize = 6_001_215;
+
+/// 1992-01-01, the first TPC-H order date
+const TPCH_START: i64 = 694_224_000;
+
+/// 1995-06-17, the TPC-H "current date" deciding line status and return flags
+const TPCH_CURRENT: i64 = 803_347_200;
+
+/// 2023-01-01, the start of the generated taxi month
+const TAXI_START: i64 = 1_672_531_200;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A reference dataset the benchmark suite knows how to run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dataset {
+    /// Yellow taxi trips in the TLC trip record schema
+    NycTaxi,
+    /// The TPC-H `lineitem` table
+    TpchLineitem,
+}
+
+impl Dataset {
+    /// The name used in reports: `nyc_taxi` or `tpch_lineitem`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dataset::NycTaxi => "nyc_taxi",
+            Dataset::TpchLineitem => "tpch_lineitem",
+        }
+    }
+
+    /// Looks a dataset up by its report name or the short forms `taxi` and `tpch`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "nyc_taxi" | "taxi" => Some(Dataset::NycTaxi),
+            "tpch_lineitem" | "tpch" => Some(Dataset::TpchLineitem),
+            _ => None,
+        }
+    }
+
+    /// The row count of the reference dataset: roughly a month of taxi trips,
+    /// or `lineitem` at TPC-H scale factor 1.
+    pub fn reference_rows(&self) -> usize {
+        match self {
+            Dataset::NycTaxi => 3_000_000,
+            Dataset::TpchLineitem => TPCH_SF1_LINEITEM_ROWS,
+        }
+    }
+
+    /// Generates `rows` rows of the dataset; the same seed gives the same data.
+    pub fn generate(&self, rows: usize, seed: u64) -> Result<DataFrame, VeloxxError> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        match self {
+            Dataset::NycTaxi => generate_taxi(rows, &mut rng),
+            Dataset::TpchLineitem => generate_lineitem(rows, &mut rng),
+        }
+    }
+
+    /// Downloads one month (`YYYY-MM`) of TLC yellow taxi trips through `cache`
+    /// and keeps the columns the suite uses, as the generated data has them.
+    ///
+    /// Rows with nulls in those columns are dropped. Reading the Parquet file
+    /// also needs the `advanced_io` and `arrow-io` features.
+    #[cfg(feature = "http")]
+    pub fn download_nyc_taxi(
+        cache: &crate::io::http::HttpCache,
+        month: &str,
+    ) -> Result<DataFrame, VeloxxError> {
+        let url = NYC_TAXI_URL.replace("{month}", month);
+        let trips = cache.read_parquet(&url, crate::io::http::CachePolicy::default())?;
+        let mut columns = HashMap::new();
+        for (name, data_type) in TAXI_NUMERIC_COLUMNS {
+            let series = trips.get_column(name).ok_or_else(|| {
+                VeloxxError::ColumnNotFound(format!("Taxi data has no '{}' column", name))
+            })?;
+            columns.insert(name.to_string(), series.cast(data_type)?);
+        }
+        DataFrame::new(columns)?.drop_nulls(None)
+    }
+}
+
+/// Taxi columns used by the suite and their types
+#[cfg(feature = "http")]
+const TAXI_NUMERIC_COLUMNS: [(&str, crate::types::DataType); 9] = {
+    use crate::types::DataType::{F64, I32};
+    [
+        ("VendorID", I32),
+        ("passenger_count", I32),
+        ("trip_distance", F64),
+        ("PULocationID", I32),
+        ("DOLocationID", I32),
+        ("payment_type", I32),
+        ("fare_amount", F64),
+        ("tip_amount", F64),
+        ("total_amount", F64),
+    ]
+};
+
+fn cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn frame(columns: Vec<Series>) -> Result<DataFrame, VeloxxError> {
+    DataFrame::new(
+        columns
+            .into_iter()
+            .map(|series| (series.name().to_string(), series))
+            .collect(),
+    )
+}
+
+fn generate_taxi(rows: usize, rng: &mut StdRng) -> Result<DataFrame, VeloxxError> {
+    let mut vendor = Vec::with_capacity(rows);
+    let mut pickup = Vec::with_capacity(rows);
+    let mut passengers = Vec::with_capacity(rows);
+    let mut distance = Vec::with_capacity(rows);
+    let mut pickup_zone = Vec::with_capacity(rows);
+    let mut dropoff_zone = Vec::with_capacity(rows);
+    let mut payment = Vec::with_capacity(rows);
+    let mut fare = Vec::with_capacity(rows);
+    let mut tip = Vec::with_capacity(rows);
+    let mut total = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let miles = cents(-rng.gen::<f64>().max(1e-9).ln() * 3.0);
+        let payment_type = [1, 1, 1, 1, 1, 1, 1, 2, 2, 3][rng.gen_range(0..10)];
+        let fare_amount = cents(3.0 + 2.5 * miles + rng.gen_range(0.0..4.0));
+        let tip_amount = if payment_type == 1 {
+            cents(fare_amount * rng.gen_range(0.0..0.3))
+        } else {
+            0.0
+        };
+        vendor.push(Some(rng.gen_range(1..=2)));
+        pickup.push(Some(TAXI_START + rng.gen_range(0..31 * SECONDS_PER_DAY)));
+        passengers.push(Some([1, 1, 1, 1, 1, 1, 2, 2, 3, 5][rng.gen_range(0..10)]));
+        distance.push(Some(miles));
+        pickup_zone.push(Some(rng.gen_range(1..=265)));
+        dropoff_zone.push(Some(rng.gen_range(1..=265)));
+        payment.push(Some(payment_type));
+        fare.push(Some(fare_amount));
+        tip.push(Some(tip_amount));
+        total.push(Some(cents(fare_amount + tip_amount + 3.5)));
+    }
+    frame(vec![
+        Series::new_i32("VendorID", vendor),
+        Series::new_datetime("tpep_pickup_datetime", pickup),
+        Series::new_i32("passenger_count", passengers),
+        Series::new_f64("trip_distance", distance),
+        Series::new_i32("PULocationID", pickup_zone),
+        Series::new_i32("DOLocationID", dropoff_zone),
+        Series::new_i32("payment_type", payment),
+        Series::new_f64("fare_amount", fare),
+        Series::new_f64("tip_amount", tip),
+        Series::new_f64("total_amount", total),
+    ])
+}
+
+/// Suppliers for `rows` lineitem rows, at least ten as in TPC-H
+fn supplier_count(rows: usize) -> i32 {
+    ((rows as f64 / TPCH_SF1_LINEITEM_ROWS as f64) * 10_000.0).max(10.0) as i32
+}
+
+fn generate_lineitem(rows: usize, rng: &mut StdRng) -> Result<DataFrame, VeloxxError> {
+    const SHIP_MODES: [&str; 7] = ["REG AIR", "AIR", "RAIL", "SHIP", "TRUCK", "MAIL", "FOB"];
+    let parts = ((rows as f64 / TPCH_SF1_LINEITEM_ROWS as f64) * 200_000.0).max(10.0) as i32;
+    let suppliers = supplier_count(rows);
+
+    let mut order_key = Vec::with_capacity(rows);
+    let mut part_key = Vec::with_capacity(rows);
+    let mut supp_key = Vec::with_capacity(rows);
+    let mut line_number = Vec::with_capacity(rows);
+    let mut quantity = Vec::with_capacity(rows);
+    let mut extended_price = Vec::with_capacity(rows);
+    let mut discount = Vec::with_capacity(rows);
+    let mut tax = Vec::with_capacity(rows);
+    let mut return_flag = Vec::with_capacity(rows);
+    let mut line_status = Vec::with_capacity(rows);
+    let mut ship_date = Vec::with_capacity(rows);
+    let mut ship_mode = Vec::with_capacity(rows);
+    let mut order = 0;
+    while order_key.len() < rows {
+        order += 1;
+        let order_date = TPCH_START + rng.gen_range(0..2_406) * SECONDS_PER_DAY;
+        let lines = rng.gen_range(1..=7).min(rows - order_key.len());
+        for line in 1..=lines as i32 {
+            let part = rng.gen_range(1..=parts);
+            let retail_price =
+                (90_000 + (part / 10) % 20_001 + 100 * (part % 1_000)) as f64 / 100.0;
+            let qty = rng.gen_range(1..=50) as f64;
+            let shipped = order_date + rng.gen_range(1..=121) * SECONDS_PER_DAY;
+            let received = shipped + rng.gen_range(1..=30) * SECONDS_PER_DAY;
+            order_key.push(Some(order));
+            part_key.push(Some(part));
+            supp_key.push(Some(rng.gen_range(1..=suppliers)));
+            line_number.push(Some(line));
+            quantity.push(Some(qty));
+            extended_price.push(Some(cents(qty * retail_price)));
+            discount.push(Some(rng.gen_range(0..=10) as f64 / 100.0));
+            tax.push(Some(rng.gen_range(0..=8) as f64 / 100.0));
+            let flag = match received <= TPCH_CURRENT {
+                true if rng.gen_bool(0.5) => "R",
+                true => "A",
+                false => "N",
+            };
+            return_flag.push(Some(flag.to_string()));
+            let status = if shipped > TPCH_CURRENT { "O" } else { "F" };
+            line_status.push(Some(status.to_string()));
+            ship_date.push(Some(shipped));
+            ship_mode.push(Some(
+                SHIP_MODES[rng.gen_range(0..SHIP_MODES.len())].to_string(),
+            ));
+        }
+    }
+    frame(vec![
+        Series::new_i32("l_orderkey", order_key),
+        Series::new_i32("l_partkey", part_key),
+        Series::new_i32("l_suppkey", supp_key),
+        Series::new_i32("l_linenumber", line_number),
+        Series::new_f64("l_quantity", quantity),
+        Series::new_f64("l_extendedprice", extended_price),
+        Series::new_f64("l_discount", discount),
+        Series::new_f64("l_tax", tax),
+        Series::new_string("l_returnflag", return_flag),
+        Series::new_string("l_linestatus", line_status),
+        Series::new_datetime("l_shipdate", ship_date),
+        Series::new_string("l_shipmode", ship_mode),
+    ])
+}
+
+/// How [`run_suite`] measures each operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    /// Timed runs per operation, after one untimed warm-up run
+    pub iterations: usize,
+    /// Version recorded in the report; defaults to this crate's version
+    pub version: String,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 3,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+/// Timings of one operation of the suite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    pub dataset: String,
+    pub operation: String,
+    /// Rows of the input dataset
+    pub rows: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+}
+
+/// The timings of a suite run, tagged with the version that produced them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub version: String,
+    pub results: Vec<BenchResult>,
+}
+
+type Operation<'a> = Box<dyn Fn() -> Result<(), VeloxxError> + 'a>;
+
+/// Runs the fixed operation suite for `dataset` on `df`, which must have the
+/// dataset's schema (as produced by [`Dataset::generate`]).
+///
+/// CSV operations use a scratch file in the system temporary directory.
+pub fn run_suite(
+    dataset: Dataset,
+    df: &DataFrame,
+    options: &BenchOptions,
+) -> Result<BenchReport, VeloxxError> {
+    let csv_path = std::env::temp_dir().join(format!(
+        "veloxx_bench_{}_{}.csv",
+        std::process::id(),
+        dataset.name()
+    ));
+    let csv = csv_path.to_string_lossy().into_owned();
+    let mut operations = match dataset {
+        Dataset::NycTaxi => taxi_operations(df)?,
+        Dataset::TpchLineitem => lineitem_operations(df)?,
+    };
+    operations.push(("csv_write", Box::new(|| df.to_csv(&csv)) as Operation));
+    operations.push((
+        "csv_read",
+        Box::new(|| DataFrame::from_csv(&csv).map(|read| drop(black_box(read)))),
+    ));
+
+    let mut results = Vec::with_capacity(operations.len());
+    for (operation, run) in &operations {
+        let mut times = Vec::with_capacity(options.iterations);
+        run()?;
+        for _ in 0..options.iterations.max(1) {
+            let start = Instant::now();
+            run()?;
+            // Microsecond resolution keeps reports readable
+            times.push((start.elapsed().as_secs_f64() * 1e6).round() / 1000.0);
+        }
+        times.sort_by(f64::total_cmp);
+        results.push(BenchResult {
+            dataset: dataset.name().to_string(),
+            operation: operation.to_string(),
+            rows: df.row_count(),
+            min_ms: times[0],
+            median_ms: times[times.len() / 2],
+        });
+    }
+    let _ = std::fs::remove_file(&csv_path);
+    Ok(BenchReport {
+        version: options.version.clone(),
+        results,
+    })
+}
+
+fn col(name: &str) -> Box<Expr> {
+    Box::new(Expr::Column(name.to_string()))
+}
+
+fn taxi_operations(df: &DataFrame) -> Result<Vec<(&'static str, Operation<'_>)>, VeloxxError> {
+    let payment_types = frame(vec![
+        Series::new_i32("payment_type", (1..=6).map(Some).collect()),
+        Series::new_string(
+            "payment_name",
+            [
+                "Credit card",
+                "Cash",
+                "No charge",
+                "Dispute",
+                "Unknown",
+                "Voided trip",
+            ]
+            .map(|name| Some(name.to_string()))
+            .to_vec(),
+        ),
+    ])?;
+    let long_shared_trips = Condition::And(
+        Box::new(Condition::Gt("trip_distance".to_string(), Value::F64(2.0))),
+        Box::new(Condition::Gt("passenger_count".to_string(), Value::I32(1))),
+    );
+    Ok(vec![
+        (
+            "filter",
+            Box::new(move || df.filter(&long_shared_trips).map(|r| drop(black_box(r)))),
+        ),
+        (
+            "sort",
+            Box::new(|| {
+                df.sort(vec!["total_amount".to_string()], false)
+                    .map(|r| drop(black_box(r)))
+            }),
+        ),
+        (
+            "group_by",
+            Box::new(|| {
+                df.group_by(vec!["PULocationID".to_string()])?
+                    .agg(vec![
+                        ("fare_amount", "mean"),
+                        ("tip_amount", "sum"),
+                        ("total_amount", "count"),
+                    ])
+                    .map(|r| drop(black_box(r)))
+            }),
+        ),
+        (
+            "with_column",
+            Box::new(|| {
+                df.with_column(
+                    "tip_share",
+                    &Expr::Divide(col("tip_amount"), col("total_amount")),
+                )
+                .map(|r| drop(black_box(r)))
+            }),
+        ),
+        (
+            "join",
+            Box::new(move || {
+                df.join(&payment_types, "payment_type", JoinType::Left)
+                    .map(|r| drop(black_box(r)))
+            }),
+        ),
+    ])
+}
+
+fn lineitem_operations(df: &DataFrame) -> Result<Vec<(&'static str, Operation<'_>)>, VeloxxError> {
+    let suppliers = supplier_count(df.row_count());
+    let supplier = frame(vec![
+        Series::new_i32("l_suppkey", (1..=suppliers).map(Some).collect()),
+        Series::new_i32(
+            "s_nationkey",
+            (1..=suppliers).map(|s| Some(s % 25)).collect(),
+        ),
+    ])?;
+    // TPC-H Q6 without its ship date range
+    let q6 = Condition::And(
+        Box::new(Condition::And(
+            Box::new(Condition::Gt("l_discount".to_string(), Value::F64(0.045))),
+            Box::new(Condition::Lt("l_discount".to_string(), Value::F64(0.075))),
+        )),
+        Box::new(Condition::Lt("l_quantity".to_string(), Value::F64(24.0))),
+    );
+    Ok(vec![
+        (
+            "q1_group_by",
+            Box::new(|| {
+                df.group_by(vec!["l_returnflag".to_string(), "l_linestatus".to_string()])?
+                    .agg(vec![
+                        ("l_quantity", "sum"),
+                        ("l_extendedprice", "sum"),
+                        ("l_discount", "mean"),
+                        ("l_orderkey", "count"),
+                    ])
+                    .map(|r| drop(black_box(r)))
+            }),
+        ),
+        (
+            "q6_filter_revenue",
+            Box::new(move || {
+                let revenue = df.filter(&q6)?.with_column(
+                    "revenue",
+                    &Expr::Multiply(col("l_extendedprice"), col("l_discount")),
+                )?;
+                black_box(revenue.get_column("revenue").map(|r| r.sum()).transpose()?);
+                Ok(())
+            }),
+        ),
+        (
+            "sort",
+            Box::new(|| {
+                df.sort(vec!["l_extendedprice".to_string()], false)
+                    .map(|r| drop(black_box(r)))
+            }),
+        ),
+        (
+            "join",
+            Box::new(move || {
+                df.join(&supplier, "l_suppkey", JoinType::Inner)
+                    .map(|r| drop(black_box(r)))
+            }),
+        ),
+    ])
+}
+
+impl BenchReport {
+    /// One row per operation, with columns `version`, `dataset`, `operation`,
+    /// `rows`, `min_ms` and `median_ms`.
+    pub fn to_dataframe(&self) -> Result<DataFrame, VeloxxError> {
+        let strings = |f: fn(&BenchResult) -> &str| {
+            self.results
+                .iter()
+                .map(|r| Some(f(r).to_string()))
+                .collect()
+        };
+        let numbers =
+            |f: fn(&BenchResult) -> f64| self.results.iter().map(|r| Some(f(r))).collect();
+        frame(vec![
+            Series::new_string(
+                "version",
+                vec![Some(self.version.clone()); self.results.len()],
+            ),
+            Series::new_string("dataset", strings(|r| &r.dataset)),
+            Series::new_string("operation", strings(|r| &r.operation)),
+            Series::new_i32(
+                "rows",
+                self.results.iter().map(|r| Some(r.rows as i32)).collect(),
+            ),
+            Series::new_f64("min_ms", numbers(|r| r.min_ms)),
+            Series::new_f64("median_ms", numbers(|r| r.median_ms)),
+        ])
+    }
+
+    /// Reads a report back from the frame produced by [`BenchReport::to_dataframe`].
+    pub fn from_dataframe(df: &DataFrame) -> Result<Self, VeloxxError> {
+        let value = |column: &str, row: usize| {
+            df.get_column(column)
+                .ok_or_else(|| {
+                    VeloxxError::ColumnNotFound(format!("Benchmark report has no '{}'", column))
+                })?
+                .get_value(row)
+                .ok_or_else(|| {
+                    VeloxxError::InvalidOperation(format!(
+                        "Benchmark report has a null '{}' in row {}",
+                        column, row
+                    ))
+                })
+        };
+        let number = |column: &str, row: usize| match value(column, row)? {
+            Value::I32(i) => Ok(i as f64),
+            Value::F64(f) => Ok(f),
+            other => Err(VeloxxError::DataTypeMismatch(format!(
+                "Benchmark report column '{}' holds {:?}, not a number",
+                column, other
+            ))),
+        };
+        let text = |column: &str, row: usize| value(column, row).map(|v| v.to_string());
+        let results = (0..df.row_count())
+            .map(|row| {
+                Ok(BenchResult {
+                    dataset: text("dataset", row)?,
+                    operation: text("operation", row)?,
+                    rows: number("rows", row)? as usize,
+                    min_ms: number("min_ms", row)?,
+                    median_ms: number("median_ms", row)?,
+                })
+            })
+            .collect::<Result<Vec<_>, VeloxxError>>()?;
+        let version = match df.row_count() {
+            0 => String::new(),
+            _ => text("version", 0)?,
+        };
+        Ok(BenchReport { version, results })
+    }
+
+    /// Writes the report as CSV.
+    pub fn save_csv(&self, path: impl AsRef<Path>) -> Result<(), VeloxxError> {
+        self.to_dataframe()?
+            .to_csv(&path.as_ref().to_string_lossy())
+    }
+
+    /// Reads a report written by [`BenchReport::save_csv`].
+    pub fn load_csv(path: impl AsRef<Path>) -> Result<Self, VeloxxError> {
+        Self::from_dataframe(&DataFrame::from_csv(&path.as_ref().to_string_lossy())?)
+    }
+
+    /// Compares the median timings with those of `baseline`, row by row for the
+    /// operations of this report.
+    ///
+    /// The result has columns `dataset`, `operation`, `baseline_version`,
+    /// `version`, `baseline_ms`, `median_ms`, `change_pct` and `status`, which is
+    /// `regressed` or `improved` when the median moved by more than
+    /// `tolerance_pct` percent, `unchanged` otherwise, and `new` for operations
+    /// missing from the baseline (whose baseline columns are null).
+    pub fn compare(
+        &self,
+        baseline: &BenchReport,
+        tolerance_pct: f64,
+    ) -> Result<DataFrame, VeloxxError> {
+        let before: HashMap<(&str, &str), f64> = baseline
+            .results
+            .iter()
+            .map(|r| ((r.dataset.as_str(), r.operation.as_str()), r.median_ms))
+            .collect();
+        let mut baseline_ms = Vec::with_capacity(self.results.len());
+        let mut change_pct = Vec::with_capacity(self.results.len());
+        let mut status = Vec::with_capacity(self.results.len());
+        for result in &self.results {
+            let previous = before
+                .get(&(result.dataset.as_str(), result.operation.as_str()))
+                .copied();
+            let change = previous.map(|p| (result.median_ms - p) / p.max(f64::EPSILON) * 100.0);
+            baseline_ms.push(previous);
+            change_pct.push(change);
+            status.push(Some(
+                match change {
+                    None => "new",
+                    Some(c) if c > tolerance_pct => "regressed",
+                    Some(c) if c < -tolerance_pct => "improved",
+                    Some(_) => "unchanged",
+                }
+                .to_string(),
+            ));
+        }
+        let rows = self.results.len();
+        frame(vec![
+            Series::new_string(
+                "dataset",
+                self.results
+                    .iter()
+                    .map(|r| Some(r.dataset.clone()))
+                    .collect(),
+            ),
+            Series::new_string(
+                "operation",
+                self.results
+                    .iter()
+                    .map(|r| Some(r.operation.clone()))
+                    .collect(),
+            ),
+            Series::new_string(
+                "baseline_version",
+                vec![Some(baseline.version.clone()); rows],
+            ),
+            Series::new_string("version", vec![Some(self.version.clone()); rows]),
+            Series::new_f64("baseline_ms", baseline_ms),
+            Series::new_f64(
+                "median_ms",
+                self.results.iter().map(|r| Some(r.median_ms)).collect(),
+            ),
+            Series::new_f64("change_pct", change_pct),
+            Series::new_string("status", status),
+        ])
+    }
+}
@@ -0,0 +1,291 @@
+//! `veloxx`: query and convert CSV, JSON and Parquet files from the command line.
+//!
+//! Built with the `cli` feature: `cargo install veloxx --features cli`.
+
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use veloxx::bench::{run_suite, BenchOptions, BenchReport, Dataset};
+use veloxx::dataframe::DataFrame;
+use veloxx::query::sql;
+use veloxx::types::Value;
+use veloxx::VeloxxError;
+
+const USAGE: &str = "\
+Usage:
+  veloxx query <INPUT> <SQL> [-o OUTPUT] [-f FORMAT]
+  veloxx head <INPUT> [-n ROWS] [-o OUTPUT] [-f FORMAT]
+  veloxx schema <INPUT>
+  veloxx convert <INPUT> <OUTPUT>
+  veloxx bench <DATASET> [-n ROWS] [--baseline REPORT] [-o OUTPUT] [-f FORMAT]
+
+INPUT is a .csv, .json or .parquet file, or - for CSV on standard input.
+Results go to OUTPUT (format taken from its extension) or to standard output
+as FORMAT: table, csv or json (default: table on a terminal, csv otherwise).
+
+bench times a fixed suite of operations on generated data for DATASET (taxi
+or tpch, ROWS rows, by default the size of the reference dataset) and outputs
+the timings, or their comparison with a REPORT saved earlier by bench -o.
+
+Example:
+  veloxx query data.csv \"select city, sum(sales) group by city\"";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Table,
+    Csv,
+    Json,
+    Parquet,
+}
+
+impl Format {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "table" => Some(Format::Table),
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            "parquet" => Some(Format::Parquet),
+            _ => None,
+        }
+    }
+
+    fn from_path(path: &str) -> Result<Self, VeloxxError> {
+        Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(Self::from_name)
+            .filter(|format| *format != Format::Table)
+            .ok_or_else(|| {
+                VeloxxError::Unsupported(format!(
+                    "Cannot tell the format of '{}'; use a .csv, .json or .parquet file",
+                    path
+                ))
+            })
+    }
+}
+
+/// Parsed command line: positional arguments plus the shared options
+struct Args {
+    positional: Vec<String>,
+    output: Option<String>,
+    format: Option<Format>,
+    rows: Option<usize>,
+    baseline: Option<String>,
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        positional: Vec::new(),
+        output: None,
+        format: None,
+        rows: None,
+        baseline: None,
+    };
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| format!("{} requires a value", flag))
+        };
+        match arg.as_str() {
+            "-o" | "--output" => parsed.output = Some(value(&arg)?),
+            "-f" | "--format" => {
+                let name = value(&arg)?;
+                parsed.format = Some(
+                    Format::from_name(&name).ok_or_else(|| format!("unknown format '{}'", name))?,
+                );
+            }
+            "-n" | "--rows" => {
+                let rows = value(&arg)?;
+                parsed.rows = Some(
+                    rows.parse()
+                        .map_err(|_| format!("invalid row count '{}'", rows))?,
+                );
+            }
+            "--baseline" => parsed.baseline = Some(value(&arg)?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option '{}'", arg))
+            }
+            _ => parsed.positional.push(arg),
+        }
+    }
+    Ok(parsed)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => return usage_error(&message),
+    };
+    let positional: Vec<&str> = args.positional.iter().map(String::as_str).collect();
+    let result = match positional.as_slice() {
+        ["query", input, query] => {
+            read_input(input).and_then(|df| write_output(&sql::execute(&df, query)?, &args))
+        }
+        ["head", input] => read_input(input).and_then(|df| {
+            let rows: Vec<usize> = (0..df.row_count().min(args.rows.unwrap_or(10))).collect();
+            write_output(&df.filter_by_indices(&rows)?, &args)
+        }),
+        ["schema", input] => read_input(input).map(|df| print_schema(&df)),
+        ["convert", input, output] => read_input(input).and_then(|df| {
+            write_file(
+                &df,
+                output,
+                args.format.unwrap_or(Format::from_path(output)?),
+            )
+        }),
+        ["bench", dataset] => match Dataset::from_name(dataset) {
+            Some(dataset) => bench(dataset, &args),
+            None => return usage_error(&format!("unknown dataset '{}'", dataset)),
+        },
+        [] => return usage_error(""),
+        _ => return usage_error("unrecognized command"),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("veloxx: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    if !message.is_empty() {
+        eprintln!("veloxx: {}\n", message);
+    }
+    eprintln!("{}", USAGE);
+    ExitCode::from(2)
+}
+
+fn bench(dataset: Dataset, args: &Args) -> Result<(), VeloxxError> {
+    let rows = args.rows.unwrap_or(dataset.reference_rows());
+    let report = run_suite(
+        dataset,
+        &dataset.generate(rows, 42)?,
+        &BenchOptions::default(),
+    )?;
+    match &args.baseline {
+        Some(path) => write_output(&report.compare(&BenchReport::load_csv(path)?, 10.0)?, args),
+        None => write_output(&report.to_dataframe()?, args),
+    }
+}
+
+fn read_input(input: &str) -> Result<DataFrame, VeloxxError> {
+    if input == "-" {
+        return DataFrame::from_stdin_csv();
+    }
+    match Format::from_path(input)? {
+        Format::Json => DataFrame::from_json(input),
+        Format::Parquet => read_parquet(input),
+        _ => DataFrame::from_csv(input),
+    }
+}
+
+#[cfg(all(feature = "advanced_io", feature = "arrow"))]
+fn read_parquet(path: &str) -> Result<DataFrame, VeloxxError> {
+    veloxx::io::arrow::read_parquet_to_dataframe(path)
+}
+
+#[cfg(not(all(feature = "advanced_io", feature = "arrow")))]
+fn read_parquet(_path: &str) -> Result<DataFrame, VeloxxError> {
+    Err(VeloxxError::Unsupported(
+        "Parquet support requires the advanced_io and arrow features".to_string(),
+    ))
+}
+
+fn write_output(df: &DataFrame, args: &Args) -> Result<(), VeloxxError> {
+    if let Some(path) = &args.output {
+        let format = match args.format {
+            Some(format) => format,
+            None => Format::from_path(path)?,
+        };
+        return write_file(df, path, format);
+    }
+    let format = args.format.unwrap_or(if std::io::stdout().is_terminal() {
+        Format::Table
+    } else {
+        Format::Csv
+    });
+    let text = match format {
+        Format::Table => format!("{}\n", df),
+        Format::Csv => df.to_csv_string(),
+        Format::Json => format!("{}\n", to_json(df)),
+        Format::Parquet => {
+            return Err(VeloxxError::Unsupported(
+                "Parquet output needs a file; use -o".to_string(),
+            ))
+        }
+    };
+    let mut stdout = std::io::stdout().lock();
+    match stdout
+        .write_all(text.as_bytes())
+        .and_then(|_| stdout.flush())
+    {
+        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+fn write_file(df: &DataFrame, path: &str, format: Format) -> Result<(), VeloxxError> {
+    match format {
+        Format::Table => Ok(std::fs::write(path, format!("{}\n", df))?),
+        Format::Csv => df.to_csv(path),
+        Format::Json => Ok(std::fs::write(path, format!("{}\n", to_json(df)))?),
+        Format::Parquet => write_parquet(df, path),
+    }
+}
+
+#[cfg(all(feature = "advanced_io", feature = "arrow"))]
+fn write_parquet(df: &DataFrame, path: &str) -> Result<(), VeloxxError> {
+    let batch = veloxx::io::arrow::dataframe_to_record_batch(df)?;
+    let mut writer =
+        parquet::arrow::ArrowWriter::try_new(std::fs::File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(not(all(feature = "advanced_io", feature = "arrow")))]
+fn write_parquet(_df: &DataFrame, _path: &str) -> Result<(), VeloxxError> {
+    Err(VeloxxError::Unsupported(
+        "Parquet support requires the advanced_io and arrow features".to_string(),
+    ))
+}
+
+/// Renders rows as a JSON array of objects, with columns in sorted order like CSV
+fn to_json(df: &DataFrame) -> String {
+    let mut names = df.column_names();
+    names.sort();
+    let rows: Vec<serde_json::Value> = (0..df.row_count())
+        .map(|row| {
+            let object = names
+                .iter()
+                .map(|name| {
+                    let value = match df.get_column(name).unwrap().get_value(row) {
+                        Some(Value::I32(v)) => v.into(),
+                        Some(Value::F64(v)) => v.into(),
+                        Some(Value::Bool(v)) => v.into(),
+                        Some(Value::String(v)) => v.into(),
+                        Some(Value::DateTime(v)) => v.into(),
+                        Some(v @ Value::Binary(_)) => v.to_string().into(),
+                        Some(Value::Null) | None => serde_json::Value::Null,
+                    };
+                    (name.to_string(), value)
+                })
+                .collect();
+            serde_json::Value::Object(object)
+        })
+        .collect();
+    serde_json::Value::Array(rows).to_string()
+}
+
+fn print_schema(df: &DataFrame) {
+    let mut names = df.column_names();
+    names.sort();
+    for name in names {
+        println!("{}: {:?}", name, df.get_column(name).unwrap().data_type());
+    }
+    println!("({} rows)", df.row_count());
+}
@@ -1,4 +1,5 @@
 use crate::dataframe::DataFrame;
+use crate::series::ip::IpNetwork;
 use crate::types::Value;
 use crate::VeloxxError;
 
@@ -126,6 +127,14 @@ pub enum Condition {
     /// # Arguments
     /// - `Box<Condition>`: The condition to negate.
     Not(Box<Condition>),
+    /// Represents an IP subnet membership test on a `String` column of addresses.
+    ///
+    /// Nulls and values that are not IP addresses do not match.
+    ///
+    /// # Arguments
+    /// - `String`: The name of the column holding the addresses.
+    /// - `IpNetwork`: The CIDR block, e.g. parsed from `"10.0.0.0/8"`.
+    InSubnet(String, IpNetwork),
 }
 
 impl Condition {
@@ -175,6 +184,18 @@ impl Condition {
                 Ok(left.evaluate(df, row_index)? || right.evaluate(df, row_index)?)
             }
             Condition::Not(cond) => Ok(!cond.evaluate(df, row_index)?),
+            Condition::InSubnet(col_name, network) => {
+                let series = df
+                    .get_column(col_name)
+                    .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
+                match series.get_value(row_index) {
+                    Some(Value::String(address)) => Ok(network.contains_str(&address)),
+                    None => Ok(false),
+                    Some(other) => Err(VeloxxError::InvalidOperation(format!(
+                        "Cannot test {other:?} for subnet membership"
+                    ))),
+                }
+            }
         }
     }
 }
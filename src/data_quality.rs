@@ -156,7 +156,7 @@ impl SchemaValidator {
     ///
     /// let df = DataFrame::new(columns).unwrap();
     ///
-    /// let mut schema_columns = BTreeMap::new();
+    /// let mut schema_columns = HashMap::new();
     /// schema_columns.insert(
     ///     "age".to_string(),
     ///     ColumnSchema {
@@ -0,0 +1,80 @@
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// Leading bytes of every encoded frame: a magic tag followed by a format version.
+const HEADER: &[u8; 5] = b"VLXB\x01";
+
+impl DataFrame {
+    /// Encodes the `DataFrame` into Veloxx's compact binary format.
+    ///
+    /// The encoding is a short header followed by the columns in sorted name order,
+    /// each storing its raw values and validity bitmap. It round-trips every column
+    /// type exactly, including nulls and `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::df;
+    ///
+    /// let df = df!("x" => [1, 2, 3]).unwrap();
+    /// let restored = DataFrame::from_bytes(&df.to_bytes()).unwrap();
+    /// assert_eq!(restored.get_column("x"), df.get_column("x"));
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut names: Vec<&String> = self.columns.keys().collect();
+        names.sort_unstable();
+        let columns: Vec<&Series> = names.into_iter().map(|name| &self.columns[name]).collect();
+
+        let mut bytes = HEADER.to_vec();
+        bincode::encode_into_std_write(&columns, &mut bytes, bincode::config::standard())
+            .expect("writing into a Vec cannot fail");
+        bytes
+    }
+
+    /// Decodes a `DataFrame` produced by [`DataFrame::to_bytes`].
+    ///
+    /// Returns `VeloxxError::Parsing` if the bytes are not in the expected format or
+    /// were written by an incompatible version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VeloxxError> {
+        let payload = bytes.strip_prefix(HEADER.as_slice()).ok_or_else(|| {
+            VeloxxError::Parsing("Data is not a Veloxx binary DataFrame".to_string())
+        })?;
+        let (columns, read): (Vec<Series>, usize) =
+            bincode::decode_from_slice(payload, bincode::config::standard())
+                .map_err(|e| VeloxxError::Parsing(format!("Invalid binary DataFrame: {}", e)))?;
+        if read != payload.len() {
+            return Err(VeloxxError::Parsing(
+                "Invalid binary DataFrame: trailing bytes".to_string(),
+            ));
+        }
+
+        let mut map = HashMap::with_capacity(columns.len());
+        for series in columns {
+            let (values, validity) = match &series {
+                Series::I32(_, v, b) => (v.len(), b.len()),
+                Series::F64(_, v, b) => (v.len(), b.len()),
+                Series::Bool(_, v, b) => (v.len(), b.len()),
+                Series::String(_, v, b) => (v.len(), b.len()),
+                Series::DateTime(_, v, b) => (v.len(), b.len()),
+                Series::Binary(_, v, b) => (v.len(), b.len()),
+            };
+            if values != validity {
+                return Err(VeloxxError::Parsing(format!(
+                    "Invalid binary DataFrame: column '{}' has {} values but {} validity flags",
+                    series.name(),
+                    values,
+                    validity
+                )));
+            }
+            if map.insert(series.name().to_string(), series).is_some() {
+                return Err(VeloxxError::Parsing(
+                    "Invalid binary DataFrame: duplicate column name".to_string(),
+                ));
+            }
+        }
+        DataFrame::new(map)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// A scalar type that can be collected into a typed [`Series`].
+///
+/// Implemented for the native element types (`i32`, `f64`, `bool`, `String`, `&str`)
+/// and their `Option` counterparts, so both dense and nullable data can be passed to
+/// [`DataFrameBuilder::col`] and the [`df!`](crate::df) macro.
+pub trait ColumnElement: Sized {
+    /// Builds a series named `name` from `values`.
+    fn into_series(name: &str, values: Vec<Self>) -> Series;
+}
+
+macro_rules! impl_column_element {
+    ($ty:ty, $ctor:ident, $conv:expr) => {
+        impl ColumnElement for $ty {
+            fn into_series(name: &str, values: Vec<Self>) -> Series {
+                Series::$ctor(name, values.into_iter().map(|v| Some($conv(v))).collect())
+            }
+        }
+
+        impl ColumnElement for Option<$ty> {
+            fn into_series(name: &str, values: Vec<Self>) -> Series {
+                Series::$ctor(name, values.into_iter().map(|v| v.map($conv)).collect())
+            }
+        }
+    };
+}
+
+impl_column_element!(i32, new_i32, |v: i32| v);
+impl_column_element!(f64, new_f64, |v: f64| v);
+impl_column_element!(bool, new_bool, |v: bool| v);
+impl_column_element!(String, new_string, |v: String| v);
+impl_column_element!(&str, new_string, |v: &str| v.to_string());
+
+/// A string element for [`DataFrameBuilder::col_str`]: `&str`, `String` or
+/// their `Option` counterparts.
+pub trait StringElement {
+    /// The element as a nullable `String`.
+    fn into_string(self) -> Option<String>;
+}
+
+impl StringElement for &str {
+    fn into_string(self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl StringElement for String {
+    fn into_string(self) -> Option<String> {
+        Some(self)
+    }
+}
+
+impl<S: StringElement> StringElement for Option<S> {
+    fn into_string(self) -> Option<String> {
+        self.and_then(StringElement::into_string)
+    }
+}
+
+/// Incrementally constructs a [`DataFrame`] column by column.
+///
+/// Column names are taken from the builder calls, so there is no need to keep
+/// `HashMap` keys and `Series` names in sync by hand. Length and duplicate-name
+/// checks are deferred to [`build`](DataFrameBuilder::build).
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::dataframe::DataFrame;
+///
+/// let df = DataFrame::builder()
+///     .col_i32("id", [1, 2, 3])
+///     .col_str("name", [Some("a"), None, Some("c")])
+///     .col_f64("score", [0.5, 1.5, 2.5])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(df.row_count(), 3);
+/// assert_eq!(df.column_count(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DataFrameBuilder {
+    columns: Vec<Series>,
+}
+
+impl DataFrameBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a column of any supported element type.
+    pub fn col<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: ColumnElement,
+        I: IntoIterator<Item = T>,
+    {
+        self.series(T::into_series(name, values.into_iter().collect()))
+    }
+
+    /// Adds an `I32` column. Accepts plain values or `Option<i32>` for nulls.
+    pub fn col_i32<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: Into<Option<i32>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.series(Series::new_i32(
+            name,
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Adds an `F64` column. Accepts plain values or `Option<f64>` for nulls.
+    pub fn col_f64<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: Into<Option<f64>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.series(Series::new_f64(
+            name,
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Adds a `Bool` column. Accepts plain values or `Option<bool>` for nulls.
+    pub fn col_bool<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: Into<Option<bool>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.series(Series::new_bool(
+            name,
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Adds a `String` column. Accepts `&str`, `String` or their `Option` forms.
+    pub fn col_str<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: StringElement,
+        I: IntoIterator<Item = T>,
+    {
+        self.series(Series::new_string(
+            name,
+            values.into_iter().map(StringElement::into_string).collect(),
+        ))
+    }
+
+    /// Adds a `DateTime` column of Unix timestamps.
+    pub fn col_datetime<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: Into<Option<i64>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.series(Series::new_datetime(
+            name,
+            values.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Adds an existing series, using its own name as the column name.
+    pub fn series(mut self, series: Series) -> Self {
+        self.columns.push(series);
+        self
+    }
+
+    /// Validates the columns and builds the `DataFrame`.
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if a column name is used twice or
+    /// the columns have different lengths.
+    pub fn build(self) -> Result<DataFrame, VeloxxError> {
+        let mut columns = HashMap::with_capacity(self.columns.len());
+        for series in self.columns {
+            let name = series.name().to_string();
+            if columns.contains_key(&name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Duplicate column name '{}' in DataFrame builder",
+                    name
+                )));
+            }
+            columns.insert(name, series);
+        }
+        DataFrame::new(columns)
+    }
+}
+
+impl DataFrame {
+    /// Returns a [`DataFrameBuilder`] for constructing a `DataFrame` column by column.
+    pub fn builder() -> DataFrameBuilder {
+        DataFrameBuilder::new()
+    }
+}
+
+/// Creates a [`DataFrame`](crate::dataframe::DataFrame) from `name => values` pairs.
+///
+/// The column type is inferred from the element type of each collection; use
+/// `Option` elements for nullable columns. Expands to a
+/// [`DataFrameBuilder`](crate::dataframe::builder::DataFrameBuilder) chain and
+/// returns `Result<DataFrame, VeloxxError>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::df;
+///
+/// let df = df!(
+///     "id" => [1, 2, 3],
+///     "name" => ["a", "b", "c"],
+///     "score" => [Some(1.0), None, Some(3.0)],
+/// )
+/// .unwrap();
+///
+/// assert_eq!(df.row_count(), 3);
+/// ```
+#[macro_export]
+macro_rules! df {
+    ($($name:expr => $values:expr),* $(,)?) => {
+        $crate::dataframe::DataFrame::builder()
+            $(.col($name, $values))*
+            .build()
+    };
+}
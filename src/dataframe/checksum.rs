@@ -0,0 +1,182 @@
+//! Stable row hashes and content checksums, for checking that data arrived
+//! intact after a hop between systems.
+//!
+//! Hashes use XXH64 over a fixed byte encoding of each value, so they are the
+//! same on every platform and Rust release and can be stored and compared later.
+//! Columns are visited in name order, since frames do not order their columns.
+//! Two `F64` NaNs hash alike whatever their bits; all other values hash by
+//! their exact contents, so `0.0` and `-0.0` differ.
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::DataType;
+use xxhash_rust::xxh64::Xxh64;
+
+/// Seed of [`DataFrame::checksum`]; changing it changes every stored checksum
+const CHECKSUM_SEED: u64 = 0;
+
+/// Feeds the value at `row` to `hasher` after a type tag (0 for null), so values
+/// of different types never collide by encoding
+fn write_value(hasher: &mut Xxh64, series: &Series, row: usize) {
+    let valid = match series {
+        Series::I32(_, _, validity)
+        | Series::F64(_, _, validity)
+        | Series::Bool(_, _, validity)
+        | Series::String(_, _, validity)
+        | Series::DateTime(_, _, validity)
+        | Series::Binary(_, _, validity) => validity[row],
+    };
+    if !valid {
+        hasher.update(&[0]);
+        return;
+    }
+    match series {
+        Series::I32(_, values, _) => {
+            hasher.update(&[1]);
+            hasher.update(&values[row].to_le_bytes());
+        }
+        Series::F64(_, values, _) => {
+            let value = if values[row].is_nan() {
+                f64::NAN
+            } else {
+                values[row]
+            };
+            hasher.update(&[2]);
+            hasher.update(&value.to_bits().to_le_bytes());
+        }
+        Series::Bool(_, values, _) => hasher.update(&[3, u8::from(values[row])]),
+        Series::String(_, values, _) => {
+            hasher.update(&[4]);
+            write_bytes(hasher, values[row].as_bytes());
+        }
+        Series::DateTime(_, values, _) => {
+            hasher.update(&[5]);
+            hasher.update(&values[row].to_le_bytes());
+        }
+        Series::Binary(_, values, _) => {
+            hasher.update(&[6]);
+            write_bytes(hasher, &values[row]);
+        }
+    }
+}
+
+/// Length-prefixed, so `("ab", "c")` and `("a", "bc")` hash differently
+fn write_bytes(hasher: &mut Xxh64, bytes: &[u8]) {
+    hasher.update(&(bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
+
+impl DataFrame {
+    /// Columns in name order
+    fn sorted_columns(&self) -> Vec<&Series> {
+        let mut names = self.column_names();
+        names.sort();
+        names.into_iter().map(|name| &self.columns[name]).collect()
+    }
+
+    fn row_hashes(&self, seed: u64) -> Vec<u64> {
+        let columns = self.sorted_columns();
+        (0..self.row_count)
+            .map(|row| {
+                let mut hasher = Xxh64::new(seed);
+                for series in &columns {
+                    write_value(&mut hasher, series, row);
+                }
+                hasher.digest()
+            })
+            .collect()
+    }
+
+    /// Hashes every row into a column named `row_hash` holding the XXH64 of
+    /// the row's values under `seed`.
+    ///
+    /// Veloxx has no 64-bit integer column, so the `u64` hashes are stored
+    /// bit-cast to `i64` in a `DateTime` column, the only type with 64-bit
+    /// integer values; `hash as u64` recovers the XXH64 value. The column
+    /// compares, sorts, joins and groups like any integer key.
+    ///
+    /// Only the values are hashed, visited in column-name order; the names
+    /// themselves are not, so equal rows hash equally in any frame whose columns
+    /// sort into the same order, and the hashes can find rows that changed
+    /// between two copies of a table. Use [`DataFrame::checksum`] to compare
+    /// column names as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("id" => [1, 2, 1], "tag" => ["a", "b", "a"]).unwrap();
+    /// let hashes = df.hash_rows(0);
+    /// assert_eq!(hashes.get_value(0), hashes.get_value(2));
+    /// assert_ne!(hashes.get_value(0), hashes.get_value(1));
+    /// if let Some(Value::DateTime(hash)) = hashes.get_value(0) {
+    ///     println!("{:016x}", hash as u64);
+    /// }
+    /// ```
+    pub fn hash_rows(&self, seed: u64) -> Series {
+        let hashes = self
+            .row_hashes(seed)
+            .into_iter()
+            .map(|hash| Some(hash as i64))
+            .collect();
+        Series::new_datetime("row_hash", hashes)
+    }
+
+    /// A hash of the frame's column names, types and rows, in row order.
+    ///
+    /// Two frames have the same checksum when they hold the same columns and the
+    /// same rows in the same order; use [`DataFrame::checksum_unordered`] when
+    /// the row order may change along the way, e.g. after a parallel write.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let sent = df!("id" => [1, 2], "tag" => ["a", "b"]).unwrap();
+    /// let received = df!("tag" => ["a", "b"], "id" => [1, 2]).unwrap();
+    /// assert_eq!(sent.checksum(), received.checksum());
+    /// ```
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = self.schema_hasher();
+        for hash in self.row_hashes(CHECKSUM_SEED) {
+            hasher.update(&hash.to_le_bytes());
+        }
+        hasher.digest()
+    }
+
+    /// Like [`DataFrame::checksum`], but the same for any order of the rows.
+    ///
+    /// Duplicated rows still count, so a frame that lost or repeated a row has
+    /// a different checksum.
+    pub fn checksum_unordered(&self) -> u64 {
+        let sum = self
+            .row_hashes(CHECKSUM_SEED)
+            .into_iter()
+            .fold(0u64, u64::wrapping_add);
+        let mut hasher = self.schema_hasher();
+        hasher.update(&sum.to_le_bytes());
+        hasher.digest()
+    }
+
+    /// A hasher that has seen the column names and types and the row count
+    fn schema_hasher(&self) -> Xxh64 {
+        let mut hasher = Xxh64::new(CHECKSUM_SEED);
+        for series in self.sorted_columns() {
+            write_bytes(&mut hasher, series.name().as_bytes());
+            let tag = match series.data_type() {
+                DataType::I32 => 1,
+                DataType::F64 => 2,
+                DataType::Bool => 3,
+                DataType::String => 4,
+                DataType::DateTime => 5,
+                DataType::Binary => 6,
+            };
+            hasher.update(&[tag]);
+        }
+        hasher.update(&(self.row_count as u64).to_le_bytes());
+        hasher
+    }
+}
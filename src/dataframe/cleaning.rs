@@ -1,3 +1,4 @@
+use crate::series::interpolate::InterpolationMethod;
 use crate::VeloxxError;
 use crate::{dataframe::DataFrame, series::Series, types::Value};
 use std::collections::HashMap;
@@ -60,7 +61,7 @@ impl DataFrame {
             new_columns.insert(col_name.clone(), new_series);
         }
 
-        DataFrame::new(new_columns)
+        Ok(DataFrame::new(new_columns)?.carry_constraints(&self.constraints, true))
     }
 
     /// Fills null values in the `DataFrame` with a specified `Value`.
@@ -117,6 +118,59 @@ impl DataFrame {
         DataFrame::new(new_columns)
     }
 
+    /// Fills the nulls of `target` with the values of `source` on the same
+    /// rows, as [`Series::coalesce`] does; `source` is left as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!(
+    ///     "shipping_city" => [None, Some("Oslo")],
+    ///     "billing_city" => [Some("Bergen"), Some("Tromsø")],
+    /// )
+    /// .unwrap();
+    /// let filled = df.fill_null_with_column("shipping_city", "billing_city").unwrap();
+    /// let city = filled.get_column("shipping_city").unwrap();
+    /// assert_eq!(city.get_value(0), Some(Value::String("Bergen".to_string())));
+    /// assert_eq!(city.get_value(1), Some(Value::String("Oslo".to_string())));
+    /// ```
+    pub fn fill_null_with_column(&self, target: &str, source: &str) -> Result<Self, VeloxxError> {
+        let column = |name: &str| {
+            self.get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+        };
+        let filled = column(target)?.coalesce(column(source)?)?;
+        let mut new_columns = self.columns.clone();
+        new_columns.insert(target.to_string(), filled);
+        DataFrame::new(new_columns)
+    }
+
+    /// Replaces values in one column, as [`Series::replace`] does; useful for
+    /// recoding codes to labels or fixing known bad values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("age" => [34, -1, 51]).unwrap();
+    /// let fixed = df.replace("age", &[(Value::I32(-1), Value::Null)]).unwrap();
+    /// assert_eq!(fixed.get_column("age").unwrap().get_value(1), None);
+    /// ```
+    pub fn replace(&self, column: &str, mapping: &[(Value, Value)]) -> Result<Self, VeloxxError> {
+        let replaced = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?
+            .replace(mapping)?;
+        let mut new_columns = self.columns.clone();
+        new_columns.insert(column.to_string(), replaced);
+        DataFrame::new(new_columns)
+    }
+
     /// Interpolates null values in a specific column using linear interpolation.
     ///
     /// This method performs linear interpolation on null values in the specified column.
@@ -156,4 +210,71 @@ impl DataFrame {
         new_columns.insert(column_name.to_string(), interpolated);
         DataFrame::new(new_columns)
     }
+
+    /// Fills the nulls of `column` with [`Series::interpolate`], treating rows
+    /// as evenly spaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::series::interpolate::InterpolationMethod;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("status" => [Some("ok"), None, Some("down")]).unwrap();
+    /// let filled = df
+    ///     .interpolate("status", InterpolationMethod::ForwardFill { limit: None })
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     filled.get_column("status").unwrap().get_value(1),
+    ///     Some(Value::String("ok".to_string()))
+    /// );
+    /// ```
+    pub fn interpolate(
+        &self,
+        column: &str,
+        method: InterpolationMethod,
+    ) -> Result<Self, VeloxxError> {
+        let series = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+        let mut new_columns = self.columns.clone();
+        new_columns.insert(column.to_string(), series.interpolate(method)?);
+        DataFrame::new(new_columns)
+    }
+
+    /// Fills the nulls of `column` with [`Series::interpolate_by`], spacing
+    /// rows by the values of `x_column`, typically a sorted `DateTime` column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::interpolate::InterpolationMethod;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = HashMap::new();
+    /// columns.insert("ts".to_string(), Series::new_datetime("ts", vec![Some(0), Some(30), Some(40)]));
+    /// columns.insert("level".to_string(), Series::new_f64("level", vec![Some(0.0), None, Some(4.0)]));
+    /// let df = DataFrame::new(columns).unwrap();
+    /// let filled = df.interpolate_by("level", InterpolationMethod::Linear, "ts").unwrap();
+    /// assert_eq!(filled.get_column("level").unwrap().get_value(1), Some(Value::F64(3.0)));
+    /// ```
+    pub fn interpolate_by(
+        &self,
+        column: &str,
+        method: InterpolationMethod,
+        x_column: &str,
+    ) -> Result<Self, VeloxxError> {
+        let get = |name: &str| {
+            self.get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+        };
+        let interpolated = get(column)?.interpolate_by(method, get(x_column)?)?;
+        let mut new_columns = self.columns.clone();
+        new_columns.insert(column.to_string(), interpolated);
+        DataFrame::new(new_columns)
+    }
 }
@@ -0,0 +1,232 @@
+//! DataFrames whose cold columns are kept compressed in memory.
+//!
+//! Built with the `compression` feature. [`DataFrame::compress`] encodes each
+//! column in Veloxx's binary column format and compresses it with LZ4 or zstd;
+//! [`CompressedDataFrame::get_column`] decompresses a column when it is first
+//! needed. This trades some CPU on access for a much smaller resident size,
+//! which suits services that keep many frames in memory.
+
+use crate::dataframe::DataFrame;
+use crate::performance::memory::MemoryAnalyzer;
+use crate::series::Series;
+use crate::types::DataType;
+use crate::VeloxxError;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Compression codec for cold columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnCodec {
+    /// LZ4: fast to compress and very fast to decompress
+    #[default]
+    Lz4,
+    /// zstd at the given level (1-22): smaller, slower to decompress; not
+    /// available on WASM
+    #[cfg(not(target_arch = "wasm32"))]
+    Zstd(i32),
+}
+
+/// Options for [`DataFrame::compress`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompressOptions {
+    pub codec: ColumnCodec,
+    /// Columns accessed often enough to be kept uncompressed
+    pub hot_columns: Vec<String>,
+    /// Number of decompressed cold columns kept around for repeated access;
+    /// `0` decompresses on every access
+    pub cache_size: usize,
+}
+
+/// How one column is held
+#[derive(Debug)]
+enum StoredColumn {
+    Plain(Arc<Series>),
+    Compressed {
+        bytes: Vec<u8>,
+        data_type: DataType,
+        /// Estimated size once decompressed
+        uncompressed_size: usize,
+    },
+}
+
+/// A `DataFrame` whose cold columns are stored compressed and decompressed on
+/// access; see [`DataFrame::compress`].
+#[derive(Debug)]
+pub struct CompressedDataFrame {
+    columns: HashMap<String, StoredColumn>,
+    row_count: usize,
+    codec: ColumnCodec,
+    cache_size: usize,
+    /// Recently decompressed columns, oldest first
+    cache: Mutex<VecDeque<(String, Arc<Series>)>>,
+}
+
+impl DataFrame {
+    /// Compresses every column not listed in `options.hot_columns`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::compressed::CompressOptions;
+    /// use veloxx::df;
+    ///
+    /// let df = df!("id" => 0..10_000, "group" => (0..10_000).map(|i| i % 4)).unwrap();
+    /// let compressed = df.compress(&CompressOptions::default()).unwrap();
+    /// assert!(compressed.compressed_size() < compressed.uncompressed_size());
+    /// assert_eq!(compressed.get_column("group").unwrap().len(), 10_000);
+    /// assert_eq!(compressed.decompress().unwrap().get_column("id"), df.get_column("id"));
+    /// ```
+    pub fn compress(&self, options: &CompressOptions) -> Result<CompressedDataFrame, VeloxxError> {
+        if let Some(missing) = options
+            .hot_columns
+            .iter()
+            .find(|name| !self.columns.contains_key(*name))
+        {
+            return Err(VeloxxError::ColumnNotFound(missing.clone()));
+        }
+        let mut columns = HashMap::with_capacity(self.columns.len());
+        for (name, series) in &self.columns {
+            let stored = if options.hot_columns.contains(name) {
+                StoredColumn::Plain(Arc::new(series.clone()))
+            } else {
+                let raw = bincode::encode_to_vec(series, bincode::config::standard())
+                    .map_err(|e| VeloxxError::InvalidOperation(e.to_string()))?;
+                StoredColumn::Compressed {
+                    bytes: compress_bytes(options.codec, &raw)?,
+                    data_type: series.data_type(),
+                    uncompressed_size: MemoryAnalyzer::estimate_series_memory(series),
+                }
+            };
+            columns.insert(name.clone(), stored);
+        }
+        Ok(CompressedDataFrame {
+            columns,
+            row_count: self.row_count,
+            codec: options.codec,
+            cache_size: options.cache_size,
+            cache: Mutex::new(VecDeque::new()),
+        })
+    }
+}
+
+impl CompressedDataFrame {
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn column_names(&self) -> Vec<&String> {
+        self.columns.keys().collect()
+    }
+
+    /// Data type of a column, without decompressing it
+    pub fn column_type(&self, name: &str) -> Option<DataType> {
+        self.columns.get(name).map(|stored| match stored {
+            StoredColumn::Plain(series) => series.data_type(),
+            StoredColumn::Compressed { data_type, .. } => data_type.clone(),
+        })
+    }
+
+    /// Whether a column is held compressed
+    pub fn is_compressed(&self, name: &str) -> bool {
+        matches!(
+            self.columns.get(name),
+            Some(StoredColumn::Compressed { .. })
+        )
+    }
+
+    /// Returns a column, decompressing it if it is stored compressed.
+    pub fn get_column(&self, name: &str) -> Result<Arc<Series>, VeloxxError> {
+        let bytes = match self.columns.get(name) {
+            None => return Err(VeloxxError::ColumnNotFound(name.to_string())),
+            Some(StoredColumn::Plain(series)) => return Ok(Arc::clone(series)),
+            Some(StoredColumn::Compressed { bytes, .. }) => bytes,
+        };
+        if self.cache_size > 0 {
+            let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((_, series)) = cache.iter().find(|(cached, _)| cached == name) {
+                return Ok(Arc::clone(series));
+            }
+        }
+
+        let raw = decompress_bytes(self.codec, bytes)?;
+        let (series, _): (Series, usize) =
+            bincode::decode_from_slice(&raw, bincode::config::standard()).map_err(|e| {
+                VeloxxError::Parsing(format!("Corrupt compressed column '{}': {}", name, e))
+            })?;
+        let series = Arc::new(series);
+
+        if self.cache_size > 0 {
+            let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            if !cache.iter().any(|(cached, _)| cached == name) {
+                if cache.len() == self.cache_size {
+                    cache.pop_front();
+                }
+                cache.push_back((name.to_string(), Arc::clone(&series)));
+            }
+        }
+        Ok(series)
+    }
+
+    /// Decompresses the named columns into a regular `DataFrame`
+    pub fn select(&self, names: &[&str]) -> Result<DataFrame, VeloxxError> {
+        let mut columns = HashMap::with_capacity(names.len());
+        for name in names {
+            columns.insert(name.to_string(), (*self.get_column(name)?).clone());
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Decompresses every column into a regular `DataFrame`
+    pub fn decompress(&self) -> Result<DataFrame, VeloxxError> {
+        let names: Vec<&str> = self.columns.keys().map(String::as_str).collect();
+        self.select(&names)
+    }
+
+    /// Bytes held by the stored columns: compressed payloads plus the
+    /// estimated size of hot columns
+    pub fn compressed_size(&self) -> usize {
+        self.columns
+            .values()
+            .map(|stored| match stored {
+                StoredColumn::Plain(series) => MemoryAnalyzer::estimate_series_memory(series),
+                StoredColumn::Compressed { bytes, .. } => bytes.len(),
+            })
+            .sum()
+    }
+
+    /// Estimated size of the frame once fully decompressed
+    pub fn uncompressed_size(&self) -> usize {
+        self.columns
+            .values()
+            .map(|stored| match stored {
+                StoredColumn::Plain(series) => MemoryAnalyzer::estimate_series_memory(series),
+                StoredColumn::Compressed {
+                    uncompressed_size, ..
+                } => *uncompressed_size,
+            })
+            .sum()
+    }
+}
+
+fn compress_bytes(codec: ColumnCodec, raw: &[u8]) -> Result<Vec<u8>, VeloxxError> {
+    match codec {
+        ColumnCodec::Lz4 => Ok(lz4_flex::compress_prepend_size(raw)),
+        #[cfg(not(target_arch = "wasm32"))]
+        ColumnCodec::Zstd(level) => zstd::bulk::compress(raw, level)
+            .map_err(|e| VeloxxError::InvalidOperation(format!("zstd compression failed: {}", e))),
+    }
+}
+
+fn decompress_bytes(codec: ColumnCodec, bytes: &[u8]) -> Result<Vec<u8>, VeloxxError> {
+    match codec {
+        ColumnCodec::Lz4 => lz4_flex::decompress_size_prepended(bytes)
+            .map_err(|e| VeloxxError::Parsing(format!("LZ4 decompression failed: {}", e))),
+        #[cfg(not(target_arch = "wasm32"))]
+        ColumnCodec::Zstd(_) => zstd::stream::decode_all(bytes)
+            .map_err(|e| VeloxxError::Parsing(format!("zstd decompression failed: {}", e))),
+    }
+}
@@ -0,0 +1,399 @@
+//! Joins on comparisons between a left and a right column, such as
+//! `left.ts BETWEEN right.start AND right.end`, without a cross join.
+//!
+//! Equality predicates hash-partition both frames first. Within a partition,
+//! one inequality sorts the right rows and binary-searches each left value; two
+//! or more run an IEJoin-style sweep: the right rows are ranked by the first
+//! inequality's column and marked in a bitset as the sweep over the second
+//! inequality admits them, so each left row only scans the marked bits in its
+//! range. Any further predicates filter the candidate pairs.
+
+use crate::dataframe::join::{key_cmp, JoinType};
+use crate::dataframe::DataFrame;
+use crate::instrument::instrumented;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Comparison between a left-frame and a right-frame value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl JoinOp {
+    /// Whether `left op right` holds given how `left` compares to `right`
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            JoinOp::Eq => ordering.is_eq(),
+            JoinOp::Ne => ordering.is_ne(),
+            JoinOp::Lt => ordering.is_lt(),
+            JoinOp::Lte => ordering.is_le(),
+            JoinOp::Gt => ordering.is_gt(),
+            JoinOp::Gte => ordering.is_ge(),
+        }
+    }
+
+    fn is_inequality(self) -> bool {
+        matches!(self, JoinOp::Lt | JoinOp::Lte | JoinOp::Gt | JoinOp::Gte)
+    }
+}
+
+/// `left op right`, where `left` names a column of the left frame and
+/// `right` one of the right frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinPredicate {
+    pub left: String,
+    pub op: JoinOp,
+    pub right: String,
+}
+
+impl JoinPredicate {
+    pub fn new(left: &str, op: JoinOp, right: &str) -> Self {
+        Self {
+            left: left.to_string(),
+            op,
+            right: right.to_string(),
+        }
+    }
+
+    /// `left BETWEEN right_start AND right_end`, both ends inclusive
+    pub fn between(left: &str, right_start: &str, right_end: &str) -> [Self; 2] {
+        [
+            Self::new(left, JoinOp::Gte, right_start),
+            Self::new(left, JoinOp::Lte, right_end),
+        ]
+    }
+}
+
+/// A predicate's values, `None` for nulls
+struct Operands {
+    op: JoinOp,
+    left: Vec<Option<Value>>,
+    right: Vec<Option<Value>>,
+}
+
+impl Operands {
+    fn holds(&self, l: usize, r: usize) -> bool {
+        match (&self.left[l], &self.right[r]) {
+            (Some(a), Some(b)) => self.op.holds(key_cmp(a, b)),
+            _ => false,
+        }
+    }
+
+    fn left_value(&self, l: usize) -> &Value {
+        self.left[l]
+            .as_ref()
+            .expect("null rows are never candidates")
+    }
+
+    fn right_value(&self, r: usize) -> &Value {
+        self.right[r]
+            .as_ref()
+            .expect("null rows are never candidates")
+    }
+
+    /// The positions in `sorted`, right rows ascending by this predicate's
+    /// right value, whose rows satisfy the inequality against left row `l`
+    fn range(&self, sorted: &[usize], l: usize) -> Range<usize> {
+        let value = self.left_value(l);
+        let below = |strict: bool| {
+            sorted.partition_point(|&r| {
+                let ordering = key_cmp(self.right_value(r), value);
+                ordering.is_lt() || (!strict && ordering.is_eq())
+            })
+        };
+        match self.op {
+            JoinOp::Lt => below(false)..sorted.len(),
+            JoinOp::Lte => below(true)..sorted.len(),
+            JoinOp::Gt => 0..below(true),
+            JoinOp::Gte => 0..below(false),
+            JoinOp::Eq | JoinOp::Ne => unreachable!("only inequalities select ranges"),
+        }
+    }
+
+    /// Sorts `rows` of one side by this predicate's value on that side
+    fn sort(&self, rows: &mut [usize], left: bool, descending: bool) {
+        rows.sort_by(|&a, &b| {
+            let ordering = if left {
+                key_cmp(self.left_value(a), self.left_value(b))
+            } else {
+                key_cmp(self.right_value(a), self.right_value(b))
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+}
+
+/// Matching `(left, right)` row pairs within one partition, in no particular
+/// order; `inequalities` are the partition-selecting predicates and `residual`
+/// the ones checked pair by pair
+fn match_partition(
+    left_rows: &[usize],
+    right_rows: &[usize],
+    inequalities: &[&Operands],
+    residual: &[&Operands],
+) -> Vec<(usize, usize)> {
+    let keep = |l: usize, r: usize| residual.iter().all(|p| p.holds(l, r));
+    match inequalities {
+        [] => left_rows
+            .par_iter()
+            .flat_map_iter(|&l| {
+                right_rows
+                    .iter()
+                    .filter(move |&&r| keep(l, r))
+                    .map(move |&r| (l, r))
+            })
+            .collect(),
+        [x] => {
+            let mut by_x = right_rows.to_vec();
+            x.sort(&mut by_x, false, false);
+            left_rows
+                .par_iter()
+                .flat_map_iter(|&l| {
+                    by_x[x.range(&by_x, l)]
+                        .iter()
+                        .filter(move |&&r| keep(l, r))
+                        .map(move |&r| (l, r))
+                })
+                .collect()
+        }
+        [x, y, ..] => {
+            let mut by_x = right_rows.to_vec();
+            x.sort(&mut by_x, false, false);
+            // Walking the left rows in this order, the right rows satisfying
+            // `y` only ever grow, so each is marked once
+            let descending = matches!(y.op, JoinOp::Lt | JoinOp::Lte);
+            let mut sweep = left_rows.to_vec();
+            y.sort(&mut sweep, true, descending);
+            let mut by_y = right_rows.to_vec();
+            y.sort(&mut by_y, false, descending);
+            let mut rank = HashMap::with_capacity(by_x.len());
+            for (position, &r) in by_x.iter().enumerate() {
+                rank.insert(r, position);
+            }
+
+            let mut marked = vec![0u64; by_x.len().div_ceil(64)];
+            let mut admitted = 0;
+            let mut pairs = Vec::new();
+            for l in sweep {
+                while admitted < by_y.len() && y.holds(l, by_y[admitted]) {
+                    let position = rank[&by_y[admitted]];
+                    marked[position / 64] |= 1 << (position % 64);
+                    admitted += 1;
+                }
+                let range = x.range(&by_x, l);
+                let mut position = range.start;
+                while position < range.end {
+                    let word = marked[position / 64] >> (position % 64);
+                    if word == 0 {
+                        position = (position / 64 + 1) * 64;
+                        continue;
+                    }
+                    position += word.trailing_zeros() as usize;
+                    if position < range.end && keep(l, by_x[position]) {
+                        pairs.push((l, by_x[position]));
+                    }
+                    position += 1;
+                }
+            }
+            pairs
+        }
+    }
+}
+
+impl DataFrame {
+    /// Joins the rows of this frame and `other` for which every predicate
+    /// holds, e.g. to match events to the time windows containing them.
+    ///
+    /// Predicates compare a column of this frame with a column of `other` of
+    /// the same data type; nulls satisfy none. Rows follow this frame (`other`
+    /// for [`JoinType::Right`]) and multiple matches follow the other frame's
+    /// order, as for [`DataFrame::join`]. All columns of both frames are kept,
+    /// with `_right` appended to right-frame names that clash. Column
+    /// constraints of both frames are checked on the result.
+    ///
+    /// See the [module documentation](crate::dataframe::conditional_join) for
+    /// how matches are found without comparing every pair of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::conditional_join::{JoinOp, JoinPredicate};
+    /// use veloxx::dataframe::join::JoinType;
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let readings = df!("sensor" => [1, 1, 2], "ts" => [5, 15, 7]).unwrap();
+    /// let outages = df!("unit" => [1, 2], "start" => [0, 10], "end" => [9, 20]).unwrap();
+    /// let mut predicates = JoinPredicate::between("ts", "start", "end").to_vec();
+    /// predicates.push(JoinPredicate::new("sensor", JoinOp::Eq, "unit"));
+    ///
+    /// let during = readings.join_where(&outages, &predicates, JoinType::Left).unwrap();
+    /// assert_eq!(during.row_count(), 3);
+    /// assert_eq!(during.get_column("start").unwrap().get_value(0), Some(Value::I32(0)));
+    /// assert_eq!(during.get_column("start").unwrap().get_value(1), None);
+    /// assert_eq!(during.get_column("start").unwrap().get_value(2), None);
+    /// ```
+    pub fn join_where(
+        &self,
+        other: &DataFrame,
+        predicates: &[JoinPredicate],
+        join_type: JoinType,
+    ) -> Result<Self, VeloxxError> {
+        instrumented!(
+            "join_where",
+            {
+                rows_in = self.row_count,
+                rows_right = other.row_count,
+                predicates = predicates.len(),
+                how = ?join_type,
+            },
+            self.join_where_rows(other, predicates, join_type)
+                .and_then(|joined| self.join_constraints(other, joined, None, "_right"))
+        )
+    }
+
+    fn join_where_rows(
+        &self,
+        other: &DataFrame,
+        predicates: &[JoinPredicate],
+        join_type: JoinType,
+    ) -> Result<Self, VeloxxError> {
+        if predicates.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "A conditional join needs at least one predicate.".to_string(),
+            ));
+        }
+        fn column<'a>(
+            df: &'a DataFrame,
+            name: &str,
+            side: &str,
+        ) -> Result<&'a Series, VeloxxError> {
+            df.get_column(name).ok_or_else(|| {
+                VeloxxError::ColumnNotFound(format!(
+                    "Join column '{name}' not found in {side} DataFrame."
+                ))
+            })
+        }
+        let mut operands = Vec::with_capacity(predicates.len());
+        for predicate in predicates {
+            let left = column(self, &predicate.left, "left")?;
+            let right = column(other, &predicate.right, "right")?;
+            if left.data_type() != right.data_type() {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot compare '{}' ({:?}) with '{}' ({:?})",
+                    predicate.left,
+                    left.data_type(),
+                    predicate.right,
+                    right.data_type()
+                )));
+            }
+            operands.push(Operands {
+                op: predicate.op,
+                left: (0..left.len()).map(|i| left.get_value(i)).collect(),
+                right: (0..right.len()).map(|i| right.get_value(i)).collect(),
+            });
+        }
+
+        let (equalities, others): (Vec<&Operands>, Vec<&Operands>) =
+            operands.iter().partition(|p| p.op == JoinOp::Eq);
+        let (mut inequalities, mut residual): (Vec<&Operands>, Vec<&Operands>) =
+            others.into_iter().partition(|p| p.op.is_inequality());
+        residual.extend(inequalities.split_off(inequalities.len().min(2)));
+
+        // Rows with a null operand match nothing; the rest are grouped by
+        // their equality-predicate values
+        let key = |side: fn(&Operands) -> &[Option<Value>], row: usize| {
+            if inequalities
+                .iter()
+                .chain(&residual)
+                .any(|p| side(p)[row].is_none())
+            {
+                return None;
+            }
+            equalities
+                .iter()
+                .map(|p| side(p)[row].clone())
+                .collect::<Option<Vec<Value>>>()
+        };
+        let mut partitions: HashMap<Vec<Value>, (Vec<usize>, Vec<usize>)> = HashMap::new();
+        for l in 0..self.row_count {
+            if let Some(key) = key(|p| &p.left, l) {
+                partitions.entry(key).or_default().0.push(l);
+            }
+        }
+        for r in 0..other.row_count {
+            if let Some(key) = key(|p| &p.right, r) {
+                if let Some(partition) = partitions.get_mut(&key) {
+                    partition.1.push(r);
+                }
+            }
+        }
+
+        let mut matches: Vec<(usize, usize)> = partitions
+            .values()
+            .flat_map(|(left_rows, right_rows)| {
+                match_partition(left_rows, right_rows, &inequalities, &residual)
+            })
+            .collect();
+        let pairs: Vec<(Option<usize>, Option<usize>)> = match join_type {
+            JoinType::Inner => {
+                matches.par_sort_unstable();
+                matches
+                    .into_iter()
+                    .map(|(l, r)| (Some(l), Some(r)))
+                    .collect()
+            }
+            JoinType::Left => {
+                matches.par_sort_unstable();
+                with_unmatched(&matches, self.row_count)
+                    .map(|(l, r)| (Some(l), r))
+                    .collect()
+            }
+            JoinType::Right => {
+                let mut flipped: Vec<(usize, usize)> =
+                    matches.into_iter().map(|(l, r)| (r, l)).collect();
+                flipped.par_sort_unstable();
+                with_unmatched(&flipped, other.row_count)
+                    .map(|(r, l)| (l, Some(r)))
+                    .collect()
+            }
+        };
+        self.combine_rows(other, &pairs, None, "_right")
+    }
+}
+
+/// Every row `0..rows` with its matches from sorted `(row, match)` pairs, or
+/// once with `None` if it has none
+fn with_unmatched(
+    matches: &[(usize, usize)],
+    rows: usize,
+) -> impl Iterator<Item = (usize, Option<usize>)> + '_ {
+    let mut next = 0;
+    (0..rows).flat_map(move |row| {
+        let start = next;
+        while next < matches.len() && matches[next].0 == row {
+            next += 1;
+        }
+        let found = &matches[start..next];
+        let unmatched = found.is_empty().then_some((row, None));
+        found
+            .iter()
+            .map(|&(row, other)| (row, Some(other)))
+            .chain(unmatched)
+    })
+}
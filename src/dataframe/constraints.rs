@@ -0,0 +1,266 @@
+//! Column-level constraints declared on a frame and checked as it changes.
+//!
+//! A frame carries its constraints as metadata: [`DataFrame::with_constraints`]
+//! validates and attaches them, [`DataFrame::append`] re-validates the combined
+//! rows, and [`DataFrame::join_with_options`] and [`DataFrame::join_where`]
+//! re-validate the constraints of both inputs on the joined frame (following
+//! right-frame columns through any suffix). [`DataFrame::upsert`] and
+//! [`DataFrame::update_where`] keep and re-validate this frame's constraints,
+//! and the in-place edits [`DataFrame::set_value`],
+//! [`DataFrame::replace_column`] and [`DataFrame::extend`] undo any change that
+//! breaks one.
+//!
+//! Row filters, sorts, limits, column selections, [`DataFrame::with_column`]
+//! and [`DataFrame::shrink_dtypes`] keep the constraints on the columns they
+//! return. Where rows may be reordered or repeated, as in a sort or
+//! [`DataFrame::filter_by_indices`], `unique` and `monotonic` rules are kept
+//! only if they still hold. Other operations, such as aggregations and
+//! renames, return frames without constraints.
+//!
+//! Checks can be switched off process-wide with [`set_check_constraints`],
+//! e.g. in release pipelines whose inputs were validated upstream; the
+//! constraints are then still attached and carried along, just not verified.
+
+use crate::dataframe::DataFrame;
+use crate::types::Value;
+use crate::VeloxxError;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static CHECK_CONSTRAINTS: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables constraint validation for the whole process.
+pub fn set_check_constraints(enabled: bool) {
+    CHECK_CONSTRAINTS.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether constraints are validated; `true` unless switched off.
+pub fn check_constraints() -> bool {
+    CHECK_CONSTRAINTS.load(Ordering::Relaxed)
+}
+
+/// A rule on the values of a single column
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ColumnConstraint {
+    /// The column has no nulls
+    NonNull(String),
+    /// No two non-null values of the column are equal
+    Unique(String),
+    /// The column's non-null values never decrease from one row to the next
+    Monotonic(String),
+}
+
+/// Requires `column` to have no nulls.
+pub fn non_null(column: &str) -> ColumnConstraint {
+    ColumnConstraint::NonNull(column.to_string())
+}
+
+/// Requires the non-null values of `column` to be distinct.
+pub fn unique(column: &str) -> ColumnConstraint {
+    ColumnConstraint::Unique(column.to_string())
+}
+
+/// Requires the non-null values of `column` to be non-decreasing.
+pub fn monotonic(column: &str) -> ColumnConstraint {
+    ColumnConstraint::Monotonic(column.to_string())
+}
+
+impl ColumnConstraint {
+    /// The constrained column
+    pub fn column(&self) -> &str {
+        match self {
+            ColumnConstraint::NonNull(column)
+            | ColumnConstraint::Unique(column)
+            | ColumnConstraint::Monotonic(column) => column,
+        }
+    }
+
+    /// The same rule on another column
+    fn on(&self, column: String) -> ColumnConstraint {
+        match self {
+            ColumnConstraint::NonNull(_) => ColumnConstraint::NonNull(column),
+            ColumnConstraint::Unique(_) => ColumnConstraint::Unique(column),
+            ColumnConstraint::Monotonic(_) => ColumnConstraint::Monotonic(column),
+        }
+    }
+
+    /// Checks the constraint against `df`, regardless of
+    /// [`check_constraints`].
+    ///
+    /// A missing column gives `VeloxxError::ColumnNotFound`; a violation gives
+    /// `VeloxxError::InvalidOperation` naming the first offending row.
+    pub fn validate(&self, df: &DataFrame) -> Result<(), VeloxxError> {
+        let series = df.get_column(self.column()).ok_or_else(|| {
+            VeloxxError::ColumnNotFound(format!(
+                "Column '{}' of constraint {self} not found.",
+                self.column()
+            ))
+        })?;
+        let violation = |row: usize, what: &str| {
+            Err(VeloxxError::InvalidOperation(format!(
+                "Constraint {self} violated at row {row}: {what}"
+            )))
+        };
+        match self {
+            ColumnConstraint::NonNull(_) => {
+                if let Some(row) = (0..series.len()).find(|&i| series.get_value(i).is_none()) {
+                    return violation(row, "null value");
+                }
+            }
+            ColumnConstraint::Unique(_) => {
+                let mut seen = HashSet::new();
+                for row in 0..series.len() {
+                    if let Some(value) = series.get_value(row) {
+                        if !seen.insert(value.clone()) {
+                            return violation(row, &format!("duplicate value {value}"));
+                        }
+                    }
+                }
+            }
+            ColumnConstraint::Monotonic(_) => {
+                let mut previous: Option<Value> = None;
+                for row in 0..series.len() {
+                    if let Some(value) = series.get_value(row) {
+                        if previous.as_ref().is_some_and(|prev| value < *prev) {
+                            return violation(row, &format!("{value} follows a larger value"));
+                        }
+                        previous = Some(value);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ColumnConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rule = match self {
+            ColumnConstraint::NonNull(_) => "non_null",
+            ColumnConstraint::Unique(_) => "unique",
+            ColumnConstraint::Monotonic(_) => "monotonic",
+        };
+        write!(f, "{rule}(\"{}\")", self.column())
+    }
+}
+
+impl DataFrame {
+    /// Attaches `constraints` to the frame after checking that it satisfies
+    /// them; see the [module documentation](crate::dataframe::constraints) for
+    /// which operations keep and re-check them.
+    ///
+    /// Constraints already on the frame are kept, and declaring one twice has
+    /// no effect. Columns must exist even when checks are switched off.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::constraints::{monotonic, non_null, unique};
+    /// use veloxx::df;
+    ///
+    /// let events = df!("id" => [1, 2], "ts" => [10, 20]).unwrap()
+    ///     .with_constraints(vec![non_null("id"), unique("id"), monotonic("ts")])
+    ///     .unwrap();
+    /// assert_eq!(events.constraints().len(), 3);
+    ///
+    /// // Appending a repeated id is rejected
+    /// let more = df!("id" => [2], "ts" => [30]).unwrap();
+    /// assert!(events.append(&more).is_err());
+    /// ```
+    pub fn with_constraints(
+        mut self,
+        constraints: Vec<ColumnConstraint>,
+    ) -> Result<Self, VeloxxError> {
+        for constraint in constraints {
+            if !self.constraints.contains(&constraint) {
+                self.constraints.push(constraint);
+            }
+        }
+        self.validate_constraints()?;
+        Ok(self)
+    }
+
+    /// The constraints attached to the frame, in declaration order
+    pub fn constraints(&self) -> &[ColumnConstraint] {
+        &self.constraints
+    }
+
+    /// Checks every attached constraint, unless checks are switched off; a
+    /// missing column is an error either way.
+    pub fn validate_constraints(&self) -> Result<(), VeloxxError> {
+        for constraint in &self.constraints {
+            if check_constraints() {
+                constraint.validate(self)?;
+            } else if self.get_column(constraint.column()).is_none() {
+                return Err(VeloxxError::ColumnNotFound(format!(
+                    "Column '{}' of constraint {constraint} not found.",
+                    constraint.column()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the attached constraints on `column` only, for edits that
+    /// changed no other column.
+    pub(crate) fn validate_column_constraints(&self, column: &str) -> Result<(), VeloxxError> {
+        if !check_constraints() {
+            return Ok(());
+        }
+        self.constraints
+            .iter()
+            .filter(|constraint| constraint.column() == column)
+            .try_for_each(|constraint| constraint.validate(self))
+    }
+
+    /// Attaches the `constraints` of the frame this one was built from that
+    /// are on columns it still has, without checking them. With `in_order`
+    /// false the rows may have been reordered or repeated, so `unique` and
+    /// `monotonic` rules are kept only if they still hold, or if checks are
+    /// switched off.
+    pub(crate) fn carry_constraints(
+        mut self,
+        constraints: &[ColumnConstraint],
+        in_order: bool,
+    ) -> DataFrame {
+        self.constraints = constraints
+            .iter()
+            .filter(|constraint| {
+                self.get_column(constraint.column()).is_some()
+                    && (in_order
+                        || matches!(constraint, ColumnConstraint::NonNull(_))
+                        || !check_constraints()
+                        || constraint.validate(&self).is_ok())
+            })
+            .cloned()
+            .collect();
+        self
+    }
+
+    /// Attaches the constraints of both join inputs to `joined`, mapping
+    /// right-frame columns to their names in the result, and checks them;
+    /// `on_column` is the key column merged into one, if any.
+    pub(crate) fn join_constraints(
+        &self,
+        other: &DataFrame,
+        mut joined: DataFrame,
+        on_column: Option<&str>,
+        suffix: &str,
+    ) -> Result<DataFrame, VeloxxError> {
+        if self.constraints.is_empty() && other.constraints.is_empty() {
+            return Ok(joined);
+        }
+        let right = other.constraints.iter().map(|constraint| {
+            let column = constraint.column();
+            if Some(column) != on_column && self.get_column(column).is_some() {
+                constraint.on(format!("{column}{suffix}"))
+            } else {
+                constraint.clone()
+            }
+        });
+        let constraints = self.constraints.iter().cloned().chain(right).collect();
+        joined.constraints = Vec::new();
+        joined.with_constraints(constraints)
+    }
+}
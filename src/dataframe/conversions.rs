@@ -0,0 +1,151 @@
+use crate::dataframe::builder::ColumnElement;
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+impl TryFrom<Vec<HashMap<String, Value>>> for DataFrame {
+    type Error = VeloxxError;
+
+    /// Builds a `DataFrame` from row records keyed by column name.
+    ///
+    /// The column set is the union of all record keys; a key missing from a record
+    /// becomes a null. Each column's type is taken from its non-null values, with
+    /// mixed `I32`/`F64` columns widened to `F64`. Any other mix of types returns
+    /// `VeloxxError::DataTypeMismatch`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::types::Value;
+    /// use std::collections::HashMap;
+    ///
+    /// let rows = vec![
+    ///     HashMap::from([("a".to_string(), Value::I32(1))]),
+    ///     HashMap::from([("a".to_string(), Value::F64(2.5))]),
+    /// ];
+    /// let df = DataFrame::try_from(rows).unwrap();
+    /// assert_eq!(df.get_column("a").unwrap().get_value(1), Some(Value::F64(2.5)));
+    /// ```
+    fn try_from(rows: Vec<HashMap<String, Value>>) -> Result<Self, Self::Error> {
+        let mut names: Vec<String> = Vec::new();
+        let mut types: HashMap<String, Option<DataType>> = HashMap::new();
+        for row in &rows {
+            for (name, value) in row {
+                let entry = types.entry(name.clone()).or_insert_with(|| {
+                    names.push(name.clone());
+                    None
+                });
+                if matches!(value, Value::Null) {
+                    continue;
+                }
+                let value_type = value.data_type();
+                *entry = match (entry.take(), value_type) {
+                    (None, t) => Some(t),
+                    (Some(a), b) if a == b => Some(a),
+                    (Some(DataType::I32), DataType::F64) | (Some(DataType::F64), DataType::I32) => {
+                        Some(DataType::F64)
+                    }
+                    (Some(a), b) => {
+                        return Err(VeloxxError::DataTypeMismatch(format!(
+                            "Column '{}' contains both {:?} and {:?} values",
+                            name, a, b
+                        )))
+                    }
+                };
+            }
+        }
+
+        let mut columns = HashMap::with_capacity(names.len());
+        for name in names {
+            let values = rows.iter().map(|row| row.get(&name));
+            let series = match types[&name].clone().unwrap_or(DataType::String) {
+                DataType::I32 => {
+                    Series::new_i32(&name, values.map(|v| v.and_then(Value::as_i32)).collect())
+                }
+                DataType::F64 => Series::new_f64(
+                    &name,
+                    values
+                        .map(|v| match v {
+                            Some(Value::I32(i)) => Some(*i as f64),
+                            Some(v) => v.as_f64(),
+                            None => None,
+                        })
+                        .collect(),
+                ),
+                DataType::Bool => {
+                    Series::new_bool(&name, values.map(|v| v.and_then(Value::as_bool)).collect())
+                }
+                DataType::String => Series::new_string(
+                    &name,
+                    values
+                        .map(|v| v.and_then(Value::as_string).cloned())
+                        .collect(),
+                ),
+                DataType::DateTime => Series::new_datetime(
+                    &name,
+                    values.map(|v| v.and_then(Value::as_datetime)).collect(),
+                ),
+                DataType::Binary => Series::new_binary(
+                    &name,
+                    values
+                        .map(|v| v.and_then(Value::as_binary).map(<[u8]>::to_vec))
+                        .collect(),
+                ),
+            };
+            columns.insert(name, series);
+        }
+        DataFrame::new(columns)
+    }
+}
+
+impl TryFrom<Vec<(String, Vec<f64>)>> for DataFrame {
+    type Error = VeloxxError;
+
+    /// Builds a `DataFrame` of `F64` columns from `(name, values)` pairs.
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if the columns have different
+    /// lengths or a name is repeated, as [`DataFrame::builder`] does.
+    fn try_from(columns: Vec<(String, Vec<f64>)>) -> Result<Self, Self::Error> {
+        columns
+            .into_iter()
+            .fold(DataFrame::builder(), |builder, (name, values)| {
+                builder.col_f64(&name, values)
+            })
+            .build()
+    }
+}
+
+macro_rules! impl_from_row_tuples {
+    ($(($($ty:ident => $idx:tt),+)),+ $(,)?) => {
+        $(
+            impl<$($ty: ColumnElement),+> FromIterator<($($ty,)+)> for DataFrame {
+                /// Collects row tuples into a `DataFrame` with columns named
+                /// `column_0`, `column_1`, ... in tuple order.
+                fn from_iter<It: IntoIterator<Item = ($($ty,)+)>>(iter: It) -> Self {
+                    let mut values = ($(Vec::<$ty>::new(),)+);
+                    for row in iter {
+                        $(values.$idx.push(row.$idx);)+
+                    }
+                    let mut columns = HashMap::new();
+                    $(
+                        let name = format!("column_{}", $idx);
+                        columns.insert(name.clone(), $ty::into_series(&name, values.$idx));
+                    )+
+                    DataFrame::new(columns).expect("row tuples always produce equal-length columns")
+                }
+            }
+        )+
+    };
+}
+
+impl_from_row_tuples!(
+    (A => 0),
+    (A => 0, B => 1),
+    (A => 0, B => 1, C => 2),
+    (A => 0, B => 1, C => 2, D => 3),
+    (A => 0, B => 1, C => 2, D => 3, E => 4),
+    (A => 0, B => 1, C => 2, D => 3, E => 4, F => 5),
+);
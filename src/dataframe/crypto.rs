@@ -0,0 +1,68 @@
+//! Pseudonymizing columns for export: salted hashes and AES-GCM encryption of
+//! whole columns, built on [`Series::hash_values`], [`Series::encrypt`] and
+//! [`Series::decrypt`].
+
+use crate::dataframe::DataFrame;
+use crate::series::crypto::{EncryptionKey, HashAlgorithm};
+use crate::series::Series;
+use crate::VeloxxError;
+
+impl DataFrame {
+    /// Replaces each of `columns` with its salted hashes; other columns are kept
+    /// as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::series::crypto::HashAlgorithm;
+    ///
+    /// let users = df!("email" => ["a@x.io", "b@x.io", "a@x.io"], "visits" => [3, 1, 2]).unwrap();
+    /// let export = users.hash_columns(&["email"], HashAlgorithm::Sha256, b"pepper").unwrap();
+    /// let email = export.get_column("email").unwrap();
+    /// assert_eq!(email.get_value(0), email.get_value(2));
+    /// assert_ne!(email.get_value(0), email.get_value(1));
+    /// ```
+    pub fn hash_columns(
+        &self,
+        columns: &[&str],
+        algorithm: HashAlgorithm,
+        salt: &[u8],
+    ) -> Result<Self, VeloxxError> {
+        self.map_columns(columns, |series| series.hash_values(algorithm, salt))
+    }
+
+    /// Replaces each of `columns` with its encrypted `Binary` envelopes.
+    pub fn encrypt_columns(
+        &self,
+        columns: &[&str],
+        key: &EncryptionKey,
+    ) -> Result<Self, VeloxxError> {
+        self.map_columns(columns, |series| series.encrypt(key))
+    }
+
+    /// Decrypts each of `columns`, as encrypted by [`DataFrame::encrypt_columns`],
+    /// back to its original type.
+    pub fn decrypt_columns(
+        &self,
+        columns: &[&str],
+        key: &EncryptionKey,
+    ) -> Result<Self, VeloxxError> {
+        self.map_columns(columns, |series| series.decrypt(key))
+    }
+
+    fn map_columns(
+        &self,
+        columns: &[&str],
+        f: impl Fn(&Series) -> Result<Series, VeloxxError>,
+    ) -> Result<Self, VeloxxError> {
+        let mut new_columns = self.columns.clone();
+        for &column in columns {
+            let series = self
+                .get_column(column)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+            new_columns.insert(column.to_string(), f(series)?);
+        }
+        DataFrame::new(new_columns)
+    }
+}
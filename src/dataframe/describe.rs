@@ -0,0 +1,190 @@
+//! Summary statistics for a whole `DataFrame`.
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::VeloxxError;
+use std::collections::{BTreeMap, HashMap};
+
+/// Name of the column holding the statistic names in [`DataFrame::describe`] output
+pub const STATISTIC_COLUMN: &str = "statistic";
+
+/// Options for [`DataFrame::describe_with_options`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribeOptions {
+    /// Percentiles to report as fractions in `[0, 1]`, labelled like `"25%"`
+    pub percentiles: Vec<f64>,
+}
+
+impl Default for DescribeOptions {
+    fn default() -> Self {
+        Self {
+            percentiles: vec![0.25, 0.5, 0.75],
+        }
+    }
+}
+
+impl DataFrame {
+    /// Summarizes every column with the 25th, 50th and 75th percentiles; see
+    /// [`DataFrame::describe_with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("age" => [Some(20), Some(30), Some(25), None, Some(35)]).unwrap();
+    /// let summary = df.describe().unwrap();
+    /// let statistics = summary.get_column("statistic").unwrap();
+    /// assert_eq!(statistics.get_value(5), Some(Value::String("25%".to_string())));
+    ///
+    /// let age = summary.get_column("age").unwrap();
+    /// assert_eq!(age.get_value(0), Some(Value::F64(4.0))); // count
+    /// assert_eq!(age.get_value(1), Some(Value::F64(1.0))); // null_count
+    /// assert_eq!(age.get_value(6), Some(Value::F64(27.5))); // 50%
+    /// ```
+    pub fn describe(&self) -> Result<DataFrame, VeloxxError> {
+        self.describe_with_options(&DescribeOptions::default())
+    }
+
+    /// Generates descriptive statistics with one row per statistic and one
+    /// `F64` column per column of this frame, plus a `statistic` column naming
+    /// the rows: `count` and `null_count`, then `mean`, `std`, `min`, each of
+    /// `options.percentiles` and `max`.
+    ///
+    /// Counts are given for every column. The other statistics cover `I32`,
+    /// `F64` and `DateTime` (as epoch values) columns and are null for other
+    /// types or columns without values. `std` is the sample standard deviation
+    /// and percentiles interpolate linearly between the closest values. See
+    /// [`DataFrame::dtype_summary`] for a breakdown by data type.
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if a percentile is outside
+    /// `[0, 1]` or a column is already named `statistic`.
+    pub fn describe_with_options(
+        &self,
+        options: &DescribeOptions,
+    ) -> Result<DataFrame, VeloxxError> {
+        if let Some(p) = options
+            .percentiles
+            .iter()
+            .find(|p| !(0.0..=1.0).contains(*p))
+        {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Percentile {p} must be between 0 and 1"
+            )));
+        }
+        if self.columns.contains_key(STATISTIC_COLUMN) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Cannot describe a DataFrame with a '{STATISTIC_COLUMN}' column"
+            )));
+        }
+
+        let mut labels: Vec<String> = ["count", "null_count", "mean", "std", "min"]
+            .map(String::from)
+            .to_vec();
+        labels.extend(options.percentiles.iter().map(|p| {
+            // Rounded so that e.g. 0.999 is labelled "99.9%"
+            format!("{}%", (p * 100.0 * 1e6).round() / 1e6)
+        }));
+        labels.push("max".to_string());
+
+        let mut columns: HashMap<String, Series> = HashMap::with_capacity(self.columns.len() + 1);
+        for (name, series) in &self.columns {
+            let mut values: Vec<f64> = match series {
+                Series::I32(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .filter(|(_, valid)| **valid)
+                    .map(|(v, _)| *v as f64)
+                    .collect(),
+                Series::F64(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .filter(|(v, valid)| **valid && !v.is_nan())
+                    .map(|(v, _)| *v)
+                    .collect(),
+                Series::DateTime(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .filter(|(_, valid)| **valid)
+                    .map(|(v, _)| *v as f64)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let null_count = series.len() - series.count();
+            let mut stats = vec![Some(series.count() as f64), Some(null_count as f64)];
+            if values.is_empty() {
+                stats.resize(labels.len(), None);
+            } else {
+                values.sort_by(f64::total_cmp);
+                let n = values.len() as f64;
+                let mean = values.iter().sum::<f64>() / n;
+                let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+                stats.push(Some(mean));
+                stats.push((values.len() > 1).then(|| variance.sqrt()));
+                stats.push(values.first().copied());
+                stats.extend(
+                    options
+                        .percentiles
+                        .iter()
+                        .map(|&p| Some(interpolated_percentile(&values, p))),
+                );
+                stats.push(values.last().copied());
+            }
+            columns.insert(name.clone(), Series::new_f64(name, stats));
+        }
+        columns.insert(
+            STATISTIC_COLUMN.to_string(),
+            Series::new_string(STATISTIC_COLUMN, labels.into_iter().map(Some).collect()),
+        );
+        DataFrame::new(columns)
+    }
+
+    /// Counts columns and null values per data type, one row per type present,
+    /// with columns `dtype` (such as `"I32"`), `columns` and `null_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("a" => [1, 2], "b" => [Some(1.5), None], "c" => [3.0, 4.0]).unwrap();
+    /// let dtypes = df.dtype_summary().unwrap();
+    /// assert_eq!(dtypes.get_column("dtype").unwrap().get_value(0), Some(Value::String("F64".to_string())));
+    /// assert_eq!(dtypes.get_column("columns").unwrap().get_value(0), Some(Value::I32(2)));
+    /// assert_eq!(dtypes.get_column("null_count").unwrap().get_value(0), Some(Value::I32(1)));
+    /// ```
+    pub fn dtype_summary(&self) -> Result<DataFrame, VeloxxError> {
+        let mut by_type: BTreeMap<String, (i32, i32)> = BTreeMap::new();
+        for series in self.columns.values() {
+            let entry = by_type
+                .entry(format!("{:?}", series.data_type()))
+                .or_default();
+            entry.0 += 1;
+            entry.1 += (series.len() - series.count()) as i32;
+        }
+        let mut columns = HashMap::new();
+        columns.insert(
+            "dtype".to_string(),
+            Series::new_string("dtype", by_type.keys().cloned().map(Some).collect()),
+        );
+        columns.insert(
+            "columns".to_string(),
+            Series::new_i32("columns", by_type.values().map(|v| Some(v.0)).collect()),
+        );
+        columns.insert(
+            "null_count".to_string(),
+            Series::new_i32("null_count", by_type.values().map(|v| Some(v.1)).collect()),
+        );
+        DataFrame::new(columns)
+    }
+}
+
+/// Percentile `p` of ascending `sorted` values, interpolating linearly
+/// between the two closest ranks
+fn interpolated_percentile(sorted: &[f64], p: f64) -> f64 {
+    let position = p * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
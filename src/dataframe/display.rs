@@ -1,5 +1,6 @@
 use crate::dataframe::DataFrame;
 use crate::series::Series;
+use crate::types::hex_string;
 use std::fmt;
 
 /// Implements the `Display` trait for `DataFrame`.
@@ -66,6 +67,9 @@ impl fmt::Display for DataFrame {
                     Series::DateTime(_, v, _) => {
                         v.get(i).map_or("null".to_string(), |t| t.to_string())
                     }
+                    Series::Binary(_, v, _) => {
+                        v.get(i).map_or("null".to_string(), |b| hex_string(b))
+                    }
                 };
                 write!(f, "{value_str: <15}")?;
             }
@@ -74,3 +78,116 @@ impl fmt::Display for DataFrame {
         Ok(())
     }
 }
+
+const HTML_STYLE: &str = "<style>\
+.veloxx-dataframe table{border-collapse:collapse;font-family:monospace;font-size:12px}\
+.veloxx-dataframe th,.veloxx-dataframe td{padding:2px 8px;text-align:right;border-bottom:1px solid #ddd}\
+.veloxx-dataframe thead th{background:#f5f5f5}\
+.veloxx-dataframe tr.dtype th{font-weight:normal;font-style:italic;color:#888}\
+.veloxx-dataframe td.null{color:#aaa}\
+</style>";
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Splits `0..len` into a leading and trailing run of at most `limit` items in total,
+/// returning `None` for the gap when nothing is elided.
+fn truncated_range(len: usize, limit: usize) -> (Vec<usize>, Option<Vec<usize>>) {
+    if len <= limit {
+        return ((0..len).collect(), None);
+    }
+    let head = limit.div_ceil(2);
+    let tail = limit / 2;
+    ((0..head).collect(), Some((len - tail..len).collect()))
+}
+
+impl DataFrame {
+    /// Renders the `DataFrame` as a styled HTML table, as used by notebook front-ends.
+    ///
+    /// Columns are sorted by name and a second header row shows each column's type.
+    /// When there are more than `max_rows` rows or `max_cols` columns, the middle
+    /// ones are replaced by an ellipsis row or column. Cell contents are HTML-escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let df = df!("x" => [1, 2, 3, 4]).unwrap();
+    /// let html = df.to_html(2, 10);
+    /// assert!(html.contains("<td>1</td>"));
+    /// assert!(html.contains("4 rows × 1 columns"));
+    /// ```
+    pub fn to_html(&self, max_rows: usize, max_cols: usize) -> String {
+        let mut names: Vec<&String> = self.columns.keys().collect();
+        names.sort_unstable();
+        let (head_cols, tail_cols) = truncated_range(names.len(), max_cols);
+        let (head_rows, tail_rows) = truncated_range(self.row_count, max_rows);
+
+        let header_cells = |cell: &dyn Fn(&Series) -> String| {
+            let mut html = String::new();
+            for &i in &head_cols {
+                html.push_str(&format!("<th>{}</th>", cell(&self.columns[names[i]])));
+            }
+            if let Some(tail_cols) = &tail_cols {
+                html.push_str("<th>…</th>");
+                for &i in tail_cols {
+                    html.push_str(&format!("<th>{}</th>", cell(&self.columns[names[i]])));
+                }
+            }
+            html
+        };
+        let row_html = |row: usize| {
+            let cell = |i: usize| match self.columns[names[i]].get_value(row) {
+                Some(value) => format!("<td>{}</td>", escape_html(&value.to_string())),
+                None => "<td class=\"null\">null</td>".to_string(),
+            };
+            let mut html = format!("<tr><th>{}</th>", row);
+            html.extend(head_cols.iter().map(|&i| cell(i)));
+            if let Some(tail_cols) = &tail_cols {
+                html.push_str("<td>…</td>");
+                html.extend(tail_cols.iter().map(|&i| cell(i)));
+            }
+            html.push_str("</tr>");
+            html
+        };
+
+        let mut html = String::from("<div class=\"veloxx-dataframe\">");
+        html.push_str(HTML_STYLE);
+        html.push_str("<table><thead><tr><th></th>");
+        html.push_str(&header_cells(&|s| escape_html(s.name())));
+        html.push_str("</tr><tr class=\"dtype\"><th></th>");
+        html.push_str(&header_cells(&|s| format!("{:?}", s.data_type())));
+        html.push_str("</tr></thead><tbody>");
+        for &row in &head_rows {
+            html.push_str(&row_html(row));
+        }
+        if let Some(tail_rows) = &tail_rows {
+            let width = head_cols.len() + tail_cols.as_ref().map_or(0, |c| c.len() + 1);
+            html.push_str("<tr><th>…</th>");
+            html.push_str(&"<td>…</td>".repeat(width));
+            html.push_str("</tr>");
+            for &row in tail_rows {
+                html.push_str(&row_html(row));
+            }
+        }
+        html.push_str(&format!(
+            "</tbody></table><p>{} rows × {} columns</p></div>",
+            self.row_count,
+            names.len()
+        ));
+        html
+    }
+}
@@ -0,0 +1,165 @@
+//! Turning list-valued cells into one row per element.
+//!
+//! Columns have no list type, so lists are `String` cells whose elements are
+//! separated by a delimiter, as produced by CSV exports or by flattening nested
+//! API responses into text.
+
+use crate::dataframe::join::take_optional;
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// Options for [`DataFrame::explode_with_options`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplodeOptions {
+    /// Separator between the elements of a cell
+    pub delimiter: String,
+    /// Name of an `I32` column to add holding each row's original row number
+    pub index_column: Option<String>,
+}
+
+impl Default for ExplodeOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ",".to_string(),
+            index_column: None,
+        }
+    }
+}
+
+impl DataFrame {
+    /// Splits the comma-separated lists in `columns` into one row per element,
+    /// repeating the other columns; see [`DataFrame::explode_with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let orders = df!("order" => [1, 2], "items" => ["pen,ink", "pad"]).unwrap();
+    /// let lines = orders.explode(&["items"]).unwrap();
+    /// assert_eq!(lines.row_count(), 3);
+    /// assert_eq!(lines.get_column("order").unwrap().get_value(1), Some(Value::I32(1)));
+    /// assert_eq!(
+    ///     lines.get_column("items").unwrap().get_value(1),
+    ///     Some(Value::String("ink".to_string()))
+    /// );
+    /// ```
+    pub fn explode(&self, columns: &[&str]) -> Result<Self, VeloxxError> {
+        self.explode_with_options(columns, &ExplodeOptions::default())
+    }
+
+    /// Splits the delimited lists in `columns` into one row per element,
+    /// repeating the other columns' values; rows keep their order.
+    ///
+    /// Several columns explode in lockstep: the `n`th element of each lands in
+    /// the same output row, so their lists must have equal lengths within a
+    /// row. A null or empty cell is an empty list and is padded with nulls to
+    /// its row's length; a row whose lists are all empty yields one row of
+    /// nulls. The exploded columns must be `String` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::explode::ExplodeOptions;
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let users = df!(
+    ///     "user" => ["ann", "bob"],
+    ///     "tags" => ["admin|dev", "ops"],
+    ///     "since" => ["2020|2022", "2021"],
+    /// )
+    /// .unwrap();
+    /// let options = ExplodeOptions {
+    ///     delimiter: "|".to_string(),
+    ///     index_column: Some("row".to_string()),
+    /// };
+    /// let tags = users.explode_with_options(&["tags", "since"], &options).unwrap();
+    /// assert_eq!(tags.row_count(), 3);
+    /// assert_eq!(
+    ///     tags.get_column("since").unwrap().get_value(1),
+    ///     Some(Value::String("2022".to_string()))
+    /// );
+    /// assert_eq!(tags.get_column("row").unwrap().get_value(2), Some(Value::I32(1)));
+    /// ```
+    pub fn explode_with_options(
+        &self,
+        columns: &[&str],
+        options: &ExplodeOptions,
+    ) -> Result<Self, VeloxxError> {
+        if options.delimiter.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "Explode delimiter must not be empty.".to_string(),
+            ));
+        }
+        if let Some(index) = &options.index_column {
+            if self.columns.contains_key(index) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Index column '{index}' already exists."
+                )));
+            }
+        }
+        let mut lists: Vec<(&str, &[String], &[bool])> = Vec::with_capacity(columns.len());
+        for &name in columns {
+            match self.get_column(name) {
+                Some(Series::String(_, values, validity)) => lists.push((name, values, validity)),
+                Some(other) => {
+                    return Err(VeloxxError::DataTypeMismatch(format!(
+                        "Cannot explode column '{name}' of type {:?}; expected String.",
+                        other.data_type()
+                    )))
+                }
+                None => return Err(VeloxxError::ColumnNotFound(name.to_string())),
+            }
+        }
+
+        let mut rows: Vec<Option<usize>> = Vec::with_capacity(self.row_count);
+        let mut elements: Vec<Vec<Option<String>>> = vec![Vec::new(); lists.len()];
+        for row in 0..self.row_count {
+            let split: Vec<Vec<&str>> = lists
+                .iter()
+                .map(|(_, values, validity)| {
+                    if validity[row] && !values[row].is_empty() {
+                        values[row].split(options.delimiter.as_str()).collect()
+                    } else {
+                        Vec::new()
+                    }
+                })
+                .collect();
+            let length = split.iter().map(Vec::len).max().unwrap_or(0);
+            if let Some(column) = lists
+                .iter()
+                .zip(&split)
+                .find(|(_, parts)| !parts.is_empty() && parts.len() != length)
+                .map(|((name, _, _), _)| name)
+            {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Cannot explode row {row}: column '{column}' has a different number of elements."
+                )));
+            }
+            let length = length.max(1);
+            rows.extend(std::iter::repeat_n(Some(row), length));
+            for (parts, out) in split.iter().zip(&mut elements) {
+                out.extend((0..length).map(|i| parts.get(i).map(|part| part.to_string())));
+            }
+        }
+
+        let mut new_columns: HashMap<String, Series> = self
+            .columns
+            .iter()
+            .filter(|(name, _)| !columns.contains(&name.as_str()))
+            .map(|(name, series)| (name.clone(), take_optional(series, &rows)))
+            .collect();
+        for ((name, _, _), values) in lists.iter().zip(elements) {
+            new_columns.insert(name.to_string(), Series::new_string(name, values));
+        }
+        if let Some(index) = &options.index_column {
+            let ids = rows.iter().map(|row| row.map(|row| row as i32)).collect();
+            new_columns.insert(index.clone(), Series::new_i32(index, ids));
+        }
+        DataFrame::new(new_columns)
+    }
+}
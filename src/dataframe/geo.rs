@@ -0,0 +1,189 @@
+//! Basic geo analytics over paired latitude/longitude columns.
+//!
+//! Points are kept as two numeric columns in decimal degrees rather than a
+//! dedicated column type. Distances use the haversine formula on a spherical
+//! Earth, which is accurate to about 0.5% — fine for "within 5 km" questions,
+//! not for surveying.
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::VeloxxError;
+
+/// Mean Earth radius in kilometres
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// A location in decimal degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl GeoPoint {
+    /// Creates a point, checking that latitude is within ±90 and longitude
+    /// within ±180 degrees.
+    pub fn new(lat: f64, lon: f64) -> Result<Self, VeloxxError> {
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Invalid coordinates ({lat}, {lon}); latitude must be within ±90 and longitude within ±180"
+            )));
+        }
+        Ok(GeoPoint { lat, lon })
+    }
+
+    /// Great-circle distance to `other` in kilometres.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::geo::GeoPoint;
+    ///
+    /// let paris = GeoPoint::new(48.8566, 2.3522).unwrap();
+    /// let london = GeoPoint::new(51.5074, -0.1278).unwrap();
+    /// assert!((paris.distance_km(&london) - 343.5).abs() < 1.0);
+    /// ```
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        haversine_km(self.lat, self.lon, other.lat, other.lon)
+    }
+}
+
+/// A latitude/longitude rectangle.
+///
+/// A box whose `min_lon` is greater than its `max_lon` wraps across the
+/// antimeridian, e.g. `min_lon: 170.0, max_lon: -170.0` covers Fiji.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub min_lon: f64,
+    pub max_lat: f64,
+    pub max_lon: f64,
+}
+
+impl BoundingBox {
+    /// Whether the point lies inside the box, edges included
+    pub fn contains(&self, lat: f64, lon: f64) -> bool {
+        let lon_inside = if self.min_lon <= self.max_lon {
+            (self.min_lon..=self.max_lon).contains(&lon)
+        } else {
+            lon >= self.min_lon || lon <= self.max_lon
+        };
+        (self.min_lat..=self.max_lat).contains(&lat) && lon_inside
+    }
+}
+
+/// Great-circle distance between two points given in decimal degrees, in kilometres.
+pub fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+impl DataFrame {
+    /// Returns an `F64` series named `distance_km` with the distance from each
+    /// row's point to `center`; rows with a null coordinate are null.
+    pub fn geo_distance_km(
+        &self,
+        lat_column: &str,
+        lon_column: &str,
+        center: &GeoPoint,
+    ) -> Result<Series, VeloxxError> {
+        let distances = self
+            .coordinates(lat_column, lon_column)?
+            .into_iter()
+            .map(|point| point.map(|(lat, lon)| haversine_km(lat, lon, center.lat, center.lon)))
+            .collect();
+        Ok(Series::new_f64("distance_km", distances))
+    }
+
+    /// Returns a `Bool` mask that is `true` for rows within `radius_km` of
+    /// `center`, for use with [`DataFrame::filter_mask`]. Rows with a null
+    /// coordinate are null.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::geo::GeoPoint;
+    /// use veloxx::df;
+    ///
+    /// let stops = df!(
+    ///     "name" => ["Louvre", "Eiffel Tower", "Versailles"],
+    ///     "lat" => [48.8606, 48.8584, 48.8049],
+    ///     "lon" => [2.3376, 2.2945, 2.1204],
+    /// )
+    /// .unwrap();
+    /// let center = GeoPoint::new(48.8566, 2.3522).unwrap();
+    /// let mask = stops.within_radius("lat", "lon", &center, 5.0).unwrap();
+    /// assert_eq!(stops.filter_mask(&mask).unwrap().row_count(), 2);
+    /// ```
+    pub fn within_radius(
+        &self,
+        lat_column: &str,
+        lon_column: &str,
+        center: &GeoPoint,
+        radius_km: f64,
+    ) -> Result<Series, VeloxxError> {
+        let inside = self
+            .coordinates(lat_column, lon_column)?
+            .into_iter()
+            .map(|point| {
+                point.map(|(lat, lon)| haversine_km(lat, lon, center.lat, center.lon) <= radius_km)
+            })
+            .collect();
+        Ok(Series::new_bool("within_radius", inside))
+    }
+
+    /// Returns a `Bool` mask that is `true` for rows inside `bounds`, for use
+    /// with [`DataFrame::filter_mask`]. Rows with a null coordinate are null.
+    pub fn in_bounding_box(
+        &self,
+        lat_column: &str,
+        lon_column: &str,
+        bounds: &BoundingBox,
+    ) -> Result<Series, VeloxxError> {
+        let inside = self
+            .coordinates(lat_column, lon_column)?
+            .into_iter()
+            .map(|point| point.map(|(lat, lon)| bounds.contains(lat, lon)))
+            .collect();
+        Ok(Series::new_bool("in_bounding_box", inside))
+    }
+
+    /// Reads a pair of numeric columns as points; a null in either is a null point
+    fn coordinates(
+        &self,
+        lat_column: &str,
+        lon_column: &str,
+    ) -> Result<Vec<Option<(f64, f64)>>, VeloxxError> {
+        let degrees = |name: &str| -> Result<Vec<Option<f64>>, VeloxxError> {
+            let series = self
+                .get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))?;
+            match series {
+                Series::F64(_, values, validity) => Ok(values
+                    .iter()
+                    .zip(validity)
+                    .map(|(&v, &valid)| valid.then_some(v))
+                    .collect()),
+                Series::I32(_, values, validity) => Ok(values
+                    .iter()
+                    .zip(validity)
+                    .map(|(&v, &valid)| valid.then_some(v as f64))
+                    .collect()),
+                _ => Err(VeloxxError::DataTypeMismatch(format!(
+                    "Coordinate column '{}' must be numeric, got {:?}",
+                    name,
+                    series.data_type()
+                ))),
+            }
+        };
+        let lats = degrees(lat_column)?;
+        let lons = degrees(lon_column)?;
+        Ok(lats
+            .into_iter()
+            .zip(lons)
+            .map(|(lat, lon)| lat.zip(lon))
+            .collect())
+    }
+}
@@ -84,12 +84,14 @@ fn simd_max_f64(values: &[f64]) -> f64 {
         .max_by(|a, b| a.partial_cmp(b).unwrap())
         .unwrap_or(&0.0)
 }
+use crate::instrument::instrumented;
 #[cfg(all(feature = "simd", not(target_arch = "wasm32")))]
 use crate::performance::simd_eq_str;
 #[cfg(not(all(feature = "simd", not(target_arch = "wasm32"))))]
 use crate::performance::simd_string::simd_eq_str;
 use crate::{dataframe::DataFrame, series::Series, types::Value, VeloxxError};
 // use bincode::{config, decode_from_slice, encode_to_vec};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 // Helper struct to reduce argument count for dense groupby
@@ -108,9 +110,14 @@ struct DenseGroupByParams<'a> {
 /// Represents a `DataFrame` that has been grouped by one or more columns.
 ///
 /// This struct is typically created by calling the `group_by` method on a `DataFrame`.
-/// It holds a reference to the original `DataFrame`, the columns used for grouping,
+/// It holds the original `DataFrame` (or, for [`DataFrame::group_by_exprs`], a
+/// frame with the computed keys), the columns used for grouping,
 /// and an internal map that stores the row indices belonging to each unique group.
 ///
+/// Groups come out in hash order, which can change between runs, unless
+/// [`crate::dataframe::set_deterministic`] or [`GroupedDataFrame::deterministic`]
+/// orders them by key.
+///
 /// # Examples
 ///
 /// ```rust
@@ -127,11 +134,13 @@ struct DenseGroupByParams<'a> {
 /// // Now `grouped_df` can be used to perform aggregations.
 /// ```
 pub struct GroupedDataFrame<'a> {
-    dataframe: &'a DataFrame,
+    dataframe: Cow<'a, DataFrame>,
     group_columns: Vec<String>,
     // Use contiguous Vecs for group storage for cache locality
     group_keys: Vec<Vec<String>>,   // direct keys
     group_indices: Vec<Vec<usize>>, // row indices for each group
+    // Groups are ordered by key and aggregations keep that order
+    deterministic: bool,
 }
 
 impl<'a> GroupedDataFrame<'a> {
@@ -185,7 +194,52 @@ impl<'a> GroupedDataFrame<'a> {
     /// // The `grouped_df` now holds the grouped structure.
     /// ```
     pub fn new(dataframe: &'a DataFrame, group_columns: Vec<String>) -> Result<Self, VeloxxError> {
+        Self::group(Cow::Borrowed(dataframe), group_columns)
+    }
+
+    /// Like [`GroupedDataFrame::new`], but owning the grouped frame
+    pub(crate) fn from_owned(
+        dataframe: DataFrame,
+        group_columns: Vec<String>,
+    ) -> Result<Self, VeloxxError> {
+        Self::group(Cow::Owned(dataframe), group_columns)
+    }
+
+    fn group(
+        dataframe: Cow<'a, DataFrame>,
+        group_columns: Vec<String>,
+    ) -> Result<Self, VeloxxError> {
         use rayon::prelude::*;
+        // A run-length compact key (e.g. a sorted one) is grouped a run at a time
+        if let [column] = group_columns.as_slice() {
+            if let Some(runs) = dataframe
+                .get_column(column)
+                .and_then(Series::rle_if_compact)
+            {
+                let is_string = runs.data_type() == crate::types::DataType::String;
+                let (group_keys, group_indices) = runs
+                    .group_ranges()
+                    .into_iter()
+                    .map(|(value, ranges)| {
+                        let key = match value {
+                            Some(Value::String(s)) => s,
+                            None if is_string => "<NULL>".to_string(),
+                            value => format!("{:?}", value.unwrap_or(Value::Null)),
+                        };
+                        (vec![key], ranges.into_iter().flatten().collect())
+                    })
+                    .unzip();
+                return Ok(GroupedDataFrame {
+                    dataframe,
+                    group_columns,
+                    group_keys,
+                    group_indices,
+                    deterministic: false,
+                }
+                .deterministic(crate::dataframe::deterministic()));
+            }
+        }
+
         let row_count = dataframe.row_count();
         // Use direct key representation for string/categorical columns
         let key_row_pairs: Vec<(Vec<String>, usize)> = (0..row_count)
@@ -245,7 +299,68 @@ impl<'a> GroupedDataFrame<'a> {
             group_columns,
             group_keys,
             group_indices,
-        })
+            deterministic: false,
+        }
+        .deterministic(crate::dataframe::deterministic()))
+    }
+
+    /// Row indices of each group, in group order
+    pub(crate) fn group_rows(&self) -> &[Vec<usize>] {
+        &self.group_indices
+    }
+
+    /// Orders the groups by their key values (nulls first, as in
+    /// [`Value`]'s ordering) when `enabled`, so [`GroupedDataFrame::agg`] and the
+    /// other aggregations return rows in the same order on every run.
+    ///
+    /// Sorting the groups and skipping the hash-ordered fast path for single
+    /// `sum` aggregations makes this slower than the default; set
+    /// [`crate::dataframe::set_deterministic`] to enable it for every group-by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("city" => ["Oslo", "Paris", "Lima", "Oslo"], "sales" => [1, 2, 3, 4]).unwrap();
+    /// let totals = df
+    ///     .group_by(vec!["city".to_string()])
+    ///     .unwrap()
+    ///     .deterministic(true)
+    ///     .agg(vec![("sales", "sum")])
+    ///     .unwrap();
+    /// let cities = totals.get_column("city").unwrap();
+    /// assert_eq!(cities.get_value(0), Some(Value::String("Lima".to_string())));
+    /// assert_eq!(cities.get_value(2), Some(Value::String("Paris".to_string())));
+    /// ```
+    pub fn deterministic(mut self, enabled: bool) -> Self {
+        if enabled && !self.deterministic {
+            let keys: Vec<Vec<Value>> = self
+                .group_indices
+                .iter()
+                .map(|rows| {
+                    self.group_columns
+                        .iter()
+                        .map(|column| {
+                            self.dataframe
+                                .get_column(column)
+                                .and_then(|series| series.get_value(rows[0]))
+                                .unwrap_or(Value::Null)
+                        })
+                        .collect()
+                })
+                .collect();
+            let mut order: Vec<usize> = (0..keys.len()).collect();
+            order.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+            self.group_keys = order.iter().map(|&i| self.group_keys[i].clone()).collect();
+            self.group_indices = order
+                .iter()
+                .map(|&i| std::mem::take(&mut self.group_indices[i]))
+                .collect();
+        }
+        self.deterministic = enabled;
+        self
     }
 
     /// Performs aggregation operations on the grouped data.
@@ -299,15 +414,31 @@ impl<'a> GroupedDataFrame<'a> {
     /// // New York       300.00          15.00          2              
     /// ```
     pub fn agg(&self, aggregations: Vec<(&str, &str)>) -> Result<DataFrame, VeloxxError> {
-        // Try the super-fast path that avoids GroupedDataFrame creation entirely
-        // This should only be reached if we're already in a GroupedDataFrame, which means
-        // the expensive setup already happened. In that case, use our existing fast path.
-        if let Some(fast_result) = self.try_fast_groupby_sum(&aggregations)? {
-            return Ok(fast_result);
-        }
-
-        // Fallback to the original complex implementation
-        self.agg_fallback(aggregations)
+        instrumented!(
+            "group_by",
+            {
+                rows_in = self.dataframe.row_count,
+                groups = self.group_keys.len(),
+                keys = ?self.group_columns,
+                aggregations = ?aggregations,
+            },
+            {
+                // Try the super-fast path that avoids GroupedDataFrame creation entirely
+                // This should only be reached if we're already in a GroupedDataFrame, which means
+                // the expensive setup already happened. In that case, use our existing fast path.
+                // Its hash-based variant ignores the group order, so deterministic
+                // grouping skips it
+                let fast_result = match self.deterministic {
+                    true => None,
+                    false => self.try_fast_groupby_sum(&aggregations)?,
+                };
+                match fast_result {
+                    Some(fast_result) => Ok(fast_result),
+                    // Fallback to the original complex implementation
+                    None => self.agg_fallback(aggregations),
+                }
+            }
+        )
     }
 
     /// Attempts to use high-performance vectorized groupby for simple sum operations
@@ -352,6 +483,7 @@ impl<'a> GroupedDataFrame<'a> {
                 Ok(Some(DataFrame {
                     columns: result_columns,
                     row_count,
+                    constraints: Vec::new(),
                 }))
             }
             Err(_) => Ok(None), // Fall back to regular implementation
@@ -650,22 +782,13 @@ impl<'a> GroupedDataFrame<'a> {
         // Add group columns to new_columns
         for col_name in self.group_columns.iter() {
             let original_series = self.dataframe.get_column(col_name).unwrap();
-            let col_idx = self
-                .group_columns
+            // Keys are stored as strings, so read each group's value back from
+            // its first row to keep the column's type
+            let data_for_new_series: Vec<Option<Value>> = self
+                .group_indices
                 .iter()
-                .position(|x| x == col_name)
-                .unwrap();
-            let mut data_for_new_series: Vec<Option<Value>> =
-                Vec::with_capacity(self.group_keys.len());
-            for key in self.group_keys.iter() {
-                // All keys are Vec<String>, so wrap as Value::String
-                let v = if key[col_idx] == "<NULL>" {
-                    None
-                } else {
-                    Some(Value::String(key[col_idx].clone()))
-                };
-                data_for_new_series.push(v);
-            }
+                .map(|rows| original_series.get_value(rows[0]))
+                .collect();
             let new_series = match original_series.data_type() {
                 crate::types::DataType::I32 => Series::new_i32(
                     col_name,
@@ -742,6 +865,21 @@ impl<'a> GroupedDataFrame<'a> {
                         })
                         .collect(),
                 ),
+                crate::types::DataType::Binary => Series::new_binary(
+                    col_name,
+                    data_for_new_series
+                        .into_iter()
+                        .map(|x| {
+                            x.and_then(|v| {
+                                if let Value::Binary(val) = v {
+                                    Some(val)
+                                } else {
+                                    None
+                                }
+                            })
+                        })
+                        .collect(),
+                ),
             };
             new_columns.insert(col_name.clone(), new_series);
         }
@@ -810,6 +948,14 @@ impl<'a> GroupedDataFrame<'a> {
                         })
                         .collect(),
                 )
+            } else if agg_func == "count" {
+                Series::new_i32(
+                    &new_series_name,
+                    aggregated_data
+                        .into_iter()
+                        .map(|x| x.and_then(|v| v.as_i32()))
+                        .collect(),
+                )
             } else {
                 match original_series.data_type() {
                     crate::types::DataType::I32 => Series::new_i32(
@@ -887,6 +1033,21 @@ impl<'a> GroupedDataFrame<'a> {
                             })
                             .collect(),
                     ),
+                    crate::types::DataType::Binary => Series::new_binary(
+                        &new_series_name,
+                        aggregated_data
+                            .into_iter()
+                            .map(|x| {
+                                x.and_then(|v| {
+                                    if let Value::Binary(val) = v {
+                                        Some(val)
+                                    } else {
+                                        None
+                                    }
+                                })
+                            })
+                            .collect(),
+                    ),
                 }
             };
             new_columns.insert(new_series_name, new_series);
@@ -895,3 +1056,200 @@ impl<'a> GroupedDataFrame<'a> {
         DataFrame::new(new_columns)
     }
 }
+
+/// Aggregations supported by [`RollingGroupBy::agg`]
+const WINDOW_AGGREGATIONS: [&str; 5] = ["sum", "mean", "min", "max", "count"];
+
+impl<'a> GroupedDataFrame<'a> {
+    /// Aggregates over a trailing window of `window` rows within each group, e.g.
+    /// a per-customer moving average; see [`RollingGroupBy::agg`].
+    pub fn rolling(self, window: usize) -> RollingGroupBy<'a> {
+        RollingGroupBy {
+            grouped: self,
+            window: Some(window),
+        }
+    }
+
+    /// Aggregates over all rows so far within each group, e.g. a per-customer
+    /// running total; see [`RollingGroupBy::agg`].
+    pub fn expanding(self) -> RollingGroupBy<'a> {
+        RollingGroupBy {
+            grouped: self,
+            window: None,
+        }
+    }
+}
+
+/// Per-group window aggregations, created with [`GroupedDataFrame::rolling`] or
+/// [`GroupedDataFrame::expanding`].
+pub struct RollingGroupBy<'a> {
+    grouped: GroupedDataFrame<'a>,
+    /// Rows per window, or `None` for windows that start at the group's first row
+    window: Option<usize>,
+}
+
+impl RollingGroupBy<'_> {
+    /// Computes each `(column, function)` aggregation over every row's window
+    /// and adds it as a new column, keeping all rows in their original order.
+    ///
+    /// A window holds the row and the rows before it in the same group, in frame
+    /// order. Functions are "sum", "mean", "min", "max" and "count" of non-null
+    /// values; rolling results are null until the group has `window` rows, and
+    /// windows with only nulls give null (or 0 for "count"). Columns are named
+    /// `{column}_rolling_{function}_{window}` or `{column}_expanding_{function}`;
+    /// "mean" is `F64`, "count" is `I32` and the others keep the column's type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let orders = df!(
+    ///     "customer" => ["a", "b", "a", "a"],
+    ///     "amount" => [10, 5, 20, 30],
+    /// )
+    /// .unwrap();
+    /// let running = orders
+    ///     .group_by(vec!["customer".to_string()])
+    ///     .unwrap()
+    ///     .expanding()
+    ///     .agg(vec![("amount", "sum")])
+    ///     .unwrap();
+    /// let totals = running.get_column("amount_expanding_sum").unwrap();
+    /// assert_eq!(totals.get_value(3), Some(Value::I32(60)));
+    ///
+    /// let trailing = orders
+    ///     .group_by(vec!["customer".to_string()])
+    ///     .unwrap()
+    ///     .rolling(2)
+    ///     .agg(vec![("amount", "mean")])
+    ///     .unwrap();
+    /// let means = trailing.get_column("amount_rolling_mean_2").unwrap();
+    /// assert_eq!(means.get_value(0), None);
+    /// assert_eq!(means.get_value(3), Some(Value::F64(25.0)));
+    /// ```
+    pub fn agg(&self, aggregations: Vec<(&str, &str)>) -> Result<DataFrame, VeloxxError> {
+        if self.window == Some(0) {
+            return Err(VeloxxError::InvalidOperation(
+                "Window size must be greater than 0".to_string(),
+            ));
+        }
+        let dataframe: &DataFrame = &self.grouped.dataframe;
+        let mut new_columns = dataframe.columns.clone();
+        for (column, func) in aggregations {
+            if !WINDOW_AGGREGATIONS.contains(&func) {
+                return Err(VeloxxError::Unsupported(format!(
+                    "Unsupported window aggregation '{func}'; expected one of {WINDOW_AGGREGATIONS:?}"
+                )));
+            }
+            let series = dataframe
+                .get_column(column)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+            let values: Vec<Option<f64>> = match series {
+                Series::I32(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .map(|(&v, &valid)| valid.then_some(v as f64))
+                    .collect(),
+                Series::F64(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .map(|(&v, &valid)| valid.then_some(v))
+                    .collect(),
+                _ => {
+                    return Err(VeloxxError::DataTypeMismatch(format!(
+                        "Window aggregation requires a numeric column, '{}' is {:?}",
+                        column,
+                        series.data_type()
+                    )))
+                }
+            };
+
+            let mut results = vec![None; values.len()];
+            for rows in &self.grouped.group_indices {
+                let mut running = WindowStats::default();
+                for (position, &row) in rows.iter().enumerate() {
+                    results[row] = match self.window {
+                        None => {
+                            if let Some(v) = values[row] {
+                                running.push(v);
+                            }
+                            running.result(func)
+                        }
+                        Some(window) if position + 1 < window => None,
+                        Some(window) => {
+                            let mut stats = WindowStats::default();
+                            for &earlier in &rows[position + 1 - window..=position] {
+                                if let Some(v) = values[earlier] {
+                                    stats.push(v);
+                                }
+                            }
+                            stats.result(func)
+                        }
+                    };
+                }
+            }
+
+            let name = match self.window {
+                Some(window) => format!("{column}_rolling_{func}_{window}"),
+                None => format!("{column}_expanding_{func}"),
+            };
+            let integer = func == "count"
+                || (matches!(series, Series::I32(..)) && matches!(func, "sum" | "min" | "max"));
+            let new_series = if integer {
+                Series::new_i32(
+                    &name,
+                    results.into_iter().map(|v| v.map(|v| v as i32)).collect(),
+                )
+            } else {
+                Series::new_f64(&name, results)
+            };
+            new_columns.insert(name, new_series);
+        }
+        DataFrame::new(new_columns)
+    }
+}
+
+/// Running summary of the non-null values in a window
+struct WindowStats {
+    sum: f64,
+    count: usize,
+    min: f64,
+    max: f64,
+}
+
+impl Default for WindowStats {
+    fn default() -> Self {
+        Self {
+            sum: 0.0,
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl WindowStats {
+    fn push(&mut self, value: f64) {
+        self.sum += value;
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn result(&self, func: &str) -> Option<f64> {
+        if func == "count" {
+            return Some(self.count as f64);
+        }
+        if self.count == 0 {
+            return None;
+        }
+        match func {
+            "sum" => Some(self.sum),
+            "mean" => Some(self.sum / self.count as f64),
+            "min" => Some(self.min),
+            _ => Some(self.max),
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use crate::dataframe::DataFrame;
+use crate::error::ErrorContext;
+use crate::instrument::instrumented;
+use crate::io::{CsvReadOptions, JsonReadOptions};
 use crate::series::Series;
+use crate::types::Value;
 use crate::VeloxxError;
-use csv_core::{ReadFieldResult, Reader};
-use microjson::JSONValue;
+use csv_core::{ReadFieldResult, ReaderBuilder};
+use microjson::{JSONValue, JSONValueType};
 use std::collections::HashMap;
-use std::io::Read;
+use std::io::{Read, Write};
 
 impl DataFrame {
     #[cfg(all(feature = "arrow-io", not(target_arch = "wasm32")))]
@@ -25,7 +29,11 @@ impl DataFrame {
         not(target_arch = "wasm32")
     ))]
     pub fn from_arrow_parquet(path: &str) -> Result<Self, crate::error::VeloxxError> {
-        crate::io::arrow::read_parquet_to_dataframe(path)
+        instrumented!(
+            "read_parquet",
+            { path },
+            crate::io::arrow::read_parquet_to_dataframe(path).map_err(|e| e.in_file(path))
+        )
     }
 
     #[cfg(not(all(
@@ -39,13 +47,63 @@ impl DataFrame {
                 .to_string(),
         ))
     }
+    /// Reads a CSV file with default [`CsvReadOptions`], so ISO-8601 date columns
+    /// are read as `DateTime`.
     pub fn from_csv(path: &str) -> Result<Self, VeloxxError> {
-        let mut file = std::fs::File::open(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        Self::from_csv_with_options(path, &CsvReadOptions::default())
+    }
+
+    /// Reads a CSV file, converting date columns as configured in `options`.
+    pub fn from_csv_with_options(
+        path: &str,
+        options: &CsvReadOptions,
+    ) -> Result<Self, VeloxxError> {
+        instrumented!(
+            "read_csv",
+            { path },
+            options
+                .apply(Self::read_csv_file(path)?)
+                .map_err(|e| e.in_file(path))
+        )
+    }
+
+    /// Reads CSV text from standard input with default [`CsvReadOptions`], for use
+    /// in shell pipelines such as `cat data.csv | my-tool`.
+    pub fn from_stdin_csv() -> Result<Self, VeloxxError> {
         let mut contents = Vec::new();
-        file.read_to_end(&mut contents)
+        std::io::stdin()
+            .lock()
+            .read_to_end(&mut contents)
             .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        CsvReadOptions::default().apply(Self::parse_delimited(&contents, b',')?)
+    }
+
+    /// Reads a table from the system clipboard; requires the `clipboard` feature.
+    ///
+    /// The clipboard text is parsed as tab-separated values with a header row, which
+    /// is what spreadsheets place on the clipboard when cells are copied.
+    #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+    pub fn from_clipboard() -> Result<Self, VeloxxError> {
+        let text = arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .map_err(|e| VeloxxError::FileIO(format!("Failed to read clipboard: {}", e)))?;
+        CsvReadOptions::default().apply(Self::parse_delimited(text.as_bytes(), b'\t')?)
+    }
+
+    fn read_csv_file(path: &str) -> Result<Self, VeloxxError> {
+        let mut file = std::fs::File::open(path)
+            .map_err(|e| VeloxxError::FileIO(e.to_string()).in_file(path))?;
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)
+            .map_err(|e| VeloxxError::FileIO(e.to_string()).in_file(path))?;
+        Self::parse_delimited(&contents, b',').map_err(|e| e.in_file(path))
+    }
 
-        let mut trimmed_bytes = contents.as_slice();
+    /// Parses delimited text with a header row into string-inferred columns.
+    ///
+    /// Malformed records are reported with the line they start on.
+    pub(crate) fn parse_delimited(contents: &[u8], delimiter: u8) -> Result<Self, VeloxxError> {
+        let mut trimmed_bytes = contents;
         if let Some(i) = trimmed_bytes
             .iter()
             .rposition(|&x| x != b'\n' && x != b'\r')
@@ -57,7 +115,7 @@ impl DataFrame {
             return DataFrame::new(HashMap::new());
         }
 
-        let mut rdr = Reader::new();
+        let mut rdr = ReaderBuilder::new().delimiter(delimiter).build();
         let mut field_buf = [0; 8192]; // Buffer for a single field
 
         let mut column_names: Vec<String> = Vec::new();
@@ -66,14 +124,26 @@ impl DataFrame {
 
         let mut bytes = trimmed_bytes;
         let mut is_header = true;
+        // One-based line of the input and of the record being read, and the
+        // line each data row starts on
+        let mut line = 1;
+        let mut record_line = 1;
+        let mut row_lines: Vec<usize> = Vec::new();
+        let at_line = |line: usize| ErrorContext::new().line(line);
 
         loop {
             let (result, bytes_consumed, bytes_written) = rdr.read_field(bytes, &mut field_buf);
 
-            let field_str = String::from_utf8(field_buf[..bytes_written].to_vec())
-                .map_err(|e| VeloxxError::Parsing(e.to_string()))?;
+            let field_str =
+                String::from_utf8(field_buf[..bytes_written].to_vec()).map_err(|e| {
+                    VeloxxError::Parsing(e.to_string()).with_context(at_line(record_line))
+                })?;
             current_row_fields.push(field_str);
 
+            line += bytes[..bytes_consumed]
+                .iter()
+                .filter(|&&b| b == b'\n')
+                .count();
             bytes = &bytes[bytes_consumed..];
 
             match result {
@@ -83,6 +153,7 @@ impl DataFrame {
                             column_names = current_row_fields.clone();
                         } else {
                             all_rows_as_strings.push(current_row_fields.clone());
+                            row_lines.push(record_line);
                         }
                     }
                     break;
@@ -90,7 +161,8 @@ impl DataFrame {
                 ReadFieldResult::OutputFull => {
                     return Err(VeloxxError::Parsing(
                         "CSV field too large for buffer.".to_string(),
-                    ));
+                    )
+                    .with_context(at_line(record_line)));
                 }
                 ReadFieldResult::Field { record_end } => {
                     if record_end {
@@ -99,8 +171,10 @@ impl DataFrame {
                             is_header = false;
                         } else {
                             all_rows_as_strings.push(current_row_fields.clone());
+                            row_lines.push(record_line);
                         }
                         current_row_fields.clear();
+                        record_line = line;
                     }
                 }
                 ReadFieldResult::End => {
@@ -109,6 +183,7 @@ impl DataFrame {
                             column_names = current_row_fields.clone();
                         } else {
                             all_rows_as_strings.push(current_row_fields.clone());
+                            row_lines.push(record_line);
                         }
                     }
                     break;
@@ -131,7 +206,8 @@ impl DataFrame {
                     header.len(),
                     header,
                     row
-                )));
+                ))
+                .with_context(at_line(row_lines[row_idx])));
             }
         }
 
@@ -276,19 +352,63 @@ impl DataFrame {
     }
 
     pub fn to_csv(&self, path: &str) -> Result<(), VeloxxError> {
-        use std::io::Write;
-        let mut file =
-            std::fs::File::create(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        instrumented!(
+            "write_csv",
+            { path, rows_in = self.row_count },
+            std::fs::write(path, self.to_csv_string())
+                .map_err(|e| VeloxxError::FileIO(e.to_string()))
+        )
+    }
+
+    /// Renders the `DataFrame` as CSV text with a header row.
+    ///
+    /// Columns are written in sorted name order and nulls as empty fields. Fields
+    /// containing a comma, quote or line break are quoted.
+    pub fn to_csv_string(&self) -> String {
+        self.to_delimited_string(',')
+    }
 
+    /// Writes the `DataFrame` as CSV to standard output.
+    ///
+    /// A closed pipe (for example `my-tool | head`) is not treated as an error.
+    pub fn to_stdout_csv(&self) -> Result<(), VeloxxError> {
+        let mut stdout = std::io::stdout().lock();
+        match stdout
+            .write_all(self.to_csv_string().as_bytes())
+            .and_then(|_| stdout.flush())
+        {
+            Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+                Err(VeloxxError::FileIO(e.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Copies the `DataFrame` to the system clipboard as tab-separated values, ready
+    /// to paste into a spreadsheet; requires the `clipboard` feature.
+    #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+    pub fn to_clipboard(&self) -> Result<(), VeloxxError> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(self.to_delimited_string('\t')))
+            .map_err(|e| VeloxxError::FileIO(format!("Failed to write clipboard: {}", e)))
+    }
+
+    fn to_delimited_string(&self, delimiter: char) -> String {
+        let delimiter_str = delimiter.to_string();
+        let mut csv = String::new();
         if self.column_count() == 0 {
-            return Ok(());
+            return csv;
         }
 
         let mut column_names: Vec<&str> = self.column_names().iter().map(|s| s.as_str()).collect();
         // Sort column names to ensure consistent ordering
         column_names.sort();
-        writeln!(file, "{}", column_names.join(","))
-            .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let header: Vec<String> = column_names
+            .iter()
+            .map(|name| csv_field(name, delimiter))
+            .collect();
+        csv.push_str(&header.join(&delimiter_str));
+        csv.push('\n');
 
         for i in 0..self.row_count() {
             let mut row_values: Vec<String> = Vec::new();
@@ -298,24 +418,252 @@ impl DataFrame {
                     Some(crate::types::Value::I32(v)) => v.to_string(),
                     Some(crate::types::Value::F64(v)) => v.to_string(),
                     Some(crate::types::Value::Bool(v)) => v.to_string(),
-                    Some(crate::types::Value::String(v)) => v.clone(),
+                    Some(crate::types::Value::String(v)) => csv_field(&v, delimiter),
                     Some(crate::types::Value::DateTime(v)) => v.to_string(),
+                    Some(v @ crate::types::Value::Binary(_)) => v.to_string(),
                     Some(crate::types::Value::Null) => "".to_string(),
                     None => "".to_string(),
                 };
                 row_values.push(value_str);
             }
-            writeln!(file, "{}", row_values.join(","))
-                .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+            csv.push_str(&row_values.join(&delimiter_str));
+            csv.push('\n');
         }
 
-        Ok(())
+        csv
+    }
+
+    /// Writes a Hive-style partitioned dataset under `path`.
+    ///
+    /// Rows are split by the values of `partition_cols` into `col=value/` directories,
+    /// each holding a `part-00000.csv` or `part-00000.parquet` file with the remaining
+    /// columns. Existing files with the same names are overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use veloxx::io::PartitionFormat;
+    /// # use veloxx::dataframe::DataFrame;
+    /// # fn run(df: &DataFrame) -> Result<(), veloxx::VeloxxError> {
+    /// // sales/year=2023/region=EU/part-00000.csv, ...
+    /// df.write_partitioned("sales", &["year", "region"], PartitionFormat::Csv)?;
+    /// let all = DataFrame::read_partitioned("sales")?;
+    /// let eu = DataFrame::read_partitioned("sales/year=*/region=EU/*.csv")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write_partitioned(
+        &self,
+        path: &str,
+        partition_cols: &[&str],
+        format: crate::io::PartitionFormat,
+    ) -> Result<(), VeloxxError> {
+        crate::io::partitioned::write_partitioned(self, path, partition_cols, format)
+    }
+
+    /// Reads a partitioned dataset written by [`DataFrame::write_partitioned`] (or any
+    /// Hive-style layout).
+    ///
+    /// `pattern` is either a directory, searched recursively, or a glob selecting the
+    /// files to read. Files whose names start with `_` or `.` are skipped. Every
+    /// `key=value` directory below the pattern's literal prefix becomes a column.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_partitioned(pattern: &str) -> Result<Self, VeloxxError> {
+        crate::io::partitioned::read_partitioned(pattern)
+    }
+
+    /// Writes the `DataFrame` to the database table `table` with batched,
+    /// parameterized inserts inside one transaction, returning the number of rows
+    /// written.
+    ///
+    /// `mode` decides what happens when the table exists: [`SqlWriteMode::Fail`]
+    /// returns an error, [`SqlWriteMode::Replace`] drops and recreates it and
+    /// [`SqlWriteMode::Append`] inserts into it. New tables get one column per
+    /// DataFrame column, in name order, typed by
+    /// [`SqlConnection::sql_type`](crate::advanced_io::sql::SqlConnection::sql_type).
+    ///
+    /// [`SqlWriteMode::Fail`]: crate::advanced_io::sql::SqlWriteMode::Fail
+    /// [`SqlWriteMode::Replace`]: crate::advanced_io::sql::SqlWriteMode::Replace
+    /// [`SqlWriteMode::Append`]: crate::advanced_io::sql::SqlWriteMode::Append
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_sql(
+        &self,
+        connection: &dyn crate::advanced_io::sql::SqlConnection,
+        table: &str,
+        mode: crate::advanced_io::sql::SqlWriteMode,
+    ) -> Result<usize, VeloxxError> {
+        self.to_sql_with_options(connection, table, mode, &Default::default())
+    }
+
+    /// [`DataFrame::to_sql`] with explicit batch size, transaction and column type
+    /// settings.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_sql_with_options(
+        &self,
+        connection: &dyn crate::advanced_io::sql::SqlConnection,
+        table: &str,
+        mode: crate::advanced_io::sql::SqlWriteMode,
+        options: &crate::advanced_io::sql::SqlWriteOptions,
+    ) -> Result<usize, VeloxxError> {
+        crate::advanced_io::sql::write_table(self, connection, table, mode, options)
+    }
+
+    /// Reads and stacks every CSV file matched by the glob `pattern`; see
+    /// [`DataFrame::from_csv_glob_with_options`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_csv_glob(pattern: &str) -> Result<Self, VeloxxError> {
+        Self::from_csv_glob_with_options(pattern, &crate::io::GlobReadOptions::default())
+    }
+
+    /// Reads every CSV file matched by the glob `pattern` in parallel and stacks
+    /// them in path order.
+    ///
+    /// Every file must have the same column names and types, except that `I32`
+    /// columns are widened to `F64` where another file holds fractions. The error
+    /// names the first file that differs. Unlike [`DataFrame::read_partitioned`],
+    /// directory names are not turned into columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::io::GlobReadOptions;
+    ///
+    /// let options = GlobReadOptions::new().source_column("source_file");
+    /// let df = DataFrame::from_csv_glob_with_options("data/2024-*.csv", &options).unwrap();
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_csv_glob_with_options(
+        pattern: &str,
+        options: &crate::io::GlobReadOptions,
+    ) -> Result<Self, VeloxxError> {
+        instrumented!(
+            "read_csv_glob",
+            { pattern },
+            crate::io::partitioned::read_glob(pattern, crate::io::PartitionFormat::Csv, options)
+        )
+    }
+
+    /// Reads and stacks every Parquet file matched by the glob `pattern`, like
+    /// [`DataFrame::from_csv_glob`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_parquet_glob(pattern: &str) -> Result<Self, VeloxxError> {
+        Self::from_parquet_glob_with_options(pattern, &crate::io::GlobReadOptions::default())
+    }
+
+    /// Reads every Parquet file matched by the glob `pattern` in parallel, like
+    /// [`DataFrame::from_csv_glob_with_options`]; requires the `advanced_io` and
+    /// `arrow` features.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_parquet_glob_with_options(
+        pattern: &str,
+        options: &crate::io::GlobReadOptions,
+    ) -> Result<Self, VeloxxError> {
+        instrumented!(
+            "read_parquet_glob",
+            { pattern },
+            crate::io::partitioned::read_glob(
+                pattern,
+                crate::io::PartitionFormat::Parquet,
+                options
+            )
+        )
     }
 
     pub fn from_json(path: &str) -> Result<Self, VeloxxError> {
-        let contents =
-            std::fs::read_to_string(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
-        let json = JSONValue::load(&contents);
+        Self::from_json_with_options(path, &JsonReadOptions::default())
+    }
+
+    /// Reads a JSON file, converting date columns as configured in `options`.
+    pub fn from_json_with_options(
+        path: &str,
+        options: &JsonReadOptions,
+    ) -> Result<Self, VeloxxError> {
+        instrumented!("read_json", { path }, {
+            let contents = std::fs::read_to_string(path)
+                .map_err(|e| VeloxxError::FileIO(e.to_string()).in_file(path))?;
+            Self::from_json_str_with_options(&contents, options).map_err(|e| e.in_file(path))
+        })
+    }
+
+    /// Parses a JSON array of row objects, as read by [`DataFrame::from_json`].
+    pub fn from_json_str(contents: &str) -> Result<Self, VeloxxError> {
+        Self::from_json_str_with_options(contents, &JsonReadOptions::default())
+    }
+
+    /// Parses a JSON array of row objects, converting date columns as configured in `options`.
+    pub fn from_json_str_with_options(
+        contents: &str,
+        options: &JsonReadOptions,
+    ) -> Result<Self, VeloxxError> {
+        options.apply(Self::parse_json_rows(contents, options)?)
+    }
+
+    /// Reads the records of an XML file selected by `record_path` as rows, with one
+    /// column per entry of `fields` (column name, path relative to the record).
+    ///
+    /// The file is parsed as a stream. Paths are described in [`crate::io::xml`];
+    /// with no `fields`, the records' attributes and child elements become columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use veloxx::dataframe::DataFrame;
+    ///
+    /// let trades = DataFrame::from_xml(
+    ///     "trades.xml",
+    ///     "/report/trades/trade",
+    ///     &[("id", "@id"), ("price", "price"), ("counterparty", "party/@lei")],
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_xml(
+        path: &str,
+        record_path: &str,
+        fields: &[(&str, &str)],
+    ) -> Result<Self, VeloxxError> {
+        instrumented!("read_xml", { path }, {
+            let file = std::fs::File::open(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+            crate::io::xml::read_xml(std::io::BufReader::new(file), record_path, fields)
+        })
+    }
+
+    /// Reads records from XML text, as [`DataFrame::from_xml`] does from a file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::types::Value;
+    ///
+    /// let xml = r#"<trades>
+    ///     <trade id="7"><price>101.5</price><party lei="ABC">Acme &amp; Co</party></trade>
+    ///     <trade id="8"><price>99</price></trade>
+    /// </trades>"#;
+    /// let df = DataFrame::from_xml_str(
+    ///     xml,
+    ///     "trade",
+    ///     &[("id", "@id"), ("price", "price"), ("party", "party"), ("lei", "party/@lei")],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(df.get_column("id").unwrap().get_value(1), Some(Value::I32(8)));
+    /// assert_eq!(
+    ///     df.get_column("party").unwrap().get_value(0),
+    ///     Some(Value::String("Acme & Co".to_string()))
+    /// );
+    /// assert_eq!(df.get_column("lei").unwrap().get_value(1), None);
+    /// ```
+    pub fn from_xml_str(
+        xml: &str,
+        record_path: &str,
+        fields: &[(&str, &str)],
+    ) -> Result<Self, VeloxxError> {
+        crate::io::xml::read_xml(xml.as_bytes(), record_path, fields)
+    }
+
+    fn parse_json_rows(contents: &str, options: &JsonReadOptions) -> Result<Self, VeloxxError> {
+        let json = JSONValue::load(contents);
         let arr_iter = match json.iter_array() {
             Ok(arr) => arr,
             Err(_) => {
@@ -326,45 +674,37 @@ impl DataFrame {
         };
         let mut rows = Vec::new();
         for row_val in arr_iter {
-            let obj_iter = match row_val.iter_object() {
-                Ok(obj) => obj,
-                Err(_) => {
-                    return Err(VeloxxError::Parsing(
-                        "Each row must be a JSON object".to_string(),
-                    ))
-                }
-            };
-            let mut row = std::collections::HashMap::new();
-            for entry in obj_iter {
-                let (k, v) = match entry {
-                    Ok((k, v)) => (k, v),
-                    Err(_) => {
-                        return Err(VeloxxError::Parsing(
-                            "Error reading key-value pair".to_string(),
-                        ))
-                    }
-                };
-                let value = if let Ok(f) = v.read_float() {
-                    Some(crate::types::Value::F64(f as f64))
-                } else if let Ok(i) = v.read_integer() {
-                    Some(crate::types::Value::I32(i as i32))
-                } else if let Ok(s) = v.read_string() {
-                    Some(crate::types::Value::String(s.to_string()))
-                } else if let Ok(b) = v.read_boolean() {
-                    Some(crate::types::Value::Bool(b))
-                } else if let Ok(dt) = v.read_integer() {
-                    Some(crate::types::Value::DateTime(dt as i64))
-                } else {
-                    None
-                };
-                row.insert(k.to_string(), value);
-            }
+            let mut row = HashMap::new();
+            flatten_json_object(row_val, "", 0, options, &mut row)?;
             rows.push(row);
         }
         if rows.is_empty() {
             return Err(VeloxxError::Parsing("JSON array is empty".to_string()));
         }
-        let column_names: Vec<String> = rows[0].keys().cloned().collect();
+        let mut seen = std::collections::HashSet::new();
+        let mut column_names: Vec<String> = Vec::new();
+        for row in &rows {
+            for name in row.keys() {
+                if seen.insert(name) {
+                    column_names.push(name.clone());
+                }
+            }
+        }
+        // A key that is null wherever it is present but an object elsewhere
+        // only contributes its flattened columns
+        let nested = |name: &str| {
+            let prefix = format!("{name}{}", options.separator);
+            column_names.iter().any(|other| other.starts_with(&prefix))
+        };
+        let all_null = |name: &str| {
+            rows.iter()
+                .all(|row| row.get(name).is_none_or(Option::is_none))
+        };
+        let column_names: Vec<String> = column_names
+            .iter()
+            .filter(|name| !(nested(name) && all_null(name)))
+            .cloned()
+            .collect();
         let mut columns: std::collections::HashMap<String, Vec<Option<crate::types::Value>>> =
             std::collections::HashMap::new();
         for name in &column_names {
@@ -449,3 +789,400 @@ impl DataFrame {
         DataFrame::new(series_map)
     }
 }
+
+/// Adds the fields of a JSON object to `row`, flattening nested objects into
+/// `prefix`-qualified names as described on [`JsonReadOptions`].
+fn flatten_json_object(
+    object: JSONValue,
+    prefix: &str,
+    depth: usize,
+    options: &JsonReadOptions,
+    row: &mut HashMap<String, Option<Value>>,
+) -> Result<(), VeloxxError> {
+    let entries = object
+        .iter_object()
+        .map_err(|_| VeloxxError::Parsing("Each row must be a JSON object".to_string()))?;
+    for entry in entries {
+        let (key, value) =
+            entry.map_err(|_| VeloxxError::Parsing("Error reading key-value pair".to_string()))?;
+        let name = if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}{}{key}", options.separator)
+        };
+        let cell = match value.value_type {
+            JSONValueType::Object if options.max_depth.is_none_or(|max| depth < max) => {
+                flatten_json_object(value, &name, depth + 1, options, row)?;
+                continue;
+            }
+            JSONValueType::Object => Some(Value::String(json_text(&value)?)),
+            JSONValueType::Array => Some(Value::String(json_list(&value, options)?)),
+            _ => json_scalar(&value),
+        };
+        row.insert(name, cell);
+    }
+    Ok(())
+}
+
+/// A scalar JSON value; `None` for null
+fn json_scalar(value: &JSONValue) -> Option<Value> {
+    // microjson reads floats as f32, so whole numbers (e.g. epoch
+    // milliseconds) are read as integers to keep them exact.
+    if let Ok(i) = value.read_integer() {
+        Some(Value::F64(i as f64))
+    } else if let Ok(f) = value.read_float() {
+        Some(Value::F64(f as f64))
+    } else if let Ok(s) = value.read_string() {
+        Some(Value::String(s.to_string()))
+    } else if let Ok(b) = value.read_boolean() {
+        Some(Value::Bool(b))
+    } else {
+        None
+    }
+}
+
+/// An array as a list cell: its elements joined by the list delimiter, or
+/// JSON text if any element is itself an object or array
+fn json_list(array: &JSONValue, options: &JsonReadOptions) -> Result<String, VeloxxError> {
+    let elements: Vec<JSONValue> = array
+        .iter_array()
+        .map_err(|_| VeloxxError::Parsing("Error reading JSON array".to_string()))?
+        .collect();
+    if elements
+        .iter()
+        .any(|e| matches!(e.value_type, JSONValueType::Object | JSONValueType::Array))
+    {
+        return json_text(array);
+    }
+    let parts: Vec<String> = elements
+        .iter()
+        .map(|element| match json_scalar(element) {
+            Some(Value::F64(f)) => f.to_string(),
+            Some(value) => value.to_string(),
+            None => String::new(),
+        })
+        .collect();
+    Ok(parts.join(&options.list_delimiter))
+}
+
+/// Compact JSON text of `value`; string escapes are kept as written
+fn json_text(value: &JSONValue) -> Result<String, VeloxxError> {
+    let error = |what: &str| VeloxxError::Parsing(format!("Error reading JSON {what}"));
+    Ok(match value.value_type {
+        JSONValueType::Object => {
+            let mut fields = Vec::new();
+            for entry in value.iter_object().map_err(|_| error("object"))? {
+                let (key, value) = entry.map_err(|_| error("object"))?;
+                fields.push(format!("\"{key}\":{}", json_text(&value)?));
+            }
+            format!("{{{}}}", fields.join(","))
+        }
+        JSONValueType::Array => {
+            let elements = value
+                .iter_array()
+                .map_err(|_| error("array"))?
+                .map(|element| json_text(&element))
+                .collect::<Result<Vec<_>, _>>()?;
+            format!("[{}]", elements.join(","))
+        }
+        JSONValueType::String => {
+            format!("\"{}\"", value.read_string().map_err(|_| error("string"))?)
+        }
+        JSONValueType::Number => match value.read_integer() {
+            Ok(i) => i.to_string(),
+            Err(_) => value.read_float().map_err(|_| error("number"))?.to_string(),
+        },
+        JSONValueType::Bool => value
+            .read_boolean()
+            .map_err(|_| error("boolean"))?
+            .to_string(),
+        JSONValueType::Null => "null".to_string(),
+        JSONValueType::Error => return Err(error("value")),
+    })
+}
+
+fn csv_field(value: &str, delimiter: char) -> String {
+    if value.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Running count, sum, min and max of one numeric CSV column, kept while scanning
+/// so the column never has to be materialized.
+#[derive(Debug, Clone)]
+pub(crate) struct CsvColumnAggregate {
+    all_i32: bool,
+    all_f64: bool,
+    /// Number of non-empty cells
+    pub(crate) count: usize,
+    int_sum: i64,
+    int_min: Option<i32>,
+    int_max: Option<i32>,
+    float_sum: f64,
+    float_min: Option<f64>,
+    float_max: Option<f64>,
+}
+
+impl CsvColumnAggregate {
+    fn new() -> Self {
+        CsvColumnAggregate {
+            all_i32: true,
+            all_f64: true,
+            count: 0,
+            int_sum: 0,
+            int_min: None,
+            int_max: None,
+            float_sum: 0.0,
+            float_min: None,
+            float_max: None,
+        }
+    }
+
+    fn push(&mut self, cell: &str) {
+        if cell.is_empty() {
+            return;
+        }
+        self.count += 1;
+        if self.all_i32 {
+            match cell.parse::<i32>() {
+                Ok(v) => {
+                    self.int_sum += v as i64;
+                    self.int_min = Some(self.int_min.map_or(v, |m| m.min(v)));
+                    self.int_max = Some(self.int_max.map_or(v, |m| m.max(v)));
+                }
+                Err(_) => self.all_i32 = false,
+            }
+        }
+        if self.all_f64 {
+            match cell.parse::<f64>() {
+                Ok(v) => {
+                    self.float_sum += v;
+                    self.float_min = Some(self.float_min.map_or(v, |m| m.min(v)));
+                    self.float_max = Some(self.float_max.map_or(v, |m| m.max(v)));
+                }
+                Err(_) => self.all_f64 = false,
+            }
+        }
+    }
+
+    /// Whether the column would be read as `I32` or `F64`
+    fn is_numeric(&self) -> bool {
+        self.all_i32 || self.all_f64
+    }
+
+    /// Sum with the type the column would be read as, as `Series::sum` returns it
+    pub(crate) fn sum(&self) -> Value {
+        if self.all_i32 {
+            crate::series::aggregations::integer_sum_value(self.int_sum)
+        } else {
+            Value::F64(self.float_sum)
+        }
+    }
+
+    /// Smallest value, or `Null` when the column has no values
+    pub(crate) fn min(&self) -> Value {
+        if self.all_i32 {
+            self.int_min.map_or(Value::Null, Value::I32)
+        } else {
+            self.float_min.map_or(Value::Null, Value::F64)
+        }
+    }
+
+    /// Largest value, or `Null` when the column has no values
+    pub(crate) fn max(&self) -> Value {
+        if self.all_i32 {
+            self.int_max.map_or(Value::Null, Value::I32)
+        } else {
+            self.float_max.map_or(Value::Null, Value::F64)
+        }
+    }
+}
+
+/// Streams a CSV file once, aggregating the named columns without building a
+/// `DataFrame`.
+///
+/// Returns `Ok(None)` when a column would not be read as `I32` or `F64`, or the
+/// file has no data rows, so the caller can fall back to reading the file.
+pub(crate) fn aggregate_csv_columns(
+    path: &str,
+    columns: &[&str],
+) -> Result<Option<Vec<CsvColumnAggregate>>, VeloxxError> {
+    use std::io::BufRead;
+
+    let file = std::fs::File::open(path).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+    let mut input = std::io::BufReader::with_capacity(1 << 16, file);
+    let mut rdr = ReaderBuilder::new().build();
+    let mut field_buf = [0; 8192];
+    let mut field: Vec<u8> = Vec::new();
+
+    let mut header: Vec<String> = Vec::new();
+    // For each CSV column, the index of the aggregate it feeds
+    let mut targets: Vec<Option<usize>> = Vec::new();
+    let mut aggregates = vec![CsvColumnAggregate::new(); columns.len()];
+    let mut in_header = true;
+    let mut column = 0;
+    let mut rows = 0;
+
+    loop {
+        let bytes = input
+            .fill_buf()
+            .map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let at_eof = bytes.is_empty();
+        let (result, bytes_consumed, bytes_written) = rdr.read_field(bytes, &mut field_buf);
+        field.extend_from_slice(&field_buf[..bytes_written]);
+        input.consume(bytes_consumed);
+
+        let record_end = match result {
+            ReadFieldResult::End => break,
+            ReadFieldResult::InputEmpty if at_eof => break,
+            // A field longer than the buffer keeps accumulating across calls
+            ReadFieldResult::InputEmpty | ReadFieldResult::OutputFull => continue,
+            ReadFieldResult::Field { record_end } => record_end,
+        };
+        let text = std::str::from_utf8(&field).map_err(|e| VeloxxError::Parsing(e.to_string()))?;
+        if in_header {
+            header.push(text.to_string());
+        } else if let Some(Some(target)) = targets.get(column) {
+            aggregates[*target].push(text);
+        }
+        field.clear();
+        column += 1;
+        if !record_end {
+            continue;
+        }
+
+        if in_header {
+            in_header = false;
+            targets = header
+                .iter()
+                .map(|name| columns.iter().position(|c| c == name))
+                .collect();
+            if let Some(missing) = columns.iter().find(|c| !header.iter().any(|h| h == *c)) {
+                return Err(VeloxxError::ColumnNotFound(missing.to_string()));
+            }
+        } else if column == 1 && header.len() > 1 {
+            // Blank line, ignored like trailing newlines when reading the file
+        } else if column != header.len() {
+            return Err(VeloxxError::Parsing(format!(
+                "CSV row {} has {} columns, expected {}",
+                rows + 1,
+                column,
+                header.len()
+            )));
+        } else {
+            rows += 1;
+        }
+        column = 0;
+    }
+
+    if rows == 0 || !aggregates.iter().all(CsvColumnAggregate::is_numeric) {
+        return Ok(None);
+    }
+    Ok(Some(aggregates))
+}
+
+/// Bytes read from the start of a CSV file by [`estimate_csv_rows`]
+const CSV_HEAD_BYTES: usize = 1 << 16;
+/// Bytes read at each of the [`CSV_WINDOWS`] further into the file
+const CSV_WINDOW_BYTES: usize = 1 << 14;
+const CSV_WINDOWS: u64 = 4;
+
+/// Estimates the data rows of a CSV file by sampling it: the average row length
+/// in its first [`CSV_HEAD_BYTES`] and in windows spread across the rest of the
+/// file is scaled up to the file size. Rows in the windows are counted from
+/// the first line break, which may fall inside a quoted field.
+///
+/// Returns the estimate, whether it is exact (the head was the whole file) and
+/// the file size in bytes.
+pub(crate) fn estimate_csv_rows(path: &str) -> Result<(usize, bool, u64), VeloxxError> {
+    use std::io::{Seek, SeekFrom};
+
+    let io_error = |e: std::io::Error| VeloxxError::FileIO(e.to_string());
+    let mut file = std::fs::File::open(path).map_err(io_error)?;
+    let size = file.metadata().map_err(io_error)?.len();
+    let mut head = Vec::with_capacity(CSV_HEAD_BYTES);
+    (&mut file)
+        .take(CSV_HEAD_BYTES as u64)
+        .read_to_end(&mut head)
+        .map_err(io_error)?;
+    if head.len() as u64 == size {
+        let (rows, _, _) = count_csv_rows(&head, true, true);
+        return Ok((rows, true, size));
+    }
+
+    // Bytes per row of each sample, weighted equally as the samples stand for
+    // equal parts of the file
+    let (rows, header_end, rows_end) = count_csv_rows(&head, false, true);
+    let mut row_lengths = Vec::new();
+    if rows > 0 {
+        row_lengths.push((rows_end - header_end) as f64 / rows as f64);
+    }
+    for k in 1..CSV_WINDOWS {
+        let mut window = Vec::with_capacity(CSV_WINDOW_BYTES);
+        file.seek(SeekFrom::Start(size * k / CSV_WINDOWS))
+            .map_err(io_error)?;
+        (&mut file)
+            .take(CSV_WINDOW_BYTES as u64)
+            .read_to_end(&mut window)
+            .map_err(io_error)?;
+        let Some(line_start) = window.iter().position(|&b| b == b'\n') else {
+            continue;
+        };
+        let (rows, _, rows_end) = count_csv_rows(&window[line_start + 1..], false, false);
+        if rows > 0 {
+            row_lengths.push(rows_end as f64 / rows as f64);
+        }
+    }
+    if row_lengths.is_empty() {
+        // Rows longer than the samples
+        return Ok((1, false, size));
+    }
+    let row_length = row_lengths.iter().sum::<f64>() / row_lengths.len() as f64;
+    let estimate = (size - header_end as u64) as f64 / row_length;
+    Ok((estimate.round().max(1.0) as usize, false, size))
+}
+
+/// Counts the complete data rows in `sample`, skipping blank lines, returning
+/// the count and the offsets just past the header (0 without one) and past the
+/// last row.
+///
+/// Without `complete`, a row running to the end of the sample may be cut short
+/// and is not counted.
+fn count_csv_rows(sample: &[u8], complete: bool, has_header: bool) -> (usize, usize, usize) {
+    let mut rdr = ReaderBuilder::new().build();
+    let mut field_buf = [0; 8192];
+    let mut input = sample;
+    let (mut header_end, mut rows_end) = (if has_header { None } else { Some(0) }, 0);
+    let (mut header_fields, mut fields, mut record_bytes, mut rows) = (0, 0, 0, 0);
+    loop {
+        let (result, bytes_consumed, bytes_written) = rdr.read_field(input, &mut field_buf);
+        input = &input[bytes_consumed..];
+        record_bytes += bytes_written;
+        let record_end = match result {
+            ReadFieldResult::End => break,
+            ReadFieldResult::InputEmpty if !complete => break,
+            ReadFieldResult::InputEmpty | ReadFieldResult::OutputFull => continue,
+            ReadFieldResult::Field { record_end } => record_end,
+        };
+        fields += 1;
+        if !record_end {
+            continue;
+        }
+        let offset = sample.len() - input.len();
+        if header_end.is_none() {
+            header_end = Some(offset);
+            header_fields = fields;
+        } else if fields == 1 && record_bytes == 0 && header_fields != 1 {
+            // Blank line, ignored like trailing newlines when reading the file
+        } else {
+            rows += 1;
+            rows_end = offset;
+        }
+        fields = 0;
+        record_bytes = 0;
+    }
+    let header_end = header_end.unwrap_or(sample.len());
+    (rows, header_end, rows_end.max(header_end))
+}
@@ -1,10 +1,15 @@
+use crate::instrument::instrumented;
+use crate::series::uuid::{parse_uuid, uuid_keys};
 use crate::VeloxxError;
 use crate::{dataframe::DataFrame, series::Series, types::Value};
-use rayon::iter::IntoParallelIterator;
 use rayon::prelude::*;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
-#[derive(PartialEq)]
+/// Sides with at most this many rows are broadcast by [`JoinAlgorithm::Auto`]
+pub(crate) const BROADCAST_MAX_ROWS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Defines the type of join to be performed between two DataFrames.
 pub enum JoinType {
     /// Returns only the rows that have matching values in both DataFrames.
@@ -15,6 +20,74 @@ pub enum JoinType {
     Right,
 }
 
+/// How matching rows are found in [`DataFrame::join_with_algorithm`].
+///
+/// Every algorithm produces the same rows in the same order, so the choice only
+/// affects speed and memory: rows follow the left frame (the right frame for
+/// [`JoinType::Right`]) and multiple matches follow the other frame's order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinAlgorithm {
+    /// Hash 128-bit values when both keys are UUID strings, broadcast a small side
+    /// (preferring the right), match runs when both keys are run-length compact,
+    /// sort-merge when both keys are already sorted, and hash join otherwise
+    #[default]
+    Auto,
+    /// Builds a hash table over one side in parallel and probes it with the other
+    Hash,
+    /// Sorts both key columns (skipped when already sorted) and merges them; avoids
+    /// a hash table, which helps with pre-sorted or very high-cardinality keys
+    SortMerge,
+    /// Builds a small lookup table over the right side serially and streams the
+    /// left side past it in parallel; meant for tiny right sides
+    Broadcast,
+    /// Like `Broadcast` with the lookup table built over a tiny left side
+    BroadcastLeft,
+    /// Run-length encodes both key columns and matches whole runs of equal keys,
+    /// so each distinct key run is looked up once; suits sorted or low-cardinality
+    /// keys
+    RunLength,
+    /// Parses both `String` key columns as UUIDs and hashes their 128-bit values,
+    /// avoiding a string copy per row; a key that is not a UUID is an error
+    Uuid,
+}
+
+/// Expected key cardinality, checked by [`DataFrame::join_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinValidation {
+    /// Keys are unique in both frames
+    OneToOne,
+    /// Keys are unique in the left frame
+    OneToMany,
+    /// Keys are unique in the right frame
+    ManyToOne,
+    /// No check
+    #[default]
+    ManyToMany,
+}
+
+/// Options for [`DataFrame::join_with_options`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinOptions {
+    /// Whether null keys match each other; by default they match nothing
+    pub join_nulls: bool,
+    /// Cardinality to assert before joining
+    pub validate: JoinValidation,
+    /// Appended to right-frame columns whose names clash with left-frame columns
+    pub suffix: String,
+    pub algorithm: JoinAlgorithm,
+}
+
+impl Default for JoinOptions {
+    fn default() -> Self {
+        Self {
+            join_nulls: false,
+            validate: JoinValidation::ManyToMany,
+            suffix: "_right".to_string(),
+            algorithm: JoinAlgorithm::Auto,
+        }
+    }
+}
+
 impl DataFrame {
     /// Performs a join operation with another `DataFrame`.
     ///
@@ -28,6 +101,10 @@ impl DataFrame {
     ///   and have comparable data types.
     /// * `join_type` - The type of join to perform (`Inner`, `Left`, or `Right`).
     ///
+    /// Null keys never match, and other columns present in both frames are kept from
+    /// both, with `_right` appended to the right frame's copy; see
+    /// [`DataFrame::join_with_options`] to change either.
+    ///
     /// # Returns
     ///
     /// A `Result` which is `Ok(DataFrame)` containing the joined `DataFrame`,
@@ -137,384 +214,615 @@ impl DataFrame {
         on_column: &str,
         join_type: JoinType,
     ) -> Result<Self, VeloxxError> {
-        let mut new_columns: HashMap<String, Series> = HashMap::new();
+        self.join_with_algorithm(other, on_column, join_type, JoinAlgorithm::Auto)
+    }
 
-        let self_col_names: Vec<String> =
-            self.column_names().iter().map(|s| (*s).clone()).collect();
-        let other_col_names: Vec<String> =
-            other.column_names().iter().map(|s| (*s).clone()).collect();
+    /// Joins like [`DataFrame::join`], using `algorithm` to find matching rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use veloxx::dataframe::join::{JoinAlgorithm, JoinType};
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    ///
+    /// let ids = |values: Vec<i32>| {
+    ///     let mut columns = HashMap::new();
+    ///     columns.insert("id".to_string(), Series::new_i32("id", values.into_iter().map(Some).collect()));
+    ///     DataFrame::new(columns).unwrap()
+    /// };
+    /// let joined = ids(vec![1, 2, 3])
+    ///     .join_with_algorithm(&ids(vec![2, 3, 4]), "id", JoinType::Inner, JoinAlgorithm::SortMerge)
+    ///     .unwrap();
+    /// assert_eq!(joined.row_count(), 2);
+    /// ```
+    pub fn join_with_algorithm(
+        &self,
+        other: &DataFrame,
+        on_column: &str,
+        join_type: JoinType,
+        algorithm: JoinAlgorithm,
+    ) -> Result<Self, VeloxxError> {
+        let options = JoinOptions {
+            algorithm,
+            ..JoinOptions::default()
+        };
+        self.join_with_options(other, on_column, join_type, &options)
+    }
 
-        // Check if join column exists in both DataFrames
-        if !self_col_names.contains(&on_column.to_string()) {
-            return Err(VeloxxError::ColumnNotFound(format!(
+    /// Joins like [`DataFrame::join`] with control over null keys, key cardinality
+    /// and the suffix for clashing column names.
+    ///
+    /// Returns `VeloxxError::InvalidOperation` when `options.validate` does not hold
+    /// or a suffixed name is still taken.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use veloxx::dataframe::join::{JoinOptions, JoinType, JoinValidation};
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    ///
+    /// let frame = |ids: Vec<Option<i32>>| {
+    ///     let mut columns = HashMap::new();
+    ///     columns.insert("score".to_string(), Series::new_i32("score", vec![Some(1); ids.len()]));
+    ///     columns.insert("id".to_string(), Series::new_i32("id", ids));
+    ///     DataFrame::new(columns).unwrap()
+    /// };
+    /// let options = JoinOptions {
+    ///     join_nulls: true,
+    ///     validate: JoinValidation::OneToOne,
+    ///     suffix: "_other".to_string(),
+    ///     ..JoinOptions::default()
+    /// };
+    /// let joined = frame(vec![Some(1), None])
+    ///     .join_with_options(&frame(vec![None, Some(2)]), "id", JoinType::Inner, &options)
+    ///     .unwrap();
+    /// assert_eq!(joined.row_count(), 1);
+    /// assert!(joined.get_column("score_other").is_some());
+    ///
+    /// // Duplicate right keys break a one-to-one expectation
+    /// assert!(frame(vec![Some(1)])
+    ///     .join_with_options(&frame(vec![Some(1), Some(1)]), "id", JoinType::Inner, &options)
+    ///     .is_err());
+    /// ```
+    pub fn join_with_options(
+        &self,
+        other: &DataFrame,
+        on_column: &str,
+        join_type: JoinType,
+        options: &JoinOptions,
+    ) -> Result<Self, VeloxxError> {
+        instrumented!(
+            "join",
+            {
+                rows_in = self.row_count,
+                rows_right = other.row_count,
+                on = on_column,
+                how = ?join_type,
+                algorithm = ?options.algorithm,
+            },
+            self.join_rows(other, on_column, join_type, options)
+                .and_then(|joined| {
+                    self.join_constraints(other, joined, Some(on_column), &options.suffix)
+                })
+        )
+    }
+
+    fn join_rows(
+        &self,
+        other: &DataFrame,
+        on_column: &str,
+        join_type: JoinType,
+        options: &JoinOptions,
+    ) -> Result<Self, VeloxxError> {
+        let self_on_series = self.get_column(on_column).ok_or_else(|| {
+            VeloxxError::ColumnNotFound(format!(
                 "Join column '{on_column}' not found in left DataFrame."
-            )));
-        }
-        if !other_col_names.contains(&on_column.to_string()) {
-            return Err(VeloxxError::ColumnNotFound(format!(
+            ))
+        })?;
+        let other_on_series = other.get_column(on_column).ok_or_else(|| {
+            VeloxxError::ColumnNotFound(format!(
                 "Join column '{on_column}' not found in right DataFrame."
-            )));
-        }
+            ))
+        })?;
 
-        // Determine all unique column names and their types
-        let all_column_names: Vec<String> = {
-            let mut temp_names = Vec::new();
-            for col_name in self_col_names.iter() {
-                temp_names.push(col_name.clone());
-            }
-            for col_name in other_col_names.iter() {
-                if !temp_names.contains(col_name) {
-                    temp_names.push(col_name.clone());
+        let unique = |series: &Series, side: &str| -> Result<(), VeloxxError> {
+            let mut seen = std::collections::HashSet::new();
+            for i in 0..series.len() {
+                let key = series.get_value(i);
+                if (key.is_some() || options.join_nulls) && !seen.insert(key.clone()) {
+                    return Err(VeloxxError::InvalidOperation(format!(
+                        "Join keys in {side} DataFrame are not unique: '{}' is repeated",
+                        key.map_or("null".to_string(), |k| k.to_string())
+                    )));
                 }
             }
-            temp_names
+            Ok(())
         };
+        if matches!(
+            options.validate,
+            JoinValidation::OneToOne | JoinValidation::OneToMany
+        ) {
+            unique(self_on_series, "left")?;
+        }
+        if matches!(
+            options.validate,
+            JoinValidation::OneToOne | JoinValidation::ManyToOne
+        ) {
+            unique(other_on_series, "right")?;
+        }
 
-        let mut column_types: HashMap<String, crate::types::DataType> = HashMap::new();
+        let algorithm = match options.algorithm {
+            JoinAlgorithm::Auto => choose_algorithm(self_on_series, other_on_series),
+            chosen => chosen,
+        };
+        // Right joins are left joins driven by the right frame
+        let (probe, build) = match join_type {
+            JoinType::Right => (other_on_series, self_on_series),
+            JoinType::Inner | JoinType::Left => (self_on_series, other_on_series),
+        };
+        let mut matches = match algorithm {
+            JoinAlgorithm::Uuid => match uuid_matches(probe, build) {
+                Some(matches) => matches,
+                // `Auto` only sampled the keys, so it falls back instead of failing
+                None if options.algorithm == JoinAlgorithm::Auto => hash_matches(probe, build),
+                None => {
+                    return Err(VeloxxError::InvalidOperation(format!(
+                        "Join column '{on_column}' does not hold UUID strings in both DataFrames"
+                    )))
+                }
+            },
+            JoinAlgorithm::SortMerge => sort_merge_matches(probe, build),
+            JoinAlgorithm::RunLength => run_length_matches(probe, build),
+            // The table goes over the broadcast side, whichever side drives the join
+            JoinAlgorithm::Broadcast if join_type == JoinType::Right => {
+                broadcast_reverse_matches(probe, build)
+            }
+            JoinAlgorithm::BroadcastLeft if join_type != JoinType::Right => {
+                broadcast_reverse_matches(probe, build)
+            }
+            JoinAlgorithm::Broadcast | JoinAlgorithm::BroadcastLeft => {
+                broadcast_matches(probe, build)
+            }
+            JoinAlgorithm::Hash | JoinAlgorithm::Auto => hash_matches(probe, build),
+        };
+        if options.join_nulls {
+            let nulls = |series: &Series| -> Vec<usize> {
+                (0..series.len())
+                    .filter(|&i| series.get_value(i).is_none())
+                    .collect()
+            };
+            let build_nulls = nulls(build);
+            if !build_nulls.is_empty() {
+                for probe_idx in nulls(probe) {
+                    matches[probe_idx] = build_nulls.clone();
+                }
+            }
+        }
 
-        for col_name in self_col_names.iter() {
-            column_types.insert(
-                col_name.clone(),
-                self.get_column(col_name).unwrap().data_type(),
-            );
+        let mut pairs: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+        for (probe_idx, build_indices) in matches.iter().enumerate() {
+            if build_indices.is_empty() {
+                if join_type != JoinType::Inner {
+                    pairs.push((Some(probe_idx), None));
+                }
+            } else {
+                pairs.extend(build_indices.iter().map(|&b| (Some(probe_idx), Some(b))));
+            }
         }
-        for col_name in other_col_names.iter() {
-            if !column_types.contains_key(col_name) {
-                column_types.insert(
-                    col_name.clone(),
-                    other.get_column(col_name).unwrap().data_type(),
-                );
+        if join_type == JoinType::Right {
+            for pair in pairs.iter_mut() {
+                *pair = (pair.1, pair.0);
             }
         }
 
-        // Initialize new Series data vectors
-        let mut series_data: std::collections::HashMap<String, Vec<Option<Value>>> =
-            std::collections::HashMap::new();
-        for col_name in all_column_names.iter() {
-            series_data.insert(col_name.clone(), Vec::new());
+        // The join column is taken from the left frame; other clashing right-frame
+        // columns get the suffix
+        self.combine_rows(other, &pairs, Some(on_column), &options.suffix)
+    }
+
+    /// Joins each row of this frame to the rows of `other` whose interval
+    /// `[right_lower, right_upper]` (both ends inclusive) contains its
+    /// `left_on` value, e.g. to map IP numbers to geo ranges or prices to tiers.
+    ///
+    /// Only rows with at least one match are kept, each repeated per matching
+    /// interval in `other`'s row order. Null values and bounds never match, nor
+    /// do intervals whose lower bound exceeds the upper. The three columns must
+    /// share a data type. Right-frame columns whose names clash get `_right`
+    /// appended.
+    ///
+    /// Intervals are sorted once by lower bound and each value binary-searches
+    /// them, so overlapping intervals are fine and no cross join is built.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let orders = df!("order" => [1, 2, 3], "price" => [5.0, 25.0, 250.0]).unwrap();
+    /// let tiers = df!(
+    ///     "tier" => ["small", "medium"],
+    ///     "min" => [0.0, 10.0],
+    ///     "max" => [9.99, 99.99],
+    /// )
+    /// .unwrap();
+    /// let joined = orders.join_range(&tiers, "price", "min", "max").unwrap();
+    /// assert_eq!(joined.row_count(), 2);
+    /// assert_eq!(
+    ///     joined.get_column("tier").unwrap().get_value(1),
+    ///     Some(Value::String("medium".to_string()))
+    /// );
+    /// ```
+    pub fn join_range(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_lower: &str,
+        right_upper: &str,
+    ) -> Result<Self, VeloxxError> {
+        fn column<'a>(
+            df: &'a DataFrame,
+            name: &str,
+            side: &str,
+        ) -> Result<&'a Series, VeloxxError> {
+            df.get_column(name).ok_or_else(|| {
+                VeloxxError::ColumnNotFound(format!(
+                    "Join column '{name}' not found in {side} DataFrame."
+                ))
+            })
+        }
+        let values = column(self, left_on, "left")?;
+        let lower = column(other, right_lower, "right")?;
+        let upper = column(other, right_upper, "right")?;
+        for bound in [lower, upper] {
+            if bound.data_type() != values.data_type() {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Interval bound '{}' is {:?} but '{}' is {:?}",
+                    bound.name(),
+                    bound.data_type(),
+                    left_on,
+                    values.data_type()
+                )));
+            }
         }
 
-        match join_type {
-            JoinType::Inner => {
-                let other_on_series = other.get_column(on_column).unwrap();
-                let other_join_map: std::collections::HashMap<Value, Vec<usize>> = (0..other
-                    .row_count())
-                    .into_par_iter()
-                    .filter_map(|i| other_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        std::collections::HashMap::new,
-                        |mut map: std::collections::HashMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(std::collections::HashMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
+        let mut intervals: Vec<(Value, Value, usize)> = (0..other.row_count())
+            .filter_map(|i| Some((lower.get_value(i)?, upper.get_value(i)?, i)))
+            .filter(|(low, high, _)| key_cmp(low, high) != Ordering::Greater)
+            .collect();
+        intervals.par_sort_by(|a, b| key_cmp(&a.0, &b.0));
+        // Running maximum of the upper bounds, so a search walking back from the
+        // last interval starting at or below a value knows when to stop
+        let mut reach: Vec<Value> = Vec::with_capacity(intervals.len());
+        for (_, high, _) in &intervals {
+            let next = match reach.last() {
+                Some(previous) if key_cmp(previous, high) == Ordering::Greater => previous.clone(),
+                _ => high.clone(),
+            };
+            reach.push(next);
+        }
 
-                let self_on_series = self.get_column(on_column).unwrap();
-                let results: Vec<Vec<(String, Option<Value>)>> = (0..self.row_count())
-                    .into_par_iter()
-                    .filter_map(|i| {
-                        if let Some(self_join_val) = self_on_series.get_value(i) {
-                            if let Some(other_indices) = other_join_map.get(&self_join_val) {
-                                let self_col_names_cloned = self_col_names.clone();
-                                let all_column_names_cloned = all_column_names.clone();
-                                Some(
-                                    other_indices
-                                        .par_iter()
-                                        .flat_map(move |&other_idx| {
-                                            let mut row_values = Vec::new();
-                                            for col_name in all_column_names_cloned.iter() {
-                                                let value = if self_col_names_cloned
-                                                    .contains(col_name)
-                                                {
-                                                    self.get_column(col_name).unwrap().get_value(i)
-                                                } else {
-                                                    other
-                                                        .get_column(col_name)
-                                                        .unwrap()
-                                                        .get_value(other_idx)
-                                                };
-                                                row_values.push((col_name.clone(), value));
-                                            }
-                                            vec![row_values]
-                                        })
-                                        .collect::<Vec<_>>(),
-                                )
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    })
-                    .flatten()
+        let matches: Vec<Vec<usize>> = (0..self.row_count())
+            .into_par_iter()
+            .map(|i| {
+                let Some(value) = values.get_value(i) else {
+                    return Vec::new();
+                };
+                let end = intervals
+                    .partition_point(|(low, _, _)| key_cmp(low, &value) != Ordering::Greater);
+                let mut found: Vec<usize> = (0..end)
+                    .rev()
+                    .take_while(|&j| key_cmp(&reach[j], &value) != Ordering::Less)
+                    .filter(|&j| key_cmp(&intervals[j].1, &value) != Ordering::Less)
+                    .map(|j| intervals[j].2)
                     .collect();
+                found.sort_unstable();
+                found
+            })
+            .collect();
+        let pairs: Vec<(Option<usize>, Option<usize>)> = matches
+            .iter()
+            .enumerate()
+            .flat_map(|(l, rights)| rights.iter().map(move |&r| (Some(l), Some(r))))
+            .collect();
+        self.combine_rows(other, &pairs, None, "_right")
+    }
 
-                for row_values in results {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
-                    }
-                }
+    /// Builds the joined frame from row pairs, `None` giving nulls: all columns of
+    /// this frame, then those of `other` except `skip_right`, with `suffix`
+    /// appended to names that clash.
+    pub(crate) fn combine_rows(
+        &self,
+        other: &DataFrame,
+        pairs: &[(Option<usize>, Option<usize>)],
+        skip_right: Option<&str>,
+        suffix: &str,
+    ) -> Result<Self, VeloxxError> {
+        let self_col_names: Vec<&String> = self.column_names();
+        let mut sources: Vec<(String, &Series, bool)> = self_col_names
+            .iter()
+            .map(|name| ((*name).clone(), self.get_column(name).unwrap(), true))
+            .collect();
+        for name in other.column_names() {
+            if Some(name.as_str()) == skip_right {
+                continue;
             }
-            JoinType::Left => {
-                let other_on_series = other.get_column(on_column).unwrap();
-                let other_join_map: std::collections::HashMap<Value, Vec<usize>> = (0..other
-                    .row_count())
-                    .into_par_iter()
-                    .filter_map(|i| other_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        std::collections::HashMap::new,
-                        |mut map: std::collections::HashMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(std::collections::HashMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
-
-                let self_on_series = self.get_column(on_column).unwrap();
-                let collected_rows: Vec<Vec<(String, Option<Value>)>> = (0..self.row_count())
-                    .into_par_iter()
-                    .flat_map(|i| {
-                        if let Some(self_join_val) = self_on_series.get_value(i) {
-                            if let Some(other_indices) = other_join_map.get(&self_join_val) {
-                                let self_col_names_cloned = self_col_names.clone();
-                                let all_column_names_cloned = all_column_names.clone();
-                                let _other_col_names_cloned = other_col_names.clone();
-                                other_indices
-                                    .par_iter()
-                                    .map(move |&other_idx| {
-                                        let mut row_values = Vec::new();
-                                        for col_name in all_column_names_cloned.iter() {
-                                            let value = if self_col_names_cloned.contains(col_name)
-                                            {
-                                                self.get_column(col_name).unwrap().get_value(i)
-                                            } else {
-                                                other
-                                                    .get_column(col_name)
-                                                    .unwrap()
-                                                    .get_value(other_idx)
-                                            };
-                                            row_values.push((col_name.clone(), value));
-                                        }
-                                        row_values
-                                    })
-                                    .collect::<Vec<_>>()
-                            } else {
-                                let all_column_names_cloned = all_column_names.clone();
-                                let self_col_names_cloned = self_col_names.clone();
-                                let mut row_values = Vec::new();
-                                for col_name in all_column_names_cloned.iter() {
-                                    let value = if self_col_names_cloned.contains(col_name) {
-                                        self.get_column(col_name).unwrap().get_value(i)
-                                    } else {
-                                        None
-                                    };
-                                    row_values.push((col_name.clone(), value));
-                                }
-                                vec![row_values]
-                            }
-                        } else {
-                            let all_column_names_cloned = all_column_names.clone();
-                            let self_col_names_cloned = self_col_names.clone();
-                            let mut row_values = Vec::new();
-                            for col_name in all_column_names_cloned.iter() {
-                                let value = if self_col_names_cloned.contains(col_name) {
-                                    self.get_column(col_name).unwrap().get_value(i)
-                                } else {
-                                    None
-                                };
-                                row_values.push((col_name.clone(), value));
-                            }
-                            vec![row_values]
-                        }
-                    })
+            let output = if self_col_names.contains(&name) {
+                let suffixed = format!("{}{}", name, suffix);
+                if self.get_column(&suffixed).is_some() || other.get_column(&suffixed).is_some() {
+                    return Err(VeloxxError::InvalidOperation(format!(
+                        "Cannot rename clashing column '{name}' to '{suffixed}': name is taken"
+                    )));
+                }
+                suffixed
+            } else {
+                name.clone()
+            };
+            sources.push((output, other.get_column(name).unwrap(), false));
+        }
+        let new_columns: HashMap<String, Series> = sources
+            .par_iter()
+            .map(|(name, series, from_left)| {
+                let indices: Vec<Option<usize>> = pairs
+                    .iter()
+                    .map(|(left, right)| if *from_left { *left } else { *right })
                     .collect();
+                let mut taken = take_optional(series, &indices);
+                taken.set_name(name);
+                (name.clone(), taken)
+            })
+            .collect();
 
-                for row_values in collected_rows {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
-                    }
-                }
+        DataFrame::new(new_columns)
+    }
+}
+
+/// Picks an algorithm from the key columns' sizes and order.
+fn choose_algorithm(left_keys: &Series, right_keys: &Series) -> JoinAlgorithm {
+    if looks_like_uuids(left_keys) && looks_like_uuids(right_keys) {
+        JoinAlgorithm::Uuid
+    } else if right_keys.len() <= BROADCAST_MAX_ROWS && right_keys.len() < left_keys.len() {
+        JoinAlgorithm::Broadcast
+    } else if left_keys.len() <= BROADCAST_MAX_ROWS && left_keys.len() < right_keys.len() {
+        JoinAlgorithm::BroadcastLeft
+    } else if left_keys.rle_if_compact().is_some() && right_keys.rle_if_compact().is_some() {
+        JoinAlgorithm::RunLength
+    } else if is_sorted(left_keys) && is_sorted(right_keys) {
+        JoinAlgorithm::SortMerge
+    } else {
+        JoinAlgorithm::Hash
+    }
+}
+
+/// Whether the first non-null key of a `String` column is a UUID; [`uuid_matches`]
+/// still checks every key
+fn looks_like_uuids(keys: &Series) -> bool {
+    match keys {
+        Series::String(_, values, validity) => values
+            .iter()
+            .zip(validity)
+            .find(|(_, &valid)| valid)
+            .is_some_and(|(value, _)| parse_uuid(value).is_some()),
+        _ => false,
+    }
+}
+
+/// Ordering of join keys that agrees with `Value` equality (bitwise for `F64`)
+pub(crate) fn key_cmp(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::F64(a), Value::F64(b)) => a.total_cmp(b),
+        _ => a.cmp(b),
+    }
+}
+
+/// Whether the non-null keys are in ascending order
+fn is_sorted(keys: &Series) -> bool {
+    let mut previous: Option<Value> = None;
+    for i in 0..keys.len() {
+        if let Some(value) = keys.get_value(i) {
+            if previous
+                .as_ref()
+                .is_some_and(|p| key_cmp(p, &value) == Ordering::Greater)
+            {
+                return false;
             }
-            JoinType::Right => {
-                let self_on_series = self.get_column(on_column).unwrap();
-                let self_join_map: std::collections::HashMap<Value, Vec<usize>> = (0..self
-                    .row_count())
-                    .into_par_iter()
-                    .filter_map(|i| self_on_series.get_value(i).map(|val| (val, i)))
-                    .fold(
-                        std::collections::HashMap::new,
-                        |mut map: std::collections::HashMap<Value, Vec<usize>>, (val, i)| {
-                            map.entry(val).or_default().push(i);
-                            map
-                        },
-                    )
-                    .reduce(std::collections::HashMap::new, |mut acc, map| {
-                        for (key, value) in map {
-                            acc.entry(key).or_default().extend(value);
-                        }
-                        acc
-                    });
+            previous = Some(value);
+        }
+    }
+    true
+}
 
-                let other_on_series = other.get_column(on_column).unwrap();
-                let collected_rows: Vec<Vec<(String, Option<Value>)>> = (0..other.row_count())
-                    .into_par_iter()
-                    .flat_map(|i| {
-                        if let Some(other_join_val) = other_on_series.get_value(i) {
-                            if let Some(self_indices) = self_join_map.get(&other_join_val) {
-                                let other_col_names_cloned = other_col_names.clone();
-                                let all_column_names_cloned = all_column_names.clone();
-                                let _self_col_names_cloned = self_col_names.clone();
-                                self_indices
-                                    .par_iter()
-                                    .map(move |&self_idx| {
-                                        let mut row_values = Vec::new();
-                                        for col_name in all_column_names_cloned.iter() {
-                                            let value = if other_col_names_cloned.contains(col_name)
-                                            {
-                                                other.get_column(col_name).unwrap().get_value(i)
-                                            } else {
-                                                self.get_column(col_name)
-                                                    .unwrap()
-                                                    .get_value(self_idx)
-                                            };
-                                            row_values.push((col_name.clone(), value));
-                                        }
-                                        row_values
-                                    })
-                                    .collect::<Vec<_>>()
-                            } else {
-                                let all_column_names_cloned = all_column_names.clone();
-                                let other_col_names_cloned = other_col_names.clone();
-                                let mut row_values = Vec::new();
-                                for col_name in all_column_names_cloned.iter() {
-                                    let value = if other_col_names_cloned.contains(col_name) {
-                                        other.get_column(col_name).unwrap().get_value(i)
-                                    } else {
-                                        None
-                                    };
-                                    row_values.push((col_name.clone(), value));
-                                }
-                                vec![row_values]
-                            }
-                        } else {
-                            let all_column_names_cloned = all_column_names.clone();
-                            let other_col_names_cloned = other_col_names.clone();
-                            let mut row_values = Vec::new();
-                            for col_name in all_column_names_cloned.iter() {
-                                let value = if other_col_names_cloned.contains(col_name) {
-                                    other.get_column(col_name).unwrap().get_value(i)
-                                } else {
-                                    None
-                                };
-                                row_values.push((col_name.clone(), value));
-                            }
-                            vec![row_values]
-                        }
-                    })
-                    .collect();
+/// For each probe row, the build rows with an equal (non-null) key, in build order
+fn hash_matches(probe: &Series, build: &Series) -> Vec<Vec<usize>> {
+    let build_map: HashMap<Value, Vec<usize>> = (0..build.len())
+        .into_par_iter()
+        .filter_map(|i| build.get_value(i).map(|val| (val, i)))
+        .fold(
+            HashMap::new,
+            |mut map: HashMap<Value, Vec<usize>>, (val, i)| {
+                map.entry(val).or_default().push(i);
+                map
+            },
+        )
+        .reduce(HashMap::new, |mut acc, map| {
+            for (key, value) in map {
+                acc.entry(key).or_default().extend(value);
+            }
+            acc
+        });
+    (0..probe.len())
+        .into_par_iter()
+        .map(|i| {
+            probe
+                .get_value(i)
+                .and_then(|val| build_map.get(&val).cloned())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Like [`hash_matches`] over keys parsed as UUIDs; `None` unless every non-null
+/// key on both sides is a UUID
+fn uuid_matches(probe: &Series, build: &Series) -> Option<Vec<Vec<usize>>> {
+    let (probe_keys, build_keys) = rayon::join(|| uuid_keys(probe), || uuid_keys(build));
+    let (probe_keys, build_keys) = (probe_keys?, build_keys?);
+    let mut build_map: HashMap<u128, Vec<usize>> = HashMap::new();
+    for (i, key) in build_keys.into_iter().enumerate() {
+        if let Some(key) = key {
+            build_map.entry(key).or_default().push(i);
+        }
+    }
+    Some(
+        probe_keys
+            .into_par_iter()
+            .map(|key| {
+                key.and_then(|key| build_map.get(&key).cloned())
+                    .unwrap_or_default()
+            })
+            .collect(),
+    )
+}
+
+/// Like [`hash_matches`], with the table built serially for a small build side
+fn broadcast_matches(probe: &Series, build: &Series) -> Vec<Vec<usize>> {
+    let mut build_map: HashMap<Value, Vec<usize>> = HashMap::new();
+    for i in 0..build.len() {
+        if let Some(val) = build.get_value(i) {
+            build_map.entry(val).or_default().push(i);
+        }
+    }
+    (0..probe.len())
+        .into_par_iter()
+        .map(|i| {
+            probe
+                .get_value(i)
+                .and_then(|val| build_map.get(&val).cloned())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Like [`hash_matches`] for a small probe side: the table is built over the probe
+/// keys and the build side is streamed in parallel chunks.
+fn broadcast_reverse_matches(probe: &Series, build: &Series) -> Vec<Vec<usize>> {
+    let mut probe_map: HashMap<Value, Vec<usize>> = HashMap::new();
+    for i in 0..probe.len() {
+        if let Some(val) = probe.get_value(i) {
+            probe_map.entry(val).or_default().push(i);
+        }
+    }
+    // Chunks are collected in order, so each probe row sees build rows in order
+    let found: Vec<(usize, usize)> = (0..build.len())
+        .into_par_iter()
+        .flat_map_iter(|b| {
+            let probes = build
+                .get_value(b)
+                .and_then(|val| probe_map.get(&val))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            probes.iter().map(move |&p| (p, b))
+        })
+        .collect();
+    let mut matches = vec![Vec::new(); probe.len()];
+    for (p, b) in found {
+        matches[p].push(b);
+    }
+    matches
+}
 
-                for row_values in collected_rows {
-                    for (col_name, value) in row_values {
-                        series_data.get_mut(&col_name).unwrap().push(value);
-                    }
+/// Sort-merge matching; sorting is skipped for key columns that are already sorted.
+fn sort_merge_matches(probe: &Series, build: &Series) -> Vec<Vec<usize>> {
+    let mut matches = vec![Vec::new(); probe.len()];
+    // Keys of different types never compare equal
+    if probe.data_type() != build.data_type() {
+        return matches;
+    }
+    let sorted_keys = |series: &Series| -> Vec<(Value, usize)> {
+        let mut keys: Vec<(Value, usize)> = (0..series.len())
+            .filter_map(|i| series.get_value(i).map(|val| (val, i)))
+            .collect();
+        if !is_sorted(series) {
+            // Stable, so rows with equal keys keep their original order
+            keys.par_sort_by(|a, b| key_cmp(&a.0, &b.0));
+        }
+        keys
+    };
+    let (probe_keys, build_keys) = rayon::join(|| sorted_keys(probe), || sorted_keys(build));
+
+    let (mut p, mut b) = (0, 0);
+    while p < probe_keys.len() && b < build_keys.len() {
+        match key_cmp(&probe_keys[p].0, &build_keys[b].0) {
+            Ordering::Less => p += 1,
+            Ordering::Greater => b += 1,
+            Ordering::Equal => {
+                let key = &probe_keys[p].0;
+                let run_end = build_keys[b..]
+                    .iter()
+                    .position(|(val, _)| key_cmp(val, key) != Ordering::Equal)
+                    .map_or(build_keys.len(), |offset| b + offset);
+                let run: Vec<usize> = build_keys[b..run_end].iter().map(|(_, i)| *i).collect();
+                while p < probe_keys.len() && key_cmp(&probe_keys[p].0, key) == Ordering::Equal {
+                    matches[probe_keys[p].1] = run.clone();
+                    p += 1;
                 }
+                b = run_end;
             }
         }
+    }
+    matches
+}
 
-        // Create new Series objects
-        for (col_name, data_vec) in series_data {
-            let col_data_type = column_types.get(&col_name).unwrap();
-            let new_series = match col_data_type {
-                crate::types::DataType::I32 => Series::new_i32(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::I32(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::F64 => Series::new_f64(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::F64(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::Bool => Series::new_bool(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::Bool(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::String => Series::new_string(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::String(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-                crate::types::DataType::DateTime => Series::new_datetime(
-                    &col_name,
-                    data_vec
-                        .into_iter()
-                        .map(|x| {
-                            x.and_then(|v| {
-                                if let Value::DateTime(val) = v {
-                                    Some(val)
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect(),
-                ),
-            };
-            new_columns.insert(col_name, new_series);
+/// Run-length matching: build rows are indexed a run at a time and each probe run
+/// is looked up once, sharing its matches across the run's rows.
+fn run_length_matches(probe: &Series, build: &Series) -> Vec<Vec<usize>> {
+    let (probe_runs, build_runs) = rayon::join(|| probe.to_rle(), || build.to_rle());
+    let mut build_map: HashMap<&Value, Vec<usize>> = HashMap::new();
+    for (value, rows) in build_runs.runs() {
+        if let Some(value) = value {
+            build_map.entry(value).or_default().extend(rows);
         }
+    }
+    let mut matches = Vec::with_capacity(probe.len());
+    for (value, rows) in probe_runs.runs() {
+        let found = value
+            .and_then(|value| build_map.get(value))
+            .cloned()
+            .unwrap_or_default();
+        matches.extend(std::iter::repeat_n(found, rows.len()));
+    }
+    matches
+}
 
-        DataFrame::new(new_columns)
+/// Gathers `indices` from `series`; `None` produces a null.
+pub(crate) fn take_optional(series: &Series, indices: &[Option<usize>]) -> Series {
+    fn gather<T: Clone>(
+        data: &[T],
+        validity: &[bool],
+        indices: &[Option<usize>],
+    ) -> Vec<Option<T>> {
+        indices
+            .iter()
+            .map(|i| i.filter(|&i| validity[i]).map(|i| data[i].clone()))
+            .collect()
+    }
+    match series {
+        Series::I32(name, data, validity) => Series::new_i32(name, gather(data, validity, indices)),
+        Series::F64(name, data, validity) => Series::new_f64(name, gather(data, validity, indices)),
+        Series::Bool(name, data, validity) => {
+            Series::new_bool(name, gather(data, validity, indices))
+        }
+        Series::String(name, data, validity) => {
+            Series::new_string(name, gather(data, validity, indices))
+        }
+        Series::DateTime(name, data, validity) => {
+            Series::new_datetime(name, gather(data, validity, indices))
+        }
+        Series::Binary(name, data, validity) => {
+            Series::new_binary(name, gather(data, validity, indices))
+        }
     }
 }
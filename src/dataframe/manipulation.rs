@@ -22,6 +22,7 @@ impl DataFrame {
         let filtered_df = DataFrame {
             columns: filtered_columns,
             row_count: row_indices.len(),
+            constraints: self.constraints.clone(),
         };
 
         // Step 3: Group-by and aggregate on filtered DataFrame
@@ -29,6 +30,7 @@ impl DataFrame {
         grouped_df.agg(aggregations)
     }
 }
+use crate::instrument::instrumented;
 use crate::VeloxxError;
 use crate::{
     conditions::Condition,
@@ -85,7 +87,7 @@ impl DataFrame {
                 return Err(VeloxxError::ColumnNotFound(name));
             }
         }
-        DataFrame::new(selected_columns)
+        Ok(DataFrame::new(selected_columns)?.carry_constraints(&self.constraints, true))
     }
 
     /// Drops specified columns from the `DataFrame`.
@@ -126,7 +128,7 @@ impl DataFrame {
                 return Err(VeloxxError::ColumnNotFound(name));
             }
         }
-        DataFrame::new(new_columns)
+        Ok(DataFrame::new(new_columns)?.carry_constraints(&self.constraints, true))
     }
 
     /// Renames a column in the `DataFrame`.
@@ -177,6 +179,84 @@ impl DataFrame {
         }
     }
 
+    /// Renames several columns at once from `(old_name, new_name)` pairs.
+    ///
+    /// All renames apply together, so names can be swapped, and the frame is
+    /// copied once rather than once per column. Columns not in `mapping` keep
+    /// their names.
+    ///
+    /// # Errors
+    ///
+    /// `ColumnNotFound` if an old name does not exist, and `InvalidOperation`
+    /// if a column is renamed twice or two columns would end up with the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let df = df!("Order ID" => [1, 2], "Unit Price" => [9.5, 3.0]).unwrap();
+    /// let renamed = df.rename(&[("Order ID", "order_id"), ("Unit Price", "price")]).unwrap();
+    /// assert!(renamed.get_column("order_id").is_some());
+    /// assert!(renamed.get_column("price").is_some());
+    /// ```
+    pub fn rename(&self, mapping: &[(&str, &str)]) -> Result<Self, VeloxxError> {
+        let mut renames: HashMap<&str, String> = HashMap::with_capacity(mapping.len());
+        for &(old_name, new_name) in mapping {
+            if !self.columns.contains_key(old_name) {
+                return Err(VeloxxError::ColumnNotFound(old_name.to_string()));
+            }
+            if renames.insert(old_name, new_name.to_string()).is_some() {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{old_name}' is renamed more than once."
+                )));
+            }
+        }
+        self.rename_all(&renames)
+    }
+
+    /// Renames every column to `f(name)`, e.g. to lowercase all column names.
+    ///
+    /// Fails with `InvalidOperation` if two columns would end up with the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let df = df!("Order ID" => [1, 2], "Unit Price" => [9.5, 3.0]).unwrap();
+    /// let snake = df.rename_with(|name| name.to_lowercase().replace(' ', "_")).unwrap();
+    /// assert!(snake.get_column("order_id").is_some());
+    /// assert!(snake.get_column("unit_price").is_some());
+    /// ```
+    pub fn rename_with<F>(&self, f: F) -> Result<Self, VeloxxError>
+    where
+        F: Fn(&str) -> String,
+    {
+        let renames: HashMap<&str, String> = self
+            .columns
+            .keys()
+            .map(|name| (name.as_str(), f(name)))
+            .collect();
+        self.rename_all(&renames)
+    }
+
+    fn rename_all(&self, renames: &HashMap<&str, String>) -> Result<Self, VeloxxError> {
+        let mut new_columns: HashMap<String, Series> = HashMap::with_capacity(self.columns.len());
+        for (name, series) in &self.columns {
+            let new_name = renames.get(name.as_str()).unwrap_or(name);
+            if new_columns.contains_key(new_name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Renaming would create more than one column named '{new_name}'."
+                )));
+            }
+            let mut series = series.clone();
+            series.set_name(new_name);
+            new_columns.insert(new_name.clone(), series);
+        }
+        DataFrame::new(new_columns)
+    }
+
     /// Sorts the `DataFrame` by one or more columns.
     ///
     /// This method creates a new `DataFrame` with rows sorted according to the values
@@ -218,6 +298,14 @@ impl DataFrame {
     /// assert_eq!(sorted_df_name_desc.get_column("name").unwrap().get_value(0), Some(Value::String("Charlie".to_string())));
     /// ```
     pub fn sort(&self, by_columns: Vec<String>, ascending: bool) -> Result<Self, VeloxxError> {
+        instrumented!(
+            "sort",
+            { rows_in = self.row_count, by = ?by_columns, ascending },
+            self.sort_rows(&by_columns, ascending)
+        )
+    }
+
+    fn sort_rows(&self, by_columns: &[String], ascending: bool) -> Result<Self, VeloxxError> {
         if self.row_count == 0 {
             return Ok(self.clone());
         }
@@ -365,11 +453,40 @@ impl DataFrame {
                         })
                         .collect(),
                 ),
+                crate::types::DataType::Binary => Series::new_binary(
+                    &col_name,
+                    data_vec
+                        .into_iter()
+                        .map(|x| {
+                            x.and_then(|v| {
+                                if let Value::Binary(val) = v {
+                                    Some(val)
+                                } else {
+                                    None
+                                }
+                            })
+                        })
+                        .collect(),
+                ),
             };
             new_series_map.insert(col_name, new_series);
         }
 
-        DataFrame::new(new_series_map)
+        Ok(DataFrame::new(new_series_map)?.carry_constraints(&self.constraints, false))
+    }
+
+    /// Sorts the rows by the IP addresses in a `String` column, numerically
+    /// rather than as text (so `9.0.0.1` comes before `10.0.0.1`).
+    pub fn sort_by_ip(&self, column: &str, ascending: bool) -> Result<Self, VeloxxError> {
+        let ips = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?
+            .to_ip()?;
+        let mut indices = ips.argsort();
+        if !ascending {
+            indices.reverse();
+        }
+        self.filter_by_indices(&indices)
     }
 
     /// Adds a new column to the `DataFrame` based on an expression.
@@ -430,81 +547,226 @@ impl DataFrame {
         }
 
         let mut evaluated_values: Vec<Value> = Vec::with_capacity(self.row_count);
-        let mut inferred_type: Option<crate::types::DataType> = None;
-
         for i in 0..self.row_count {
-            let evaluated_value = expr.evaluate(self, i)?;
-            if inferred_type.is_none() && evaluated_value != Value::Null {
-                inferred_type = Some(evaluated_value.data_type());
+            evaluated_values.push(expr.evaluate(self, i)?);
+        }
+        let new_series = series_from_values(new_col_name, evaluated_values);
+
+        new_columns.insert(new_col_name.to_string(), new_series);
+        Ok(DataFrame::new(new_columns)?.carry_constraints(&self.constraints, true))
+    }
+
+    /// Adds several derived columns at once, like chained
+    /// [`DataFrame::with_column`] calls but copying the frame only once.
+    ///
+    /// Every expression is evaluated against this frame as it is, in parallel
+    /// across expressions, so one new column cannot refer to another; use
+    /// [`ExpressionFusion::fused_with_columns`] for that.
+    ///
+    /// [`ExpressionFusion::fused_with_columns`]: crate::performance::expression_fusion::ExpressionFusion::fused_with_columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("price" => [10.0, 20.0], "qty" => [3.0, 1.0]).unwrap();
+    /// let col = |name: &str| Box::new(Expr::Column(name.to_string()));
+    /// let derived = df
+    ///     .with_columns(&[
+    ///         ("total", Expr::Multiply(col("price"), col("qty"))),
+    ///         ("discounted", Expr::Multiply(col("price"), Box::new(Expr::Literal(Value::F64(0.9))))),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(derived.column_count(), 4);
+    /// assert_eq!(derived.get_column("total").unwrap().get_value(0), Some(Value::F64(30.0)));
+    /// ```
+    pub fn with_columns(&self, columns: &[(&str, Expr)]) -> Result<Self, VeloxxError> {
+        for (i, (name, _)) in columns.iter().enumerate() {
+            if self.columns.contains_key(*name) || columns[..i].iter().any(|(n, _)| n == name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{name}' already exists."
+                )));
             }
-            evaluated_values.push(evaluated_value);
         }
 
-        let new_series = match inferred_type {
-            Some(DataType::I32) => Series::new_i32(
-                new_col_name,
-                evaluated_values
-                    .into_iter()
-                    .map(|v| if let Value::I32(x) = v { Some(x) } else { None })
-                    .collect(),
-            ),
-            Some(DataType::F64) => Series::new_f64(
-                new_col_name,
-                evaluated_values
-                    .into_iter()
-                    .map(|v| if let Value::F64(x) = v { Some(x) } else { None })
-                    .collect(),
-            ),
-            Some(DataType::Bool) => Series::new_bool(
-                new_col_name,
-                evaluated_values
-                    .into_iter()
-                    .map(|v| {
-                        if let Value::Bool(x) = v {
-                            Some(x)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
-            ),
-            Some(DataType::String) => Series::new_string(
-                new_col_name,
-                evaluated_values
-                    .into_iter()
-                    .map(|v| {
-                        if let Value::String(x) = v {
-                            Some(x)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
-            ),
-            Some(DataType::DateTime) => Series::new_datetime(
-                new_col_name,
-                evaluated_values
-                    .into_iter()
-                    .map(|v| {
-                        if let Value::DateTime(x) = v {
-                            Some(x)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
-            ),
-            None => Series::new_string(new_col_name, vec![None; self.row_count]), // All nulls, default to String
-        };
+        use rayon::prelude::*;
+        let derived = columns
+            .par_iter()
+            .map(|(name, expr)| {
+                let values = (0..self.row_count)
+                    .map(|row| expr.evaluate(self, row))
+                    .collect::<Result<Vec<Value>, VeloxxError>>()?;
+                Ok(series_from_values(name, values))
+            })
+            .collect::<Result<Vec<Series>, VeloxxError>>()?;
 
-        new_columns.insert(new_col_name.to_string(), new_series);
+        let mut new_columns = self.columns.clone();
+        for ((name, _), series) in columns.iter().zip(derived) {
+            new_columns.insert(name.to_string(), series);
+        }
         DataFrame::new(new_columns)
     }
 
+    /// Projects the `DataFrame` onto a list of expressions, like SQL
+    /// `SELECT a, b, a * b AS product`.
+    ///
+    /// Each expression must be a column reference or be named with
+    /// [`Expr::alias`]. Plain and renamed columns are copied as they are; the
+    /// remaining expressions are all evaluated in a single pass over the rows.
+    /// Expressions see only the original columns, not each other's outputs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("a" => [2, 3], "b" => [4, 5], "c" => [0, 0]).unwrap();
+    /// let product = Expr::Multiply(
+    ///     Box::new(Expr::Column("a".to_string())),
+    ///     Box::new(Expr::Column("b".to_string())),
+    /// );
+    /// let projected = df
+    ///     .select_exprs(&[
+    ///         Expr::Column("a".to_string()),
+    ///         Expr::Column("b".to_string()).alias("bee"),
+    ///         product.alias("product"),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(projected.column_count(), 3);
+    /// assert_eq!(projected.get_column("product").unwrap().get_value(1), Some(Value::I32(15)));
+    /// ```
+    pub fn select_exprs(&self, exprs: &[Expr]) -> Result<Self, VeloxxError> {
+        let mut selected: HashMap<String, Series> = HashMap::new();
+        let mut computed: Vec<(&str, &Expr)> = Vec::new();
+        for expr in exprs {
+            let name = expr.output_name().ok_or_else(|| {
+                VeloxxError::InvalidOperation(format!(
+                    "Expression {expr:?} needs a name; use Expr::alias"
+                ))
+            })?;
+            if selected.contains_key(name) || computed.iter().any(|(n, _)| *n == name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{name}' is selected more than once."
+                )));
+            }
+            let mut source = expr;
+            while let Expr::Alias(inner, _) = source {
+                source = inner;
+            }
+            match source {
+                Expr::Column(column) => {
+                    let mut series = self
+                        .get_column(column)
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?
+                        .clone();
+                    series.set_name(name);
+                    selected.insert(name.to_string(), series);
+                }
+                _ => computed.push((name, source)),
+            }
+        }
+
+        let mut values = vec![Vec::with_capacity(self.row_count); computed.len()];
+        for row in 0..self.row_count {
+            for ((_, expr), column) in computed.iter().zip(values.iter_mut()) {
+                column.push(expr.evaluate(self, row)?);
+            }
+        }
+        for ((name, _), values) in computed.into_iter().zip(values) {
+            selected.insert(name.to_string(), series_from_values(name, values));
+        }
+        DataFrame::new(selected)
+    }
+
+    /// Returns a copy with `column` set to `expr` on the rows matching `condition`,
+    /// like SQL `UPDATE ... SET column = expr WHERE condition`.
+    ///
+    /// The condition becomes a row mask in one columnar pass, as in
+    /// [`DataFrame::filter`]. Other expressions are evaluated once over every
+    /// row, as in [`DataFrame::with_columns`], so they must not fail on rows the
+    /// condition leaves out; the result is then copied over the masked rows in
+    /// bulk. Values must have the column's type, and a `Value::Null` literal
+    /// clears the matching cells. The frame's constraints are kept, and the
+    /// update fails if it breaks one on `column`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::conditions::Condition;
+    /// use veloxx::df;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("price" => [5.0, 50.0, 500.0], "tier" => ["low", "low", "low"]).unwrap();
+    /// let updated = df
+    ///     .update_where(
+    ///         &Condition::Gt("price".to_string(), Value::F64(10.0)),
+    ///         "tier",
+    ///         &Expr::Literal(Value::String("high".to_string())),
+    ///     )
+    ///     .unwrap();
+    /// let tier = updated.get_column("tier").unwrap();
+    /// assert_eq!(tier.get_value(0), Some(Value::String("low".to_string())));
+    /// assert_eq!(tier.get_value(2), Some(Value::String("high".to_string())));
+    /// ```
+    pub fn update_where(
+        &self,
+        condition: &Condition,
+        column: &str,
+        expr: &Expr,
+    ) -> Result<Self, VeloxxError> {
+        let mut updated = self
+            .get_column(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?
+            .clone();
+        let mask = crate::performance::adaptive_filter::adaptive_mask(self, condition)?;
+        match expr {
+            Expr::Column(source) => {
+                let source = self
+                    .get_column(source)
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(source.to_string()))?;
+                assign_where(&mut updated, &mask, source)?;
+            }
+            _ => {
+                let values = match expr {
+                    Expr::Literal(value) => {
+                        if *value != Value::Null && value.data_type() != updated.data_type() {
+                            return Err(VeloxxError::DataTypeMismatch(format!(
+                                "Cannot store {:?} in column '{}' of type {:?}",
+                                value,
+                                column,
+                                updated.data_type()
+                            )));
+                        }
+                        vec![value.clone(); self.row_count]
+                    }
+                    _ => (0..self.row_count)
+                        .map(|row| expr.evaluate(self, row))
+                        .collect::<Result<Vec<Value>, VeloxxError>>()?,
+                };
+                let evaluated = values_like(&updated, values);
+                assign_where(&mut updated, &mask, &evaluated)?;
+            }
+        }
+
+        let mut result = self.clone();
+        result.columns.insert(column.to_string(), updated);
+        result.validate_column_constraints(column)?;
+        Ok(result)
+    }
+
     /// Filters the `DataFrame` based on a given condition.
     ///
     /// This method evaluates the provided `Condition` for each row. Only rows for which
     /// the condition evaluates to `true` are included in the new `DataFrame`.
+    /// Conditions joined with `And` are evaluated adaptively: the conjunct that
+    /// rejects the most rows on a small sample runs first, and later conjuncts
+    /// only look at the rows that are still selected.
     ///
     /// # Arguments
     ///
@@ -542,6 +804,14 @@ impl DataFrame {
             return Ok(filtered_df);
         }
 
+        // AND chains run their most selective conjuncts first
+        if let Condition::And(..) = condition {
+            let mask = crate::performance::adaptive_filter::adaptive_mask(self, condition)?;
+            let row_indices_to_keep: Vec<usize> =
+                (0..self.row_count).filter(|&i| mask[i]).collect();
+            return self.filter_by_indices(&row_indices_to_keep);
+        }
+
         // Fallback to row-by-row evaluation for complex conditions
         let mut row_indices_to_keep: Vec<usize> = Vec::new();
 
@@ -571,8 +841,11 @@ impl DataFrame {
             None => return Ok(None),
         };
 
-        // Create bit mask using vectorized operations
-        let mask = VectorizedFilter::fast_filter_single_column(series, comparison_value, op)?;
+        // Compare once per run when the column is run-length compact, e.g. after a sort
+        let mask = match series.rle_if_compact() {
+            Some(runs) => runs.comparison_mask(comparison_value, op)?,
+            None => VectorizedFilter::fast_filter_single_column(series, comparison_value, op)?,
+        };
 
         // Apply mask to all columns
         let mut filtered_columns = std::collections::HashMap::new();
@@ -585,6 +858,7 @@ impl DataFrame {
         Ok(Some(Self {
             columns: filtered_columns,
             row_count: filtered_row_count,
+            constraints: self.constraints.clone(),
         }))
     }
 
@@ -626,6 +900,7 @@ impl DataFrame {
             return Ok(DataFrame {
                 columns: std::collections::HashMap::new(),
                 row_count: 0,
+                constraints: Vec::new(),
             });
         }
 
@@ -636,7 +911,59 @@ impl DataFrame {
             new_columns.insert(col_name.clone(), new_series);
         }
 
-        DataFrame::new(new_columns)
+        let in_order = row_indices.windows(2).all(|pair| pair[0] < pair[1]);
+        Ok(DataFrame::new(new_columns)?.carry_constraints(&self.constraints, in_order))
+    }
+
+    /// Keeps the rows where a boolean `mask` is `true`; null entries count as `false`.
+    ///
+    /// The mask can come from any frame with the same number of rows, so one mask
+    /// can be built once and reused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::series::Series;
+    ///
+    /// let df = df!("age" => [15, 30, 45], "member" => [true, false, true]).unwrap();
+    /// let adult = Series::new_bool("adult", vec![Some(false), Some(true), Some(true)]);
+    /// let mask = (&adult & df.get_column("member").unwrap()).unwrap();
+    /// assert_eq!(df.filter_mask(&mask).unwrap().row_count(), 1);
+    /// ```
+    pub fn filter_mask(&self, mask: &Series) -> Result<Self, VeloxxError> {
+        use crate::performance::specialized_structures::BitPackedArray;
+        use crate::performance::vectorized_filter::VectorizedFilter;
+
+        let Series::Bool(_, values, bitmap) = mask else {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Filter mask '{}' must be Bool, not {:?}",
+                mask.name(),
+                mask.data_type()
+            )));
+        };
+        if values.len() != self.row_count {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Filter mask has {} rows but the DataFrame has {}",
+                values.len(),
+                self.row_count
+            )));
+        }
+        let mut bits = BitPackedArray::new(values.len());
+        for (&v, &b) in values.iter().zip(bitmap.iter()) {
+            bits.push(v && b);
+        }
+
+        let mut filtered_columns = HashMap::with_capacity(self.columns.len());
+        for (name, series) in &self.columns {
+            let filtered_series = VectorizedFilter::filter_series_with_mask(series, &bits)?;
+            filtered_columns.insert(name.clone(), filtered_series);
+        }
+        Ok(Self {
+            columns: filtered_columns,
+            row_count: bits.count_ones(),
+            constraints: self.constraints.clone(),
+        })
     }
 
     /// Appends another `DataFrame` to the end of this `DataFrame`.
@@ -723,7 +1050,71 @@ impl DataFrame {
             new_columns.insert(col_name.clone(), appended_series);
         }
 
-        DataFrame::new(new_columns)
+        DataFrame::new(new_columns)?.with_constraints(self.constraints.clone())
+    }
+
+    /// Merges two frames that are both sorted ascending by `on` into one
+    /// frame sorted by `on`, in a single O(n + m) pass instead of a re-sort.
+    ///
+    /// Nulls sort first, as in [`DataFrame::sort`], and rows with equal keys
+    /// keep their order with this frame's rows first. The frames must have the
+    /// same columns as for [`DataFrame::append`]; a frame that is not sorted by
+    /// `on` gives `VeloxxError::InvalidOperation`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let web = df!("ts" => [1, 4, 6], "source" => ["web", "web", "web"]).unwrap();
+    /// let app = df!("ts" => [2, 4, 9], "source" => ["app", "app", "app"]).unwrap();
+    /// let events = web.merge_sorted(&app, "ts").unwrap();
+    /// let ts: Vec<_> = (0..6).map(|i| events.get_column("ts").unwrap().get_value(i)).collect();
+    /// assert_eq!(ts, [1, 2, 4, 4, 6, 9].map(|t| Some(Value::I32(t))));
+    /// assert_eq!(
+    ///     events.get_column("source").unwrap().get_value(2),
+    ///     Some(Value::String("web".to_string()))
+    /// );
+    /// ```
+    pub fn merge_sorted(&self, other: &DataFrame, on: &str) -> Result<Self, VeloxxError> {
+        let keys = |df: &DataFrame, side: &str| -> Result<Vec<Option<Value>>, VeloxxError> {
+            let series = df.get_column(on).ok_or_else(|| {
+                VeloxxError::ColumnNotFound(format!(
+                    "Merge column '{on}' not found in {side} DataFrame."
+                ))
+            })?;
+            let keys: Vec<Option<Value>> = (0..series.len()).map(|i| series.get_value(i)).collect();
+            if let Some(row) = keys.windows(2).position(|pair| pair[0] > pair[1]) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "{side} DataFrame is not sorted by '{on}' at row {}",
+                    row + 1
+                )));
+            }
+            Ok(keys)
+        };
+        let left = keys(self, "Left")?;
+        let right = keys(other, "Right")?;
+        // Also checks that the columns line up
+        let stacked = self.append(other)?;
+
+        let mut order = Vec::with_capacity(left.len() + right.len());
+        let (mut l, mut r) = (0, 0);
+        while l < left.len() && r < right.len() {
+            if right[r] < left[l] {
+                order.push(left.len() + r);
+                r += 1;
+            } else {
+                order.push(l);
+                l += 1;
+            }
+        }
+        order.extend(l..left.len());
+        order.extend(left.len() + r..left.len() + right.len());
+        if order.is_empty() {
+            return Ok(stacked);
+        }
+        stacked.filter_by_indices(&order)
     }
 
     /// Groups the `DataFrame` by one or more columns.
@@ -763,6 +1154,107 @@ impl DataFrame {
         crate::dataframe::group_by::GroupedDataFrame::new(self, group_columns)
     }
 
+    /// Groups the `DataFrame` by computed keys, such as timestamps truncated to
+    /// the day, without adding helper columns to the frame first.
+    ///
+    /// Each key is named by [`Expr::output_name`]: its alias, or the column it
+    /// reads for a bare or truncated column. A computed key replaces a column
+    /// of the same name in the grouped frame, so aggregate other columns.
+    /// Computed keys are evaluated in parallel with each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut columns = HashMap::new();
+    /// columns.insert(
+    ///     "ts".to_string(),
+    ///     Series::new_datetime("ts", vec![Some(3_600), Some(7_200), Some(90_000)]),
+    /// );
+    /// columns.insert(
+    ///     "sales".to_string(),
+    ///     Series::new_f64("sales", vec![Some(10.0), Some(20.0), Some(5.0)]),
+    /// );
+    /// let df = DataFrame::new(columns).unwrap();
+    /// let day = Expr::dt_truncate(Expr::Column("ts".to_string()), "1d");
+    /// let daily = df
+    ///     .group_by_exprs(&[day])
+    ///     .unwrap()
+    ///     .agg(vec![("sales", "sum")])
+    ///     .unwrap()
+    ///     .sort(vec!["ts".to_string()], true)
+    ///     .unwrap();
+    /// assert_eq!(daily.get_column("ts").unwrap().get_value(1), Some(Value::DateTime(86_400)));
+    /// assert_eq!(daily.get_column("sales_sum").unwrap().get_value(0), Some(Value::F64(30.0)));
+    /// ```
+    pub fn group_by_exprs(
+        &self,
+        keys: &[Expr],
+    ) -> Result<crate::dataframe::group_by::GroupedDataFrame<'_>, VeloxxError> {
+        let mut names: Vec<String> = Vec::with_capacity(keys.len());
+        let mut derived: Vec<(&str, &Expr)> = Vec::new();
+        for key in keys {
+            let name = key.output_name().ok_or_else(|| {
+                VeloxxError::InvalidOperation(format!(
+                    "Group key {key:?} needs a name; use Expr::alias"
+                ))
+            })?;
+            if names.iter().any(|n| n == name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{name}' is grouped by more than once."
+                )));
+            }
+            names.push(name.to_string());
+            let mut source = key;
+            while let Expr::Alias(inner, _) = source {
+                source = inner;
+            }
+            match source {
+                Expr::Column(column) if column == name => {
+                    if !self.columns.contains_key(column) {
+                        return Err(VeloxxError::ColumnNotFound(column.to_string()));
+                    }
+                }
+                _ => derived.push((name, source)),
+            }
+        }
+        if derived.is_empty() {
+            return self.group_by(names);
+        }
+
+        use rayon::prelude::*;
+        let derived = derived
+            .par_iter()
+            .map(|(name, expr)| match expr {
+                Expr::Column(column) => {
+                    let mut series = self
+                        .get_column(column)
+                        .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?
+                        .clone();
+                    series.set_name(name);
+                    Ok(series)
+                }
+                _ => {
+                    let values = (0..self.row_count)
+                        .map(|row| expr.evaluate(self, row))
+                        .collect::<Result<Vec<Value>, VeloxxError>>()?;
+                    Ok(series_from_values(name, values))
+                }
+            })
+            .collect::<Result<Vec<Series>, VeloxxError>>()?;
+
+        let mut columns = self.columns.clone();
+        for series in derived {
+            columns.insert(series.name().to_string(), series);
+        }
+        crate::dataframe::group_by::GroupedDataFrame::from_owned(DataFrame::new(columns)?, names)
+    }
+
     /// High-performance combined groupby and aggregation for simple cases
     /// This method avoids the expensive GroupedDataFrame creation entirely
     pub fn groupby_agg(
@@ -855,124 +1347,35 @@ impl DataFrame {
         }
     }
 
-    /// Generates descriptive statistics for the `DataFrame`.
-    ///
-    /// This method calculates various statistical measures for each column in the DataFrame.
-    /// For numeric columns (`I32`, `F64`, `DateTime`), it computes count, mean, standard deviation,
-    /// minimum, maximum, and median. For non-numeric columns (`Bool`, `String`), only the count
-    /// of non-null values is provided.
-    ///
-    /// # Returns
+    /// Returns [`ColumnStats`](crate::series::statistics::ColumnStats) for every
+    /// column, sorted by column name.
     ///
-    /// A `Result` which is `Ok(DataFrame)` containing a new `DataFrame` where each row represents
-    /// a statistical measure (e.g., "count", "mean"), and each column represents an original
-    /// column from the input DataFrame. Returns `Err(VeloxxError)` if any statistical calculation fails.
+    /// These are the statistics the lazy optimizer uses to order joins.
     ///
     /// # Examples
     ///
     /// ```rust
+    /// use std::collections::HashMap;
     /// use veloxx::dataframe::DataFrame;
     /// use veloxx::series::Series;
-    /// use std::collections::HashMap;
+    /// use veloxx::types::Value;
     ///
     /// let mut columns = HashMap::new();
-    /// columns.insert("age".to_string(), Series::new_i32("age", vec![Some(20), Some(30), Some(25), None, Some(35)]));
-    /// columns.insert("city".to_string(), Series::new_string("city", vec![Some("NY".to_string()), Some("LA".to_string()), Some("NY".to_string()), Some("SF".to_string()), None]));
+    /// columns.insert("age".to_string(), Series::new_i32("age", vec![Some(30), None, Some(25), Some(30)]));
     /// let df = DataFrame::new(columns).unwrap();
     ///
-    /// let description_df = df.describe().unwrap();
-    /// println!("Descriptive Statistics:\n{}", description_df);
-    /// // Expected output (column order might vary):
-    /// // column         count          mean           std            min            max            median         
-    /// // --------------- --------------- --------------- --------------- --------------- --------------- ---------------
-    /// // age            4              27.50          6.45           Value::I32(20) Value::I32(35) Value::F64(27.50)
-    /// // city           4              null           null           null           null           null           
+    /// let stats = &df.stats()[0];
+    /// assert_eq!((stats.null_count, stats.n_unique), (1, 2));
+    /// assert_eq!(stats.min, Some(Value::I32(25)));
+    /// assert!(!stats.sorted);
     /// ```
-    pub fn describe(&self) -> Result<DataFrame, VeloxxError> {
-        let mut descriptions: std::collections::HashMap<String, Series> =
-            std::collections::HashMap::new();
-        let mut counts: Vec<Option<i32>> = Vec::new();
-        let mut means: Vec<Option<f64>> = Vec::new();
-        let mut std_devs: Vec<Option<f64>> = Vec::new();
-        let mut mins: Vec<Option<Value>> = Vec::new();
-        let mut maxs: Vec<Option<Value>> = Vec::new();
-        let mut medians: Vec<Option<Value>> = Vec::new();
-
-        let mut column_names_vec: Vec<String> = Vec::new();
-
-        for (col_name, series) in self.columns.iter() {
-            column_names_vec.push(col_name.clone());
-            counts.push(Some(series.len() as i32));
-
-            match series.data_type() {
-                crate::types::DataType::I32
-                | crate::types::DataType::F64
-                | crate::types::DataType::DateTime => {
-                    means.push(series.mean().ok().and_then(|v| {
-                        if let Value::F64(val) = v {
-                            Some(val)
-                        } else {
-                            None
-                        }
-                    }));
-                    std_devs.push(series.std_dev().ok().and_then(|v| {
-                        if let Value::F64(val) = v {
-                            Some(val)
-                        } else {
-                            None
-                        }
-                    }));
-                    mins.push(series.min().ok());
-                    maxs.push(series.max().ok());
-                    medians.push(series.median().ok());
-                }
-                _ => {
-                    means.push(None);
-                    std_devs.push(None);
-                    mins.push(None);
-                    maxs.push(None);
-                    medians.push(None);
-                }
-            }
-        }
-
-        descriptions.insert(
-            "column".to_string(),
-            Series::new_string("column", column_names_vec.into_iter().map(Some).collect()),
-        );
-        descriptions.insert("count".to_string(), Series::new_i32("count", counts));
-        descriptions.insert("mean".to_string(), Series::new_f64("mean", means));
-        descriptions.insert("std".to_string(), Series::new_f64("std", std_devs));
-        descriptions.insert(
-            "min".to_string(),
-            Series::new_string(
-                "min",
-                mins.into_iter()
-                    .map(|x| x.map(|v| format!("{v:?}")))
-                    .collect(),
-            ),
-        );
-        descriptions.insert(
-            "max".to_string(),
-            Series::new_string(
-                "max",
-                maxs.into_iter()
-                    .map(|x| x.map(|v| format!("{v:?}")))
-                    .collect(),
-            ),
-        );
-        descriptions.insert(
-            "median".to_string(),
-            Series::new_string(
-                "median",
-                medians
-                    .into_iter()
-                    .map(|x| x.map(|v| format!("{v:?}")))
-                    .collect(),
-            ),
-        );
-
-        DataFrame::new(descriptions)
+    pub fn stats(&self) -> Vec<crate::series::statistics::ColumnStats> {
+        let mut names = self.column_names();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| self.columns[name].stats())
+            .collect()
     }
 
     /// Calculates the Pearson correlation coefficient between two columns in the `DataFrame`.
@@ -1324,3 +1727,145 @@ fn hashmap_groupby_direct(
 
     DataFrame::new(result)
 }
+
+/// Copies `source` into `target` on the rows set in `mask`
+fn assign_where(target: &mut Series, mask: &[bool], source: &Series) -> Result<(), VeloxxError> {
+    fn copy<T: Clone>(
+        values: &mut [T],
+        bitmap: &mut [bool],
+        source: &[T],
+        source_bitmap: &[bool],
+        mask: &[bool],
+    ) {
+        for row in (0..mask.len()).filter(|&row| mask[row]) {
+            values[row] = source[row].clone();
+            bitmap[row] = source_bitmap[row];
+        }
+    }
+    match (target, source) {
+        (Series::I32(_, values, bitmap), Series::I32(_, source, source_bitmap)) => {
+            copy(values, bitmap, source, source_bitmap, mask)
+        }
+        (Series::F64(_, values, bitmap), Series::F64(_, source, source_bitmap)) => {
+            copy(values, bitmap, source, source_bitmap, mask)
+        }
+        (Series::Bool(_, values, bitmap), Series::Bool(_, source, source_bitmap)) => {
+            copy(values, bitmap, source, source_bitmap, mask)
+        }
+        (Series::String(_, values, bitmap), Series::String(_, source, source_bitmap)) => {
+            copy(values, bitmap, source, source_bitmap, mask)
+        }
+        (Series::DateTime(_, values, bitmap), Series::DateTime(_, source, source_bitmap)) => {
+            copy(values, bitmap, source, source_bitmap, mask)
+        }
+        (Series::Binary(_, values, bitmap), Series::Binary(_, source, source_bitmap)) => {
+            copy(values, bitmap, source, source_bitmap, mask)
+        }
+        (target, source) => {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Cannot copy column '{}' of type {:?} into '{}' of type {:?}",
+                source.name(),
+                source.data_type(),
+                target.name(),
+                target.data_type()
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Builds a column from computed values to copy into `target`; values that are
+/// all null keep the target's type instead of defaulting to `String`
+fn values_like(target: &Series, values: Vec<Value>) -> Series {
+    if values.iter().any(|value| *value != Value::Null) {
+        return series_from_values(target.name(), values);
+    }
+    let mut nulls = target.clone();
+    match &mut nulls {
+        Series::I32(_, _, bitmap)
+        | Series::F64(_, _, bitmap)
+        | Series::Bool(_, _, bitmap)
+        | Series::String(_, _, bitmap)
+        | Series::DateTime(_, _, bitmap)
+        | Series::Binary(_, _, bitmap) => bitmap.fill(false),
+    }
+    nulls
+}
+
+/// Builds a column from computed values, typed after the first non-null value
+pub(crate) fn series_from_values(name: &str, values: Vec<Value>) -> Series {
+    let len = values.len();
+    let inferred_type = values
+        .iter()
+        .find(|value| **value != Value::Null)
+        .map(Value::data_type);
+    match inferred_type {
+        Some(DataType::I32) => Series::new_i32(
+            name,
+            values
+                .into_iter()
+                .map(|v| if let Value::I32(x) = v { Some(x) } else { None })
+                .collect(),
+        ),
+        Some(DataType::F64) => Series::new_f64(
+            name,
+            values
+                .into_iter()
+                .map(|v| if let Value::F64(x) = v { Some(x) } else { None })
+                .collect(),
+        ),
+        Some(DataType::Bool) => Series::new_bool(
+            name,
+            values
+                .into_iter()
+                .map(|v| {
+                    if let Value::Bool(x) = v {
+                        Some(x)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        ),
+        Some(DataType::String) => Series::new_string(
+            name,
+            values
+                .into_iter()
+                .map(|v| {
+                    if let Value::String(x) = v {
+                        Some(x)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        ),
+        Some(DataType::DateTime) => Series::new_datetime(
+            name,
+            values
+                .into_iter()
+                .map(|v| {
+                    if let Value::DateTime(x) = v {
+                        Some(x)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        ),
+        Some(DataType::Binary) => Series::new_binary(
+            name,
+            values
+                .into_iter()
+                .map(|v| {
+                    if let Value::Binary(x) = v {
+                        Some(x)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        ),
+        None => Series::new_string(name, vec![None; len]), // All nulls, default to String
+    }
+}
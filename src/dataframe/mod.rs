@@ -1,17 +1,60 @@
+use crate::dataframe::constraints::ColumnConstraint;
 use crate::lazy::LazyDataFrame;
+use crate::series::typed::{ChunkedIterator, ColumnType};
 use crate::series::Series;
 use crate::VeloxxError;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+pub mod binary;
+pub mod builder;
+pub mod checksum;
 pub mod cleaning;
+#[cfg(feature = "compression")]
+pub mod compressed;
+pub mod conditional_join;
+pub mod constraints;
+pub mod conversions;
+#[cfg(feature = "crypto")]
+pub mod crypto;
+pub mod describe;
 pub mod display;
+pub mod explode;
+pub mod geo;
 pub mod group_by;
-#[cfg(not(target_arch = "wasm32"))]
 pub mod io;
 pub mod join;
 pub mod manipulation;
+pub mod mutation;
+#[cfg(feature = "ndarray")]
+pub mod ndarray_interop;
+pub mod normalize;
+#[cfg(all(feature = "polars", not(target_arch = "wasm32")))]
+pub mod polars_interop;
+pub mod rows;
+pub mod selectors;
+pub mod shrink;
 pub mod sources;
 pub mod time_series;
+pub mod upsert;
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Makes every group-by order its groups by key, so aggregations return the
+/// same rows in the same order on every run, e.g. for snapshot tests or
+/// diffing outputs; see [`group_by::GroupedDataFrame::deterministic`] for the
+/// per-call switch and its cost.
+///
+/// Joins need no switch: their rows always follow the left frame (the right
+/// frame for right joins), whichever algorithm runs.
+pub fn set_deterministic(enabled: bool) {
+    DETERMINISTIC.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether group-bys order their groups by key by default.
+pub fn deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
 
 /// Represents a tabular data structure with named columns, similar to a data frame in other data manipulation libraries.
 ///
@@ -61,6 +104,8 @@ pub mod time_series;
 pub struct DataFrame {
     pub(crate) columns: HashMap<String, Series>,
     pub(crate) row_count: usize,
+    /// Column constraints carried as metadata; see [`constraints`]
+    pub(crate) constraints: Vec<ColumnConstraint>,
 }
 
 impl DataFrame {
@@ -79,6 +124,9 @@ impl DataFrame {
     /// or `Err(VeloxxError::InvalidOperation)` if there are inconsistent series lengths
     /// or name mismatches.
     ///
+    /// The new frame has no column constraints; declare them with
+    /// [`DataFrame::with_constraints`], which checks the rows straight away.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -98,6 +146,7 @@ impl DataFrame {
             return Ok(DataFrame {
                 columns,
                 row_count: 0,
+                constraints: Vec::new(),
             });
         }
 
@@ -119,7 +168,11 @@ impl DataFrame {
             }
         }
 
-        Ok(DataFrame { columns, row_count })
+        Ok(DataFrame {
+            columns,
+            row_count,
+            constraints: Vec::new(),
+        })
     }
 
     /// Returns the number of rows in the `DataFrame`.
@@ -225,6 +278,28 @@ impl DataFrame {
         self.columns.get(name)
     }
 
+    /// The values of column `name` as a slice of `T`, e.g. `df.column::<f64>("price")`.
+    ///
+    /// Null slots hold placeholders; see [`Series::values`]. A missing column or
+    /// one of another type is an error.
+    pub fn column<T: ColumnType>(&self, name: &str) -> Result<&[T], VeloxxError> {
+        self.typed_column(name)?.values()
+    }
+
+    /// Iterates over column `name` as `Option<&T>`, with `None` for nulls.
+    pub fn column_iter<T: ColumnType>(
+        &self,
+        name: &str,
+    ) -> Result<ChunkedIterator<'_, T>, VeloxxError> {
+        self.typed_column(name)?.iter_as()
+    }
+
+    fn typed_column(&self, name: &str) -> Result<&Series, VeloxxError> {
+        self.columns
+            .get(name)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+    }
+
     /// Converts this DataFrame to a LazyDataFrame for lazy evaluation
     ///
     /// # Returns
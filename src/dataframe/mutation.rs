@@ -0,0 +1,157 @@
+//! In-place edits of a `DataFrame`.
+//!
+//! Every other operation returns a new frame. These methods take `&mut self`
+//! instead, so the borrow checker guarantees no other reference observes the
+//! change. Each one validates its input, then re-checks the frame's
+//! [constraints](crate::dataframe::constraints) on what it changed, and leaves
+//! the frame untouched when either fails.
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+
+impl DataFrame {
+    /// Overwrites one cell; `Value::Null` clears it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let mut df = df!("id" => [1, 2, 3]).unwrap();
+    /// df.set_value(1, "id", Value::I32(20)).unwrap();
+    /// df.set_value(2, "id", Value::Null).unwrap();
+    /// assert_eq!(df.get_column("id").unwrap().get_value(1), Some(Value::I32(20)));
+    /// assert_eq!(df.get_column("id").unwrap().get_value(2), None);
+    /// assert!(df.set_value(0, "id", Value::F64(1.5)).is_err());
+    /// ```
+    pub fn set_value(&mut self, row: usize, column: &str, value: Value) -> Result<(), VeloxxError> {
+        let series = self
+            .columns
+            .get_mut(column)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+        let previous = series.get_value(row);
+        series.set_value(row, value)?;
+        if let Err(error) = self.validate_column_constraints(column) {
+            self.columns
+                .get_mut(column)
+                .expect("column checked above")
+                .set_value(row, previous.unwrap_or(Value::Null))?;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Replaces the column named like `series` and returns the old one.
+    ///
+    /// The new column may have a different type but must have as many rows as
+    /// the frame.
+    pub fn replace_column(&mut self, series: Series) -> Result<Series, VeloxxError> {
+        if !self.columns.contains_key(series.name()) {
+            return Err(VeloxxError::ColumnNotFound(series.name().to_string()));
+        }
+        self.check_length(&series)?;
+        let name = series.name().to_string();
+        let previous = self
+            .columns
+            .insert(name.clone(), series)
+            .expect("column checked above");
+        if let Err(error) = self.validate_column_constraints(&name) {
+            self.columns.insert(name, previous);
+            return Err(error);
+        }
+        Ok(previous)
+    }
+
+    /// Adds a new column.
+    ///
+    /// Columns are keyed by name and have no position, so the column is not
+    /// placed anywhere in particular; use [`DataFrame::replace_column`] to
+    /// overwrite an existing one. Constraints are only ever declared on
+    /// existing columns, so the frame's constraints are untouched and still
+    /// hold.
+    pub fn insert_column(&mut self, series: Series) -> Result<(), VeloxxError> {
+        if self.columns.contains_key(series.name()) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Column '{}' already exists",
+                series.name()
+            )));
+        }
+        // The first column of an empty frame sets its length
+        if self.columns.is_empty() {
+            self.row_count = series.len();
+        } else {
+            self.check_length(&series)?;
+        }
+        self.columns.insert(series.name().to_string(), series);
+        Ok(())
+    }
+
+    /// Appends the rows of `other` in place, without copying this frame's data.
+    ///
+    /// Both frames need the same column names and types, as for
+    /// [`DataFrame::append`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let mut df = df!("id" => [1, 2], "name" => ["a", "b"]).unwrap();
+    /// df.extend(&df!("id" => [3], "name" => ["c"]).unwrap()).unwrap();
+    /// assert_eq!(df.row_count(), 3);
+    /// assert!(df.extend(&df!("id" => [4]).unwrap()).is_err());
+    /// ```
+    pub fn extend(&mut self, other: &DataFrame) -> Result<(), VeloxxError> {
+        if self.columns.is_empty() {
+            *self = other.clone();
+            return Ok(());
+        }
+        if self.column_count() != other.column_count() {
+            return Err(VeloxxError::InvalidOperation(
+                "Cannot append DataFrames with different number of columns.".to_string(),
+            ));
+        }
+        for (name, series) in &self.columns {
+            let other_series = other.get_column(name).ok_or_else(|| {
+                VeloxxError::InvalidOperation(format!(
+                    "Column '{name}' is missing from the DataFrame being appended."
+                ))
+            })?;
+            if series.data_type() != other_series.data_type() {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot append column '{}' of type {:?} to type {:?}",
+                    name,
+                    other_series.data_type(),
+                    series.data_type()
+                )));
+            }
+        }
+        for (name, series) in self.columns.iter_mut() {
+            series.extend(&other.columns[name])?;
+        }
+        self.row_count += other.row_count;
+        if let Err(error) = self.validate_constraints() {
+            self.row_count -= other.row_count;
+            for series in self.columns.values_mut() {
+                series.truncate(self.row_count);
+            }
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn check_length(&self, series: &Series) -> Result<(), VeloxxError> {
+        if series.len() != self.row_count {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Column '{}' has {} rows but the DataFrame has {}",
+                series.name(),
+                series.len(),
+                self.row_count
+            )));
+        }
+        Ok(())
+    }
+}
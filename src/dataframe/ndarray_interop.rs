@@ -0,0 +1,126 @@
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+use ndarray::{Array2, ArrayView2};
+use std::collections::HashMap;
+
+/// Element types that can be exchanged between a [`DataFrame`] and an `ndarray` matrix.
+pub trait NdarrayElement: Copy + Default {
+    /// Converts a non-null cell value, returning `None` if the value has an incompatible type.
+    fn from_value(value: &Value) -> Option<Self>;
+
+    /// The element used for null cells, or `None` if the type cannot represent nulls.
+    fn null() -> Option<Self>;
+
+    /// Builds a series named `name` from a column of the matrix.
+    fn into_series(name: &str, values: Vec<Self>) -> Series;
+}
+
+impl NdarrayElement for f64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::F64(v) => Some(*v),
+            Value::I32(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    fn null() -> Option<Self> {
+        Some(f64::NAN)
+    }
+
+    fn into_series(name: &str, values: Vec<Self>) -> Series {
+        Series::new_f64(name, values.into_iter().map(Some).collect())
+    }
+}
+
+impl NdarrayElement for i32 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::I32(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn null() -> Option<Self> {
+        None
+    }
+
+    fn into_series(name: &str, values: Vec<Self>) -> Series {
+        Series::new_i32(name, values.into_iter().map(Some).collect())
+    }
+}
+
+impl DataFrame {
+    /// Copies the given columns into a row-major `(rows, columns)` matrix.
+    ///
+    /// For `f64` output, nulls become `NaN` and `I32` columns are widened. For `i32`
+    /// output, nulls and non-`I32` columns are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let df = df!("x" => [1.0, 2.0], "y" => [3, 4]).unwrap();
+    /// let matrix = df.to_ndarray::<f64>(&["x", "y"]).unwrap();
+    /// assert_eq!(matrix[[1, 1]], 4.0);
+    /// ```
+    pub fn to_ndarray<T: NdarrayElement>(
+        &self,
+        columns: &[&str],
+    ) -> Result<Array2<T>, VeloxxError> {
+        let mut matrix = Array2::<T>::default((self.row_count, columns.len()));
+        for (j, &name) in columns.iter().enumerate() {
+            let series = self
+                .get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))?;
+            for i in 0..self.row_count {
+                matrix[[i, j]] = match series.get_value(i) {
+                    Some(value) => T::from_value(&value).ok_or_else(|| {
+                        VeloxxError::DataTypeMismatch(format!(
+                            "Column '{}' of type {:?} cannot be converted to the requested element type",
+                            name,
+                            series.data_type()
+                        ))
+                    })?,
+                    None => T::null().ok_or_else(|| {
+                        VeloxxError::InvalidOperation(format!(
+                            "Column '{}' contains nulls at row {}, which the requested element type cannot represent",
+                            name, i
+                        ))
+                    })?,
+                };
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Builds a `DataFrame` from a `(rows, columns)` matrix, naming columns in order.
+    ///
+    /// Values are copied as-is, so `NaN` stays a value rather than becoming a null.
+    pub fn from_ndarray<T: NdarrayElement>(
+        array: ArrayView2<T>,
+        column_names: &[&str],
+    ) -> Result<Self, VeloxxError> {
+        if array.ncols() != column_names.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Array has {} columns but {} column names were given",
+                array.ncols(),
+                column_names.len()
+            )));
+        }
+        let mut columns = HashMap::with_capacity(column_names.len());
+        for (column, &name) in array.columns().into_iter().zip(column_names) {
+            if columns.contains_key(name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Duplicate column name '{}'",
+                    name
+                )));
+            }
+            columns.insert(name.to_string(), T::into_series(name, column.to_vec()));
+        }
+        DataFrame::new(columns)
+    }
+}
@@ -0,0 +1,166 @@
+//! Rescaling numeric columns: z-scores, min-max scaling and percent of total,
+//! over the whole frame or within groups.
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// How [`DataFrame::normalize`] rescales a column.
+///
+/// Nulls are ignored when computing the statistics and stay null. Values
+/// whose result is undefined, such as z-scores of a constant column, are null.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizeMethod {
+    /// `(x - mean) / std_dev`, using the sample standard deviation as
+    /// [`Series::std_dev`] does; needs at least two distinct values
+    ZScore,
+    /// `(x - min) / (max - min)`, mapping values onto `[0, 1]`
+    MinMax,
+    /// `100 * x / sum`, each value's share of the total in percent
+    PercentOfTotal,
+}
+
+impl NormalizeMethod {
+    /// Rescales `values` in place using statistics of their non-null entries
+    fn apply(self, values: &mut [Option<f64>]) {
+        let present: Vec<f64> = values.iter().flatten().copied().collect();
+        let n = present.len() as f64;
+        let sum: f64 = present.iter().sum();
+        let rescale: Box<dyn Fn(f64) -> f64> = match self {
+            NormalizeMethod::ZScore => {
+                let mean = sum / n;
+                let variance = present.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+                Box::new(move |v| (v - mean) / variance.sqrt())
+            }
+            NormalizeMethod::MinMax => {
+                let min = present.iter().copied().fold(f64::INFINITY, f64::min);
+                let max = present.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                Box::new(move |v| (v - min) / (max - min))
+            }
+            NormalizeMethod::PercentOfTotal => Box::new(move |v| 100.0 * v / sum),
+        };
+        for value in values.iter_mut() {
+            *value = value.map(&rescale).filter(|v| v.is_finite());
+        }
+    }
+}
+
+impl DataFrame {
+    /// Replaces each of `columns` with an `F64` column rescaled by `method`
+    /// over the whole frame; other columns are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::normalize::NormalizeMethod;
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let sales = df!("region" => ["north", "south", "east"], "revenue" => [50, 30, 20]).unwrap();
+    /// let shares = sales.normalize(&["revenue"], NormalizeMethod::PercentOfTotal).unwrap();
+    /// assert_eq!(shares.get_column("revenue").unwrap().get_value(0), Some(Value::F64(50.0)));
+    /// ```
+    pub fn normalize(
+        &self,
+        columns: &[&str],
+        method: NormalizeMethod,
+    ) -> Result<Self, VeloxxError> {
+        let all_rows: Vec<usize> = (0..self.row_count).collect();
+        self.normalize_groups(columns, method, &[all_rows])
+    }
+
+    /// Like [`DataFrame::normalize`], with the statistics computed separately
+    /// within each group of rows sharing the same `keys` values, e.g. each
+    /// product's share of its region's revenue. Null keys form their own group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::normalize::NormalizeMethod;
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let sales = df!(
+    ///     "region" => ["north", "north", "south"],
+    ///     "revenue" => [30.0, 10.0, 40.0],
+    /// )
+    /// .unwrap();
+    /// let shares = sales
+    ///     .normalize_over(&["revenue"], NormalizeMethod::PercentOfTotal, &["region"])
+    ///     .unwrap();
+    /// let revenue = shares.get_column("revenue").unwrap();
+    /// assert_eq!(revenue.get_value(0), Some(Value::F64(75.0)));
+    /// assert_eq!(revenue.get_value(2), Some(Value::F64(100.0)));
+    /// ```
+    pub fn normalize_over(
+        &self,
+        columns: &[&str],
+        method: NormalizeMethod,
+        keys: &[&str],
+    ) -> Result<Self, VeloxxError> {
+        let key_columns = keys
+            .iter()
+            .map(|&key| {
+                self.get_column(key)
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(key.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut group_of: HashMap<Vec<Option<Value>>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for row in 0..self.row_count {
+            let key = key_columns.iter().map(|s| s.get_value(row)).collect();
+            let next = groups.len();
+            let group = *group_of.entry(key).or_insert(next);
+            if group == next {
+                groups.push(Vec::new());
+            }
+            groups[group].push(row);
+        }
+        self.normalize_groups(columns, method, &groups)
+    }
+
+    fn normalize_groups(
+        &self,
+        columns: &[&str],
+        method: NormalizeMethod,
+        groups: &[Vec<usize>],
+    ) -> Result<Self, VeloxxError> {
+        let mut new_columns = self.columns.clone();
+        for &column in columns {
+            let series = self
+                .get_column(column)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+            let values: Vec<Option<f64>> = match series {
+                Series::I32(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .map(|(&v, &valid)| valid.then_some(v as f64))
+                    .collect(),
+                Series::F64(_, data, validity) => data
+                    .iter()
+                    .zip(validity)
+                    .map(|(&v, &valid)| valid.then_some(v))
+                    .collect(),
+                _ => {
+                    return Err(VeloxxError::DataTypeMismatch(format!(
+                        "Cannot normalize non-numeric column '{}' of type {:?}",
+                        column,
+                        series.data_type()
+                    )))
+                }
+            };
+            let mut normalized = vec![None; values.len()];
+            for rows in groups {
+                let mut group: Vec<Option<f64>> = rows.iter().map(|&row| values[row]).collect();
+                method.apply(&mut group);
+                for (&row, value) in rows.iter().zip(group) {
+                    normalized[row] = value;
+                }
+            }
+            new_columns.insert(column.to_string(), Series::new_f64(column, normalized));
+        }
+        DataFrame::new(new_columns)
+    }
+}
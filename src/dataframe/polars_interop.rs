@@ -0,0 +1,89 @@
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::VeloxxError;
+use arrow::array::{make_array, Array, ArrayRef};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
+use arrow::ffi::{from_ffi, to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use polars::export::arrow as pl_arrow;
+use polars::prelude::{DataFrame as PolarsDataFrame, Series as PolarsSeries};
+use std::collections::HashMap;
+
+// Polars ships its own Arrow implementation, so arrays are handed across through
+// the Arrow C data interface. Both sides define the C structs with identical
+// `#[repr(C)]` layouts, which is what makes the pointer casts below sound.
+
+fn polars_err(err: polars::prelude::PolarsError) -> VeloxxError {
+    VeloxxError::ExecutionError(format!("Polars error: {}", err))
+}
+
+/// Moves an arrow-rs array into a Polars series without copying its buffers.
+fn arrow_to_polars(name: &str, array: ArrayRef) -> Result<PolarsSeries, VeloxxError> {
+    let (ffi_array, ffi_schema) = to_ffi(&array.to_data())?;
+    // SAFETY: `FFI_ArrowArray`/`FFI_ArrowSchema` and the Polars `ArrowArray`/`ArrowSchema`
+    // are both the C data interface structs. Ownership of the array (and its release
+    // callback) moves to Polars; the schema is only borrowed and released by arrow-rs.
+    let pl_array = unsafe {
+        let schema = &*(&ffi_schema as *const FFI_ArrowSchema as *const pl_arrow::ffi::ArrowSchema);
+        let field = pl_arrow::ffi::import_field_from_c(schema).map_err(polars_err)?;
+        let array = std::mem::transmute::<FFI_ArrowArray, pl_arrow::ffi::ArrowArray>(ffi_array);
+        pl_arrow::ffi::import_array_from_c(array, field.data_type).map_err(polars_err)?
+    };
+    PolarsSeries::from_arrow(name, pl_array).map_err(polars_err)
+}
+
+/// Moves a single-chunk Polars series into an arrow-rs array without copying its buffers.
+fn polars_to_arrow(series: &PolarsSeries) -> Result<ArrayRef, VeloxxError> {
+    let series = series.rechunk();
+    let pl_array = series.to_arrow(0, false);
+    let field = pl_arrow::datatypes::Field::new(series.name(), pl_array.data_type().clone(), true);
+    let pl_schema = pl_arrow::ffi::export_field_to_c(&field);
+    let pl_array = pl_arrow::ffi::export_array_to_c(pl_array);
+    // SAFETY: see `arrow_to_polars`; here ownership of the array moves to arrow-rs.
+    let data = unsafe {
+        let schema = &*(&pl_schema as *const pl_arrow::ffi::ArrowSchema as *const FFI_ArrowSchema);
+        let array = std::mem::transmute::<pl_arrow::ffi::ArrowArray, FFI_ArrowArray>(pl_array);
+        from_ffi(array, schema)?
+    };
+    Ok(make_array(data))
+}
+
+impl DataFrame {
+    /// Converts a Polars `DataFrame` into a Veloxx `DataFrame`.
+    ///
+    /// Columns are transferred through the Arrow C data interface and then converted
+    /// with [`Series::from_arrow_array`].
+    pub fn from_polars(df: &PolarsDataFrame) -> Result<Self, VeloxxError> {
+        let mut columns = HashMap::with_capacity(df.width());
+        for series in df.get_columns() {
+            let name = series.name().to_string();
+            let array = polars_to_arrow(series)?;
+            columns.insert(name.clone(), Series::from_arrow_array(array, name)?);
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Converts this `DataFrame` into a Polars `DataFrame`.
+    ///
+    /// Columns are emitted in sorted name order since Veloxx does not track column order.
+    /// `DateTime` columns become millisecond `Datetime` columns, the coarsest unit
+    /// Polars has.
+    pub fn to_polars(&self) -> Result<PolarsDataFrame, VeloxxError> {
+        let mut names: Vec<&String> = self.columns.keys().collect();
+        names.sort();
+        let series = names
+            .into_iter()
+            .map(|name| {
+                let mut array = self.columns[name].to_arrow_array();
+                if let ArrowDataType::Timestamp(TimeUnit::Second, _) = array.data_type() {
+                    array = cast(
+                        &array,
+                        &ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
+                    )?;
+                }
+                arrow_to_polars(name, array)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        PolarsDataFrame::new(series).map_err(polars_err)
+    }
+}
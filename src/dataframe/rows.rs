@@ -0,0 +1,107 @@
+//! Row-wise iteration over a [`DataFrame`] without copying it.
+//!
+//! [`DataFrame::iter_rows`] yields a [`Row`] per row: a frame reference and a
+//! row index, so values are only read when asked for, unlike
+//! [`DataFrame::to_vec_of_vec`] which builds every row up front.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use veloxx::df;
+//!
+//! let df = df!("id" => [1, 2, 3], "price" => [Some(9.5), None, Some(2.0)]).unwrap();
+//! let priced: Vec<i32> = df
+//!     .iter_rows()
+//!     .filter(|row| row.get("price").is_some())
+//!     .map(|row| *row.get_as::<i32>("id").unwrap().unwrap())
+//!     .collect();
+//! assert_eq!(priced, vec![1, 3]);
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::series::typed::ColumnType;
+use crate::types::Value;
+use crate::VeloxxError;
+use std::ops::Range;
+
+/// A view of one row of a [`DataFrame`], from [`DataFrame::iter_rows`]
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    df: &'a DataFrame,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    /// The position of this row in the frame
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The value of column `name`, `None` if it is null or there is no such column.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.df.get_column(name)?.get_value(self.index)
+    }
+
+    /// The value of column `name` as `Option<&T>`, with `None` for null; a
+    /// missing column or one of another type is an error, as for
+    /// [`DataFrame::column`].
+    pub fn get_as<T: ColumnType>(&self, name: &str) -> Result<Option<&'a T>, VeloxxError> {
+        let series = self.df.typed_column(name)?;
+        let values = series.values::<T>()?;
+        Ok(series.validity()[self.index].then(|| &values[self.index]))
+    }
+
+    /// The values of the row with their column names, in name order.
+    pub fn to_vec(&self) -> Vec<(&'a str, Option<Value>)> {
+        let mut names = self.df.column_names();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| (name.as_str(), self.df.columns[name].get_value(self.index)))
+            .collect()
+    }
+}
+
+/// Iterator over the rows of a [`DataFrame`], from [`DataFrame::iter_rows`]
+#[derive(Debug, Clone)]
+pub struct Rows<'a> {
+    df: &'a DataFrame,
+    range: Range<usize>,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.range.next()?;
+        Some(Row { df: self.df, index })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.range.nth(n)?;
+        Some(Row { df: self.df, index })
+    }
+}
+
+impl DoubleEndedIterator for Rows<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.range.next_back()?;
+        Some(Row { df: self.df, index })
+    }
+}
+
+impl ExactSizeIterator for Rows<'_> {}
+
+impl DataFrame {
+    /// Iterates over the rows as lightweight [`Row`] views.
+    pub fn iter_rows(&self) -> Rows<'_> {
+        Rows {
+            df: self,
+            range: 0..self.row_count,
+        }
+    }
+}
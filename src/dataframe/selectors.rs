@@ -0,0 +1,116 @@
+//! Choosing columns by pattern or type instead of listing names, for wide
+//! frames such as telemetry with hundreds of `sensor_*` columns.
+
+use crate::dataframe::DataFrame;
+use crate::types::DataType;
+use crate::VeloxxError;
+use regex::Regex;
+
+/// A rule that picks columns of a [`DataFrame`].
+///
+/// [`ColumnSelector::resolve`] turns a selector into sorted column names, so
+/// it can feed anything that takes names: [`DataFrame::select_columns`],
+/// [`DataFrame::drop_columns`] or aggregation specs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnSelector {
+    /// Columns whose name matches a regular expression anywhere; anchor the
+    /// pattern with `^` and `$` to match whole names
+    Matching(String),
+    /// Columns whose name starts with the given text
+    Prefix(String),
+    /// Columns whose name ends with the given text
+    Suffix(String),
+    /// Columns of any of the given types
+    DataTypes(Vec<DataType>),
+}
+
+impl ColumnSelector {
+    /// The names of the columns of `df` this selector picks, sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::selectors::ColumnSelector;
+    /// use veloxx::df;
+    ///
+    /// let readings = df!(
+    ///     "device" => ["a", "a", "b"],
+    ///     "sensor_temp" => [20.5, 21.0, 19.5],
+    ///     "sensor_rpm" => [900.0, 950.0, 870.0],
+    /// )
+    /// .unwrap();
+    /// let sensors = ColumnSelector::Prefix("sensor_".to_string()).resolve(&readings).unwrap();
+    /// assert_eq!(sensors, vec!["sensor_rpm", "sensor_temp"]);
+    ///
+    /// let aggregations: Vec<(&str, &str)> = sensors.iter().map(|c| (c.as_str(), "mean")).collect();
+    /// let means = readings.group_by(vec!["device".to_string()]).unwrap().agg(aggregations).unwrap();
+    /// assert_eq!(means.row_count(), 2);
+    /// ```
+    pub fn resolve(&self, df: &DataFrame) -> Result<Vec<String>, VeloxxError> {
+        let keep: Box<dyn Fn(&str) -> bool> = match self {
+            ColumnSelector::Matching(pattern) => {
+                let regex = Regex::new(pattern).map_err(|e| {
+                    VeloxxError::InvalidOperation(format!("Invalid regex pattern: {e}"))
+                })?;
+                Box::new(move |name| regex.is_match(name))
+            }
+            ColumnSelector::Prefix(prefix) => {
+                Box::new(move |name| name.starts_with(prefix.as_str()))
+            }
+            ColumnSelector::Suffix(suffix) => Box::new(move |name| name.ends_with(suffix.as_str())),
+            ColumnSelector::DataTypes(types) => {
+                Box::new(move |name| types.contains(&df.columns[name].data_type()))
+            }
+        };
+        let mut names: Vec<String> = df
+            .columns
+            .keys()
+            .filter(|name| keep(name))
+            .cloned()
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+}
+
+impl DataFrame {
+    /// Keeps only the columns picked by `selector`.
+    pub fn select_by(&self, selector: &ColumnSelector) -> Result<Self, VeloxxError> {
+        self.select_columns(selector.resolve(self)?)
+    }
+
+    /// Removes the columns picked by `selector`.
+    pub fn drop_by(&self, selector: &ColumnSelector) -> Result<Self, VeloxxError> {
+        self.drop_columns(selector.resolve(self)?)
+    }
+
+    /// Keeps only the columns of the given types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::DataType;
+    ///
+    /// let df = df!("id" => [1, 2], "score" => [0.5, 0.7], "label" => ["x", "y"]).unwrap();
+    /// let numeric = df.select_dtypes(&[DataType::I32, DataType::F64]).unwrap();
+    /// assert_eq!(numeric.column_count(), 2);
+    /// ```
+    pub fn select_dtypes(&self, types: &[DataType]) -> Result<Self, VeloxxError> {
+        self.select_by(&ColumnSelector::DataTypes(types.to_vec()))
+    }
+
+    /// Keeps only the columns whose name matches the regular expression `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let df = df!("sensor_a" => [1.0], "sensor_b" => [2.0], "site" => ["x"]).unwrap();
+    /// assert_eq!(df.select_matching("^sensor_").unwrap().column_count(), 2);
+    /// ```
+    pub fn select_matching(&self, pattern: &str) -> Result<Self, VeloxxError> {
+        self.select_by(&ColumnSelector::Matching(pattern.to_string()))
+    }
+}
@@ -0,0 +1,148 @@
+//! Downcasting columns to smaller types when their values allow it.
+
+use crate::dataframe::DataFrame;
+use crate::performance::memory::MemoryAnalyzer;
+use crate::series::Series;
+use crate::types::DataType;
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// Options for [`DataFrame::shrink_dtypes_with_options`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ShrinkOptions {
+    /// Largest distance from a whole number at which an `F64` value is still
+    /// rounded into an `I32` column; `0.0` only converts exact integers
+    pub float_tolerance: f64,
+}
+
+/// A column whose type was changed by shrinking
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShrunkColumn {
+    pub name: String,
+    pub from: DataType,
+    pub to: DataType,
+    /// Estimated size before shrinking, in bytes
+    pub bytes_before: usize,
+    /// Estimated size after shrinking, in bytes
+    pub bytes_after: usize,
+}
+
+/// What [`DataFrame::shrink_dtypes`] changed and how much memory it saved
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ShrinkReport {
+    /// Changed columns, sorted by name
+    pub columns: Vec<ShrunkColumn>,
+    /// Estimated size of the whole frame before shrinking, in bytes
+    pub bytes_before: usize,
+    /// Estimated size of the whole frame after shrinking, in bytes
+    pub bytes_after: usize,
+}
+
+impl ShrinkReport {
+    /// Estimated number of bytes saved
+    pub fn bytes_saved(&self) -> usize {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
+
+impl DataFrame {
+    /// Downcasts columns whose values fit a smaller type, with default
+    /// [`ShrinkOptions`]: `F64` columns holding only whole numbers in the `i32`
+    /// range become `I32`.
+    ///
+    /// `Series` has no 32-bit float or categorical type, so other float
+    /// columns and string columns are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::DataType;
+    ///
+    /// let df = df!("count" => [1.0, 2.0, 3.0], "ratio" => [0.5, 0.25, 1.0]).unwrap();
+    /// let (shrunk, report) = df.shrink_dtypes();
+    /// assert_eq!(shrunk.get_column("count").unwrap().data_type(), DataType::I32);
+    /// assert_eq!(shrunk.get_column("ratio").unwrap().data_type(), DataType::F64);
+    /// assert!(report.bytes_saved() > 0);
+    /// ```
+    pub fn shrink_dtypes(&self) -> (Self, ShrinkReport) {
+        self.shrink(0.0)
+    }
+
+    /// Like [`DataFrame::shrink_dtypes`], rounding float values that are within
+    /// `options.float_tolerance` of a whole number.
+    pub fn shrink_dtypes_with_options(
+        &self,
+        options: &ShrinkOptions,
+    ) -> Result<(Self, ShrinkReport), VeloxxError> {
+        if !(options.float_tolerance >= 0.0 && options.float_tolerance < 0.5) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Float tolerance must be in [0, 0.5), got {}",
+                options.float_tolerance
+            )));
+        }
+        Ok(self.shrink(options.float_tolerance))
+    }
+
+    fn shrink(&self, float_tolerance: f64) -> (Self, ShrinkReport) {
+        let mut report = ShrinkReport::default();
+        let mut columns = HashMap::with_capacity(self.columns.len());
+        for (name, series) in &self.columns {
+            let bytes_before = MemoryAnalyzer::estimate_series_memory(series);
+            let shrunk = match series {
+                Series::F64(name, values, validity) => {
+                    floats_to_i32(name, values, validity, float_tolerance)
+                }
+                _ => None,
+            };
+            let shrunk = match shrunk {
+                Some(shrunk) => {
+                    let bytes_after = MemoryAnalyzer::estimate_series_memory(&shrunk);
+                    report.columns.push(ShrunkColumn {
+                        name: name.clone(),
+                        from: series.data_type(),
+                        to: shrunk.data_type(),
+                        bytes_before,
+                        bytes_after,
+                    });
+                    shrunk
+                }
+                None => series.clone(),
+            };
+            report.bytes_before += bytes_before;
+            report.bytes_after += MemoryAnalyzer::estimate_series_memory(&shrunk);
+            columns.insert(name.clone(), shrunk);
+        }
+        report.columns.sort_by(|a, b| a.name.cmp(&b.name));
+        let df = DataFrame {
+            columns,
+            row_count: self.row_count,
+            constraints: Vec::new(),
+        };
+        // Rounding within a tolerance can make distinct values equal
+        (
+            df.carry_constraints(&self.constraints, float_tolerance == 0.0),
+            report,
+        )
+    }
+}
+
+/// Rounds a float column into `I32` when every value is close enough to a
+/// whole number in range; nulls stay null
+fn floats_to_i32(name: &str, values: &[f64], validity: &[bool], tolerance: f64) -> Option<Series> {
+    let fits = values.iter().zip(validity).all(|(&v, &valid)| {
+        !valid
+            || ((v - v.round()).abs() <= tolerance
+                && v.round() >= i32::MIN as f64
+                && v.round() <= i32::MAX as f64)
+    });
+    if !fits {
+        return None;
+    }
+    let data = values
+        .iter()
+        .zip(validity)
+        .map(|(&v, &valid)| if valid { v.round() as i32 } else { 0 })
+        .collect();
+    Some(Series::I32(name.to_string(), data, validity.to_vec()))
+}
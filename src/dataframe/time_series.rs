@@ -1,11 +1,7 @@
 use crate::dataframe::DataFrame;
-use crate::VeloxxError;
-
-#[cfg(test)]
 use crate::series::Series;
-#[cfg(test)]
 use crate::types::Value;
-#[cfg(test)]
+use crate::VeloxxError;
 use std::collections::HashMap;
 
 impl DataFrame {
@@ -183,6 +179,50 @@ impl DataFrame {
         DataFrame::new(new_columns)
     }
 
+    /// Adds the rolling correlation of every pair of `columns`, named
+    /// `{a}_{b}_rolling_corr_{window_size}` for each `a` listed before `b`; see
+    /// [`Series::rolling_corr`] for null handling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let returns = df!(
+    ///     "aapl" => [0.01, 0.02, -0.01, 0.03],
+    ///     "msft" => [0.02, 0.01, -0.02, 0.02],
+    ///     "spy" => [0.01, 0.01, -0.01, 0.02],
+    /// )
+    /// .unwrap();
+    /// let result = returns.pairwise_rolling_corr(&["aapl", "msft", "spy"], 3).unwrap();
+    /// assert!(result.get_column("aapl_msft_rolling_corr_3").is_some());
+    /// assert!(result.get_column("msft_spy_rolling_corr_3").is_some());
+    /// assert_eq!(result.column_count(), 6);
+    /// ```
+    pub fn pairwise_rolling_corr(
+        &self,
+        columns: &[&str],
+        window_size: usize,
+    ) -> Result<DataFrame, VeloxxError> {
+        let series = columns
+            .iter()
+            .map(|name| {
+                self.get_column(name)
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut new_columns = self.columns.clone();
+        for (i, left) in series.iter().enumerate() {
+            for right in &series[i + 1..] {
+                let corr = left.rolling_corr(right, window_size)?;
+                new_columns.insert(corr.name().to_string(), corr);
+            }
+        }
+
+        DataFrame::new(new_columns)
+    }
+
     /// Calculates percentage change between consecutive values for specified numeric columns.
     ///
     /// This method creates new columns with percentage change calculations.
@@ -250,6 +290,414 @@ impl DataFrame {
 
         DataFrame::new(new_columns)
     }
+
+    /// Running sum of each of `columns`, added as `{column}_cum_sum`; see
+    /// [`DataFrame::cumulative`] for ordering and partitions.
+    ///
+    /// Unlike [`DataFrame::cumsum`], nulls are skipped and stay null.
+    pub fn cum_sum(&self, columns: &[&str]) -> Result<DataFrame, VeloxxError> {
+        self.cumulative(columns, CumulativeOp::Sum, &CumulativeOptions::default())
+    }
+
+    /// Running maximum of each of `columns`, added as `{column}_cum_max`
+    pub fn cum_max(&self, columns: &[&str]) -> Result<DataFrame, VeloxxError> {
+        self.cumulative(columns, CumulativeOp::Max, &CumulativeOptions::default())
+    }
+
+    /// Running minimum of each of `columns`, added as `{column}_cum_min`
+    pub fn cum_min(&self, columns: &[&str]) -> Result<DataFrame, VeloxxError> {
+        self.cumulative(columns, CumulativeOp::Min, &CumulativeOptions::default())
+    }
+
+    /// Running product of each of `columns`, added as `{column}_cum_prod`
+    pub fn cum_prod(&self, columns: &[&str]) -> Result<DataFrame, VeloxxError> {
+        self.cumulative(columns, CumulativeOp::Prod, &CumulativeOptions::default())
+    }
+
+    /// Adds a running aggregate of each numeric column in `columns`, named
+    /// `{column}_cum_{sum,max,min,prod}` and of the column's type.
+    ///
+    /// Rows are accumulated in `options.order_by` order (ascending, nulls
+    /// first, ties in frame order) or frame order, restarting for each
+    /// distinct combination of `options.partition_by` values. The result keeps
+    /// the frame's row order. Null values are skipped and stay null; an `I32`
+    /// result that overflows is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::dataframe::time_series::{CumulativeOp, CumulativeOptions};
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let sales = df!(
+    ///     "store" => ["a", "b", "a", "a"],
+    ///     "day" => [3, 1, 1, 2],
+    ///     "units" => [5, 7, 2, 4],
+    /// )
+    /// .unwrap();
+    /// let options = CumulativeOptions {
+    ///     order_by: Some("day".to_string()),
+    ///     partition_by: vec!["store".to_string()],
+    /// };
+    /// let running = sales.cumulative(&["units"], CumulativeOp::Sum, &options).unwrap();
+    /// let totals = running.get_column("units_cum_sum").unwrap();
+    /// // Store "a" on days 1, 2 and 3 sums 2, then 2 + 4, then 2 + 4 + 5
+    /// assert_eq!(totals.get_value(0), Some(Value::I32(11)));
+    /// assert_eq!(totals.get_value(3), Some(Value::I32(6)));
+    /// ```
+    pub fn cumulative(
+        &self,
+        columns: &[&str],
+        op: CumulativeOp,
+        options: &CumulativeOptions,
+    ) -> Result<DataFrame, VeloxxError> {
+        let column = |name: &str| {
+            self.get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+        };
+        let mut order: Vec<usize> = (0..self.row_count).collect();
+        if let Some(order_by) = &options.order_by {
+            let keys = column(order_by)?;
+            order.sort_by_cached_key(|&row| keys.get_value(row));
+        }
+        let segments = if options.partition_by.is_empty() {
+            vec![order]
+        } else {
+            let keys = options
+                .partition_by
+                .iter()
+                .map(|name| column(name))
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut segment_of: HashMap<Vec<Option<Value>>, usize> = HashMap::new();
+            let mut segments: Vec<Vec<usize>> = Vec::new();
+            for row in order {
+                let key = keys.iter().map(|series| series.get_value(row)).collect();
+                let next = segments.len();
+                let segment = *segment_of.entry(key).or_insert(next);
+                if segment == next {
+                    segments.push(Vec::new());
+                }
+                segments[segment].push(row);
+            }
+            segments
+        };
+
+        let mut new_columns = self.columns.clone();
+        for &name in columns {
+            let output = format!("{}_cum_{}", name, op.suffix());
+            let overflow =
+                || VeloxxError::InvalidOperation(format!("Integer overflow computing '{output}'"));
+            let result = match column(name)? {
+                Series::I32(_, data, validity) => {
+                    let step: fn(i32, i32) -> Option<i32> = match op {
+                        CumulativeOp::Sum => i32::checked_add,
+                        CumulativeOp::Prod => i32::checked_mul,
+                        CumulativeOp::Max => |a, b| Some(a.max(b)),
+                        CumulativeOp::Min => |a, b| Some(a.min(b)),
+                    };
+                    let values = running(data, validity, &segments, step).ok_or_else(overflow)?;
+                    Series::I32(output.clone(), values, validity.clone())
+                }
+                Series::F64(_, data, validity) => {
+                    let step: fn(f64, f64) -> Option<f64> = match op {
+                        CumulativeOp::Sum => |a, b| Some(a + b),
+                        CumulativeOp::Prod => |a, b| Some(a * b),
+                        CumulativeOp::Max => |a, b| Some(a.max(b)),
+                        CumulativeOp::Min => |a, b| Some(a.min(b)),
+                    };
+                    let values = running(data, validity, &segments, step).ok_or_else(overflow)?;
+                    Series::F64(output.clone(), values, validity.clone())
+                }
+                other => {
+                    return Err(VeloxxError::DataTypeMismatch(format!(
+                        "Cumulative {} requires a numeric column, '{}' is {:?}",
+                        op.suffix(),
+                        name,
+                        other.data_type()
+                    )))
+                }
+            };
+            new_columns.insert(output, result);
+        }
+        DataFrame::new(new_columns)
+    }
+}
+
+/// Running aggregate computed by [`DataFrame::cumulative`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CumulativeOp {
+    Sum,
+    Max,
+    Min,
+    Prod,
+}
+
+impl CumulativeOp {
+    fn suffix(self) -> &'static str {
+        match self {
+            CumulativeOp::Sum => "sum",
+            CumulativeOp::Max => "max",
+            CumulativeOp::Min => "min",
+            CumulativeOp::Prod => "prod",
+        }
+    }
+}
+
+/// Row order and partitions for [`DataFrame::cumulative`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CumulativeOptions {
+    /// Column whose ascending order the running aggregate follows; frame order if `None`
+    pub order_by: Option<String>,
+    /// Columns whose distinct value combinations each get their own running aggregate
+    pub partition_by: Vec<String>,
+}
+
+/// Folds `step` over the valid values of each segment of row indices, writing
+/// each running value back at its row; `None` if `step` fails
+fn running<T: Copy>(
+    data: &[T],
+    validity: &[bool],
+    segments: &[Vec<usize>],
+    step: impl Fn(T, T) -> Option<T>,
+) -> Option<Vec<T>> {
+    let mut out = data.to_vec();
+    for rows in segments {
+        let mut acc: Option<T> = None;
+        for &row in rows.iter().filter(|&&row| validity[row]) {
+            let next = match acc {
+                Some(acc) => step(acc, data[row])?,
+                None => data[row],
+            };
+            out[row] = next;
+            acc = Some(next);
+        }
+    }
+    Some(out)
+}
+
+/// Daily trading hours that [`ohlc_with`] keeps ticks from and aligns bars to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradingSession {
+    /// Session open, in seconds after local midnight
+    pub open: i64,
+    /// Session close, in seconds after local midnight; ticks at or after it are dropped
+    pub close: i64,
+    /// Seconds added to UTC timestamps to get the exchange's local time
+    pub utc_offset: i64,
+    /// Whether Saturday and Sunday ticks are dropped
+    pub weekdays_only: bool,
+}
+
+impl TradingSession {
+    /// A weekday session from `open` to `close`, in seconds after midnight UTC
+    pub fn new(open: i64, close: i64) -> Self {
+        TradingSession {
+            open,
+            close,
+            utc_offset: 0,
+            weekdays_only: true,
+        }
+    }
+
+    /// Start of the `every`-second bar holding `time`, counted from the
+    /// session open; `None` outside the session
+    fn bucket(&self, time: i64, every: i64) -> Option<i64> {
+        let local = time + self.utc_offset;
+        let day = local.div_euclid(86_400);
+        let since_open = local.rem_euclid(86_400) - self.open;
+        // 1970-01-01 was a Thursday, so this is 0 for Sunday and 6 for Saturday
+        let weekday = (day + 4).rem_euclid(7);
+        if since_open < 0
+            || since_open >= self.close - self.open
+            || (self.weekdays_only && (weekday == 0 || weekday == 6))
+        {
+            return None;
+        }
+        Some(day * 86_400 + self.open + since_open / every * every - self.utc_offset)
+    }
+}
+
+/// Volume source and session for [`ohlc_with`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OhlcOptions {
+    /// Numeric column summed into each bar's `volume`; ticks are counted if `None`
+    pub volume_col: Option<String>,
+    /// Trading hours to keep ticks from; every tick is kept, in bars aligned to
+    /// the Unix epoch as by [`Expr::dt_truncate`](crate::expressions::Expr::dt_truncate), if `None`
+    pub session: Option<TradingSession>,
+}
+
+/// Open/high/low/close bars of `price_col` over `every`-long buckets of the
+/// `DateTime` column `time_col`, with the number of ticks as `volume`; see
+/// [`ohlc_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::dataframe::time_series::ohlc;
+/// use veloxx::dataframe::DataFrame;
+/// use veloxx::types::Value;
+///
+/// let ticks = DataFrame::builder()
+///     .col_datetime("ts", [0, 30, 10, 50, 70])
+///     .col_f64("price", [10.0, 12.0, 9.0, 11.0, 20.0])
+///     .build()
+///     .unwrap();
+/// let bars = ohlc(&ticks, "ts", "price", "1m").unwrap();
+/// assert_eq!(bars.row_count(), 2);
+/// assert_eq!(bars.get_column("open").unwrap().get_value(0), Some(Value::F64(10.0)));
+/// assert_eq!(bars.get_column("low").unwrap().get_value(0), Some(Value::F64(9.0)));
+/// assert_eq!(bars.get_column("close").unwrap().get_value(0), Some(Value::F64(11.0)));
+/// assert_eq!(bars.get_column("volume").unwrap().get_value(1), Some(Value::I32(1)));
+/// ```
+pub fn ohlc(
+    df: &DataFrame,
+    time_col: &str,
+    price_col: &str,
+    every: &str,
+) -> Result<DataFrame, VeloxxError> {
+    ohlc_with(df, time_col, price_col, every, &OhlcOptions::default())
+}
+
+/// Open/high/low/close/volume bars of `price_col` over `every`-long buckets of
+/// the `DateTime` column `time_col`; `every` is as for
+/// [`Expr::dt_truncate`](crate::expressions::Expr::dt_truncate).
+///
+/// The result has one row per bucket holding a tick, in time order: the bucket
+/// start in `time_col`, then `open`, `high`, `low` and `close` as `F64`, and
+/// `volume`, the `F64` sum of `options.volume_col` or the `I32` tick count.
+/// Ticks need not be sorted; open and close are the earliest and latest ticks
+/// of the bucket, ties going to the first and last in frame order. Ticks with
+/// a null time or price are skipped, as are those outside `options.session`,
+/// whose bars start at the session open rather than the epoch.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::dataframe::time_series::{ohlc_with, OhlcOptions, TradingSession};
+/// use veloxx::dataframe::DataFrame;
+/// use veloxx::types::Value;
+///
+/// // Monday 2024-01-01 at 09:45, 10:15 and 16:05 UTC
+/// let monday = 1_704_067_200;
+/// let ticks = DataFrame::builder()
+///     .col_datetime("ts", [monday + 35_100, monday + 36_900, monday + 57_900])
+///     .col_f64("price", [100.0, 101.5, 99.0])
+///     .col_f64("size", [5.0, 2.5, 1.0])
+///     .build()
+///     .unwrap();
+/// let options = OhlcOptions {
+///     volume_col: Some("size".to_string()),
+///     session: Some(TradingSession::new(34_200, 57_600)), // 09:30 to 16:00
+/// };
+/// let bars = ohlc_with(&ticks, "ts", "price", "1h", &options).unwrap();
+/// // Both session ticks fall in the 09:30 bar; the 16:05 tick is dropped
+/// assert_eq!(bars.row_count(), 1);
+/// assert_eq!(bars.get_column("ts").unwrap().get_value(0), Some(Value::DateTime(monday + 34_200)));
+/// assert_eq!(bars.get_column("volume").unwrap().get_value(0), Some(Value::F64(7.5)));
+/// ```
+pub fn ohlc_with(
+    df: &DataFrame,
+    time_col: &str,
+    price_col: &str,
+    every: &str,
+    options: &OhlcOptions,
+) -> Result<DataFrame, VeloxxError> {
+    let column = |name: &str| {
+        df.get_column(name)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+    };
+    let times = match column(time_col)? {
+        Series::DateTime(_, values, validity) => (values, validity),
+        other => {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "OHLC bars require a DateTime time column, '{}' is {:?}",
+                time_col,
+                other.data_type()
+            )))
+        }
+    };
+    let prices = numeric_column(column(price_col)?)?;
+    let volumes = match &options.volume_col {
+        Some(name) => Some(numeric_column(column(name)?)?),
+        None => None,
+    };
+    let every = crate::expressions::parse_interval(every)?;
+
+    let buckets: Vec<Option<i64>> = (0..df.row_count())
+        .map(|row| {
+            if !times.1[row] || prices(row).is_none() {
+                return None;
+            }
+            let time = times.0[row];
+            match &options.session {
+                Some(session) => session.bucket(time, every),
+                None => Some(time.div_euclid(every) * every),
+            }
+        })
+        .collect();
+    let mut keys = HashMap::new();
+    keys.insert(
+        time_col.to_string(),
+        Series::new_datetime(time_col, buckets.clone()),
+    );
+    let keys = DataFrame::new(keys)?;
+    let grouped = keys
+        .group_by(vec![time_col.to_string()])?
+        .deterministic(true);
+
+    let mut starts = Vec::new();
+    let (mut open, mut high, mut low, mut close) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    let (mut volume, mut ticks) = (Vec::new(), Vec::new());
+    for rows in grouped.group_rows() {
+        let Some(start) = buckets[rows[0]] else {
+            continue;
+        };
+        let mut ordered = rows.clone();
+        ordered.sort_by_key(|&row| times.0[row]);
+        let bar_prices: Vec<f64> = ordered.iter().filter_map(|&row| prices(row)).collect();
+        starts.push(Some(start));
+        open.push(Some(bar_prices[0]));
+        high.push(Some(bar_prices.iter().copied().fold(f64::MIN, f64::max)));
+        low.push(Some(bar_prices.iter().copied().fold(f64::MAX, f64::min)));
+        close.push(Some(bar_prices[bar_prices.len() - 1]));
+        if let Some(volumes) = &volumes {
+            volume.push(Some(
+                rows.iter().filter_map(|&row| volumes(row)).sum::<f64>(),
+            ));
+        }
+        ticks.push(Some(rows.len() as i32));
+    }
+
+    let mut columns = HashMap::new();
+    let mut add = |series: Series| columns.insert(series.name().to_string(), series);
+    add(Series::new_datetime(time_col, starts));
+    add(Series::new_f64("open", open));
+    add(Series::new_f64("high", high));
+    add(Series::new_f64("low", low));
+    add(Series::new_f64("close", close));
+    add(match volumes {
+        Some(_) => Series::new_f64("volume", volume),
+        None => Series::new_i32("volume", ticks),
+    });
+    DataFrame::new(columns)
+}
+
+/// Reads the values of an `I32` or `F64` column as `f64`, `None` for nulls
+fn numeric_column(series: &Series) -> Result<impl Fn(usize) -> Option<f64> + '_, VeloxxError> {
+    if !series.is_numeric() {
+        return Err(VeloxxError::DataTypeMismatch(format!(
+            "OHLC bars require numeric columns, '{}' is {:?}",
+            series.name(),
+            series.data_type()
+        )));
+    }
+    Ok(move |row| match series.get_value(row)? {
+        Value::I32(v) => Some(v as f64),
+        Value::F64(v) => Some(v),
+        _ => None,
+    })
 }
 
 #[cfg(test)]
@@ -423,4 +871,128 @@ mod tests {
         let result = df.rolling_mean(vec!["price".to_string()], 5);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_dataframe_pairwise_rolling_corr() {
+        let mut columns = HashMap::new();
+        columns.insert(
+            "a".to_string(),
+            Series::new_f64("a", vec![Some(1.0), Some(2.0), Some(3.0)]),
+        );
+        columns.insert(
+            "b".to_string(),
+            Series::new_f64("b", vec![Some(2.0), Some(4.0), Some(6.0)]),
+        );
+        columns.insert(
+            "c".to_string(),
+            Series::new_i32("c", vec![Some(3), Some(2), Some(1)]),
+        );
+        let df = DataFrame::new(columns).unwrap();
+
+        let result = df.pairwise_rolling_corr(&["a", "b", "c"], 2).unwrap();
+        assert_eq!(result.column_count(), 6);
+        let ab = result.get_column("a_b_rolling_corr_2").unwrap();
+        assert_eq!(ab.get_value(0), None);
+        assert_eq!(ab.get_value(2), Some(Value::F64(1.0)));
+        let bc = result.get_column("b_c_rolling_corr_2").unwrap();
+        assert_eq!(bc.get_value(1), Some(Value::F64(-1.0)));
+
+        assert!(matches!(
+            df.pairwise_rolling_corr(&["a", "missing"], 2),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_ohlc_bars() {
+        let mut columns = HashMap::new();
+        columns.insert(
+            "ts".to_string(),
+            Series::new_datetime(
+                "ts",
+                vec![Some(130), Some(65), None, Some(10), Some(70), Some(5)],
+            ),
+        );
+        columns.insert(
+            "price".to_string(),
+            Series::new_i32(
+                "price",
+                vec![Some(7), Some(4), Some(100), Some(2), Some(9), None],
+            ),
+        );
+        let df = DataFrame::new(columns).unwrap();
+
+        let bars = ohlc(&df, "ts", "price", "1m").unwrap();
+        let column = |name: &str| bars.get_column(name).unwrap().clone();
+        assert_eq!(bars.row_count(), 3);
+        assert_eq!(column("ts").get_value(1), Some(Value::DateTime(60)));
+        // The 60s bar holds 4 at 65s and 9 at 70s
+        assert_eq!(column("open").get_value(1), Some(Value::F64(4.0)));
+        assert_eq!(column("high").get_value(1), Some(Value::F64(9.0)));
+        assert_eq!(column("close").get_value(1), Some(Value::F64(9.0)));
+        assert_eq!(column("volume").get_value(1), Some(Value::I32(2)));
+        // The null price at 5s and the null time are skipped
+        assert_eq!(column("volume").get_value(0), Some(Value::I32(1)));
+
+        assert!(matches!(
+            ohlc(&df, "price", "price", "1m"),
+            Err(VeloxxError::DataTypeMismatch(_))
+        ));
+        assert!(ohlc(&df, "ts", "price", "1x").is_err());
+        assert!(matches!(
+            ohlc(&df, "ts", "missing", "1m"),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_ohlc_trading_session() {
+        // Friday 2024-01-05 and Saturday 2024-01-06, midnight UTC
+        let friday = 1_704_412_800;
+        let saturday = friday + 86_400;
+        let mut columns = HashMap::new();
+        columns.insert(
+            "ts".to_string(),
+            Series::new_datetime(
+                "ts",
+                vec![
+                    Some(friday + 13 * 3_600),
+                    Some(friday + 14 * 3_600 + 2_400),
+                    Some(friday + 15 * 3_600),
+                    Some(saturday + 14 * 3_600),
+                ],
+            ),
+        );
+        columns.insert(
+            "price".to_string(),
+            Series::new_f64("price", vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0)]),
+        );
+        let df = DataFrame::new(columns).unwrap();
+
+        // 09:30 to 16:00 in UTC-5, i.e. 14:30 to 21:00 UTC
+        let options = OhlcOptions {
+            volume_col: None,
+            session: Some(TradingSession {
+                open: 34_200,
+                close: 57_600,
+                utc_offset: -5 * 3_600,
+                weekdays_only: true,
+            }),
+        };
+        let bars = ohlc_with(&df, "ts", "price", "30m", &options).unwrap();
+        // 13:00 is before the open and the Saturday tick is off-session
+        assert_eq!(bars.row_count(), 2);
+        let starts = bars.get_column("ts").unwrap();
+        assert_eq!(
+            starts.get_value(0),
+            Some(Value::DateTime(friday + 14 * 3_600 + 1_800))
+        );
+        assert_eq!(
+            starts.get_value(1),
+            Some(Value::DateTime(friday + 15 * 3_600))
+        );
+        let open = bars.get_column("open").unwrap();
+        assert_eq!(open.get_value(0), Some(Value::F64(2.0)));
+        assert_eq!(open.get_value(1), Some(Value::F64(3.0)));
+    }
 }
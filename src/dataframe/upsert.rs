@@ -0,0 +1,209 @@
+//! Updating rows by key from another frame and adding the new ones.
+
+use crate::dataframe::join::take_optional;
+use crate::dataframe::DataFrame;
+use crate::types::Value;
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+/// How [`DataFrame::upsert_with_options`] picks a column's value for a row
+/// whose key is in both frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Keep this frame's value
+    PreferLeft,
+    /// Take the other frame's value, even when it is null
+    #[default]
+    PreferRight,
+    /// Take the other frame's value unless it is null
+    Coalesce,
+}
+
+/// Options for [`DataFrame::upsert_with_options`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UpsertOptions {
+    /// Strategy for columns without an entry in `strategies`
+    pub default_strategy: MergeStrategy,
+    /// Per-column strategies, by column name
+    pub strategies: HashMap<String, MergeStrategy>,
+}
+
+impl UpsertOptions {
+    /// Sets the strategy for `column`.
+    pub fn merge_strategy(mut self, column: &str, strategy: MergeStrategy) -> Self {
+        self.strategies.insert(column.to_string(), strategy);
+        self
+    }
+
+    fn strategy(&self, column: &str) -> MergeStrategy {
+        self.strategies
+            .get(column)
+            .copied()
+            .unwrap_or(self.default_strategy)
+    }
+}
+
+/// The key of `row`, or `None` if any key column is null there
+fn row_key(df: &DataFrame, key_columns: &[&str], row: usize) -> Option<Vec<Value>> {
+    key_columns
+        .iter()
+        .map(|name| df.columns[*name].get_value(row))
+        .collect()
+}
+
+impl DataFrame {
+    /// Updates the rows whose `key_columns` values appear in `other` with
+    /// `other`'s values and appends `other`'s remaining rows; see
+    /// [`DataFrame::upsert_with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::types::Value;
+    ///
+    /// let customers = df!("id" => [1, 2], "city" => ["Oslo", "Rome"]).unwrap();
+    /// let changes = df!("id" => [2, 3], "city" => ["Paris", "Lima"]).unwrap();
+    /// let customers = customers.upsert(&changes, &["id"]).unwrap();
+    ///
+    /// let city = customers.get_column("city").unwrap();
+    /// assert_eq!(customers.row_count(), 3);
+    /// assert_eq!(city.get_value(1), Some(Value::String("Paris".to_string())));
+    /// assert_eq!(city.get_value(2), Some(Value::String("Lima".to_string())));
+    /// ```
+    pub fn upsert(&self, other: &DataFrame, key_columns: &[&str]) -> Result<Self, VeloxxError> {
+        self.upsert_with_options(other, key_columns, &UpsertOptions::default())
+    }
+
+    /// Updates the rows whose `key_columns` values appear in `other`, merging
+    /// each column as `options` says, and appends `other`'s remaining rows.
+    ///
+    /// This frame's rows keep their order, followed by the appended rows in
+    /// `other`'s order. Every column of `other` must exist here with the same
+    /// type; columns `other` lacks keep their values on updated rows and are
+    /// null on appended ones. Rows with a null key never match and are simply
+    /// appended. A key must occur at most once in `other`, but may repeat here,
+    /// in which case every matching row is updated. Column constraints are
+    /// kept and checked as for [`DataFrame::append`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use veloxx::dataframe::upsert::{MergeStrategy, UpsertOptions};
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let frame = |ids: Vec<i32>, emails: Vec<Option<&str>>, since: Vec<i32>| {
+    ///     let mut columns = HashMap::new();
+    ///     columns.insert("id".to_string(), Series::new_i32("id", ids.into_iter().map(Some).collect()));
+    ///     let emails = emails.into_iter().map(|e| e.map(String::from)).collect();
+    ///     columns.insert("email".to_string(), Series::new_string("email", emails));
+    ///     columns.insert("since".to_string(), Series::new_i32("since", since.into_iter().map(Some).collect()));
+    ///     DataFrame::new(columns).unwrap()
+    /// };
+    /// let current = frame(vec![1], vec![Some("a@x.org")], vec![2019]);
+    /// let update = frame(vec![1], vec![None], vec![2024]);
+    ///
+    /// // Keep the first-seen date and don't let a missing email erase one
+    /// let options = UpsertOptions::default()
+    ///     .merge_strategy("since", MergeStrategy::PreferLeft)
+    ///     .merge_strategy("email", MergeStrategy::Coalesce);
+    /// let merged = current.upsert_with_options(&update, &["id"], &options).unwrap();
+    /// assert_eq!(merged.get_column("since").unwrap().get_value(0), Some(Value::I32(2019)));
+    /// assert_eq!(
+    ///     merged.get_column("email").unwrap().get_value(0),
+    ///     Some(Value::String("a@x.org".to_string()))
+    /// );
+    /// ```
+    pub fn upsert_with_options(
+        &self,
+        other: &DataFrame,
+        key_columns: &[&str],
+        options: &UpsertOptions,
+    ) -> Result<Self, VeloxxError> {
+        if key_columns.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "Upsert needs at least one key column.".to_string(),
+            ));
+        }
+        for name in key_columns {
+            if !other.columns.contains_key(*name) {
+                return Err(VeloxxError::ColumnNotFound(format!(
+                    "Key column '{name}' not found in other DataFrame."
+                )));
+            }
+        }
+        for (name, series) in &other.columns {
+            let own = self.get_column(name).ok_or_else(|| {
+                VeloxxError::ColumnNotFound(format!(
+                    "Column '{name}' of other DataFrame not found in this DataFrame."
+                ))
+            })?;
+            if own.data_type() != series.data_type() {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot upsert column '{name}': {:?} does not match {:?}.",
+                    series.data_type(),
+                    own.data_type()
+                )));
+            }
+        }
+
+        let mut index: HashMap<Vec<Value>, usize> = HashMap::new();
+        for row in 0..other.row_count {
+            if let Some(key) = row_key(other, key_columns, row) {
+                if index.insert(key, row).is_some() {
+                    return Err(VeloxxError::InvalidOperation(format!(
+                        "Duplicate upsert key at row {row} of other DataFrame."
+                    )));
+                }
+            }
+        }
+
+        let mut matched = vec![false; other.row_count];
+        let matches: Vec<Option<usize>> = (0..self.row_count)
+            .map(|row| {
+                let found =
+                    row_key(self, key_columns, row).and_then(|key| index.get(&key).copied());
+                if let Some(other_row) = found {
+                    matched[other_row] = true;
+                }
+                found
+            })
+            .collect();
+        let appended: Vec<usize> = (0..other.row_count).filter(|&row| !matched[row]).collect();
+
+        let n = self.row_count;
+        let mut columns = HashMap::with_capacity(self.columns.len());
+        for (name, own) in &self.columns {
+            // Gather from this column followed by the other frame's, if any
+            let (combined, indices) = match other.columns.get(name) {
+                Some(theirs) => {
+                    let strategy = if key_columns.contains(&name.as_str()) {
+                        MergeStrategy::PreferLeft
+                    } else {
+                        options.strategy(name)
+                    };
+                    let updated = matches.iter().enumerate().map(|(row, found)| {
+                        let take_theirs = match (strategy, found) {
+                            (MergeStrategy::PreferLeft, _) | (_, None) => None,
+                            (MergeStrategy::PreferRight, Some(j)) => Some(*j),
+                            (MergeStrategy::Coalesce, Some(j)) => theirs.get_value(*j).map(|_| *j),
+                        };
+                        Some(take_theirs.map_or(row, |j| n + j))
+                    });
+                    let indices = updated.chain(appended.iter().map(|&j| Some(n + j)));
+                    (own.append(theirs)?, indices.collect::<Vec<_>>())
+                }
+                None => {
+                    let indices = (0..n).map(Some).chain(appended.iter().map(|_| None));
+                    (own.clone(), indices.collect())
+                }
+            };
+            columns.insert(name.clone(), take_optional(&combined, &indices));
+        }
+
+        DataFrame::new(columns)?.with_constraints(self.constraints.clone())
+    }
+}
@@ -57,7 +57,7 @@ use rayon::prelude::*;
 use std::sync::Arc;
 
 #[cfg(feature = "distributed")]
-use arrow::array::{Array, BooleanArray, Float64Array, Int32Array, StringArray};
+use arrow::array::{Array, BinaryArray, BooleanArray, Float64Array, Int32Array, StringArray};
 #[cfg(feature = "distributed")]
 use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
 #[cfg(feature = "distributed")]
@@ -187,6 +187,11 @@ impl DistributedDataFrame {
                 let sliced_bitmap: Vec<bool> = bitmap[start_row..end_row].to_vec();
                 Ok(Series::DateTime(name.clone(), sliced_values, sliced_bitmap))
             }
+            Series::Binary(name, values, bitmap) => {
+                let sliced_values: Vec<Vec<u8>> = values[start_row..end_row].to_vec();
+                let sliced_bitmap: Vec<bool> = bitmap[start_row..end_row].to_vec();
+                Ok(Series::Binary(name.clone(), sliced_values, sliced_bitmap))
+            }
         }
     }
 
@@ -632,6 +637,13 @@ impl ArrowInterop {
                     let placeholder_array = Int32Array::from(vec![Some(0); dataframe.row_count()]);
                     arrays.push(Arc::new(placeholder_array));
                 }
+                Series::Binary(name, values, _bitmap) => {
+                    let field = Field::new(name, ArrowDataType::Binary, true);
+                    fields.push(field);
+
+                    let arrow_array = BinaryArray::from_iter_values(values);
+                    arrays.push(Arc::new(arrow_array));
+                }
             }
         }
 
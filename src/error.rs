@@ -10,6 +10,10 @@ pub enum VeloxxError {
     MemoryError(String),
     ExecutionError(String),
     Other(String),
+    WithContext {
+        context: ErrorContext,
+        source: Box<VeloxxError>,
+    },
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -25,12 +29,22 @@ impl std::fmt::Display for VeloxxError {
             VeloxxError::MemoryError(msg) => write!(f, "Memory error: {}", msg),
             VeloxxError::ExecutionError(msg) => write!(f, "Execution error: {}", msg),
             VeloxxError::Other(msg) => write!(f, "Error: {}", msg),
+            VeloxxError::WithContext { context, source } => {
+                write!(f, "{} ({})", source, context)
+            }
         }
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-impl std::error::Error for VeloxxError {}
+impl std::error::Error for VeloxxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VeloxxError::WithContext { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 // This file handles error types for the Veloxx library.
 // Ensure that any error handling that uses non-WASM-compatible dependencies
 // is feature gated and excluded from WASM builds.
@@ -79,6 +93,167 @@ pub enum VeloxxError {
     ExecutionError(String),
     #[error("Other error: {0}")]
     Other(String),
+    /// An error annotated with the operation, column, row or file location it
+    /// occurred at. The wrapped error is exposed through `Error::source()`.
+    #[error("{source} ({context})")]
+    WithContext {
+        context: ErrorContext,
+        source: Box<VeloxxError>,
+    },
+}
+
+/// Location information attached to a [`VeloxxError`].
+///
+/// Every field is optional; only the pieces that are known at the point the
+/// context is added are rendered.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::error::{ErrorContext, VeloxxError};
+///
+/// let err = VeloxxError::ColumnNotFound("price".to_string())
+///     .with_context(ErrorContext::new().operation("select").column("price"));
+/// assert_eq!(
+///     err.to_string(),
+///     "Column not found: price (operation 'select', column 'price')"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub operation: Option<String>,
+    pub column: Option<String>,
+    pub row: Option<usize>,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+}
+
+impl ErrorContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the operation that failed.
+    pub fn operation(mut self, operation: &str) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+
+    /// Sets the column being processed.
+    pub fn column(mut self, column: &str) -> Self {
+        self.column = Some(column.to_string());
+        self
+    }
+
+    /// Sets the (zero-based) row index being processed.
+    pub fn row(mut self, row: usize) -> Self {
+        self.row = Some(row);
+        self
+    }
+
+    /// Sets the file being read or written.
+    pub fn file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    /// Sets the (one-based) line within the file.
+    pub fn line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(operation) = &self.operation {
+            parts.push(format!("operation '{}'", operation));
+        }
+        if let Some(column) = &self.column {
+            parts.push(format!("column '{}'", column));
+        }
+        if let Some(row) = self.row {
+            parts.push(format!("row {}", row));
+        }
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => parts.push(format!("file {}:{}", file, line)),
+            (Some(file), None) => parts.push(format!("file {}", file)),
+            (None, Some(line)) => parts.push(format!("line {}", line)),
+            (None, None) => {}
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl VeloxxError {
+    /// Wraps this error with additional context.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        VeloxxError::WithContext {
+            context,
+            source: Box::new(self),
+        }
+    }
+
+    /// Names `file` in this error's outermost context, e.g. next to the line a
+    /// reader failed at, or wraps the error in a new context naming it.
+    pub fn in_file(self, file: &str) -> Self {
+        match self {
+            VeloxxError::WithContext {
+                mut context,
+                source,
+            } if context.file.is_none() => {
+                context.file = Some(file.to_string());
+                VeloxxError::WithContext { context, source }
+            }
+            other => other.with_context(ErrorContext::new().file(file)),
+        }
+    }
+
+    /// Returns the innermost context attached to this error, if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            VeloxxError::WithContext { context, source } => source.context().or(Some(context)),
+            _ => None,
+        }
+    }
+
+    /// Returns every context in the chain, from outermost to innermost.
+    pub fn contexts(&self) -> Vec<&ErrorContext> {
+        let mut contexts = Vec::new();
+        let mut current = self;
+        while let VeloxxError::WithContext { context, source } = current {
+            contexts.push(context);
+            current = source;
+        }
+        contexts
+    }
+
+    /// Returns the underlying error with all context layers stripped.
+    pub fn root_cause(&self) -> &VeloxxError {
+        match self {
+            VeloxxError::WithContext { source, .. } => source.root_cause(),
+            other => other,
+        }
+    }
+}
+
+/// Extension trait for attaching [`ErrorContext`] to `Result`s.
+pub trait ResultExt<T> {
+    /// Attaches the context produced by `f` if the result is an error.
+    fn with_context<F>(self, f: F) -> Result<T, VeloxxError>
+    where
+        F: FnOnce() -> ErrorContext;
+}
+
+impl<T> ResultExt<T> for Result<T, VeloxxError> {
+    fn with_context<F>(self, f: F) -> Result<T, VeloxxError>
+    where
+        F: FnOnce() -> ErrorContext,
+    {
+        self.map_err(|e| e.with_context(f()))
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -111,7 +286,22 @@ impl From<arrow::error::ArrowError> for VeloxxError {
 
 #[cfg(all(feature = "python", not(target_arch = "wasm32")))]
 impl From<VeloxxError> for pyo3::PyErr {
+    /// Maps each error kind to the closest built-in Python exception.
+    ///
+    /// The root cause decides the exception type, so added context never changes
+    /// which `except` clause catches an error.
     fn from(err: VeloxxError) -> Self {
-        pyo3::exceptions::PyValueError::new_err(err.to_string())
+        use pyo3::exceptions::{
+            PyIOError, PyKeyError, PyMemoryError, PyNotImplementedError, PyTypeError, PyValueError,
+        };
+        let message = err.to_string();
+        match err.root_cause() {
+            VeloxxError::ColumnNotFound(_) => PyKeyError::new_err(message),
+            VeloxxError::DataTypeMismatch(_) => PyTypeError::new_err(message),
+            VeloxxError::FileIO(_) => PyIOError::new_err(message),
+            VeloxxError::Unsupported(_) => PyNotImplementedError::new_err(message),
+            VeloxxError::MemoryError(_) => PyMemoryError::new_err(message),
+            _ => PyValueError::new_err(message),
+        }
     }
 }
@@ -1,3 +1,5 @@
+use crate::series::bytes::{decode_base64, encode_base64};
+use crate::series::strings::slice_chars;
 use crate::types::Value;
 use crate::VeloxxError;
 
@@ -173,9 +175,101 @@ pub enum Expr {
     /// # Arguments
     /// - `Box<Expr>`: The expression to negate.
     Not(Box<Expr>),
+    /// Represents the number of chars (not bytes) in a string expression.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The string expression to measure.
+    StrLenChars(Box<Expr>),
+    /// Represents a char-based substring of a string expression.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The string expression to slice.
+    /// - `usize`: The char offset to start at.
+    /// - `Option<usize>`: The maximum number of chars to take, or `None` for the rest.
+    StrSliceChars(Box<Expr>, usize, Option<usize>),
+    /// Represents the base64 text of a binary expression.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The binary expression to encode.
+    Base64Encode(Box<Expr>),
+    /// Represents the bytes decoded from a base64 string expression.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The string expression to decode.
+    Base64Decode(Box<Expr>),
+    /// Represents a timestamp rounded down to a multiple of a fixed interval,
+    /// e.g. the start of its day; see [`Expr::dt_truncate`].
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The `DateTime` expression to truncate.
+    /// - `String`: The interval, such as `"15m"` or `"1d"`.
+    DtTruncate(Box<Expr>, String),
+    /// Names the output of an expression, e.g. in [`DataFrame::select_exprs`].
+    /// Evaluates to the same value as the wrapped expression.
+    ///
+    /// # Arguments
+    /// - `Box<Expr>`: The expression to name.
+    /// - `String`: The output column name.
+    ///
+    /// [`DataFrame::select_exprs`]: crate::dataframe::DataFrame::select_exprs
+    Alias(Box<Expr>, String),
 }
 
 impl Expr {
+    /// Names the output of this expression, like SQL `expr AS name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::expressions::Expr;
+    ///
+    /// let product = Expr::Multiply(
+    ///     Box::new(Expr::Column("a".to_string())),
+    ///     Box::new(Expr::Column("b".to_string())),
+    /// )
+    /// .alias("product");
+    /// assert_eq!(product.output_name(), Some("product"));
+    /// ```
+    pub fn alias(self, name: &str) -> Expr {
+        Expr::Alias(Box::new(self), name.to_string())
+    }
+
+    /// Rounds a `DateTime` expression down to a multiple of `every` since the
+    /// Unix epoch, e.g. `"1d"` for the start of the day in UTC. `every` is a
+    /// positive count followed by `s`, `m`, `h`, `d` or `w`; other strings make
+    /// evaluation fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use veloxx::dataframe::DataFrame;
+    /// use veloxx::expressions::Expr;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// // 1970-01-02 01:01:01 UTC
+    /// let mut columns = HashMap::new();
+    /// columns.insert("ts".to_string(), Series::new_datetime("ts", vec![Some(90_061)]));
+    /// let df = DataFrame::new(columns).unwrap();
+    /// let hour = Expr::dt_truncate(Expr::Column("ts".to_string()), "1h");
+    /// assert_eq!(hour.evaluate(&df, 0).unwrap(), Value::DateTime(86400 + 3600));
+    /// ```
+    pub fn dt_truncate(expr: Expr, every: &str) -> Expr {
+        Expr::DtTruncate(Box::new(expr), every.to_string())
+    }
+
+    /// The column name this expression produces when selected: its alias, the
+    /// column name for a bare column reference or a truncated one; `None`
+    /// otherwise.
+    pub fn output_name(&self) -> Option<&str> {
+        match self {
+            Expr::Alias(_, name) | Expr::Column(name) => Some(name),
+            Expr::DtTruncate(inner, _) => inner.output_name(),
+            _ => None,
+        }
+    }
+
     /// Evaluates the expression for a specific row in the DataFrame.
     ///
     /// Returns the computed `Value` or an error if the expression cannot be evaluated.
@@ -183,9 +277,28 @@ impl Expr {
         &self,
         df: &crate::dataframe::DataFrame,
         row_index: usize,
+    ) -> Result<Value, VeloxxError> {
+        self.evaluate_with(df, row_index, &[])
+    }
+
+    /// Like [`Expr::evaluate`], but columns named in `derived` resolve to the
+    /// given values for this row instead of being looked up in `df`.
+    pub(crate) fn evaluate_with(
+        &self,
+        df: &crate::dataframe::DataFrame,
+        row_index: usize,
+        derived: &[(&str, Value)],
     ) -> Result<Value, VeloxxError> {
         match self {
             Expr::Column(col_name) => {
+                if let Some((_, value)) = derived.iter().find(|(name, _)| name == col_name) {
+                    return match value {
+                        Value::Null => Err(VeloxxError::InvalidOperation(format!(
+                            "Null value at row {row_index} in column {col_name}"
+                        ))),
+                        value => Ok(value.clone()),
+                    };
+                }
                 let series = df
                     .get_column(col_name)
                     .ok_or(VeloxxError::ColumnNotFound(col_name.to_string()))?;
@@ -197,8 +310,8 @@ impl Expr {
             }
             Expr::Literal(value) => Ok(value.clone()),
             Expr::Add(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::I32(l + r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::F64(l + r)),
@@ -208,8 +321,8 @@ impl Expr {
                 }
             }
             Expr::Subtract(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::I32(l - r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::F64(l - r)),
@@ -219,8 +332,8 @@ impl Expr {
                 }
             }
             Expr::Multiply(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::I32(l * r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::F64(l * r)),
@@ -230,8 +343,8 @@ impl Expr {
                 }
             }
             Expr::Divide(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => {
                         if r == 0 {
@@ -255,18 +368,18 @@ impl Expr {
                 }
             }
             Expr::Equals(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 Ok(Value::Bool(left_val == right_val))
             }
             Expr::NotEquals(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 Ok(Value::Bool(left_val != right_val))
             }
             Expr::GreaterThan(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::Bool(l > r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::Bool(l > r)),
@@ -276,8 +389,8 @@ impl Expr {
                 }
             }
             Expr::LessThan(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::Bool(l < r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::Bool(l < r)),
@@ -287,8 +400,8 @@ impl Expr {
                 }
             }
             Expr::GreaterThanOrEqual(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::Bool(l >= r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::Bool(l >= r)),
@@ -298,8 +411,8 @@ impl Expr {
                 }
             }
             Expr::LessThanOrEqual(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::I32(l), Value::I32(r)) => Ok(Value::Bool(l <= r)),
                     (Value::F64(l), Value::F64(r)) => Ok(Value::Bool(l <= r)),
@@ -309,8 +422,8 @@ impl Expr {
                 }
             }
             Expr::And(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::Bool(l), Value::Bool(r)) => Ok(Value::Bool(l && r)),
                     _ => Err(VeloxxError::InvalidOperation(
@@ -319,8 +432,8 @@ impl Expr {
                 }
             }
             Expr::Or(left, right) => {
-                let left_val = left.evaluate(df, row_index)?;
-                let right_val = right.evaluate(df, row_index)?;
+                let left_val = left.evaluate_with(df, row_index, derived)?;
+                let right_val = right.evaluate_with(df, row_index, derived)?;
                 match (left_val, right_val) {
                     (Value::Bool(l), Value::Bool(r)) => Ok(Value::Bool(l || r)),
                     _ => Err(VeloxxError::InvalidOperation(
@@ -329,7 +442,7 @@ impl Expr {
                 }
             }
             Expr::Not(expr) => {
-                let val = expr.evaluate(df, row_index)?;
+                let val = expr.evaluate_with(df, row_index, derived)?;
                 match val {
                     Value::Bool(b) => Ok(Value::Bool(!b)),
                     _ => Err(VeloxxError::InvalidOperation(
@@ -337,6 +450,68 @@ impl Expr {
                     )),
                 }
             }
+            Expr::StrLenChars(expr) => match expr.evaluate_with(df, row_index, derived)? {
+                Value::String(s) => Ok(Value::I32(s.chars().count() as i32)),
+                _ => Err(VeloxxError::InvalidOperation(
+                    "Unsupported type for string length".to_string(),
+                )),
+            },
+            Expr::StrSliceChars(expr, start, length) => {
+                match expr.evaluate_with(df, row_index, derived)? {
+                    Value::String(s) => {
+                        Ok(Value::String(slice_chars(&s, *start, *length).to_string()))
+                    }
+                    _ => Err(VeloxxError::InvalidOperation(
+                        "Unsupported type for string slicing".to_string(),
+                    )),
+                }
+            }
+            Expr::Base64Encode(expr) => match expr.evaluate_with(df, row_index, derived)? {
+                Value::Binary(bytes) => Ok(Value::String(encode_base64(&bytes))),
+                Value::Null => Ok(Value::Null),
+                _ => Err(VeloxxError::InvalidOperation(
+                    "Unsupported type for base64 encoding".to_string(),
+                )),
+            },
+            Expr::Base64Decode(expr) => match expr.evaluate_with(df, row_index, derived)? {
+                Value::String(s) => Ok(Value::Binary(decode_base64(&s)?)),
+                Value::Null => Ok(Value::Null),
+                _ => Err(VeloxxError::InvalidOperation(
+                    "Unsupported type for base64 decoding".to_string(),
+                )),
+            },
+            Expr::DtTruncate(expr, every) => {
+                let every = parse_interval(every)?;
+                match expr.evaluate_with(df, row_index, derived)? {
+                    Value::DateTime(t) => Ok(Value::DateTime(t.div_euclid(every) * every)),
+                    Value::Null => Ok(Value::Null),
+                    _ => Err(VeloxxError::InvalidOperation(
+                        "Unsupported type for datetime truncation".to_string(),
+                    )),
+                }
+            }
+            Expr::Alias(expr, _) => expr.evaluate_with(df, row_index, derived),
         }
     }
 }
+
+/// Seconds in an interval such as `"30s"`, `"15m"`, `"1h"`, `"1d"` or `"2w"`
+pub(crate) fn parse_interval(every: &str) -> Result<i64, VeloxxError> {
+    let invalid = || VeloxxError::InvalidOperation(format!("Invalid interval '{every}'"));
+    let split = every
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (count, unit) = every.split_at(split);
+    let unit_seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => return Err(invalid()),
+    };
+    match count.parse::<i64>() {
+        Ok(count) if count > 0 => count.checked_mul(unit_seconds).ok_or_else(invalid),
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,81 @@
+//! `tracing` spans around expensive operations.
+//!
+//! With the `tracing` feature enabled, joins, sorts, group-by aggregations and
+//! file IO each run inside an `INFO` span with target `veloxx`, named after the
+//! operation (`join`, `sort`, `group_by`, `read_csv`, ...). Every span carries
+//! the operation's own fields, such as `rows_in` or `path`, and on completion
+//! records `rows_out` (when the operation produces a `DataFrame`) and
+//! `duration_ms`. Failures are reported as a `WARN` event inside the span.
+//!
+//! Without the feature the [`instrumented!`] macro expands to the wrapped
+//! expression alone, so untraced builds pay nothing.
+
+#[cfg(feature = "tracing")]
+use crate::dataframe::DataFrame;
+#[cfg(feature = "tracing")]
+use crate::VeloxxError;
+
+/// Evaluates `$body`, a `Result` expression, inside a span named `$name` with
+/// the given `tracing` fields; see the [module documentation](self).
+#[cfg(feature = "tracing")]
+macro_rules! instrumented {
+    ($name:literal, { $($fields:tt)* }, $body:expr) => {{
+        let span = ::tracing::info_span!(
+            target: "veloxx",
+            $name,
+            rows_out = ::tracing::field::Empty,
+            duration_ms = ::tracing::field::Empty,
+            $($fields)*
+        );
+        $crate::instrument::in_span(&span, || $body)
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! instrumented {
+    ($name:literal, { $($fields:tt)* }, $body:expr) => {
+        $body
+    };
+}
+
+pub(crate) use instrumented;
+
+/// Output whose row count is recorded as `rows_out`
+#[cfg(feature = "tracing")]
+pub(crate) trait Output {
+    fn rows(&self) -> Option<usize>;
+}
+
+#[cfg(feature = "tracing")]
+impl Output for DataFrame {
+    fn rows(&self) -> Option<usize> {
+        Some(self.row_count())
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Output for () {
+    fn rows(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn in_span<T: Output>(
+    span: &tracing::Span,
+    body: impl FnOnce() -> Result<T, VeloxxError>,
+) -> Result<T, VeloxxError> {
+    let _entered = span.enter();
+    let start = std::time::Instant::now();
+    let result = body();
+    span.record("duration_ms", start.elapsed().as_secs_f64() * 1000.0);
+    match &result {
+        Ok(output) => {
+            if let Some(rows) = output.rows() {
+                span.record("rows_out", rows);
+            }
+        }
+        Err(error) => tracing::warn!(target: "veloxx", %error, "operation failed"),
+    }
+    result
+}
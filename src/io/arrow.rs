@@ -10,7 +10,9 @@ use crate::VeloxxError;
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
 use arrow::csv::reader::Format;
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
-use arrow::record_batch::RecordBatch;
+use arrow::datatypes::{Field, Schema};
+#[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
 use arrow_csv::ReaderBuilder;
 #[cfg(all(
@@ -42,27 +44,7 @@ pub fn read_csv_to_dataframe(file_path: &str) -> Result<DataFrame, VeloxxError>
         record_batches.push(batch);
     }
 
-    if record_batches.is_empty() {
-        return DataFrame::new(HashMap::new());
-    }
-
-    let schema = record_batches[0].schema();
-    let mut columns: HashMap<String, Series> = HashMap::new();
-
-    for i in 0..schema.fields().len() {
-        let field = schema.field(i);
-        let mut series_data: Vec<Series> = Vec::new();
-        for batch in &record_batches {
-            let array = batch.column(i);
-            series_data.push(Series::from_arrow_array(
-                array.clone(),
-                field.name().clone(),
-            )?);
-        }
-        columns.insert(field.name().clone(), Series::concat(series_data)?);
-    }
-
-    DataFrame::new(columns)
+    record_batches_to_dataframe(&record_batches)
 }
 
 #[cfg(feature = "advanced_io")]
@@ -76,20 +58,27 @@ pub fn read_parquet_to_dataframe(file_path: &str) -> Result<DataFrame, VeloxxErr
         record_batches.push(batch?);
     }
 
-    if record_batches.is_empty() {
-        return DataFrame::new(std::collections::HashMap::new());
+    record_batches_to_dataframe(&record_batches)
+}
+
+/// Convert a sequence of record batches sharing one schema into a `DataFrame`
+///
+/// Each column is converted batch by batch with [`Series::from_arrow_array`] and the
+/// pieces are concatenated.
+#[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+pub fn record_batches_to_dataframe(batches: &[RecordBatch]) -> Result<DataFrame, VeloxxError> {
+    if batches.is_empty() {
+        return DataFrame::new(HashMap::new());
     }
 
-    let schema = record_batches[0].schema();
-    let mut columns: std::collections::HashMap<String, Series> = std::collections::HashMap::new();
+    let schema = batches[0].schema();
+    let mut columns: HashMap<String, Series> = HashMap::new();
 
-    for i in 0..schema.fields().len() {
-        let field = schema.field(i);
-        let mut series_data: Vec<Series> = Vec::new();
-        for batch in &record_batches {
-            let array = batch.column(i);
+    for (i, field) in schema.fields().iter().enumerate() {
+        let mut series_data: Vec<Series> = Vec::with_capacity(batches.len());
+        for batch in batches {
             series_data.push(Series::from_arrow_array(
-                array.clone(),
+                batch.column(i).clone(),
                 field.name().clone(),
             )?);
         }
@@ -98,3 +87,28 @@ pub fn read_parquet_to_dataframe(file_path: &str) -> Result<DataFrame, VeloxxErr
 
     DataFrame::new(columns)
 }
+
+/// Convert a `DataFrame` into a single record batch with columns in sorted name order
+#[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+pub fn dataframe_to_record_batch(df: &DataFrame) -> Result<RecordBatch, VeloxxError> {
+    let mut names: Vec<&String> = df.column_names();
+    names.sort();
+
+    let mut fields = Vec::with_capacity(names.len());
+    let mut arrays = Vec::with_capacity(names.len());
+    for name in names {
+        let array = df
+            .get_column(name)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))?
+            .to_arrow_array();
+        fields.push(Field::new(name.as_str(), array.data_type().clone(), true));
+        arrays.push(array);
+    }
+
+    let options = RecordBatchOptions::new().with_row_count(Some(df.row_count()));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(Schema::new(fields)),
+        arrays,
+        &options,
+    )?)
+}
@@ -8,11 +8,17 @@
 //! - Target: 2-5 million rows/second (2-5x faster than Polars)
 
 use crate::dataframe::DataFrame;
+use crate::error::ErrorContext;
+use crate::io::datetime::{parse_datetime, INFERRED_DATETIME_FORMATS};
+use crate::io::options::{BadRows, ParseMode};
 use crate::series::Series;
+use crate::types::{parse_hex, DataType};
 use crate::VeloxxError;
 // ...existing code...
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::sync::Mutex;
 
 /// SIMD-accelerated CSV parser for ultra-fast data loading
 ///
@@ -32,6 +38,46 @@ pub struct UltraFastCsvParser {
     infer_types: bool,
     /// Buffer size for reading chunks
     _buffer_size: usize,
+    /// Number of data rows sampled for type inference (0 samples every row)
+    sample_rows: usize,
+    /// Column types that bypass inference
+    type_overrides: HashMap<String, DataType>,
+    /// Whether malformed rows fail the read or are set aside
+    parse_mode: ParseMode,
+    /// Rows set aside by the last permissive read
+    bad_rows: Mutex<Option<DataFrame>>,
+}
+
+/// Inferred (or overridden) type of one CSV column
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvColumnSchema {
+    pub name: String,
+    pub data_type: DataType,
+    /// Format used to parse a `DateTime` column, see [`crate::io::datetime`]
+    pub datetime_format: Option<String>,
+    /// Notes on competing interpretations of the values, such as day-first versus
+    /// month-first dates, or a column widened after the sample
+    pub ambiguities: Vec<String>,
+    /// Whether the type was set with [`UltraFastCsvParser::column_type`]
+    pub overridden: bool,
+}
+
+/// Column types of a CSV source, in header order
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CsvSchema {
+    pub columns: Vec<CsvColumnSchema>,
+}
+
+impl CsvSchema {
+    /// Returns the schema of the named column.
+    pub fn column(&self, name: &str) -> Option<&CsvColumnSchema> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    /// Returns `true` if any column has a reported ambiguity.
+    pub fn has_ambiguities(&self) -> bool {
+        self.columns.iter().any(|c| !c.ambiguities.is_empty())
+    }
 }
 
 impl Default for UltraFastCsvParser {
@@ -42,6 +88,10 @@ impl Default for UltraFastCsvParser {
             escape: b'\\',
             infer_types: true,
             _buffer_size: 64 * 1024, // 64KB chunks
+            sample_rows: 1000,
+            type_overrides: HashMap::new(),
+            parse_mode: ParseMode::Strict,
+            bad_rows: Mutex::new(None),
         }
     }
 }
@@ -70,16 +120,142 @@ impl UltraFastCsvParser {
         self
     }
 
-    /// Parse CSV from file path
-    pub fn read_file(&self, path: &str) -> Result<DataFrame, VeloxxError> {
+    /// Set how many data rows are sampled to infer column types (0 samples every row)
+    pub fn sample_rows(mut self, rows: usize) -> Self {
+        self.sample_rows = rows;
+        self
+    }
+
+    /// Force the type of a column instead of inferring it.
+    ///
+    /// Values that do not parse as `data_type` make the read fail. `DateTime` columns
+    /// use the first format from [`INFERRED_DATETIME_FORMATS`] that fits the sample.
+    pub fn column_type(mut self, column: &str, data_type: DataType) -> Self {
+        self.type_overrides.insert(column.to_string(), data_type);
+        self
+    }
+
+    /// Choose how rows with the wrong number of fields, or with values that do not
+    /// parse as an overridden column type, are handled (strict by default).
+    ///
+    /// In [`ParseMode::Permissive`] such rows are left out of the result and kept
+    /// for [`UltraFastCsvParser::bad_rows`]. Inferred columns are still widened
+    /// rather than rejecting rows.
+    pub fn parse_mode(mut self, mode: ParseMode) -> Self {
+        self.parse_mode = mode;
+        self
+    }
+
+    /// Rows set aside by the last read in [`ParseMode::Permissive`], or `None` if
+    /// the last read was strict.
+    ///
+    /// The frame has the 1-based data `row`, the failing `column` (null when the
+    /// field count was wrong), the `error` and the `record` text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::io::{ParseMode, UltraFastCsvParser};
+    /// use veloxx::types::{DataType, Value};
+    ///
+    /// let csv = "id,price\n1,9.5\n2,n/a\n3\n4,12\n";
+    /// let parser = UltraFastCsvParser::new()
+    ///     .column_type("price", DataType::F64)
+    ///     .parse_mode(ParseMode::Permissive);
+    /// let df = parser.read_from_reader(csv.as_bytes()).unwrap();
+    /// assert_eq!(df.row_count(), 2);
+    ///
+    /// let bad = parser.bad_rows().unwrap();
+    /// assert_eq!(bad.get_column("row").unwrap().get_value(0), Some(Value::I32(2)));
+    /// assert_eq!(
+    ///     bad.get_column("column").unwrap().get_value(0),
+    ///     Some(Value::String("price".to_string()))
+    /// );
+    /// assert_eq!(bad.get_column("column").unwrap().get_value(1), None);
+    /// ```
+    pub fn bad_rows(&self) -> Option<DataFrame> {
+        self.bad_rows.lock().unwrap().clone()
+    }
+
+    /// Infer the schema of a CSV file from its header and sampled rows
+    pub fn infer_schema(&self, path: &str) -> Result<CsvSchema, VeloxxError> {
         let file = File::open(path)
             .map_err(|e| VeloxxError::FileIO(format!("Failed to open file: {}", e)))?;
+        self.infer_schema_from_reader(BufReader::new(file))
+    }
+
+    /// Infer the schema of CSV data without reading past the sampled rows
+    pub fn infer_schema_from_reader<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<CsvSchema, VeloxxError> {
+        let limit = (self.sample_rows > 0).then_some(self.sample_rows);
+        let (headers, columns_data) = self.read_columns(reader, limit, &mut BadRows::default())?;
+        Ok(self.build_schema(&headers, &columns_data))
+    }
+
+    /// Parse CSV from file path
+    pub fn read_file(&self, path: &str) -> Result<DataFrame, VeloxxError> {
+        let file = File::open(path).map_err(|e| {
+            VeloxxError::FileIO(format!("Failed to open file: {}", e)).in_file(path)
+        })?;
         let reader = BufReader::new(file);
-        self.read_from_reader(reader)
+        self.read_from_reader(reader).map_err(|e| e.in_file(path))
     }
 
     /// Parse CSV from any BufRead source
     pub fn read_from_reader<R: BufRead>(&self, reader: R) -> Result<DataFrame, VeloxxError> {
+        self.read_from_reader_with_schema(reader)
+            .map(|(dataframe, _)| dataframe)
+    }
+
+    /// Parse CSV from any BufRead source, also returning the schema that was applied.
+    ///
+    /// Inferred columns whose later rows do not fit the sampled type are widened
+    /// (`I32` to `F64`, anything to `String`) and the widening is recorded in the
+    /// column's `ambiguities`.
+    pub fn read_from_reader_with_schema<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<(DataFrame, CsvSchema), VeloxxError> {
+        let mut bad_rows = BadRows::default();
+        let (headers, columns_data) = self.read_columns(reader, None, &mut bad_rows)?;
+        self.convert_columns(&headers, columns_data, bad_rows)
+    }
+
+    /// Types column-oriented strings as configured on this parser, after setting
+    /// malformed rows aside in permissive mode; shared with the fixed-width reader.
+    pub(crate) fn convert_columns(
+        &self,
+        headers: &[String],
+        mut columns_data: Vec<Vec<String>>,
+        mut bad_rows: BadRows,
+    ) -> Result<(DataFrame, CsvSchema), VeloxxError> {
+        let mut schema = self.build_schema(headers, &columns_data);
+        if self.parse_mode == ParseMode::Permissive {
+            set_aside_malformed(&schema, &mut columns_data, &mut bad_rows, self.delimiter);
+        }
+        *self.bad_rows.lock().unwrap() =
+            (self.parse_mode == ParseMode::Permissive).then(|| bad_rows.into_dataframe());
+
+        let mut dataframe_columns = HashMap::new();
+        for (column, raw_data) in schema.columns.iter_mut().zip(&columns_data) {
+            let series = convert_column(column, raw_data)?;
+            dataframe_columns.insert(column.name.clone(), series);
+        }
+
+        Ok((DataFrame::new(dataframe_columns)?, schema))
+    }
+
+    /// Reads the header and up to `limit` data rows into column-oriented strings.
+    ///
+    /// In permissive mode rows with the wrong field count go to `bad_rows`.
+    fn read_columns<R: BufRead>(
+        &self,
+        reader: R,
+        limit: Option<usize>,
+        bad_rows: &mut BadRows,
+    ) -> Result<(Vec<String>, Vec<Vec<String>>), VeloxxError> {
         let mut lines = reader.lines();
 
         // Read header
@@ -88,32 +264,53 @@ impl UltraFastCsvParser {
             .ok_or_else(|| VeloxxError::InvalidOperation("Empty CSV file".to_string()))?
             .map_err(|e| VeloxxError::FileIO(format!("Failed to read header: {}", e)))?;
 
-        let headers = self.parse_csv_line(&header_line)?;
+        let headers = self
+            .parse_csv_line(&header_line)
+            .map_err(|e| e.with_context(ErrorContext::new().line(1)))?;
         let num_columns = headers.len();
 
         // Initialize column data storage
         let mut columns_data: Vec<Vec<String>> = vec![Vec::new(); num_columns];
         let mut row_count = 0;
+        let mut record_count = 0;
 
         // Read data rows with SIMD acceleration
-        for line_result in lines {
-            let line = line_result
-                .map_err(|e| VeloxxError::FileIO(format!("Failed to read line: {}", e)))?;
+        for (index, line_result) in lines.enumerate() {
+            if limit.is_some_and(|limit| row_count >= limit) {
+                break;
+            }
+            // One-based, after the header line
+            let at_line = || ErrorContext::new().line(index + 2);
+            let line = line_result.map_err(|e| {
+                VeloxxError::FileIO(format!("Failed to read line: {}", e)).with_context(at_line())
+            })?;
 
             if line.trim().is_empty() {
                 continue;
             }
 
-            let fields = self.parse_csv_line(&line)?;
+            let fields = self
+                .parse_csv_line(&line)
+                .map_err(|e| e.with_context(at_line()))?;
+            record_count += 1;
 
             // Ensure we have the right number of fields
             if fields.len() != num_columns {
-                return Err(VeloxxError::InvalidOperation(format!(
+                let error = format!(
                     "Row {} has {} fields, expected {}",
-                    row_count + 1,
+                    record_count,
                     fields.len(),
                     num_columns
-                )));
+                );
+                match self.parse_mode {
+                    ParseMode::Strict => {
+                        return Err(VeloxxError::InvalidOperation(error).with_context(at_line()))
+                    }
+                    ParseMode::Permissive => {
+                        bad_rows.push(record_count, None, error, line);
+                        continue;
+                    }
+                }
             }
 
             // Store fields in column-oriented format
@@ -124,27 +321,36 @@ impl UltraFastCsvParser {
             row_count += 1;
         }
 
-        // Convert to typed Series with type inference
-        let mut dataframe_columns = std::collections::HashMap::new();
-
-        for (col_idx, column_name) in headers.iter().enumerate() {
-            let raw_data = &columns_data[col_idx];
-
-            if self.infer_types {
-                let series = self.infer_and_convert_column(column_name, raw_data)?;
-                dataframe_columns.insert(column_name.clone(), series);
-            } else {
-                // Convert to Option<String> format for Series::new_string
-                let string_data: Vec<Option<String>> = raw_data
-                    .iter()
-                    .map(|s| if s.is_empty() { None } else { Some(s.clone()) })
-                    .collect();
-                let series = Series::new_string(column_name, string_data);
-                dataframe_columns.insert(column_name.clone(), series);
-            }
-        }
+        Ok((headers, columns_data))
+    }
 
-        DataFrame::new(dataframe_columns)
+    /// Chooses each column's type from overrides, or from the sampled rows
+    fn build_schema(&self, headers: &[String], columns_data: &[Vec<String>]) -> CsvSchema {
+        let sample_len = |raw: &[String]| match self.sample_rows {
+            0 => raw.len(),
+            n => raw.len().min(n),
+        };
+        let columns = headers
+            .iter()
+            .zip(columns_data)
+            .map(|(name, raw)| {
+                let sample = &raw[..sample_len(raw)];
+                match self.type_overrides.get(name) {
+                    Some(data_type) => {
+                        let mut column = CsvColumnSchema::new(name, data_type.clone());
+                        column.overridden = true;
+                        if *data_type == DataType::DateTime {
+                            let formats = matching_datetime_formats(sample);
+                            column.datetime_format = formats.first().map(|f| f.to_string());
+                        }
+                        column
+                    }
+                    None if self.infer_types => infer_column(name, sample),
+                    None => CsvColumnSchema::new(name, DataType::String),
+                }
+            })
+            .collect();
+        CsvSchema { columns }
     }
 
     /// SIMD-accelerated CSV line parsing
@@ -180,106 +386,255 @@ impl UltraFastCsvParser {
 
         Ok(fields)
     }
+}
 
-    /// Intelligent type inference for optimal storage
-    fn infer_and_convert_column(
-        &self,
-        name: &str,
-        raw_data: &[String],
-    ) -> Result<Series, VeloxxError> {
-        if raw_data.is_empty() {
-            return Ok(Series::new_string(name, vec![]));
+impl CsvColumnSchema {
+    fn new(name: &str, data_type: DataType) -> Self {
+        Self {
+            name: name.to_string(),
+            data_type,
+            datetime_format: None,
+            ambiguities: Vec::new(),
+            overridden: false,
         }
+    }
+}
 
-        // Try to infer type from non-empty values
-        let non_empty_samples: Vec<_> = raw_data
-            .iter()
-            .filter(|s| !s.is_empty())
-            .take(100) // Sample first 100 non-empty values
-            .collect();
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "t" | "yes" | "y" | "1" => Some(true),
+        "false" | "f" | "no" | "n" | "0" => Some(false),
+        _ => None,
+    }
+}
 
-        if non_empty_samples.is_empty() {
-            return Ok(Series::new_string(name, vec![]));
-        }
+/// Returns the inference formats that parse every non-empty sampled value
+fn matching_datetime_formats(sample: &[String]) -> Vec<&'static str> {
+    let values: Vec<&String> = sample.iter().filter(|s| !s.is_empty()).collect();
+    if values.is_empty() {
+        return Vec::new();
+    }
+    INFERRED_DATETIME_FORMATS
+        .iter()
+        .copied()
+        .filter(|format| values.iter().all(|v| parse_datetime(v, format).is_some()))
+        .collect()
+}
 
-        // Try i32 first
-        let mut all_i32 = true;
-        let mut i32_values = Vec::with_capacity(raw_data.len());
+/// Intelligent type inference for optimal storage.
+///
+/// Types are tried from the narrowest: `I32`, `F64`, `Bool`, `DateTime`, then `String`.
+fn infer_column(name: &str, sample: &[String]) -> CsvColumnSchema {
+    let values: Vec<&str> = sample
+        .iter()
+        .map(String::as_str)
+        .filter(|s| !s.is_empty())
+        .collect();
+    let mut column = CsvColumnSchema::new(name, DataType::String);
+    if values.is_empty() {
+        column
+            .ambiguities
+            .push("no non-empty values in the sample; read as String".to_string());
+        return column;
+    }
 
-        for value_str in raw_data {
-            if value_str.is_empty() {
-                i32_values.push(None);
-            } else {
-                match value_str.parse::<i32>() {
-                    Ok(val) => i32_values.push(Some(val)),
-                    Err(_) => {
-                        all_i32 = false;
-                        break;
-                    }
-                }
-            }
+    if values.iter().all(|v| v.parse::<i32>().is_ok()) {
+        column.data_type = DataType::I32;
+        if values.iter().all(|v| *v == "0" || *v == "1") {
+            column
+                .ambiguities
+                .push("only 0/1 values; could also be Bool".to_string());
         }
+        return column;
+    }
+    if values.iter().all(|v| v.parse::<f64>().is_ok()) {
+        column.data_type = DataType::F64;
+        return column;
+    }
+    if values.iter().all(|v| parse_bool(v).is_some()) {
+        column.data_type = DataType::Bool;
+        return column;
+    }
 
-        if all_i32 {
-            return Ok(Series::new_i32(name, i32_values));
+    let formats = matching_datetime_formats(sample);
+    if let Some(&format) = formats.first() {
+        column.data_type = DataType::DateTime;
+        column.datetime_format = Some(format.to_string());
+        let conflicting: Vec<&str> = formats[1..]
+            .iter()
+            .copied()
+            .filter(|other| {
+                values
+                    .iter()
+                    .any(|v| parse_datetime(v, other) != parse_datetime(v, format))
+            })
+            .collect();
+        if !conflicting.is_empty() {
+            column.ambiguities.push(format!(
+                "dates match {format} and also {}; using {format}",
+                conflicting.join(", ")
+            ));
         }
+        return column;
+    }
 
-        // Try f64
-        let mut all_f64 = true;
-        let mut f64_values = Vec::with_capacity(raw_data.len());
+    let numeric = values.iter().filter(|v| v.parse::<f64>().is_ok()).count();
+    if numeric * 2 >= values.len() {
+        column.ambiguities.push(format!(
+            "{numeric} of {} sampled values are numeric; read as String",
+            values.len()
+        ));
+    }
+    column
+}
 
-        for value_str in raw_data {
-            if value_str.is_empty() {
-                f64_values.push(None);
-            } else {
-                match value_str.parse::<f64>() {
-                    Ok(val) => f64_values.push(Some(val)),
-                    Err(_) => {
-                        all_f64 = false;
-                        break;
-                    }
-                }
-            }
+/// Whether `value` parses as the column's type; empty values are nulls and always fit
+fn fits(column: &CsvColumnSchema, value: &str) -> bool {
+    value.is_empty()
+        || match column.data_type {
+            DataType::I32 => value.parse::<i32>().is_ok(),
+            DataType::F64 => value.parse::<f64>().is_ok(),
+            DataType::Bool => parse_bool(value).is_some(),
+            DataType::DateTime => column
+                .datetime_format
+                .as_deref()
+                .is_some_and(|format| parse_datetime(value, format).is_some()),
+            DataType::Binary => parse_hex(value).is_some(),
+            DataType::String => true,
         }
+}
 
-        if all_f64 {
-            return Ok(Series::new_f64(name, f64_values));
+/// Moves rows holding a value that does not parse as its overridden column type
+/// from `columns_data` to `bad_rows`.
+fn set_aside_malformed(
+    schema: &CsvSchema,
+    columns_data: &mut [Vec<String>],
+    bad_rows: &mut BadRows,
+    delimiter: u8,
+) {
+    let row_count = columns_data.first().map_or(0, Vec::len);
+    // Data row numbers in the source, skipping rows already set aside
+    let skipped = bad_rows.row_numbers();
+    let mut number = 0;
+    let numbers: Vec<usize> = (0..row_count)
+        .map(|_| {
+            number += 1;
+            while skipped.contains(&number) {
+                number += 1;
+            }
+            number
+        })
+        .collect();
+    let mut keep = vec![true; row_count];
+    for (column, raw_data) in schema.columns.iter().zip(columns_data.iter()) {
+        if !column.overridden {
+            continue;
         }
-
-        // Try boolean
-        let mut all_bool = true;
-        let mut bool_values = Vec::with_capacity(raw_data.len());
-
-        for value_str in raw_data {
-            if value_str.is_empty() {
-                bool_values.push(None);
-            } else {
-                let lower = value_str.to_lowercase();
-                match lower.as_str() {
-                    "true" | "t" | "yes" | "y" | "1" => bool_values.push(Some(true)),
-                    "false" | "f" | "no" | "n" | "0" => bool_values.push(Some(false)),
-                    _ => {
-                        all_bool = false;
-                        break;
-                    }
-                }
+        for (row, value) in raw_data.iter().enumerate() {
+            if keep[row] && !fits(column, value) {
+                keep[row] = false;
+                let record: Vec<&str> = columns_data.iter().map(|c| c[row].as_str()).collect();
+                bad_rows.push(
+                    numbers[row],
+                    Some(&column.name),
+                    format!("cannot parse '{}' as {:?}", value, column.data_type),
+                    record.join(&(delimiter as char).to_string()),
+                );
             }
         }
+    }
+    for raw_data in columns_data.iter_mut() {
+        let mut row = 0;
+        raw_data.retain(|_| {
+            row += 1;
+            keep[row - 1]
+        });
+    }
+}
 
-        if all_bool {
-            return Ok(Series::new_bool(name, bool_values));
+/// Parses every value of a column as `column.data_type`.
+///
+/// An overridden type fails on the first unparsable value; an inferred one is widened
+/// instead, updating `column`.
+fn convert_column(
+    column: &mut CsvColumnSchema,
+    raw_data: &[String],
+) -> Result<Series, VeloxxError> {
+    loop {
+        let name = column.name.as_str();
+        let converted = match column.data_type {
+            DataType::I32 => parse_all(raw_data, |v| v.parse::<i32>().ok())
+                .map(|values| Series::new_i32(name, values)),
+            DataType::F64 => parse_all(raw_data, |v| v.parse::<f64>().ok())
+                .map(|values| Series::new_f64(name, values)),
+            DataType::Bool => {
+                parse_all(raw_data, parse_bool).map(|values| Series::new_bool(name, values))
+            }
+            DataType::DateTime => match &column.datetime_format {
+                Some(format) => parse_all(raw_data, |v| parse_datetime(v, format))
+                    .map(|values| Series::new_datetime(name, values)),
+                None => Err(0),
+            },
+            DataType::Binary => {
+                parse_all(raw_data, parse_hex).map(|values| Series::new_binary(name, values))
+            }
+            DataType::String => {
+                let values = raw_data
+                    .iter()
+                    .map(|s| if s.is_empty() { None } else { Some(s.clone()) })
+                    .collect();
+                return Ok(Series::new_string(name, values));
+            }
+        };
+        let row = match converted {
+            Ok(series) => return Ok(series),
+            Err(row) => row,
+        };
+
+        let value = raw_data.get(row).map(String::as_str).unwrap_or_default();
+        if column.overridden {
+            return Err(VeloxxError::Parsing(format!(
+                "Column '{}' row {}: cannot parse '{}' as {:?}",
+                column.name,
+                row + 1,
+                value,
+                column.data_type
+            )));
         }
-
-        // Default to string
-        let string_values: Vec<Option<String>> = raw_data
-            .iter()
-            .map(|s| if s.is_empty() { None } else { Some(s.clone()) })
-            .collect();
-
-        Ok(Series::new_string(name, string_values))
+        let widened = match column.data_type {
+            DataType::I32 => DataType::F64,
+            _ => DataType::String,
+        };
+        column.ambiguities.push(format!(
+            "row {} value '{}' is not {:?}; widened to {:?}",
+            row + 1,
+            value,
+            column.data_type,
+            widened
+        ));
+        column.data_type = widened;
+        column.datetime_format = None;
     }
 }
 
+/// Parses all non-empty values, or returns the index of the first one that fails
+fn parse_all<T>(
+    raw_data: &[String],
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<Option<T>>, usize> {
+    raw_data
+        .iter()
+        .enumerate()
+        .map(|(row, s)| {
+            if s.is_empty() {
+                Ok(None)
+            } else {
+                parse(s).map(Some).ok_or(row)
+            }
+        })
+        .collect()
+}
+
 /// High-level convenience functions for CSV parsing
 impl UltraFastCsvParser {
     /// Quick CSV parsing with default settings
@@ -0,0 +1,253 @@
+//! Dependency-free parsing of date/time text into Unix timestamps (seconds, UTC).
+//!
+//! Formats use a strftime-like subset:
+//! - `%Y` four-digit year
+//! - `%m`, `%d`, `%H`, `%M` one or two digits
+//! - `%S` two digits, with an optional fraction that is truncated
+//! - `%z` `Z`, `±HH:MM` or `±HHMM`
+//! - `%%` a literal percent sign
+//!
+//! Any other character must match literally.
+
+/// Formats tried, in order, when inferring `DateTime` columns
+pub const INFERRED_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%z",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M:%S%z",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d",
+    "%Y/%m/%d %H:%M:%S",
+    "%Y/%m/%d",
+    "%d/%m/%Y %H:%M:%S",
+    "%m/%d/%Y %H:%M:%S",
+    "%d/%m/%Y",
+    "%m/%d/%Y",
+    "%d.%m.%Y",
+];
+
+/// Parses `text` with `format`, returning seconds since the Unix epoch.
+///
+/// Returns `None` if the text does not match the format exactly or names an
+/// invalid date or time.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::io::datetime::parse_datetime;
+///
+/// assert_eq!(parse_datetime("2023-01-01", "%Y-%m-%d"), Some(1672531200));
+/// assert_eq!(parse_datetime("2023-01-01T02:00:00+02:00", "%Y-%m-%dT%H:%M:%S%z"), Some(1672531200));
+/// assert_eq!(parse_datetime("2023-02-30", "%Y-%m-%d"), None);
+/// ```
+pub fn parse_datetime(text: &str, format: &str) -> Option<i64> {
+    let mut input = text.as_bytes();
+    let (mut year, mut month, mut day) = (1970i64, 1u32, 1u32);
+    let (mut hour, mut minute, mut second) = (0u32, 0u32, 0u32);
+    let mut offset = 0i64;
+
+    let mut spec = format.bytes();
+    while let Some(c) = spec.next() {
+        if c != b'%' {
+            input = input.strip_prefix(&[c])?;
+            continue;
+        }
+        match spec.next()? {
+            b'Y' => year = take_digits(&mut input, 4, 4)? as i64,
+            b'm' => month = take_digits(&mut input, 1, 2)?,
+            b'd' => day = take_digits(&mut input, 1, 2)?,
+            b'H' => hour = take_digits(&mut input, 1, 2)?,
+            b'M' => minute = take_digits(&mut input, 2, 2)?,
+            b'S' => {
+                second = take_digits(&mut input, 2, 2)?;
+                if let Some(rest) = input.strip_prefix(b".") {
+                    let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+                    if digits == 0 {
+                        return None;
+                    }
+                    input = &rest[digits..];
+                }
+            }
+            b'z' => offset = take_offset(&mut input)?,
+            b'%' => input = input.strip_prefix(b"%")?,
+            _ => return None,
+        }
+    }
+    if !input.is_empty()
+        || !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    Some(days * 86_400 + (hour * 3600 + minute * 60 + second) as i64 - offset)
+}
+
+fn take_digits(input: &mut &[u8], min: usize, max: usize) -> Option<u32> {
+    let len = input
+        .iter()
+        .take(max)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if len < min {
+        return None;
+    }
+    let value = input[..len]
+        .iter()
+        .fold(0u32, |acc, b| acc * 10 + (b - b'0') as u32);
+    *input = &input[len..];
+    Some(value)
+}
+
+/// Parses a UTC offset into seconds east of UTC.
+fn take_offset(input: &mut &[u8]) -> Option<i64> {
+    if let Some(rest) = input.strip_prefix(b"Z") {
+        *input = rest;
+        return Some(0);
+    }
+    let sign = match input.first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    *input = &input[1..];
+    let hours = take_digits(input, 2, 2)? as i64;
+    if let Some(rest) = input.strip_prefix(b":") {
+        *input = rest;
+    }
+    let minutes = take_digits(input, 2, 2)? as i64;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar (Howard Hinnant's algorithm).
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of a count of days since 1970-01-01; the inverse of
+/// [`days_from_civil`].
+#[cfg(feature = "odbc")]
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// How to read a column of date/time values
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::io::datetime::DateTimeFormat;
+///
+/// assert_eq!(DateTimeFormat::Rfc3339.parse("2023-01-01T00:00:00Z"), Some(1672531200));
+/// assert_eq!(DateTimeFormat::EpochMillis.parse("1672531200500"), Some(1672531200));
+/// assert_eq!(DateTimeFormat::from("%d.%m.%Y").parse("01.01.2023"), Some(1672531200));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateTimeFormat {
+    /// Any of [`INFERRED_DATETIME_FORMATS`], tried in order
+    Auto,
+    /// `2023-01-31`, `2023-01-31T12:00`, `2023-01-31T12:00:00` with an optional offset
+    Iso8601,
+    /// `2023-01-31T12:00:00Z` or with a numeric offset; a space may replace the `T`
+    Rfc3339,
+    /// Integer (or fractional) seconds since the Unix epoch
+    EpochSeconds,
+    /// Integer milliseconds since the Unix epoch
+    EpochMillis,
+    /// A strftime-style format, see the [module docs](self)
+    Custom(String),
+}
+
+const ISO8601_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%z",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d",
+];
+
+const RFC3339_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%z", "%Y-%m-%d %H:%M:%S%z"];
+
+impl DateTimeFormat {
+    /// Parses `text` into seconds since the Unix epoch.
+    pub fn parse(&self, text: &str) -> Option<i64> {
+        let first_match = |formats: &[&str]| formats.iter().find_map(|f| parse_datetime(text, f));
+        match self {
+            DateTimeFormat::Auto => first_match(INFERRED_DATETIME_FORMATS),
+            DateTimeFormat::Iso8601 => first_match(ISO8601_FORMATS),
+            DateTimeFormat::Rfc3339 => first_match(RFC3339_FORMATS),
+            DateTimeFormat::EpochSeconds => text
+                .parse::<i64>()
+                .ok()
+                .or_else(|| text.parse::<f64>().ok().and_then(|f| self.parse_number(f))),
+            DateTimeFormat::EpochMillis => text.parse::<i64>().ok().map(|ms| ms.div_euclid(1000)),
+            DateTimeFormat::Custom(format) => parse_datetime(text, format),
+        }
+    }
+
+    /// Converts a numeric value into seconds since the Unix epoch; only the epoch
+    /// formats accept numbers.
+    pub fn parse_number(&self, value: f64) -> Option<i64> {
+        if !value.is_finite() {
+            return None;
+        }
+        match self {
+            DateTimeFormat::EpochSeconds => Some(value.floor() as i64),
+            DateTimeFormat::EpochMillis => Some((value / 1000.0).floor() as i64),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for DateTimeFormat {
+    fn from(format: &str) -> Self {
+        DateTimeFormat::Custom(format.to_string())
+    }
+}
+
+impl From<String> for DateTimeFormat {
+    fn from(format: String) -> Self {
+        DateTimeFormat::Custom(format)
+    }
+}
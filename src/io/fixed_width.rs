@@ -0,0 +1,236 @@
+//! Fixed-width text files, where every field occupies the same character positions
+//! on each line, as in mainframe extracts and many regulatory feeds.
+//!
+//! Columns are laid out with [`FixedWidthReader::column`] and
+//! [`FixedWidthReader::column_at`], or loaded from a spec with one column per line:
+//!
+//! ```text
+//! # name    start  width  [type]
+//! account   1      8      I32
+//! holder    9      20
+//! balance   29     12     F64
+//! ```
+//!
+//! Starts in a spec are 1-based, as in most record layout documents. Fields are
+//! trimmed of padding, empty fields are null, and column types are inferred unless
+//! given, exactly as for CSV.
+
+use crate::dataframe::DataFrame;
+use crate::io::options::BadRows;
+use crate::io::UltraFastCsvParser;
+use crate::types::DataType;
+use crate::VeloxxError;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// Position of one field within a fixed-width line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedWidthColumn {
+    pub name: String,
+    /// 0-based character offset of the field
+    pub start: usize,
+    /// Number of characters in the field
+    pub width: usize,
+}
+
+/// Reader for fixed-width text files
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::io::fixed_width::FixedWidthReader;
+/// use veloxx::types::{DataType, Value};
+///
+/// let data = "00042Alice      0001250.50\n\
+///             00043Bob        0000099.00\n";
+/// let reader = FixedWidthReader::new()
+///     .column("account", 5)
+///     .column("holder", 11)
+///     .column("balance", 10)
+///     .column_type("account", DataType::I32);
+/// let df = reader.read_from_reader(data.as_bytes()).unwrap();
+/// assert_eq!(
+///     df.get_column("holder").unwrap().get_value(1),
+///     Some(Value::String("Bob".to_string()))
+/// );
+/// assert_eq!(
+///     df.get_column("balance").unwrap().get_value(0),
+///     Some(Value::F64(1250.5))
+/// );
+/// ```
+#[derive(Default)]
+pub struct FixedWidthReader {
+    columns: Vec<FixedWidthColumn>,
+    skip_rows: usize,
+    parser: UltraFastCsvParser,
+}
+
+impl FixedWidthReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field of `width` characters directly after the previous one.
+    pub fn column(self, name: &str, width: usize) -> Self {
+        let start = self.columns.last().map_or(0, |c| c.start + c.width);
+        self.column_at(name, start, width)
+    }
+
+    /// Adds a field of `width` characters at the 0-based character offset `start`.
+    pub fn column_at(mut self, name: &str, start: usize, width: usize) -> Self {
+        self.columns.push(FixedWidthColumn {
+            name: name.to_string(),
+            start,
+            width,
+        });
+        self
+    }
+
+    /// Force the type of a column instead of inferring it; values that do not parse
+    /// make the read fail.
+    pub fn column_type(mut self, column: &str, data_type: DataType) -> Self {
+        self.parser = self.parser.column_type(column, data_type);
+        self
+    }
+
+    /// Enable or disable automatic type inference (enabled by default)
+    pub fn infer_types(mut self, infer: bool) -> Self {
+        self.parser = self.parser.infer_types(infer);
+        self
+    }
+
+    /// Skip `rows` lines, such as a banner or header, before the first record
+    pub fn skip_rows(mut self, rows: usize) -> Self {
+        self.skip_rows = rows;
+        self
+    }
+
+    /// Builds a reader from a column spec, as described in the
+    /// [module documentation](self).
+    ///
+    /// Blank lines and lines starting with `#` are ignored. The type is one of
+    /// `I32`, `F64`, `Bool`, `String`, `DateTime` or `Binary`, in any case.
+    pub fn from_spec(spec: &str) -> Result<Self, VeloxxError> {
+        let mut reader = Self::new();
+        for (index, line) in spec.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| {
+                VeloxxError::Parsing(format!(
+                    "Fixed-width spec line {}: {} in '{}'",
+                    index + 1,
+                    reason,
+                    line
+                ))
+            };
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let (name, start, width, data_type) = match parts.as_slice() {
+                [name, start, width] => (name, start, width, None),
+                [name, start, width, data_type] => (name, start, width, Some(data_type)),
+                _ => return Err(invalid("expected 'name start width [type]'")),
+            };
+            let start: usize = start
+                .parse()
+                .ok()
+                .filter(|&start| start > 0)
+                .ok_or_else(|| invalid("start must be a positive integer"))?;
+            let width: usize = width
+                .parse()
+                .map_err(|_| invalid("width must be an integer"))?;
+            reader = reader.column_at(name, start - 1, width);
+            if let Some(data_type) = data_type {
+                let data_type = parse_data_type(data_type)
+                    .ok_or_else(|| invalid(&format!("unknown type '{}'", data_type)))?;
+                reader = reader.column_type(name, data_type);
+            }
+        }
+        Ok(reader)
+    }
+
+    /// Builds a reader from a column spec file; see [`FixedWidthReader::from_spec`].
+    pub fn from_spec_file(path: &str) -> Result<Self, VeloxxError> {
+        let spec = std::fs::read_to_string(path)
+            .map_err(|e| VeloxxError::FileIO(format!("Failed to read spec file: {}", e)))?;
+        Self::from_spec(&spec)
+    }
+
+    /// Returns the configured fields, in column order.
+    pub fn columns(&self) -> &[FixedWidthColumn] {
+        &self.columns
+    }
+
+    /// Parse a fixed-width file from a path
+    pub fn read_file(&self, path: &str) -> Result<DataFrame, VeloxxError> {
+        let file = File::open(path)
+            .map_err(|e| VeloxxError::FileIO(format!("Failed to open file: {}", e)))?;
+        self.read_from_reader(BufReader::new(file))
+    }
+
+    /// Parse fixed-width text from any BufRead source.
+    ///
+    /// Blank lines are skipped; a line that ends before a field leaves it null.
+    pub fn read_from_reader<R: BufRead>(&self, reader: R) -> Result<DataFrame, VeloxxError> {
+        self.validate()?;
+        let mut columns_data: Vec<Vec<String>> = vec![Vec::new(); self.columns.len()];
+        for line in reader.lines().skip(self.skip_rows) {
+            let line =
+                line.map_err(|e| VeloxxError::FileIO(format!("Failed to read line: {}", e)))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            // Offsets are in characters, so multi-byte text keeps its alignment
+            let chars: Vec<char> = line.chars().collect();
+            for (column, values) in self.columns.iter().zip(&mut columns_data) {
+                let end = (column.start + column.width).min(chars.len());
+                let field: String = chars
+                    .get(column.start..end)
+                    .unwrap_or_default()
+                    .iter()
+                    .collect();
+                values.push(field.trim().to_string());
+            }
+        }
+        let headers: Vec<String> = self.columns.iter().map(|c| c.name.clone()).collect();
+        self.parser
+            .convert_columns(&headers, columns_data, BadRows::default())
+            .map(|(dataframe, _)| dataframe)
+    }
+
+    /// Checks that there is at least one field, with unique names and positive widths
+    fn validate(&self) -> Result<(), VeloxxError> {
+        if self.columns.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "Fixed-width reader has no columns".to_string(),
+            ));
+        }
+        for (index, column) in self.columns.iter().enumerate() {
+            if column.width == 0 {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Fixed-width column '{}' has zero width",
+                    column.name
+                )));
+            }
+            if self.columns[..index].iter().any(|c| c.name == column.name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Fixed-width column '{}' is defined twice",
+                    column.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_data_type(name: &str) -> Option<DataType> {
+    match name.to_ascii_lowercase().as_str() {
+        "i32" => Some(DataType::I32),
+        "f64" => Some(DataType::F64),
+        "bool" => Some(DataType::Bool),
+        "string" => Some(DataType::String),
+        "datetime" => Some(DataType::DateTime),
+        "binary" => Some(DataType::Binary),
+        _ => None,
+    }
+}
@@ -0,0 +1,306 @@
+//! Reading datasets over HTTP(S) with an on-disk cache, enabled with the `http` feature.
+//!
+//! Downloads are stored under a cache directory together with the response's `ETag`
+//! and `Last-Modified` headers. With [`CachePolicy::Revalidate`] (the default) a
+//! cached copy is revalidated with a conditional request, so an unchanged dataset is
+//! answered with `304 Not Modified` instead of being downloaded again.
+//!
+//! The default cache directory is `$VELOXX_CACHE_DIR`, or `veloxx/http` inside the
+//! platform cache directory (`$XDG_CACHE_HOME`, `~/.cache` or `%LOCALAPPDATA%`).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use veloxx::io::http::{read_csv_url, CachePolicy, HttpCache};
+//!
+//! # fn main() -> Result<(), veloxx::VeloxxError> {
+//! let url = "https://example.com/data/iris.csv";
+//! let df = read_csv_url(url, CachePolicy::Revalidate)?;
+//!
+//! // Skip the network entirely for a day, using a project-local cache
+//! let cache = HttpCache::new(".veloxx-cache");
+//! let df = cache.read_csv(url, CachePolicy::MaxAge(Duration::from_secs(86_400)))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::VeloxxError;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// When a cached download may be used instead of the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CachePolicy {
+    /// Always download and leave the cache untouched
+    NoCache,
+    /// Use the cached copy if the server confirms it is unchanged
+    #[default]
+    Revalidate,
+    /// Use the cached copy without contacting the server while it is younger than
+    /// the given age, then revalidate
+    MaxAge(Duration),
+    /// Only use the cache; fail if the URL has not been downloaded before
+    Offline,
+}
+
+/// Cache entry headers, stored next to the downloaded body
+#[derive(Debug, Default)]
+struct CacheMeta {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// Unix seconds of the last download or successful revalidation
+    fetched: u64,
+}
+
+/// A downloaded body, either cached or a temporary file removed after reading
+enum Fetched {
+    Cached(PathBuf),
+    Temporary(PathBuf),
+}
+
+/// An on-disk cache of HTTP downloads
+#[derive(Debug, Clone)]
+pub struct HttpCache {
+    dir: PathBuf,
+}
+
+impl Default for HttpCache {
+    fn default() -> Self {
+        Self::new(default_cache_dir())
+    }
+}
+
+impl HttpCache {
+    /// Uses `dir` as the cache directory; it is created on first download.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the cached body for `url`, if it has been downloaded
+    pub fn cached_path(&self, url: &str) -> Option<PathBuf> {
+        let (data, _) = self.entry_paths(url);
+        data.is_file().then_some(data)
+    }
+
+    /// Removes every cached download.
+    pub fn clear(&self) -> Result<(), VeloxxError> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Downloads (or reuses) a CSV file and reads it like [`DataFrame::from_csv`].
+    pub fn read_csv(&self, url: &str, policy: CachePolicy) -> Result<DataFrame, VeloxxError> {
+        self.read_with(url, policy, DataFrame::from_csv)
+    }
+
+    /// Downloads (or reuses) a JSON file and reads it like [`DataFrame::from_json`].
+    pub fn read_json(&self, url: &str, policy: CachePolicy) -> Result<DataFrame, VeloxxError> {
+        self.read_with(url, policy, DataFrame::from_json)
+    }
+
+    /// Downloads (or reuses) a Parquet file; requires the `advanced_io` and `arrow`
+    /// features.
+    #[cfg(all(feature = "advanced_io", feature = "arrow"))]
+    pub fn read_parquet(&self, url: &str, policy: CachePolicy) -> Result<DataFrame, VeloxxError> {
+        self.read_with(url, policy, crate::io::arrow::read_parquet_to_dataframe)
+    }
+
+    fn read_with(
+        &self,
+        url: &str,
+        policy: CachePolicy,
+        read: impl FnOnce(&str) -> Result<DataFrame, VeloxxError>,
+    ) -> Result<DataFrame, VeloxxError> {
+        let fetched = self.fetch(url, policy)?;
+        let (Fetched::Cached(path) | Fetched::Temporary(path)) = &fetched;
+        let result = path
+            .to_str()
+            .ok_or_else(|| VeloxxError::FileIO(format!("Non UTF-8 path '{}'", path.display())))
+            .and_then(read);
+        if let Fetched::Temporary(path) = fetched {
+            let _ = fs::remove_file(path);
+        }
+        result
+    }
+
+    fn fetch(&self, url: &str, policy: CachePolicy) -> Result<Fetched, VeloxxError> {
+        let (data, meta_path) = self.entry_paths(url);
+        if policy == CachePolicy::NoCache {
+            let temporary =
+                self.dir
+                    .join(format!("{}.nocache-{}", cache_key(url), std::process::id()));
+            download(ureq::get(url), url, &temporary)?;
+            return Ok(Fetched::Temporary(temporary));
+        }
+
+        let cached = data.is_file().then(|| read_meta(&meta_path)).flatten();
+        let now = unix_now();
+        match (&cached, policy) {
+            (None, CachePolicy::Offline) => {
+                return Err(VeloxxError::FileIO(format!(
+                    "'{}' is not in the HTTP cache at '{}'",
+                    url,
+                    self.dir.display()
+                )))
+            }
+            (Some(_), CachePolicy::Offline) => return Ok(Fetched::Cached(data)),
+            (Some(meta), CachePolicy::MaxAge(max_age))
+                if now.saturating_sub(meta.fetched) < max_age.as_secs() =>
+            {
+                return Ok(Fetched::Cached(data))
+            }
+            _ => {}
+        }
+
+        let mut request = ureq::get(url);
+        if let Some(meta) = &cached {
+            if let Some(etag) = &meta.etag {
+                request = request.set("If-None-Match", etag);
+            }
+            if let Some(last_modified) = &meta.last_modified {
+                request = request.set("If-Modified-Since", last_modified);
+            }
+        }
+        let temporary = data.with_extension(format!("part-{}", std::process::id()));
+        match download(request, url, &temporary)? {
+            None => {
+                // 304 Not Modified: keep the body, restart the max-age clock
+                let meta = CacheMeta {
+                    fetched: now,
+                    ..cached.unwrap_or_default()
+                };
+                write_meta(&meta_path, &meta)?;
+            }
+            Some(mut meta) => {
+                meta.fetched = now;
+                fs::rename(&temporary, &data)?;
+                write_meta(&meta_path, &meta)?;
+            }
+        }
+        Ok(Fetched::Cached(data))
+    }
+
+    fn entry_paths(&self, url: &str) -> (PathBuf, PathBuf) {
+        let key = cache_key(url);
+        (
+            self.dir.join(format!("{}.data", key)),
+            self.dir.join(format!("{}.meta", key)),
+        )
+    }
+}
+
+/// Reads a CSV file from `url` through the default [`HttpCache`].
+pub fn read_csv_url(url: &str, policy: CachePolicy) -> Result<DataFrame, VeloxxError> {
+    HttpCache::default().read_csv(url, policy)
+}
+
+/// Reads a JSON file from `url` through the default [`HttpCache`].
+pub fn read_json_url(url: &str, policy: CachePolicy) -> Result<DataFrame, VeloxxError> {
+    HttpCache::default().read_json(url, policy)
+}
+
+/// Reads a Parquet file from `url` through the default [`HttpCache`].
+#[cfg(all(feature = "advanced_io", feature = "arrow"))]
+pub fn read_parquet_url(url: &str, policy: CachePolicy) -> Result<DataFrame, VeloxxError> {
+    HttpCache::default().read_parquet(url, policy)
+}
+
+/// Sends `request`, writing a `200` body to `target`. Returns `None` for
+/// `304 Not Modified` and the response's cache headers otherwise.
+fn download(
+    request: ureq::Request,
+    url: &str,
+    target: &Path,
+) -> Result<Option<CacheMeta>, VeloxxError> {
+    let response = request.call().map_err(|e| match e {
+        ureq::Error::Status(code, _) => {
+            VeloxxError::FileIO(format!("HTTP {} fetching '{}'", code, url))
+        }
+        ureq::Error::Transport(e) => {
+            VeloxxError::FileIO(format!("Failed to fetch '{}': {}", url, e))
+        }
+    })?;
+    if response.status() == 304 {
+        return Ok(None);
+    }
+    let meta = CacheMeta {
+        etag: response.header("ETag").map(str::to_string),
+        last_modified: response.header("Last-Modified").map(str::to_string),
+        fetched: 0,
+    };
+    if let Some(dir) = target.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = fs::File::create(target)?;
+    let copied = std::io::copy(&mut response.into_reader(), &mut file);
+    if let Err(e) = copied.and_then(|_| file.flush()) {
+        let _ = fs::remove_file(target);
+        return Err(VeloxxError::FileIO(format!(
+            "Failed to download '{}': {}",
+            url, e
+        )));
+    }
+    Ok(Some(meta))
+}
+
+fn read_meta(path: &Path) -> Option<CacheMeta> {
+    let text = fs::read_to_string(path).ok()?;
+    let mut meta = CacheMeta::default();
+    for line in text.lines() {
+        let (key, value) = line.split_once(": ")?;
+        match key {
+            "etag" => meta.etag = Some(value.to_string()),
+            "last-modified" => meta.last_modified = Some(value.to_string()),
+            "fetched" => meta.fetched = value.parse().ok()?,
+            _ => {}
+        }
+    }
+    Some(meta)
+}
+
+fn write_meta(path: &Path, meta: &CacheMeta) -> Result<(), VeloxxError> {
+    let mut text = format!("fetched: {}\n", meta.fetched);
+    if let Some(etag) = &meta.etag {
+        text.push_str(&format!("etag: {}\n", etag));
+    }
+    if let Some(last_modified) = &meta.last_modified {
+        text.push_str(&format!("last-modified: {}\n", last_modified));
+    }
+    Ok(fs::write(path, text)?)
+}
+
+/// Stable file name for `url` (64-bit FNV-1a)
+fn cache_key(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("VELOXX_CACHE_DIR") {
+        return PathBuf::from(dir);
+    }
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("veloxx").join("http")
+}
@@ -1,11 +1,14 @@
 use crate::dataframe::DataFrame;
+use crate::io::options::{BadRows, ParseMode};
 use crate::series::Series;
+use crate::types::DataType;
 use crate::VeloxxError;
 use memmap2::Mmap;
 use std::collections::HashMap;
 // ...existing code...
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::sync::Mutex;
 
 /// Ultra-fast SIMD-accelerated JSON parser for structured data
 /// Optimized for parsing arrays of JSON objects (common in data processing)
@@ -14,6 +17,8 @@ pub struct UltraFastJsonParser {
     chunk_size: usize,
     streaming_threshold: usize,
     parallel_processing: bool,
+    parse_mode: ParseMode,
+    bad_rows: Mutex<Option<DataFrame>>,
 }
 
 impl Default for UltraFastJsonParser {
@@ -23,6 +28,8 @@ impl Default for UltraFastJsonParser {
             chunk_size: 1024 * 1024,               // 1MB chunks
             streaming_threshold: 10 * 1024 * 1024, // 10MB threshold for streaming
             parallel_processing: true,
+            parse_mode: ParseMode::Strict,
+            bad_rows: Mutex::new(None),
         }
     }
 }
@@ -47,6 +54,27 @@ impl UltraFastJsonParser {
         self
     }
 
+    /// Choose how values that do not fit their column's inferred type are handled
+    /// (strict by default).
+    ///
+    /// A column takes the type of most of its sampled values; in
+    /// [`ParseMode::Permissive`] rows holding a value of another type, such as
+    /// text in a number column, are left out and kept for
+    /// [`UltraFastJsonParser::bad_rows`].
+    pub fn parse_mode(mut self, mode: ParseMode) -> Self {
+        self.parse_mode = mode;
+        self
+    }
+
+    /// Rows set aside by the last read in [`ParseMode::Permissive`], or `None` if
+    /// the last read was strict.
+    ///
+    /// The frame has the 1-based `row`, the failing `column`, the `error` and the
+    /// row's JSON `record`.
+    pub fn bad_rows(&self) -> Option<DataFrame> {
+        self.bad_rows.lock().unwrap().clone()
+    }
+
     /// Parse JSON file with automatic format detection and optimization
     pub fn read_file(&self, path: &str) -> Result<DataFrame, VeloxxError> {
         let file = File::open(path)
@@ -613,22 +641,53 @@ impl UltraFastJsonParser {
             }
         }
 
-        let keys: Vec<String> = all_keys.into_iter().collect();
-        let mut columns = std::collections::HashMap::new();
-
-        // Build columns with type inference
-        for key in keys {
-            let key_string = key.to_string();
-            let values: Vec<Option<JsonValue>> =
-                objects.iter().map(|obj| obj.get(&key).cloned()).collect();
-
-            let series = if self.infer_types {
-                self.infer_json_column_type(&key_string, &values)?
+        // Choose column types, then reject rows whose values do not fit them
+        let mut bad_rows = BadRows::default();
+        let mut keep = vec![true; objects.len()];
+        let mut column_types = Vec::with_capacity(all_keys.len());
+        for key in all_keys {
+            let values: Vec<Option<&JsonValue>> = objects.iter().map(|obj| obj.get(&key)).collect();
+            let data_type = if self.infer_types {
+                self.infer_json_column_type(&values)
             } else {
-                self.json_values_to_string_series(&key_string, &values)
+                DataType::String
             };
+            for (row, value) in values.iter().enumerate() {
+                let Some(value) = value.filter(|v| keep[row] && !json_fits(v, &data_type)) else {
+                    continue;
+                };
+                let error = format!(
+                    "Column '{}' row {}: cannot read {} as {:?}",
+                    key,
+                    row + 1,
+                    json_value_text(value),
+                    data_type
+                );
+                match self.parse_mode {
+                    ParseMode::Strict => return Err(VeloxxError::Parsing(error)),
+                    ParseMode::Permissive => {
+                        keep[row] = false;
+                        let record = json_value_text(&JsonValue::Object(objects[row].clone()));
+                        bad_rows.push(row + 1, Some(&key), error, record);
+                    }
+                }
+            }
+            column_types.push((key, data_type));
+        }
+        *self.bad_rows.lock().unwrap() =
+            (self.parse_mode == ParseMode::Permissive).then(|| bad_rows.into_dataframe());
 
-            columns.insert(key_string, series);
+        let objects: Vec<JsonObject> = objects
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(obj, keep)| keep.then_some(obj))
+            .collect();
+        let mut columns = std::collections::HashMap::new();
+        for (key, data_type) in column_types {
+            let values: Vec<Option<JsonValue>> =
+                objects.iter().map(|obj| obj.get(&key).cloned()).collect();
+            let series = self.json_values_to_series(&key, &data_type, &values);
+            columns.insert(key, series);
         }
 
         DataFrame::new(columns)
@@ -639,12 +698,9 @@ impl UltraFastJsonParser {
         self.objects_to_dataframe(vec![object])
     }
 
-    /// Intelligent type inference for JSON columns
-    fn infer_json_column_type(
-        &self,
-        name: &str,
-        values: &[Option<JsonValue>],
-    ) -> Result<Series, VeloxxError> {
+    /// Intelligent type inference for JSON columns: the type held by more than
+    /// 80% of the sampled non-null values, else `String`
+    fn infer_json_column_type(&self, values: &[Option<&JsonValue>]) -> DataType {
         let sample_size = std::cmp::min(1000, values.len());
         let mut int_count = 0;
         let mut float_count = 0;
@@ -666,40 +722,62 @@ impl UltraFastJsonParser {
         let total_non_null = sample_size - null_count;
 
         if total_non_null == 0 {
-            return Ok(Series::new_string(name, vec![None; values.len()]));
+            return DataType::String;
         }
 
         // Determine best type based on majority
         if int_count as f64 / total_non_null as f64 > 0.8 {
-            let series_values: Vec<Option<i32>> = values
-                .iter()
-                .map(|v| match v {
-                    Some(JsonValue::Integer(i)) => Some(*i as i32),
-                    _ => None,
-                })
-                .collect();
-            Ok(Series::new_i32(name, series_values))
+            DataType::I32
         } else if (int_count + float_count) as f64 / total_non_null as f64 > 0.8 {
-            let series_values: Vec<Option<f64>> = values
-                .iter()
-                .map(|v| match v {
-                    Some(JsonValue::Integer(i)) => Some(*i as f64),
-                    Some(JsonValue::Float(f)) => Some(*f),
-                    _ => None,
-                })
-                .collect();
-            Ok(Series::new_f64(name, series_values))
+            DataType::F64
         } else if bool_count as f64 / total_non_null as f64 > 0.8 {
-            let series_values: Vec<Option<bool>> = values
-                .iter()
-                .map(|v| match v {
-                    Some(JsonValue::Bool(b)) => Some(*b),
-                    _ => None,
-                })
-                .collect();
-            Ok(Series::new_bool(name, series_values))
+            DataType::Bool
         } else {
-            Ok(self.json_values_to_string_series(name, values))
+            DataType::String
+        }
+    }
+
+    /// Convert JSON values to a series of `data_type`; values that do not fit it
+    /// become nulls, so callers reject them first with [`json_fits`]
+    fn json_values_to_series(
+        &self,
+        name: &str,
+        data_type: &DataType,
+        values: &[Option<JsonValue>],
+    ) -> Series {
+        match data_type {
+            DataType::I32 => Series::new_i32(
+                name,
+                values
+                    .iter()
+                    .map(|v| match v {
+                        Some(JsonValue::Integer(i)) => i32::try_from(*i).ok(),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            DataType::F64 => Series::new_f64(
+                name,
+                values
+                    .iter()
+                    .map(|v| match v {
+                        Some(JsonValue::Integer(i)) => Some(*i as f64),
+                        Some(JsonValue::Float(f)) => Some(*f),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            DataType::Bool => Series::new_bool(
+                name,
+                values
+                    .iter()
+                    .map(|v| match v {
+                        Some(JsonValue::Bool(b)) => Some(*b),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            _ => self.json_values_to_string_series(name, values),
         }
     }
 
@@ -746,6 +824,41 @@ enum JsonValue {
 
 type JsonObject = HashMap<String, JsonValue>;
 
+/// Whether a JSON value can be stored in a column of `data_type`; nulls always fit
+fn json_fits(value: &JsonValue, data_type: &DataType) -> bool {
+    match (value, data_type) {
+        (JsonValue::Null, _) | (_, DataType::String) => true,
+        (JsonValue::Integer(i), DataType::I32) => i32::try_from(*i).is_ok(),
+        (JsonValue::Integer(_) | JsonValue::Float(_), DataType::F64) => true,
+        (JsonValue::Bool(_), DataType::Bool) => true,
+        _ => false,
+    }
+}
+
+/// Compact JSON text of a value, with object keys in sorted order
+fn json_value_text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => format!("{:?}", s),
+        JsonValue::Integer(i) => i.to_string(),
+        JsonValue::Float(f) => f.to_string(),
+        JsonValue::Bool(b) => b.to_string(),
+        JsonValue::Null => "null".to_string(),
+        JsonValue::Array(values) => {
+            let values: Vec<String> = values.iter().map(json_value_text).collect();
+            format!("[{}]", values.join(","))
+        }
+        JsonValue::Object(object) => {
+            let mut keys: Vec<&String> = object.keys().collect();
+            keys.sort();
+            let fields: Vec<String> = keys
+                .into_iter()
+                .map(|key| format!("{:?}:{}", key, json_value_text(&object[key])))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,33 @@
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
 pub mod arrow;
 pub mod csv;
+pub mod datetime;
+pub mod fixed_width;
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+pub mod http;
 pub mod json;
 pub mod mmap_csv;
+pub mod options;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod partitioned;
+#[cfg(all(feature = "proto", not(target_arch = "wasm32")))]
+pub mod proto;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod stream;
+pub mod xml;
 
 use crate::dataframe::DataFrame;
 use crate::VeloxxError;
 
 // Re-export the new ultra-fast parsers
-pub use csv::UltraFastCsvParser;
+pub use csv::{CsvColumnSchema, CsvSchema, UltraFastCsvParser};
+pub use datetime::DateTimeFormat;
+pub use fixed_width::FixedWidthReader;
 pub use json::UltraFastJsonParser;
 pub use mmap_csv::MemoryMappedCsvParser;
+pub use options::{CsvReadOptions, GlobReadOptions, JsonReadOptions, ParseMode};
+#[cfg(not(target_arch = "wasm32"))]
+pub use partitioned::PartitionFormat;
 
 #[derive(Default)]
 pub struct CsvReader;
@@ -25,7 +42,7 @@ impl CsvReader {
     pub fn read_file(&self, _path: &str) -> Result<DataFrame, VeloxxError> {
         #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
         {
-            arrow::read_csv_to_dataframe(_path)
+            arrow::read_csv_to_dataframe(_path).map_err(|e| e.in_file(_path))
         }
         #[cfg(any(target_arch = "wasm32", not(feature = "arrow")))]
         {
@@ -58,7 +75,7 @@ impl ParquetReader {
     pub fn read_file(&self, path: &str) -> Result<DataFrame, VeloxxError> {
         #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
         {
-            arrow::read_parquet_to_dataframe(path)
+            arrow::read_parquet_to_dataframe(path).map_err(|e| e.in_file(path))
         }
         #[cfg(any(target_arch = "wasm32", not(feature = "arrow")))]
         {
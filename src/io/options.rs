@@ -0,0 +1,363 @@
+//! Options for `DataFrame::from_csv_with_options` and `DataFrame::from_json_with_options`.
+
+use crate::dataframe::DataFrame;
+use crate::io::datetime::DateTimeFormat;
+use crate::series::Series;
+use crate::VeloxxError;
+
+/// Which columns are read as `DateTime`, shared by the CSV and JSON options
+#[derive(Debug, Clone)]
+struct DateColumns {
+    columns: Vec<(String, DateTimeFormat)>,
+    infer: bool,
+}
+
+impl Default for DateColumns {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            infer: true,
+        }
+    }
+}
+
+impl DateColumns {
+    fn add<I, S>(&mut self, columns: I, format: DateTimeFormat)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for column in columns {
+            self.columns.push((column.into(), format.clone()));
+        }
+    }
+
+    /// Converts the requested columns, then any remaining `String` column whose
+    /// values are all ISO-8601 dates when inference is enabled.
+    fn apply(&self, mut df: DataFrame) -> Result<DataFrame, VeloxxError> {
+        for (name, format) in &self.columns {
+            let series = df
+                .columns
+                .get(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))?;
+            let converted = to_datetime(series, format)?;
+            df.columns.insert(name.clone(), converted);
+        }
+        if self.infer {
+            let candidates: Vec<String> = df
+                .columns
+                .iter()
+                .filter(|(name, series)| {
+                    matches!(series, Series::String(..))
+                        && !self.columns.iter().any(|(column, _)| column == *name)
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            for name in candidates {
+                let series = &df.columns[&name];
+                if series.count() > 0 {
+                    if let Ok(converted) = to_datetime(series, &DateTimeFormat::Iso8601) {
+                        df.columns.insert(name, converted);
+                    }
+                }
+            }
+        }
+        Ok(df)
+    }
+}
+
+/// Converts a `String`, numeric or `DateTime` series into a `DateTime` series,
+/// failing on the first value the format does not accept.
+fn to_datetime(series: &Series, format: &DateTimeFormat) -> Result<Series, VeloxxError> {
+    let fail = |value: String| {
+        VeloxxError::Parsing(format!(
+            "Column '{}': cannot parse '{}' as a date with {:?}",
+            series.name(),
+            value,
+            format
+        ))
+    };
+    let values: Vec<i64> = match series {
+        Series::String(_, values, bitmap) => values
+            .iter()
+            .zip(bitmap)
+            .map(|(v, &valid)| match valid {
+                true => format.parse(v).ok_or_else(|| fail(v.clone())),
+                false => Ok(0),
+            })
+            .collect::<Result<_, _>>()?,
+        // Integer columns may also hold compact dates such as `20230131`.
+        Series::I32(_, values, bitmap) => values
+            .iter()
+            .zip(bitmap)
+            .map(|(&v, &valid)| match valid {
+                true => format
+                    .parse_number(v as f64)
+                    .or_else(|| format.parse(&v.to_string()))
+                    .ok_or_else(|| fail(v.to_string())),
+                false => Ok(0),
+            })
+            .collect::<Result<_, _>>()?,
+        Series::F64(_, values, bitmap) => values
+            .iter()
+            .zip(bitmap)
+            .map(|(&v, &valid)| match valid {
+                true => format.parse_number(v).ok_or_else(|| fail(v.to_string())),
+                false => Ok(0),
+            })
+            .collect::<Result<_, _>>()?,
+        Series::DateTime(_, values, bitmap) => match format {
+            DateTimeFormat::EpochMillis => values
+                .iter()
+                .zip(bitmap)
+                .map(|(&v, &valid)| if valid { v.div_euclid(1000) } else { 0 })
+                .collect(),
+            _ => values.clone(),
+        },
+        Series::Bool(..) | Series::Binary(..) => {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "Column '{}': cannot read {:?} values as dates",
+                series.name(),
+                series.data_type()
+            )))
+        }
+    };
+    let bitmap = match series {
+        Series::I32(_, _, b)
+        | Series::F64(_, _, b)
+        | Series::Bool(_, _, b)
+        | Series::String(_, _, b)
+        | Series::DateTime(_, _, b)
+        | Series::Binary(_, _, b) => b.clone(),
+    };
+    Ok(Series::DateTime(series.name().to_string(), values, bitmap))
+}
+
+/// Options for [`DataFrame::from_csv_with_options`]
+///
+/// By default, text columns whose values are all ISO-8601 dates are read as
+/// `DateTime` (Unix seconds).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use veloxx::dataframe::DataFrame;
+/// use veloxx::io::{CsvReadOptions, DateTimeFormat};
+///
+/// let options = CsvReadOptions::new()
+///     .parse_dates(["ts"], "%Y-%m-%d %H:%M:%S")
+///     .parse_dates(["created_ms"], DateTimeFormat::EpochMillis);
+/// let df = DataFrame::from_csv_with_options("events.csv", &options).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CsvReadOptions {
+    dates: DateColumns,
+}
+
+impl CsvReadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `columns` as `DateTime` using `format`; a value that does not parse fails the read.
+    pub fn parse_dates<I, S>(mut self, columns: I, format: impl Into<DateTimeFormat>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.dates.add(columns, format.into());
+        self
+    }
+
+    /// Enables or disables detection of ISO-8601 date columns (enabled by default).
+    pub fn infer_dates(mut self, infer: bool) -> Self {
+        self.dates.infer = infer;
+        self
+    }
+
+    pub(crate) fn apply(&self, df: DataFrame) -> Result<DataFrame, VeloxxError> {
+        self.dates.apply(df)
+    }
+}
+
+/// Options for [`DataFrame::from_json_with_options`]
+///
+/// Date handling matches [`CsvReadOptions`]; JSON numbers can be read with the
+/// epoch formats.
+///
+/// Nested objects are flattened into one column per leaf, named by joining
+/// the keys on the way with the separator (`.` by default), so
+/// `{"user": {"id": 1}}` gives a `user.id` column. Objects nested deeper than
+/// the maximum depth, if one is set, are kept as JSON text instead. Arrays of
+/// scalars become `String` cells with their elements joined by the list
+/// delimiter (`,` by default), ready for [`DataFrame::explode`]; arrays holding
+/// objects or arrays are kept as JSON text. Columns are the union of the keys
+/// of every row, with nulls where a row lacks a key.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::dataframe::DataFrame;
+/// use veloxx::io::JsonReadOptions;
+/// use veloxx::types::Value;
+///
+/// let json = r#"[
+///     {"id": 1, "user": {"name": "ann", "address": {"city": "Oslo"}}, "tags": ["a", "b"]},
+///     {"id": 2, "user": {"name": "bob"}}
+/// ]"#;
+/// let options = JsonReadOptions::new().separator("_").max_depth(1);
+/// let df = DataFrame::from_json_str_with_options(json, &options).unwrap();
+///
+/// let text = |column: &str, row: usize| df.get_column(column).unwrap().get_value(row);
+/// assert_eq!(text("user_name", 1), Some(Value::String("bob".to_string())));
+/// assert_eq!(
+///     text("user_address", 0),
+///     Some(Value::String(r#"{"city":"Oslo"}"#.to_string()))
+/// );
+/// assert_eq!(text("user_address", 1), None);
+/// assert_eq!(text("tags", 0), Some(Value::String("a,b".to_string())));
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonReadOptions {
+    dates: DateColumns,
+    pub(crate) separator: String,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) list_delimiter: String,
+}
+
+impl Default for JsonReadOptions {
+    fn default() -> Self {
+        Self {
+            dates: DateColumns::default(),
+            separator: ".".to_string(),
+            max_depth: None,
+            list_delimiter: ",".to_string(),
+        }
+    }
+}
+
+impl JsonReadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `columns` as `DateTime` using `format`; a value that does not parse fails the read.
+    pub fn parse_dates<I, S>(mut self, columns: I, format: impl Into<DateTimeFormat>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.dates.add(columns, format.into());
+        self
+    }
+
+    /// Enables or disables detection of ISO-8601 date columns (enabled by default).
+    pub fn infer_dates(mut self, infer: bool) -> Self {
+        self.dates.infer = infer;
+        self
+    }
+
+    /// Sets the text joining the keys of nested objects into column names.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Flattens at most `depth` levels of nested objects, keeping deeper ones
+    /// as JSON text; `0` keeps every nested object as text.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Sets the text joining the elements of arrays of scalars.
+    pub fn list_delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.list_delimiter = delimiter.into();
+        self
+    }
+
+    pub(crate) fn apply(&self, df: DataFrame) -> Result<DataFrame, VeloxxError> {
+        self.dates.apply(df)
+    }
+}
+
+/// Options for [`DataFrame::from_csv_glob_with_options`] and
+/// [`DataFrame::from_parquet_glob_with_options`]
+#[derive(Debug, Clone, Default)]
+pub struct GlobReadOptions {
+    pub(crate) source_column: Option<String>,
+}
+
+impl GlobReadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `String` column named `name` holding the path of the file each row came from.
+    pub fn source_column(mut self, name: impl Into<String>) -> Self {
+        self.source_column = Some(name.into());
+        self
+    }
+}
+
+/// How a reader handles values that do not fit their column's type
+///
+/// Used by [`UltraFastCsvParser`](crate::io::UltraFastCsvParser) and
+/// [`UltraFastJsonParser`](crate::io::UltraFastJsonParser).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Fail the read on the first malformed row, naming its row and column
+    #[default]
+    Strict,
+    /// Leave malformed rows out of the result and keep them for the reader's
+    /// `bad_rows()`
+    Permissive,
+}
+
+/// Rows rejected by a permissive read, in the shape returned by `bad_rows()`
+#[derive(Debug, Default)]
+pub(crate) struct BadRows {
+    rows: Vec<Option<i32>>,
+    columns: Vec<Option<String>>,
+    errors: Vec<Option<String>>,
+    records: Vec<Option<String>>,
+}
+
+impl BadRows {
+    /// Records the 1-based data row `row`, the column that failed (if the row
+    /// failed as a whole, `None`), the reason, and the row's source text.
+    pub(crate) fn push(&mut self, row: usize, column: Option<&str>, error: String, record: String) {
+        self.rows.push(Some(row as i32));
+        self.columns.push(column.map(str::to_string));
+        self.errors.push(Some(error));
+        self.records.push(Some(record));
+    }
+
+    /// The data row numbers recorded so far
+    pub(crate) fn row_numbers(&self) -> std::collections::HashSet<usize> {
+        self.rows
+            .iter()
+            .flatten()
+            .map(|&row| row as usize)
+            .collect()
+    }
+
+    /// A frame with `row`, `column`, `error` and `record` columns, one row per
+    /// rejected row in source order.
+    pub(crate) fn into_dataframe(self) -> DataFrame {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        order.sort_by_key(|&i| self.rows[i]);
+        let pick = |values: &[Option<String>]| order.iter().map(|&i| values[i].clone()).collect();
+        let columns = [
+            Series::new_i32("row", order.iter().map(|&i| self.rows[i]).collect()),
+            Series::new_string("column", pick(&self.columns)),
+            Series::new_string("error", pick(&self.errors)),
+            Series::new_string("record", pick(&self.records)),
+        ];
+        let columns = columns
+            .into_iter()
+            .map(|series| (series.name().to_string(), series))
+            .collect();
+        DataFrame::new(columns).expect("bad row columns have equal lengths")
+    }
+}
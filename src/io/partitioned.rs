@@ -0,0 +1,445 @@
+//! Hive-style partitioned datasets: `col=value/` directory trees of CSV or Parquet files.
+//!
+//! Partition values are percent-encoded in directory names and nulls are written as
+//! [`NULL_PARTITION`]. When reading, the partition columns are rebuilt from the paths
+//! and typed as `I32`, `F64`, `Bool` or `String`, whichever fits every value.
+
+use crate::dataframe::DataFrame;
+use crate::io::GlobReadOptions;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Directory value used for null partition keys, as in Hive and Spark
+pub const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// File format of the leaf files in a partitioned dataset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionFormat {
+    Csv,
+    /// Requires the `advanced_io` and `arrow` features
+    Parquet,
+}
+
+impl PartitionFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            PartitionFormat::Csv => "csv",
+            PartitionFormat::Parquet => "parquet",
+        }
+    }
+
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "csv" => Some(PartitionFormat::Csv),
+            "parquet" => Some(PartitionFormat::Parquet),
+            _ => None,
+        }
+    }
+}
+
+/// Writes one file per distinct combination of `partition_cols` values under `path`.
+pub fn write_partitioned(
+    df: &DataFrame,
+    path: &str,
+    partition_cols: &[&str],
+    format: PartitionFormat,
+) -> Result<(), VeloxxError> {
+    if partition_cols.is_empty() {
+        return Err(VeloxxError::InvalidOperation(
+            "At least one partition column is required".to_string(),
+        ));
+    }
+    let mut keys = Vec::with_capacity(partition_cols.len());
+    for name in partition_cols {
+        keys.push(
+            df.get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))?,
+        );
+    }
+    let data_columns: Vec<&String> = df
+        .column_names()
+        .into_iter()
+        .filter(|name| !partition_cols.contains(&name.as_str()))
+        .collect();
+    if data_columns.is_empty() {
+        return Err(VeloxxError::InvalidOperation(
+            "Cannot partition by every column of the DataFrame".to_string(),
+        ));
+    }
+
+    // Row indices per partition directory, in order of first appearance
+    let mut partitions: Vec<(PathBuf, Vec<usize>)> = Vec::new();
+    let mut lookup: HashMap<PathBuf, usize> = HashMap::new();
+    for row in 0..df.row_count() {
+        let mut dir = PathBuf::from(path);
+        for (name, series) in partition_cols.iter().zip(&keys) {
+            dir.push(format!(
+                "{}={}",
+                escape(name),
+                partition_value(series.get_value(row))
+            ));
+        }
+        let slot = *lookup.entry(dir.clone()).or_insert_with(|| {
+            partitions.push((dir, Vec::new()));
+            partitions.len() - 1
+        });
+        partitions[slot].1.push(row);
+    }
+
+    for (dir, rows) in partitions {
+        let mut columns = HashMap::with_capacity(data_columns.len());
+        for name in &data_columns {
+            columns.insert((*name).clone(), df.columns[*name].filter(&rows)?);
+        }
+        let part = DataFrame::new(columns)?;
+        std::fs::create_dir_all(&dir)?;
+        let file = dir.join(format!("part-00000.{}", format.extension()));
+        write_file(&part, &file, format)?;
+    }
+    Ok(())
+}
+
+/// Reads every CSV/Parquet file matched by `pattern` (a glob, or a directory that is
+/// searched recursively) and adds the `key=value` path segments as columns.
+pub fn read_partitioned(pattern: &str) -> Result<DataFrame, VeloxxError> {
+    let (base, pattern) = if Path::new(pattern).is_dir() {
+        (
+            PathBuf::from(pattern),
+            format!("{}/**/*", pattern.trim_end_matches('/')),
+        )
+    } else {
+        (literal_prefix(pattern), pattern.to_string())
+    };
+
+    let paths = glob::glob(&pattern)
+        .map_err(|e| VeloxxError::InvalidOperation(format!("Invalid glob pattern: {}", e)))?;
+    let mut files = Vec::new();
+    for entry in paths {
+        let file = entry.map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let hidden = file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_none_or(|name| name.starts_with('_') || name.starts_with('.'));
+        if file.is_file() && !hidden {
+            if let Some(format) = PartitionFormat::from_path(&file) {
+                files.push((file, format));
+            }
+        }
+    }
+    if files.is_empty() {
+        return Err(VeloxxError::FileIO(format!(
+            "No CSV or Parquet files match '{}'",
+            pattern
+        )));
+    }
+
+    let mut frames = Vec::with_capacity(files.len());
+    let mut partition_values: Vec<(String, Vec<Option<String>>)> = Vec::new();
+    for (file, format) in &files {
+        let df = read_file(file, *format)?;
+        let segments = partition_segments(file, &base)?;
+        if frames.is_empty() {
+            partition_values = segments
+                .iter()
+                .map(|(key, _)| (key.clone(), Vec::new()))
+                .collect();
+        } else if segments.len() != partition_values.len()
+            || segments
+                .iter()
+                .zip(&partition_values)
+                .any(|((key, _), (expected, _))| key != expected)
+        {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "'{}' does not have the same partition columns as the other files",
+                file.display()
+            )));
+        }
+        for ((_, value), (_, values)) in segments.into_iter().zip(&mut partition_values) {
+            values.extend(std::iter::repeat_n(value, df.row_count()));
+        }
+        frames.push(df);
+    }
+
+    let mut combined = concat_frames(frames)?;
+    for (key, values) in partition_values {
+        if combined.columns.contains_key(&key) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Partition column '{}' is also stored in the data files",
+                key
+            )));
+        }
+        combined
+            .columns
+            .insert(key.clone(), infer_series(&key, values));
+    }
+    Ok(combined)
+}
+
+/// Reads the `format` files matched by the glob `pattern` in parallel and stacks them
+/// in path order, as described on [`DataFrame::from_csv_glob_with_options`].
+pub fn read_glob(
+    pattern: &str,
+    format: PartitionFormat,
+    options: &GlobReadOptions,
+) -> Result<DataFrame, VeloxxError> {
+    let paths = glob::glob(pattern)
+        .map_err(|e| VeloxxError::InvalidOperation(format!("Invalid glob pattern: {}", e)))?;
+    let mut files = Vec::new();
+    for entry in paths {
+        let file = entry.map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        if file.is_file() {
+            files.push(file);
+        }
+    }
+    if files.is_empty() {
+        return Err(VeloxxError::FileIO(format!("No files match '{}'", pattern)));
+    }
+
+    let frames = files
+        .par_iter()
+        .map(|file| read_file(file, format))
+        .collect::<Result<Vec<_>, _>>()?;
+    check_same_schema(&files, &frames)?;
+    let sources: Vec<Option<String>> = files
+        .iter()
+        .zip(&frames)
+        .flat_map(|(file, frame)| {
+            std::iter::repeat_n(Some(file.display().to_string()), frame.row_count())
+        })
+        .collect();
+
+    let mut combined = concat_frames(frames)?;
+    if let Some(name) = &options.source_column {
+        if combined.columns.contains_key(name) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Source column '{}' is also stored in the data files",
+                name
+            )));
+        }
+        combined
+            .columns
+            .insert(name.clone(), Series::new_string(name, sources));
+    }
+    Ok(combined)
+}
+
+/// Fails unless every frame has the columns of the first, with the same types or
+/// with `I32` and `F64` mixed.
+fn check_same_schema(files: &[PathBuf], frames: &[DataFrame]) -> Result<(), VeloxxError> {
+    let numeric = |t: &DataType| matches!(t, DataType::I32 | DataType::F64);
+    let mut expected: Vec<&String> = frames[0].column_names();
+    expected.sort();
+    for (file, frame) in files.iter().zip(frames).skip(1) {
+        let mut names = frame.column_names();
+        names.sort();
+        if names != expected {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "'{}' has columns {:?}, but '{}' has {:?}",
+                file.display(),
+                names,
+                files[0].display(),
+                expected
+            )));
+        }
+        for name in &expected {
+            let first = frames[0].columns[*name].data_type();
+            let other = frame.columns[*name].data_type();
+            if first != other && !(numeric(&first) && numeric(&other)) {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Column '{}' is {:?} in '{}' but {:?} in '{}'",
+                    name,
+                    other,
+                    file.display(),
+                    first,
+                    files[0].display()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_file(df: &DataFrame, file: &Path, format: PartitionFormat) -> Result<(), VeloxxError> {
+    match format {
+        PartitionFormat::Csv => Ok(std::fs::write(file, df.to_csv_string())?),
+        #[cfg(all(feature = "advanced_io", feature = "arrow"))]
+        PartitionFormat::Parquet => {
+            let batch = crate::io::arrow::dataframe_to_record_batch(df)?;
+            let mut writer = parquet::arrow::ArrowWriter::try_new(
+                std::fs::File::create(file)?,
+                batch.schema(),
+                None,
+            )?;
+            writer.write(&batch)?;
+            writer.close()?;
+            Ok(())
+        }
+        #[cfg(not(all(feature = "advanced_io", feature = "arrow")))]
+        PartitionFormat::Parquet => Err(parquet_unsupported()),
+    }
+}
+
+fn read_file(file: &Path, format: PartitionFormat) -> Result<DataFrame, VeloxxError> {
+    let path = file
+        .to_str()
+        .ok_or_else(|| VeloxxError::FileIO(format!("Non UTF-8 path '{}'", file.display())))?;
+    match format {
+        PartitionFormat::Csv => DataFrame::from_csv(path),
+        #[cfg(all(feature = "advanced_io", feature = "arrow"))]
+        PartitionFormat::Parquet => crate::io::arrow::read_parquet_to_dataframe(path),
+        #[cfg(not(all(feature = "advanced_io", feature = "arrow")))]
+        PartitionFormat::Parquet => Err(parquet_unsupported()),
+    }
+}
+
+#[cfg(not(all(feature = "advanced_io", feature = "arrow")))]
+fn parquet_unsupported() -> VeloxxError {
+    VeloxxError::Unsupported(
+        "Parquet partitions require the advanced_io and arrow features".to_string(),
+    )
+}
+
+fn partition_value(value: Option<Value>) -> String {
+    match value {
+        Some(Value::I32(v)) => v.to_string(),
+        Some(Value::F64(v)) => v.to_string(),
+        Some(Value::Bool(v)) => v.to_string(),
+        Some(Value::String(v)) if !v.is_empty() => escape(&v),
+        Some(Value::DateTime(v)) => v.to_string(),
+        _ => NULL_PARTITION.to_string(),
+    }
+}
+
+/// Percent-encodes the characters Hive escapes in partition directory names.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_control() || "\"#%'*/:=?\\{[]^".contains(c) {
+            out.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+pub(crate) fn unescape(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let decoded = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match decoded {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// The directories of `pattern` before the first component with a glob metacharacter
+fn literal_prefix(pattern: &str) -> PathBuf {
+    Path::new(pattern)
+        .components()
+        .take_while(|c| !c.as_os_str().to_string_lossy().contains(['*', '?', '[']))
+        .collect()
+}
+
+/// `key=value` directories between `base` and the file, with null values as `None`
+fn partition_segments(
+    file: &Path,
+    base: &Path,
+) -> Result<Vec<(String, Option<String>)>, VeloxxError> {
+    let dir = file.parent().unwrap_or(Path::new(""));
+    let relative = dir.strip_prefix(base).unwrap_or(dir);
+    let mut segments = Vec::new();
+    for component in relative.components() {
+        let component = component.as_os_str().to_string_lossy();
+        if let Some((key, value)) = component.split_once('=') {
+            let value = (value != NULL_PARTITION).then(|| unescape(value));
+            segments.push((unescape(key), value));
+        }
+    }
+    Ok(segments)
+}
+
+/// Builds a partition column with the narrowest type that holds every value.
+fn infer_series(name: &str, values: Vec<Option<String>>) -> Series {
+    let present = || values.iter().flatten();
+    if present().all(|v| v.parse::<i32>().is_ok()) {
+        Series::new_i32(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().map(|s| s.parse().unwrap()))
+                .collect(),
+        )
+    } else if present().all(|v| v.parse::<f64>().is_ok()) {
+        Series::new_f64(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().map(|s| s.parse().unwrap()))
+                .collect(),
+        )
+    } else if present().all(|v| v.parse::<bool>().is_ok()) {
+        Series::new_bool(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_ref().map(|s| s.parse().unwrap()))
+                .collect(),
+        )
+    } else {
+        Series::new_string(name, values)
+    }
+}
+
+/// Stacks the frames read from each file, widening `I32` columns to `F64` where the
+/// files disagree.
+pub(crate) fn concat_frames(frames: Vec<DataFrame>) -> Result<DataFrame, VeloxxError> {
+    let mut names: Vec<String> = frames[0].columns.keys().cloned().collect();
+    names.sort();
+    let mut columns = HashMap::with_capacity(names.len());
+    for name in names {
+        let mut parts = Vec::with_capacity(frames.len());
+        for frame in &frames {
+            if frame.column_count() != frames[0].column_count() {
+                return Err(VeloxxError::InvalidOperation(
+                    "Partition files have different columns".to_string(),
+                ));
+            }
+            parts.push(
+                frame
+                    .get_column(&name)
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))?
+                    .clone(),
+            );
+        }
+        let types: Vec<DataType> = parts.iter().map(|s| s.data_type()).collect();
+        if types.contains(&DataType::F64) && types.contains(&DataType::I32) {
+            parts = parts
+                .into_iter()
+                .map(|s| match s.data_type() {
+                    DataType::I32 => s.cast(DataType::F64),
+                    _ => Ok(s),
+                })
+                .collect::<Result<_, _>>()?;
+        }
+        columns.insert(name, Series::concat(parts)?);
+    }
+    DataFrame::new(columns)
+}
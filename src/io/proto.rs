@@ -0,0 +1,840 @@
+//! Streams of length-prefixed binary messages, such as service logs stored as
+//! Protocol Buffers.
+//!
+//! [`RecordDecoder`] is the extension point: it names the columns and decodes one
+//! message into a row. [`ProtoDescriptor`] implements it for Protocol Buffers on top
+//! of `prost-reflect`, configured from field numbers, a `.proto` message definition
+//! or a compiled descriptor set. Other formats, such as FlatBuffers with their
+//! generated accessors, can implement it and reuse [`read_messages`] for framing
+//! and column building.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use veloxx::io::proto::{read_messages, LengthPrefix, ProtoDescriptor};
+//! use veloxx::types::Value;
+//!
+//! let schema = r#"
+//!     syntax = "proto3";
+//!     message Request {
+//!         string path = 1;
+//!         int32 status = 2;
+//!         Timing timing = 3;
+//!     }
+//!     message Timing { double millis = 1; }
+//! "#;
+//! let descriptor = ProtoDescriptor::from_proto(schema, "Request")
+//!     .unwrap()
+//!     .select(&["status", "timing.millis"])
+//!     .unwrap();
+//!
+//! // One varint-delimited message: status = 404, timing { millis = 1.5 }
+//! let mut stream = vec![14, 0x10, 0x94, 0x03, 0x1a, 9, 0x09];
+//! stream.extend(1.5f64.to_le_bytes());
+//! let df = read_messages(stream.as_slice(), LengthPrefix::Varint, &descriptor).unwrap();
+//! assert_eq!(df.get_column("status").unwrap().get_value(0), Some(Value::I32(404)));
+//! assert_eq!(df.get_column("timing.millis").unwrap().get_value(0), Some(Value::F64(1.5)));
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use prost_reflect::prost_types::field_descriptor_proto::{Label, Type as FieldType};
+use prost_reflect::prost_types::{
+    DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+};
+use prost_reflect::Value as ReflectValue;
+use prost_reflect::{DescriptorPool, DynamicMessage, Kind, MessageDescriptor};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::sync::OnceLock;
+
+/// How each message in a stream is prefixed with its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LengthPrefix {
+    /// A base-128 varint, as written by protobuf's `writeDelimitedTo`
+    #[default]
+    Varint,
+    /// A 4-byte little-endian integer, as in size-prefixed FlatBuffers
+    U32LittleEndian,
+}
+
+/// Decodes one binary message into a row of values
+pub trait RecordDecoder {
+    /// Column names and types, in row order
+    fn columns(&self) -> Vec<(String, DataType)>;
+
+    /// Decodes `message` into one value per column, `None` for null
+    fn decode(&self, message: &[u8]) -> Result<Vec<Option<Value>>, VeloxxError>;
+}
+
+/// Reads every message of a length-prefixed stream into a row, using `decoder`.
+///
+/// The reader is read a few bytes at a time, so pass a buffered one.
+pub fn read_messages<R: Read>(
+    mut reader: R,
+    prefix: LengthPrefix,
+    decoder: &dyn RecordDecoder,
+) -> Result<DataFrame, VeloxxError> {
+    let columns = decoder.columns();
+    let mut values: Vec<Vec<Option<Value>>> = vec![Vec::new(); columns.len()];
+    let mut message = Vec::new();
+    let mut count = 0;
+    while let Some(length) = read_length(&mut reader, prefix)? {
+        count += 1;
+        message.resize(length, 0);
+        reader
+            .read_exact(&mut message)
+            .map_err(|e| VeloxxError::Parsing(format!("Message {} is truncated: {}", count, e)))?;
+        let row = decoder
+            .decode(&message)
+            .map_err(|e| VeloxxError::Parsing(format!("Message {}: {}", count, e)))?;
+        if row.len() != columns.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Decoder returned {} values for {} columns",
+                row.len(),
+                columns.len()
+            )));
+        }
+        for (column, value) in values.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+
+    let mut series = HashMap::with_capacity(columns.len());
+    for ((name, data_type), values) in columns.into_iter().zip(values) {
+        let column = typed_series(&name, data_type, values)?;
+        series.insert(name, column);
+    }
+    DataFrame::new(series)
+}
+
+/// Reads a file of length-prefixed messages; see [`read_messages`].
+pub fn read_file(
+    path: &str,
+    prefix: LengthPrefix,
+    decoder: &dyn RecordDecoder,
+) -> Result<DataFrame, VeloxxError> {
+    let file =
+        File::open(path).map_err(|e| VeloxxError::FileIO(format!("Failed to open file: {}", e)))?;
+    read_messages(BufReader::new(file), prefix, decoder)
+}
+
+/// Reads the next length prefix, or `None` at the end of the stream
+fn read_length<R: Read>(
+    reader: &mut R,
+    prefix: LengthPrefix,
+) -> Result<Option<usize>, VeloxxError> {
+    let truncated = || VeloxxError::Parsing("Stream ends inside a length prefix".to_string());
+    let mut byte = [0u8; 1];
+    let read = reader
+        .read(&mut byte)
+        .map_err(|e| VeloxxError::FileIO(format!("Failed to read message: {}", e)))?;
+    if read == 0 {
+        return Ok(None);
+    }
+    match prefix {
+        LengthPrefix::Varint => {
+            let mut length = u64::from(byte[0] & 0x7f);
+            let mut shift = 7;
+            while byte[0] & 0x80 != 0 {
+                if shift > 63 {
+                    return Err(VeloxxError::Parsing(
+                        "Length prefix is too long".to_string(),
+                    ));
+                }
+                reader.read_exact(&mut byte).map_err(|_| truncated())?;
+                length |= u64::from(byte[0] & 0x7f) << shift;
+                shift += 7;
+            }
+            Ok(Some(length as usize))
+        }
+        LengthPrefix::U32LittleEndian => {
+            let mut rest = [0u8; 3];
+            reader.read_exact(&mut rest).map_err(|_| truncated())?;
+            Ok(Some(
+                u32::from_le_bytes([byte[0], rest[0], rest[1], rest[2]]) as usize,
+            ))
+        }
+    }
+}
+
+/// Builds a column of `data_type`, failing on values of another type
+fn typed_series(
+    name: &str,
+    data_type: DataType,
+    values: Vec<Option<Value>>,
+) -> Result<Series, VeloxxError> {
+    let mismatch = |value: &Value| {
+        VeloxxError::DataTypeMismatch(format!(
+            "Column '{}' is {:?} but was decoded as {:?}",
+            name,
+            data_type,
+            value.data_type()
+        ))
+    };
+    macro_rules! collect {
+        ($variant:ident, $constructor:ident) => {
+            Series::$constructor(
+                name,
+                values
+                    .into_iter()
+                    .map(|value| match value {
+                        None | Some(Value::Null) => Ok(None),
+                        Some(Value::$variant(v)) => Ok(Some(v)),
+                        Some(other) => Err(mismatch(&other)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            )
+        };
+    }
+    Ok(match data_type {
+        DataType::I32 => collect!(I32, new_i32),
+        DataType::F64 => collect!(F64, new_f64),
+        DataType::Bool => collect!(Bool, new_bool),
+        DataType::String => collect!(String, new_string),
+        DataType::DateTime => collect!(DateTime, new_datetime),
+        DataType::Binary => collect!(Binary, new_binary),
+    })
+}
+
+/// Scalar type of a Protocol Buffers field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtoType {
+    Double,
+    Float,
+    Int32,
+    Int64,
+    UInt32,
+    UInt64,
+    SInt32,
+    SInt64,
+    Fixed32,
+    Fixed64,
+    SFixed32,
+    SFixed64,
+    Bool,
+    Enum,
+    String,
+    Bytes,
+}
+
+impl ProtoType {
+    /// The type named `name` in a `.proto` file; enums are named by the user
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "double" => Self::Double,
+            "float" => Self::Float,
+            "int32" => Self::Int32,
+            "int64" => Self::Int64,
+            "uint32" => Self::UInt32,
+            "uint64" => Self::UInt64,
+            "sint32" => Self::SInt32,
+            "sint64" => Self::SInt64,
+            "fixed32" => Self::Fixed32,
+            "fixed64" => Self::Fixed64,
+            "sfixed32" => Self::SFixed32,
+            "sfixed64" => Self::SFixed64,
+            "bool" => Self::Bool,
+            "string" => Self::String,
+            "bytes" => Self::Bytes,
+            _ => return None,
+        })
+    }
+
+    /// The scalar type of a field in a compiled descriptor; `None` for messages
+    fn from_kind(kind: &Kind) -> Option<Self> {
+        Some(match kind {
+            Kind::Double => Self::Double,
+            Kind::Float => Self::Float,
+            Kind::Int32 => Self::Int32,
+            Kind::Int64 => Self::Int64,
+            Kind::Uint32 => Self::UInt32,
+            Kind::Uint64 => Self::UInt64,
+            Kind::Sint32 => Self::SInt32,
+            Kind::Sint64 => Self::SInt64,
+            Kind::Fixed32 => Self::Fixed32,
+            Kind::Fixed64 => Self::Fixed64,
+            Kind::Sfixed32 => Self::SFixed32,
+            Kind::Sfixed64 => Self::SFixed64,
+            Kind::Bool => Self::Bool,
+            Kind::Enum(_) => Self::Enum,
+            Kind::String => Self::String,
+            Kind::Bytes => Self::Bytes,
+            Kind::Message(_) => return None,
+        })
+    }
+
+    /// Column type holding the field's values.
+    ///
+    /// 64-bit and unsigned 32-bit integers are read as `F64`, so values beyond
+    /// 2^53 lose precision.
+    pub fn data_type(self) -> DataType {
+        match self {
+            Self::Int32 | Self::SInt32 | Self::SFixed32 | Self::Enum => DataType::I32,
+            Self::Bool => DataType::Bool,
+            Self::String => DataType::String,
+            Self::Bytes => DataType::Binary,
+            _ => DataType::F64,
+        }
+    }
+
+    /// Field type used to decode the field; enums are read as `int32`, so values
+    /// missing from the enum definition are kept
+    fn field_type(self) -> FieldType {
+        match self {
+            Self::Double => FieldType::Double,
+            Self::Float => FieldType::Float,
+            Self::Int32 | Self::Enum => FieldType::Int32,
+            Self::Int64 => FieldType::Int64,
+            Self::UInt32 => FieldType::Uint32,
+            Self::UInt64 => FieldType::Uint64,
+            Self::SInt32 => FieldType::Sint32,
+            Self::SInt64 => FieldType::Sint64,
+            Self::Fixed32 => FieldType::Fixed32,
+            Self::Fixed64 => FieldType::Fixed64,
+            Self::SFixed32 => FieldType::Sfixed32,
+            Self::SFixed64 => FieldType::Sfixed64,
+            Self::Bool => FieldType::Bool,
+            Self::String => FieldType::String,
+            Self::Bytes => FieldType::Bytes,
+        }
+    }
+}
+
+/// One column of a [`ProtoDescriptor`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProtoColumn {
+    name: String,
+    /// Field numbers from the outer message down to the scalar field
+    path: Vec<u32>,
+    proto_type: ProtoType,
+}
+
+/// Selects the fields of a Protocol Buffers message to decode into columns
+///
+/// Messages are decoded with [`prost_reflect::DynamicMessage`]. Unset fields are
+/// null rather than the proto3 default. When a field appears more than once the
+/// last value wins, as in protobuf. Repeated and map fields are not supported.
+#[derive(Debug, Clone, Default)]
+pub struct ProtoDescriptor {
+    columns: Vec<ProtoColumn>,
+    /// Message type holding just the selected fields, built on first decode
+    message: OnceLock<Result<MessageDescriptor, String>>,
+}
+
+impl ProtoDescriptor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a column read from the field numbered `path[0]`, or from a field nested
+    /// inside message fields when `path` is longer (`[3, 1]` is field 1 of the
+    /// message in field 3).
+    pub fn field(mut self, column: &str, path: &[u32], proto_type: ProtoType) -> Self {
+        self.columns.push(ProtoColumn {
+            name: column.to_string(),
+            path: path.to_vec(),
+            proto_type,
+        });
+        self.message = OnceLock::new();
+        self
+    }
+
+    /// Builds a descriptor with a column for every singular scalar field of
+    /// `message`, as defined in the `.proto` source.
+    ///
+    /// Fields of message type are flattened into `outer.inner` columns. Repeated
+    /// fields, maps and imported types are skipped. To use imports, compile the
+    /// schema to a descriptor set and call [`ProtoDescriptor::from_descriptor_set`].
+    pub fn from_proto(source: &str, message: &str) -> Result<Self, VeloxxError> {
+        let schema = ProtoSchema::parse(source)?;
+        let mut descriptor = Self::new();
+        schema.flatten(message, "", &[], &mut Vec::new(), &mut descriptor)?;
+        Ok(descriptor)
+    }
+
+    /// Builds a descriptor from a serialized `FileDescriptorSet`, as written by
+    /// `protoc --include_imports --descriptor_set_out` or `buf build`.
+    ///
+    /// `message` is the fully qualified name, such as `logs.Log`. Columns are
+    /// flattened as in [`ProtoDescriptor::from_proto`].
+    pub fn from_descriptor_set(bytes: &[u8], message: &str) -> Result<Self, VeloxxError> {
+        let pool = DescriptorPool::decode(bytes)
+            .map_err(|e| VeloxxError::Parsing(format!("Invalid descriptor set: {}", e)))?;
+        let message = pool.get_message_by_name(message).ok_or_else(|| {
+            VeloxxError::Parsing(format!("Message {} is not in the descriptor set", message))
+        })?;
+        Ok(Self::from_descriptor(&message))
+    }
+
+    /// Builds a descriptor for a message type from a [`DescriptorPool`], such as
+    /// one generated for a `prost` message
+    pub fn from_descriptor(message: &MessageDescriptor) -> Self {
+        let mut descriptor = Self::new();
+        flatten_descriptor(message, "", &[], &mut Vec::new(), &mut descriptor);
+        descriptor
+    }
+
+    /// Keeps only `columns`, in the given order.
+    pub fn select(self, columns: &[&str]) -> Result<Self, VeloxxError> {
+        let selected = columns
+            .iter()
+            .map(|name| {
+                self.columns
+                    .iter()
+                    .find(|c| c.name == *name)
+                    .cloned()
+                    .ok_or_else(|| VeloxxError::ColumnNotFound(name.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            columns: selected,
+            message: OnceLock::new(),
+        })
+    }
+
+    /// Returns the column names, in order.
+    pub fn column_names(&self) -> Vec<&str> {
+        self.columns.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    /// Builds a message type with one field per column path, nesting a message
+    /// type for every step but the last.
+    ///
+    /// Fields are proto2 `optional`, so unset fields are told apart from defaults,
+    /// and fields that are not selected are skipped as unknown.
+    fn decoding_message(&self) -> Result<MessageDescriptor, String> {
+        let mut row = DescriptorProto {
+            name: Some("Row".to_string()),
+            ..Default::default()
+        };
+        for column in &self.columns {
+            if column.path.is_empty() {
+                return Err(format!("Column '{}' has no field path", column.name));
+            }
+            add_field(
+                &mut row,
+                ".veloxx.Row",
+                &[],
+                &column.path,
+                column.proto_type,
+            )?;
+        }
+        let file = FileDescriptorProto {
+            name: Some("veloxx/row.proto".to_string()),
+            package: Some("veloxx".to_string()),
+            message_type: vec![row],
+            syntax: Some("proto2".to_string()),
+            ..Default::default()
+        };
+        let pool = DescriptorPool::from_file_descriptor_set(FileDescriptorSet { file: vec![file] })
+            .map_err(|e| format!("Invalid field path: {}", e))?;
+        pool.get_message_by_name("veloxx.Row")
+            .ok_or_else(|| "Decoding message was not built".to_string())
+    }
+}
+
+impl RecordDecoder for ProtoDescriptor {
+    fn columns(&self) -> Vec<(String, DataType)> {
+        self.columns
+            .iter()
+            .map(|c| (c.name.clone(), c.proto_type.data_type()))
+            .collect()
+    }
+
+    fn decode(&self, message: &[u8]) -> Result<Vec<Option<Value>>, VeloxxError> {
+        let descriptor = self
+            .message
+            .get_or_init(|| self.decoding_message())
+            .clone()
+            .map_err(VeloxxError::InvalidOperation)?;
+        let message = DynamicMessage::decode(descriptor, message)
+            .map_err(|e| VeloxxError::Parsing(e.to_string()))?;
+        Ok(self
+            .columns
+            .iter()
+            .map(|column| lookup(&message, &column.path).and_then(convert))
+            .collect())
+    }
+}
+
+/// Adds the field at `path` to `message`, whose fully qualified name is
+/// `type_name` and which sits at `prefix` in the row message
+fn add_field(
+    message: &mut DescriptorProto,
+    type_name: &str,
+    prefix: &[u32],
+    path: &[u32],
+    proto_type: ProtoType,
+) -> Result<(), String> {
+    let (&number, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Ok(()),
+    };
+    let nested_name = format!("F{}", number);
+    let nested_type = format!("{}.{}", type_name, nested_name);
+    let (field_type, field_type_name) = if rest.is_empty() {
+        (proto_type.field_type(), None)
+    } else {
+        (FieldType::Message, Some(nested_type.clone()))
+    };
+
+    match message
+        .field
+        .iter()
+        .find(|f| f.number == Some(number as i32))
+    {
+        Some(existing)
+            if existing.r#type == Some(field_type as i32)
+                && existing.type_name == field_type_name => {}
+        Some(_) => return Err(field_error(prefix, number, "is read with two types")),
+        None => {
+            message.field.push(FieldDescriptorProto {
+                name: Some(format!("f{}", number)),
+                number: Some(number as i32),
+                label: Some(Label::Optional as i32),
+                r#type: Some(field_type as i32),
+                type_name: field_type_name,
+                ..Default::default()
+            });
+            if !rest.is_empty() {
+                message.nested_type.push(DescriptorProto {
+                    name: Some(nested_name.clone()),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    if rest.is_empty() {
+        return Ok(());
+    }
+    if let Some(nested) = message
+        .nested_type
+        .iter_mut()
+        .find(|m| m.name.as_deref() == Some(nested_name.as_str()))
+    {
+        let mut prefix = prefix.to_vec();
+        prefix.push(number);
+        add_field(nested, &nested_type, &prefix, rest, proto_type)?;
+    }
+    Ok(())
+}
+
+/// Adds the singular scalar fields of `message` to `descriptor`, recursing into
+/// message fields; `seen` holds the enclosing messages, so recursive types stop
+fn flatten_descriptor(
+    message: &MessageDescriptor,
+    prefix: &str,
+    path: &[u32],
+    seen: &mut Vec<String>,
+    descriptor: &mut ProtoDescriptor,
+) {
+    seen.push(message.full_name().to_string());
+    for field in message.fields().filter(|f| !f.is_list() && !f.is_map()) {
+        let name = format!("{}{}", prefix, field.name());
+        let mut field_path = path.to_vec();
+        field_path.push(field.number());
+        match field.kind() {
+            Kind::Message(nested) => {
+                if !seen.iter().any(|m| m == nested.full_name()) {
+                    let prefix = format!("{}.", name);
+                    flatten_descriptor(&nested, &prefix, &field_path, seen, descriptor);
+                }
+            }
+            kind => {
+                if let Some(proto_type) = ProtoType::from_kind(&kind) {
+                    descriptor.columns.push(ProtoColumn {
+                        name,
+                        path: field_path,
+                        proto_type,
+                    });
+                }
+            }
+        }
+    }
+    seen.pop();
+}
+
+/// The value of the field at `path`, or `None` if it or an enclosing message is unset
+fn lookup(message: &DynamicMessage, path: &[u32]) -> Option<ReflectValue> {
+    let (&number, rest) = path.split_first()?;
+    if !message.has_field_by_number(number) {
+        return None;
+    }
+    let value = message.get_field_by_number(number)?;
+    if rest.is_empty() {
+        Some(value.into_owned())
+    } else {
+        lookup(value.as_message()?, rest)
+    }
+}
+
+fn convert(value: ReflectValue) -> Option<Value> {
+    Some(match value {
+        ReflectValue::I32(v) | ReflectValue::EnumNumber(v) => Value::I32(v),
+        ReflectValue::I64(v) => Value::F64(v as f64),
+        ReflectValue::U32(v) => Value::F64(v as f64),
+        ReflectValue::U64(v) => Value::F64(v as f64),
+        ReflectValue::F32(v) => Value::F64(v as f64),
+        ReflectValue::F64(v) => Value::F64(v),
+        ReflectValue::Bool(v) => Value::Bool(v),
+        ReflectValue::String(v) => Value::String(v),
+        ReflectValue::Bytes(v) => Value::Binary(v.to_vec()),
+        ReflectValue::Message(_) | ReflectValue::List(_) | ReflectValue::Map(_) => return None,
+    })
+}
+
+fn field_error(prefix: &[u32], number: u32, problem: &str) -> String {
+    let path: Vec<String> = prefix
+        .iter()
+        .chain(std::iter::once(&number))
+        .map(u32::to_string)
+        .collect();
+    format!("Field {} {}", path.join("."), problem)
+}
+
+/// Field of a message parsed from `.proto` source
+struct ProtoSchemaField {
+    name: String,
+    type_name: String,
+    number: u32,
+    repeated: bool,
+}
+
+/// The messages and enums of a `.proto` file, by simple name
+#[derive(Default)]
+struct ProtoSchema {
+    messages: HashMap<String, Vec<ProtoSchemaField>>,
+    enums: HashSet<String>,
+}
+
+impl ProtoSchema {
+    fn parse(source: &str) -> Result<Self, VeloxxError> {
+        let tokens = tokenize(source);
+        let mut schema = Self::default();
+        let mut position = 0;
+        while position < tokens.len() {
+            schema.parse_item(&tokens, &mut position, None)?;
+        }
+        Ok(schema)
+    }
+
+    /// Parses one top-level statement, or one statement in the body of `message`
+    fn parse_item(
+        &mut self,
+        tokens: &[String],
+        position: &mut usize,
+        message: Option<&str>,
+    ) -> Result<(), VeloxxError> {
+        let token = tokens[*position].as_str();
+        match token {
+            "message" => {
+                let name = expect_name(tokens, *position + 1)?;
+                *position += 2;
+                expect(tokens, position, "{")?;
+                self.messages.entry(name.clone()).or_default();
+                while tokens.get(*position).map(String::as_str) != Some("}") {
+                    if *position >= tokens.len() {
+                        return Err(proto_error(&format!("message {} is not closed", name)));
+                    }
+                    self.parse_item(tokens, position, Some(&name))?;
+                }
+                *position += 1;
+            }
+            "enum" => {
+                self.enums.insert(expect_name(tokens, *position + 1)?);
+                *position += 2;
+                skip_block(tokens, position)?;
+            }
+            "service" | "extend" => {
+                *position += 2;
+                skip_block(tokens, position)?;
+            }
+            // The fields of a oneof belong to the enclosing message
+            "oneof" if message.is_some() => {
+                *position += 2;
+                expect(tokens, position, "{")?;
+            }
+            "}" if message.is_some() => *position += 1,
+            ";" => *position += 1,
+            "syntax" | "package" | "import" | "option" | "reserved" | "extensions" | "edition" => {
+                skip_statement(tokens, position)
+            }
+            "map" => skip_statement(tokens, position),
+            _ => match message {
+                Some(message) => {
+                    let field = parse_field(tokens, position)?;
+                    if let Some(fields) = self.messages.get_mut(message) {
+                        fields.push(field);
+                    }
+                }
+                None => return Err(proto_error(&format!("unexpected '{}'", token))),
+            },
+        }
+        Ok(())
+    }
+
+    /// Adds the scalar fields of `message` to `descriptor`, recursing into message
+    /// fields; `seen` holds the enclosing messages, so recursive types stop
+    fn flatten(
+        &self,
+        message: &str,
+        prefix: &str,
+        path: &[u32],
+        seen: &mut Vec<String>,
+        descriptor: &mut ProtoDescriptor,
+    ) -> Result<(), VeloxxError> {
+        let fields = self
+            .messages
+            .get(message)
+            .ok_or_else(|| proto_error(&format!("message {} is not defined", message)))?;
+        seen.push(message.to_string());
+        for field in fields.iter().filter(|f| !f.repeated) {
+            let name = format!("{}{}", prefix, field.name);
+            let mut field_path = path.to_vec();
+            field_path.push(field.number);
+            let type_name = field.type_name.rsplit('.').next().unwrap_or_default();
+            let proto_type = ProtoType::from_name(type_name)
+                .or_else(|| self.enums.contains(type_name).then_some(ProtoType::Enum));
+            if let Some(proto_type) = proto_type {
+                descriptor.columns.push(ProtoColumn {
+                    name,
+                    path: field_path,
+                    proto_type,
+                });
+            } else if self.messages.contains_key(type_name) && !seen.iter().any(|m| m == type_name)
+            {
+                let prefix = format!("{}.", name);
+                self.flatten(type_name, &prefix, &field_path, seen, descriptor)?;
+            }
+        }
+        seen.pop();
+        Ok(())
+    }
+}
+
+fn proto_error(problem: &str) -> VeloxxError {
+    VeloxxError::Parsing(format!("Invalid .proto source: {}", problem))
+}
+
+/// Splits `.proto` source into identifiers, numbers, strings and symbols, dropping comments
+fn tokenize(source: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            '"' | '\'' => {
+                let mut token = c.to_string();
+                for next in chars.by_ref() {
+                    token.push(next);
+                    if next == c {
+                        break;
+                    }
+                }
+                tokens.push(token);
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' => {
+                let mut token = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_alphanumeric() || next == '_' || next == '.') {
+                        break;
+                    }
+                    token.push(next);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+            c => tokens.push(c.to_string()),
+        }
+    }
+    tokens
+}
+
+/// Parses `[optional|required|repeated] type name = number [options];`
+fn parse_field(tokens: &[String], position: &mut usize) -> Result<ProtoSchemaField, VeloxxError> {
+    let mut repeated = false;
+    while let Some(label @ ("optional" | "required" | "repeated")) =
+        tokens.get(*position).map(String::as_str)
+    {
+        repeated |= label == "repeated";
+        *position += 1;
+    }
+    let type_name = expect_name(tokens, *position)?;
+    let name = expect_name(tokens, *position + 1)?;
+    *position += 2;
+    expect(tokens, position, "=")?;
+    let number = tokens
+        .get(*position)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| proto_error(&format!("field {} has no number", name)))?;
+    *position += 1;
+    skip_statement(tokens, position);
+    Ok(ProtoSchemaField {
+        name,
+        type_name,
+        number,
+        repeated,
+    })
+}
+
+fn expect_name(tokens: &[String], position: usize) -> Result<String, VeloxxError> {
+    tokens
+        .get(position)
+        .filter(|t| t.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '.'))
+        .cloned()
+        .ok_or_else(|| proto_error("expected a name"))
+}
+
+fn expect(tokens: &[String], position: &mut usize, symbol: &str) -> Result<(), VeloxxError> {
+    if tokens.get(*position).map(String::as_str) != Some(symbol) {
+        return Err(proto_error(&format!("expected '{}'", symbol)));
+    }
+    *position += 1;
+    Ok(())
+}
+
+/// Moves past the next `;`
+fn skip_statement(tokens: &[String], position: &mut usize) {
+    while *position < tokens.len() && tokens[*position] != ";" {
+        *position += 1;
+    }
+    *position += 1;
+}
+
+/// Moves past a `{ ... }` block starting at `position`
+fn skip_block(tokens: &[String], position: &mut usize) -> Result<(), VeloxxError> {
+    expect(tokens, position, "{")?;
+    let mut depth = 1;
+    while depth > 0 {
+        match tokens.get(*position).map(String::as_str) {
+            Some("{") => depth += 1,
+            Some("}") => depth -= 1,
+            Some(_) => {}
+            None => return Err(proto_error("block is not closed")),
+        }
+        *position += 1;
+    }
+    Ok(())
+}
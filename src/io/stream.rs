@@ -0,0 +1,412 @@
+//! Ingestion of record streams into `DataFrame` batches.
+//!
+//! A [`StreamIngestor`] polls a [`StreamSource`] and cuts a batch whenever it holds
+//! `batch_size` records or `batch_interval` has passed since the batch's first record.
+//! Batches go to a callback ([`StreamIngestor::run`]) or into a [`RollingTable`] that
+//! keeps the most recent rows and can be read from other threads while ingestion runs.
+//!
+//! Built-in sources are [`IteratorSource`] and [`ChannelSource`]; a Kafka consumer
+//! is available as [`kafka::KafkaSource`] with the `kafka` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use veloxx::io::stream::{IteratorSource, RollingTable, StreamIngestor};
+//! use veloxx::types::Value;
+//!
+//! let records = (0..10).map(|i| HashMap::from([("id".to_string(), Value::I32(i))]));
+//! let table = RollingTable::new(4);
+//! StreamIngestor::new(IteratorSource::new(records))
+//!     .batch_size(3)
+//!     .run_into(&table)
+//!     .unwrap();
+//!
+//! let latest = table.snapshot();
+//! assert_eq!(latest.row_count(), 4);
+//! assert_eq!(latest.get_column("id").unwrap().get_value(0), Some(Value::I32(6)));
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+/// One record: column name to value. Missing columns are null.
+pub type StreamRecord = HashMap<String, Value>;
+
+/// Result of polling a [`StreamSource`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SourcePoll {
+    Record(StreamRecord),
+    /// No record arrived within the timeout
+    Idle,
+    /// The source is exhausted and will not produce more records
+    Finished,
+}
+
+/// A pluggable producer of records
+pub trait StreamSource {
+    /// Waits at most `timeout` for the next record.
+    fn poll(&mut self, timeout: Duration) -> Result<SourcePoll, VeloxxError>;
+}
+
+/// A source over an in-memory iterator; finishes when the iterator does.
+pub struct IteratorSource<I> {
+    records: I,
+}
+
+impl<I: Iterator<Item = StreamRecord>> IteratorSource<I> {
+    pub fn new(records: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            records: records.into_iter(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = StreamRecord>> StreamSource for IteratorSource<I> {
+    fn poll(&mut self, _timeout: Duration) -> Result<SourcePoll, VeloxxError> {
+        Ok(self
+            .records
+            .next()
+            .map_or(SourcePoll::Finished, SourcePoll::Record))
+    }
+}
+
+/// A source fed by other threads through a channel; finishes when every sender is dropped.
+pub struct ChannelSource {
+    receiver: Receiver<StreamRecord>,
+}
+
+impl ChannelSource {
+    pub fn new(receiver: Receiver<StreamRecord>) -> Self {
+        Self { receiver }
+    }
+}
+
+impl StreamSource for ChannelSource {
+    fn poll(&mut self, timeout: Duration) -> Result<SourcePoll, VeloxxError> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(record) => Ok(SourcePoll::Record(record)),
+            Err(RecvTimeoutError::Timeout) => Ok(SourcePoll::Idle),
+            Err(RecvTimeoutError::Disconnected) => Ok(SourcePoll::Finished),
+        }
+    }
+}
+
+/// Batches records from a [`StreamSource`] into `DataFrame`s.
+///
+/// Column types are fixed by the first non-null value seen (or by [`column_type`]),
+/// so every batch has the same schema: columns seen in earlier batches are present
+/// as nulls, and `I32` values in an `F64` column are widened.
+///
+/// [`column_type`]: StreamIngestor::column_type
+pub struct StreamIngestor<S> {
+    source: S,
+    batch_size: usize,
+    batch_interval: Duration,
+    schema: Vec<(String, DataType)>,
+    finished: bool,
+}
+
+impl<S: StreamSource> StreamIngestor<S> {
+    /// Creates an ingestor cutting batches of 1000 records or every second.
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            batch_size: 1000,
+            batch_interval: Duration::from_secs(1),
+            schema: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Maximum number of records per batch (at least 1)
+    pub fn batch_size(mut self, records: usize) -> Self {
+        self.batch_size = records.max(1);
+        self
+    }
+
+    /// Maximum time between a batch's first record and the batch being emitted
+    pub fn batch_interval(mut self, interval: Duration) -> Self {
+        self.batch_interval = interval;
+        self
+    }
+
+    /// Declares the type of a column instead of inferring it from the first value.
+    pub fn column_type(mut self, name: &str, data_type: DataType) -> Self {
+        match self.schema.iter_mut().find(|(column, _)| column == name) {
+            Some((_, existing)) => *existing = data_type,
+            None => self.schema.push((name.to_string(), data_type)),
+        }
+        self
+    }
+
+    /// Returns the next batch, or `None` once the source has finished and every
+    /// record has been emitted. Blocks while the source is idle and no records are
+    /// buffered.
+    pub fn next_batch(&mut self) -> Result<Option<DataFrame>, VeloxxError> {
+        let mut records = Vec::new();
+        let mut deadline: Option<Instant> = None;
+        while !self.finished && records.len() < self.batch_size {
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    remaining
+                }
+                None => self.batch_interval,
+            };
+            match self.source.poll(timeout)? {
+                SourcePoll::Record(record) => {
+                    deadline.get_or_insert_with(|| Instant::now() + self.batch_interval);
+                    records.push(record);
+                }
+                SourcePoll::Idle => {}
+                SourcePoll::Finished => self.finished = true,
+            }
+        }
+        if records.is_empty() {
+            return Ok(None);
+        }
+        self.build_batch(records).map(Some)
+    }
+
+    /// Passes each batch to `on_batch` until the source finishes or `on_batch`
+    /// returns `Ok(false)`.
+    pub fn run<F>(&mut self, mut on_batch: F) -> Result<(), VeloxxError>
+    where
+        F: FnMut(DataFrame) -> Result<bool, VeloxxError>,
+    {
+        while let Some(batch) = self.next_batch()? {
+            if !on_batch(batch)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends every batch to `table` until the source finishes.
+    pub fn run_into(&mut self, table: &RollingTable) -> Result<(), VeloxxError> {
+        self.run(|batch| table.push(batch).map(|_| true))
+    }
+
+    fn build_batch(&mut self, records: Vec<StreamRecord>) -> Result<DataFrame, VeloxxError> {
+        for record in &records {
+            for (name, value) in record {
+                if matches!(value, Value::Null) {
+                    continue;
+                }
+                match self.schema.iter_mut().find(|(column, _)| column == name) {
+                    Some((_, data_type @ DataType::I32)) if matches!(value, Value::F64(_)) => {
+                        // Earlier batches keep their I32 column; RollingTable widens them
+                        *data_type = DataType::F64;
+                    }
+                    Some(_) => {}
+                    None => self.schema.push((name.clone(), value.data_type())),
+                }
+            }
+        }
+
+        let mut columns = HashMap::with_capacity(self.schema.len());
+        for (name, data_type) in &self.schema {
+            let values = records.iter().map(|record| record.get(name));
+            let mismatch = |value: &Value| {
+                VeloxxError::DataTypeMismatch(format!(
+                    "Column '{}' is {:?} but a record has {:?}",
+                    name, data_type, value
+                ))
+            };
+            let series = match data_type {
+                DataType::I32 => Series::new_i32(
+                    name,
+                    values
+                        .map(|value| match value {
+                            None | Some(Value::Null) => Ok(None),
+                            Some(Value::I32(v)) => Ok(Some(*v)),
+                            Some(other) => Err(mismatch(other)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                DataType::F64 => Series::new_f64(
+                    name,
+                    values
+                        .map(|value| match value {
+                            None | Some(Value::Null) => Ok(None),
+                            Some(Value::F64(v)) => Ok(Some(*v)),
+                            Some(Value::I32(v)) => Ok(Some(*v as f64)),
+                            Some(other) => Err(mismatch(other)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                DataType::Bool => Series::new_bool(
+                    name,
+                    values
+                        .map(|value| match value {
+                            None | Some(Value::Null) => Ok(None),
+                            Some(Value::Bool(v)) => Ok(Some(*v)),
+                            Some(other) => Err(mismatch(other)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                DataType::String => Series::new_string(
+                    name,
+                    values
+                        .map(|value| match value {
+                            None | Some(Value::Null) => Ok(None),
+                            Some(Value::String(v)) => Ok(Some(v.clone())),
+                            Some(other) => Err(mismatch(other)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                DataType::DateTime => Series::new_datetime(
+                    name,
+                    values
+                        .map(|value| match value {
+                            None | Some(Value::Null) => Ok(None),
+                            Some(Value::DateTime(v)) => Ok(Some(*v)),
+                            Some(other) => Err(mismatch(other)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                DataType::Binary => Series::new_binary(
+                    name,
+                    values
+                        .map(|value| match value {
+                            None | Some(Value::Null) => Ok(None),
+                            Some(Value::Binary(v)) => Ok(Some(v.clone())),
+                            Some(other) => Err(mismatch(other)),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+            };
+            columns.insert(name.clone(), series);
+        }
+        DataFrame::new(columns)
+    }
+}
+
+/// A bounded table holding the most recent rows of a stream.
+///
+/// Clones share the same rows, so one clone can be filled by a [`StreamIngestor`]
+/// while others take snapshots.
+#[derive(Debug, Clone)]
+pub struct RollingTable {
+    capacity: usize,
+    rows: Arc<RwLock<Option<DataFrame>>>,
+}
+
+impl RollingTable {
+    /// Creates a table keeping at most `capacity` rows.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            rows: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().as_ref().map_or(0, |df| df.row_count())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends `batch`, dropping the oldest rows beyond the capacity. Columns new
+    /// to the table are added with nulls for earlier rows.
+    pub fn push(&self, batch: DataFrame) -> Result<(), VeloxxError> {
+        let mut rows = self.rows.write().map_err(|_| poisoned())?;
+        let combined = match rows.take() {
+            Some(existing) => append_aligned(&existing, &batch)?,
+            None => batch,
+        };
+        let excess = combined.row_count().saturating_sub(self.capacity);
+        *rows = Some(if excess > 0 {
+            let keep: Vec<usize> = (excess..combined.row_count()).collect();
+            let mut columns = HashMap::with_capacity(combined.column_count());
+            for (name, series) in &combined.columns {
+                columns.insert(name.clone(), series.filter(&keep)?);
+            }
+            DataFrame::new(columns)?
+        } else {
+            combined
+        });
+        Ok(())
+    }
+
+    /// Copies the current rows, oldest first.
+    pub fn snapshot(&self) -> DataFrame {
+        self.read()
+            .clone()
+            .unwrap_or_else(|| DataFrame::new(HashMap::new()).expect("empty DataFrame"))
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut rows) = self.rows.write() {
+            *rows = None;
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Option<DataFrame>> {
+        // A panic while pushing leaves the previous rows in place, so they stay readable
+        self.rows
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn poisoned() -> VeloxxError {
+    VeloxxError::ExecutionError("Rolling table lock was poisoned".to_string())
+}
+
+/// Appends `batch` to `existing`, null-filling columns missing from either side and
+/// widening `I32` columns that meet `F64`.
+fn append_aligned(existing: &DataFrame, batch: &DataFrame) -> Result<DataFrame, VeloxxError> {
+    let mut left = existing.columns.clone();
+    let mut right = batch.columns.clone();
+    for (name, series) in &batch.columns {
+        left.entry(name.clone())
+            .or_insert_with(|| null_series(name, series.data_type(), existing.row_count()));
+    }
+    for (name, series) in &existing.columns {
+        right
+            .entry(name.clone())
+            .or_insert_with(|| null_series(name, series.data_type(), batch.row_count()));
+    }
+    let mut columns = HashMap::with_capacity(left.len());
+    for (name, mut old) in left {
+        let mut new = right.remove(&name).expect("columns aligned above");
+        match (old.data_type(), new.data_type()) {
+            (DataType::I32, DataType::F64) => old = old.cast(DataType::F64)?,
+            (DataType::F64, DataType::I32) => new = new.cast(DataType::F64)?,
+            _ => {}
+        }
+        columns.insert(name, old.append(&new)?);
+    }
+    DataFrame::new(columns)
+}
+
+fn null_series(name: &str, data_type: DataType, len: usize) -> Series {
+    match data_type {
+        DataType::I32 => Series::new_i32(name, vec![None; len]),
+        DataType::F64 => Series::new_f64(name, vec![None; len]),
+        DataType::Bool => Series::new_bool(name, vec![None; len]),
+        DataType::String => Series::new_string(name, vec![None; len]),
+        DataType::DateTime => Series::new_datetime(name, vec![None; len]),
+        DataType::Binary => Series::new_binary(name, vec![None; len]),
+    }
+}
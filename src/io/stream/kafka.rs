@@ -0,0 +1,111 @@
+//! Kafka consumer source, enabled with the `kafka` feature.
+
+use super::{SourcePoll, StreamRecord, StreamSource};
+use crate::types::Value;
+use crate::VeloxxError;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{BaseConsumer, Consumer};
+use rdkafka::message::Message;
+use std::time::Duration;
+
+/// Turns a message payload into a record
+pub type PayloadDecoder = Box<dyn Fn(&[u8]) -> Result<StreamRecord, VeloxxError> + Send>;
+
+/// Consumes records from Kafka topics.
+///
+/// Payloads are decoded with [`decode_json`] unless another decoder is set. The
+/// source never finishes; stop ingestion from the batch callback instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use veloxx::io::stream::kafka::KafkaSource;
+/// use veloxx::io::stream::StreamIngestor;
+///
+/// let source = KafkaSource::new("localhost:9092", "dashboard", &["clicks"]).unwrap();
+/// StreamIngestor::new(source)
+///     .batch_size(500)
+///     .run(|batch| {
+///         println!("{} clicks", batch.row_count());
+///         Ok(true)
+///     })
+///     .unwrap();
+/// ```
+pub struct KafkaSource {
+    consumer: BaseConsumer,
+    decoder: PayloadDecoder,
+}
+
+impl KafkaSource {
+    /// Subscribes to `topics` as a member of consumer group `group_id`.
+    pub fn new(brokers: &str, group_id: &str, topics: &[&str]) -> Result<Self, VeloxxError> {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", brokers)
+            .set("group.id", group_id)
+            .set("enable.partition.eof", "false");
+        Self::from_config(&config, topics)
+    }
+
+    /// Subscribes to `topics` with a fully custom client configuration.
+    pub fn from_config(config: &ClientConfig, topics: &[&str]) -> Result<Self, VeloxxError> {
+        let consumer: BaseConsumer = config.create().map_err(kafka_error)?;
+        consumer.subscribe(topics).map_err(kafka_error)?;
+        Ok(Self {
+            consumer,
+            decoder: Box::new(decode_json),
+        })
+    }
+
+    /// Replaces the payload decoder.
+    pub fn with_decoder<F>(mut self, decoder: F) -> Self
+    where
+        F: Fn(&[u8]) -> Result<StreamRecord, VeloxxError> + Send + 'static,
+    {
+        self.decoder = Box::new(decoder);
+        self
+    }
+}
+
+impl StreamSource for KafkaSource {
+    fn poll(&mut self, timeout: Duration) -> Result<SourcePoll, VeloxxError> {
+        match self.consumer.poll(timeout) {
+            None => Ok(SourcePoll::Idle),
+            Some(Err(e)) => Err(kafka_error(e)),
+            Some(Ok(message)) => match message.payload() {
+                // Tombstones carry no payload
+                None => Ok(SourcePoll::Idle),
+                Some(payload) => (self.decoder)(payload).map(SourcePoll::Record),
+            },
+        }
+    }
+}
+
+fn kafka_error(err: rdkafka::error::KafkaError) -> VeloxxError {
+    VeloxxError::ExecutionError(format!("Kafka error: {}", err))
+}
+
+/// Decodes a JSON object payload. Numbers become `F64` (as in
+/// `DataFrame::from_json`), nested arrays and objects are kept as JSON text.
+pub fn decode_json(payload: &[u8]) -> Result<StreamRecord, VeloxxError> {
+    let value: serde_json::Value = serde_json::from_slice(payload)
+        .map_err(|e| VeloxxError::Parsing(format!("Invalid JSON payload: {}", e)))?;
+    let serde_json::Value::Object(object) = value else {
+        return Err(VeloxxError::Parsing(
+            "JSON payload must be an object".to_string(),
+        ));
+    };
+    Ok(object
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::Null => Value::Null,
+                serde_json::Value::Bool(b) => Value::Bool(b),
+                serde_json::Value::Number(n) => n.as_f64().map_or(Value::Null, Value::F64),
+                serde_json::Value::String(s) => Value::String(s),
+                nested => Value::String(nested.to_string()),
+            };
+            (key, value)
+        })
+        .collect())
+}
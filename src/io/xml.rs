@@ -0,0 +1,442 @@
+//! Tabular records from XML documents, read as a stream so that only the record
+//! being parsed is held in memory.
+//!
+//! A record path selects the elements that become rows. It is anchored at the root
+//! when it starts with a single `/` (`/feed/trades/trade`) and matches at any depth
+//! otherwise (`//trade` or `trade`). `*` matches any element name, and a name
+//! without a prefix also matches prefixed elements (`trade` matches `fx:trade`).
+//!
+//! Field paths are relative to the record: `price` is the text of the `price`
+//! child, `party/name` that of a grandchild, `@id` an attribute of the record,
+//! `party/@lei` an attribute of a child, and `.` the record's own text. Text
+//! includes that of nested elements, is trimmed, and the first match wins.
+//! Missing and empty fields are null; column types are inferred as for CSV.
+
+use crate::dataframe::DataFrame;
+use crate::io::options::BadRows;
+use crate::io::UltraFastCsvParser;
+use crate::VeloxxError;
+use std::io::BufRead;
+
+/// Reads the records matched by `record_path` into a DataFrame.
+///
+/// `fields` maps column names to field paths. When it is empty, the attributes
+/// and child elements of the records become columns, named after them.
+pub fn read_xml<R: BufRead>(
+    reader: R,
+    record_path: &str,
+    fields: &[(&str, &str)],
+) -> Result<DataFrame, VeloxxError> {
+    let record_path = PathPattern::parse(record_path)?;
+    let mut fields = fields
+        .iter()
+        .map(|(column, path)| Field::parse(column, path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let discover = fields.is_empty();
+
+    let mut events = XmlEvents::new(reader);
+    let mut stack: Vec<String> = Vec::new();
+    let mut columns_data: Vec<Vec<String>> = vec![Vec::new(); fields.len()];
+    let mut rows = 0;
+    // Depth of the current record element, with the values and open text captures
+    // of its fields
+    let mut record: Option<usize> = None;
+    let mut values: Vec<Option<String>> = Vec::new();
+    let mut captures: Vec<Option<(usize, String)>> = Vec::new();
+
+    while let Some(event) = events.next_event()? {
+        match event {
+            Event::Start { name, attributes } => {
+                stack.push(name);
+                if record.is_none() && record_path.matches(&stack) {
+                    record = Some(stack.len());
+                    values = vec![None; fields.len()];
+                    captures = vec![None; fields.len()];
+                }
+                let Some(depth) = record else {
+                    continue;
+                };
+                let relative = &stack[depth..];
+                if discover {
+                    let mut discovered = Vec::new();
+                    if relative.is_empty() {
+                        for (attribute, _) in &attributes {
+                            discovered.push(Field::attribute(attribute));
+                        }
+                    } else if relative.len() == 1 {
+                        discovered.push(Field::element(&relative[0]));
+                    }
+                    for field in discovered {
+                        if !fields.iter().any(|f| f.column == field.column) {
+                            fields.push(field);
+                            columns_data.push(vec![String::new(); rows]);
+                            values.push(None);
+                            captures.push(None);
+                        }
+                    }
+                }
+                for (i, field) in fields.iter().enumerate() {
+                    if values[i].is_some() || captures[i].is_some() || !field.matches(relative) {
+                        continue;
+                    }
+                    match &field.attribute {
+                        Some(attribute) => {
+                            values[i] = attributes
+                                .iter()
+                                .find(|(name, _)| name_matches(name, attribute))
+                                .map(|(_, value)| value.clone());
+                        }
+                        None => captures[i] = Some((stack.len(), String::new())),
+                    }
+                }
+            }
+            Event::Text(text) => {
+                for (_, captured) in captures.iter_mut().flatten() {
+                    captured.push_str(&text);
+                }
+            }
+            Event::End(name) => {
+                match stack.last() {
+                    Some(open) if *open == name => {}
+                    Some(open) => {
+                        return Err(VeloxxError::Parsing(format!(
+                            "Expected </{}> but found </{}>",
+                            open, name
+                        )))
+                    }
+                    None => {
+                        return Err(VeloxxError::Parsing(format!(
+                            "Unexpected closing tag </{}>",
+                            name
+                        )))
+                    }
+                }
+                for (value, capture) in values.iter_mut().zip(&mut captures) {
+                    if capture
+                        .as_ref()
+                        .is_some_and(|(depth, _)| *depth == stack.len())
+                    {
+                        let (_, text) = capture.take().unwrap_or_default();
+                        *value = Some(text.trim().to_string());
+                    }
+                }
+                if record == Some(stack.len()) {
+                    for (column, value) in columns_data.iter_mut().zip(&mut values) {
+                        column.push(value.take().unwrap_or_default());
+                    }
+                    rows += 1;
+                    record = None;
+                }
+                stack.pop();
+            }
+        }
+    }
+    if let Some(open) = stack.last() {
+        return Err(VeloxxError::Parsing(format!(
+            "Unexpected end of XML inside <{}>",
+            open
+        )));
+    }
+
+    let headers: Vec<String> = fields.into_iter().map(|f| f.column).collect();
+    UltraFastCsvParser::new()
+        .convert_columns(&headers, columns_data, BadRows::default())
+        .map(|(dataframe, _)| dataframe)
+}
+
+/// Whether the element or attribute `name` matches the path segment `segment`
+fn name_matches(name: &str, segment: &str) -> bool {
+    segment == "*"
+        || name == segment
+        || (!segment.contains(':') && name.rsplit(':').next() == Some(segment))
+}
+
+/// A record path, see the [module documentation](self)
+struct PathPattern {
+    segments: Vec<String>,
+    anchored: bool,
+}
+
+impl PathPattern {
+    fn parse(path: &str) -> Result<Self, VeloxxError> {
+        let anchored = path.starts_with('/') && !path.starts_with("//");
+        let segments: Vec<String> = path
+            .trim_start_matches('/')
+            .split('/')
+            .map(str::to_string)
+            .collect();
+        if segments.iter().any(|s| s.is_empty() || s.starts_with('@')) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Invalid XML record path '{}'",
+                path
+            )));
+        }
+        Ok(Self { segments, anchored })
+    }
+
+    fn matches(&self, stack: &[String]) -> bool {
+        if stack.len() < self.segments.len()
+            || (self.anchored && stack.len() != self.segments.len())
+        {
+            return false;
+        }
+        stack[stack.len() - self.segments.len()..]
+            .iter()
+            .zip(&self.segments)
+            .all(|(name, segment)| name_matches(name, segment))
+    }
+}
+
+/// A column read from each record
+struct Field {
+    column: String,
+    /// Element names below the record
+    elements: Vec<String>,
+    /// Attribute of the last element (or of the record) holding the value, if the
+    /// value is not the element's text
+    attribute: Option<String>,
+}
+
+impl Field {
+    fn parse(column: &str, path: &str) -> Result<Self, VeloxxError> {
+        let path = path.trim_start_matches("./");
+        let mut elements: Vec<String> = match path {
+            "" | "." => Vec::new(),
+            _ => path.split('/').map(str::to_string).collect(),
+        };
+        let attribute = match elements.last() {
+            Some(last) if last.starts_with('@') => elements.pop().map(|a| a[1..].to_string()),
+            _ => None,
+        };
+        let invalid = elements
+            .iter()
+            .any(|e| e.is_empty() || e == "." || e.starts_with('@'))
+            || attribute.as_ref().is_some_and(|a| a.is_empty());
+        if invalid {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Invalid XML field path '{}' for column '{}'",
+                path, column
+            )));
+        }
+        Ok(Self {
+            column: column.to_string(),
+            elements,
+            attribute,
+        })
+    }
+
+    fn attribute(name: &str) -> Self {
+        Self {
+            column: name.to_string(),
+            elements: Vec::new(),
+            attribute: Some(name.to_string()),
+        }
+    }
+
+    fn element(name: &str) -> Self {
+        Self {
+            column: name.to_string(),
+            elements: vec![name.to_string()],
+            attribute: None,
+        }
+    }
+
+    /// Whether this field is read from the element at `relative` below the record
+    fn matches(&self, relative: &[String]) -> bool {
+        relative.len() == self.elements.len()
+            && relative
+                .iter()
+                .zip(&self.elements)
+                .all(|(name, segment)| name_matches(name, segment))
+    }
+}
+
+enum Event {
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+    },
+    End(String),
+    Text(String),
+}
+
+/// Pull parser producing start tags, end tags and text. Comments, processing
+/// instructions and the document type are skipped; self-closing tags produce a
+/// start and an end.
+struct XmlEvents<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    in_tag: bool,
+    pending_end: Option<String>,
+}
+
+impl<R: BufRead> XmlEvents<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            in_tag: false,
+            pending_end: None,
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Option<Event>, VeloxxError> {
+        loop {
+            if let Some(name) = self.pending_end.take() {
+                return Ok(Some(Event::End(name)));
+            }
+            if !self.in_tag {
+                self.buffer.clear();
+                if self.read_until(b'<')? == 0 {
+                    return Ok(None);
+                }
+                if self.buffer.last() == Some(&b'<') {
+                    self.buffer.pop();
+                    self.in_tag = true;
+                }
+                let text = utf8(&self.buffer)?;
+                if !text.trim().is_empty() {
+                    return Ok(Some(Event::Text(decode_entities(text))));
+                }
+                continue;
+            }
+
+            self.in_tag = false;
+            let tag = self.read_tag()?;
+            if let Some(cdata) = tag
+                .strip_prefix("![CDATA[")
+                .and_then(|t| t.strip_suffix("]]"))
+            {
+                return Ok(Some(Event::Text(cdata.to_string())));
+            }
+            if tag.starts_with('!') || tag.starts_with('?') {
+                continue;
+            }
+            if let Some(name) = tag.strip_prefix('/') {
+                return Ok(Some(Event::End(name.trim().to_string())));
+            }
+            let (tag, self_closing) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag.as_str(), false),
+            };
+            let (name, attributes) = parse_start_tag(tag)?;
+            if self_closing {
+                self.pending_end = Some(name.clone());
+            }
+            return Ok(Some(Event::Start { name, attributes }));
+        }
+    }
+
+    /// Reads the rest of a tag after its `<`, returning the text before its `>`
+    fn read_tag(&mut self) -> Result<String, VeloxxError> {
+        self.buffer.clear();
+        loop {
+            if self.read_until(b'>')? == 0 || self.buffer.last() != Some(&b'>') {
+                return Err(VeloxxError::Parsing(
+                    "Unexpected end of XML inside a tag".to_string(),
+                ));
+            }
+            let content = &self.buffer[..self.buffer.len() - 1];
+            let complete = if content.starts_with(b"!--") {
+                content.len() >= 5 && content.ends_with(b"--")
+            } else if content.starts_with(b"![CDATA[") {
+                content.ends_with(b"]]")
+            } else {
+                !in_quotes(content)
+            };
+            if complete {
+                let tag = utf8(content)?.to_string();
+                return Ok(tag);
+            }
+        }
+    }
+
+    fn read_until(&mut self, delimiter: u8) -> Result<usize, VeloxxError> {
+        self.reader
+            .read_until(delimiter, &mut self.buffer)
+            .map_err(|e| VeloxxError::FileIO(format!("Failed to read XML: {}", e)))
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<&str, VeloxxError> {
+    std::str::from_utf8(bytes)
+        .map_err(|e| VeloxxError::Parsing(format!("Invalid UTF-8 in XML: {}", e)))
+}
+
+/// Whether `tag` ends inside a quoted attribute value
+fn in_quotes(tag: &[u8]) -> bool {
+    let mut quote = None;
+    for &byte in tag {
+        match quote {
+            Some(open) if byte == open => quote = None,
+            None if byte == b'"' || byte == b'\'' => quote = Some(byte),
+            _ => {}
+        }
+    }
+    quote.is_some()
+}
+
+/// Splits the inside of a start tag into its name and attributes
+fn parse_start_tag(tag: &str) -> Result<(String, Vec<(String, String)>), VeloxxError> {
+    let invalid = || VeloxxError::Parsing(format!("Malformed XML tag <{}>", tag));
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = &tag[..name_end];
+    if name.is_empty() {
+        return Err(invalid());
+    }
+    let mut attributes = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let (attribute, after) = rest.split_once('=').ok_or_else(invalid)?;
+        let after = after.trim_start();
+        let quote = after.chars().next().filter(|c| *c == '"' || *c == '\'');
+        let quote = quote.ok_or_else(invalid)?;
+        let value_end = after[1..].find(quote).ok_or_else(invalid)? + 1;
+        attributes.push((
+            attribute.trim().to_string(),
+            decode_entities(&after[1..value_end]),
+        ));
+        rest = after[value_end + 1..].trim_start();
+    }
+    Ok((name.to_string(), attributes))
+}
+
+/// Replaces the predefined and numeric character references; others are kept as written
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let replacement = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+        match (entity, replacement) {
+            (Some(entity), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
@@ -3,11 +3,18 @@
 //! This module implements lazy evaluation for DataFrames, allowing for query optimization
 //! and improved performance through techniques like predicate pushdown and projection pushdown.
 
+use crate::dataframe::io::{aggregate_csv_columns, estimate_csv_rows};
+use crate::dataframe::join::{JoinAlgorithm, JoinType};
+use crate::dataframe::manipulation::series_from_values;
 use crate::dataframe::DataFrame;
+use crate::error::{ErrorContext, ResultExt};
+use crate::performance::expression_fusion::ExpressionFusion;
+use crate::performance::memory::MemoryAnalyzer;
 use crate::series::Series;
 use crate::types::Value;
 use crate::VeloxxError;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 pub mod optimizer;
 
@@ -21,6 +28,14 @@ pub enum LogicalPlan {
         projection: Option<Vec<String>>,
         filters: Vec<Expr>,
     },
+    /// Read a CSV file when the plan is executed
+    CsvScan {
+        path: String,
+        projection: Option<Vec<String>>,
+        /// Whole-file aggregates computed while scanning instead of reading the
+        /// columns; set by the optimizer
+        aggregations: Vec<Aggregation>,
+    },
     /// Filter operation
     Filter {
         input: Box<LogicalPlan>,
@@ -36,9 +51,32 @@ pub enum LogicalPlan {
     GroupBy {
         input: Box<LogicalPlan>,
         keys: Vec<String>,
+        /// Keys computed from expressions while grouping, by their name in `keys`
+        computed_keys: Vec<(String, Expr)>,
         aggregations: Vec<Aggregation>,
         schema: HashMap<String, String>,
     },
+    /// Add a column computed from an expression
+    WithColumn {
+        input: Box<LogicalPlan>,
+        name: String,
+        expr: Expr,
+    },
+    /// Sort rows by one or more columns
+    Sort {
+        input: Box<LogicalPlan>,
+        by: Vec<String>,
+        ascending: bool,
+    },
+    /// Join two plans on a shared column
+    Join {
+        left: Box<LogicalPlan>,
+        right: Box<LogicalPlan>,
+        on: String,
+        how: JoinType,
+        /// Chosen by the optimizer from column statistics; `Auto` decides at run time
+        algorithm: JoinAlgorithm,
+    },
 }
 
 /// Represents an expression in a logical plan
@@ -54,6 +92,22 @@ pub enum Expr {
         op: BinaryOperator,
         right: Box<Expr>,
     },
+    /// Logical negation
+    Not(Box<Expr>),
+    /// Number of chars in a string
+    StrLenChars(Box<Expr>),
+    /// Substring of up to `length` chars starting at char `start`
+    StrSliceChars {
+        input: Box<Expr>,
+        start: usize,
+        length: Option<usize>,
+    },
+    /// Base64 text of a binary value
+    Base64Encode(Box<Expr>),
+    /// Bytes decoded from base64 text
+    Base64Decode(Box<Expr>),
+    /// Timestamp rounded down to a multiple of an interval such as `"1d"`
+    DtTruncate { input: Box<Expr>, every: String },
 }
 
 /// Represents a binary operator
@@ -83,6 +137,31 @@ pub enum Aggregation {
     Max(String),
 }
 
+impl Aggregation {
+    /// The `(column, function)` pair understood by `GroupedDataFrame::agg`
+    fn spec(&self) -> (&str, &str) {
+        match self {
+            Aggregation::Sum(col) => (col, "sum"),
+            Aggregation::Mean(col) => (col, "mean"),
+            Aggregation::Count(col) => (col, "count"),
+            Aggregation::Min(col) => (col, "min"),
+            Aggregation::Max(col) => (col, "max"),
+        }
+    }
+}
+
+/// Rows and size of a plan's result, from [`LazyDataFrame::estimate_rows`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowEstimate {
+    /// Estimated number of rows
+    pub rows: usize,
+    /// Size of the scanned data: bytes on disk for file scans, estimated memory
+    /// for in-memory frames
+    pub bytes: u64,
+    /// Whether `rows` is an exact count rather than an estimate
+    pub exact: bool,
+}
+
 /// Lazy DataFrame structure
 #[derive(Debug, Clone)]
 pub struct LazyDataFrame {
@@ -101,6 +180,7 @@ impl LazyDataFrame {
                 Series::Bool(_, _, _) => "bool".to_string(),
                 Series::String(_, _, _) => "string".to_string(),
                 Series::DateTime(_, _, _) => "datetime".to_string(),
+                Series::Binary(_, _, _) => "binary".to_string(),
             };
             schema.insert(name.clone(), dtype);
         }
@@ -115,6 +195,20 @@ impl LazyDataFrame {
         LazyDataFrame { logical_plan }
     }
 
+    /// Lazily read a CSV file; nothing is read until the plan is collected.
+    ///
+    /// Whole-file `sum`, `count`, `min` and `max` aggregates over the scan are
+    /// computed while streaming the file, without materializing the columns.
+    pub fn scan_csv(path: &str) -> Self {
+        LazyDataFrame {
+            logical_plan: LogicalPlan::CsvScan {
+                path: path.to_string(),
+                projection: None,
+                aggregations: vec![],
+            },
+        }
+    }
+
     /// Filter the DataFrame based on a predicate
     pub fn filter(self, predicate: Expr) -> Self {
         let logical_plan = LogicalPlan::Filter {
@@ -160,7 +254,35 @@ impl LazyDataFrame {
 
     /// Group by specific columns
     pub fn group_by(self, keys: Vec<String>) -> LazyGroupBy {
-        LazyGroupBy { input: self, keys }
+        LazyGroupBy {
+            input: self,
+            keys,
+            computed_keys: Vec::new(),
+        }
+    }
+
+    /// Group by named expressions, e.g. `("day", dt_truncate(col("ts"), "1d"))`
+    ///
+    /// The keys are computed while grouping rather than added as columns first;
+    /// a key named after the column it reads replaces that column.
+    pub fn group_by_exprs(self, keys: Vec<(&str, Expr)>) -> LazyGroupBy {
+        let names = keys.iter().map(|(name, _)| name.to_string()).collect();
+        let computed_keys = keys
+            .into_iter()
+            .filter(|(name, expr)| !matches!(expr, Expr::Column(column) if column == name))
+            .map(|(name, expr)| (name.to_string(), expr))
+            .collect();
+        LazyGroupBy {
+            input: self,
+            keys: names,
+            computed_keys,
+        }
+    }
+
+    /// Aggregate the whole frame into a single row, with columns named
+    /// `<column>_<function>` as in grouped aggregation
+    pub fn agg(self, aggregations: Vec<Aggregation>) -> Self {
+        self.group_by(vec![]).agg(aggregations)
     }
 
     /// Collect and execute the lazy plan
@@ -179,8 +301,236 @@ impl LazyDataFrame {
         Self::execute_plan_static(&self.logical_plan)
     }
 
+    /// Add a column computed from an expression
+    ///
+    /// Consecutive `with_column` calls are evaluated together in a single pass
+    /// over the rows when the plan is collected.
+    pub fn with_column(self, name: &str, expr: Expr) -> Self {
+        let logical_plan = LogicalPlan::WithColumn {
+            input: Box::new(self.logical_plan),
+            name: name.to_string(),
+            expr,
+        };
+        LazyDataFrame { logical_plan }
+    }
+
+    /// Sort by the given columns
+    pub fn sort(self, by: Vec<String>, ascending: bool) -> Self {
+        let logical_plan = LogicalPlan::Sort {
+            input: Box::new(self.logical_plan),
+            by,
+            ascending,
+        };
+        LazyDataFrame { logical_plan }
+    }
+
+    /// Join with another lazy DataFrame on a shared column
+    pub fn join(self, other: LazyDataFrame, on: &str, how: JoinType) -> Self {
+        let logical_plan = LogicalPlan::Join {
+            left: Box::new(self.logical_plan),
+            right: Box::new(other.logical_plan),
+            on: on.to_string(),
+            how,
+            algorithm: JoinAlgorithm::Auto,
+        };
+        LazyDataFrame { logical_plan }
+    }
+
+    /// Describe the query plan, optionally after optimization
+    pub fn explain(&self, optimized: bool) -> String {
+        if optimized {
+            let optimizer = optimizer::QueryOptimizer::new();
+            optimizer.optimize(self.logical_plan.clone()).to_string()
+        } else {
+            self.logical_plan.to_string()
+        }
+    }
+
+    /// Optimize and execute the plan like [`LazyDataFrame::collect`], also
+    /// returning a profile with one row per executed operator, in execution
+    /// order (inputs before the operators that consume them).
+    ///
+    /// The profile has the columns:
+    /// - `operator`: the operator as shown by [`LazyDataFrame::explain`]; fused
+    ///   `with_column` chains are a single operator
+    /// - `depth`: nesting in the plan tree, 0 for the final operator
+    /// - `rows_in`: rows read from its inputs, or from the in-memory source for
+    ///   a scan; null for CSV scans
+    /// - `rows_out`: rows produced
+    /// - `time_ms`: wall time spent in the operator itself
+    /// - `total_time_ms`: wall time including its inputs
+    /// - `peak_memory_mb`: estimated size of its input and output frames,
+    ///   which are held at the same time
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    /// use veloxx::lazy::{binary_op, col, lit, BinaryOperator};
+    /// use veloxx::types::Value;
+    ///
+    /// let df = df!("x" => [1, 2, 3, 4]).unwrap();
+    /// let (result, profile) = df
+    ///     .lazy()
+    ///     .filter(binary_op(col("x"), BinaryOperator::Gt, lit(Value::I32(2))))
+    ///     .sort(vec!["x".to_string()], false)
+    ///     .profile()
+    ///     .unwrap();
+    /// assert_eq!(result.row_count(), 2);
+    /// // The filter is pushed into the scan, which sort then reads
+    /// let operators = profile.get_column("operator").unwrap();
+    /// assert!(matches!(operators.get_value(0), Some(Value::String(s)) if s.starts_with("SCAN")));
+    /// assert_eq!(profile.get_column("rows_out").unwrap().get_value(1), Some(Value::I32(2)));
+    /// ```
+    pub fn profile(self) -> Result<(DataFrame, DataFrame), VeloxxError> {
+        let optimizer = optimizer::QueryOptimizer::new();
+        let optimized_plan = optimizer.optimize(self.logical_plan);
+        let mut profile = Some(Profile::default());
+        let df = Self::execute(&optimized_plan, &mut profile)?;
+        let operators = profile.map(|p| p.operators).unwrap_or_default();
+        Ok((df, Profile::to_dataframe(&operators)?))
+    }
+
+    /// Estimates the rows and size of the result without executing the plan.
+    ///
+    /// CSV scans sample the start of the file and a few windows spread through
+    /// it, and scale the average row length found there up to the file size; a
+    /// file of up to 64 KiB is counted exactly. In-memory frames are counted exactly. Filters, keyed group-bys
+    /// and joins are assumed to keep the rows of their (larger) input, so the
+    /// estimate is an upper bound for them rather than an exact count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::df;
+    ///
+    /// let df = df!("x" => [1, 2, 3]).unwrap();
+    /// let estimate = df.lazy().estimate_rows().unwrap();
+    /// assert_eq!(estimate.rows, 3);
+    /// assert!(estimate.exact);
+    /// ```
+    pub fn estimate_rows(&self) -> Result<RowEstimate, VeloxxError> {
+        Self::estimate(&self.logical_plan)
+    }
+
+    fn estimate(plan: &LogicalPlan) -> Result<RowEstimate, VeloxxError> {
+        Ok(match plan {
+            LogicalPlan::DataFrameScan {
+                dataframe, filters, ..
+            } => RowEstimate {
+                rows: dataframe.row_count(),
+                bytes: dataframe
+                    .columns
+                    .values()
+                    .map(MemoryAnalyzer::estimate_series_memory)
+                    .sum::<usize>() as u64,
+                exact: filters.is_empty(),
+            },
+            LogicalPlan::CsvScan {
+                path, aggregations, ..
+            } => {
+                let (rows, exact, bytes) = estimate_csv_rows(path)
+                    .with_context(|| ErrorContext::new().operation("estimate_rows"))?;
+                if aggregations.is_empty() {
+                    RowEstimate { rows, bytes, exact }
+                } else {
+                    RowEstimate {
+                        rows: 1,
+                        bytes,
+                        exact: true,
+                    }
+                }
+            }
+            LogicalPlan::Filter { input, .. } => RowEstimate {
+                exact: false,
+                ..Self::estimate(input)?
+            },
+            LogicalPlan::Projection { input, .. }
+            | LogicalPlan::WithColumn { input, .. }
+            | LogicalPlan::Sort { input, .. } => Self::estimate(input)?,
+            LogicalPlan::GroupBy { input, keys, .. } => {
+                let input = Self::estimate(input)?;
+                if keys.is_empty() {
+                    RowEstimate {
+                        rows: 1,
+                        exact: true,
+                        ..input
+                    }
+                } else {
+                    RowEstimate {
+                        exact: false,
+                        ..input
+                    }
+                }
+            }
+            LogicalPlan::Join { left, right, .. } => {
+                let (left, right) = (Self::estimate(left)?, Self::estimate(right)?);
+                RowEstimate {
+                    rows: left.rows.max(right.rows),
+                    bytes: left.bytes + right.bytes,
+                    exact: false,
+                }
+            }
+        })
+    }
+
     /// Execute a logical plan (static method to avoid borrow issues)
     fn execute_plan_static(plan: &LogicalPlan) -> Result<DataFrame, VeloxxError> {
+        Self::execute(plan, &mut None)
+    }
+
+    /// Execute a plan node, recording it in `profile` when profiling
+    fn execute(
+        plan: &LogicalPlan,
+        profile: &mut Option<Profile>,
+    ) -> Result<DataFrame, VeloxxError> {
+        let Some(p) = profile.as_mut() else {
+            return Self::execute_node(plan, profile);
+        };
+        let (depth, first) = (p.depth, p.operators.len());
+        p.depth += 1;
+        let start = Instant::now();
+        let result = Self::execute_node(plan, profile);
+        let total = start.elapsed();
+        let Some(p) = profile.as_mut() else {
+            return result;
+        };
+        p.depth = depth;
+        let df = result?;
+
+        let inputs: Vec<&OperatorProfile> = p.operators[first..]
+            .iter()
+            .filter(|op| op.depth == depth + 1)
+            .collect();
+        let rows_in = match plan {
+            LogicalPlan::DataFrameScan { dataframe, .. } => Some(dataframe.row_count()),
+            _ if inputs.is_empty() => None,
+            _ => Some(inputs.iter().map(|op| op.rows_out).sum()),
+        };
+        let input_bytes: usize = inputs.iter().map(|op| op.output_bytes).sum();
+        let inputs_time: Duration = inputs.iter().map(|op| op.total).sum();
+        let output_bytes = df
+            .columns
+            .values()
+            .map(MemoryAnalyzer::estimate_series_memory)
+            .sum();
+        p.operators.push(OperatorProfile {
+            operator: operator_label(plan),
+            depth,
+            rows_in,
+            rows_out: df.row_count(),
+            own_time: total.saturating_sub(inputs_time),
+            total,
+            output_bytes,
+            peak_bytes: input_bytes + output_bytes,
+        });
+        Ok(df)
+    }
+
+    fn execute_node(
+        plan: &LogicalPlan,
+        profile: &mut Option<Profile>,
+    ) -> Result<DataFrame, VeloxxError> {
         match plan {
             LogicalPlan::DataFrameScan {
                 dataframe,
@@ -190,30 +540,48 @@ impl LazyDataFrame {
             } => {
                 let mut df = dataframe.clone();
 
-                // Apply filters
-                for _filter in filters {
-                    // Simplified filter application - in a real implementation,
-                    // we would need a proper expression evaluator
-                    // For now, we'll skip filter execution in this basic implementation
+                // Apply filters before the projection, which may drop filter columns
+                for filter in filters {
+                    df = Self::apply_filter(&df, filter)
+                        .with_context(|| ErrorContext::new().operation("scan filter"))?;
                 }
 
-                // Apply projection
                 if let Some(columns) = projection {
-                    df = df.select_columns(columns.clone())?;
+                    df = df
+                        .select_columns(columns.clone())
+                        .with_context(|| ErrorContext::new().operation("scan projection"))?;
                 }
 
                 Ok(df)
             }
-            LogicalPlan::Filter { input, .. } => {
-                let df = Self::execute_plan_static(input)?;
-
-                // Simplified filter application
-                // In a real implementation, we would evaluate the predicate expression
-                // For now, we'll just return the DataFrame as-is
-                Ok(df)
+            LogicalPlan::CsvScan {
+                path,
+                projection,
+                aggregations,
+            } => {
+                if !aggregations.is_empty() {
+                    if let Some(df) = Self::aggregate_csv(path, aggregations)? {
+                        return Ok(df);
+                    }
+                }
+                let mut df = DataFrame::from_csv(path)
+                    .with_context(|| ErrorContext::new().operation("scan_csv"))?;
+                if let Some(columns) = projection {
+                    df = df.select_columns(columns.clone())?;
+                }
+                if aggregations.is_empty() {
+                    Ok(df)
+                } else {
+                    Self::aggregate_all(&df, aggregations)
+                }
+            }
+            LogicalPlan::Filter { input, predicate } => {
+                let df = Self::execute(input, profile)?;
+                Self::apply_filter(&df, predicate)
+                    .with_context(|| ErrorContext::new().operation("filter"))
             }
             LogicalPlan::Projection { input, expr, .. } => {
-                let df = Self::execute_plan_static(input)?;
+                let df = Self::execute(input, profile)?;
 
                 // Extract column names from expressions and select them
                 let mut column_names = Vec::new();
@@ -225,25 +593,292 @@ impl LazyDataFrame {
 
                 if !column_names.is_empty() {
                     df.select_columns(column_names)
+                        .with_context(|| ErrorContext::new().operation("projection"))
                 } else {
                     Ok(df)
                 }
             }
-            LogicalPlan::GroupBy { input, .. } => {
-                let df = Self::execute_plan_static(input)?;
+            LogicalPlan::GroupBy {
+                input,
+                keys,
+                computed_keys,
+                aggregations,
+                ..
+            } => {
+                let df = Self::execute(input, profile)?;
+                if keys.is_empty() {
+                    return Self::aggregate_all(&df, aggregations);
+                }
+                let specs: Vec<(&str, &str)> = aggregations.iter().map(Aggregation::spec).collect();
+                if !computed_keys.is_empty() {
+                    use crate::expressions::Expr as RowExpr;
+                    let key_exprs: Vec<RowExpr> = keys
+                        .iter()
+                        .map(
+                            |key| match computed_keys.iter().find(|(name, _)| name == key) {
+                                Some((_, expr)) => RowExpr::from(expr).alias(key),
+                                None => RowExpr::Column(key.clone()),
+                            },
+                        )
+                        .collect();
+                    return df
+                        .group_by_exprs(&key_exprs)
+                        .and_then(|grouped| grouped.agg(specs))
+                        .with_context(|| ErrorContext::new().operation("group_by"));
+                }
+                df.group_by(keys.clone())
+                    .and_then(|grouped| grouped.agg(specs))
+                    .with_context(|| ErrorContext::new().operation("group_by"))
+            }
+            LogicalPlan::WithColumn { input, name, expr } => {
+                // Fuse a chain of derived columns into a single pass over the rows
+                let mut columns = vec![(name.clone(), expr.into())];
+                let mut input = input;
+                while let LogicalPlan::WithColumn {
+                    input: inner,
+                    name,
+                    expr,
+                } = input.as_ref()
+                {
+                    columns.push((name.clone(), expr.into()));
+                    input = inner;
+                }
+                columns.reverse();
+                let df = Self::execute(input, profile)?;
+                if let [(name, expr)] = columns.as_slice() {
+                    return df.with_column(name, expr).with_context(|| {
+                        ErrorContext::new().operation("with_column").column(name)
+                    });
+                }
+                let names: Vec<&str> = columns.iter().map(|(name, _)| name.as_str()).collect();
+                ExpressionFusion::fused_with_columns(&df, &columns).with_context(|| {
+                    ErrorContext::new()
+                        .operation("with_column")
+                        .column(&names.join(", "))
+                })
+            }
+            LogicalPlan::Sort {
+                input,
+                by,
+                ascending,
+            } => {
+                let df = Self::execute(input, profile)?;
+                df.sort(by.clone(), *ascending)
+                    .with_context(|| ErrorContext::new().operation("sort"))
+            }
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            } => {
+                let left_df = Self::execute(left, profile)?;
+                let right_df = Self::execute(right, profile)?;
+                left_df
+                    .join_with_algorithm(&right_df, on, *how, *algorithm)
+                    .with_context(|| ErrorContext::new().operation("join").column(on))
+            }
+        }
+    }
 
-                // For now, we'll just return the original DataFrame
-                // A full implementation would perform group-by and aggregation operations
-                Ok(df)
+    /// Aggregate every row of `df` into a one-row frame
+    fn aggregate_all(
+        df: &DataFrame,
+        aggregations: &[Aggregation],
+    ) -> Result<DataFrame, VeloxxError> {
+        let mut columns = HashMap::new();
+        for aggregation in aggregations {
+            let (column, function) = aggregation.spec();
+            let series = df
+                .get_column(column)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+            let value = match aggregation {
+                Aggregation::Count(_) => Value::I32(series.count() as i32),
+                // Aggregates over no values are null rather than an error
+                _ if series.count() == 0 => Value::Null,
+                Aggregation::Sum(_) => series.sum()?,
+                Aggregation::Mean(_) => series.mean()?,
+                Aggregation::Min(_) => series.min()?,
+                Aggregation::Max(_) => series.max()?,
+            };
+            let name = format!("{}_{}", column, function);
+            columns.insert(name.clone(), series_from_values(&name, vec![value]));
+        }
+        DataFrame::new(columns).with_context(|| ErrorContext::new().operation("agg"))
+    }
+
+    /// Compute whole-file aggregates while streaming a CSV file; `None` when a
+    /// column is not numeric and the file has to be read instead
+    fn aggregate_csv(
+        path: &str,
+        aggregations: &[Aggregation],
+    ) -> Result<Option<DataFrame>, VeloxxError> {
+        let mut names: Vec<&str> = Vec::new();
+        for aggregation in aggregations {
+            let (column, _) = aggregation.spec();
+            if !names.contains(&column) {
+                names.push(column);
             }
         }
+        let Some(running) = aggregate_csv_columns(path, &names)
+            .with_context(|| ErrorContext::new().operation("scan_csv"))?
+        else {
+            return Ok(None);
+        };
+        let mut columns = HashMap::new();
+        for aggregation in aggregations {
+            let (column, function) = aggregation.spec();
+            let running = &running[names.iter().position(|n| *n == column).unwrap_or(0)];
+            let value = match aggregation {
+                Aggregation::Count(_) => Value::I32(running.count as i32),
+                _ if running.count == 0 => Value::Null,
+                Aggregation::Sum(_) => running.sum(),
+                Aggregation::Min(_) => running.min(),
+                Aggregation::Max(_) => running.max(),
+                Aggregation::Mean(_) => return Ok(None),
+            };
+            let name = format!("{}_{}", column, function);
+            columns.insert(name.clone(), series_from_values(&name, vec![value]));
+        }
+        DataFrame::new(columns).map(Some)
+    }
+
+    /// Keep the rows for which `predicate` evaluates to `true`
+    fn apply_filter(df: &DataFrame, predicate: &Expr) -> Result<DataFrame, VeloxxError> {
+        let predicate: crate::expressions::Expr = predicate.into();
+        let mut indices = Vec::new();
+        for row in 0..df.row_count() {
+            match predicate
+                .evaluate(df, row)
+                .with_context(|| ErrorContext::new().row(row))?
+            {
+                Value::Bool(true) => indices.push(row),
+                Value::Bool(false) | Value::Null => {}
+                other => {
+                    return Err(VeloxxError::DataTypeMismatch(format!(
+                        "Filter predicate must evaluate to a boolean, got {:?}",
+                        other
+                    )))
+                }
+            }
+        }
+        df.filter_by_indices(&indices)
+    }
+}
+
+/// Measurements of one executed operator
+struct OperatorProfile {
+    operator: String,
+    depth: usize,
+    rows_in: Option<usize>,
+    rows_out: usize,
+    own_time: Duration,
+    total: Duration,
+    output_bytes: usize,
+    peak_bytes: usize,
+}
+
+/// Operators measured so far by [`LazyDataFrame::profile`]
+#[derive(Default)]
+struct Profile {
+    operators: Vec<OperatorProfile>,
+    /// Depth of the operator being executed
+    depth: usize,
+}
+
+impl Profile {
+    fn to_dataframe(operators: &[OperatorProfile]) -> Result<DataFrame, VeloxxError> {
+        let ms = |d: Duration| Some(d.as_secs_f64() * 1000.0);
+        let column = |name: &str, series: Series| (name.to_string(), series);
+        let columns = [
+            column(
+                "operator",
+                Series::new_string(
+                    "operator",
+                    operators
+                        .iter()
+                        .map(|op| Some(op.operator.clone()))
+                        .collect(),
+                ),
+            ),
+            column(
+                "depth",
+                Series::new_i32(
+                    "depth",
+                    operators.iter().map(|op| Some(op.depth as i32)).collect(),
+                ),
+            ),
+            column(
+                "rows_in",
+                Series::new_i32(
+                    "rows_in",
+                    operators
+                        .iter()
+                        .map(|op| op.rows_in.map(|r| r as i32))
+                        .collect(),
+                ),
+            ),
+            column(
+                "rows_out",
+                Series::new_i32(
+                    "rows_out",
+                    operators
+                        .iter()
+                        .map(|op| Some(op.rows_out as i32))
+                        .collect(),
+                ),
+            ),
+            column(
+                "time_ms",
+                Series::new_f64(
+                    "time_ms",
+                    operators.iter().map(|op| ms(op.own_time)).collect(),
+                ),
+            ),
+            column(
+                "total_time_ms",
+                Series::new_f64(
+                    "total_time_ms",
+                    operators.iter().map(|op| ms(op.total)).collect(),
+                ),
+            ),
+            column(
+                "peak_memory_mb",
+                Series::new_f64(
+                    "peak_memory_mb",
+                    operators
+                        .iter()
+                        .map(|op| Some(op.peak_bytes as f64 / (1024.0 * 1024.0)))
+                        .collect(),
+                ),
+            ),
+        ];
+        DataFrame::new(columns.into_iter().collect())
+    }
+}
+
+/// One-line description of a plan node, as in its `explain` output
+fn operator_label(plan: &LogicalPlan) -> String {
+    let mut names = Vec::new();
+    let mut node = plan;
+    while let LogicalPlan::WithColumn { input, name, .. } = node {
+        names.push(format!("\"{}\"", name));
+        node = input;
+    }
+    if names.len() > 1 {
+        names.reverse();
+        return format!("WITH COLUMNS [{}] (fused)", names.join(", "));
     }
+    let text = plan.to_string();
+    text.lines().next().unwrap_or_default().to_string()
 }
 
 /// Lazy GroupBy structure
 pub struct LazyGroupBy {
     input: LazyDataFrame,
     keys: Vec<String>,
+    computed_keys: Vec<(String, Expr)>,
 }
 
 impl LazyGroupBy {
@@ -283,6 +918,7 @@ impl LazyGroupBy {
         let logical_plan = LogicalPlan::GroupBy {
             input: Box::new(self.input.logical_plan),
             keys: self.keys,
+            computed_keys: self.computed_keys,
             aggregations,
             schema,
         };
@@ -301,6 +937,14 @@ pub fn lit(value: Value) -> Expr {
     Expr::Literal(value)
 }
 
+/// Helper function to create a datetime truncation expression, e.g. to `"1d"`
+pub fn dt_truncate(expr: Expr, every: &str) -> Expr {
+    Expr::DtTruncate {
+        input: Box::new(expr),
+        every: every.to_string(),
+    }
+}
+
 /// Helper function to create a binary operation expression
 pub fn binary_op(left: Expr, op: BinaryOperator, right: Expr) -> Expr {
     Expr::BinaryOp {
@@ -309,3 +953,247 @@ pub fn binary_op(left: Expr, op: BinaryOperator, right: Expr) -> Expr {
         right: Box::new(right),
     }
 }
+
+impl From<&Expr> for crate::expressions::Expr {
+    fn from(expr: &Expr) -> Self {
+        use crate::expressions::Expr as RowExpr;
+        match expr {
+            Expr::Column(name) => RowExpr::Column(name.clone()),
+            Expr::Literal(value) => RowExpr::Literal(value.clone()),
+            Expr::Not(inner) => RowExpr::Not(Box::new(inner.as_ref().into())),
+            Expr::StrLenChars(inner) => RowExpr::StrLenChars(Box::new(inner.as_ref().into())),
+            Expr::StrSliceChars {
+                input,
+                start,
+                length,
+            } => RowExpr::StrSliceChars(Box::new(input.as_ref().into()), *start, *length),
+            Expr::Base64Encode(inner) => RowExpr::Base64Encode(Box::new(inner.as_ref().into())),
+            Expr::Base64Decode(inner) => RowExpr::Base64Decode(Box::new(inner.as_ref().into())),
+            Expr::DtTruncate { input, every } => {
+                RowExpr::DtTruncate(Box::new(input.as_ref().into()), every.clone())
+            }
+            Expr::BinaryOp { left, op, right } => {
+                let l = Box::new(left.as_ref().into());
+                let r = Box::new(right.as_ref().into());
+                match op {
+                    BinaryOperator::Eq => RowExpr::Equals(l, r),
+                    BinaryOperator::Neq => RowExpr::NotEquals(l, r),
+                    BinaryOperator::Lt => RowExpr::LessThan(l, r),
+                    BinaryOperator::LtEq => RowExpr::LessThanOrEqual(l, r),
+                    BinaryOperator::Gt => RowExpr::GreaterThan(l, r),
+                    BinaryOperator::GtEq => RowExpr::GreaterThanOrEqual(l, r),
+                    BinaryOperator::And => RowExpr::And(l, r),
+                    BinaryOperator::Or => RowExpr::Or(l, r),
+                    BinaryOperator::Add => RowExpr::Add(l, r),
+                    BinaryOperator::Subtract => RowExpr::Subtract(l, r),
+                    BinaryOperator::Multiply => RowExpr::Multiply(l, r),
+                    BinaryOperator::Divide => RowExpr::Divide(l, r),
+                }
+            }
+        }
+    }
+}
+
+impl From<&crate::expressions::Expr> for Expr {
+    fn from(expr: &crate::expressions::Expr) -> Self {
+        use crate::expressions::Expr as RowExpr;
+        let bin = |l: &RowExpr, op: BinaryOperator, r: &RowExpr| binary_op(l.into(), op, r.into());
+        match expr {
+            RowExpr::Column(name) => Expr::Column(name.clone()),
+            RowExpr::Literal(value) => Expr::Literal(value.clone()),
+            RowExpr::Not(inner) => Expr::Not(Box::new(inner.as_ref().into())),
+            RowExpr::Add(l, r) => bin(l, BinaryOperator::Add, r),
+            RowExpr::Subtract(l, r) => bin(l, BinaryOperator::Subtract, r),
+            RowExpr::Multiply(l, r) => bin(l, BinaryOperator::Multiply, r),
+            RowExpr::Divide(l, r) => bin(l, BinaryOperator::Divide, r),
+            RowExpr::Equals(l, r) => bin(l, BinaryOperator::Eq, r),
+            RowExpr::NotEquals(l, r) => bin(l, BinaryOperator::Neq, r),
+            RowExpr::GreaterThan(l, r) => bin(l, BinaryOperator::Gt, r),
+            RowExpr::LessThan(l, r) => bin(l, BinaryOperator::Lt, r),
+            RowExpr::GreaterThanOrEqual(l, r) => bin(l, BinaryOperator::GtEq, r),
+            RowExpr::LessThanOrEqual(l, r) => bin(l, BinaryOperator::LtEq, r),
+            RowExpr::And(l, r) => bin(l, BinaryOperator::And, r),
+            RowExpr::Or(l, r) => bin(l, BinaryOperator::Or, r),
+            RowExpr::StrLenChars(inner) => Expr::StrLenChars(Box::new(inner.as_ref().into())),
+            RowExpr::StrSliceChars(inner, start, length) => Expr::StrSliceChars {
+                input: Box::new(inner.as_ref().into()),
+                start: *start,
+                length: *length,
+            },
+            RowExpr::Base64Encode(inner) => Expr::Base64Encode(Box::new(inner.as_ref().into())),
+            RowExpr::Base64Decode(inner) => Expr::Base64Decode(Box::new(inner.as_ref().into())),
+            RowExpr::DtTruncate(inner, every) => Expr::DtTruncate {
+                input: Box::new(inner.as_ref().into()),
+                every: every.clone(),
+            },
+            RowExpr::Alias(inner, _) => inner.as_ref().into(),
+        }
+    }
+}
+
+impl std::fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            BinaryOperator::Eq => "==",
+            BinaryOperator::Neq => "!=",
+            BinaryOperator::Lt => "<",
+            BinaryOperator::LtEq => "<=",
+            BinaryOperator::Gt => ">",
+            BinaryOperator::GtEq => ">=",
+            BinaryOperator::And => "AND",
+            BinaryOperator::Or => "OR",
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Column(name) => write!(f, "col(\"{}\")", name),
+            Expr::Literal(value) => write!(f, "{}", value),
+            Expr::BinaryOp { left, op, right } => write!(f, "({} {} {})", left, op, right),
+            Expr::Not(inner) => write!(f, "NOT {}", inner),
+            Expr::StrLenChars(inner) => write!(f, "str_len_chars({})", inner),
+            Expr::StrSliceChars {
+                input,
+                start,
+                length: Some(length),
+            } => write!(f, "str_slice_chars({}, {}, {})", input, start, length),
+            Expr::StrSliceChars { input, start, .. } => {
+                write!(f, "str_slice_chars({}, {})", input, start)
+            }
+            Expr::Base64Encode(inner) => write!(f, "base64_encode({})", inner),
+            Expr::Base64Decode(inner) => write!(f, "base64_decode({})", inner),
+            Expr::DtTruncate { input, every } => write!(f, "dt_truncate({}, \"{}\")", input, every),
+        }
+    }
+}
+
+impl std::fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (col, func) = self.spec();
+        write!(f, "{}(col(\"{}\"))", func, col)
+    }
+}
+
+impl LogicalPlan {
+    fn fmt_indented(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        let join = |items: Vec<String>| items.join(", ");
+        match self {
+            LogicalPlan::DataFrameScan {
+                dataframe,
+                projection,
+                filters,
+                ..
+            } => {
+                let mut names: Vec<&String> = dataframe.column_names();
+                names.sort();
+                write!(
+                    f,
+                    "{}SCAN [{}]",
+                    indent,
+                    join(names.into_iter().cloned().collect())
+                )?;
+                if let Some(projection) = projection {
+                    write!(f, "; PROJECT [{}]", projection.join(", "))?;
+                }
+                if !filters.is_empty() {
+                    let filters = filters.iter().map(|e| e.to_string()).collect();
+                    write!(f, "; FILTER {}", join(filters))?;
+                }
+                writeln!(f)
+            }
+            LogicalPlan::CsvScan {
+                path,
+                projection,
+                aggregations,
+            } => {
+                write!(f, "{}CSV SCAN \"{}\"", indent, path)?;
+                if let Some(projection) = projection {
+                    write!(f, "; PROJECT [{}]", projection.join(", "))?;
+                }
+                if !aggregations.is_empty() {
+                    let aggs = aggregations.iter().map(|a| a.to_string()).collect();
+                    write!(f, "; AGG [{}]", join(aggs))?;
+                }
+                writeln!(f)
+            }
+            LogicalPlan::Filter { input, predicate } => {
+                writeln!(f, "{}FILTER {}", indent, predicate)?;
+                input.fmt_indented(f, depth + 1)
+            }
+            LogicalPlan::Projection { input, expr, .. } => {
+                let expr = expr.iter().map(|e| e.to_string()).collect();
+                writeln!(f, "{}SELECT [{}]", indent, join(expr))?;
+                input.fmt_indented(f, depth + 1)
+            }
+            LogicalPlan::GroupBy {
+                input,
+                keys,
+                computed_keys,
+                aggregations,
+                ..
+            } => {
+                let keys = keys
+                    .iter()
+                    .map(
+                        |key| match computed_keys.iter().find(|(name, _)| name == key) {
+                            Some((_, expr)) => format!("{} = {}", key, expr),
+                            None => key.clone(),
+                        },
+                    )
+                    .collect();
+                let aggs = aggregations.iter().map(|a| a.to_string()).collect();
+                writeln!(
+                    f,
+                    "{}GROUP BY [{}] AGG [{}]",
+                    indent,
+                    join(keys),
+                    join(aggs)
+                )?;
+                input.fmt_indented(f, depth + 1)
+            }
+            LogicalPlan::WithColumn { input, name, expr } => {
+                writeln!(f, "{}WITH COLUMN \"{}\" = {}", indent, name, expr)?;
+                input.fmt_indented(f, depth + 1)
+            }
+            LogicalPlan::Sort {
+                input,
+                by,
+                ascending,
+            } => {
+                let order = if *ascending { "ASC" } else { "DESC" };
+                writeln!(f, "{}SORT BY [{}] {}", indent, by.join(", "), order)?;
+                input.fmt_indented(f, depth + 1)
+            }
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            } => {
+                write!(f, "{}{:?} JOIN ON \"{}\"", indent, how, on)?;
+                if *algorithm != JoinAlgorithm::Auto {
+                    write!(f, " USING {:?}", algorithm)?;
+                }
+                writeln!(f)?;
+                left.fmt_indented(f, depth + 1)?;
+                right.fmt_indented(f, depth + 1)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for LogicalPlan {
+    /// Renders the plan as an indented tree, one operator per line
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
@@ -1,9 +1,13 @@
 //! Query optimization module for lazy evaluation
 //!
 //! This module implements query optimization rules like predicate pushdown
-//! and projection pushdown to improve performance of lazy DataFrames.
+//! and projection pushdown to improve performance of lazy DataFrames, and
+//! orders joins using column statistics gathered from the scanned frames.
 
-use crate::lazy::{Expr, LogicalPlan};
+use crate::dataframe::join::{JoinAlgorithm, JoinType, BROADCAST_MAX_ROWS};
+use crate::lazy::{Aggregation, BinaryOperator, Expr, LogicalPlan};
+use crate::types::Value;
+use std::collections::HashSet;
 
 /// Query optimizer that applies various optimization rules
 pub struct QueryOptimizer;
@@ -23,7 +27,9 @@ impl QueryOptimizer {
     /// Optimize a logical plan
     pub fn optimize(&self, plan: LogicalPlan) -> LogicalPlan {
         let plan = self.predicate_pushdown(plan);
-        self.projection_pushdown(plan)
+        let plan = self.join_ordering(plan);
+        let plan = self.projection_pushdown(plan);
+        self.aggregate_pushdown(plan)
     }
 
     /// Push predicates down towards scan nodes
@@ -93,17 +99,47 @@ impl QueryOptimizer {
                 projection,
                 filters,
             },
+            scan @ LogicalPlan::CsvScan { .. } => scan,
             LogicalPlan::GroupBy {
                 input,
                 keys,
+                computed_keys,
                 aggregations,
                 schema,
             } => LogicalPlan::GroupBy {
                 input: Box::new(self.predicate_pushdown(*input)),
                 keys,
+                computed_keys,
                 aggregations,
                 schema,
             },
+            LogicalPlan::WithColumn { input, name, expr } => LogicalPlan::WithColumn {
+                input: Box::new(self.predicate_pushdown(*input)),
+                name,
+                expr,
+            },
+            LogicalPlan::Sort {
+                input,
+                by,
+                ascending,
+            } => LogicalPlan::Sort {
+                input: Box::new(self.predicate_pushdown(*input)),
+                by,
+                ascending,
+            },
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            } => LogicalPlan::Join {
+                left: Box::new(self.predicate_pushdown(*left)),
+                right: Box::new(self.predicate_pushdown(*right)),
+                on,
+                how,
+                algorithm,
+            },
         }
     }
 
@@ -141,6 +177,27 @@ impl QueryOptimizer {
                             filters,
                         }
                     }
+                    LogicalPlan::CsvScan {
+                        path,
+                        projection: _,
+                        aggregations,
+                    } if aggregations.is_empty()
+                        && expr.iter().all(|e| matches!(e, Expr::Column(_))) =>
+                    {
+                        // Only the selected columns are kept after reading
+                        let column_names = expr
+                            .iter()
+                            .filter_map(|e| match e {
+                                Expr::Column(name) => Some(name.clone()),
+                                _ => None,
+                            })
+                            .collect();
+                        LogicalPlan::CsvScan {
+                            path,
+                            projection: Some(column_names),
+                            aggregations,
+                        }
+                    }
                     _ => {
                         // For other node types, keep the projection where it is
                         LogicalPlan::Projection {
@@ -166,18 +223,496 @@ impl QueryOptimizer {
                 projection,
                 filters,
             },
+            scan @ LogicalPlan::CsvScan { .. } => scan,
             LogicalPlan::GroupBy {
                 input,
                 keys,
+                computed_keys,
                 aggregations,
                 schema,
             } => LogicalPlan::GroupBy {
                 input: Box::new(self.projection_pushdown(*input)),
                 keys,
+                computed_keys,
+                aggregations,
+                schema,
+            },
+            LogicalPlan::WithColumn { input, name, expr } => LogicalPlan::WithColumn {
+                input: Box::new(self.projection_pushdown(*input)),
+                name,
+                expr,
+            },
+            LogicalPlan::Sort {
+                input,
+                by,
+                ascending,
+            } => LogicalPlan::Sort {
+                input: Box::new(self.projection_pushdown(*input)),
+                by,
+                ascending,
+            },
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            } => LogicalPlan::Join {
+                left: Box::new(self.projection_pushdown(*left)),
+                right: Box::new(self.projection_pushdown(*right)),
+                on,
+                how,
+                algorithm,
+            },
+        }
+    }
+
+    /// Compute whole-frame sum/count/min/max aggregates of a CSV file while it
+    /// is scanned instead of after reading it
+    #[allow(clippy::only_used_in_recursion)]
+    fn aggregate_pushdown(&self, plan: LogicalPlan) -> LogicalPlan {
+        match plan {
+            LogicalPlan::GroupBy {
+                input,
+                keys,
+                computed_keys,
+                aggregations,
+                schema,
+            } => match self.aggregate_pushdown(*input) {
+                LogicalPlan::CsvScan {
+                    path,
+                    projection,
+                    aggregations: scan_aggregations,
+                } if keys.is_empty()
+                    && scan_aggregations.is_empty()
+                    && aggregations.iter().all(|a| {
+                        let (column, _) = a.spec();
+                        !matches!(a, Aggregation::Mean(_))
+                            && projection
+                                .as_ref()
+                                .is_none_or(|p| p.iter().any(|c| c == column))
+                    }) =>
+                {
+                    LogicalPlan::CsvScan {
+                        path,
+                        projection,
+                        aggregations,
+                    }
+                }
+                input => LogicalPlan::GroupBy {
+                    input: Box::new(input),
+                    keys,
+                    computed_keys,
+                    aggregations,
+                    schema,
+                },
+            },
+            LogicalPlan::Filter { input, predicate } => LogicalPlan::Filter {
+                input: Box::new(self.aggregate_pushdown(*input)),
+                predicate,
+            },
+            LogicalPlan::Projection {
+                input,
+                expr,
+                schema,
+            } => LogicalPlan::Projection {
+                input: Box::new(self.aggregate_pushdown(*input)),
+                expr,
+                schema,
+            },
+            LogicalPlan::WithColumn { input, name, expr } => LogicalPlan::WithColumn {
+                input: Box::new(self.aggregate_pushdown(*input)),
+                name,
+                expr,
+            },
+            LogicalPlan::Sort {
+                input,
+                by,
+                ascending,
+            } => LogicalPlan::Sort {
+                input: Box::new(self.aggregate_pushdown(*input)),
+                by,
+                ascending,
+            },
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            } => LogicalPlan::Join {
+                left: Box::new(self.aggregate_pushdown(*left)),
+                right: Box::new(self.aggregate_pushdown(*right)),
+                on,
+                how,
+                algorithm,
+            },
+            scan @ (LogicalPlan::DataFrameScan { .. } | LogicalPlan::CsvScan { .. }) => scan,
+        }
+    }
+
+    /// Reorder chains of inner joins so the most selective joins run first,
+    /// and pick a build side for every join from estimated input sizes
+    #[allow(clippy::only_used_in_recursion)]
+    fn join_ordering(&self, plan: LogicalPlan) -> LogicalPlan {
+        match plan {
+            LogicalPlan::Join {
+                how: JoinType::Inner,
+                ..
+            } => {
+                // Flatten the left-deep chain of inner joins
+                let mut rights = Vec::new();
+                let mut node = plan;
+                let base = loop {
+                    match node {
+                        LogicalPlan::Join {
+                            left,
+                            right,
+                            on,
+                            how: JoinType::Inner,
+                            algorithm,
+                        } => {
+                            rights.push((self.join_ordering(*right), on, algorithm));
+                            node = *left;
+                        }
+                        other => break self.join_ordering(other),
+                    }
+                };
+                rights.reverse();
+                if rights.len() > 1 && chain_is_reorderable(&base, &rights) {
+                    // Joins that shrink (or grow) the intermediate result least go first
+                    let factors: Vec<f64> = rights
+                        .iter()
+                        .map(|(right, on, _)| {
+                            let distinct = distinct_count(&base, on)
+                                .max(distinct_count(right, on))
+                                .max(1.0);
+                            estimate_rows(right) / distinct
+                        })
+                        .collect();
+                    let mut order: Vec<usize> = (0..rights.len()).collect();
+                    order.sort_by(|&a, &b| factors[a].total_cmp(&factors[b]));
+                    let mut slots: Vec<Option<_>> = rights.into_iter().map(Some).collect();
+                    rights = order.into_iter().filter_map(|i| slots[i].take()).collect();
+                }
+                rights
+                    .into_iter()
+                    .fold(base, |left, (right, on, algorithm)| {
+                        with_build_side(LogicalPlan::Join {
+                            left: Box::new(left),
+                            right: Box::new(right),
+                            on,
+                            how: JoinType::Inner,
+                            algorithm,
+                        })
+                    })
+            }
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            } => with_build_side(LogicalPlan::Join {
+                left: Box::new(self.join_ordering(*left)),
+                right: Box::new(self.join_ordering(*right)),
+                on,
+                how,
+                algorithm,
+            }),
+            LogicalPlan::Filter { input, predicate } => LogicalPlan::Filter {
+                input: Box::new(self.join_ordering(*input)),
+                predicate,
+            },
+            LogicalPlan::Projection {
+                input,
+                expr,
+                schema,
+            } => LogicalPlan::Projection {
+                input: Box::new(self.join_ordering(*input)),
+                expr,
+                schema,
+            },
+            LogicalPlan::GroupBy {
+                input,
+                keys,
+                computed_keys,
                 aggregations,
                 schema,
+            } => LogicalPlan::GroupBy {
+                input: Box::new(self.join_ordering(*input)),
+                keys,
+                computed_keys,
+                aggregations,
+                schema,
+            },
+            LogicalPlan::WithColumn { input, name, expr } => LogicalPlan::WithColumn {
+                input: Box::new(self.join_ordering(*input)),
+                name,
+                expr,
+            },
+            LogicalPlan::Sort {
+                input,
+                by,
+                ascending,
+            } => LogicalPlan::Sort {
+                input: Box::new(self.join_ordering(*input)),
+                by,
+                ascending,
             },
+            scan @ (LogicalPlan::DataFrameScan { .. } | LogicalPlan::CsvScan { .. }) => scan,
+        }
+    }
+}
+
+/// Row estimate for sources that cannot be sized without reading them
+const UNKNOWN_SOURCE_ROWS: f64 = 1_000_000.0;
+
+/// Broadcast the side estimated to be small, unless an algorithm was already chosen
+fn with_build_side(plan: LogicalPlan) -> LogicalPlan {
+    match plan {
+        LogicalPlan::Join {
+            left,
+            right,
+            on,
+            how,
+            algorithm: JoinAlgorithm::Auto,
+        } => {
+            let left_rows = estimate_rows(&left);
+            let right_rows = estimate_rows(&right);
+            let small = BROADCAST_MAX_ROWS as f64;
+            let algorithm = if right_rows <= small && right_rows < left_rows {
+                JoinAlgorithm::Broadcast
+            } else if left_rows <= small && left_rows < right_rows {
+                JoinAlgorithm::BroadcastLeft
+            } else {
+                JoinAlgorithm::Auto
+            };
+            LogicalPlan::Join {
+                left,
+                right,
+                on,
+                how,
+                algorithm,
+            }
+        }
+        other => other,
+    }
+}
+
+/// Reordering a chain is only safe when every join key comes from the base
+/// and no two inputs share a non-key column (which would be suffixed differently)
+fn chain_is_reorderable(
+    base: &LogicalPlan,
+    rights: &[(LogicalPlan, String, JoinAlgorithm)],
+) -> bool {
+    let Some(mut seen) = output_columns(base) else {
+        return false;
+    };
+    let base_columns = seen.clone();
+    for (right, on, _) in rights {
+        let Some(columns) = output_columns(right) else {
+            return false;
+        };
+        if !base_columns.contains(on) || !columns.contains(on) {
+            return false;
+        }
+        for column in columns.into_iter().filter(|c| c != on) {
+            if !seen.insert(column) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Column names produced by a plan, when they can be known without executing it
+fn output_columns(plan: &LogicalPlan) -> Option<HashSet<String>> {
+    match plan {
+        LogicalPlan::DataFrameScan {
+            dataframe,
+            projection,
+            ..
+        } => Some(match projection {
+            Some(projection) => projection.iter().cloned().collect(),
+            None => dataframe.column_names().into_iter().cloned().collect(),
+        }),
+        LogicalPlan::CsvScan {
+            projection,
+            aggregations,
+            ..
+        } => {
+            if aggregations.is_empty() {
+                projection.as_ref().map(|p| p.iter().cloned().collect())
+            } else {
+                Some(
+                    aggregations
+                        .iter()
+                        .map(|a| {
+                            let (column, function) = a.spec();
+                            format!("{}_{}", column, function)
+                        })
+                        .collect(),
+                )
+            }
+        }
+        LogicalPlan::Filter { input, .. } | LogicalPlan::Sort { input, .. } => {
+            output_columns(input)
+        }
+        LogicalPlan::Projection { input, expr, .. } => {
+            let names: HashSet<String> = expr
+                .iter()
+                .filter_map(|e| match e {
+                    Expr::Column(name) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect();
+            if names.is_empty() {
+                output_columns(input)
+            } else {
+                Some(names)
+            }
+        }
+        LogicalPlan::WithColumn { input, name, .. } => {
+            let mut columns = output_columns(input)?;
+            columns.insert(name.clone());
+            Some(columns)
+        }
+        LogicalPlan::Join {
+            left, right, on, ..
+        } => {
+            let mut columns = output_columns(left)?;
+            for column in output_columns(right)? {
+                if column != *on && !columns.insert(column) {
+                    // Clashing names get a suffix we do not track here
+                    return None;
+                }
+            }
+            Some(columns)
+        }
+        LogicalPlan::GroupBy { .. } => None,
+    }
+}
+
+/// Estimated number of rows a plan produces
+fn estimate_rows(plan: &LogicalPlan) -> f64 {
+    match plan {
+        LogicalPlan::CsvScan { aggregations, .. } if !aggregations.is_empty() => 1.0,
+        LogicalPlan::CsvScan { .. } => UNKNOWN_SOURCE_ROWS,
+        LogicalPlan::DataFrameScan { filters, .. } => {
+            filters.iter().fold(unfiltered_rows(plan), |rows, filter| {
+                rows * selectivity(filter, plan)
+            })
+        }
+        LogicalPlan::Filter { input, predicate } => {
+            estimate_rows(input) * selectivity(predicate, input)
+        }
+        LogicalPlan::Projection { input, .. }
+        | LogicalPlan::WithColumn { input, .. }
+        | LogicalPlan::Sort { input, .. } => estimate_rows(input),
+        LogicalPlan::GroupBy { input, keys, .. } => {
+            let rows = estimate_rows(input);
+            keys.iter()
+                .map(|key| distinct_count(input, key))
+                .product::<f64>()
+                .min(rows)
+        }
+        LogicalPlan::Join {
+            left,
+            right,
+            on,
+            how,
+            ..
+        } => {
+            let left_rows = estimate_rows(left);
+            let right_rows = estimate_rows(right);
+            let distinct = distinct_count(left, on)
+                .max(distinct_count(right, on))
+                .max(1.0);
+            let matched = left_rows * right_rows / distinct;
+            match how {
+                JoinType::Inner => matched,
+                JoinType::Left => matched.max(left_rows),
+                JoinType::Right => matched.max(right_rows),
+            }
+        }
+    }
+}
+
+/// Estimated number of distinct values of `column` in a plan's output
+fn distinct_count(plan: &LogicalPlan, column: &str) -> f64 {
+    let distinct = match plan {
+        LogicalPlan::DataFrameScan { dataframe, .. } => match dataframe.get_column(column) {
+            Some(series) => series.stats().n_unique as f64,
+            None => unfiltered_rows(plan),
+        },
+        LogicalPlan::Filter { input, .. }
+        | LogicalPlan::Projection { input, .. }
+        | LogicalPlan::Sort { input, .. } => distinct_count(input, column),
+        LogicalPlan::WithColumn { input, name, .. } if name != column => {
+            distinct_count(input, column)
+        }
+        LogicalPlan::GroupBy { input, keys, .. } if keys.iter().any(|k| k == column) => {
+            distinct_count(input, column)
+        }
+        LogicalPlan::Join { left, right, .. } => {
+            match (output_columns(left), output_columns(right)) {
+                (Some(columns), _) if columns.contains(column) => distinct_count(left, column),
+                (_, Some(columns)) if columns.contains(column) => distinct_count(right, column),
+                _ => estimate_rows(plan),
+            }
+        }
+        _ => estimate_rows(plan),
+    };
+    distinct.min(unfiltered_rows(plan)).max(1.0)
+}
+
+/// Row count before a scan's own filters, so estimating them does not recurse
+fn unfiltered_rows(plan: &LogicalPlan) -> f64 {
+    match plan {
+        LogicalPlan::DataFrameScan { dataframe, .. } => dataframe.row_count() as f64,
+        _ => estimate_rows(plan),
+    }
+}
+
+/// Estimated fraction of rows of `input` for which `predicate` holds
+fn selectivity(predicate: &Expr, input: &LogicalPlan) -> f64 {
+    match predicate {
+        Expr::BinaryOp { left, op, right } => {
+            let column = match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(name), Expr::Literal(_)) | (Expr::Literal(_), Expr::Column(name)) => {
+                    Some(name.as_str())
+                }
+                _ => None,
+            };
+            let equality = || match column {
+                Some(name) => 1.0 / distinct_count(input, name),
+                None => 0.1,
+            };
+            match op {
+                BinaryOperator::Eq => equality(),
+                BinaryOperator::Neq => 1.0 - equality(),
+                BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq => 1.0 / 3.0,
+                BinaryOperator::And => selectivity(left, input) * selectivity(right, input),
+                BinaryOperator::Or => {
+                    let (a, b) = (selectivity(left, input), selectivity(right, input));
+                    a + b - a * b
+                }
+                _ => 0.5,
+            }
         }
+        Expr::Not(inner) => 1.0 - selectivity(inner, input),
+        Expr::Literal(Value::Bool(true)) => 1.0,
+        Expr::Literal(_) => 0.0,
+        Expr::Column(_)
+        | Expr::StrLenChars(_)
+        | Expr::StrSliceChars { .. }
+        | Expr::Base64Encode(_)
+        | Expr::Base64Decode(_)
+        | Expr::DtTruncate { .. } => 0.5,
     }
 }
 
@@ -27,12 +27,20 @@ pub mod conditions;
 pub mod data_quality;
 pub mod dataframe;
 pub mod error;
+mod instrument;
 pub mod io;
 #[cfg(feature = "ml")]
 pub mod ml;
 pub mod performance;
+pub mod pipeline;
 pub mod query;
+#[cfg(feature = "recipes")]
+pub mod recipe;
 pub mod series;
+pub mod stats;
+pub mod streaming;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
 #[cfg(feature = "visualization")]
 pub mod visualization;
@@ -49,6 +57,8 @@ pub mod advanced_io;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod audit;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod bench;
+#[cfg(not(target_arch = "wasm32"))]
 // pub mod distributed; // Remove duplicate
 #[cfg(all(not(target_arch = "wasm32"), feature = "python"))]
 pub mod python_bindings;
@@ -0,0 +1,179 @@
+// src/performance/adaptive_filter.rs
+//! Adaptive evaluation of AND-ed filter conditions.
+//!
+//! The conjuncts of a condition are ranked using their selectivity on a
+//! small sample of rows and a rough per-row cost, then evaluated one after
+//! another. Each conjunct only looks at the rows that are still selected in
+//! the mask, so a selective predicate run first spares the later ones most
+//! of their work.
+
+use crate::conditions::Condition;
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+use std::cmp::Ordering;
+
+/// Number of evenly spaced rows sampled to estimate a conjunct's selectivity
+pub const SAMPLE_ROWS: usize = 1024;
+
+/// A conjunct together with the estimates used to order it
+#[derive(Debug, Clone)]
+pub struct RankedConjunct<'a> {
+    pub condition: &'a Condition,
+    /// Fraction of sampled rows that passed
+    pub selectivity: f64,
+    /// Relative cost of evaluating one row
+    pub cost: f64,
+}
+
+impl RankedConjunct<'_> {
+    /// Cost per row eliminated; the cheapest way to shrink the mask goes first
+    fn rank(&self) -> f64 {
+        self.cost / (1.0 - self.selectivity).max(f64::EPSILON)
+    }
+}
+
+/// Splits nested `And`s into their conjuncts, left to right
+pub fn conjuncts(condition: &Condition) -> Vec<&Condition> {
+    match condition {
+        Condition::And(left, right) => {
+            let mut parts = conjuncts(left);
+            parts.extend(conjuncts(right));
+            parts
+        }
+        other => vec![other],
+    }
+}
+
+/// Orders conjuncts by sampled selectivity and cost, most useful first.
+///
+/// Conjuncts that fail on the sample (for example by comparing against a
+/// null) are treated as passing every row, so they are ranked last.
+pub fn rank_conjuncts<'a>(df: &DataFrame, parts: &[&'a Condition]) -> Vec<RankedConjunct<'a>> {
+    let step = (df.row_count() / SAMPLE_ROWS).max(1);
+    let sample: Vec<usize> = (0..df.row_count())
+        .step_by(step)
+        .take(SAMPLE_ROWS)
+        .collect();
+    let mut ranked: Vec<RankedConjunct<'a>> = parts
+        .iter()
+        .map(|condition| {
+            let passed = sample
+                .iter()
+                .map(|&row| condition.evaluate(df, row))
+                .try_fold(0usize, |passed, result| {
+                    result.map(|ok| passed + ok as usize)
+                });
+            let selectivity = match passed {
+                Ok(passed) if !sample.is_empty() => passed as f64 / sample.len() as f64,
+                _ => 1.0,
+            };
+            RankedConjunct {
+                condition,
+                selectivity,
+                cost: cost(df, condition),
+            }
+        })
+        .collect();
+    // Stable, so ties keep the order the caller wrote them in
+    ranked.sort_by(|a, b| a.rank().partial_cmp(&b.rank()).unwrap_or(Ordering::Equal));
+    ranked
+}
+
+/// Evaluates `condition` into a row mask, running its conjuncts adaptively.
+///
+/// Conjuncts may be evaluated in any order, so a row is only guaranteed to be
+/// checked against a conjunct while every conjunct run before it still holds.
+pub fn adaptive_mask(df: &DataFrame, condition: &Condition) -> Result<Vec<bool>, VeloxxError> {
+    let mut mask = vec![true; df.row_count()];
+    for part in rank_conjuncts(df, &conjuncts(condition)) {
+        refine_mask(df, part.condition, &mut mask)?;
+        if !mask.contains(&true) {
+            break;
+        }
+    }
+    Ok(mask)
+}
+
+/// Clears the mask for selected rows that fail `condition`; cleared rows are skipped
+fn refine_mask(
+    df: &DataFrame,
+    condition: &Condition,
+    mask: &mut [bool],
+) -> Result<(), VeloxxError> {
+    let leaf = match condition {
+        Condition::Eq(column, value) => Some((column, value, Ordering::Equal)),
+        Condition::Gt(column, value) => Some((column, value, Ordering::Greater)),
+        Condition::Lt(column, value) => Some((column, value, Ordering::Less)),
+        _ => None,
+    };
+    let series = leaf.and_then(|(column, _, _)| df.get_column(column));
+    for (row, selected) in mask.iter_mut().enumerate() {
+        if !*selected {
+            continue;
+        }
+        let fast = match (leaf, series) {
+            // `Condition` only orders numbers; other types go through `evaluate`
+            (Some((_, value, wanted)), Some(series))
+                if wanted == Ordering::Equal
+                    || matches!(series, Series::I32(..) | Series::F64(..)) =>
+            {
+                compare_cell(series, row, value).map(|ordering| ordering == wanted)
+            }
+            _ => None,
+        };
+        *selected = match fast {
+            Some(result) => result,
+            // Nulls, mixed types and compound conditions keep their usual semantics
+            None => condition.evaluate(df, row)?,
+        };
+    }
+    Ok(())
+}
+
+/// Compares a valid cell with a value of the same type without building a `Value`
+fn compare_cell(series: &Series, row: usize, value: &Value) -> Option<Ordering> {
+    match (series, value) {
+        (Series::I32(_, data, validity), Value::I32(v)) if validity[row] => Some(data[row].cmp(v)),
+        (Series::F64(_, data, validity), Value::F64(v)) if validity[row] => {
+            if data[row].to_bits() == v.to_bits() {
+                Some(Ordering::Equal)
+            } else {
+                // Bitwise-unequal floats (NaN, or 0.0 against -0.0) are never `Eq`
+                data[row]
+                    .partial_cmp(v)
+                    .filter(|ordering| *ordering != Ordering::Equal)
+            }
+        }
+        (Series::String(_, data, validity), Value::String(v)) if validity[row] => {
+            Some(data[row].as_str().cmp(v.as_str()))
+        }
+        (Series::Bool(_, data, validity), Value::Bool(v)) if validity[row] => {
+            Some(data[row].cmp(v))
+        }
+        (Series::DateTime(_, data, validity), Value::DateTime(v)) if validity[row] => {
+            Some(data[row].cmp(v))
+        }
+        _ => None,
+    }
+}
+
+/// Rough per-row cost: typed comparisons are cheap, strings and
+/// row-by-row evaluation of compound conditions less so
+fn cost(df: &DataFrame, condition: &Condition) -> f64 {
+    match condition {
+        Condition::Eq(column, _) | Condition::Gt(column, _) | Condition::Lt(column, _) => {
+            match df.get_column(column) {
+                Some(Series::String(..)) => 2.0,
+                _ => 1.0,
+            }
+        }
+        Condition::And(left, right) | Condition::Or(left, right) => {
+            2.0 + cost(df, left) + cost(df, right)
+        }
+        Condition::Not(inner) => 1.0 + cost(df, inner),
+        // Parses an address per row
+        Condition::InSubnet(..) => 3.0,
+    }
+}
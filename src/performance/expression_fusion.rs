@@ -1,10 +1,71 @@
 // src/performance/expression_fusion.rs
+use crate::dataframe::manipulation::series_from_values;
+use crate::dataframe::DataFrame;
+use crate::expressions::Expr;
+use crate::types::Value;
 use crate::VeloxxError;
 
+/// Rows evaluated together by [`ExpressionFusion::fused_with_columns`]
+pub const FUSION_CHUNK_ROWS: usize = 4096;
+
 /// Fused expression operations for better performance
 pub struct ExpressionFusion;
 
 impl ExpressionFusion {
+    /// Adds several derived columns in one pass over the rows.
+    ///
+    /// Rows are processed in parallel chunks, and every expression is evaluated
+    /// for a row before moving on to the next, so later expressions can refer
+    /// to columns derived earlier in `columns`. The result matches chaining
+    /// [`DataFrame::with_column`] calls without copying the frame once per column.
+    pub fn fused_with_columns(
+        df: &DataFrame,
+        columns: &[(String, Expr)],
+    ) -> Result<DataFrame, VeloxxError> {
+        for (i, (name, _)) in columns.iter().enumerate() {
+            if df.get_column(name).is_some() || columns[..i].iter().any(|(n, _)| n == name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Column '{name}' already exists."
+                )));
+            }
+        }
+
+        use rayon::prelude::*;
+        let row_count = df.row_count();
+        let chunks: Vec<Vec<Vec<Value>>> = (0..row_count.div_ceil(FUSION_CHUNK_ROWS))
+            .into_par_iter()
+            .map(|chunk| {
+                let rows =
+                    chunk * FUSION_CHUNK_ROWS..((chunk + 1) * FUSION_CHUNK_ROWS).min(row_count);
+                let mut values = vec![Vec::with_capacity(rows.len()); columns.len()];
+                let mut derived: Vec<(&str, Value)> = Vec::with_capacity(columns.len());
+                for row in rows {
+                    derived.clear();
+                    for (i, (name, expr)) in columns.iter().enumerate() {
+                        let value = expr.evaluate_with(df, row, &derived)?;
+                        values[i].push(value.clone());
+                        derived.push((name, value));
+                    }
+                }
+                Ok(values)
+            })
+            .collect::<Result<_, VeloxxError>>()?;
+
+        let mut values = vec![Vec::with_capacity(row_count); columns.len()];
+        for chunk in chunks {
+            for (column, chunk_values) in values.iter_mut().zip(chunk) {
+                column.extend(chunk_values);
+            }
+        }
+        let mut result = df.clone();
+        for ((name, _), values) in columns.iter().zip(values) {
+            result
+                .columns
+                .insert(name.clone(), series_from_values(name, values));
+        }
+        Ok(result)
+    }
+
     /// Fused add and multiply: (a + b) * c
     pub fn fused_add_mul_f64(
         a: &[f64],
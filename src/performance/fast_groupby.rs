@@ -144,6 +144,7 @@ impl FastGroupBy {
                 if group_bitmap[i] && value_bitmap[i] {
                     final_min = final_min.min(group_values[i]);
                     final_max = final_max.max(group_values[i]);
+                    count += 1;
                 }
             }
 
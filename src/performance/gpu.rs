@@ -0,0 +1,544 @@
+//! Optional wgpu compute backend for large numeric columns (feature `gpu`).
+//!
+//! `Series::add`, `Series::multiply`, `Series::sum`, `Series::mean` and the fast
+//! comparison path of `DataFrame::filter` hand `I32`/`F64` columns of at least
+//! [`gpu_threshold`] elements to the kernels below. Every entry point returns `None`
+//! when no suitable device is present, the column is too large for a single storage
+//! buffer, or the device reports an error, and the caller then runs its CPU code.
+//!
+//! `F64` kernels need an adapter with `SHADER_F64`; `I32` kernels run everywhere.
+//! Software rasterizers (e.g. llvmpipe) are slower than the CPU paths and are
+//! ignored unless `VELOXX_GPU_ALLOW_SOFTWARE` is set.
+
+use crate::performance::specialized_structures::BitPackedArray;
+use crate::performance::vectorized_filter::ComparisonOp;
+use crate::series::Series;
+use crate::types::Value;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use wgpu::util::DeviceExt;
+
+/// Default minimum column length routed to the GPU
+pub const DEFAULT_GPU_THRESHOLD: usize = 1 << 20;
+
+const WORKGROUP_SIZE: u32 = 256;
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_GPU_THRESHOLD);
+static CONTEXT: OnceLock<Option<GpuContext>> = OnceLock::new();
+
+/// Sets the minimum column length routed to the GPU; `usize::MAX` disables it.
+pub fn set_gpu_threshold(len: usize) {
+    THRESHOLD.store(len, Ordering::Relaxed);
+}
+
+/// Returns the minimum column length routed to the GPU.
+pub fn gpu_threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Returns `true` if a usable GPU adapter was found.
+pub fn gpu_available() -> bool {
+    context().is_some()
+}
+
+/// Returns the name of the adapter in use, if any.
+pub fn gpu_adapter_name() -> Option<String> {
+    context().map(|ctx| ctx.adapter_name.clone())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GpuBinaryOp {
+    Add,
+    Mul,
+}
+
+impl GpuBinaryOp {
+    fn entry_point(self) -> &'static str {
+        match self {
+            GpuBinaryOp::Add => "add",
+            GpuBinaryOp::Mul => "mul",
+        }
+    }
+}
+
+fn comparison_entry_point(op: ComparisonOp) -> &'static str {
+    match op {
+        ComparisonOp::Gt => "gt",
+        ComparisonOp::Gte => "ge",
+        ComparisonOp::Lt => "lt",
+        ComparisonOp::Lte => "le",
+        ComparisonOp::Eq => "eq",
+        ComparisonOp::Ne => "ne",
+    }
+}
+
+/// Element types the kernels are compiled for
+trait GpuElement: bytemuck::Pod + Default {
+    const WGSL_TYPE: &'static str;
+    fn shaders(ctx: &GpuContext) -> Option<&Shaders>;
+    fn combine(a: Self, b: Self) -> Self;
+}
+
+impl GpuElement for i32 {
+    const WGSL_TYPE: &'static str = "i32";
+    fn shaders(ctx: &GpuContext) -> Option<&Shaders> {
+        Some(&ctx.i32_shaders)
+    }
+    fn combine(a: Self, b: Self) -> Self {
+        // WGSL integer arithmetic wraps, so partial sums are combined the same way.
+        a.wrapping_add(b)
+    }
+}
+
+impl GpuElement for f64 {
+    const WGSL_TYPE: &'static str = "f64";
+    fn shaders(ctx: &GpuContext) -> Option<&Shaders> {
+        ctx.f64_shaders.as_ref()
+    }
+    fn combine(a: Self, b: Self) -> Self {
+        a + b
+    }
+}
+
+struct Shaders {
+    elementwise: wgpu::ShaderModule,
+    compare: wgpu::ShaderModule,
+    reduce: wgpu::ShaderModule,
+}
+
+struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    adapter_name: String,
+    i32_shaders: Shaders,
+    f64_shaders: Option<Shaders>,
+}
+
+fn context() -> Option<&'static GpuContext> {
+    CONTEXT.get_or_init(GpuContext::new).as_ref()
+}
+
+const INDEX_FN: &str = "
+fn global_index(wid: vec3<u32>, nwg: vec3<u32>, lid: u32) -> u32 {
+    return (wid.y * nwg.x + wid.x) * 256u + lid;
+}
+";
+
+fn elementwise_source(ty: &str) -> String {
+    let mut src = format!(
+        "alias T = {ty};
+@group(0) @binding(0) var<storage, read> lhs: array<T>;
+@group(0) @binding(1) var<storage, read> rhs: array<T>;
+@group(0) @binding(2) var<storage, read_write> result: array<T>;
+{INDEX_FN}"
+    );
+    for (name, op) in [("add", "+"), ("mul", "*")] {
+        src.push_str(&format!(
+            "
+@compute @workgroup_size(256)
+fn {name}(@builtin(workgroup_id) wid: vec3<u32>, @builtin(num_workgroups) nwg: vec3<u32>,
+          @builtin(local_invocation_index) lid: u32) {{
+    let i = global_index(wid, nwg, lid);
+    if (i < arrayLength(&result)) {{
+        result[i] = lhs[i] {op} rhs[i];
+    }}
+}}
+"
+        ));
+    }
+    src
+}
+
+fn compare_source(ty: &str) -> String {
+    let mut src = format!(
+        "alias T = {ty};
+@group(0) @binding(0) var<storage, read> values: array<T>;
+@group(0) @binding(1) var<storage, read> scalar: array<T>;
+@group(0) @binding(2) var<storage, read_write> mask: array<u32>;
+{INDEX_FN}"
+    );
+    for (name, op) in [
+        ("gt", ">"),
+        ("ge", ">="),
+        ("lt", "<"),
+        ("le", "<="),
+        ("eq", "=="),
+        ("ne", "!="),
+    ] {
+        src.push_str(&format!(
+            "
+@compute @workgroup_size(256)
+fn {name}(@builtin(workgroup_id) wid: vec3<u32>, @builtin(num_workgroups) nwg: vec3<u32>,
+          @builtin(local_invocation_index) lid: u32) {{
+    let i = global_index(wid, nwg, lid);
+    if (i < arrayLength(&mask)) {{
+        mask[i] = select(0u, 1u, values[i] {op} scalar[0]);
+    }}
+}}
+"
+        ));
+    }
+    src
+}
+
+fn reduce_source(ty: &str) -> String {
+    format!(
+        "alias T = {ty};
+@group(0) @binding(0) var<storage, read> values: array<T>;
+@group(0) @binding(1) var<storage, read_write> partials: array<T>;
+var<workgroup> scratch: array<T, 256>;
+{INDEX_FN}
+@compute @workgroup_size(256)
+fn sum(@builtin(workgroup_id) wid: vec3<u32>, @builtin(num_workgroups) nwg: vec3<u32>,
+       @builtin(local_invocation_index) lid: u32) {{
+    let i = global_index(wid, nwg, lid);
+    var v = T();
+    if (i < arrayLength(&values)) {{
+        v = values[i];
+    }}
+    scratch[lid] = v;
+    workgroupBarrier();
+    for (var stride = 128u; stride > 0u; stride = stride >> 1u) {{
+        if (lid < stride) {{
+            scratch[lid] = scratch[lid] + scratch[lid + stride];
+        }}
+        workgroupBarrier();
+    }}
+    if (lid == 0u) {{
+        partials[wid.y * nwg.x + wid.x] = scratch[0];
+    }}
+}}
+"
+    )
+}
+
+impl Shaders {
+    fn compile(device: &wgpu::Device, ty: &str) -> Self {
+        let module = |label: &str, source: String| {
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(label),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            })
+        };
+        Shaders {
+            elementwise: module("veloxx-elementwise", elementwise_source(ty)),
+            compare: module("veloxx-compare", compare_source(ty)),
+            reduce: module("veloxx-reduce", reduce_source(ty)),
+        }
+    }
+}
+
+impl GpuContext {
+    fn new() -> Option<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))
+        .ok()?;
+        let info = adapter.get_info();
+        if info.device_type == wgpu::DeviceType::Cpu
+            && std::env::var_os("VELOXX_GPU_ALLOW_SOFTWARE").is_none()
+        {
+            return None;
+        }
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+        {
+            return None;
+        }
+
+        let required_features = adapter.features() & wgpu::Features::SHADER_F64;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("veloxx"),
+            required_features,
+            required_limits: adapter.limits(),
+            ..Default::default()
+        }))
+        .ok()?;
+
+        let i32_shaders = Shaders::compile(&device, i32::WGSL_TYPE);
+        let f64_shaders = required_features
+            .contains(wgpu::Features::SHADER_F64)
+            .then(|| Shaders::compile(&device, f64::WGSL_TYPE));
+        Some(GpuContext {
+            device,
+            queue,
+            adapter_name: info.name,
+            i32_shaders,
+            f64_shaders,
+        })
+    }
+
+    fn fits(&self, bytes: usize) -> bool {
+        let limits = self.device.limits();
+        bytes > 0
+            && bytes as u64 <= limits.max_storage_buffer_binding_size as u64
+            && bytes as u64 <= limits.max_buffer_size
+    }
+
+    /// Splits `len` invocations into a 2D grid of workgroups within the dispatch limit.
+    fn grid(&self, len: usize) -> (u32, u32) {
+        let groups = len.div_ceil(WORKGROUP_SIZE as usize) as u32;
+        let max = self.device.limits().max_compute_workgroups_per_dimension;
+        let x = groups.min(max);
+        (x, groups.div_ceil(x))
+    }
+
+    fn storage(&self, contents: &[u8]) -> wgpu::Buffer {
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents,
+                usage: wgpu::BufferUsages::STORAGE,
+            })
+    }
+
+    fn output(&self, size: usize) -> wgpu::Buffer {
+        self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: size as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Runs one dispatch of `entry_point` over `inputs` and reads `output` back.
+    /// Any device error (validation, out of memory, lost device) yields `None`.
+    fn run<T: bytemuck::Pod>(
+        &self,
+        module: &wgpu::ShaderModule,
+        entry_point: &str,
+        inputs: &[&wgpu::Buffer],
+        output: &wgpu::Buffer,
+        len: usize,
+    ) -> Option<Vec<T>> {
+        self.device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+        self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+        let pipeline = self
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: None,
+                module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        let entries: Vec<wgpu::BindGroupEntry> = inputs
+            .iter()
+            .chain(std::iter::once(&output))
+            .enumerate()
+            .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                binding: binding as u32,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &entries,
+        });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: output.size(),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let (x, y) = self.grid(len);
+            pass.dispatch_workgroups(x, y, 1);
+        }
+        encoder.copy_buffer_to_buffer(output, 0, &staging, 0, output.size());
+        self.queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = sender.send(result);
+            });
+        let polled = self.device.poll(wgpu::PollType::Wait).is_ok();
+
+        let validation = pollster::block_on(self.device.pop_error_scope());
+        let out_of_memory = pollster::block_on(self.device.pop_error_scope());
+        if !polled || validation.is_some() || out_of_memory.is_some() {
+            return None;
+        }
+        receiver.recv().ok()?.ok()?;
+        let view = staging.slice(..).get_mapped_range();
+        let values = bytemuck::cast_slice::<u8, T>(&view).to_vec();
+        drop(view);
+        staging.unmap();
+        Some(values)
+    }
+}
+
+/// Computes `lhs op rhs` elementwise, or `None` if the GPU cannot run it.
+fn binary<T: GpuElement>(lhs: &[T], rhs: &[T], op: GpuBinaryOp) -> Option<Vec<T>> {
+    let ctx = context()?;
+    let shaders = T::shaders(ctx)?;
+    if lhs.len() != rhs.len() || !ctx.fits(std::mem::size_of_val(lhs)) {
+        return None;
+    }
+    let a = ctx.storage(bytemuck::cast_slice(lhs));
+    let b = ctx.storage(bytemuck::cast_slice(rhs));
+    let out = ctx.output(std::mem::size_of_val(lhs));
+    ctx.run(
+        &shaders.elementwise,
+        op.entry_point(),
+        &[&a, &b],
+        &out,
+        lhs.len(),
+    )
+}
+
+/// Evaluates `value op scalar` for every element, or `None` if the GPU cannot run it.
+fn compare<T: GpuElement>(values: &[T], scalar: T, op: ComparisonOp) -> Option<Vec<bool>> {
+    let ctx = context()?;
+    let shaders = T::shaders(ctx)?;
+    if !ctx.fits(values.len() * std::mem::size_of::<u32>().max(std::mem::size_of::<T>())) {
+        return None;
+    }
+    let input = ctx.storage(bytemuck::cast_slice(values));
+    let scalar = ctx.storage(bytemuck::bytes_of(&scalar));
+    let out = ctx.output(values.len() * std::mem::size_of::<u32>());
+    let mask: Vec<u32> = ctx.run(
+        &shaders.compare,
+        comparison_entry_point(op),
+        &[&input, &scalar],
+        &out,
+        values.len(),
+    )?;
+    Some(mask.into_iter().map(|m| m != 0).collect())
+}
+
+/// Sums `values`, or returns `None` if the GPU cannot run it.
+///
+/// Partial sums are combined in a different order than on the CPU, so `f64`
+/// results may differ in the last bits.
+fn sum<T: GpuElement>(values: &[T]) -> Option<T> {
+    let ctx = context()?;
+    let shaders = T::shaders(ctx)?;
+    if !ctx.fits(std::mem::size_of_val(values)) {
+        return None;
+    }
+    let (x, y) = ctx.grid(values.len());
+    let input = ctx.storage(bytemuck::cast_slice(values));
+    let out = ctx.output((x * y) as usize * std::mem::size_of::<T>());
+    let partials: Vec<T> = ctx.run(&shaders.reduce, "sum", &[&input], &out, values.len())?;
+    Some(partials.into_iter().fold(T::default(), T::combine))
+}
+
+fn above_threshold(len: usize) -> bool {
+    len > 0 && len >= gpu_threshold()
+}
+
+/// Replaces null slots with zero so they do not contribute to a sum.
+fn zero_nulls<'a, T: GpuElement>(values: &'a [T], bitmap: &[bool]) -> std::borrow::Cow<'a, [T]> {
+    if bitmap.iter().all(|&b| b) {
+        std::borrow::Cow::Borrowed(values)
+    } else {
+        values
+            .iter()
+            .zip(bitmap)
+            .map(|(&v, &b)| if b { v } else { T::default() })
+            .collect()
+    }
+}
+
+fn masked_binary<T: GpuElement>(
+    lhs: &[T],
+    lhs_bitmap: &[bool],
+    rhs: &[T],
+    rhs_bitmap: &[bool],
+    op: GpuBinaryOp,
+) -> Option<(Vec<T>, Vec<bool>)> {
+    let mut values = binary(lhs, rhs, op)?;
+    let bitmap: Vec<bool> = lhs_bitmap
+        .iter()
+        .zip(rhs_bitmap)
+        .map(|(&a, &b)| a && b)
+        .collect();
+    for (v, &valid) in values.iter_mut().zip(&bitmap) {
+        if !valid {
+            *v = T::default();
+        }
+    }
+    Some((values, bitmap))
+}
+
+/// GPU path of the same-typed `I32`/`F64` cases of `Series::add` and `Series::multiply`.
+pub(crate) fn series_binary(lhs: &Series, rhs: &Series, op: GpuBinaryOp) -> Option<Series> {
+    if lhs.len() != rhs.len() || !above_threshold(lhs.len()) {
+        return None;
+    }
+    match (lhs, rhs) {
+        (Series::I32(name, a, a_bitmap), Series::I32(_, b, b_bitmap)) => {
+            let (values, bitmap) = masked_binary(a, a_bitmap, b, b_bitmap, op)?;
+            Some(Series::I32(name.clone(), values, bitmap))
+        }
+        (Series::F64(name, a, a_bitmap), Series::F64(_, b, b_bitmap)) => {
+            let (values, bitmap) = masked_binary(a, a_bitmap, b, b_bitmap, op)?;
+            Some(Series::F64(name.clone(), values, bitmap))
+        }
+        _ => None,
+    }
+}
+
+/// GPU path of `Series::sum`.
+pub(crate) fn series_sum(series: &Series) -> Option<Value> {
+    if !above_threshold(series.len()) {
+        return None;
+    }
+    match series {
+        // I32 sums stay on the CPU: WGSL has no 64-bit integers, so the shader
+        // would wrap on overflow where `Series::sum` widens to `F64`
+        Series::F64(_, values, bitmap) => sum(&zero_nulls(values, bitmap)).map(Value::F64),
+        _ => None,
+    }
+}
+
+/// GPU path of `Series::mean`; `None` also when every value is null, leaving the
+/// error to the CPU path.
+pub(crate) fn series_mean(series: &Series) -> Option<Value> {
+    let count = series.count();
+    if count == 0 {
+        return None;
+    }
+    match series_sum(series)? {
+        Value::F64(total) => Some(Value::F64(total / count as f64)),
+        _ => None,
+    }
+}
+
+/// GPU path of the `I32`/`F64` filter masks built by `VectorizedFilter`.
+pub(crate) fn series_mask(
+    series: &Series,
+    comparison_value: &Value,
+    op: ComparisonOp,
+) -> Option<BitPackedArray> {
+    if !above_threshold(series.len()) {
+        return None;
+    }
+    let (matches, bitmap) = match (series, comparison_value) {
+        (Series::I32(_, values, bitmap), Value::I32(v)) => (compare(values, *v, op)?, bitmap),
+        (Series::F64(_, values, bitmap), Value::F64(v)) => (compare(values, *v, op)?, bitmap),
+        _ => return None,
+    };
+    let mut mask = BitPackedArray::new(matches.len());
+    for (hit, &valid) in matches.into_iter().zip(bitmap) {
+        mask.push(hit && valid);
+    }
+    Some(mask)
+}
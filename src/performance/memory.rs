@@ -33,6 +33,13 @@ impl MemoryAnalyzer {
             Series::DateTime(name, values, _) => {
                 name.len() + values.len() * std::mem::size_of::<Option<i64>>()
             }
+            Series::Binary(name, values, _) => {
+                name.len()
+                    + values
+                        .iter()
+                        .map(|v| v.len() + std::mem::size_of::<Option<Vec<u8>>>())
+                        .sum::<usize>()
+            }
         }
     }
 
@@ -2,6 +2,7 @@ pub mod simd_string;
 #[cfg(all(feature = "simd", not(target_arch = "wasm32")))]
 pub use simd_string::*;
 /// Performance optimization utilities
+pub mod adaptive_filter;
 pub mod advanced_memory_pool;
 pub mod advanced_parallel;
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
@@ -11,6 +12,8 @@ pub mod expression_fusion;
 pub mod fast_filter;
 pub mod fast_groupby;
 pub mod global_aggregate;
+#[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+pub mod gpu;
 pub mod memory;
 pub mod memory_compression;
 pub mod memory_pool;
@@ -35,3 +35,193 @@ pub fn simd_eq_str(a: &str, b: &str) -> bool {
 pub fn simd_eq_str(a: &str, b: &str) -> bool {
     a == b
 }
+
+/// SSE2 kernels; SSE2 is part of the x86_64 baseline, so no runtime detection is needed.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    use std::arch::x86_64::*;
+
+    /// Appends `bytes` to `out` with ASCII letters mapped to one case. Returns `false`
+    /// as soon as a non-ASCII byte is seen, leaving `out` incomplete.
+    pub(super) fn convert_ascii_case(bytes: &[u8], out: &mut Vec<u8>, upper: bool) -> bool {
+        let (first, last) = if upper { (b'a', b'z') } else { (b'A', b'Z') };
+        let mut chunks = bytes.chunks_exact(16);
+        // SAFETY: unaligned loads/stores of 16-byte chunks and stack buffers.
+        unsafe {
+            let below = _mm_set1_epi8((first - 1) as i8);
+            let above = _mm_set1_epi8((last + 1) as i8);
+            let case_bit = _mm_set1_epi8(0x20);
+            for chunk in &mut chunks {
+                let v = _mm_loadu_si128(chunk.as_ptr() as *const __m128i);
+                if _mm_movemask_epi8(v) != 0 {
+                    return false;
+                }
+                // Signed compares are exact here because every byte is below 0x80.
+                let letters = _mm_and_si128(_mm_cmpgt_epi8(v, below), _mm_cmplt_epi8(v, above));
+                let converted = _mm_xor_si128(v, _mm_and_si128(letters, case_bit));
+                let mut buf = [0u8; 16];
+                _mm_storeu_si128(buf.as_mut_ptr() as *mut __m128i, converted);
+                out.extend_from_slice(&buf);
+            }
+        }
+        for &b in chunks.remainder() {
+            if !b.is_ascii() {
+                return false;
+            }
+            out.push(if upper {
+                b.to_ascii_uppercase()
+            } else {
+                b.to_ascii_lowercase()
+            });
+        }
+        true
+    }
+
+    pub(super) fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i + 16 <= a.len() {
+            // SAFETY: both slices have at least 16 bytes left at `i`.
+            let equal = unsafe {
+                let x = _mm_loadu_si128(a.as_ptr().add(i) as *const __m128i);
+                let y = _mm_loadu_si128(b.as_ptr().add(i) as *const __m128i);
+                _mm_movemask_epi8(_mm_cmpeq_epi8(x, y)) == 0xFFFF
+            };
+            if !equal {
+                return false;
+            }
+            i += 16;
+        }
+        a[i..] == b[i..]
+    }
+
+    /// Byte offset of the first occurrence of a non-empty `needle`; the haystack must
+    /// hold at least `needle.len() + 15` bytes.
+    ///
+    /// Each 16-byte block is screened for positions where both the first and the last
+    /// byte of the needle match, and only those candidates are compared in full. The
+    /// final block is shifted back to end at the last possible match position.
+    pub(super) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        let last = needle.len() - 1;
+        let end = haystack.len() - last;
+        debug_assert!(end >= 16);
+        // SAFETY: every block start is at most `end - 16`, so both loads stay in bounds.
+        unsafe {
+            let first_byte = _mm_set1_epi8(needle[0] as i8);
+            let last_byte = _mm_set1_epi8(needle[last] as i8);
+            let candidates = |start: usize| {
+                let head = _mm_loadu_si128(haystack.as_ptr().add(start) as *const __m128i);
+                let tail = _mm_loadu_si128(haystack.as_ptr().add(start + last) as *const __m128i);
+                _mm_movemask_epi8(_mm_and_si128(
+                    _mm_cmpeq_epi8(head, first_byte),
+                    _mm_cmpeq_epi8(tail, last_byte),
+                )) as u32
+            };
+            let check = |start: usize, mut mask: u32| {
+                while mask != 0 {
+                    let pos = start + mask.trailing_zeros() as usize;
+                    if eq_bytes(&haystack[pos..pos + needle.len()], needle) {
+                        return Some(pos);
+                    }
+                    mask &= mask - 1;
+                }
+                None
+            };
+            let mut block = 0;
+            while block + 16 <= end {
+                let found = check(block, candidates(block));
+                if found.is_some() {
+                    return found;
+                }
+                block += 16;
+            }
+            if block < end {
+                // Overlap the final block with the previous one, skipping screened positions.
+                let start = end - 16;
+                return check(start, candidates(start) & (u32::MAX << (block - start)));
+            }
+        }
+        None
+    }
+}
+
+/// Lowercases `s`, with a vectorized path for ASCII strings; same result as `str::to_lowercase`.
+pub fn simd_to_lowercase(s: &str) -> String {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        let mut out = Vec::with_capacity(s.len());
+        if sse2::convert_ascii_case(s.as_bytes(), &mut out, false) {
+            // SAFETY: the input was entirely ASCII and case mapping keeps it ASCII.
+            return unsafe { String::from_utf8_unchecked(out) };
+        }
+    }
+    s.to_lowercase()
+}
+
+/// Uppercases `s`, with a vectorized path for ASCII strings; same result as `str::to_uppercase`.
+pub fn simd_to_uppercase(s: &str) -> String {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        let mut out = Vec::with_capacity(s.len());
+        if sse2::convert_ascii_case(s.as_bytes(), &mut out, true) {
+            // SAFETY: the input was entirely ASCII and case mapping keeps it ASCII.
+            return unsafe { String::from_utf8_unchecked(out) };
+        }
+    }
+    s.to_uppercase()
+}
+
+pub fn simd_starts_with(haystack: &str, prefix: &str) -> bool {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        haystack.len() >= prefix.len()
+            && sse2::eq_bytes(&haystack.as_bytes()[..prefix.len()], prefix.as_bytes())
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        haystack.starts_with(prefix)
+    }
+}
+
+pub fn simd_ends_with(haystack: &str, suffix: &str) -> bool {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        haystack.len() >= suffix.len()
+            && sse2::eq_bytes(
+                &haystack.as_bytes()[haystack.len() - suffix.len()..],
+                suffix.as_bytes(),
+            )
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        haystack.ends_with(suffix)
+    }
+}
+
+/// Byte offset of the first occurrence of `needle`, as `str::find` returns it.
+pub fn simd_find(haystack: &str, needle: &str) -> Option<usize> {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    {
+        if needle.is_empty() || haystack.len() < needle.len() + 15 {
+            haystack.find(needle)
+        } else {
+            sse2::find(haystack.as_bytes(), needle.as_bytes())
+        }
+    }
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    {
+        haystack.find(needle)
+    }
+}
+
+/// Substring test. On x86_64 the standard library already vectorizes `str::contains`
+/// for needles of up to 32 bytes, so only longer needles use `simd_find`.
+pub fn simd_contains(haystack: &str, needle: &str) -> bool {
+    if needle.len() <= 32 {
+        haystack.contains(needle)
+    } else {
+        simd_find(haystack, needle).is_some()
+    }
+}
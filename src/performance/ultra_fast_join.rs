@@ -6,6 +6,7 @@
 //! - Memory-efficient result building with proper schema preservation
 //! - Target: Sub-second joins for 100k+ row DataFrames
 
+use crate::dataframe::join::take_optional;
 use crate::dataframe::DataFrame;
 use crate::series::Series;
 use crate::VeloxxError;
@@ -125,8 +126,8 @@ impl UltraFastJoin {
             .ok_or_else(|| VeloxxError::ColumnNotFound(right_key.to_string()))?;
 
         // Extract i32 values
-        let left_values = match left_series {
-            Series::I32(_, values, _) => values,
+        let (left_values, left_valid) = match left_series {
+            Series::I32(_, values, validity) => (values, validity),
             _ => {
                 return Err(VeloxxError::InvalidOperation(
                     "Expected i32 series".to_string(),
@@ -134,8 +135,8 @@ impl UltraFastJoin {
             }
         };
 
-        let right_values = match right_series {
-            Series::I32(_, values, _) => values,
+        let (right_values, right_valid) = match right_series {
+            Series::I32(_, values, validity) => (values, validity),
             _ => {
                 return Err(VeloxxError::InvalidOperation(
                     "Expected i32 series".to_string(),
@@ -145,8 +146,11 @@ impl UltraFastJoin {
 
         // Build SIMD hash table from right DataFrame
         let mut hash_table = SimdHashTable::with_capacity(right_values.len());
+        // Null keys match nothing
         for (idx, &value) in right_values.iter().enumerate() {
-            hash_table.insert(value, idx as u32);
+            if right_valid[idx] {
+                hash_table.insert(value, idx as u32);
+            }
         }
 
         // Perform batch lookup for all left values
@@ -155,6 +159,9 @@ impl UltraFastJoin {
         // Build result pairs (left_idx, right_idx)
         let mut result_pairs = Vec::new();
         for (left_idx, right_indices) in lookup_results.iter().enumerate() {
+            if !left_valid[left_idx] {
+                continue;
+            }
             for &right_idx in right_indices {
                 result_pairs.push((left_idx, right_idx as usize));
             }
@@ -206,79 +213,13 @@ impl UltraFastJoin {
         } else {
             format!("right_{}", new_name)
         };
-
-        match series {
-            Series::I32(_, values, _) => {
-                let mut result_values = Vec::with_capacity(result_pairs.len());
-
-                for &(left_idx, right_idx) in result_pairs {
-                    let idx = if use_left { left_idx } else { right_idx };
-                    if idx < values.len() {
-                        result_values.push(Some(values[idx]));
-                    } else {
-                        result_values.push(None);
-                    }
-                }
-
-                Ok(Series::new_i32(&prefixed_name, result_values))
-            }
-            Series::F64(_, values, _) => {
-                let mut result_values = Vec::with_capacity(result_pairs.len());
-
-                for &(left_idx, right_idx) in result_pairs {
-                    let idx = if use_left { left_idx } else { right_idx };
-                    if idx < values.len() {
-                        result_values.push(Some(values[idx]));
-                    } else {
-                        result_values.push(None);
-                    }
-                }
-
-                Ok(Series::new_f64(&prefixed_name, result_values))
-            }
-            Series::String(_, values, _) => {
-                let mut result_values = Vec::with_capacity(result_pairs.len());
-
-                for &(left_idx, right_idx) in result_pairs {
-                    let idx = if use_left { left_idx } else { right_idx };
-                    if idx < values.len() {
-                        result_values.push(Some(values[idx].clone()));
-                    } else {
-                        result_values.push(None);
-                    }
-                }
-
-                Ok(Series::new_string(&prefixed_name, result_values))
-            }
-            Series::Bool(_, values, _) => {
-                let mut result_values = Vec::with_capacity(result_pairs.len());
-
-                for &(left_idx, right_idx) in result_pairs {
-                    let idx = if use_left { left_idx } else { right_idx };
-                    if idx < values.len() {
-                        result_values.push(Some(values[idx]));
-                    } else {
-                        result_values.push(None);
-                    }
-                }
-
-                Ok(Series::new_bool(&prefixed_name, result_values))
-            }
-            Series::DateTime(_, values, _) => {
-                let mut result_values = Vec::with_capacity(result_pairs.len());
-
-                for &(left_idx, right_idx) in result_pairs {
-                    let idx = if use_left { left_idx } else { right_idx };
-                    if idx < values.len() {
-                        result_values.push(Some(values[idx]));
-                    } else {
-                        result_values.push(None);
-                    }
-                }
-
-                Ok(Series::new_datetime(&prefixed_name, result_values))
-            }
-        }
+        let indices: Vec<Option<usize>> = result_pairs
+            .iter()
+            .map(|&(left_idx, right_idx)| Some(if use_left { left_idx } else { right_idx }))
+            .collect();
+        let mut result = take_optional(series, &indices);
+        result.set_name(&prefixed_name);
+        Ok(result)
     }
 }
 
@@ -203,6 +203,30 @@ impl VectorizedFilter {
                     filtered_bitmap,
                 ))
             }
+            Series::Binary(name, values, bitmap) => {
+                if values.len() != mask.len() {
+                    return Err(VeloxxError::InvalidOperation(
+                        "Series and mask must have same length".to_string(),
+                    ));
+                }
+
+                let estimated_size = mask.count_ones().min(values.len() / 2);
+                let mut filtered_values = Vec::with_capacity(estimated_size);
+                let mut filtered_bitmap = Vec::with_capacity(estimated_size);
+
+                for i in 0..values.len() {
+                    if mask.get(i).unwrap_or(false) {
+                        filtered_values.push(values[i].clone());
+                        filtered_bitmap.push(bitmap[i]);
+                    }
+                }
+
+                Ok(Series::Binary(
+                    name.clone(),
+                    filtered_values,
+                    filtered_bitmap,
+                ))
+            }
         }
     }
 
@@ -212,6 +236,11 @@ impl VectorizedFilter {
         comparison_value: &Value,
         op: ComparisonOp,
     ) -> Result<BitPackedArray, VeloxxError> {
+        #[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+        if let Some(mask) = crate::performance::gpu::series_mask(series, comparison_value, op) {
+            return Ok(mask);
+        }
+
         match (series, comparison_value) {
             (Series::F64(_, values, bitmap), Value::F64(cmp_val)) => {
                 Self::create_comparison_mask_f64(values, bitmap, *cmp_val, op)
@@ -0,0 +1,230 @@
+//! Named, checkpointed transformation pipelines.
+//!
+//! A [`Pipeline`] runs a sequence of named steps over a `DataFrame`. With a
+//! checkpoint directory set, each step's output is saved in Veloxx's binary
+//! format (see [`DataFrame::to_bytes`]) as soon as it finishes, and the next run
+//! over the same input resumes after the last step that has a checkpoint. A
+//! failed run can therefore be fixed and rerun without repeating the steps that
+//! already succeeded.
+//!
+//! Each checkpoint records a fingerprint of the input frame and of the names of
+//! the steps up to and including its own, so checkpoints from a different input
+//! or an edited step list are ignored rather than reused. Changing what a step
+//! does without renaming it is not detected; call
+//! [`Pipeline::clear_checkpoints`] in that case.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use veloxx::df;
+//! use veloxx::expressions::Expr;
+//! use veloxx::pipeline::Pipeline;
+//! use veloxx::types::Value;
+//!
+//! let dir = std::env::temp_dir().join("veloxx_pipeline_doc");
+//! let pipeline = Pipeline::new()
+//!     .step("drop_nulls", |df| df.drop_nulls(None))
+//!     .step("double", |df| {
+//!         let x = || Box::new(Expr::Column("x".to_string()));
+//!         df.with_column("doubled", &Expr::Add(x(), x()))
+//!     })
+//!     .checkpoint_dir(&dir);
+//!
+//! let input = df!("x" => [Some(1), None, Some(3)]).unwrap();
+//! let output = pipeline.run(&input).unwrap();
+//! assert_eq!(output.get_column("doubled").unwrap().get_value(1), Some(Value::I32(6)));
+//! pipeline.clear_checkpoints().unwrap();
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::error::{ErrorContext, ResultExt};
+use crate::VeloxxError;
+use std::path::{Path, PathBuf};
+
+/// Extension of checkpoint files
+const CHECKPOINT_EXTENSION: &str = "vlxc";
+
+type Transform = Box<dyn Fn(&DataFrame) -> Result<DataFrame, VeloxxError> + Send + Sync>;
+
+struct Step {
+    name: String,
+    transform: Transform,
+}
+
+/// A sequence of named `DataFrame` transformations, optionally checkpointed to
+/// disk; see the [module documentation](self).
+#[derive(Default)]
+pub struct Pipeline {
+    steps: Vec<Step>,
+    checkpoint_dir: Option<PathBuf>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline without checkpointing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a step named `name` that transforms the previous step's output.
+    ///
+    /// Step names identify checkpoints, so they should be unique; [`Pipeline::run`]
+    /// rejects duplicates.
+    pub fn step<F>(mut self, name: &str, transform: F) -> Self
+    where
+        F: Fn(&DataFrame) -> Result<DataFrame, VeloxxError> + Send + Sync + 'static,
+    {
+        self.steps.push(Step {
+            name: name.to_string(),
+            transform: Box::new(transform),
+        });
+        self
+    }
+
+    /// Saves each step's output under `dir`, which is created if needed, and
+    /// resumes from those checkpoints on later runs.
+    pub fn checkpoint_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.checkpoint_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// The step names, in execution order.
+    pub fn step_names(&self) -> Vec<&str> {
+        self.steps.iter().map(|step| step.name.as_str()).collect()
+    }
+
+    /// Runs the steps over `input` and returns the last step's output, or
+    /// `input` itself for an empty pipeline.
+    ///
+    /// With a checkpoint directory, the run starts from the latest valid
+    /// checkpoint for this input and saves the output of every step it runs.
+    /// A failing step's error names the step; the checkpoints of the steps
+    /// before it are kept.
+    pub fn run(&self, input: &DataFrame) -> Result<DataFrame, VeloxxError> {
+        for (i, step) in self.steps.iter().enumerate() {
+            if self.steps[..i].iter().any(|s| s.name == step.name) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "Pipeline step '{}' is defined more than once",
+                    step.name
+                )));
+            }
+        }
+        let Some(dir) = &self.checkpoint_dir else {
+            return self.run_from(input.clone(), 0, |_, _| Ok(()));
+        };
+
+        let fingerprints = self.fingerprints(input);
+        let mut start = (input.clone(), 0);
+        for i in (0..self.steps.len()).rev() {
+            if let Some(df) = self.load_checkpoint(dir, i, fingerprints[i])? {
+                start = (df, i + 1);
+                break;
+            }
+        }
+        std::fs::create_dir_all(dir).map_err(|e| VeloxxError::FileIO(e.to_string()))?;
+        let (df, first) = start;
+        self.run_from(df, first, |i, output| {
+            let mut bytes = fingerprints[i].to_le_bytes().to_vec();
+            bytes.extend(output.to_bytes());
+            let path = self.checkpoint_path(dir, i);
+            // Write then rename, so an interrupted save never looks like a checkpoint
+            let partial = path.with_extension("partial");
+            std::fs::write(&partial, bytes)
+                .and_then(|_| std::fs::rename(&partial, &path))
+                .map_err(|e| VeloxxError::FileIO(e.to_string()))
+                .with_context(|| ErrorContext::new().file(&path.display().to_string()))
+        })
+    }
+
+    /// Deletes the checkpoints of this pipeline's steps, if any.
+    pub fn clear_checkpoints(&self) -> Result<(), VeloxxError> {
+        let Some(dir) = &self.checkpoint_dir else {
+            return Ok(());
+        };
+        for i in 0..self.steps.len() {
+            match std::fs::remove_file(self.checkpoint_path(dir, i)) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(VeloxxError::FileIO(e.to_string()))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn run_from<S>(
+        &self,
+        mut df: DataFrame,
+        first: usize,
+        save: S,
+    ) -> Result<DataFrame, VeloxxError>
+    where
+        S: Fn(usize, &DataFrame) -> Result<(), VeloxxError>,
+    {
+        for (i, step) in self.steps.iter().enumerate().skip(first) {
+            df = (step.transform)(&df).with_context(|| {
+                ErrorContext::new().operation(&format!("pipeline step '{}'", step.name))
+            })?;
+            save(i, &df)?;
+        }
+        Ok(df)
+    }
+
+    fn checkpoint_path(&self, dir: &Path, index: usize) -> PathBuf {
+        let name: String = self.steps[index]
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        dir.join(format!("{index:03}-{name}.{CHECKPOINT_EXTENSION}"))
+    }
+
+    /// The fingerprint each step's checkpoint must carry for this input
+    fn fingerprints(&self, input: &DataFrame) -> Vec<u64> {
+        let mut hash = fnv1a(FNV_OFFSET, &input.to_bytes());
+        self.steps
+            .iter()
+            .map(|step| {
+                // Length-prefix names so ["ab", "c"] and ["a", "bc"] differ
+                hash = fnv1a(hash, &(step.name.len() as u64).to_le_bytes());
+                hash = fnv1a(hash, step.name.as_bytes());
+                hash
+            })
+            .collect()
+    }
+
+    fn load_checkpoint(
+        &self,
+        dir: &Path,
+        index: usize,
+        fingerprint: u64,
+    ) -> Result<Option<DataFrame>, VeloxxError> {
+        let bytes = match std::fs::read(self.checkpoint_path(dir, index)) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(VeloxxError::FileIO(e.to_string())),
+        };
+        match bytes.split_at_checked(8) {
+            Some((stored, frame)) if stored == fingerprint.to_le_bytes() => {
+                DataFrame::from_bytes(frame).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is stable across Rust releases
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
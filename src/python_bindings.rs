@@ -21,9 +21,35 @@ use pyo3::{pyclass, pymethods, pymodule, wrap_pyfunction, PyErr, PyObject, PyRes
 #[cfg(feature = "python")]
 use crate::{
     conditions::Condition, dataframe::DataFrame, performance::optimized_simd::OptimizedSimdOps,
-    performance::ultra_fast_join::UltraFastJoin, series::Series, types::Value,
+    performance::ultra_fast_join::UltraFastJoin, series::Series, types::DataType, types::Value,
+    VeloxxError,
 };
 
+#[cfg(feature = "python")]
+use crate::lazy::{Aggregation, LazyDataFrame};
+
+#[cfg(feature = "python")]
+use numpy::{PyArray1, PyArrayMethods};
+
+#[cfg(feature = "python")]
+use crate::io::arrow::{dataframe_to_record_batch, record_batches_to_dataframe};
+#[cfg(feature = "python")]
+use arrow::array::{make_array, Array, ArrayRef};
+#[cfg(feature = "python")]
+use arrow::datatypes::Field;
+#[cfg(feature = "python")]
+use arrow::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+#[cfg(feature = "python")]
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+#[cfg(feature = "python")]
+use arrow::record_batch::RecordBatchIterator;
+#[cfg(feature = "python")]
+use pyo3::types::{PyBool, PyBytes, PyCapsule, PyIterator, PyList, PySlice, PyTuple};
+#[cfg(feature = "python")]
+use pyo3::IntoPyObjectExt;
+#[cfg(feature = "python")]
+use std::ffi::CString;
+
 #[cfg(feature = "python")]
 use std::collections::HashMap;
 
@@ -51,6 +77,44 @@ impl PyDataType {
             PyDataType::DateTime => "DateTime".to_string(),
         }
     }
+
+    fn name(&self) -> String {
+        self.__str__()
+    }
+}
+
+/// Parses an interpolation method name as accepted by the `interpolate` methods
+#[cfg(feature = "python")]
+fn interpolation_method(
+    method: &str,
+    limit: Option<usize>,
+) -> PyResult<crate::series::interpolate::InterpolationMethod> {
+    use crate::series::interpolate::InterpolationMethod;
+    Ok(match method {
+        "linear" => InterpolationMethod::Linear,
+        "nearest" => InterpolationMethod::Nearest,
+        "spline" => InterpolationMethod::Spline,
+        "ffill" => InterpolationMethod::ForwardFill { limit },
+        "bfill" => InterpolationMethod::BackwardFill { limit },
+        other => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown interpolation method '{other}'; expected 'linear', 'nearest', 'spline', 'ffill' or 'bfill'"
+            )))
+        }
+    })
+}
+
+#[cfg(feature = "python")]
+impl From<PyDataType> for crate::types::DataType {
+    fn from(data_type: PyDataType) -> Self {
+        match data_type {
+            PyDataType::I32 => crate::types::DataType::I32,
+            PyDataType::F64 => crate::types::DataType::F64,
+            PyDataType::String => crate::types::DataType::String,
+            PyDataType::Bool => crate::types::DataType::Bool,
+            PyDataType::DateTime => crate::types::DataType::DateTime,
+        }
+    }
 }
 
 /// Python wrapper for join types
@@ -142,6 +206,42 @@ impl PyCondition {
             })
         })
     }
+
+    #[staticmethod]
+    pub fn and(left: &PyCondition, right: &PyCondition) -> Self {
+        left.__and__(right)
+    }
+
+    #[staticmethod]
+    pub fn or(left: &PyCondition, right: &PyCondition) -> Self {
+        left.__or__(right)
+    }
+
+    #[staticmethod]
+    pub fn not(condition: &PyCondition) -> Self {
+        condition.__invert__()
+    }
+
+    /// `cond_a & cond_b`
+    pub fn __and__(&self, other: &PyCondition) -> Self {
+        PyCondition {
+            inner: Condition::And(Box::new(self.inner.clone()), Box::new(other.inner.clone())),
+        }
+    }
+
+    /// `cond_a | cond_b`
+    pub fn __or__(&self, other: &PyCondition) -> Self {
+        PyCondition {
+            inner: Condition::Or(Box::new(self.inner.clone()), Box::new(other.inner.clone())),
+        }
+    }
+
+    /// `~cond`
+    pub fn __invert__(&self) -> Self {
+        PyCondition {
+            inner: Condition::Not(Box::new(self.inner.clone())),
+        }
+    }
 }
 
 /// Python wrapper for expressions
@@ -243,6 +343,26 @@ impl PyExpr {
         }
     }
 
+    #[staticmethod]
+    pub fn greater_than_or_equal(left: &PyExpr, right: &PyExpr) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::GreaterThanOrEqual(
+                Box::new(left.inner.clone()),
+                Box::new(right.inner.clone()),
+            ),
+        }
+    }
+
+    #[staticmethod]
+    pub fn less_than_or_equal(left: &PyExpr, right: &PyExpr) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::LessThanOrEqual(
+                Box::new(left.inner.clone()),
+                Box::new(right.inner.clone()),
+            ),
+        }
+    }
+
     #[staticmethod]
     pub fn equal(left: &PyExpr, right: &PyExpr) -> Self {
         PyExpr {
@@ -296,6 +416,53 @@ impl PyExpr {
         }
     }
 
+    /// Number of chars (not bytes) in a string expression
+    pub fn str_len_chars(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::StrLenChars(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Substring of up to `length` chars starting at char `start`
+    #[pyo3(signature = (start, length=None))]
+    pub fn str_slice_chars(&self, start: usize, length: Option<usize>) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::StrSliceChars(
+                Box::new(self.inner.clone()),
+                start,
+                length,
+            ),
+        }
+    }
+
+    /// Base64 text of a binary expression
+    pub fn base64_encode(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Base64Encode(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Bytes decoded from a base64 string expression
+    pub fn base64_decode(&self) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::Base64Decode(Box::new(self.inner.clone())),
+        }
+    }
+
+    /// Round a datetime down to a multiple of `every`, such as `"15m"` or `"1d"`
+    pub fn dt_truncate(&self, every: &str) -> Self {
+        PyExpr {
+            inner: crate::expressions::Expr::dt_truncate(self.inner.clone(), every),
+        }
+    }
+
+    /// Name the output column, for use with `DataFrame.select_exprs`
+    pub fn alias(&self, name: &str) -> Self {
+        PyExpr {
+            inner: self.inner.clone().alias(name),
+        }
+    }
+
     /// Instance method for greater than comparison
     pub fn gt(&self, other: &PyExpr) -> Self {
         PyExpr {
@@ -355,6 +522,145 @@ impl PyExpr {
             ),
         }
     }
+
+    /// Aggregations over a column expression, for `group_by().agg()`
+    pub fn sum(slf: &Bound<'_, Self>) -> PyResult<PyAggExpr> {
+        PyAggExpr::new(slf.as_any(), "sum")
+    }
+
+    pub fn mean(slf: &Bound<'_, Self>) -> PyResult<PyAggExpr> {
+        PyAggExpr::new(slf.as_any(), "mean")
+    }
+
+    pub fn count(slf: &Bound<'_, Self>) -> PyResult<PyAggExpr> {
+        PyAggExpr::new(slf.as_any(), "count")
+    }
+
+    pub fn min(slf: &Bound<'_, Self>) -> PyResult<PyAggExpr> {
+        PyAggExpr::new(slf.as_any(), "min")
+    }
+
+    pub fn max(slf: &Bound<'_, Self>) -> PyResult<PyAggExpr> {
+        PyAggExpr::new(slf.as_any(), "max")
+    }
+
+    // Python operators. The other operand may be another `PyExpr` or a plain
+    // Python scalar, which is wrapped in a literal.
+
+    pub fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, false, crate::expressions::Expr::Add)
+    }
+
+    pub fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, true, crate::expressions::Expr::Add)
+    }
+
+    pub fn __sub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::Subtract,
+        )
+    }
+
+    pub fn __rsub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, true, crate::expressions::Expr::Subtract)
+    }
+
+    pub fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::Multiply,
+        )
+    }
+
+    pub fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, true, crate::expressions::Expr::Multiply)
+    }
+
+    pub fn __truediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, false, crate::expressions::Expr::Divide)
+    }
+
+    pub fn __rtruediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, true, crate::expressions::Expr::Divide)
+    }
+
+    pub fn __gt__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::GreaterThan,
+        )
+    }
+
+    pub fn __ge__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::GreaterThanOrEqual,
+        )
+    }
+
+    pub fn __lt__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::LessThan,
+        )
+    }
+
+    pub fn __le__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::LessThanOrEqual,
+        )
+    }
+
+    pub fn __eq__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, false, crate::expressions::Expr::Equals)
+    }
+
+    pub fn __ne__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::NotEquals,
+        )
+    }
+
+    pub fn __and__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, false, crate::expressions::Expr::And)
+    }
+
+    pub fn __rand__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, true, crate::expressions::Expr::And)
+    }
+
+    pub fn __or__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, false, crate::expressions::Expr::Or)
+    }
+
+    pub fn __ror__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        expr_binary_op(&self.inner, other, true, crate::expressions::Expr::Or)
+    }
+
+    pub fn __invert__(&self) -> Self {
+        Self::not(self)
+    }
+
+    pub fn __repr__(&self) -> String {
+        crate::lazy::Expr::from(&self.inner).to_string()
+    }
 }
 
 /// Python wrapper for Value
@@ -410,6 +716,13 @@ impl PyValue {
         }
     }
 
+    /// Alias of `from_string`, kept for code written against the old binding module.
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    pub fn from_str_alias(value: String) -> Self {
+        Self::from_string(value)
+    }
+
     #[staticmethod]
     pub fn from_bool(value: bool) -> Self {
         PyValue {
@@ -417,6 +730,14 @@ impl PyValue {
         }
     }
 
+    /// Build a datetime value from a Unix timestamp in seconds
+    #[staticmethod]
+    pub fn from_datetime(value: i64) -> Self {
+        PyValue {
+            inner: Value::DateTime(value),
+        }
+    }
+
     #[staticmethod]
     pub fn null() -> Self {
         PyValue { inner: Value::Null }
@@ -435,6 +756,7 @@ impl PyValue {
             Value::String(_) => "string".to_string(),
             Value::Bool(_) => "bool".to_string(),
             Value::DateTime(_) => "datetime".to_string(),
+            Value::Binary(_) => "binary".to_string(),
             Value::Null => "null".to_string(),
         }
     }
@@ -448,151 +770,241 @@ impl PyValue {
 pub struct PyGroupedDataFrame {
     pub(crate) dataframe: PyDataFrame, // Own the dataframe
     pub(crate) group_columns: Vec<String>,
+    /// The keys as expressions, named by `group_columns`
+    pub(crate) key_exprs: Vec<crate::expressions::Expr>,
 }
 
 #[cfg(feature = "python")]
 #[pymethods]
 impl PyGroupedDataFrame {
-    /// Aggregate operations
-    pub fn agg(&self, aggregations: Vec<(String, String)>) -> PyResult<PyDataFrame> {
-        // Convert to &str tuples for the core API
-        let string_refs: Vec<(&str, &str)> = aggregations
-            .iter()
-            .map(|(c, a)| (c.as_str(), a.as_str()))
-            .collect();
+    /// Aggregate with expressions such as `sum("sales").alias("total")` or
+    /// `(column, function)` tuples, given as arguments or as a single list
+    ///
+    /// Unaliased aggregations keep the column name produced by the core group-by,
+    /// normally `{column}_{function}`.
+    #[pyo3(signature = (*aggregations))]
+    pub fn agg(&self, py: Python<'_>, aggregations: &Bound<'_, PyTuple>) -> PyResult<PyDataFrame> {
+        let mut exprs = Vec::with_capacity(aggregations.len());
+        for item in aggregations.iter() {
+            if let Ok(list) = item.downcast::<PyList>() {
+                for item in list.iter() {
+                    exprs.push(PyAggExpr::from_py(&item)?);
+                }
+            } else {
+                exprs.push(PyAggExpr::from_py(&item)?);
+            }
+        }
 
-        match self.dataframe.inner.group_by(self.group_columns.clone()) {
-            Ok(grouped) => match grouped.agg(string_refs) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            },
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+        let mut specs: Vec<(&str, &str)> = Vec::with_capacity(exprs.len());
+        for expr in &exprs {
+            let spec = (expr.column.as_str(), expr.func.as_str());
+            if !specs.contains(&spec) {
+                specs.push(spec);
+            }
+        }
+        let grouped = py.allow_threads(|| {
+            self.dataframe
+                .inner
+                .group_by_exprs(&self.key_exprs)?
+                .agg(specs)
+        })?;
+
+        let mut columns = HashMap::with_capacity(self.group_columns.len() + exprs.len());
+        for key in &self.group_columns {
+            if let Some(series) = grouped.get_column(key) {
+                columns.insert(key.clone(), series.clone());
+            }
+        }
+        for expr in &exprs {
+            // The single-sum fast path keeps the source column name
+            let default_name = format!("{}_{}", expr.column, expr.func);
+            let mut series = grouped
+                .get_column(&default_name)
+                .or_else(|| grouped.get_column(&expr.column))
+                .cloned()
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Aggregation {} produced no column",
+                        expr.__repr__()
+                    ))
+                })?;
+            let name = expr
+                .alias
+                .clone()
+                .unwrap_or_else(|| series.name().to_string());
+            if columns.contains_key(&name) {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Duplicate output column '{}' in aggregation",
+                    name
+                )));
+            }
+            series.set_name(&name);
+            columns.insert(name, series);
         }
+
+        Ok(PyDataFrame {
+            inner: DataFrame::new(columns)?,
+        })
     }
 
     /// Sum aggregation
-    pub fn sum(&self) -> PyResult<PyDataFrame> {
-        // Get all numeric columns for sum aggregation
-        let column_names = self.dataframe.inner.column_names();
-        let mut sum_aggs = Vec::new();
-
-        for col_name in column_names {
-            if col_name != &self.group_columns[0] {
-                // Skip group column
-                sum_aggs.push((col_name.as_str(), "sum"));
-            }
-        }
+    pub fn sum(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.aggregate_all(py, "sum")
+    }
 
-        match self.dataframe.inner.group_by(self.group_columns.clone()) {
-            Ok(grouped) => match grouped.agg(sum_aggs) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            },
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+    /// Mean aggregation
+    pub fn mean(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.aggregate_all(py, "mean")
     }
 
-    /// Mean aggregation  
-    pub fn mean(&self) -> PyResult<PyDataFrame> {
-        let column_names = self.dataframe.inner.column_names();
-        let mut mean_aggs = Vec::new();
+    /// Count aggregation
+    pub fn count(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.aggregate_all(py, "count")
+    }
 
-        for col_name in column_names {
-            if col_name != &self.group_columns[0] {
-                mean_aggs.push((col_name.as_str(), "mean"));
-            }
-        }
+    /// Min aggregation
+    pub fn min(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.aggregate_all(py, "min")
+    }
 
-        match self.dataframe.inner.group_by(self.group_columns.clone()) {
-            Ok(grouped) => match grouped.agg(mean_aggs) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            },
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+    /// Max aggregation
+    pub fn max(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        self.aggregate_all(py, "max")
     }
 
-    /// Count aggregation
-    pub fn count(&self) -> PyResult<PyDataFrame> {
-        let column_names = self.dataframe.inner.column_names();
-        let mut count_aggs = Vec::new();
+    /// Aggregate `(column, function)` pairs over each group's trailing `window` rows,
+    /// keeping every row
+    pub fn rolling_agg(
+        &self,
+        py: Python<'_>,
+        window: usize,
+        aggregations: Vec<(String, String)>,
+    ) -> PyResult<PyDataFrame> {
+        let specs: Vec<(&str, &str)> = aggregations
+            .iter()
+            .map(|(column, func)| (column.as_str(), func.as_str()))
+            .collect();
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| {
+                self.dataframe
+                    .inner
+                    .group_by_exprs(&self.key_exprs)?
+                    .rolling(window)
+                    .agg(specs)
+            })?,
+        })
+    }
 
-        for col_name in column_names {
-            if col_name != &self.group_columns[0] {
-                count_aggs.push((col_name.as_str(), "count"));
-            }
-        }
+    /// Aggregate `(column, function)` pairs over all rows so far in each group,
+    /// keeping every row
+    pub fn expanding_agg(
+        &self,
+        py: Python<'_>,
+        aggregations: Vec<(String, String)>,
+    ) -> PyResult<PyDataFrame> {
+        let specs: Vec<(&str, &str)> = aggregations
+            .iter()
+            .map(|(column, func)| (column.as_str(), func.as_str()))
+            .collect();
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| {
+                self.dataframe
+                    .inner
+                    .group_by_exprs(&self.key_exprs)?
+                    .expanding()
+                    .agg(specs)
+            })?,
+        })
+    }
+}
 
-        match self.dataframe.inner.group_by(self.group_columns.clone()) {
-            Ok(grouped) => match grouped.agg(count_aggs) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            },
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+#[cfg(feature = "python")]
+impl PyGroupedDataFrame {
+    /// Apply `func` to every column except the first group column, without holding the GIL
+    fn aggregate_all(&self, py: Python<'_>, func: &str) -> PyResult<PyDataFrame> {
+        let aggs: Vec<(&str, &str)> = self
+            .dataframe
+            .inner
+            .column_names()
+            .into_iter()
+            .filter(|name| *name != &self.group_columns[0])
+            .map(|name| (name.as_str(), func))
+            .collect();
+
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| {
+                self.dataframe
+                    .inner
+                    .group_by_exprs(&self.key_exprs)?
+                    .agg(aggs)
+            })?,
+        })
     }
+}
 
-    /// Min aggregation
-    pub fn min(&self) -> PyResult<PyDataFrame> {
-        let column_names = self.dataframe.inner.column_names();
-        let mut min_aggs = Vec::new();
+/// A named aggregation for `PyGroupedDataFrame.agg`, e.g. `sum("sales").alias("total")`
+#[cfg(feature = "python")]
+#[pyclass]
+#[derive(Clone)]
+pub struct PyAggExpr {
+    pub(crate) column: String,
+    pub(crate) func: String,
+    pub(crate) alias: Option<String>,
+}
 
-        for col_name in column_names {
-            if col_name != &self.group_columns[0] {
-                min_aggs.push((col_name.as_str(), "min"));
+#[cfg(feature = "python")]
+impl PyAggExpr {
+    fn new(column: &Bound<'_, PyAny>, func: &str) -> PyResult<Self> {
+        let column = match column.extract::<PyExpr>() {
+            Ok(PyExpr {
+                inner: crate::expressions::Expr::Column(name),
+            }) => name,
+            Ok(_) => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Aggregations can only be applied to a column expression",
+                ))
             }
-        }
+            Err(_) => column.extract::<String>()?,
+        };
+        Ok(PyAggExpr {
+            column,
+            func: func.to_string(),
+            alias: None,
+        })
+    }
 
-        match self.dataframe.inner.group_by(self.group_columns.clone()) {
-            Ok(grouped) => match grouped.agg(min_aggs) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            },
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+    fn from_py(item: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(expr) = item.extract::<PyAggExpr>() {
+            Ok(expr)
+        } else if let Ok((column, func)) = item.extract::<(String, String)>() {
+            Ok(PyAggExpr {
+                column,
+                func,
+                alias: None,
+            })
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "agg() expects aggregation expressions such as sum(\"x\") or (column, function) tuples",
+            ))
         }
     }
+}
 
-    /// Max aggregation
-    pub fn max(&self) -> PyResult<PyDataFrame> {
-        let column_names = self.dataframe.inner.column_names();
-        let mut max_aggs = Vec::new();
-
-        for col_name in column_names {
-            if col_name != &self.group_columns[0] {
-                max_aggs.push((col_name.as_str(), "max"));
-            }
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyAggExpr {
+    /// Name the output column
+    pub fn alias(&self, name: String) -> Self {
+        PyAggExpr {
+            alias: Some(name),
+            ..self.clone()
         }
+    }
 
-        match self.dataframe.inner.group_by(self.group_columns.clone()) {
-            Ok(grouped) => match grouped.agg(max_aggs) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            },
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+    pub fn __repr__(&self) -> String {
+        match &self.alias {
+            Some(alias) => format!("{}(col({:?})).alias({:?})", self.func, self.column, alias),
+            None => format!("{}(col({:?}))", self.func, self.column),
         }
     }
 }
@@ -694,6 +1106,7 @@ impl PySeries {
             Series::String(_, _, _) => "String".to_string(),
             Series::Bool(_, _, _) => "Bool".to_string(),
             Series::DateTime(_, _, _) => "DateTime".to_string(),
+            Series::Binary(_, _, _) => "Binary".to_string(),
         }
     }
 
@@ -706,19 +1119,116 @@ impl PySeries {
             Some(Value::String(v)) => Ok(Some(v.into_py(py))),
             Some(Value::Bool(v)) => Ok(Some(v.into_py(py))),
             Some(Value::DateTime(v)) => Ok(Some(v.into_py(py))),
+            Some(Value::Binary(v)) => Ok(Some(PyBytes::new(py, &v).into_any().unbind())),
             Some(Value::Null) => Ok(None),
             None => Ok(None),
         })
     }
 
+    /// Export the series as a NumPy array
+    ///
+    /// Numeric and boolean buffers are handed to NumPy without a per-element
+    /// conversion, and `DateTime` values become `datetime64[s]`; strings become an
+    /// object array. If the series contains nulls
+    /// a `numpy.ma.MaskedArray` is returned with nulls masked out.
+    pub fn to_numpy(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let (data, bitmap): (PyObject, &Vec<bool>) = match &self.inner {
+            Series::I32(_, values, bitmap) => (
+                PyArray1::from_vec(py, values.clone()).into_any().unbind(),
+                bitmap,
+            ),
+            Series::F64(_, values, bitmap) => (
+                PyArray1::from_vec(py, values.clone()).into_any().unbind(),
+                bitmap,
+            ),
+            Series::Bool(_, values, bitmap) => (
+                PyArray1::from_vec(py, values.clone()).into_any().unbind(),
+                bitmap,
+            ),
+            Series::DateTime(_, values, bitmap) => (
+                PyArray1::from_vec(py, values.clone())
+                    .call_method1("view", ("datetime64[s]",))?
+                    .unbind(),
+                bitmap,
+            ),
+            Series::String(_, values, bitmap) => {
+                let objects: Vec<PyObject> = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(v, &valid)| {
+                        if valid {
+                            pyo3::types::PyString::new(py, v).into_any().unbind()
+                        } else {
+                            py.None()
+                        }
+                    })
+                    .collect();
+                (PyArray1::from_vec(py, objects).into_any().unbind(), bitmap)
+            }
+            Series::Binary(_, values, bitmap) => {
+                let objects: Vec<PyObject> = values
+                    .iter()
+                    .zip(bitmap.iter())
+                    .map(|(v, &valid)| {
+                        if valid {
+                            PyBytes::new(py, v).into_any().unbind()
+                        } else {
+                            py.None()
+                        }
+                    })
+                    .collect();
+                (PyArray1::from_vec(py, objects).into_any().unbind(), bitmap)
+            }
+        };
+
+        if bitmap.iter().all(|&valid| valid) {
+            return Ok(data);
+        }
+        let mask: Vec<bool> = bitmap.iter().map(|&valid| !valid).collect();
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("mask", PyArray1::from_vec(py, mask))?;
+        let masked = py
+            .import("numpy.ma")?
+            .getattr("masked_array")?
+            .call((data,), Some(&kwargs))?;
+        Ok(masked.unbind())
+    }
+
+    /// Create a Series from any object implementing `__arrow_c_array__`
+    /// (for example a `pyarrow.Array` or a Polars Series)
+    #[staticmethod]
+    #[pyo3(signature = (data, name=None))]
+    pub fn from_arrow(data: &Bound<'_, PyAny>, name: Option<String>) -> PyResult<Self> {
+        let (field_name, array) = import_arrow_array(data)?;
+        let series = Series::from_arrow_array(array, name.unwrap_or(field_name))?;
+        Ok(PySeries { inner: series })
+    }
+
+    /// Arrow PyCapsule interface: export the field describing this series
+    pub fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let array = self.inner.to_arrow_array();
+        let field = Field::new(self.inner.name(), array.data_type().clone(), true);
+        export_arrow_schema(py, &field)
+    }
+
+    /// Arrow PyCapsule interface: export the series as a single Arrow array
+    ///
+    /// `requested_schema` is accepted for protocol compatibility but ignored.
+    #[pyo3(signature = (requested_schema=None))]
+    pub fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        export_arrow_array(py, self.inner.name(), &self.inner.to_arrow_array())
+    }
+
     /// Filter the series by indices (high-performance)
     pub fn filter(&self, indices: Vec<usize>) -> PyResult<Self> {
-        match self.inner.filter(&indices) {
-            Ok(filtered) => Ok(PySeries { inner: filtered }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PySeries {
+            inner: self.inner.filter(&indices)?,
+        })
     }
 
     /// Count non-null values
@@ -736,30 +1246,22 @@ impl PySeries {
             Ok(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Sum not supported for this data type",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         })
     }
 
     /// Add two series using SIMD optimization
     pub fn add(&self, other: &PySeries) -> PyResult<Self> {
-        match self.inner.add(&other.inner) {
-            Ok(result) => Ok(PySeries { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PySeries {
+            inner: self.inner.add(&other.inner)?,
+        })
     }
 
     /// Multiply two series using SIMD optimization
     pub fn multiply(&self, other: &PySeries) -> PyResult<Self> {
-        match self.inner.multiply(&other.inner) {
-            Ok(result) => Ok(PySeries { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PySeries {
+            inner: self.inner.multiply(&other.inner)?,
+        })
     }
 
     /// Get mean using optimized computation
@@ -771,9 +1273,7 @@ impl PySeries {
             Ok(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Mean not supported for this data type",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -786,9 +1286,7 @@ impl PySeries {
             Ok(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Median not supported for this data type",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -803,9 +1301,7 @@ impl PySeries {
             Ok(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Min not supported for this data type",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         })
     }
 
@@ -820,9 +1316,7 @@ impl PySeries {
             Ok(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Max not supported for this data type",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         })
     }
 
@@ -835,9 +1329,7 @@ impl PySeries {
             Ok(_) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Standard deviation not supported for this data type",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -858,71 +1350,152 @@ impl PySeries {
                 ));
             };
 
-            match self.inner.fill_nulls(&fill_value) {
-                Ok(result) => Ok(PySeries { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            }
+            Ok(PySeries {
+                inner: self.inner.fill_nulls(&fill_value)?,
+            })
+        })
+    }
+
+    /// Fill nulls with the values of `other` on the same rows
+    pub fn coalesce(&self, other: &PySeries) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.coalesce(&other.inner)?,
         })
     }
 
     /// Interpolate null values
     pub fn interpolate_nulls(&self) -> PyResult<Self> {
-        match self.inner.interpolate_nulls() {
-            Ok(result) => Ok(PySeries { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PySeries {
+            inner: self.inner.interpolate_nulls()?,
+        })
+    }
+
+    /// Fill nulls with `method` ("linear", "nearest", "spline", "ffill" or
+    /// "bfill"); `limit` caps the rows filled per gap by "ffill" and "bfill"
+    #[pyo3(signature = (method, limit=None, by=None))]
+    pub fn interpolate(
+        &self,
+        method: &str,
+        limit: Option<usize>,
+        by: Option<&PySeries>,
+    ) -> PyResult<Self> {
+        let method = interpolation_method(method, limit)?;
+        let inner = match by {
+            Some(x) => self.inner.interpolate_by(method, &x.inner)?,
+            None => self.inner.interpolate(method)?,
+        };
+        Ok(PySeries { inner })
+    }
+
+    /// Count distinct values
+    pub fn unique_count(&self) -> PyResult<usize> {
+        Ok(self.inner.unique_count()?)
     }
 
     /// Get unique values
     pub fn unique(&self) -> PyResult<Self> {
-        match self.inner.unique() {
-            Ok(result) => Ok(PySeries { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PySeries {
+            inner: self.inner.unique()?,
+        })
     }
 
     /// Append another series
     pub fn append(&self, other: &PySeries) -> PyResult<Self> {
-        match self.inner.append(&other.inner) {
-            Ok(result) => Ok(PySeries { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+        Ok(PySeries {
+            inner: self.inner.append(&other.inner)?,
+        })
+    }
+
+    /// Length of each string in chars
+    pub fn str_len_chars(&self) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.str_len_chars()?,
+        })
+    }
+
+    /// Length of each string in UTF-8 bytes
+    pub fn str_len_bytes(&self) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.str_len_bytes()?,
+        })
+    }
+
+    /// Substring of up to `length` chars starting at char `start`
+    #[pyo3(signature = (start, length=None))]
+    pub fn str_slice_chars(&self, start: usize, length: Option<usize>) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.str_slice_chars(start, length)?,
+        })
+    }
+
+    /// Substring by byte offsets; fails if an offset splits a character
+    #[pyo3(signature = (start, length=None))]
+    pub fn str_slice_bytes(&self, start: usize, length: Option<usize>) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.str_slice_bytes(start, length)?,
+        })
+    }
+
+    /// Length of each binary value in bytes
+    pub fn bin_len(&self) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.bin_len()?,
+        })
+    }
+
+    /// Whether each binary value starts with `prefix`
+    pub fn bin_starts_with(&self, prefix: Vec<u8>) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.bin_starts_with(&prefix)?,
+        })
+    }
+
+    /// First `n` bytes of each binary value
+    pub fn bin_prefix(&self, n: usize) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.bin_prefix(n)?,
+        })
+    }
+
+    /// Encode a binary series as base64 text
+    pub fn base64_encode(&self) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.base64_encode()?,
+        })
+    }
+
+    /// Decode a string series of base64 text into bytes
+    pub fn base64_decode(&self) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.base64_decode()?,
+        })
+    }
+
+    /// `n` random version 4 UUIDs as strings
+    #[staticmethod]
+    pub fn uuid_v4(n: usize) -> Self {
+        PySeries {
+            inner: Series::uuid_v4(n),
         }
     }
 
+    /// Whether each string is a valid UUID
+    pub fn is_uuid(&self) -> PyResult<Self> {
+        Ok(PySeries {
+            inner: self.inner.is_uuid()?,
+        })
+    }
+
     /// Cast to different data type
     pub fn cast(&self, target_type: PyDataType) -> PyResult<Self> {
-        let data_type = match target_type {
-            PyDataType::I32 => crate::types::DataType::I32,
-            PyDataType::F64 => crate::types::DataType::F64,
-            PyDataType::String => crate::types::DataType::String,
-            PyDataType::Bool => crate::types::DataType::Bool,
-            PyDataType::DateTime => crate::types::DataType::DateTime,
-        };
-
-        match self.inner.cast(data_type) {
-            Ok(result) => Ok(PySeries { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PySeries {
+            inner: self.inner.cast(target_type.into())?,
+        })
     }
 
     /// Convert to `Vec<f64>` for numeric series
     pub fn to_vec_f64(&self) -> PyResult<Vec<Option<f64>>> {
-        match self.inner.to_vec_f64() {
-            Ok(result) => Ok(result.into_iter().map(Some).collect()),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(self.inner.to_vec_f64()?.into_iter().map(Some).collect())
     }
 
     /// Calculate correlation with another series
@@ -932,9 +1505,7 @@ impl PySeries {
             Ok(None) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Unable to compute correlation",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -945,8 +1516,238 @@ impl PySeries {
             Ok(None) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Unable to compute covariance",
             )),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// First `n` values
+    #[pyo3(signature = (n=5))]
+    pub fn head(&self, n: usize) -> PyResult<Self> {
+        let indices: Vec<usize> = (0..n.min(self.inner.len())).collect();
+        Ok(PySeries {
+            inner: self.inner.filter(&indices)?,
+        })
+    }
+
+    /// Last `n` values
+    #[pyo3(signature = (n=5))]
+    pub fn tail(&self, n: usize) -> PyResult<Self> {
+        let len = self.inner.len();
+        let indices: Vec<usize> = (len.saturating_sub(n)..len).collect();
+        Ok(PySeries {
+            inner: self.inner.filter(&indices)?,
+        })
+    }
+
+    /// Apply a Python function to each value, returning a new series
+    ///
+    /// Nulls are passed through without calling `func` unless `skip_nulls=False`,
+    /// in which case `func` receives `None`. With `batched=True`, `func` is called
+    /// once with the whole column as a NumPy array (masked if it has nulls) and
+    /// must return an array, list or series of the same length.
+    #[pyo3(signature = (func, skip_nulls=true, batched=false))]
+    pub fn map(
+        &self,
+        py: Python<'_>,
+        func: &Bound<'_, PyAny>,
+        skip_nulls: bool,
+        batched: bool,
+    ) -> PyResult<Self> {
+        let name = self.inner.name();
+        let len = self.inner.len();
+        if batched {
+            let result = func.call1((self.to_numpy(py)?,))?;
+            return match series_from_py_result(name, &result, len)? {
+                Some(inner) => Ok(PySeries { inner }),
+                None => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                    "A batched function must return an array, list or series",
+                )),
+            };
+        }
+
+        let mut values = Vec::with_capacity(len);
+        for i in 0..len {
+            let value = self.inner.get_value(i).unwrap_or(Value::Null);
+            if skip_nulls && value == Value::Null {
+                values.push(Value::Null);
+                continue;
+            }
+            let result = func.call1((value_to_py(py, value)?,))?;
+            values.push(py_to_value(&result)?);
+        }
+        Ok(PySeries {
+            inner: series_from_values(name, values)?,
+        })
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn __repr__(&self) -> String {
+        const MAX_VALUES: usize = 10;
+        let mut values: Vec<String> = (0..self.inner.len().min(MAX_VALUES))
+            .map(|i| {
+                self.inner
+                    .get_value(i)
+                    .map_or_else(|| "null".to_string(), |v| v.to_string())
+            })
+            .collect();
+        if self.inner.len() > MAX_VALUES {
+            values.push("...".to_string());
+        }
+        format!(
+            "Series('{}', {:?}, [{}])",
+            self.inner.name(),
+            self.inner.data_type(),
+            values.join(", ")
+        )
+    }
+
+    pub fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        let values = (0..self.inner.len())
+            .map(|i| value_to_py(py, self.inner.get_value(i).unwrap_or(Value::Null)))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyList::new(py, values)?.try_iter()
+    }
+
+    /// `s[i]` returns a value (negative indices count from the end); `s[slice]`,
+    /// `s[[i, j]]` and `s[bool_series]` return a new series
+    pub fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let len = self.inner.len();
+        if let Ok(index) = key.extract::<isize>() {
+            let i = if index < 0 {
+                index + len as isize
+            } else {
+                index
+            };
+            if i < 0 || i as usize >= len {
+                return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(
+                    "Series index out of range",
+                ));
+            }
+            return value_to_py(py, self.inner.get_value(i as usize).unwrap_or(Value::Null));
+        }
+        let indices = row_indices(key, len)?;
+        PySeries {
+            inner: self.inner.filter(&indices)?,
+        }
+        .into_py_any(py)
+    }
+
+    // Elementwise operators. The other operand may be a series of the same
+    // length or a Python scalar; nulls propagate.
+
+    pub fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, false, crate::expressions::Expr::Add)
+    }
+
+    pub fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, true, crate::expressions::Expr::Add)
+    }
+
+    pub fn __sub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::Subtract,
+        )
+    }
+
+    pub fn __rsub__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, true, crate::expressions::Expr::Subtract)
+    }
+
+    pub fn __mul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::Multiply,
+        )
+    }
+
+    pub fn __rmul__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, true, crate::expressions::Expr::Multiply)
+    }
+
+    pub fn __truediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, false, crate::expressions::Expr::Divide)
+    }
+
+    pub fn __rtruediv__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, true, crate::expressions::Expr::Divide)
+    }
+
+    pub fn __gt__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::GreaterThan,
+        )
+    }
+
+    pub fn __ge__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::GreaterThanOrEqual,
+        )
+    }
+
+    pub fn __lt__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::LessThan,
+        )
+    }
+
+    pub fn __le__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::LessThanOrEqual,
+        )
+    }
+
+    pub fn __eq__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, false, crate::expressions::Expr::Equals)
+    }
+
+    pub fn __ne__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(
+            &self.inner,
+            other,
+            false,
+            crate::expressions::Expr::NotEquals,
+        )
+    }
+
+    pub fn __and__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, false, crate::expressions::Expr::And)
+    }
+
+    pub fn __or__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        series_binary_op(&self.inner, other, false, crate::expressions::Expr::Or)
+    }
+
+    pub fn __invert__(&self) -> PyResult<Self> {
+        match &self.inner {
+            Series::Bool(name, values, bitmap) => Ok(PySeries {
+                inner: Series::Bool(
+                    name.clone(),
+                    values.iter().map(|v| !v).collect(),
+                    bitmap.clone(),
+                ),
+            }),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "~ is only supported for boolean series",
             )),
         }
     }
@@ -973,12 +1774,9 @@ impl PyDataFrame {
             df_columns.insert(key, series.inner);
         }
 
-        match DataFrame::new(df_columns) {
-            Ok(df) => Ok(PyDataFrame { inner: df }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: DataFrame::new(df_columns)?,
+        })
     }
 
     #[staticmethod]
@@ -1003,11 +1801,190 @@ impl PyDataFrame {
             columns.insert(name, series);
         }
 
-        match DataFrame::new(columns) {
-            Ok(df) => Ok(PyDataFrame { inner: df }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+        Ok(PyDataFrame {
+            inner: DataFrame::new(columns)?,
+        })
+    }
+
+    /// Create a DataFrame from a dict of 1-D NumPy arrays
+    ///
+    /// Supports `int32`, `int64` (narrowed to I32, erroring on overflow), `float64`
+    /// and `bool` arrays. Masked entries of a `numpy.ma.MaskedArray` become nulls.
+    #[staticmethod]
+    pub fn from_numpy(data: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut columns = HashMap::new();
+        for (key, value) in data.iter() {
+            let name: String = key.extract()?;
+            let series = series_from_numpy(&name, &value)?;
+            columns.insert(name, series);
+        }
+
+        Ok(PyDataFrame {
+            inner: DataFrame::new(columns)?,
+        })
+    }
+
+    /// Create a DataFrame from a pandas DataFrame
+    ///
+    /// Uses pyarrow to hand columns over through the Arrow C data interface when it is
+    /// installed, and falls back to converting each column through NumPy otherwise.
+    /// Missing values (`None`, `NaN`, `NaT`, `pd.NA`) become nulls.
+    #[staticmethod]
+    pub fn from_pandas(pdf: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let py = pdf.py();
+
+        if let Ok(pa) = py.import("pyarrow") {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("preserve_index", false)?;
+            let table = pa
+                .getattr("Table")?
+                .call_method("from_pandas", (pdf,), Some(&kwargs))?;
+            return Ok(PyDataFrame {
+                inner: import_arrow_stream(&table)?,
+            });
+        }
+
+        let mut columns = HashMap::new();
+        for label in pdf.getattr("columns")?.try_iter()? {
+            let label = label?;
+            let name = label.str()?.to_string();
+            let column = pdf.get_item(label)?;
+            columns.insert(name.clone(), series_from_pandas_column(&name, &column)?);
+        }
+
+        Ok(PyDataFrame {
+            inner: DataFrame::new(columns)?,
+        })
+    }
+
+    /// Create a DataFrame from any object implementing `__arrow_c_stream__`
+    /// (for example a `pyarrow.Table`, a Polars DataFrame or a DuckDB relation)
+    #[staticmethod]
+    pub fn from_arrow(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: import_arrow_stream(data)?,
+        })
+    }
+
+    /// Arrow PyCapsule interface: export the schema of this DataFrame
+    pub fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let batch = dataframe_to_record_batch(&self.inner)?;
+        export_arrow_schema(py, batch.schema().as_ref())
+    }
+
+    /// Arrow PyCapsule interface: export the DataFrame as a stream of record batches
+    ///
+    /// Columns are emitted in sorted name order. `requested_schema` is accepted for
+    /// protocol compatibility but ignored.
+    #[pyo3(signature = (requested_schema=None))]
+    pub fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let _ = requested_schema;
+        let batch = dataframe_to_record_batch(&self.inner)?;
+        let schema = batch.schema();
+        let reader = RecordBatchIterator::new(vec![Ok(batch)], schema);
+        let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+        PyCapsule::new(py, stream, Some(CString::new("arrow_array_stream")?))
+    }
+
+    /// Convert the DataFrame to a pandas DataFrame
+    ///
+    /// Goes through pyarrow when it is installed and through NumPy arrays otherwise.
+    /// Columns are emitted in sorted name order.
+    pub fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let mut names: Vec<&String> = self.inner.column_names();
+        names.sort();
+        let columns = PyDict::new(py);
+
+        if let Ok(pa) = py.import("pyarrow") {
+            let import = pa.getattr("Array")?.getattr("_import_from_c_capsule")?;
+            for name in names {
+                let series = self.inner.get_column(name).expect("column listed by name");
+                let (schema, array) = export_arrow_array(py, name, &series.to_arrow_array())?;
+                columns.set_item(name, import.call1((schema, array))?)?;
+            }
+            let table = pa.call_method1("table", (columns,))?;
+            return Ok(table.call_method0("to_pandas")?.unbind());
+        }
+
+        for name in names {
+            let series = PySeries {
+                inner: self
+                    .inner
+                    .get_column(name)
+                    .expect("column listed by name")
+                    .clone(),
+            };
+            columns.set_item(name, series.to_numpy(py)?)?;
+        }
+        Ok(py
+            .import("pandas")?
+            .call_method1("DataFrame", (columns,))?
+            .unbind())
+    }
+
+    /// Convert to plain Python data
+    ///
+    /// `orient="list"` (the default) returns `{column: [values]}`; `orient="records"`
+    /// returns `[{column: value}]` with one dict per row. Nulls become `None`.
+    #[pyo3(signature = (orient="list"))]
+    pub fn to_dict(&self, py: Python<'_>, orient: &str) -> PyResult<PyObject> {
+        let names = self.sorted_column_names();
+        match orient {
+            "list" => {
+                let dict = PyDict::new(py);
+                for name in &names {
+                    let series = self.inner.get_column(name).expect("column listed by name");
+                    let values = (0..series.len())
+                        .map(|i| value_to_py(py, series.get_value(i).unwrap_or(Value::Null)))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    dict.set_item(name, PyList::new(py, values)?)?;
+                }
+                dict.into_py_any(py)
+            }
+            "records" => {
+                let rows = (0..self.inner.row_count())
+                    .map(|i| row_to_py(py, &self.inner, &names, i, true))
+                    .collect::<PyResult<Vec<_>>>()?;
+                PyList::new(py, rows)?.into_py_any(py)
+            }
+            other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported orient '{}': expected 'list' or 'records'",
+                other
+            ))),
+        }
+    }
+
+    /// Rows as a list of tuples, with values in sorted column-name order
+    pub fn to_records(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let names = self.sorted_column_names();
+        (0..self.inner.row_count())
+            .map(|i| row_to_py(py, &self.inner, &names, i, false))
+            .collect()
+    }
+
+    /// Render as an HTML table, eliding middle rows and columns beyond the limits
+    #[pyo3(signature = (max_rows=20, max_cols=20))]
+    pub fn to_html(&self, max_rows: usize, max_cols: usize) -> String {
+        self.inner.to_html(max_rows, max_cols)
+    }
+
+    /// Rich display hook used by Jupyter and IPython
+    pub fn _repr_html_(&self) -> String {
+        self.inner.to_html(20, 20)
+    }
+
+    /// Lazily iterate over rows as tuples (or dicts with `named=True`)
+    #[pyo3(signature = (named=false))]
+    pub fn iter_rows(&self, named: bool) -> PyRowIterator {
+        PyRowIterator {
+            frame: self.inner.clone(),
+            names: self.sorted_column_names(),
+            named,
+            index: 0,
         }
     }
 
@@ -1052,21 +2029,44 @@ impl PyDataFrame {
                 ));
             };
 
-            match self.inner.filter(&condition) {
-                Ok(filtered) => Ok(PyDataFrame { inner: filtered }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            }
+            Ok(PyDataFrame {
+                inner: self.inner.filter(&condition)?,
+            })
         })
     }
 
     /// Group by operations
-    pub fn group_by(&self, columns: Vec<String>) -> PyResult<PyGroupedDataFrame> {
-        // For now, just return a PyGroupedDataFrame with the same data
+    ///
+    /// Accepts column names as separate arguments (`group_by("a", "b")`) or as a
+    /// list (`group_by(["a", "b"])`), and named expressions such as
+    /// `col("ts").dt_truncate("1d")` computed while grouping.
+    #[pyo3(signature = (*columns))]
+    pub fn group_by(&self, columns: &Bound<'_, PyTuple>) -> PyResult<PyGroupedDataFrame> {
+        let mut key_exprs = Vec::with_capacity(columns.len());
+        for item in columns.iter() {
+            if let Ok(expr) = item.extract::<PyExpr>() {
+                key_exprs.push(expr.inner);
+            } else if let Ok(name) = item.extract::<String>() {
+                key_exprs.push(crate::expressions::Expr::Column(name));
+            } else {
+                let names = item.extract::<Vec<String>>()?;
+                key_exprs.extend(names.into_iter().map(crate::expressions::Expr::Column));
+            }
+        }
+        let group_columns = key_exprs
+            .iter()
+            .map(|expr| {
+                expr.output_name().map(str::to_string).ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Group key {expr:?} needs a name; use alias"
+                    ))
+                })
+            })
+            .collect::<PyResult<Vec<String>>>()?;
         Ok(PyGroupedDataFrame {
             dataframe: self.clone(),
-            group_columns: columns,
+            group_columns,
+            key_exprs,
         })
     }
 
@@ -1078,12 +2078,9 @@ impl PyDataFrame {
 
     /// Select columns
     pub fn select(&self, columns: Vec<String>) -> PyResult<Self> {
-        match self.inner.select_columns(columns) {
-            Ok(selected) => Ok(PyDataFrame { inner: selected }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: self.inner.select_columns(columns)?,
+        })
     }
 
     /// Select columns (alias for compatibility)
@@ -1093,32 +2090,60 @@ impl PyDataFrame {
 
     /// Drop columns
     pub fn drop_columns(&self, columns: Vec<String>) -> PyResult<Self> {
-        match self.inner.drop_columns(columns) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: self.inner.drop_columns(columns)?,
+        })
+    }
+
+    /// Keep only the columns of the given types
+    pub fn select_dtypes(&self, types: Vec<PyDataType>) -> PyResult<Self> {
+        let types: Vec<_> = types.into_iter().map(Into::into).collect();
+        Ok(PyDataFrame {
+            inner: self.inner.select_dtypes(&types)?,
+        })
+    }
+
+    /// Keep only the columns whose name matches a regular expression
+    pub fn select_matching(&self, pattern: &str) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: self.inner.select_matching(pattern)?,
+        })
     }
 
     /// Rename a column
     pub fn rename_column(&self, old_name: &str, new_name: &str) -> PyResult<Self> {
-        match self.inner.rename_column(old_name, new_name) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: self.inner.rename_column(old_name, new_name)?,
+        })
+    }
+
+    /// Rename several columns at once from an `{old: new}` dict
+    pub fn rename(&self, mapping: HashMap<String, String>) -> PyResult<Self> {
+        let mapping: Vec<(&str, &str)> = mapping
+            .iter()
+            .map(|(old, new)| (old.as_str(), new.as_str()))
+            .collect();
+        Ok(PyDataFrame {
+            inner: self.inner.rename(&mapping)?,
+        })
+    }
+
+    /// Rename every column to `func(name)`
+    pub fn rename_with(&self, py: Python<'_>, func: PyObject) -> PyResult<Self> {
+        let mapping = self
+            .inner
+            .column_names()
+            .into_iter()
+            .map(|name| Ok((name.clone(), func.call1(py, (name.as_str(),))?.extract(py)?)))
+            .collect::<PyResult<HashMap<String, String>>>()?;
+        self.rename(mapping)
     }
 
     /// Drop null values
     pub fn drop_nulls(&self, subset: Option<Vec<String>>) -> PyResult<Self> {
-        match self.inner.drop_nulls(subset.as_deref()) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: self.inner.drop_nulls(subset.as_deref())?,
+        })
     }
 
     /// Fill null values
@@ -1138,63 +2163,200 @@ impl PyDataFrame {
                 ));
             };
 
-            match self.inner.fill_nulls(fill_value) {
-                Ok(result) => Ok(PyDataFrame { inner: result }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    e.to_string(),
-                )),
-            }
+            Ok(PyDataFrame {
+                inner: self.inner.fill_nulls(fill_value)?,
+            })
+        })
+    }
+
+    /// Fill nulls in `column` with `method` as `Series.interpolate` does,
+    /// spacing rows by the `by` column if given
+    #[pyo3(signature = (column, method, limit=None, by=None))]
+    pub fn interpolate(
+        &self,
+        column: &str,
+        method: &str,
+        limit: Option<usize>,
+        by: Option<&str>,
+    ) -> PyResult<Self> {
+        let method = interpolation_method(method, limit)?;
+        let inner = match by {
+            Some(x_column) => self.inner.interpolate_by(column, method, x_column)?,
+            None => self.inner.interpolate(column, method)?,
+        };
+        Ok(PyDataFrame { inner })
+    }
+
+    /// Fill nulls in `target` from `source` on the same rows
+    pub fn fill_null_with_column(&self, target: &str, source: &str) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: self.inner.fill_null_with_column(target, source)?,
         })
     }
 
     /// Sort by columns
-    pub fn sort(&self, by_columns: Vec<String>, ascending: bool) -> PyResult<Self> {
-        match self.inner.sort(by_columns, ascending) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+    pub fn sort(&self, py: Python<'_>, by_columns: Vec<String>, ascending: bool) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| self.inner.sort(by_columns, ascending))?,
+        })
     }
 
     /// Append another DataFrame
     pub fn append(&self, other: &PyDataFrame) -> PyResult<Self> {
-        match self.inner.append(&other.inner) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
-    }
-
-    /// Calculate correlation between two columns
-    pub fn correlation(&self, col1: &str, col2: &str) -> PyResult<f64> {
-        match self.inner.correlation(col1, col2) {
-            Ok(result) => Ok(result),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: self.inner.append(&other.inner)?,
+        })
     }
 
-    /// Calculate covariance between two columns
-    pub fn covariance(&self, col1: &str, col2: &str) -> PyResult<f64> {
-        match self.inner.covariance(col1, col2) {
-            Ok(result) => Ok(result),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+    /// Rescale numeric columns with `method` ("zscore", "minmax" or "percent"),
+    /// within groups of the `over` columns if given
+    #[pyo3(signature = (columns, method, over=None))]
+    pub fn normalize(
+        &self,
+        columns: Vec<String>,
+        method: &str,
+        over: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        use crate::dataframe::normalize::NormalizeMethod;
+        let method = match method {
+            "zscore" => NormalizeMethod::ZScore,
+            "minmax" => NormalizeMethod::MinMax,
+            "percent" => NormalizeMethod::PercentOfTotal,
+            other => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown normalization method '{other}'; expected 'zscore', 'minmax' or 'percent'"
+            )))
+            }
+        };
+        let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+        let inner = match over {
+            Some(keys) => {
+                let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+                self.inner.normalize_over(&columns, method, &keys)?
+            }
+            None => self.inner.normalize(&columns, method)?,
+        };
+        Ok(PyDataFrame { inner })
+    }
+
+    /// Add running "sum", "max", "min" or "prod" columns, optionally following
+    /// `order_by` and restarting for each `partition_by` group
+    #[pyo3(signature = (columns, op, order_by=None, partition_by=None))]
+    pub fn cumulative(
+        &self,
+        columns: Vec<String>,
+        op: &str,
+        order_by: Option<String>,
+        partition_by: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        use crate::dataframe::time_series::{CumulativeOp, CumulativeOptions};
+        let op = match op {
+            "sum" => CumulativeOp::Sum,
+            "max" => CumulativeOp::Max,
+            "min" => CumulativeOp::Min,
+            "prod" => CumulativeOp::Prod,
+            other => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown cumulative operation '{other}'; expected 'sum', 'max', 'min' or 'prod'"
+                )))
+            }
+        };
+        let options = CumulativeOptions {
+            order_by,
+            partition_by: partition_by.unwrap_or_default(),
+        };
+        let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+        Ok(PyDataFrame {
+            inner: self.inner.cumulative(&columns, op, &options)?,
+        })
+    }
+
+    /// Merge with another DataFrame, both already sorted by `on`
+    pub fn merge_sorted(&self, other: &PyDataFrame, on: &str) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: self.inner.merge_sorted(&other.inner, on)?,
+        })
+    }
+
+    /// Split the `delimiter`-separated lists in `columns` into one row per
+    /// element, optionally recording each row's original position
+    #[pyo3(signature = (columns, delimiter=",", index_column=None))]
+    pub fn explode(
+        &self,
+        columns: Vec<String>,
+        delimiter: &str,
+        index_column: Option<String>,
+    ) -> PyResult<Self> {
+        let options = crate::dataframe::explode::ExplodeOptions {
+            delimiter: delimiter.to_string(),
+            index_column,
+        };
+        let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+        Ok(PyDataFrame {
+            inner: self.inner.explode_with_options(&columns, &options)?,
+        })
+    }
+
+    /// Update rows matching on `keys` from `other` and append the rest, with
+    /// per-column `strategies` ("left", "right" or "coalesce", default "right")
+    #[pyo3(signature = (other, keys, strategies=None))]
+    pub fn upsert(
+        &self,
+        other: &PyDataFrame,
+        keys: Vec<String>,
+        strategies: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        use crate::dataframe::upsert::{MergeStrategy, UpsertOptions};
+        let mut options = UpsertOptions::default();
+        for (column, strategy) in strategies.unwrap_or_default() {
+            let strategy = match strategy.as_str() {
+                "left" => MergeStrategy::PreferLeft,
+                "right" => MergeStrategy::PreferRight,
+                "coalesce" => MergeStrategy::Coalesce,
+                other => {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Unknown merge strategy '{other}'; expected 'left', 'right' or 'coalesce'"
+                    )))
+                }
+            };
+            options = options.merge_strategy(&column, strategy);
         }
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        Ok(PyDataFrame {
+            inner: self
+                .inner
+                .upsert_with_options(&other.inner, &keys, &options)?,
+        })
     }
 
-    /// Describe the DataFrame (statistical summary)
-    pub fn describe(&self) -> PyResult<Self> {
-        match self.inner.describe() {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+    /// Calculate correlation between two columns
+    pub fn correlation(&self, col1: &str, col2: &str) -> PyResult<f64> {
+        Ok(self.inner.correlation(col1, col2)?)
+    }
+
+    /// Calculate covariance between two columns
+    pub fn covariance(&self, col1: &str, col2: &str) -> PyResult<f64> {
+        Ok(self.inner.covariance(col1, col2)?)
+    }
+
+    /// Describe the DataFrame (statistical summary), with `percentiles` as
+    /// fractions (default 0.25, 0.5 and 0.75)
+    #[pyo3(signature = (percentiles=None))]
+    pub fn describe(&self, percentiles: Option<Vec<f64>>) -> PyResult<Self> {
+        let mut options = crate::dataframe::describe::DescribeOptions::default();
+        if let Some(percentiles) = percentiles {
+            options.percentiles = percentiles;
         }
+        Ok(PyDataFrame {
+            inner: self.inner.describe_with_options(&options)?,
+        })
+    }
+
+    /// Number of columns and nulls per data type
+    pub fn dtype_summary(&self) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: self.inner.dtype_summary()?,
+        })
     }
 
     /// Filter with condition or indices
@@ -1202,12 +2364,9 @@ impl PyDataFrame {
         Python::with_gil(|py| {
             // Try to extract as PyCondition first
             if let Ok(condition) = filter_param.extract::<PyCondition>(py) {
-                match self.inner.filter(&condition.inner) {
-                    Ok(result) => Ok(PyDataFrame { inner: result }),
-                    Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                        e.to_string(),
-                    )),
-                }
+                Ok(PyDataFrame {
+                    inner: self.inner.filter(&condition.inner)?,
+                })
             }
             // Try to extract as Vec<usize> for indices
             else if let Ok(indices) = filter_param.extract::<Vec<usize>>(py) {
@@ -1228,61 +2387,99 @@ impl PyDataFrame {
 
         for column_name in column_names {
             if let Some(series) = self.inner.get_column(column_name) {
-                match series.filter(&indices) {
-                    Ok(filtered_series) => {
-                        new_series.insert(column_name.clone(), filtered_series);
-                    }
-                    Err(e) => {
-                        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                            e.to_string(),
-                        ))
-                    }
-                }
+                new_series.insert(column_name.clone(), series.filter(&indices)?);
             }
         }
 
-        match DataFrame::new(new_series) {
-            Ok(df) => Ok(PyDataFrame { inner: df }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: DataFrame::new(new_series)?,
+        })
     }
 
     /// Add a computed column
     pub fn with_column(&self, name: &str, expr: &PyExpr) -> PyResult<Self> {
-        match self.inner.with_column(name, &expr.inner) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+        Ok(PyDataFrame {
+            inner: self.inner.with_column(name, &expr.inner)?,
+        })
+    }
+
+    /// Add several derived columns from `(name, expr)` pairs in one pass
+    pub fn with_columns(&self, py: Python<'_>, columns: Vec<(String, PyExpr)>) -> PyResult<Self> {
+        let columns: Vec<(&str, crate::expressions::Expr)> = columns
+            .iter()
+            .map(|(name, expr)| (name.as_str(), expr.inner.clone()))
+            .collect();
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| self.inner.with_columns(&columns))?,
+        })
+    }
+
+    /// Project onto expressions; computed ones must be named with `alias`
+    pub fn select_exprs(&self, py: Python<'_>, exprs: Vec<PyExpr>) -> PyResult<Self> {
+        let exprs: Vec<_> = exprs.into_iter().map(|expr| expr.inner).collect();
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| self.inner.select_exprs(&exprs))?,
+        })
     }
 
     /// Export to CSV
-    pub fn to_csv(&self, path: &str) -> PyResult<()> {
-        match self.inner.to_csv(path) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string())),
-        }
+    pub fn to_csv(&self, py: Python<'_>, path: &str) -> PyResult<()> {
+        py.allow_threads(|| self.inner.to_csv(path))?;
+        Ok(())
     }
 
     /// Load from JSON
     #[staticmethod]
-    pub fn from_json(path: &str) -> PyResult<Self> {
-        match DataFrame::from_json(path) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string())),
-        }
+    pub fn from_json(py: Python<'_>, path: &str) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| DataFrame::from_json(path))?,
+        })
+    }
+
+    /// Load the records of an XML file matched by `record_path`, with columns from
+    /// `(column, path)` field mappings or from the records' attributes and children
+    #[staticmethod]
+    #[pyo3(signature = (path, record_path, fields=None))]
+    pub fn from_xml(
+        py: Python<'_>,
+        path: &str,
+        record_path: &str,
+        fields: Option<Vec<(String, String)>>,
+    ) -> PyResult<Self> {
+        let fields = fields.unwrap_or_default();
+        let fields: Vec<(&str, &str)> = fields
+            .iter()
+            .map(|(column, field)| (column.as_str(), field.as_str()))
+            .collect();
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| DataFrame::from_xml(path, record_path, &fields))?,
+        })
     }
 
     /// Load from CSV
     #[staticmethod]
-    pub fn from_csv(path: &str) -> PyResult<Self> {
-        match DataFrame::from_csv(path) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string())),
+    pub fn from_csv(py: Python<'_>, path: &str) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| DataFrame::from_csv(path))?,
+        })
+    }
+
+    /// Load and stack every CSV file matched by a glob pattern, optionally adding
+    /// a column with each row's source path
+    #[staticmethod]
+    #[pyo3(signature = (pattern, source_column=None))]
+    pub fn from_csv_glob(
+        py: Python<'_>,
+        pattern: &str,
+        source_column: Option<String>,
+    ) -> PyResult<Self> {
+        let mut options = crate::io::GlobReadOptions::new();
+        if let Some(name) = source_column {
+            options = options.source_column(name);
         }
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| DataFrame::from_csv_glob_with_options(pattern, &options))?,
+        })
     }
 
     /// Export to JSON (placeholder - not yet implemented)
@@ -1295,38 +2492,884 @@ impl PyDataFrame {
     /// Join with another DataFrame
     pub fn join(
         &self,
+        py: Python<'_>,
         other: &PyDataFrame,
         on_column: &str,
         join_type: &PyJoinType,
     ) -> PyResult<Self> {
-        let jt = match join_type {
-            PyJoinType::Inner => crate::dataframe::join::JoinType::Inner,
-            PyJoinType::Left => crate::dataframe::join::JoinType::Left,
-            PyJoinType::Right => crate::dataframe::join::JoinType::Right,
-        };
+        let join_type = to_join_type(join_type);
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| self.inner.join(&other.inner, on_column, join_type))?,
+        })
+    }
 
-        match self.inner.join(&other.inner, on_column, jt) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
-        }
+    /// Join rows to those of `other` whose `[right_lower, right_upper]` interval
+    /// contains `left_on`
+    pub fn join_range(
+        &self,
+        py: Python<'_>,
+        other: &PyDataFrame,
+        left_on: &str,
+        right_lower: &str,
+        right_upper: &str,
+    ) -> PyResult<Self> {
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| {
+                self.inner
+                    .join_range(&other.inner, left_on, right_lower, right_upper)
+            })?,
+        })
+    }
+
+    /// Join rows for which every `(left_column, op, right_column)` predicate
+    /// holds, with `op` one of "==", "!=", "<", "<=", ">" or ">="
+    pub fn join_where(
+        &self,
+        py: Python<'_>,
+        other: &PyDataFrame,
+        predicates: Vec<(String, String, String)>,
+        join_type: &PyJoinType,
+    ) -> PyResult<Self> {
+        use crate::dataframe::conditional_join::{JoinOp, JoinPredicate};
+        let predicates = predicates
+            .iter()
+            .map(|(left, op, right)| {
+                let op = match op.as_str() {
+                    "==" => JoinOp::Eq,
+                    "!=" => JoinOp::Ne,
+                    "<" => JoinOp::Lt,
+                    "<=" => JoinOp::Lte,
+                    ">" => JoinOp::Gt,
+                    ">=" => JoinOp::Gte,
+                    other => {
+                        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                            "Unknown join operator '{other}'"
+                        )))
+                    }
+                };
+                Ok(JoinPredicate::new(left, op, right))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let join_type = to_join_type(join_type);
+        Ok(PyDataFrame {
+            inner: py
+                .allow_threads(|| self.inner.join_where(&other.inner, &predicates, join_type))?,
+        })
     }
 
     /// Perform an ultra-fast inner join using SIMD-accelerated operations
     pub fn fast_inner_join(
         &self,
+        py: Python<'_>,
         other: &PyDataFrame,
         left_on: &str,
         right_on: &str,
     ) -> PyResult<Self> {
-        match UltraFastJoin::inner_join_i32(&self.inner, &other.inner, left_on, right_on) {
-            Ok(result) => Ok(PyDataFrame { inner: result }),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                e.to_string(),
-            )),
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| {
+                UltraFastJoin::inner_join_i32(&self.inner, &other.inner, left_on, right_on)
+            })?,
+        })
+    }
+
+    /// Apply a Python function along an axis
+    ///
+    /// With `axis=0`, `func` is called once per column with a `PySeries` (or a NumPy
+    /// array when `batched=True`). If every call returns a column-length array, list
+    /// or series the result is a DataFrame of those columns; if every call returns a
+    /// scalar the result is a one-row DataFrame.
+    ///
+    /// With `axis=1`, `func` is called once per row with a `{column: value}` dict and
+    /// the results are returned as a series. With `batched=True` it is instead called
+    /// once with a `{column: array}` dict and must return a column-length result.
+    #[pyo3(signature = (func, axis=0, batched=false))]
+    pub fn apply(
+        &self,
+        py: Python<'_>,
+        func: &Bound<'_, PyAny>,
+        axis: usize,
+        batched: bool,
+    ) -> PyResult<PyObject> {
+        let names = self.sorted_column_names();
+        let len = self.inner.row_count();
+        match axis {
+            0 => {
+                let mut columns = Vec::with_capacity(names.len());
+                let mut scalars = Vec::with_capacity(names.len());
+                for name in &names {
+                    let column = PySeries {
+                        inner: self
+                            .inner
+                            .get_column(name)
+                            .expect("column listed by name")
+                            .clone(),
+                    };
+                    let result = if batched {
+                        func.call1((column.to_numpy(py)?,))?
+                    } else {
+                        func.call1((column,))?
+                    };
+                    match series_from_py_result(name, &result, len)? {
+                        Some(series) => columns.push(series),
+                        None => {
+                            scalars.push(series_from_values(name, vec![py_to_value(&result)?])?)
+                        }
+                    }
+                }
+                if !columns.is_empty() && !scalars.is_empty() {
+                    return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                        "apply(axis=0) function must return either columns or scalars, not both",
+                    ));
+                }
+                let columns = if columns.is_empty() { scalars } else { columns };
+                PyDataFrame {
+                    inner: DataFrame::new(
+                        columns
+                            .into_iter()
+                            .map(|s| (s.name().to_string(), s))
+                            .collect(),
+                    )?,
+                }
+                .into_py_any(py)
+            }
+            1 if batched => {
+                let arrays = PyDict::new(py);
+                for name in &names {
+                    let column = PySeries {
+                        inner: self
+                            .inner
+                            .get_column(name)
+                            .expect("column listed by name")
+                            .clone(),
+                    };
+                    arrays.set_item(name, column.to_numpy(py)?)?;
+                }
+                let result = func.call1((arrays,))?;
+                match series_from_py_result("", &result, len)? {
+                    Some(inner) => PySeries { inner }.into_py_any(py),
+                    None => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                        "A batched function must return an array, list or series",
+                    )),
+                }
+            }
+            1 => {
+                let mut values = Vec::with_capacity(len);
+                for i in 0..len {
+                    let row = row_to_py(py, &self.inner, &names, i, true)?;
+                    values.push(py_to_value(&func.call1((row,))?)?);
+                }
+                PySeries {
+                    inner: series_from_values("", values)?,
+                }
+                .into_py_any(py)
+            }
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "axis must be 0 or 1, got {}",
+                axis
+            ))),
         }
     }
+
+    /// First `n` rows
+    #[pyo3(signature = (n=5))]
+    pub fn head(&self, n: usize) -> PyResult<Self> {
+        self.filter_by_indices((0..n.min(self.inner.row_count())).collect())
+    }
+
+    /// Last `n` rows
+    #[pyo3(signature = (n=5))]
+    pub fn tail(&self, n: usize) -> PyResult<Self> {
+        let len = self.inner.row_count();
+        self.filter_by_indices((len.saturating_sub(n)..len).collect())
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.inner.row_count()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// Iterate over column names, like a dict
+    pub fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.column_names())?.try_iter()
+    }
+
+    /// `df["a"]` returns a column, `df[["a", "b"]]` selects columns, and a
+    /// boolean series, `PyCondition`, `PyExpr`, slice or list of row indices
+    /// selects rows
+    pub fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        if let Ok(name) = key.extract::<String>() {
+            return self.get_column(&name)?.into_py_any(py);
+        }
+        if let Ok(columns) = key.extract::<Vec<String>>() {
+            return self.select(columns)?.into_py_any(py);
+        }
+        if let Ok(condition) = key.extract::<PyCondition>() {
+            return PyDataFrame {
+                inner: self.inner.filter(&condition.inner)?,
+            }
+            .into_py_any(py);
+        }
+        if let Ok(expr) = key.extract::<PyExpr>() {
+            return PyDataFrame {
+                inner: self
+                    .inner
+                    .clone()
+                    .lazy()
+                    .filter((&expr.inner).into())
+                    .collect()?,
+            }
+            .into_py_any(py);
+        }
+        let indices = row_indices(key, self.inner.row_count())?;
+        self.filter_by_indices(indices)?.into_py_any(py)
+    }
+
+    /// Start a lazy query over this DataFrame
+    pub fn lazy(&self) -> PyLazyDataFrame {
+        PyLazyDataFrame {
+            inner: self.inner.clone().lazy(),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+fn to_join_type(join_type: &PyJoinType) -> crate::dataframe::join::JoinType {
+    match join_type {
+        PyJoinType::Inner => crate::dataframe::join::JoinType::Inner,
+        PyJoinType::Left => crate::dataframe::join::JoinType::Left,
+        PyJoinType::Right => crate::dataframe::join::JoinType::Right,
+    }
+}
+
+/// Python wrapper for lazily evaluated DataFrame queries
+#[cfg(feature = "python")]
+#[pyclass]
+#[derive(Clone)]
+pub struct PyLazyDataFrame {
+    pub(crate) inner: LazyDataFrame,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyLazyDataFrame {
+    /// Keep rows where the predicate evaluates to true
+    pub fn filter(&self, predicate: &PyExpr) -> Self {
+        PyLazyDataFrame {
+            inner: self.inner.clone().filter((&predicate.inner).into()),
+        }
+    }
+
+    /// Keep only the named columns
+    pub fn select(&self, columns: Vec<String>) -> Self {
+        let exprs = columns.iter().map(|name| crate::lazy::col(name)).collect();
+        PyLazyDataFrame {
+            inner: self.inner.clone().select(exprs),
+        }
+    }
+
+    /// Add or replace a column computed from an expression
+    pub fn with_column(&self, name: &str, expr: &PyExpr) -> Self {
+        PyLazyDataFrame {
+            inner: self.inner.clone().with_column(name, (&expr.inner).into()),
+        }
+    }
+
+    /// Group by the given columns; call `agg` on the result
+    pub fn group_by(&self, keys: Vec<String>) -> PyLazyGroupBy {
+        PyLazyGroupBy {
+            input: self.inner.clone(),
+            keys,
+        }
+    }
+
+    /// Join with another lazy query on a shared column
+    pub fn join(&self, other: &PyLazyDataFrame, on_column: &str, join_type: &PyJoinType) -> Self {
+        PyLazyDataFrame {
+            inner: self
+                .inner
+                .clone()
+                .join(other.inner.clone(), on_column, to_join_type(join_type)),
+        }
+    }
+
+    /// Sort by the given columns
+    #[pyo3(signature = (by, ascending=true))]
+    pub fn sort(&self, by: Vec<String>, ascending: bool) -> Self {
+        PyLazyDataFrame {
+            inner: self.inner.clone().sort(by, ascending),
+        }
+    }
+
+    /// Optimize and execute the query
+    pub fn collect(&self, py: Python<'_>) -> PyResult<PyDataFrame> {
+        Ok(PyDataFrame {
+            inner: py.allow_threads(|| self.inner.clone().collect())?,
+        })
+    }
+
+    /// Execute the query and return `(result, profile)`, where the profile has
+    /// one row per operator with its wall time, row counts and estimated memory
+    pub fn profile(&self, py: Python<'_>) -> PyResult<(PyDataFrame, PyDataFrame)> {
+        let (result, profile) = py.allow_threads(|| self.inner.clone().profile())?;
+        Ok((
+            PyDataFrame { inner: result },
+            PyDataFrame { inner: profile },
+        ))
+    }
+
+    /// Describe the query plan, after optimization unless `optimized=False`
+    #[pyo3(signature = (optimized=true))]
+    pub fn explain(&self, optimized: bool) -> String {
+        self.inner.explain(optimized)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("PyLazyDataFrame(\n{})", self.inner.explain(false))
+    }
+}
+
+/// Python wrapper for a pending lazy group-by
+#[cfg(feature = "python")]
+#[pyclass]
+pub struct PyLazyGroupBy {
+    pub(crate) input: LazyDataFrame,
+    pub(crate) keys: Vec<String>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyLazyGroupBy {
+    /// Aggregate with `(column, function)` pairs, where function is one of
+    /// `sum`, `mean`, `count`, `min` or `max`
+    pub fn agg(&self, aggregations: Vec<(String, String)>) -> PyResult<PyLazyDataFrame> {
+        let aggregations = aggregations
+            .into_iter()
+            .map(|(column, func)| match func.as_str() {
+                "sum" => Ok(Aggregation::Sum(column)),
+                "mean" => Ok(Aggregation::Mean(column)),
+                "count" => Ok(Aggregation::Count(column)),
+                "min" => Ok(Aggregation::Min(column)),
+                "max" => Ok(Aggregation::Max(column)),
+                other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unsupported aggregation: {}",
+                    other
+                ))),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyLazyDataFrame {
+            inner: self
+                .input
+                .clone()
+                .group_by(self.keys.clone())
+                .agg(aggregations),
+        })
+    }
+}
+
+#[cfg(feature = "python")]
+impl PyDataFrame {
+    fn sorted_column_names(&self) -> Vec<String> {
+        let mut names = self.column_names();
+        names.sort();
+        names
+    }
+}
+
+/// Iterator returned by `PyDataFrame.iter_rows`
+#[cfg(feature = "python")]
+#[pyclass]
+pub struct PyRowIterator {
+    frame: DataFrame,
+    names: Vec<String>,
+    named: bool,
+    index: usize,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyRowIterator {
+    pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        if self.index >= self.frame.row_count() {
+            return Ok(None);
+        }
+        let row = row_to_py(py, &self.frame, &self.names, self.index, self.named)?;
+        self.index += 1;
+        Ok(Some(row))
+    }
+}
+
+/// Convert one row into a tuple, or a dict keyed by column name when `named`
+#[cfg(feature = "python")]
+fn row_to_py(
+    py: Python<'_>,
+    df: &DataFrame,
+    names: &[String],
+    row: usize,
+    named: bool,
+) -> PyResult<PyObject> {
+    let values = names
+        .iter()
+        .map(|name| {
+            let series = df.get_column(name).expect("column listed by name");
+            value_to_py(py, series.get_value(row).unwrap_or(Value::Null))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    if named {
+        let dict = PyDict::new(py);
+        for (name, value) in names.iter().zip(values) {
+            dict.set_item(name, value)?;
+        }
+        dict.into_py_any(py)
+    } else {
+        PyTuple::new(py, values)?.into_py_any(py)
+    }
+}
+
+/// Convert a Python scalar (or `PyValue`) into a `Value`; `None` becomes null
+#[cfg(feature = "python")]
+fn py_to_value(obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if obj.is_none() {
+        Ok(Value::Null)
+    } else if let Ok(value) = obj.extract::<PyValue>() {
+        Ok(value.inner)
+    } else if obj.is_instance_of::<PyBool>() {
+        Ok(Value::Bool(obj.extract()?))
+    } else if let Ok(v) = obj.extract::<i32>() {
+        Ok(Value::I32(v))
+    } else if let Ok(v) = obj.extract::<f64>() {
+        Ok(Value::F64(v))
+    } else if let Ok(v) = obj.extract::<String>() {
+        Ok(Value::String(v))
+    } else {
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+            "Unsupported value type: {}",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+/// Convert a `Value` into the matching Python object; nulls become `None`
+#[cfg(feature = "python")]
+fn value_to_py(py: Python<'_>, value: Value) -> PyResult<PyObject> {
+    match value {
+        Value::I32(v) => v.into_py_any(py),
+        Value::F64(v) => v.into_py_any(py),
+        Value::String(v) => v.into_py_any(py),
+        Value::Bool(v) => v.into_py_any(py),
+        Value::DateTime(v) => v.into_py_any(py),
+        Value::Binary(v) => Ok(PyBytes::new(py, &v).into_any().unbind()),
+        Value::Null => Ok(py.None()),
+    }
+}
+
+/// Build a series from computed values
+///
+/// The type comes from the non-null values, with mixed `I32`/`F64` values widened
+/// to `F64`; any other mix of types is a `TypeError`. All-null input gives `F64`.
+#[cfg(feature = "python")]
+fn series_from_values(name: &str, values: Vec<Value>) -> PyResult<Series> {
+    let mut data_type: Option<DataType> = None;
+    for value in values.iter().filter(|v| !matches!(v, Value::Null)) {
+        data_type = match (data_type, value.data_type()) {
+            (None, t) => Some(t),
+            (Some(a), b) if a == b => Some(a),
+            (Some(DataType::I32), DataType::F64) | (Some(DataType::F64), DataType::I32) => {
+                Some(DataType::F64)
+            }
+            (Some(a), b) => {
+                return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                    "Cannot build series '{}' from both {:?} and {:?} values",
+                    name, a, b
+                )))
+            }
+        };
+    }
+    Ok(match data_type {
+        Some(DataType::I32) => Series::new_i32(name, values.iter().map(Value::as_i32).collect()),
+        Some(DataType::Bool) => Series::new_bool(name, values.iter().map(Value::as_bool).collect()),
+        Some(DataType::String) => Series::new_string(
+            name,
+            values.iter().map(|v| v.as_string().cloned()).collect(),
+        ),
+        Some(DataType::DateTime) => {
+            Series::new_datetime(name, values.iter().map(Value::as_datetime).collect())
+        }
+        Some(DataType::Binary) => Series::new_binary(
+            name,
+            values
+                .iter()
+                .map(|v| v.as_binary().map(<[u8]>::to_vec))
+                .collect(),
+        ),
+        Some(DataType::F64) | None => Series::new_f64(
+            name,
+            values
+                .iter()
+                .map(|v| match v {
+                    Value::I32(i) => Some(*i as f64),
+                    v => v.as_f64(),
+                })
+                .collect(),
+        ),
+    })
+}
+
+/// Convert a UDF result covering a whole column into a series of length `len`
+///
+/// Accepts a `PySeries`, a 1-D NumPy array or a list/tuple of scalars. Returns
+/// `None` for anything else so callers can treat it as a scalar.
+#[cfg(feature = "python")]
+fn series_from_py_result(
+    name: &str,
+    result: &Bound<'_, PyAny>,
+    len: usize,
+) -> PyResult<Option<Series>> {
+    let series = if let Ok(series) = result.extract::<PySeries>() {
+        let mut series = series.inner;
+        series.set_name(name);
+        series
+    } else if result.hasattr("__array_interface__")? {
+        series_from_numpy(name, result)?
+    } else if result.is_instance_of::<PyList>() || result.is_instance_of::<PyTuple>() {
+        let values = result
+            .try_iter()?
+            .map(|item| py_to_value(&item?))
+            .collect::<PyResult<Vec<_>>>()?;
+        series_from_values(name, values)?
+    } else {
+        return Ok(None);
+    };
+
+    if series.len() != len {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Function returned {} values for '{}', expected {}",
+            series.len(),
+            name,
+            len
+        )));
+    }
+    Ok(Some(series))
+}
+
+/// Apply a binary expression operator between an expression and another
+/// `PyExpr` or Python scalar, swapping the operands when `reflected`
+#[cfg(feature = "python")]
+fn expr_binary_op(
+    expr: &crate::expressions::Expr,
+    other: &Bound<'_, PyAny>,
+    reflected: bool,
+    op: fn(
+        Box<crate::expressions::Expr>,
+        Box<crate::expressions::Expr>,
+    ) -> crate::expressions::Expr,
+) -> PyResult<PyExpr> {
+    let other = match other.extract::<PyExpr>() {
+        Ok(other) => other.inner,
+        Err(_) => crate::expressions::Expr::Literal(py_to_value(other)?),
+    };
+    let (left, right) = if reflected {
+        (other, expr.clone())
+    } else {
+        (expr.clone(), other)
+    };
+    Ok(PyExpr {
+        inner: op(Box::new(left), Box::new(right)),
+    })
+}
+
+/// Apply a binary expression operator elementwise between a series and another
+/// series or a broadcast Python scalar
+///
+/// Nulls on either side produce a null, and mixed `I32`/`F64` operands are
+/// widened to `F64` before the operator is evaluated.
+#[cfg(feature = "python")]
+fn series_binary_op(
+    series: &Series,
+    other: &Bound<'_, PyAny>,
+    reflected: bool,
+    op: fn(
+        Box<crate::expressions::Expr>,
+        Box<crate::expressions::Expr>,
+    ) -> crate::expressions::Expr,
+) -> PyResult<PySeries> {
+    let len = series.len();
+    let others: Vec<Value> = match other.extract::<PySeries>() {
+        Ok(other) if other.inner.len() != len => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Series lengths differ: {} vs {}",
+                len,
+                other.inner.len()
+            )))
+        }
+        Ok(other) => (0..len)
+            .map(|i| other.inner.get_value(i).unwrap_or(Value::Null))
+            .collect(),
+        Err(_) => vec![py_to_value(other)?; len],
+    };
+
+    // Literal-only expressions never read from the frame they are evaluated against
+    let scratch = DataFrame::new(HashMap::new())?;
+    let mut values = Vec::with_capacity(len);
+    for (i, right) in others.into_iter().enumerate() {
+        let left = series.get_value(i).unwrap_or(Value::Null);
+        let (left, right) = match (left, right) {
+            (Value::Null, _) | (_, Value::Null) => {
+                values.push(Value::Null);
+                continue;
+            }
+            (Value::I32(l), Value::F64(r)) => (Value::F64(l as f64), Value::F64(r)),
+            (Value::F64(l), Value::I32(r)) => (Value::F64(l), Value::F64(r as f64)),
+            pair => pair,
+        };
+        let (left, right) = if reflected {
+            (right, left)
+        } else {
+            (left, right)
+        };
+        let expr = op(
+            Box::new(crate::expressions::Expr::Literal(left)),
+            Box::new(crate::expressions::Expr::Literal(right)),
+        );
+        values.push(expr.evaluate(&scratch, 0)?);
+    }
+    Ok(PySeries {
+        inner: series_from_values(series.name(), values)?,
+    })
+}
+
+/// Resolve a row selector (slice, list of indices or boolean series) into row indices
+#[cfg(feature = "python")]
+fn row_indices(key: &Bound<'_, PyAny>, len: usize) -> PyResult<Vec<usize>> {
+    if let Ok(slice) = key.downcast::<PySlice>() {
+        let range = slice.indices(len as isize)?;
+        return Ok((0..range.slicelength)
+            .map(|i| (range.start + i as isize * range.step) as usize)
+            .collect());
+    }
+    if let Ok(mask) = key.extract::<PySeries>() {
+        return match &mask.inner {
+            Series::Bool(_, values, bitmap) if values.len() == len => Ok(values
+                .iter()
+                .zip(bitmap)
+                .enumerate()
+                .filter(|(_, (&value, &valid))| value && valid)
+                .map(|(i, _)| i)
+                .collect()),
+            Series::Bool(_, values, _) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Boolean mask has length {}, expected {}", values.len(), len),
+            )),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Only boolean series can be used as a row mask",
+            )),
+        };
+    }
+    if let Ok(indices) = key.extract::<Vec<usize>>() {
+        return Ok(indices);
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+        "Unsupported index type: {}",
+        key.get_type().name()?
+    )))
+}
+
+/// Build a Series from a 1-D NumPy array (optionally a masked array)
+#[cfg(feature = "python")]
+fn series_from_numpy(name: &str, array: &Bound<'_, PyAny>) -> PyResult<Series> {
+    let py = array.py();
+    let ma = py.import("numpy.ma")?;
+    let (data, mask) = if ma.getattr("isMaskedArray")?.call1((array,))?.extract()? {
+        let mask = ma.getattr("getmaskarray")?.call1((array,))?;
+        let mask = mask.downcast::<PyArray1<bool>>()?.readonly();
+        (array.getattr("data")?, Some(mask.as_array().to_vec()))
+    } else {
+        (array.clone(), None)
+    };
+
+    fn with_mask<T: Copy>(values: Vec<T>, mask: &Option<Vec<bool>>) -> Vec<Option<T>> {
+        match mask {
+            Some(mask) => values
+                .into_iter()
+                .zip(mask.iter())
+                .map(|(v, &masked)| if masked { None } else { Some(v) })
+                .collect(),
+            None => values.into_iter().map(Some).collect(),
+        }
+    }
+
+    if let Ok(arr) = data.downcast::<PyArray1<i32>>() {
+        let values = arr.readonly().as_array().to_vec();
+        Ok(Series::new_i32(name, with_mask(values, &mask)))
+    } else if let Ok(arr) = data.downcast::<PyArray1<i64>>() {
+        let values = arr
+            .readonly()
+            .as_array()
+            .iter()
+            .map(|&v| i32::try_from(v))
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| {
+                PyErr::new::<pyo3::exceptions::PyOverflowError, _>(format!(
+                    "Column '{}' contains values outside the I32 range",
+                    name
+                ))
+            })?;
+        Ok(Series::new_i32(name, with_mask(values, &mask)))
+    } else if let Ok(arr) = data.downcast::<PyArray1<f64>>() {
+        let values = arr.readonly().as_array().to_vec();
+        Ok(Series::new_f64(name, with_mask(values, &mask)))
+    } else if let Ok(arr) = data.downcast::<PyArray1<bool>>() {
+        let values = arr.readonly().as_array().to_vec();
+        Ok(Series::new_bool(name, with_mask(values, &mask)))
+    } else {
+        let dtype = data
+            .getattr("dtype")
+            .map(|d| d.to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+            "Unsupported NumPy dtype '{}' for column '{}'",
+            dtype, name
+        )))
+    }
+}
+
+/// Build a Series from a pandas Series without pyarrow, going through NumPy
+#[cfg(feature = "python")]
+fn series_from_pandas_column(name: &str, column: &Bound<'_, PyAny>) -> PyResult<Series> {
+    let py = column.py();
+    let mask = column.call_method0("isna")?.call_method0("to_numpy")?;
+    let kind: String = column.getattr("dtype")?.getattr("kind")?.extract()?;
+
+    let to_numpy = |dtype: &str, na_value: PyObject| -> PyResult<Bound<'_, PyAny>> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("dtype", dtype)?;
+        kwargs.set_item("na_value", na_value)?;
+        column.call_method("to_numpy", (), Some(&kwargs))
+    };
+
+    let data = match kind.as_str() {
+        "i" | "u" => to_numpy("int64", 0i64.into_pyobject(py)?.into_any().unbind())?,
+        "f" => to_numpy("float64", 0f64.into_pyobject(py)?.into_any().unbind())?,
+        "b" => to_numpy(
+            "bool",
+            false.into_pyobject(py)?.to_owned().into_any().unbind(),
+        )?,
+        "M" => {
+            let values = column
+                .call_method1("to_numpy", ("datetime64[ns]",))?
+                .call_method1("view", ("int64",))?;
+            let values = values.downcast::<PyArray1<i64>>()?.readonly();
+            let mask = mask.downcast::<PyArray1<bool>>()?.readonly();
+            let data = values
+                .as_array()
+                .iter()
+                .zip(mask.as_array().iter())
+                .map(|(&v, &missing)| (!missing).then(|| v.div_euclid(1_000_000_000)))
+                .collect();
+            return Ok(Series::new_datetime(name, data));
+        }
+        _ => {
+            let values: Vec<Bound<'_, PyAny>> = column.call_method0("tolist")?.extract()?;
+            let mask: Vec<bool> = mask.extract()?;
+            let data = values
+                .iter()
+                .zip(mask)
+                .map(|(v, missing)| {
+                    if missing {
+                        Ok(None)
+                    } else {
+                        Ok(Some(v.str()?.to_string()))
+                    }
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(Series::new_string(name, data));
+        }
+    };
+
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("mask", mask)?;
+    let masked = py
+        .import("numpy.ma")?
+        .getattr("masked_array")?
+        .call((data,), Some(&kwargs))?;
+    series_from_numpy(name, &masked)
+}
+
+/// Import a named Arrow array from an object implementing `__arrow_c_array__`
+#[cfg(feature = "python")]
+fn import_arrow_array(obj: &Bound<'_, PyAny>) -> PyResult<(String, ArrayRef)> {
+    let (schema, array): (Bound<'_, PyCapsule>, Bound<'_, PyCapsule>) =
+        obj.call_method0("__arrow_c_array__")?.extract()?;
+    check_capsule_name(&schema, "arrow_schema")?;
+    check_capsule_name(&array, "arrow_array")?;
+
+    // SAFETY: the capsule names identify the pointers as C data interface structs.
+    // `from_raw` moves the array out and marks the producer's copy as released, so
+    // the capsule destructor will not free it a second time.
+    let (name, data) = unsafe {
+        let ffi_array = FFI_ArrowArray::from_raw(array.pointer() as *mut FFI_ArrowArray);
+        let ffi_schema = &*(schema.pointer() as *const FFI_ArrowSchema);
+        let name = ffi_schema.name().unwrap_or_default().to_string();
+        (name, from_ffi(ffi_array, ffi_schema))
+    };
+    let data = data.map_err(VeloxxError::from)?;
+    Ok((name, make_array(data)))
+}
+
+/// Import a DataFrame from an object implementing `__arrow_c_stream__`
+#[cfg(feature = "python")]
+fn import_arrow_stream(obj: &Bound<'_, PyAny>) -> PyResult<DataFrame> {
+    let stream: Bound<'_, PyCapsule> = obj.call_method0("__arrow_c_stream__")?.extract()?;
+    check_capsule_name(&stream, "arrow_array_stream")?;
+
+    // SAFETY: the capsule name identifies the pointer as an `ArrowArrayStream`, which
+    // `from_raw` takes ownership of, leaving a released stream behind in the capsule.
+    let reader =
+        unsafe { ArrowArrayStreamReader::from_raw(stream.pointer() as *mut FFI_ArrowArrayStream) }
+            .map_err(VeloxxError::from)?;
+    let batches = reader
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(VeloxxError::from)?;
+    Ok(record_batches_to_dataframe(&batches)?)
+}
+
+/// Export a named Arrow array as a pair of `arrow_schema` / `arrow_array` PyCapsules
+#[cfg(feature = "python")]
+fn export_arrow_array<'py>(
+    py: Python<'py>,
+    name: &str,
+    array: &ArrayRef,
+) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+    let ffi_array = FFI_ArrowArray::new(&array.to_data());
+    let schema = export_arrow_schema(py, &Field::new(name, array.data_type().clone(), true))?;
+    let array = PyCapsule::new(py, ffi_array, Some(CString::new("arrow_array")?))?;
+    Ok((schema, array))
+}
+
+/// Export an Arrow field or schema as an `arrow_schema` PyCapsule
+#[cfg(feature = "python")]
+fn export_arrow_schema<'py, T>(py: Python<'py>, schema: &T) -> PyResult<Bound<'py, PyCapsule>>
+where
+    for<'a> FFI_ArrowSchema: TryFrom<&'a T, Error = arrow::error::ArrowError>,
+{
+    let ffi_schema = FFI_ArrowSchema::try_from(schema).map_err(VeloxxError::from)?;
+    PyCapsule::new(py, ffi_schema, Some(CString::new("arrow_schema")?))
+}
+
+#[cfg(feature = "python")]
+fn check_capsule_name(capsule: &Bound<'_, PyCapsule>, expected: &str) -> PyResult<()> {
+    match capsule.name()? {
+        Some(name) if name.to_bytes() == expected.as_bytes() => Ok(()),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Expected a '{}' PyCapsule",
+            expected
+        ))),
+    }
 }
 
 /// High-performance vectorized operations module for Python
@@ -1351,17 +3394,79 @@ pub fn simd_sum_f64(data: Vec<f64>) -> f64 {
     data.optimized_simd_sum()
 }
 
+/// Reference a column in an expression: `col("a") + 1 > 3`
+#[cfg(feature = "python")]
+#[pyfunction]
+pub fn col(name: String) -> PyExpr {
+    PyExpr::column(name)
+}
+
+/// Wrap a Python scalar as a literal expression
+#[cfg(feature = "python")]
+#[pyfunction]
+pub fn lit(value: &Bound<'_, PyAny>) -> PyResult<PyExpr> {
+    Ok(PyExpr {
+        inner: crate::expressions::Expr::Literal(py_to_value(value)?),
+    })
+}
+
+/// Sum of a column (name or `col(...)`) within each group
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "sum")]
+pub fn agg_sum(column: &Bound<'_, PyAny>) -> PyResult<PyAggExpr> {
+    PyAggExpr::new(column, "sum")
+}
+
+/// Mean of a column (name or `col(...)`) within each group
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "mean")]
+pub fn agg_mean(column: &Bound<'_, PyAny>) -> PyResult<PyAggExpr> {
+    PyAggExpr::new(column, "mean")
+}
+
+/// Non-null count of a column (name or `col(...)`) within each group
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "count")]
+pub fn agg_count(column: &Bound<'_, PyAny>) -> PyResult<PyAggExpr> {
+    PyAggExpr::new(column, "count")
+}
+
+/// Minimum of a column (name or `col(...)`) within each group
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "min")]
+pub fn agg_min(column: &Bound<'_, PyAny>) -> PyResult<PyAggExpr> {
+    PyAggExpr::new(column, "min")
+}
+
+/// Maximum of a column (name or `col(...)`) within each group
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(name = "max")]
+pub fn agg_max(column: &Bound<'_, PyAny>) -> PyResult<PyAggExpr> {
+    PyAggExpr::new(column, "max")
+}
+
 /// Create a DataFrame from CSV with high-performance parsing
 #[cfg(feature = "python")]
 #[pyfunction]
-pub fn read_csv(file_path: String) -> PyResult<PyDataFrame> {
+pub fn read_csv(py: Python<'_>, file_path: String) -> PyResult<PyDataFrame> {
     use crate::io::CsvReader;
 
     let reader = CsvReader::new();
-    match reader.read_file(&file_path) {
-        Ok(df) => Ok(PyDataFrame { inner: df }),
-        Err(e) => Err(PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string())),
-    }
+    Ok(PyDataFrame {
+        inner: py.allow_threads(|| reader.read_file(&file_path))?,
+    })
+}
+
+/// Order group-by output by key on every call, for reproducible results
+#[cfg(feature = "python")]
+#[pyfunction]
+pub fn set_deterministic(enabled: bool) {
+    crate::dataframe::set_deterministic(enabled);
 }
 
 /// Python module definition
@@ -1372,18 +3477,46 @@ fn veloxx(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySeries>()?;
     m.add_class::<PyDataFrame>()?;
     m.add_class::<PyGroupedDataFrame>()?;
+    m.add_class::<PyLazyDataFrame>()?;
+    m.add_class::<PyLazyGroupBy>()?;
+    m.add_class::<PyRowIterator>()?;
 
     // Helper classes
     m.add_class::<PyDataType>()?;
     m.add_class::<PyJoinType>()?;
     m.add_class::<PyCondition>()?;
     m.add_class::<PyExpr>()?;
+    m.add_class::<PyAggExpr>()?;
     m.add_class::<PyValue>()?;
 
     // High-performance functions
     m.add_function(wrap_pyfunction!(simd_add_f64, m)?)?;
     m.add_function(wrap_pyfunction!(simd_sum_f64, m)?)?;
     m.add_function(wrap_pyfunction!(read_csv, m)?)?;
+    m.add_function(wrap_pyfunction!(set_deterministic, m)?)?;
+    m.add_function(wrap_pyfunction!(col, m)?)?;
+    m.add_function(wrap_pyfunction!(lit, m)?)?;
+    m.add_function(wrap_pyfunction!(agg_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(agg_mean, m)?)?;
+    m.add_function(wrap_pyfunction!(agg_count, m)?)?;
+    m.add_function(wrap_pyfunction!(agg_min, m)?)?;
+    m.add_function(wrap_pyfunction!(agg_max, m)?)?;
+
+    // Unprefixed aliases, so `from veloxx import DataFrame` works alongside `PyDataFrame`
+    for name in [
+        "Series",
+        "DataFrame",
+        "GroupedDataFrame",
+        "LazyDataFrame",
+        "DataType",
+        "JoinType",
+        "Condition",
+        "Expr",
+        "AggExpr",
+        "Value",
+    ] {
+        m.add(name, m.getattr(format!("Py{}", name))?)?;
+    }
 
     Ok(())
 }
@@ -1,4 +1,7 @@
+pub mod sql;
+
 use crate::dataframe::DataFrame;
+use crate::performance::adaptive_filter;
 use crate::series::Series;
 use crate::types::Value;
 use std::cmp::Ordering;
@@ -119,15 +122,15 @@ impl UltraFastQueryEngine {
         let row_count = df.row_count;
         let mut mask = vec![true; row_count];
 
-        // Apply WHERE conditions with predicate pushdown
-        for condition in &query.where_conditions {
-            let mut temp_mask = vec![true; row_count];
-            self.evaluate_condition(df, condition, &mut temp_mask)?;
-
-            // Apply logical AND operation (can be SIMD accelerated)
-            for i in 0..row_count {
-                mask[i] = mask[i] && temp_mask[i];
-            }
+        // Apply WHERE conditions, most selective first; each one only
+        // evaluates the rows the earlier ones kept
+        let conjuncts: Vec<&Condition> = query
+            .where_conditions
+            .iter()
+            .flat_map(adaptive_filter::conjuncts)
+            .collect();
+        for conjunct in adaptive_filter::rank_conjuncts(df, &conjuncts) {
+            self.evaluate_condition(df, conjunct.condition, &mut mask)?;
         }
 
         // Handle aggregations first (before filtering)
@@ -156,7 +159,8 @@ impl UltraFastQueryEngine {
         Ok(result_df)
     }
 
-    /// SIMD-accelerated predicate evaluation
+    /// SIMD-accelerated predicate evaluation; clears `mask` for selected
+    /// rows that fail `condition` and leaves unselected rows untouched
     fn evaluate_condition(
         &self,
         df: &DataFrame,
@@ -174,19 +178,19 @@ impl UltraFastQueryEngine {
                 self.evaluate_compare(df, column, &CompareOp::LessThan, value, mask)
             }
             Condition::And(left, right) => {
-                let mut left_mask = vec![true; mask.len()];
-                let mut right_mask = vec![true; mask.len()];
-                self.evaluate_condition(df, left, &mut left_mask)?;
-                self.evaluate_condition(df, right, &mut right_mask)?;
-                for i in 0..mask.len() {
-                    mask[i] = left_mask[i] && right_mask[i];
-                }
-                Ok(())
+                // The right side only sees rows the left side kept
+                self.evaluate_condition(df, left, mask)?;
+                self.evaluate_condition(df, right, mask)
             }
             Condition::Or(left, right) => {
-                let mut left_mask = vec![false; mask.len()];
-                let mut right_mask = vec![false; mask.len()];
+                let mut left_mask = mask.to_vec();
                 self.evaluate_condition(df, left, &mut left_mask)?;
+                // Rows the left side already matched need no second look
+                let mut right_mask: Vec<bool> = mask
+                    .iter()
+                    .zip(&left_mask)
+                    .map(|(&selected, &matched)| selected && !matched)
+                    .collect();
                 self.evaluate_condition(df, right, &mut right_mask)?;
                 for i in 0..mask.len() {
                     mask[i] = left_mask[i] || right_mask[i];
@@ -194,10 +198,18 @@ impl UltraFastQueryEngine {
                 Ok(())
             }
             Condition::Not(cond) => {
-                let mut inner_mask = vec![true; mask.len()];
+                let mut inner_mask = mask.to_vec();
                 self.evaluate_condition(df, cond, &mut inner_mask)?;
                 for i in 0..mask.len() {
-                    mask[i] = !inner_mask[i];
+                    mask[i] = mask[i] && !inner_mask[i];
+                }
+                Ok(())
+            }
+            Condition::InSubnet(..) => {
+                for (row, selected) in mask.iter_mut().enumerate() {
+                    if *selected {
+                        *selected = condition.evaluate(df, row)?;
+                    }
                 }
                 Ok(())
             }
@@ -221,6 +233,9 @@ impl UltraFastQueryEngine {
             Series::I32(_name, data, validity) => {
                 if let Value::I32(threshold) = value {
                     for (i, (&val, &is_valid)) in data.iter().zip(validity.iter()).enumerate() {
+                        if !mask[i] {
+                            continue;
+                        }
                         if !is_valid {
                             mask[i] = false;
                             continue;
@@ -240,6 +255,9 @@ impl UltraFastQueryEngine {
             Series::F64(_name, data, validity) => {
                 if let Value::F64(threshold) = value {
                     for (i, (&val, &is_valid)) in data.iter().zip(validity.iter()).enumerate() {
+                        if !mask[i] {
+                            continue;
+                        }
                         if !is_valid {
                             mask[i] = false;
                             continue;
@@ -259,6 +277,9 @@ impl UltraFastQueryEngine {
             Series::String(_name, data, validity) => {
                 if let Value::String(threshold) = value {
                     for (i, (val, &is_valid)) in data.iter().zip(validity.iter()).enumerate() {
+                        if !mask[i] {
+                            continue;
+                        }
                         if !is_valid {
                             mask[i] = false;
                             continue;
@@ -278,6 +299,9 @@ impl UltraFastQueryEngine {
             Series::Bool(_name, data, validity) => {
                 if let Value::Bool(threshold) = value {
                     for (i, (&val, &is_valid)) in data.iter().zip(validity.iter()).enumerate() {
+                        if !mask[i] {
+                            continue;
+                        }
                         if !is_valid {
                             mask[i] = false;
                             continue;
@@ -371,6 +395,19 @@ impl UltraFastQueryEngine {
 
                     Series::DateTime(name.clone(), filtered_data, filtered_validity)
                 }
+                Series::Binary(name, data, validity) => {
+                    let mut filtered_data = Vec::new();
+                    let mut filtered_validity = Vec::new();
+
+                    for (i, &include) in mask.iter().enumerate() {
+                        if include {
+                            filtered_data.push(data[i].clone());
+                            filtered_validity.push(validity[i]);
+                        }
+                    }
+
+                    Series::Binary(name.clone(), filtered_data, filtered_validity)
+                }
             };
 
             new_columns.insert(col_name.clone(), filtered_series);
@@ -382,12 +419,13 @@ impl UltraFastQueryEngine {
         Ok(DataFrame {
             columns: new_columns,
             row_count: new_row_count,
+            constraints: df.constraints.clone(),
         })
     }
 
     fn apply_order_by(
         &self,
-        df: DataFrame,
+        mut df: DataFrame,
         order_specs: &[OrderBySpec],
     ) -> Result<DataFrame, Box<dyn std::error::Error>> {
         if df.row_count == 0 {
@@ -430,6 +468,11 @@ impl UltraFastQueryEngine {
                         let val_b = if validity[b] { Some(data[b]) } else { None };
                         val_a.cmp(&val_b)
                     }
+                    Series::Binary(_, data, validity) => {
+                        let val_a = if validity[a] { Some(&data[a]) } else { None };
+                        let val_b = if validity[b] { Some(&data[b]) } else { None };
+                        val_a.cmp(&val_b)
+                    }
                 };
 
                 let final_cmp = if spec.ascending { cmp } else { cmp.reverse() };
@@ -443,6 +486,7 @@ impl UltraFastQueryEngine {
 
         // Reorder all columns based on sorted indices
         let mut new_columns = HashMap::new();
+        let constraints = std::mem::take(&mut df.constraints);
 
         for (col_name, series) in df.columns {
             let reordered_series = match series {
@@ -501,6 +545,17 @@ impl UltraFastQueryEngine {
 
                     Series::DateTime(name, reordered_data, reordered_validity)
                 }
+                Series::Binary(name, data, validity) => {
+                    let mut reordered_data = Vec::with_capacity(data.len());
+                    let mut reordered_validity = Vec::with_capacity(validity.len());
+
+                    for &idx in &indices {
+                        reordered_data.push(data[idx].clone());
+                        reordered_validity.push(validity[idx]);
+                    }
+
+                    Series::Binary(name, reordered_data, reordered_validity)
+                }
             };
 
             new_columns.insert(col_name, reordered_series);
@@ -509,7 +564,9 @@ impl UltraFastQueryEngine {
         Ok(DataFrame {
             columns: new_columns,
             row_count: df.row_count,
-        })
+            constraints: Vec::new(),
+        }
+        .carry_constraints(&constraints, false))
     }
 
     fn apply_limit(
@@ -550,6 +607,11 @@ impl UltraFastQueryEngine {
                     let limited_validity = validity.into_iter().take(limit).collect();
                     Series::DateTime(name, limited_data, limited_validity)
                 }
+                Series::Binary(name, data, validity) => {
+                    let limited_data = data.into_iter().take(limit).collect();
+                    let limited_validity = validity.into_iter().take(limit).collect();
+                    Series::Binary(name, limited_data, limited_validity)
+                }
             };
 
             new_columns.insert(col_name, limited_series);
@@ -558,6 +620,7 @@ impl UltraFastQueryEngine {
         Ok(DataFrame {
             columns: new_columns,
             row_count: limit,
+            constraints: df.constraints,
         })
     }
 
@@ -577,7 +640,9 @@ impl UltraFastQueryEngine {
         Ok(DataFrame {
             columns: new_columns,
             row_count: df.row_count,
-        })
+            constraints: Vec::new(),
+        }
+        .carry_constraints(&df.constraints, true))
     }
 
     fn apply_aggregations(
@@ -634,6 +699,11 @@ impl UltraFastQueryEngine {
                             .zip(mask.iter())
                             .filter(|(&valid, &include)| valid && include)
                             .count(),
+                        Series::Binary(_, _, validity) => validity
+                            .iter()
+                            .zip(mask.iter())
+                            .filter(|(&valid, &include)| valid && include)
+                            .count(),
                     };
                     Series::I32(agg_name.clone(), vec![count as i32], vec![true])
                 }
@@ -810,6 +880,7 @@ impl UltraFastQueryEngine {
         Ok(DataFrame {
             columns: result_columns,
             row_count: 1,
+            constraints: Vec::new(),
         })
     }
 }
@@ -0,0 +1,726 @@
+//! A small SQL dialect for querying a single `DataFrame`.
+//!
+//! Supported syntax:
+//!
+//! ```text
+//! SELECT item [, item ...] [FROM table]
+//!     [WHERE predicate] [GROUP BY column [, column ...]]
+//!     [ORDER BY column [ASC | DESC] [, ...]] [LIMIT n]
+//! ```
+//!
+//! An item is `*`, a column, or one of `sum`, `count`, `min`, `max` and `avg`/`mean`
+//! applied to a column (`count(*)` counts rows), each optionally followed by
+//! `AS alias`. Predicates compare a column with a literal (`=`, `!=`, `<>`, `<`,
+//! `<=`, `>`, `>=`), test `IS [NOT] NULL` or `[NOT] IN (...)`, and combine with
+//! `AND`, `OR`, `NOT` and parentheses; comparisons with null are never true.
+//!
+//! The table name in `FROM` is ignored, since a query always runs against the frame
+//! it is given. Aggregates are named `{column}_{aggregation}` (`count` for
+//! `count(*)`) unless aliased, groups appear in order of first appearance, and
+//! `ORDER BY` refers to output column names.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use veloxx::dataframe::DataFrame;
+//! use veloxx::query::sql;
+//! use veloxx::series::Series;
+//! use veloxx::types::Value;
+//!
+//! let mut columns = HashMap::new();
+//! columns.insert(
+//!     "city".to_string(),
+//!     Series::new_string("city", ["Paris", "Oslo", "Paris"].iter().map(|c| Some(c.to_string())).collect()),
+//! );
+//! columns.insert("sales".to_string(), Series::new_f64("sales", vec![Some(10.0), Some(5.0), Some(2.5)]));
+//! let df = DataFrame::new(columns).unwrap();
+//!
+//! let totals = sql::execute(
+//!     &df,
+//!     "SELECT city, sum(sales) AS total FROM sales WHERE sales > 1 GROUP BY city ORDER BY total DESC",
+//! )
+//! .unwrap();
+//! assert_eq!(totals.get_column("total").unwrap().get_value(0), Some(Value::F64(12.5)));
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::streaming::IncrementalGroupBy;
+use crate::types::Value;
+use crate::VeloxxError;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Helper column holding `true` for every row, used for `count(*)` and for
+/// aggregating without `GROUP BY`
+const ROW_COLUMN: &str = "__veloxx_row";
+
+/// One entry of the `SELECT` list
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectItem {
+    /// `*`
+    Wildcard,
+    Column {
+        name: String,
+        alias: Option<String>,
+    },
+    /// An aggregation; `column` is `None` for `count(*)`
+    Aggregate {
+        function: String,
+        column: Option<String>,
+        alias: Option<String>,
+    },
+}
+
+/// Comparison operators allowed in `WHERE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// A parsed `WHERE` clause
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    Compare(String, CompareOp, Value),
+    /// `column IS NULL`, or `IS NOT NULL` when the flag is set
+    IsNull(String, bool),
+    /// `column IN (...)`, or `NOT IN` when the flag is set
+    In(String, Vec<Value>, bool),
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+}
+
+/// A parsed query that can be run against any number of frames
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlQuery {
+    pub select: Vec<SelectItem>,
+    pub predicate: Option<Predicate>,
+    pub group_by: Vec<String>,
+    /// Output columns to sort by, with `true` for ascending
+    pub order_by: Vec<(String, bool)>,
+    pub limit: Option<usize>,
+}
+
+/// Parses `sql` and runs it against `df`.
+pub fn execute(df: &DataFrame, sql: &str) -> Result<DataFrame, VeloxxError> {
+    SqlQuery::parse(sql)?.execute(df)
+}
+
+impl SqlQuery {
+    /// Parses a `SELECT` statement; a trailing semicolon is allowed.
+    pub fn parse(sql: &str) -> Result<Self, VeloxxError> {
+        let mut parser = Parser {
+            tokens: tokenize(sql)?,
+            pos: 0,
+        };
+        let query = parser.query()?;
+        parser.eat_symbol(";");
+        match parser.peek() {
+            None => Ok(query),
+            Some(token) => Err(parse_error(format!("unexpected '{}'", token))),
+        }
+    }
+
+    /// Runs the query against `df`.
+    pub fn execute(&self, df: &DataFrame) -> Result<DataFrame, VeloxxError> {
+        let mut result = match &self.predicate {
+            Some(predicate) => {
+                let mut keep = Vec::new();
+                for row in 0..df.row_count() {
+                    if predicate.evaluate(df, row)? == Some(true) {
+                        keep.push(row);
+                    }
+                }
+                df.filter_by_indices(&keep)?
+            }
+            None => df.clone(),
+        };
+
+        let aggregated = !self.group_by.is_empty()
+            || self
+                .select
+                .iter()
+                .any(|item| matches!(item, SelectItem::Aggregate { .. }));
+        result = if aggregated {
+            self.aggregate(&result)?
+        } else {
+            self.project(&result)?
+        };
+
+        // Stable sorts applied from the last key to the first give a multi-key order
+        for (column, ascending) in self.order_by.iter().rev() {
+            if result.get_column(column).is_none() {
+                return Err(VeloxxError::ColumnNotFound(column.clone()));
+            }
+            result = result.sort(vec![column.clone()], *ascending)?;
+        }
+        if let Some(limit) = self.limit {
+            if limit < result.row_count() {
+                result = result.filter_by_indices(&(0..limit).collect::<Vec<_>>())?;
+            }
+        }
+        Ok(result)
+    }
+
+    fn project(&self, df: &DataFrame) -> Result<DataFrame, VeloxxError> {
+        let mut outputs = Vec::new();
+        for item in &self.select {
+            match item {
+                SelectItem::Wildcard => {
+                    let mut names: Vec<&String> = df.column_names();
+                    names.sort();
+                    outputs.extend(names.into_iter().map(|name| (name.clone(), name.clone())));
+                }
+                SelectItem::Column { name, alias } => {
+                    outputs.push((name.clone(), alias.clone().unwrap_or_else(|| name.clone())))
+                }
+                SelectItem::Aggregate { .. } => unreachable!("aggregates are handled separately"),
+            }
+        }
+        build_output(df, &outputs)
+    }
+
+    fn aggregate(&self, df: &DataFrame) -> Result<DataFrame, VeloxxError> {
+        let mut aggregations: Vec<(String, &str)> = Vec::new();
+        let mut outputs = Vec::new();
+        for item in &self.select {
+            match item {
+                SelectItem::Wildcard => {
+                    return Err(VeloxxError::InvalidOperation(
+                        "SELECT * cannot be combined with GROUP BY or aggregates".to_string(),
+                    ))
+                }
+                SelectItem::Column { name, alias } => {
+                    if !self.group_by.contains(name) {
+                        return Err(VeloxxError::InvalidOperation(format!(
+                            "Column '{}' must appear in GROUP BY or be aggregated",
+                            name
+                        )));
+                    }
+                    outputs.push((name.clone(), alias.clone().unwrap_or_else(|| name.clone())));
+                }
+                SelectItem::Aggregate {
+                    function,
+                    column,
+                    alias,
+                } => {
+                    let function = if function == "avg" { "mean" } else { function };
+                    let source = column.as_deref().unwrap_or(ROW_COLUMN);
+                    let default_name = match column {
+                        Some(column) => format!("{}_{}", column, function),
+                        None => "count".to_string(),
+                    };
+                    aggregations.push((source.to_string(), function));
+                    outputs.push((
+                        format!("{}_{}", source, function),
+                        alias.clone().unwrap_or(default_name),
+                    ));
+                }
+            }
+        }
+
+        let mut columns: HashMap<String, Series> = HashMap::new();
+        for name in df.column_names() {
+            columns.insert(name.clone(), df.get_column(name).unwrap().clone());
+        }
+        columns.insert(
+            ROW_COLUMN.to_string(),
+            Series::new_bool(ROW_COLUMN, vec![Some(true); df.row_count()]),
+        );
+        let input = DataFrame::new(columns)?;
+        let group_by = if self.group_by.is_empty() {
+            vec![ROW_COLUMN.to_string()]
+        } else {
+            self.group_by.clone()
+        };
+        let mut groups = IncrementalGroupBy::new(
+            group_by,
+            aggregations
+                .iter()
+                .map(|(column, function)| (column.as_str(), *function))
+                .collect(),
+        )?;
+        groups.update(&input)?;
+        build_output(&groups.snapshot()?, &outputs)
+    }
+}
+
+/// Builds a frame from `(source column, output name)` pairs
+fn build_output(df: &DataFrame, outputs: &[(String, String)]) -> Result<DataFrame, VeloxxError> {
+    let mut columns = HashMap::new();
+    for (source, output) in outputs {
+        let mut series = df
+            .get_column(source)
+            .ok_or_else(|| VeloxxError::ColumnNotFound(source.clone()))?
+            .clone();
+        series.set_name(output);
+        if columns.insert(output.clone(), series).is_some() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Duplicate output column '{}'",
+                output
+            )));
+        }
+    }
+    DataFrame::new(columns)
+}
+
+impl Predicate {
+    /// Evaluates the predicate for one row; `None` means unknown (a null comparison).
+    pub fn evaluate(&self, df: &DataFrame, row: usize) -> Result<Option<bool>, VeloxxError> {
+        let cell = |column: &str| -> Result<Value, VeloxxError> {
+            Ok(df
+                .get_column(column)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?
+                .get_value(row)
+                .unwrap_or(Value::Null))
+        };
+        Ok(match self {
+            Predicate::Compare(column, op, literal) => {
+                compare(&cell(column)?, literal)?.map(|ordering| match op {
+                    CompareOp::Eq => ordering == Ordering::Equal,
+                    CompareOp::NotEq => ordering != Ordering::Equal,
+                    CompareOp::Lt => ordering == Ordering::Less,
+                    CompareOp::LtEq => ordering != Ordering::Greater,
+                    CompareOp::Gt => ordering == Ordering::Greater,
+                    CompareOp::GtEq => ordering != Ordering::Less,
+                })
+            }
+            Predicate::IsNull(column, negated) => Some((cell(column)? == Value::Null) != *negated),
+            Predicate::In(column, literals, negated) => {
+                let value = cell(column)?;
+                let mut result = Some(false);
+                for literal in literals {
+                    match compare(&value, literal)? {
+                        Some(Ordering::Equal) => {
+                            result = Some(true);
+                            break;
+                        }
+                        None => result = None,
+                        Some(_) => {}
+                    }
+                }
+                result.map(|found| found != *negated)
+            }
+            Predicate::And(left, right) => {
+                match (left.evaluate(df, row)?, right.evaluate(df, row)?) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }
+            }
+            Predicate::Or(left, right) => match (left.evaluate(df, row)?, right.evaluate(df, row)?)
+            {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            Predicate::Not(inner) => inner.evaluate(df, row)?.map(|value| !value),
+        })
+    }
+}
+
+/// Orders a cell against a literal, treating numbers and timestamps as comparable;
+/// `None` if either side is null.
+fn compare(cell: &Value, literal: &Value) -> Result<Option<Ordering>, VeloxxError> {
+    let numeric = |value: &Value| match value {
+        Value::I32(v) => Some(*v as f64),
+        Value::F64(v) => Some(*v),
+        Value::DateTime(v) => Some(*v as f64),
+        _ => None,
+    };
+    Ok(match (cell, literal) {
+        (Value::Null, _) | (_, Value::Null) => None,
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => match (numeric(cell), numeric(literal)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            _ => {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot compare {:?} with {:?}",
+                    cell, literal
+                )))
+            }
+        },
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// An identifier or keyword; `true` if it was quoted, so never a keyword
+    Word(String, bool),
+    Number(String),
+    Str(String),
+    Symbol(&'static str),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word, _) | Token::Number(word) => write!(f, "{}", word),
+            Token::Str(text) => write!(f, "'{}'", text),
+            Token::Symbol(symbol) => write!(f, "{}", symbol),
+        }
+    }
+}
+
+const SYMBOLS: &[&str] = &[
+    "<=", ">=", "!=", "<>", "=", "<", ">", ",", "(", ")", "*", ";",
+];
+
+fn tokenize(sql: &str) -> Result<Vec<Token>, VeloxxError> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '\'' || c == '"' || c == '`' {
+            // Quoted string literal or identifier; a doubled quote escapes itself
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => return Err(parse_error(format!("unterminated {} quote", c))),
+                    Some(&q) if q == c && chars.get(i + 1) == Some(&c) => {
+                        text.push(c);
+                        i += 2;
+                    }
+                    Some(&q) if q == c => {
+                        i += 1;
+                        break;
+                    }
+                    Some(&other) => {
+                        text.push(other);
+                        i += 1;
+                    }
+                }
+            }
+            tokens.push(if c == '\'' {
+                Token::Str(text)
+            } else {
+                Token::Word(text, true)
+            });
+        } else if c.is_ascii_digit()
+            || (c == '-' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()))
+            || (c == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()))
+        {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect(), false));
+        } else {
+            let symbol = SYMBOLS
+                .iter()
+                .find(|symbol| {
+                    symbol
+                        .chars()
+                        .enumerate()
+                        .all(|(offset, s)| chars.get(i + offset) == Some(&s))
+                })
+                .ok_or_else(|| parse_error(format!("unexpected character '{}'", c)))?;
+            i += symbol.len();
+            tokens.push(Token::Symbol(symbol));
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_error(message: String) -> VeloxxError {
+    VeloxxError::Parsing(format!("Invalid SQL: {}", message))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, VeloxxError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| parse_error("unexpected end of query".to_string()))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn is_keyword(&self, offset: usize, keyword: &str) -> bool {
+        matches!(self.tokens.get(self.pos + offset),
+            Some(Token::Word(word, false)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(0, keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), VeloxxError> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.expected(keyword))
+        }
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = self.peek() == Some(&Token::Symbol(symbol_str(symbol)));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), VeloxxError> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.expected(&format!("'{}'", symbol)))
+        }
+    }
+
+    fn expected(&self, what: &str) -> VeloxxError {
+        match self.peek() {
+            Some(token) => parse_error(format!("expected {} but found '{}'", what, token)),
+            None => parse_error(format!("expected {} at end of query", what)),
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, VeloxxError> {
+        match self.peek() {
+            Some(Token::Word(word, quoted)) if *quoted || !is_reserved(word) => {
+                let word = word.clone();
+                self.pos += 1;
+                Ok(word)
+            }
+            _ => Err(self.expected("a column name")),
+        }
+    }
+
+    fn query(&mut self) -> Result<SqlQuery, VeloxxError> {
+        self.expect_keyword("select")?;
+        let mut select = vec![self.select_item()?];
+        while self.eat_symbol(",") {
+            select.push(self.select_item()?);
+        }
+        if self.eat_keyword("from") {
+            self.identifier()?;
+        }
+        let predicate = if self.eat_keyword("where") {
+            Some(self.or_predicate()?)
+        } else {
+            None
+        };
+        let mut group_by = Vec::new();
+        if self.eat_keyword("group") {
+            self.expect_keyword("by")?;
+            group_by = self.identifier_list()?;
+        }
+        let mut order_by = Vec::new();
+        if self.eat_keyword("order") {
+            self.expect_keyword("by")?;
+            loop {
+                let column = self.identifier()?;
+                let ascending = !self.eat_keyword("desc");
+                if ascending {
+                    self.eat_keyword("asc");
+                }
+                order_by.push((column, ascending));
+                if !self.eat_symbol(",") {
+                    break;
+                }
+            }
+        }
+        let limit = if self.eat_keyword("limit") {
+            match self.next()? {
+                Token::Number(n) => Some(
+                    n.parse()
+                        .map_err(|_| parse_error(format!("invalid LIMIT '{}'", n)))?,
+                ),
+                token => return Err(parse_error(format!("invalid LIMIT '{}'", token))),
+            }
+        } else {
+            None
+        };
+        Ok(SqlQuery {
+            select,
+            predicate,
+            group_by,
+            order_by,
+            limit,
+        })
+    }
+
+    fn identifier_list(&mut self) -> Result<Vec<String>, VeloxxError> {
+        let mut names = vec![self.identifier()?];
+        while self.eat_symbol(",") {
+            names.push(self.identifier()?);
+        }
+        Ok(names)
+    }
+
+    fn select_item(&mut self) -> Result<SelectItem, VeloxxError> {
+        if self.eat_symbol("*") {
+            return Ok(SelectItem::Wildcard);
+        }
+        let is_call = matches!(self.tokens.get(self.pos + 1), Some(Token::Symbol("(")));
+        let item = if is_call {
+            let function = self.next()?.to_string().to_lowercase();
+            if !["sum", "count", "min", "max", "avg", "mean"].contains(&function.as_str()) {
+                return Err(VeloxxError::Unsupported(format!(
+                    "Unsupported SQL function '{}'",
+                    function
+                )));
+            }
+            self.expect_symbol("(")?;
+            let column = if self.eat_symbol("*") {
+                if function != "count" {
+                    return Err(parse_error(format!("{}(*) is not allowed", function)));
+                }
+                None
+            } else {
+                Some(self.identifier()?)
+            };
+            self.expect_symbol(")")?;
+            SelectItem::Aggregate {
+                function,
+                column,
+                alias: self.alias()?,
+            }
+        } else {
+            SelectItem::Column {
+                name: self.identifier()?,
+                alias: self.alias()?,
+            }
+        };
+        Ok(item)
+    }
+
+    fn alias(&mut self) -> Result<Option<String>, VeloxxError> {
+        if self.eat_keyword("as") {
+            Ok(Some(self.identifier()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn or_predicate(&mut self) -> Result<Predicate, VeloxxError> {
+        let mut left = self.and_predicate()?;
+        while self.eat_keyword("or") {
+            left = Predicate::Or(Box::new(left), Box::new(self.and_predicate()?));
+        }
+        Ok(left)
+    }
+
+    fn and_predicate(&mut self) -> Result<Predicate, VeloxxError> {
+        let mut left = self.unary_predicate()?;
+        while self.eat_keyword("and") {
+            left = Predicate::And(Box::new(left), Box::new(self.unary_predicate()?));
+        }
+        Ok(left)
+    }
+
+    fn unary_predicate(&mut self) -> Result<Predicate, VeloxxError> {
+        if self.eat_keyword("not") {
+            return Ok(Predicate::Not(Box::new(self.unary_predicate()?)));
+        }
+        if self.eat_symbol("(") {
+            let inner = self.or_predicate()?;
+            self.expect_symbol(")")?;
+            return Ok(inner);
+        }
+        let column = self.identifier()?;
+        if self.eat_keyword("is") {
+            let negated = self.eat_keyword("not");
+            self.expect_keyword("null")?;
+            return Ok(Predicate::IsNull(column, negated));
+        }
+        let negated = self.is_keyword(0, "not") && self.is_keyword(1, "in");
+        if negated {
+            self.pos += 1;
+        }
+        if self.eat_keyword("in") {
+            self.expect_symbol("(")?;
+            let mut values = vec![self.literal()?];
+            while self.eat_symbol(",") {
+                values.push(self.literal()?);
+            }
+            self.expect_symbol(")")?;
+            return Ok(Predicate::In(column, values, negated));
+        }
+        let op = match self.next()? {
+            Token::Symbol("=") => CompareOp::Eq,
+            Token::Symbol("!=") | Token::Symbol("<>") => CompareOp::NotEq,
+            Token::Symbol("<") => CompareOp::Lt,
+            Token::Symbol("<=") => CompareOp::LtEq,
+            Token::Symbol(">") => CompareOp::Gt,
+            Token::Symbol(">=") => CompareOp::GtEq,
+            token => {
+                return Err(parse_error(format!(
+                    "expected a comparison after '{}' but found '{}'",
+                    column, token
+                )))
+            }
+        };
+        Ok(Predicate::Compare(column, op, self.literal()?))
+    }
+
+    fn literal(&mut self) -> Result<Value, VeloxxError> {
+        match self.next()? {
+            Token::Str(text) => Ok(Value::String(text)),
+            Token::Number(n) => n
+                .parse::<i32>()
+                .map(Value::I32)
+                .or_else(|_| n.parse::<f64>().map(Value::F64))
+                .map_err(|_| parse_error(format!("invalid number '{}'", n))),
+            Token::Word(word, false) if word.eq_ignore_ascii_case("true") => Ok(Value::Bool(true)),
+            Token::Word(word, false) if word.eq_ignore_ascii_case("false") => {
+                Ok(Value::Bool(false))
+            }
+            Token::Word(word, false) if word.eq_ignore_ascii_case("null") => Ok(Value::Null),
+            token => Err(parse_error(format!(
+                "expected a literal but found '{}'",
+                token
+            ))),
+        }
+    }
+}
+
+fn symbol_str(symbol: &str) -> &'static str {
+    SYMBOLS
+        .iter()
+        .find(|s| **s == symbol)
+        .expect("parser only looks for known symbols")
+}
+
+fn is_reserved(word: &str) -> bool {
+    [
+        "select", "from", "where", "group", "order", "by", "limit", "as", "and", "or", "not", "is",
+        "in", "null", "asc", "desc", "true", "false",
+    ]
+    .iter()
+    .any(|keyword| word.eq_ignore_ascii_case(keyword))
+}
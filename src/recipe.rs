@@ -0,0 +1,295 @@
+//! Declarative transformation recipes read from YAML or JSON.
+//!
+//! A [`Recipe`] names an input table and lists steps (filters, derived
+//! columns, joins, aggregations, ...) that are turned into a
+//! [`LazyDataFrame`] plan, so transformations can be configured in a file and
+//! run by a Rust service without recompiling it.
+//!
+//! Each step is a single-key map naming the operation. Expressions are written
+//! the same way: `{col: name}`, `{lit: value}`, `{not: expr}`,
+//! `{dt_truncate: {input: expr, every: "1d"}}`, and the binary operators `eq`,
+//! `neq`, `lt`, `lt_eq`, `gt`, `gt_eq`, `and`, `or`, `add`, `subtract`,
+//! `multiply` and `divide`, which take a list of two operands. Literals must
+//! match the column type they meet, e.g. `20.0` rather than `20` for an `F64`
+//! column.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use veloxx::df;
+//! use veloxx::recipe::Recipe;
+//! use veloxx::types::Value;
+//!
+//! let recipe = Recipe::from_yaml(
+//!     r#"
+//! input: orders
+//! steps:
+//!   - filter: {gt: [{col: amount}, {lit: 10}]}
+//!   - with_column: {name: doubled, expr: {multiply: [{col: amount}, {lit: 2}]}}
+//!   - group_by:
+//!       keys: [city]
+//!       aggregations: [{column: doubled, function: sum}]
+//! "#,
+//! )
+//! .unwrap();
+//!
+//! let orders = df!("city" => ["a", "b", "a"], "amount" => [20, 5, 30]).unwrap();
+//! let inputs = HashMap::from([("orders".to_string(), orders)]);
+//! let totals = recipe.execute(&inputs).unwrap();
+//! assert_eq!(totals.row_count(), 1);
+//! assert_eq!(totals.get_column("doubled_sum").unwrap().get_value(0), Some(Value::I32(100)));
+//! ```
+
+use crate::dataframe::join::JoinType;
+use crate::dataframe::DataFrame;
+use crate::lazy::{self, Aggregation, BinaryOperator, LazyDataFrame};
+use crate::types::Value;
+use crate::VeloxxError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A named input table and the steps applied to it; see the
+/// [module documentation](self) for the format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Recipe {
+    /// Name of the table the steps start from
+    pub input: String,
+    /// Operations applied in order
+    #[serde(default)]
+    pub steps: Vec<RecipeStep>,
+}
+
+/// One operation of a [`Recipe`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipeStep {
+    /// Keep the rows where the expression is true
+    Filter(RecipeExpr),
+    /// Keep only the listed columns
+    Select(Vec<String>),
+    /// Add a column computed from an expression
+    WithColumn { name: String, expr: RecipeExpr },
+    /// Join with another input table on a shared column
+    Join {
+        right: String,
+        on: String,
+        #[serde(default)]
+        how: RecipeJoin,
+    },
+    /// Group by column names or named expressions and aggregate
+    GroupBy {
+        keys: Vec<RecipeKey>,
+        aggregations: Vec<RecipeAggregation>,
+    },
+    /// Aggregate all rows into one
+    Aggregate(Vec<RecipeAggregation>),
+    /// Sort by one or more columns
+    Sort {
+        by: Vec<String>,
+        #[serde(default = "ascending")]
+        ascending: bool,
+    },
+}
+
+fn ascending() -> bool {
+    true
+}
+
+/// How a [`RecipeStep::Join`] treats unmatched rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipeJoin {
+    #[default]
+    Inner,
+    Left,
+    Right,
+}
+
+/// A group key: a column name, or an expression with an output name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecipeKey {
+    Column(String),
+    Computed { name: String, expr: RecipeExpr },
+}
+
+/// An aggregation such as `{column: sales, function: sum}`; the function is one
+/// of `sum`, `mean`, `count`, `min` or `max`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeAggregation {
+    pub column: String,
+    pub function: String,
+}
+
+/// An expression in a recipe; see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipeExpr {
+    Col(String),
+    Lit(RecipeLiteral),
+    Not(Box<RecipeExpr>),
+    DtTruncate {
+        input: Box<RecipeExpr>,
+        every: String,
+    },
+    Eq(Box<[RecipeExpr; 2]>),
+    Neq(Box<[RecipeExpr; 2]>),
+    Lt(Box<[RecipeExpr; 2]>),
+    LtEq(Box<[RecipeExpr; 2]>),
+    Gt(Box<[RecipeExpr; 2]>),
+    GtEq(Box<[RecipeExpr; 2]>),
+    And(Box<[RecipeExpr; 2]>),
+    Or(Box<[RecipeExpr; 2]>),
+    Add(Box<[RecipeExpr; 2]>),
+    Subtract(Box<[RecipeExpr; 2]>),
+    Multiply(Box<[RecipeExpr; 2]>),
+    Divide(Box<[RecipeExpr; 2]>),
+}
+
+/// A literal value; whole numbers that fit become `I32`, other numbers `F64`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecipeLiteral {
+    Null,
+    Bool(bool),
+    Int(i32),
+    Float(f64),
+    String(String),
+}
+
+impl Recipe {
+    /// Parses a recipe from JSON.
+    pub fn from_json(json: &str) -> Result<Self, VeloxxError> {
+        serde_json::from_str(json)
+            .map_err(|e| VeloxxError::Parsing(format!("Invalid JSON recipe: {}", e)))
+    }
+
+    /// Parses a recipe from YAML.
+    pub fn from_yaml(yaml: &str) -> Result<Self, VeloxxError> {
+        // serde_yaml reads enums from `!tag`s; going through a JSON value keeps
+        // the single-key map syntax of JSON recipes
+        let invalid =
+            |e: &dyn std::fmt::Display| VeloxxError::Parsing(format!("Invalid YAML recipe: {}", e));
+        let value: serde_json::Value = serde_yaml::from_str(yaml).map_err(|e| invalid(&e))?;
+        serde_json::from_value(value).map_err(|e| invalid(&e))
+    }
+
+    /// Serializes the recipe as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, VeloxxError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| VeloxxError::Parsing(format!("Cannot serialize recipe: {}", e)))
+    }
+
+    /// Builds the lazy plan for the recipe over the named `inputs`, which must
+    /// contain the recipe's input and every joined table.
+    pub fn to_lazy(
+        &self,
+        inputs: &HashMap<String, DataFrame>,
+    ) -> Result<LazyDataFrame, VeloxxError> {
+        let table = |name: &str| {
+            inputs
+                .get(name)
+                .cloned()
+                .map(DataFrame::lazy)
+                .ok_or_else(|| {
+                    VeloxxError::InvalidOperation(format!(
+                        "Recipe input '{}' was not provided",
+                        name
+                    ))
+                })
+        };
+        let mut plan = table(&self.input)?;
+        for step in &self.steps {
+            plan = match step {
+                RecipeStep::Filter(predicate) => plan.filter(predicate.to_expr()),
+                RecipeStep::Select(columns) => {
+                    plan.select(columns.iter().map(|c| lazy::col(c)).collect())
+                }
+                RecipeStep::WithColumn { name, expr } => plan.with_column(name, expr.to_expr()),
+                RecipeStep::Join { right, on, how } => {
+                    let how = match how {
+                        RecipeJoin::Inner => JoinType::Inner,
+                        RecipeJoin::Left => JoinType::Left,
+                        RecipeJoin::Right => JoinType::Right,
+                    };
+                    plan.join(table(right)?, on, how)
+                }
+                RecipeStep::GroupBy { keys, aggregations } => {
+                    let keys = keys
+                        .iter()
+                        .map(|key| match key {
+                            RecipeKey::Column(name) => (name.as_str(), lazy::col(name)),
+                            RecipeKey::Computed { name, expr } => (name.as_str(), expr.to_expr()),
+                        })
+                        .collect();
+                    plan.group_by_exprs(keys)
+                        .agg(to_aggregations(aggregations)?)
+                }
+                RecipeStep::Aggregate(aggregations) => plan.agg(to_aggregations(aggregations)?),
+                RecipeStep::Sort { by, ascending } => plan.sort(by.clone(), *ascending),
+            };
+        }
+        Ok(plan)
+    }
+
+    /// Builds and collects the lazy plan; see [`Recipe::to_lazy`].
+    pub fn execute(&self, inputs: &HashMap<String, DataFrame>) -> Result<DataFrame, VeloxxError> {
+        self.to_lazy(inputs)?.collect()
+    }
+}
+
+fn to_aggregations(aggregations: &[RecipeAggregation]) -> Result<Vec<Aggregation>, VeloxxError> {
+    aggregations
+        .iter()
+        .map(|RecipeAggregation { column, function }| {
+            let column = column.clone();
+            match function.as_str() {
+                "sum" => Ok(Aggregation::Sum(column)),
+                "mean" => Ok(Aggregation::Mean(column)),
+                "count" => Ok(Aggregation::Count(column)),
+                "min" => Ok(Aggregation::Min(column)),
+                "max" => Ok(Aggregation::Max(column)),
+                other => Err(VeloxxError::Unsupported(format!(
+                    "Unsupported aggregation in recipe: {}",
+                    other
+                ))),
+            }
+        })
+        .collect()
+}
+
+impl RecipeExpr {
+    /// The equivalent lazy expression
+    pub fn to_expr(&self) -> lazy::Expr {
+        let binary = |operands: &[RecipeExpr; 2], op| {
+            lazy::binary_op(operands[0].to_expr(), op, operands[1].to_expr())
+        };
+        match self {
+            RecipeExpr::Col(name) => lazy::col(name),
+            RecipeExpr::Lit(literal) => lazy::lit(match literal {
+                RecipeLiteral::Null => Value::Null,
+                RecipeLiteral::Bool(b) => Value::Bool(*b),
+                RecipeLiteral::Int(i) => Value::I32(*i),
+                RecipeLiteral::Float(f) => Value::F64(*f),
+                RecipeLiteral::String(s) => Value::String(s.clone()),
+            }),
+            RecipeExpr::Not(inner) => lazy::Expr::Not(Box::new(inner.to_expr())),
+            RecipeExpr::DtTruncate { input, every } => lazy::dt_truncate(input.to_expr(), every),
+            RecipeExpr::Eq(operands) => binary(operands, BinaryOperator::Eq),
+            RecipeExpr::Neq(operands) => binary(operands, BinaryOperator::Neq),
+            RecipeExpr::Lt(operands) => binary(operands, BinaryOperator::Lt),
+            RecipeExpr::LtEq(operands) => binary(operands, BinaryOperator::LtEq),
+            RecipeExpr::Gt(operands) => binary(operands, BinaryOperator::Gt),
+            RecipeExpr::GtEq(operands) => binary(operands, BinaryOperator::GtEq),
+            RecipeExpr::And(operands) => binary(operands, BinaryOperator::And),
+            RecipeExpr::Or(operands) => binary(operands, BinaryOperator::Or),
+            RecipeExpr::Add(operands) => binary(operands, BinaryOperator::Add),
+            RecipeExpr::Subtract(operands) => binary(operands, BinaryOperator::Subtract),
+            RecipeExpr::Multiply(operands) => binary(operands, BinaryOperator::Multiply),
+            RecipeExpr::Divide(operands) => binary(operands, BinaryOperator::Divide),
+        }
+    }
+}
@@ -5,16 +5,20 @@ use rayon::prelude::*;
 
 impl Series {
     /// Calculate the sum of all values in the series
+    ///
+    /// Integer columns are summed exactly in `i64` and returned as `Value::I32`
+    /// when the total fits, or widened to `Value::F64` when it does not; see
+    /// [`Series::sum_checked`] to fail instead and [`Series::sum_i64`] for the
+    /// exact total. Boolean columns sum to their number of `true` values.
     pub fn sum(&self) -> Result<Value, VeloxxError> {
+        #[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+        if let Some(sum) = crate::performance::gpu::series_sum(self) {
+            return Ok(sum);
+        }
+
         match self {
-            Series::I32(_, values, bitmap) => {
-                let sum: i32 = values
-                    .par_iter()
-                    .zip(bitmap.par_iter())
-                    .filter_map(|(&v, &b)| if b { Some(v) } else { None })
-                    .sum();
-                Ok(Value::I32(sum))
-            }
+            Series::I32(_, _, _) => Ok(integer_sum_value(self.sum_i64()?)),
+            Series::Bool(_, _, _) => Ok(integer_sum_value(self.count_true()? as i64)),
             Series::F64(_, values, bitmap) => {
                 let sum: f64 = values
                     .par_iter()
@@ -29,6 +33,62 @@ impl Series {
         }
     }
 
+    /// Calculate the exact sum of an integer series
+    pub fn sum_i64(&self) -> Result<i64, VeloxxError> {
+        match self {
+            Series::I32(_, values, bitmap) => Ok(values
+                .par_iter()
+                .zip(bitmap.par_iter())
+                .filter_map(|(&v, &b)| if b { Some(v as i64) } else { None })
+                .sum()),
+            _ => Err(VeloxxError::InvalidOperation(
+                "Integer sum operation not supported for this data type".to_string(),
+            )),
+        }
+    }
+
+    /// Calculate the sum like [`Series::sum`], but return an error when an
+    /// integer total overflows `i32` or finite floats add up to infinity
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let series = Series::new_i32("n", vec![Some(i32::MAX), Some(1)]);
+    /// assert_eq!(series.sum_i64().unwrap(), i32::MAX as i64 + 1);
+    /// assert_eq!(series.sum().unwrap(), Value::F64(2_147_483_648.0));
+    /// assert!(series.sum_checked().is_err());
+    ///
+    /// let series = Series::new_f64("x", vec![Some(f64::MAX), Some(f64::MAX)]);
+    /// assert_eq!(series.sum().unwrap(), Value::F64(f64::INFINITY));
+    /// assert!(series.sum_checked().is_err());
+    /// ```
+    pub fn sum_checked(&self) -> Result<Value, VeloxxError> {
+        let total = self.sum()?;
+        let overflowed = match (self, &total) {
+            // Integer totals are only widened to `F64` when they leave `i32` range
+            (Series::I32(_, _, _) | Series::Bool(_, _, _), Value::F64(_)) => true,
+            (Series::F64(_, values, bitmap), Value::F64(sum)) => {
+                sum.is_infinite()
+                    && values
+                        .iter()
+                        .zip(bitmap.iter())
+                        .all(|(v, &b)| !b || v.is_finite())
+            }
+            _ => false,
+        };
+        if overflowed {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Sum of '{}' overflows: {}",
+                self.name(),
+                total
+            )));
+        }
+        Ok(total)
+    }
+
     /// Calculate the minimum value in the series
     pub fn min(&self) -> Result<Value, VeloxxError> {
         match self {
@@ -129,6 +189,11 @@ impl Series {
 
     /// Calculate the mean of all values in the series
     pub fn mean(&self) -> Result<Value, VeloxxError> {
+        #[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+        if let Some(mean) = crate::performance::gpu::series_mean(self) {
+            return Ok(mean);
+        }
+
         match self {
             Series::I32(_, values, bitmap) => {
                 let valid_values: Vec<i32> = values
@@ -141,7 +206,7 @@ impl Series {
                         "No valid values in series".to_string(),
                     ));
                 }
-                let sum: i32 = valid_values.iter().sum();
+                let sum: i64 = valid_values.iter().map(|&v| v as i64).sum();
                 Ok(Value::F64(sum as f64 / valid_values.len() as f64))
             }
             Series::F64(_, values, bitmap) => {
@@ -246,7 +311,7 @@ impl Series {
                 }
                 valid_values.sort_unstable();
                 let len = valid_values.len();
-                let median = if len % 2 == 0 {
+                let median = if len.is_multiple_of(2) {
                     (valid_values[len / 2 - 1] + valid_values[len / 2]) as f64 / 2.0
                 } else {
                     valid_values[len / 2] as f64
@@ -266,7 +331,7 @@ impl Series {
                 }
                 valid_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
                 let len = valid_values.len();
-                let median = if len % 2 == 0 {
+                let median = if len.is_multiple_of(2) {
                     (valid_values[len / 2 - 1] + valid_values[len / 2]) / 2.0
                 } else {
                     valid_values[len / 2]
@@ -354,3 +419,9 @@ impl Series {
         }
     }
 }
+
+/// `Value` of an integer total: `I32` while it fits, otherwise `F64`, which
+/// holds it exactly up to 2^53 since `Value` has no 64-bit integer
+pub(crate) fn integer_sum_value(total: i64) -> Value {
+    i32::try_from(total).map_or(Value::F64(total as f64), Value::I32)
+}
@@ -91,6 +91,23 @@ impl Series {
 
                 Ok(Series::DateTime(name.clone(), new_values, new_bitmap))
             }
+            Series::Binary(name, values, bitmap) => {
+                let mut new_values = Vec::with_capacity(indices.len());
+                let mut new_bitmap = Vec::with_capacity(indices.len());
+
+                for &idx in indices {
+                    if idx < values.len() {
+                        new_values.push(values[idx].clone());
+                        new_bitmap.push(bitmap[idx]);
+                    } else {
+                        return Err(VeloxxError::InvalidOperation(
+                            "Index out of bounds".to_string(),
+                        ));
+                    }
+                }
+
+                Ok(Series::Binary(name.clone(), new_values, new_bitmap))
+            }
         }
     }
 
@@ -121,6 +138,7 @@ impl Series {
             Series::Bool(ref mut name, _, _) => *name = new_name.to_string(),
             Series::String(ref mut name, _, _) => *name = new_name.to_string(),
             Series::DateTime(ref mut name, _, _) => *name = new_name.to_string(),
+            Series::Binary(ref mut name, _, _) => *name = new_name.to_string(),
         }
     }
 
@@ -132,6 +150,7 @@ impl Series {
             Series::Bool(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
             Series::String(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
             Series::DateTime(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
+            Series::Binary(_, _, bitmap) => bitmap.iter().filter(|&&b| b).count(),
         }
     }
 
@@ -193,4 +212,92 @@ impl Series {
             )),
         }
     }
+
+    /// Fills each null with the value at the same row of `other`, like SQL
+    /// `COALESCE(self, other)`; rows null in both stay null. Chain calls to
+    /// fall back through several series.
+    ///
+    /// Both series must have the same type and length. The result keeps this
+    /// series' name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let mobile = Series::new_string("phone", vec![None, Some("555-0101".to_string()), None]);
+    /// let office = Series::new_string("office", vec![Some("555-0199".to_string()), None, None]);
+    /// let phone = mobile.coalesce(&office).unwrap();
+    /// assert_eq!(
+    ///     phone.get_data_string().unwrap(),
+    ///     vec![Some("555-0199".to_string()), Some("555-0101".to_string()), None]
+    /// );
+    /// ```
+    pub fn coalesce(&self, other: &Series) -> Result<Series, VeloxxError> {
+        fn merge<T: Clone>(
+            values: &[T],
+            bitmap: &[bool],
+            other: &[T],
+            other_bitmap: &[bool],
+        ) -> (Vec<T>, Vec<bool>) {
+            values
+                .iter()
+                .zip(bitmap)
+                .zip(other.iter().zip(other_bitmap))
+                .map(|((value, &valid), (fallback, &fallback_valid))| {
+                    if valid {
+                        (value.clone(), true)
+                    } else {
+                        (fallback.clone(), fallback_valid)
+                    }
+                })
+                .unzip()
+        }
+
+        if self.len() != other.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Cannot coalesce '{}' ({} rows) with '{}' ({} rows)",
+                self.name(),
+                self.len(),
+                other.name(),
+                other.len()
+            )));
+        }
+        let name = self.name().to_string();
+        Ok(match (self, other) {
+            (Series::I32(_, v, b), Series::I32(_, ov, ob)) => {
+                let (values, bitmap) = merge(v, b, ov, ob);
+                Series::I32(name, values, bitmap)
+            }
+            (Series::F64(_, v, b), Series::F64(_, ov, ob)) => {
+                let (values, bitmap) = merge(v, b, ov, ob);
+                Series::F64(name, values, bitmap)
+            }
+            (Series::Bool(_, v, b), Series::Bool(_, ov, ob)) => {
+                let (values, bitmap) = merge(v, b, ov, ob);
+                Series::Bool(name, values, bitmap)
+            }
+            (Series::String(_, v, b), Series::String(_, ov, ob)) => {
+                let (values, bitmap) = merge(v, b, ov, ob);
+                Series::String(name, values, bitmap)
+            }
+            (Series::DateTime(_, v, b), Series::DateTime(_, ov, ob)) => {
+                let (values, bitmap) = merge(v, b, ov, ob);
+                Series::DateTime(name, values, bitmap)
+            }
+            (Series::Binary(_, v, b), Series::Binary(_, ov, ob)) => {
+                let (values, bitmap) = merge(v, b, ov, ob);
+                Series::Binary(name, values, bitmap)
+            }
+            _ => {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot coalesce '{}' of type {:?} with '{}' of type {:?}",
+                    self.name(),
+                    self.data_type(),
+                    other.name(),
+                    other.data_type()
+                )))
+            }
+        })
+    }
 }
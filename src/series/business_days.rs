@@ -0,0 +1,254 @@
+//! Business-day arithmetic on `DateTime` series, for SLA deadlines and
+//! settlement dates.
+//!
+//! Days are UTC calendar days, counted since 1970-01-01. A day is a business
+//! day if it is a weekday and the [`HolidayCalendar`] does not list it; a
+//! calendar can also override [`HolidayCalendar::is_business_day`] for other
+//! weekends. [`Holidays`] covers a fixed list of dates, and any
+//! `Fn(i64) -> bool` over day numbers works as a calendar too.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use veloxx::series::business_days::Holidays;
+//! use veloxx::series::Series;
+//! use veloxx::types::Value;
+//!
+//! // Tuesday 2024-12-24 at 15:00 UTC, with Christmas and Boxing Day off
+//! let trades = Series::new_datetime("traded", vec![Some(1_735_052_400), None]);
+//! let holidays = Holidays::from_dates([(2024, 12, 25), (2024, 12, 26)]);
+//! let settled = trades.add_business_days(2, &holidays).unwrap();
+//! // T+2 skips the holidays: Friday 2024-12-27, then Monday 2024-12-30
+//! assert_eq!(settled.get_value(0), Some(Value::DateTime(1_735_570_800)));
+//! assert_eq!(settled.get_value(1), None);
+//! ```
+
+use crate::io::datetime::days_from_civil;
+use crate::series::Series;
+use crate::VeloxxError;
+use std::collections::HashSet;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The longest run of non-business days a calendar may have, so a calendar
+/// without business days is an error rather than an endless search
+const MAX_CLOSED_DAYS: i64 = 3_660;
+
+/// Holidays, and optionally weekends, for the business-day methods on [`Series`]
+pub trait HolidayCalendar {
+    /// Whether `day`, counted since 1970-01-01, is a holiday
+    fn is_holiday(&self, day: i64) -> bool;
+
+    /// Whether `day` is a business day; by default a weekday that is not a holiday
+    fn is_business_day(&self, day: i64) -> bool {
+        // 1970-01-01 was a Thursday, so this is 0 for Sunday and 6 for Saturday
+        let weekday = (day + 4).rem_euclid(7);
+        weekday != 0 && weekday != 6 && !self.is_holiday(day)
+    }
+}
+
+impl<F: Fn(i64) -> bool> HolidayCalendar for F {
+    fn is_holiday(&self, day: i64) -> bool {
+        self(day)
+    }
+}
+
+/// A calendar with a fixed set of holiday dates and Saturday-Sunday weekends
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::series::business_days::{HolidayCalendar, Holidays};
+///
+/// let holidays = Holidays::from_dates([(2024, 1, 1)]);
+/// assert!(holidays.is_holiday(19_723)); // 2024-01-01
+/// assert!(!holidays.is_business_day(19_723));
+/// assert!(Holidays::default().is_business_day(19_723));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Holidays {
+    days: HashSet<i64>,
+}
+
+impl Holidays {
+    /// Holidays on the given `(year, month, day)` dates
+    pub fn from_dates(dates: impl IntoIterator<Item = (i64, u32, u32)>) -> Self {
+        Holidays {
+            days: dates
+                .into_iter()
+                .map(|(year, month, day)| days_from_civil(year, month, day))
+                .collect(),
+        }
+    }
+
+    /// Holidays on the UTC days of the given Unix timestamps
+    pub fn from_timestamps(timestamps: impl IntoIterator<Item = i64>) -> Self {
+        Holidays {
+            days: timestamps
+                .into_iter()
+                .map(|t| t.div_euclid(SECONDS_PER_DAY))
+                .collect(),
+        }
+    }
+
+    /// Adds the holiday on `(year, month, day)`
+    pub fn insert(&mut self, year: i64, month: u32, day: u32) {
+        self.days.insert(days_from_civil(year, month, day));
+    }
+}
+
+impl HolidayCalendar for Holidays {
+    fn is_holiday(&self, day: i64) -> bool {
+        self.days.contains(&day)
+    }
+}
+
+/// The next business day after `day` in the direction of `step`
+fn step_business_day(
+    calendar: &impl HolidayCalendar,
+    mut day: i64,
+    step: i64,
+) -> Result<i64, VeloxxError> {
+    for _ in 0..MAX_CLOSED_DAYS {
+        day += step;
+        if calendar.is_business_day(day) {
+            return Ok(day);
+        }
+    }
+    Err(VeloxxError::InvalidOperation(format!(
+        "Holiday calendar has no business day within {MAX_CLOSED_DAYS} days"
+    )))
+}
+
+impl Series {
+    fn datetime_parts(&self, operation: &str) -> Result<(&[i64], &[bool]), VeloxxError> {
+        match self {
+            Series::DateTime(_, values, validity) => Ok((values, validity)),
+            other => Err(VeloxxError::DataTypeMismatch(format!(
+                "{operation} requires a DateTime series, '{}' is {:?}",
+                other.name(),
+                other.data_type()
+            ))),
+        }
+    }
+
+    /// A `Bool` series that is `true` where the timestamp falls on a business
+    /// day of `calendar`; nulls stay null.
+    pub fn is_business_day(&self, calendar: &impl HolidayCalendar) -> Result<Series, VeloxxError> {
+        let (values, validity) = self.datetime_parts("is_business_day")?;
+        Ok(Series::Bool(
+            self.name().to_string(),
+            values
+                .iter()
+                .map(|t| calendar.is_business_day(t.div_euclid(SECONDS_PER_DAY)))
+                .collect(),
+            validity.to_vec(),
+        ))
+    }
+
+    /// Moves each timestamp `days` business days of `calendar` forward, or
+    /// backward if negative, keeping its time of day; nulls stay null.
+    ///
+    /// Each step lands on the next business day, so one business day after a
+    /// Friday or a Saturday is the following Monday. With `days == 0`, a
+    /// timestamp on a non-business day rolls forward to the next business day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::DataTypeMismatch` for a non-`DateTime` series and
+    /// `VeloxxError::InvalidOperation` if the calendar has no business day
+    /// within ten years of a timestamp.
+    pub fn add_business_days(
+        &self,
+        days: i64,
+        calendar: &impl HolidayCalendar,
+    ) -> Result<Series, VeloxxError> {
+        let (values, validity) = self.datetime_parts("add_business_days")?;
+        let step = if days < 0 { -1 } else { 1 };
+        let shifted = values
+            .iter()
+            .zip(validity)
+            .map(|(&t, &valid)| {
+                if !valid {
+                    return Ok(t);
+                }
+                let (mut day, time) =
+                    (t.div_euclid(SECONDS_PER_DAY), t.rem_euclid(SECONDS_PER_DAY));
+                if days == 0 && !calendar.is_business_day(day) {
+                    day = step_business_day(calendar, day, 1)?;
+                }
+                for _ in 0..days.unsigned_abs() {
+                    day = step_business_day(calendar, day, step)?;
+                }
+                Ok(day * SECONDS_PER_DAY + time)
+            })
+            .collect::<Result<Vec<i64>, VeloxxError>>()?;
+        Ok(Series::DateTime(
+            self.name().to_string(),
+            shifted,
+            validity.to_vec(),
+        ))
+    }
+
+    /// The number of business days of `calendar` from each timestamp to the
+    /// one in the same row of `end`, as an `I32` series.
+    ///
+    /// Counts the business days from the start day up to but not including
+    /// the end day, negated when `end` is earlier, so a Monday-to-Monday diff
+    /// is 5 with no holidays. A null in either series gives a null.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::business_days::Holidays;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// // Friday 2024-03-01 to Tuesday 2024-03-05
+    /// let opened = Series::new_datetime("opened", vec![Some(1_709_251_200)]);
+    /// let closed = Series::new_datetime("closed", vec![Some(1_709_596_800)]);
+    /// let elapsed = opened.business_day_diff(&closed, &Holidays::default()).unwrap();
+    /// assert_eq!(elapsed.get_value(0), Some(Value::I32(2)));
+    /// ```
+    pub fn business_day_diff(
+        &self,
+        end: &Series,
+        calendar: &impl HolidayCalendar,
+    ) -> Result<Series, VeloxxError> {
+        let (starts, start_validity) = self.datetime_parts("business_day_diff")?;
+        let (ends, end_validity) = end.datetime_parts("business_day_diff")?;
+        if starts.len() != ends.len() {
+            return Err(VeloxxError::InvalidOperation(
+                "Series must have same length for business_day_diff".to_string(),
+            ));
+        }
+        let validity: Vec<bool> = start_validity
+            .iter()
+            .zip(end_validity)
+            .map(|(&a, &b)| a && b)
+            .collect();
+        let counts = starts
+            .iter()
+            .zip(ends)
+            .zip(&validity)
+            .map(|((&start, &end), &valid)| {
+                if !valid {
+                    return 0;
+                }
+                let (start, end) = (
+                    start.div_euclid(SECONDS_PER_DAY),
+                    end.div_euclid(SECONDS_PER_DAY),
+                );
+                let count = (start.min(end)..start.max(end))
+                    .filter(|&day| calendar.is_business_day(day))
+                    .count() as i32;
+                if end < start {
+                    -count
+                } else {
+                    count
+                }
+            })
+            .collect();
+        Ok(Series::I32(self.name().to_string(), counts, validity))
+    }
+}
@@ -0,0 +1,118 @@
+//! Operations on `Binary` series of raw bytes, such as hashes or serialized
+//! payloads.
+//!
+//! Base64 uses the standard alphabet with padding (RFC 4648), the form most
+//! JSON APIs and databases expect for bytes in text.
+
+use crate::series::Series;
+use crate::VeloxxError;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// Encodes bytes as padded standard base64
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    STANDARD.encode(bytes)
+}
+
+/// Decodes padded standard base64, ignoring surrounding whitespace
+pub(crate) fn decode_base64(text: &str) -> Result<Vec<u8>, VeloxxError> {
+    STANDARD
+        .decode(text.trim())
+        .map_err(|e| VeloxxError::Parsing(format!("Invalid base64 '{}': {}", text, e)))
+}
+
+impl Series {
+    /// Returns the length of each value in bytes as an `I32` series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let blobs = Series::new_binary("blob", vec![Some(vec![0xca, 0xfe]), None]);
+    /// let lengths = blobs.bin_len().unwrap();
+    /// assert_eq!(lengths.get_value(0), Some(Value::I32(2)));
+    /// assert_eq!(lengths.get_value(1), None);
+    /// ```
+    pub fn bin_len(&self) -> Result<Series, VeloxxError> {
+        self.map_binary("bin_len", |v| v.len() as i32, Series::I32)
+    }
+
+    /// Returns a `Bool` series that is `true` where the value starts with
+    /// `prefix`, e.g. a file signature such as `b"\x89PNG"`; nulls stay null.
+    pub fn bin_starts_with(&self, prefix: &[u8]) -> Result<Series, VeloxxError> {
+        self.map_binary("bin_starts_with", |v| v.starts_with(prefix), Series::Bool)
+    }
+
+    /// Keeps the first `n` bytes of each value; shorter values are unchanged.
+    pub fn bin_prefix(&self, n: usize) -> Result<Series, VeloxxError> {
+        self.map_binary(
+            "bin_prefix",
+            |v| v[..n.min(v.len())].to_vec(),
+            Series::Binary,
+        )
+    }
+
+    /// Encodes each value of a `Binary` series as base64 text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let blobs = Series::new_binary("blob", vec![Some(b"hello".to_vec())]);
+    /// let encoded = blobs.base64_encode().unwrap();
+    /// assert_eq!(encoded.get_value(0), Some(Value::String("aGVsbG8=".to_string())));
+    /// assert_eq!(encoded.base64_decode().unwrap(), blobs);
+    /// ```
+    pub fn base64_encode(&self) -> Result<Series, VeloxxError> {
+        self.map_binary("base64_encode", encode_base64, Series::String)
+    }
+
+    /// Decodes a `String` series of base64 text into a `Binary` series.
+    ///
+    /// Nulls stay null; any other value that is not valid base64 is an error.
+    pub fn base64_decode(&self) -> Result<Series, VeloxxError> {
+        let Series::String(name, values, bitmap) = self else {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "base64_decode requires a String series, got {:?}",
+                self.data_type()
+            )));
+        };
+        let decoded = values
+            .iter()
+            .zip(bitmap)
+            .map(|(v, &valid)| {
+                if valid {
+                    decode_base64(v)
+                } else {
+                    Ok(Vec::new())
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Series::Binary(name.clone(), decoded, bitmap.clone()))
+    }
+
+    /// Applies `f` to every value of a `Binary` series and rebuilds the result
+    /// with `build`, keeping the validity as is
+    fn map_binary<T>(
+        &self,
+        op: &str,
+        f: impl Fn(&[u8]) -> T,
+        build: fn(String, Vec<T>, Vec<bool>) -> Series,
+    ) -> Result<Series, VeloxxError> {
+        match self {
+            Series::Binary(name, values, bitmap) => Ok(build(
+                name.clone(),
+                values.iter().map(|v| f(v)).collect(),
+                bitmap.clone(),
+            )),
+            _ => Err(VeloxxError::DataTypeMismatch(format!(
+                "{op} requires a Binary series, got {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+}
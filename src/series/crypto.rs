@@ -0,0 +1,287 @@
+//! Salted hashing and AES-256-GCM encryption of column values, enabled with the
+//! `crypto` feature.
+//!
+//! Hashing pseudonymizes a column for good: equal values still hash equally, so
+//! hashed keys can be joined and counted, but the originals cannot be recovered.
+//! Encryption keeps them recoverable by whoever holds the key.
+//!
+//! Values are hashed by their bytes: UTF-8 for `String`, the raw bytes for
+//! `Binary`, and the display text for every other type, so `I32(42)` hashes like
+//! `"42"`.
+//!
+//! Each encrypted value is a self-contained `Binary` envelope: a format version,
+//! the source type, a random 96-bit nonce and the AES-GCM ciphertext with its tag.
+//! The version and type are authenticated too, so [`Series::decrypt`] restores
+//! the original column type and rejects tampered values.
+
+use crate::dataframe::manipulation::series_from_values;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::{Digest, Sha256};
+
+/// Envelope format version
+const VERSION: u8 = 1;
+const NONCE_LEN: usize = 12;
+/// Version and type tag
+const HEADER_LEN: usize = 2;
+const TAG_LEN: usize = 16;
+
+/// Hash function used by [`Series::hash_values`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// SHA-256, as 64 lowercase hex digits; use it when hashes leave the process
+    Sha256,
+    /// XXH64, as 16 lowercase hex digits; much faster, but not cryptographic
+    XxHash64,
+}
+
+impl HashAlgorithm {
+    fn hash(self, salt: &[u8], bytes: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Sha256 => {
+                let digest = Sha256::new()
+                    .chain_update(salt)
+                    .chain_update(bytes)
+                    .finalize();
+                digest.iter().map(|b| format!("{:02x}", b)).collect()
+            }
+            HashAlgorithm::XxHash64 => {
+                let mut hasher = xxhash_rust::xxh64::Xxh64::new(0);
+                hasher.update(salt);
+                hasher.update(bytes);
+                format!("{:016x}", hasher.digest())
+            }
+        }
+    }
+}
+
+/// A 256-bit AES key for [`Series::encrypt`] and [`Series::decrypt`]
+///
+/// The key bytes are never printed, not even by `Debug`.
+#[derive(Clone)]
+pub struct EncryptionKey(Key<Aes256Gcm>);
+
+impl EncryptionKey {
+    /// Generates a random key from the operating system's generator.
+    pub fn generate() -> Self {
+        Self(Aes256Gcm::generate_key(OsRng))
+    }
+
+    /// Uses 32 raw key bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VeloxxError> {
+        if bytes.len() != 32 {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "An AES-256 key has 32 bytes, got {}",
+                bytes.len()
+            )));
+        }
+        Ok(Self(*Key::<Aes256Gcm>::from_slice(bytes)))
+    }
+
+    /// Decodes a key stored as base64, such as one from [`EncryptionKey::to_base64`].
+    pub fn from_base64(text: &str) -> Result<Self, VeloxxError> {
+        Self::from_bytes(&crate::series::bytes::decode_base64(text)?)
+    }
+
+    /// The key as base64, for storing it in a secret manager.
+    pub fn to_base64(&self) -> String {
+        crate::series::bytes::encode_base64(&self.0)
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(&self.0)
+    }
+}
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+fn type_tag(data_type: &DataType) -> u8 {
+    match data_type {
+        DataType::I32 => 0,
+        DataType::F64 => 1,
+        DataType::Bool => 2,
+        DataType::String => 3,
+        DataType::DateTime => 4,
+        DataType::Binary => 5,
+    }
+}
+
+/// Bytes a value is hashed by
+fn hash_bytes(value: &Value) -> Vec<u8> {
+    match value {
+        Value::String(s) => s.as_bytes().to_vec(),
+        Value::Binary(b) => b.clone(),
+        other => other.to_string().into_bytes(),
+    }
+}
+
+/// Bytes a value is encrypted as; numbers are little-endian
+fn plaintext(value: &Value) -> Vec<u8> {
+    match value {
+        Value::I32(v) => v.to_le_bytes().to_vec(),
+        Value::F64(v) => v.to_le_bytes().to_vec(),
+        Value::Bool(v) => vec![u8::from(*v)],
+        Value::String(s) => s.as_bytes().to_vec(),
+        Value::DateTime(v) => v.to_le_bytes().to_vec(),
+        Value::Binary(b) => b.clone(),
+        Value::Null => Vec::new(),
+    }
+}
+
+/// The inverse of [`plaintext`] for the type with `tag`
+fn from_plaintext(tag: u8, bytes: Vec<u8>) -> Option<Value> {
+    Some(match tag {
+        0 => Value::I32(i32::from_le_bytes(bytes.try_into().ok()?)),
+        1 => Value::F64(f64::from_le_bytes(bytes.try_into().ok()?)),
+        2 => match bytes[..] {
+            [0] => Value::Bool(false),
+            [1] => Value::Bool(true),
+            _ => return None,
+        },
+        3 => Value::String(String::from_utf8(bytes).ok()?),
+        4 => Value::DateTime(i64::from_le_bytes(bytes.try_into().ok()?)),
+        5 => Value::Binary(bytes),
+        _ => return None,
+    })
+}
+
+impl Series {
+    /// Replaces every value with its salted hash, as a `String` series of hex
+    /// digits; nulls stay null.
+    ///
+    /// The salt is hashed before each value, so the same value and salt always
+    /// give the same hash. Keep the salt secret: without it, low-cardinality
+    /// values such as e-mail addresses cannot be recovered by hashing guesses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::crypto::HashAlgorithm;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let emails = Series::new_string("email", vec![Some("a@x.io".to_string()), None]);
+    /// let hashed = emails.hash_values(HashAlgorithm::Sha256, b"pepper").unwrap();
+    /// assert_eq!(hashed.get_value(0).unwrap().to_string().len(), 64);
+    /// assert_eq!(hashed.get_value(1), None);
+    /// ```
+    pub fn hash_values(
+        &self,
+        algorithm: HashAlgorithm,
+        salt: &[u8],
+    ) -> Result<Series, VeloxxError> {
+        let hashes = (0..self.len())
+            .map(|i| {
+                self.get_value(i)
+                    .map(|value| algorithm.hash(salt, &hash_bytes(&value)))
+            })
+            .collect();
+        Ok(Series::new_string(self.name(), hashes))
+    }
+
+    /// Encrypts every value with AES-256-GCM under `key`, giving a `Binary`
+    /// series of envelopes (see the [module docs](self)); nulls stay null.
+    ///
+    /// Every value gets a fresh random nonce, so equal values encrypt differently.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::crypto::EncryptionKey;
+    /// use veloxx::series::Series;
+    /// use veloxx::types::DataType;
+    ///
+    /// let key = EncryptionKey::generate();
+    /// let salaries = Series::new_i32("salary", vec![Some(52_000), None]);
+    /// let encrypted = salaries.encrypt(&key).unwrap();
+    /// assert_eq!(encrypted.data_type(), DataType::Binary);
+    /// assert_eq!(encrypted.decrypt(&key).unwrap(), salaries);
+    /// ```
+    pub fn encrypt(&self, key: &EncryptionKey) -> Result<Series, VeloxxError> {
+        let cipher = key.cipher();
+        let header = [VERSION, type_tag(&self.data_type())];
+        let envelopes = (0..self.len())
+            .map(|i| {
+                let Some(value) = self.get_value(i) else {
+                    return Ok(None);
+                };
+                let nonce = Aes256Gcm::generate_nonce(OsRng);
+                let payload = Payload {
+                    msg: &plaintext(&value),
+                    aad: &header,
+                };
+                let ciphertext = cipher.encrypt(&nonce, payload).map_err(|_| {
+                    VeloxxError::InvalidOperation(format!(
+                        "Failed to encrypt row {} of '{}'",
+                        i,
+                        self.name()
+                    ))
+                })?;
+                let mut envelope = header.to_vec();
+                envelope.extend_from_slice(&nonce);
+                envelope.extend(ciphertext);
+                Ok(Some(envelope))
+            })
+            .collect::<Result<_, VeloxxError>>()?;
+        Ok(Series::new_binary(self.name(), envelopes))
+    }
+
+    /// Decrypts a `Binary` series produced by [`Series::encrypt`], restoring the
+    /// original column type; nulls stay null.
+    ///
+    /// A wrong key or a modified value is a [`VeloxxError::Parsing`] error. A
+    /// column of only nulls comes back as `String`.
+    pub fn decrypt(&self, key: &EncryptionKey) -> Result<Series, VeloxxError> {
+        let Series::Binary(name, envelopes, bitmap) = self else {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "decrypt requires a Binary series, got {:?}",
+                self.data_type()
+            )));
+        };
+        let cipher = key.cipher();
+        let invalid = |row: usize| {
+            VeloxxError::Parsing(format!(
+                "Row {} of '{}' cannot be decrypted with this key",
+                row, name
+            ))
+        };
+        let mut column_tag = None;
+        let values = envelopes
+            .iter()
+            .zip(bitmap)
+            .enumerate()
+            .map(|(row, (envelope, &valid))| {
+                if !valid {
+                    return Ok(Value::Null);
+                }
+                if envelope.len() < HEADER_LEN + NONCE_LEN + TAG_LEN || envelope[0] != VERSION {
+                    return Err(invalid(row));
+                }
+                let (header, rest) = envelope.split_at(HEADER_LEN);
+                let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+                if *column_tag.get_or_insert(header[1]) != header[1] {
+                    return Err(VeloxxError::Parsing(format!(
+                        "Row {} of '{}' was encrypted from a different type than the rows before it",
+                        row, name
+                    )));
+                }
+                let payload = Payload {
+                    msg: ciphertext,
+                    aad: header,
+                };
+                let bytes = cipher
+                    .decrypt(Nonce::from_slice(nonce), payload)
+                    .map_err(|_| invalid(row))?;
+                from_plaintext(header[1], bytes).ok_or_else(|| invalid(row))
+            })
+            .collect::<Result<Vec<_>, VeloxxError>>()?;
+        Ok(series_from_values(name, values))
+    }
+}
@@ -0,0 +1,249 @@
+//! Filling nulls from neighbouring values, either by row position or along
+//! an x axis such as a timestamp column with irregular spacing.
+
+use crate::dataframe::join::take_optional;
+use crate::series::Series;
+use crate::types::Value;
+use crate::VeloxxError;
+
+/// How [`Series::interpolate`] fills nulls.
+///
+/// `Linear`, `Nearest` and `Spline` only fill nulls that lie between two
+/// values; leading and trailing nulls stay null. `Linear` and `Spline` need
+/// an `I32` or `F64` series and round their results for `I32`; the other
+/// methods copy existing values and work for every type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationMethod {
+    /// A straight line between the values on either side of the gap
+    Linear,
+    /// The closer of the values on either side of the gap; ties take the earlier one
+    Nearest,
+    /// A natural cubic spline through all non-null values
+    Spline,
+    /// The last value before the gap, for at most `limit` rows after it
+    ForwardFill { limit: Option<usize> },
+    /// The first value after the gap, for at most `limit` rows before it
+    BackwardFill { limit: Option<usize> },
+}
+
+/// The row each row takes its value from for the copying methods, or `None`
+/// where it stays null
+fn fill_sources(
+    method: InterpolationMethod,
+    validity: &[bool],
+    known: &[usize],
+    x: &[f64],
+) -> Vec<Option<usize>> {
+    let n = validity.len();
+    let mut sources: Vec<Option<usize>> = (0..n).map(|i| validity[i].then_some(i)).collect();
+    let mut fill_run = |rows: &mut dyn Iterator<Item = usize>, limit: Option<usize>| {
+        let (mut last, mut run) = (None, 0);
+        for i in rows {
+            if validity[i] {
+                (last, run) = (Some(i), 0);
+            } else {
+                run += 1;
+                if limit.is_none_or(|limit| run <= limit) {
+                    sources[i] = last;
+                }
+            }
+        }
+    };
+    match method {
+        InterpolationMethod::ForwardFill { limit } => fill_run(&mut (0..n), limit),
+        InterpolationMethod::BackwardFill { limit } => fill_run(&mut (0..n).rev(), limit),
+        _ => {
+            for pair in known.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                for k in a + 1..b {
+                    sources[k] = Some(if x[k] - x[a] <= x[b] - x[k] { a } else { b });
+                }
+            }
+        }
+    }
+    sources
+}
+
+/// Second derivatives of the natural cubic spline through `(xs, ys)`
+fn spline_second_derivatives(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let m = xs.len();
+    let mut second = vec![0.0; m];
+    if m < 3 {
+        return second;
+    }
+    // Thomas algorithm over the interior points; the ends are fixed at zero
+    let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut diag = vec![0.0; m];
+    let mut rhs = vec![0.0; m];
+    for i in 1..m - 1 {
+        diag[i] = 2.0 * (h[i - 1] + h[i]);
+        rhs[i] = 6.0 * ((ys[i + 1] - ys[i]) / h[i] - (ys[i] - ys[i - 1]) / h[i - 1]);
+        if i > 1 {
+            let factor = h[i - 1] / diag[i - 1];
+            diag[i] -= factor * h[i - 1];
+            rhs[i] -= factor * rhs[i - 1];
+        }
+    }
+    for i in (1..m - 1).rev() {
+        second[i] = (rhs[i] - h[i] * second[i + 1]) / diag[i];
+    }
+    second
+}
+
+impl Series {
+    /// Fills nulls from neighbouring values using `method`, treating rows as
+    /// evenly spaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::interpolate::InterpolationMethod;
+    /// use veloxx::series::Series;
+    ///
+    /// let readings = Series::new_f64("temp", vec![Some(1.0), None, None, None, Some(5.0)]);
+    /// let filled = readings
+    ///     .interpolate(InterpolationMethod::ForwardFill { limit: Some(2) })
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     filled.get_data_f64().unwrap(),
+    ///     vec![Some(1.0), Some(1.0), Some(1.0), None, Some(5.0)]
+    /// );
+    /// ```
+    pub fn interpolate(&self, method: InterpolationMethod) -> Result<Series, VeloxxError> {
+        let positions: Vec<f64> = (0..self.len()).map(|i| i as f64).collect();
+        self.interpolate_at(method, &positions)
+    }
+
+    /// Like [`Series::interpolate`], but spacing rows by the values of `x`, e.g.
+    /// a `DateTime` column of irregular timestamps, so a gap of one hour
+    /// weighs twice as much as a gap of half an hour.
+    ///
+    /// `x` must have the same length, be `I32`, `F64` or `DateTime`, have no
+    /// nulls and be sorted ascending.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::interpolate::InterpolationMethod;
+    /// use veloxx::series::Series;
+    ///
+    /// let time = Series::new_datetime("ts", vec![Some(0), Some(10), Some(40)]);
+    /// let level = Series::new_f64("level", vec![Some(0.0), None, Some(4.0)]);
+    /// let filled = level.interpolate_by(InterpolationMethod::Linear, &time).unwrap();
+    /// assert_eq!(filled.get_data_f64().unwrap(), vec![Some(0.0), Some(1.0), Some(4.0)]);
+    /// ```
+    pub fn interpolate_by(
+        &self,
+        method: InterpolationMethod,
+        x: &Series,
+    ) -> Result<Series, VeloxxError> {
+        if x.len() != self.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Cannot interpolate '{}' ({} rows) along '{}' ({} rows)",
+                self.name(),
+                self.len(),
+                x.name(),
+                x.len()
+            )));
+        }
+        let positions = (0..x.len())
+            .map(|row| match x.get_value(row) {
+                Some(Value::I32(v)) => Ok(v as f64),
+                Some(Value::F64(v)) => Ok(v),
+                Some(Value::DateTime(v)) => Ok(v as f64),
+                None => Err(VeloxxError::InvalidOperation(format!(
+                    "Interpolation axis '{}' has a null at row {row}",
+                    x.name()
+                ))),
+                Some(_) => Err(VeloxxError::DataTypeMismatch(format!(
+                    "Interpolation axis '{}' must be I32, F64 or DateTime, got {:?}",
+                    x.name(),
+                    x.data_type()
+                ))),
+            })
+            .collect::<Result<Vec<f64>, VeloxxError>>()?;
+        if positions.windows(2).any(|w| w[1] < w[0]) {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Interpolation axis '{}' must be sorted ascending",
+                x.name()
+            )));
+        }
+        self.interpolate_at(method, &positions)
+    }
+
+    fn interpolate_at(
+        &self,
+        method: InterpolationMethod,
+        x: &[f64],
+    ) -> Result<Series, VeloxxError> {
+        let validity = self.validity();
+        let known: Vec<usize> = (0..self.len()).filter(|&i| validity[i]).collect();
+        let ys: Vec<f64> = match (method, self) {
+            (
+                InterpolationMethod::Linear | InterpolationMethod::Spline,
+                Series::I32(_, data, _),
+            ) => known.iter().map(|&i| data[i] as f64).collect(),
+            (
+                InterpolationMethod::Linear | InterpolationMethod::Spline,
+                Series::F64(_, data, _),
+            ) => known.iter().map(|&i| data[i]).collect(),
+            (InterpolationMethod::Linear | InterpolationMethod::Spline, _) => {
+                return Err(VeloxxError::Unsupported(format!(
+                    "{method:?} interpolation only supported for numeric series, got {:?}",
+                    self.data_type()
+                )))
+            }
+            _ => {
+                return Ok(take_optional(
+                    self,
+                    &fill_sources(method, validity, &known, x),
+                ))
+            }
+        };
+
+        let xs: Vec<f64> = known.iter().map(|&i| x[i]).collect();
+        let second = if method == InterpolationMethod::Spline {
+            if xs.windows(2).any(|w| w[1] == w[0]) {
+                return Err(VeloxxError::InvalidOperation(
+                    "Spline interpolation needs distinct x values for non-null rows".to_string(),
+                ));
+            }
+            spline_second_derivatives(&xs, &ys)
+        } else {
+            vec![0.0; xs.len()]
+        };
+        let mut filled: Vec<Option<f64>> = vec![None; self.len()];
+        for (j, &row) in known.iter().enumerate() {
+            filled[row] = Some(ys[j]);
+        }
+        for (j, pair) in known.windows(2).enumerate() {
+            let h = xs[j + 1] - xs[j];
+            for (k, value) in filled
+                .iter_mut()
+                .enumerate()
+                .take(pair[1])
+                .skip(pair[0] + 1)
+            {
+                if h == 0.0 {
+                    *value = Some(ys[j]);
+                    continue;
+                }
+                let (a, b) = ((xs[j + 1] - x[k]) / h, (x[k] - xs[j]) / h);
+                let curvature =
+                    ((a.powi(3) - a) * second[j] + (b.powi(3) - b) * second[j + 1]) * h * h / 6.0;
+                *value = Some(a * ys[j] + b * ys[j + 1] + curvature);
+            }
+        }
+
+        Ok(match self {
+            Series::I32(name, _, _) => Series::new_i32(
+                name,
+                filled
+                    .into_iter()
+                    .map(|v| v.map(|v| v.round() as i32))
+                    .collect(),
+            ),
+            _ => Series::new_f64(self.name(), filled),
+        })
+    }
+}
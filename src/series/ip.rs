@@ -0,0 +1,230 @@
+//! IP addresses as a logical type over `String` columns.
+//!
+//! Addresses are parsed once into `u128`s, with IPv4 stored as IPv4-mapped
+//! IPv6 (`::ffff:a.b.c.d`), so both families share one representation, sort
+//! numerically (all IPv4 addresses together) and test subnet membership with a
+//! single mask instead of string comparisons.
+
+use crate::series::Series;
+use crate::VeloxxError;
+use std::fmt;
+use std::net::{IpAddr, Ipv6Addr};
+use std::str::FromStr;
+
+/// Prefix of IPv4-mapped IPv6 addresses, in bits
+const IPV4_MAPPED_PREFIX: u8 = 96;
+
+/// Parses an IPv4 or IPv6 address into its `u128` form.
+pub(crate) fn parse_ip(s: &str) -> Option<u128> {
+    s.trim().parse::<IpAddr>().ok().map(|addr| match addr {
+        IpAddr::V4(v4) => u128::from(v4.to_ipv6_mapped()),
+        IpAddr::V6(v6) => u128::from(v6),
+    })
+}
+
+fn format_ip(bits: u128) -> IpAddr {
+    let v6 = Ipv6Addr::from(bits);
+    match v6.to_ipv4_mapped() {
+        Some(v4) => IpAddr::V4(v4),
+        None => IpAddr::V6(v6),
+    }
+}
+
+/// A CIDR block such as `10.0.0.0/8` or `2001:db8::/32`.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::series::ip::IpNetwork;
+///
+/// let private: IpNetwork = "10.0.0.0/8".parse().unwrap();
+/// assert!(private.contains_str("10.1.2.3"));
+/// assert!(!private.contains_str("192.168.0.1"));
+/// assert!("10.0.0.0/33".parse::<IpNetwork>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpNetwork {
+    network: u128,
+    /// Prefix length in the shared `u128` space, so IPv4 prefixes are offset by 96
+    prefix_len: u8,
+}
+
+impl IpNetwork {
+    fn mask(&self) -> u128 {
+        u128::MAX
+            .checked_shl(128 - self.prefix_len as u32)
+            .unwrap_or(0)
+    }
+
+    /// Whether `address`, in the `u128` form used by [`IpSeries`], is in this block
+    pub fn contains(&self, address: u128) -> bool {
+        address & self.mask() == self.network
+    }
+
+    /// Whether the textual address is in this block; invalid addresses are not
+    pub fn contains_str(&self, address: &str) -> bool {
+        parse_ip(address).is_some_and(|bits| self.contains(bits))
+    }
+}
+
+impl FromStr for IpNetwork {
+    type Err = VeloxxError;
+
+    /// Parses `address/prefix`; a bare address is a single-host block. Host bits
+    /// below the prefix are ignored, so `10.1.2.3/8` means `10.0.0.0/8`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || VeloxxError::Parsing(format!("Invalid CIDR block '{s}'"));
+        let (address, prefix) = s.trim().split_once('/').unwrap_or((s.trim(), ""));
+        let address = address.parse::<IpAddr>().map_err(|_| invalid())?;
+        let (bits, max_prefix, offset) = match address {
+            IpAddr::V4(v4) => (u128::from(v4.to_ipv6_mapped()), 32, IPV4_MAPPED_PREFIX),
+            IpAddr::V6(v6) => (u128::from(v6), 128, 0),
+        };
+        let prefix: u8 = if prefix.is_empty() {
+            max_prefix
+        } else {
+            prefix.parse().map_err(|_| invalid())?
+        };
+        if prefix > max_prefix {
+            return Err(invalid());
+        }
+        let mut network = IpNetwork {
+            network: 0,
+            prefix_len: prefix + offset,
+        };
+        network.network = bits & network.mask();
+        Ok(network)
+    }
+}
+
+impl fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match format_ip(self.network) {
+            IpAddr::V4(v4) if self.prefix_len >= IPV4_MAPPED_PREFIX => {
+                write!(f, "{}/{}", v4, self.prefix_len - IPV4_MAPPED_PREFIX)
+            }
+            _ => write!(f, "{}/{}", Ipv6Addr::from(self.network), self.prefix_len),
+        }
+    }
+}
+
+/// A column of parsed IP addresses, built with [`Series::to_ip`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpSeries {
+    name: String,
+    values: Vec<u128>,
+    validity: Vec<bool>,
+}
+
+impl Series {
+    /// Parses a `String` series of IPv4 and/or IPv6 addresses.
+    ///
+    /// Nulls stay null; any other value that is not an address is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let ips = Series::new_string(
+    ///     "ip",
+    ///     vec![Some("10.0.0.2".to_string()), Some("9.255.0.1".to_string()), None],
+    /// );
+    /// let parsed = ips.to_ip().unwrap();
+    /// let internal = parsed.in_subnet(&"10.0.0.0/8".parse().unwrap());
+    /// assert_eq!(internal.get_data_bool().unwrap(), vec![Some(true), Some(false), None]);
+    /// ```
+    pub fn to_ip(&self) -> Result<IpSeries, VeloxxError> {
+        let Series::String(name, values, validity) = self else {
+            return Err(VeloxxError::DataTypeMismatch(format!(
+                "to_ip requires a String series, got {:?}",
+                self.data_type()
+            )));
+        };
+        let values = values
+            .iter()
+            .zip(validity)
+            .enumerate()
+            .map(|(row, (value, &valid))| {
+                if !valid {
+                    return Ok(0);
+                }
+                parse_ip(value).ok_or_else(|| {
+                    VeloxxError::Parsing(format!(
+                        "Invalid IP address '{value}' at row {row} of '{name}'"
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(IpSeries {
+            name: name.clone(),
+            values,
+            validity: validity.clone(),
+        })
+    }
+}
+
+impl IpSeries {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The address at `index`, or `None` if it is null or out of bounds
+    pub fn get(&self, index: usize) -> Option<IpAddr> {
+        (*self.validity.get(index)?).then(|| format_ip(self.values[index]))
+    }
+
+    /// The `u128` form of the address at `index`, as used by [`IpNetwork::contains`]
+    pub fn get_bits(&self, index: usize) -> Option<u128> {
+        (*self.validity.get(index)?).then(|| self.values[index])
+    }
+
+    /// A `Bool` series that is `true` where the address is in `network`;
+    /// nulls stay null.
+    pub fn in_subnet(&self, network: &IpNetwork) -> Series {
+        Series::Bool(
+            self.name.clone(),
+            self.values
+                .iter()
+                .map(|&bits| network.contains(bits))
+                .collect(),
+            self.validity.clone(),
+        )
+    }
+
+    /// Row indices in ascending address order, nulls first as in
+    /// `DataFrame::sort`; ties keep their original order.
+    pub fn argsort(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by_key(|&i| self.get_bits(i));
+        indices
+    }
+
+    /// The addresses in ascending order, nulls first
+    pub fn sort(&self) -> IpSeries {
+        let indices = self.argsort();
+        IpSeries {
+            name: self.name.clone(),
+            values: indices.iter().map(|&i| self.values[i]).collect(),
+            validity: indices.iter().map(|&i| self.validity[i]).collect(),
+        }
+    }
+
+    /// Formats the addresses back into a `String` series in canonical form
+    pub fn to_series(&self) -> Series {
+        Series::new_string(
+            &self.name,
+            (0..self.len())
+                .map(|i| self.get(i).map(|addr| addr.to_string()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,148 @@
+//! Boolean mask combinators.
+//!
+//! Nulls follow SQL's three-valued logic: `false AND null` is `false`,
+//! `true OR null` is `true`, and every other combination with a null is null.
+//! Masks built this way can be passed to `DataFrame::filter_mask`, where null
+//! rows are dropped.
+
+use crate::series::Series;
+use crate::VeloxxError;
+use std::ops::{BitAnd, BitOr, Not};
+
+impl Series {
+    /// Element-wise AND of two boolean series.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let a = Series::new_bool("a", vec![Some(true), Some(true), None, Some(false)]);
+    /// let b = Series::new_bool("b", vec![Some(true), Some(false), Some(false), None]);
+    /// let both = (&a & &b).unwrap();
+    /// assert_eq!(both.get_data_bool().unwrap(), vec![Some(true), Some(false), Some(false), Some(false)]);
+    /// assert_eq!((!&both).unwrap().count_true().unwrap(), 3);
+    /// ```
+    pub fn and(&self, other: &Series) -> Result<Series, VeloxxError> {
+        self.zip_bool(other, "AND", |a, b| match (a, b) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        })
+    }
+
+    /// Element-wise OR of two boolean series.
+    pub fn or(&self, other: &Series) -> Result<Series, VeloxxError> {
+        self.zip_bool(other, "OR", |a, b| match (a, b) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        })
+    }
+
+    /// Element-wise NOT of a boolean series; nulls stay null.
+    pub fn not(&self) -> Result<Series, VeloxxError> {
+        match self {
+            Series::Bool(name, values, bitmap) => Ok(Series::Bool(
+                name.clone(),
+                values.iter().map(|v| !v).collect(),
+                bitmap.clone(),
+            )),
+            _ => Err(not_boolean(self, "NOT")),
+        }
+    }
+
+    /// Whether any non-null value is `true`
+    pub fn any(&self) -> Result<bool, VeloxxError> {
+        Ok(self.count_true()? > 0)
+    }
+
+    /// Whether every non-null value is `true`; `true` for an all-null series
+    pub fn all(&self) -> Result<bool, VeloxxError> {
+        match self {
+            Series::Bool(_, values, bitmap) => {
+                Ok(values.iter().zip(bitmap.iter()).all(|(&v, &b)| !b || v))
+            }
+            _ => Err(not_boolean(self, "all")),
+        }
+    }
+
+    /// Number of `true` values
+    pub fn count_true(&self) -> Result<usize, VeloxxError> {
+        match self {
+            Series::Bool(_, values, bitmap) => Ok(values
+                .iter()
+                .zip(bitmap.iter())
+                .filter(|(&v, &b)| b && v)
+                .count()),
+            _ => Err(not_boolean(self, "count_true")),
+        }
+    }
+
+    fn zip_bool(
+        &self,
+        other: &Series,
+        operation: &str,
+        combine: impl Fn(Option<bool>, Option<bool>) -> Option<bool>,
+    ) -> Result<Series, VeloxxError> {
+        let (Series::Bool(name, values, bitmap), Series::Bool(_, other_values, other_bitmap)) =
+            (self, other)
+        else {
+            let operand = if matches!(self, Series::Bool(_, _, _)) {
+                other
+            } else {
+                self
+            };
+            return Err(not_boolean(operand, operation));
+        };
+        if values.len() != other_values.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Series length mismatch: {} vs {}",
+                values.len(),
+                other_values.len()
+            )));
+        }
+        let combined: Vec<Option<bool>> = (0..values.len())
+            .map(|i| {
+                combine(
+                    bitmap[i].then_some(values[i]),
+                    other_bitmap[i].then_some(other_values[i]),
+                )
+            })
+            .collect();
+        Ok(Series::new_bool(name, combined))
+    }
+}
+
+fn not_boolean(series: &Series, operation: &str) -> VeloxxError {
+    VeloxxError::DataTypeMismatch(format!(
+        "{} requires a Bool series, but '{}' is {:?}",
+        operation,
+        series.name(),
+        series.data_type()
+    ))
+}
+
+impl BitAnd for &Series {
+    type Output = Result<Series, VeloxxError>;
+
+    fn bitand(self, rhs: &Series) -> Self::Output {
+        self.and(rhs)
+    }
+}
+
+impl BitOr for &Series {
+    type Output = Result<Series, VeloxxError>;
+
+    fn bitor(self, rhs: &Series) -> Self::Output {
+        self.or(rhs)
+    }
+}
+
+impl Not for &Series {
+    type Output = Result<Series, VeloxxError>;
+
+    fn not(self) -> Self::Output {
+        Series::not(self)
+    }
+}
@@ -4,21 +4,71 @@ use crate::VeloxxError;
 // Arrow imports only when the `arrow` feature is enabled and not targeting WASM
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
 use arrow::array::{
-    ArrayRef, BooleanArray, Float64Array, Int32Array, StringArray, TimestampNanosecondArray,
+    Array, ArrayRef, AsArray, BinaryArray, BooleanArray, Float64Array, Int32Array, StringArray,
+    TimestampSecondArray,
 };
 #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
-use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
+use arrow::compute::{cast, cast_with_options, CastOptions};
+#[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+use arrow::datatypes::{DataType as ArrowDataType, Int64Type, TimeUnit};
+#[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+use std::sync::Arc;
+
+/// Casts Arrow types that have no direct Veloxx equivalent to the closest supported one.
+///
+/// Integer columns are narrowed to `Int32` and fail on overflow rather than wrapping.
+#[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+fn normalize_arrow_array(array: ArrayRef) -> Result<ArrayRef, VeloxxError> {
+    let target = match array.data_type() {
+        ArrowDataType::Int8
+        | ArrowDataType::Int16
+        | ArrowDataType::Int64
+        | ArrowDataType::UInt8
+        | ArrowDataType::UInt16
+        | ArrowDataType::UInt32
+        | ArrowDataType::UInt64 => ArrowDataType::Int32,
+        ArrowDataType::Float32 => ArrowDataType::Float64,
+        ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View => ArrowDataType::Utf8,
+        ArrowDataType::LargeBinary
+        | ArrowDataType::BinaryView
+        | ArrowDataType::FixedSizeBinary(_) => ArrowDataType::Binary,
+        ArrowDataType::Timestamp(TimeUnit::Second, None) => return Ok(array),
+        ArrowDataType::Timestamp(unit, _) => {
+            let per_second = match unit {
+                TimeUnit::Second => 1,
+                TimeUnit::Millisecond => 1_000,
+                TimeUnit::Microsecond => 1_000_000,
+                TimeUnit::Nanosecond => 1_000_000_000,
+            };
+            // Floor rather than truncate, so instants before 1970 keep their second
+            let raw = cast(&array, &ArrowDataType::Int64)?;
+            let seconds: TimestampSecondArray = raw
+                .as_primitive::<Int64Type>()
+                .iter()
+                .map(|v| v.map(|v| v.div_euclid(per_second)))
+                .collect();
+            return Ok(Arc::new(seconds));
+        }
+        _ => return Ok(array),
+    };
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    Ok(cast_with_options(&array, &target, &options)?)
+}
 
 // SIMD trait imports - only for native targets
 // Note: we use concrete traits in method scopes to minimize compile-time coupling
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, bincode::Encode, bincode::Decode)]
 pub enum Series {
     I32(String, Vec<i32>, Vec<bool>),
     F64(String, Vec<f64>, Vec<bool>),
     Bool(String, Vec<bool>, Vec<bool>),
     String(String, Vec<String>, Vec<bool>),
     DateTime(String, Vec<i64>, Vec<bool>),
+    Binary(String, Vec<Vec<u8>>, Vec<bool>),
 }
 
 impl Series {
@@ -29,6 +79,7 @@ impl Series {
             Series::Bool(name, _, _) => name,
             Series::String(name, _, _) => name,
             Series::DateTime(name, _, _) => name,
+            Series::Binary(name, _, _) => name,
         }
     }
 
@@ -39,6 +90,7 @@ impl Series {
             Series::Bool(_, values, _) => values.len(),
             Series::String(_, values, _) => values.len(),
             Series::DateTime(_, values, _) => values.len(),
+            Series::Binary(_, values, _) => values.len(),
         }
     }
 
@@ -53,6 +105,7 @@ impl Series {
             Series::Bool(_, _, _) => DataType::Bool,
             Series::String(_, _, _) => DataType::String,
             Series::DateTime(_, _, _) => DataType::DateTime,
+            Series::Binary(_, _, _) => DataType::Binary,
         }
     }
 
@@ -93,6 +146,13 @@ impl Series {
                     None
                 }
             }
+            Series::Binary(_, values, validity) => {
+                if index < values.len() && validity[index] {
+                    Some(Value::Binary(values[index].clone()))
+                } else {
+                    None
+                }
+            }
         }
     }
 
@@ -327,15 +387,41 @@ impl Series {
         Series::DateTime(name.to_string(), values, bitmap)
     }
 
+    /// Creates a series of raw byte strings, e.g. hashes or serialized payloads.
+    pub fn new_binary(name: &str, data: Vec<Option<Vec<u8>>>) -> Self {
+        let mut values = Vec::with_capacity(data.len());
+        let mut bitmap = Vec::with_capacity(data.len());
+        for v in data {
+            match v {
+                Some(val) => {
+                    values.push(val);
+                    bitmap.push(true);
+                }
+                None => {
+                    values.push(Vec::new()); // placeholder
+                    bitmap.push(false);
+                }
+            }
+        }
+        Series::Binary(name.to_string(), values, bitmap)
+    }
+
     /// Create a Series from an Arrow array (requires `arrow` feature, not available in WASM)
+    ///
+    /// Arrow types without a direct Veloxx equivalent are cast first: other integer
+    /// widths become `I32` (erroring on overflow), `Float32` becomes `F64`, large and
+    /// view strings become `String`, other byte-array layouts become `Binary`, and
+    /// timestamps of any unit or time zone become `DateTime` Unix seconds, rounding
+    /// sub-second instants down.
     #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
     pub fn from_arrow_array(array: ArrayRef, name: String) -> Result<Self, VeloxxError> {
+        let array = normalize_arrow_array(array)?;
         match array.data_type() {
             ArrowDataType::Int32 => {
                 let arr = array.as_any().downcast_ref::<Int32Array>().ok_or_else(|| {
                     VeloxxError::Parsing("Failed to downcast to Int32Array".to_string())
                 })?;
-                let values: Vec<i32> = arr.iter().flatten().collect();
+                let values: Vec<i32> = arr.iter().map(|x| x.unwrap_or_default()).collect();
                 let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
                 Ok(Series::I32(name, values, bitmap))
             }
@@ -346,7 +432,7 @@ impl Series {
                     .ok_or_else(|| {
                         VeloxxError::Parsing("Failed to downcast to Float64Array".to_string())
                     })?;
-                let values: Vec<f64> = arr.iter().flatten().collect();
+                let values: Vec<f64> = arr.iter().map(|x| x.unwrap_or_default()).collect();
                 let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
                 Ok(Series::F64(name, values, bitmap))
             }
@@ -357,7 +443,7 @@ impl Series {
                     .ok_or_else(|| {
                         VeloxxError::Parsing("Failed to downcast to BooleanArray".to_string())
                     })?;
-                let values: Vec<bool> = arr.iter().flatten().collect();
+                let values: Vec<bool> = arr.iter().map(|x| x.unwrap_or_default()).collect();
                 let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
                 Ok(Series::Bool(name, values, bitmap))
             }
@@ -368,23 +454,38 @@ impl Series {
                     .ok_or_else(|| {
                         VeloxxError::Parsing("Failed to downcast to StringArray".to_string())
                     })?;
-                let values: Vec<String> = arr.iter().flatten().map(|s| s.to_string()).collect();
+                let values: Vec<String> = arr
+                    .iter()
+                    .map(|x| x.unwrap_or_default().to_string())
+                    .collect();
                 let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
                 Ok(Series::String(name, values, bitmap))
             }
-            ArrowDataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            ArrowDataType::Timestamp(TimeUnit::Second, _) => {
                 let arr = array
                     .as_any()
-                    .downcast_ref::<TimestampNanosecondArray>()
+                    .downcast_ref::<TimestampSecondArray>()
                     .ok_or_else(|| {
                         VeloxxError::Parsing(
-                            "Failed to downcast to TimestampNanosecondArray".to_string(),
+                            "Failed to downcast to TimestampSecondArray".to_string(),
                         )
                     })?;
-                let values: Vec<i64> = arr.iter().flatten().collect();
+                let values: Vec<i64> = arr.iter().map(|x| x.unwrap_or_default()).collect();
                 let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
                 Ok(Series::DateTime(name, values, bitmap))
             }
+            ArrowDataType::Binary => {
+                let arr = array
+                    .as_any()
+                    .downcast_ref::<BinaryArray>()
+                    .ok_or_else(|| {
+                        VeloxxError::Parsing("Failed to downcast to BinaryArray".to_string())
+                    })?;
+                let values: Vec<Vec<u8>> =
+                    arr.iter().map(|x| x.unwrap_or_default().to_vec()).collect();
+                let bitmap: Vec<bool> = arr.iter().map(|x| x.is_some()).collect();
+                Ok(Series::Binary(name, values, bitmap))
+            }
             _ => Err(VeloxxError::Unsupported(format!(
                 "Unsupported Arrow data type: {:?}",
                 array.data_type()
@@ -392,6 +493,43 @@ impl Series {
         }
     }
 
+    /// Convert the Series into an Arrow array (requires `arrow` feature, not available in WASM)
+    ///
+    /// This is the inverse of [`Series::from_arrow_array`]; `DateTime` values are
+    /// exported as second-resolution timestamps.
+    #[cfg(all(feature = "arrow", not(target_arch = "wasm32")))]
+    pub fn to_arrow_array(&self) -> ArrayRef {
+        fn masked<T: Copy>(values: &[T], bitmap: &[bool]) -> Vec<Option<T>> {
+            values
+                .iter()
+                .zip(bitmap)
+                .map(|(v, &valid)| valid.then_some(*v))
+                .collect()
+        }
+        match self {
+            Series::I32(_, values, bitmap) => Arc::new(Int32Array::from(masked(values, bitmap))),
+            Series::F64(_, values, bitmap) => Arc::new(Float64Array::from(masked(values, bitmap))),
+            Series::Bool(_, values, bitmap) => Arc::new(BooleanArray::from(masked(values, bitmap))),
+            Series::String(_, values, bitmap) => Arc::new(StringArray::from(
+                values
+                    .iter()
+                    .zip(bitmap)
+                    .map(|(v, &valid)| valid.then_some(v.as_str()))
+                    .collect::<Vec<_>>(),
+            )),
+            Series::DateTime(_, values, bitmap) => {
+                Arc::new(TimestampSecondArray::from(masked(values, bitmap)))
+            }
+            Series::Binary(_, values, bitmap) => Arc::new(BinaryArray::from(
+                values
+                    .iter()
+                    .zip(bitmap)
+                    .map(|(v, &valid)| valid.then_some(v.as_slice()))
+                    .collect::<Vec<_>>(),
+            )),
+        }
+    }
+
     pub fn concat(series_list: Vec<Series>) -> Result<Self, VeloxxError> {
         if series_list.is_empty() {
             return Err(VeloxxError::InvalidOperation(
@@ -477,6 +615,19 @@ impl Series {
                 }
                 Ok(Series::DateTime(name, values, bitmap))
             }
+            DataType::Binary => {
+                let mut values = Vec::new();
+                let mut bitmap = Vec::new();
+                for s in series_list {
+                    if let Series::Binary(_, v, b) = s {
+                        values.extend(v);
+                        bitmap.extend(b);
+                    } else {
+                        unreachable!();
+                    }
+                }
+                Ok(Series::Binary(name, values, bitmap))
+            }
         }
     }
 
@@ -528,7 +679,8 @@ impl Series {
             )),
         }
     }
-    /// Interpolates null values using linear interpolation for numeric series.
+    /// Interpolates null values using linear interpolation for numeric series;
+    /// see [`Series::interpolate`] for other methods.
     ///
     /// This method performs linear interpolation on null values. It only works
     /// on numeric series (I32 and F64). Null values at the beginning or end
@@ -539,83 +691,7 @@ impl Series {
     /// A `Result` which is `Ok(Series)` containing a new series with interpolated values,
     /// or `Err(VeloxxError)` if interpolation is not supported for this series type.
     pub fn interpolate_nulls(&self) -> Result<Self, VeloxxError> {
-        if !self.is_numeric() {
-            return Err(VeloxxError::Unsupported(
-                "Interpolation only supported for numeric series".to_string(),
-            ));
-        }
-
-        let name = self.name().to_string();
-        let (values, bitmap): (Vec<Option<f64>>, Vec<bool>) = match self {
-            Series::I32(_, values, bitmap) => values
-                .iter()
-                .zip(bitmap.iter())
-                .map(|(&v, &b)| (if b { Some(v as f64) } else { None }, b))
-                .unzip(),
-            Series::F64(_, values, bitmap) => values
-                .iter()
-                .zip(bitmap.iter())
-                .map(|(&v, &b)| (if b { Some(v) } else { None }, b))
-                .unzip(),
-            _ => unreachable!(),
-        };
-
-        let mut interpolated_values = vec![0.0; values.len()]; // Initialize with placeholders
-        let mut interpolated_bitmap = vec![false; values.len()]; // Initialize with false
-
-        let mut i = 0;
-        // Skip leading nulls - they remain as null
-        while i < values.len() && !bitmap[i] {
-            i += 1;
-        }
-
-        let mut last_non_null_idx = None;
-        for j in i..values.len() {
-            if bitmap[j] {
-                // This is a non-null value
-                if let Some(last_idx) = last_non_null_idx {
-                    // We have a previous non-null value, interpolate between them
-                    let start_val_unwrapped: f64 = match &values[last_idx] {
-                        Some(val) => *val,
-                        None => unreachable!(), // We know this is Some because of the bitmap check
-                    };
-                    let end_val_unwrapped: f64 = match &values[j] {
-                        Some(val) => *val,
-                        None => unreachable!(), // We know this is Some because of the bitmap check
-                    };
-                    let num_steps = (j - last_idx) as f64;
-
-                    // Interpolate values between last_idx and j
-                    for k in (last_idx + 1)..j {
-                        let interpolated_val = start_val_unwrapped
-                            + (end_val_unwrapped - start_val_unwrapped)
-                                * ((k - last_idx) as f64 / num_steps);
-                        interpolated_values[k] = interpolated_val;
-                        interpolated_bitmap[k] = true;
-                    }
-                }
-
-                // Set the current non-null value
-                let val: Option<f64> = values[j];
-                interpolated_values[j] = val.unwrap();
-                interpolated_bitmap[j] = true;
-                last_non_null_idx = Some(j);
-            }
-            // If current is null, we'll handle it later
-            // Trailing nulls remain as null
-        }
-
-        match self {
-            Series::I32(_, _, _) => {
-                let rounded: Vec<i32> = interpolated_values
-                    .iter()
-                    .map(|&v| v.round() as i32)
-                    .collect();
-                Ok(Series::I32(name, rounded, interpolated_bitmap))
-            }
-            Series::F64(_, _, _) => Ok(Series::F64(name, interpolated_values, interpolated_bitmap)),
-            _ => unreachable!(),
-        }
+        self.interpolate(interpolate::InterpolationMethod::Linear)
     }
 
     pub fn append(&self, other: &Series) -> Result<Self, VeloxxError> {
@@ -670,6 +746,123 @@ impl Series {
         }
     }
 
+    /// Appends `other` to this series in place; like [`Series::append`] without
+    /// copying the existing values.
+    pub fn extend(&mut self, other: &Series) -> Result<(), VeloxxError> {
+        match (self, other) {
+            (Series::I32(_, values1, bitmap1), Series::I32(_, values2, bitmap2)) => {
+                values1.extend_from_slice(values2);
+                bitmap1.extend_from_slice(bitmap2);
+            }
+            (Series::F64(_, values1, bitmap1), Series::F64(_, values2, bitmap2)) => {
+                values1.extend_from_slice(values2);
+                bitmap1.extend_from_slice(bitmap2);
+            }
+            (Series::Bool(_, values1, bitmap1), Series::Bool(_, values2, bitmap2)) => {
+                values1.extend_from_slice(values2);
+                bitmap1.extend_from_slice(bitmap2);
+            }
+            (Series::String(_, values1, bitmap1), Series::String(_, values2, bitmap2)) => {
+                values1.extend_from_slice(values2);
+                bitmap1.extend_from_slice(bitmap2);
+            }
+            (Series::DateTime(_, values1, bitmap1), Series::DateTime(_, values2, bitmap2)) => {
+                values1.extend_from_slice(values2);
+                bitmap1.extend_from_slice(bitmap2);
+            }
+            (Series::Binary(_, values1, bitmap1), Series::Binary(_, values2, bitmap2)) => {
+                values1.extend_from_slice(values2);
+                bitmap1.extend_from_slice(bitmap2);
+            }
+            (this, other) => {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot append Series of different types: {:?} and {:?}",
+                    this.data_type(),
+                    other.data_type()
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Drops the values after the first `len`, undoing [`Series::extend`].
+    pub(crate) fn truncate(&mut self, len: usize) {
+        match self {
+            Series::I32(_, values, bitmap) => {
+                values.truncate(len);
+                bitmap.truncate(len);
+            }
+            Series::F64(_, values, bitmap) => {
+                values.truncate(len);
+                bitmap.truncate(len);
+            }
+            Series::Bool(_, values, bitmap) => {
+                values.truncate(len);
+                bitmap.truncate(len);
+            }
+            Series::String(_, values, bitmap) => {
+                values.truncate(len);
+                bitmap.truncate(len);
+            }
+            Series::DateTime(_, values, bitmap) => {
+                values.truncate(len);
+                bitmap.truncate(len);
+            }
+            Series::Binary(_, values, bitmap) => {
+                values.truncate(len);
+                bitmap.truncate(len);
+            }
+        }
+    }
+
+    /// Overwrites the value at `index`; `Value::Null` clears it.
+    pub fn set_value(&mut self, index: usize, value: Value) -> Result<(), VeloxxError> {
+        if index >= self.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Index {} out of bounds for Series '{}' of length {}",
+                index,
+                self.name(),
+                self.len()
+            )));
+        }
+        match (self, value) {
+            (Series::I32(_, _, bitmap), Value::Null)
+            | (Series::F64(_, _, bitmap), Value::Null)
+            | (Series::Bool(_, _, bitmap), Value::Null)
+            | (Series::String(_, _, bitmap), Value::Null)
+            | (Series::DateTime(_, _, bitmap), Value::Null) => bitmap[index] = false,
+            (Series::I32(_, values, bitmap), Value::I32(v)) => {
+                values[index] = v;
+                bitmap[index] = true;
+            }
+            (Series::F64(_, values, bitmap), Value::F64(v)) => {
+                values[index] = v;
+                bitmap[index] = true;
+            }
+            (Series::Bool(_, values, bitmap), Value::Bool(v)) => {
+                values[index] = v;
+                bitmap[index] = true;
+            }
+            (Series::String(_, values, bitmap), Value::String(v)) => {
+                values[index] = v;
+                bitmap[index] = true;
+            }
+            (Series::DateTime(_, values, bitmap), Value::DateTime(v)) => {
+                values[index] = v;
+                bitmap[index] = true;
+            }
+            (this, value) => {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot store {:?} in Series '{}' of type {:?}",
+                    value,
+                    this.name(),
+                    this.data_type()
+                )))
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_data_i32(&self) -> Result<Vec<Option<i32>>, VeloxxError> {
         match self {
             Series::I32(_, values, validity) => Ok(values
@@ -735,6 +928,19 @@ impl Series {
         }
     }
 
+    pub fn get_data_binary(&self) -> Result<Vec<Option<Vec<u8>>>, VeloxxError> {
+        match self {
+            Series::Binary(_, values, validity) => Ok(values
+                .iter()
+                .zip(validity.iter())
+                .map(|(v, &b)| if b { Some(v.clone()) } else { None })
+                .collect()),
+            _ => Err(VeloxxError::DataTypeMismatch(
+                "Expected Binary series".to_string(),
+            )),
+        }
+    }
+
     /// Cast series to a different data type
     pub fn cast(&self, to_type: DataType) -> Result<Series, VeloxxError> {
         let name = self.name();
@@ -882,7 +1088,41 @@ impl Series {
     }
 }
 
+macro_rules! impl_series_from_vec {
+    ($ty:ty, $ctor:ident, $conv:expr) => {
+        impl From<Vec<Option<$ty>>> for Series {
+            /// Creates an unnamed series; use [`Series::set_name`] to name it.
+            fn from(data: Vec<Option<$ty>>) -> Self {
+                Series::$ctor("", data.into_iter().map(|v| v.map($conv)).collect())
+            }
+        }
+    };
+}
+
+impl_series_from_vec!(i32, new_i32, |v: i32| v);
+impl_series_from_vec!(f64, new_f64, |v: f64| v);
+impl_series_from_vec!(bool, new_bool, |v: bool| v);
+impl_series_from_vec!(String, new_string, |v: String| v);
+impl_series_from_vec!(&str, new_string, |v: &str| v.to_string());
+impl_series_from_vec!(i64, new_datetime, |v: i64| v);
+impl_series_from_vec!(Vec<u8>, new_binary, |v: Vec<u8>| v);
+
 pub mod aggregations;
 pub mod arithmetic;
+pub mod business_days;
+pub mod bytes;
+#[cfg(feature = "crypto")]
+pub mod crypto;
+pub mod interpolate;
+pub mod ip;
+pub mod logical;
 pub mod ops;
+pub mod replace;
+pub mod rle;
+pub mod statistics;
+pub mod string_ops;
+pub mod strings;
 pub mod time_series;
+pub mod typed;
+pub mod uuid;
+pub mod web;
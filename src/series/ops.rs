@@ -12,6 +12,15 @@ impl Series {
             )));
         }
 
+        #[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+        if let Some(result) = crate::performance::gpu::series_binary(
+            self,
+            other,
+            crate::performance::gpu::GpuBinaryOp::Add,
+        ) {
+            return Ok(result);
+        }
+
         match (self, other) {
             (Series::I32(name, values, bitmap), Series::I32(_, other_values, other_bitmap)) => {
                 let mut new_values = Vec::with_capacity(values.len());
@@ -78,6 +87,15 @@ impl Series {
     }
 
     pub fn multiply(&self, other: &Series) -> Result<Series, VeloxxError> {
+        #[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+        if let Some(result) = crate::performance::gpu::series_binary(
+            self,
+            other,
+            crate::performance::gpu::GpuBinaryOp::Mul,
+        ) {
+            return Ok(result);
+        }
+
         match (self, other) {
             (Series::I32(name, values, bitmap), Series::I32(_, other_values, other_bitmap)) => {
                 let mut new_values = Vec::with_capacity(values.len());
@@ -0,0 +1,147 @@
+//! Recoding the values of a series.
+//!
+//! String columns are the closest thing Veloxx has to categoricals, so they take
+//! a dictionary path: each distinct string is looked up (or passed to the mapping
+//! function) once, and every repeat reuses the result.
+
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+impl Series {
+    /// Replaces every value equal to the first element of a pair in `mapping`
+    /// with the second; other values are kept.
+    ///
+    /// A `Value::Null` key replaces nulls. The column may change type, e.g. when
+    /// recoding integer codes to string labels, but then every non-null value has
+    /// to be mapped, since a column cannot mix types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let codes = Series::new_i32("status", vec![Some(1), Some(2), None, Some(1)]);
+    /// let labels = codes
+    ///     .replace(&[
+    ///         (Value::I32(1), Value::String("open".to_string())),
+    ///         (Value::I32(2), Value::String("closed".to_string())),
+    ///         (Value::Null, Value::String("unknown".to_string())),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(labels.get_value(2), Some(Value::String("unknown".to_string())));
+    /// assert!(codes.replace(&[(Value::I32(1), Value::String("open".to_string()))]).is_err());
+    /// ```
+    pub fn replace(&self, mapping: &[(Value, Value)]) -> Result<Series, VeloxxError> {
+        let lookup: HashMap<&Value, &Value> = mapping.iter().map(|(from, to)| (from, to)).collect();
+        self.recode(|value| {
+            Ok(lookup
+                .get(value)
+                .map_or_else(|| value.clone(), |to| (*to).clone()))
+        })
+    }
+
+    /// Applies `f` to every non-null value; nulls stay null, and `f` may return
+    /// `Value::Null` to clear a value.
+    ///
+    /// Results may have a different type from the column, but must all have the
+    /// same one.
+    pub fn map_values<F>(&self, f: F) -> Result<Series, VeloxxError>
+    where
+        F: Fn(&Value) -> Value,
+    {
+        self.recode(|value| {
+            Ok(match value {
+                Value::Null => Value::Null,
+                value => f(value),
+            })
+        })
+    }
+
+    /// Maps every value (nulls as `Value::Null`) and rebuilds a typed series
+    fn recode(
+        &self,
+        mut map: impl FnMut(&Value) -> Result<Value, VeloxxError>,
+    ) -> Result<Series, VeloxxError> {
+        let mapped: Vec<Value> = match self {
+            Series::String(_, values, bitmap) => {
+                let null = map(&Value::Null)?;
+                let mut dictionary: HashMap<&str, Value> = HashMap::new();
+                let mut mapped = Vec::with_capacity(values.len());
+                for (value, &valid) in values.iter().zip(bitmap.iter()) {
+                    if !valid {
+                        mapped.push(null.clone());
+                        continue;
+                    }
+                    let result = match dictionary.get(value.as_str()) {
+                        Some(result) => result.clone(),
+                        None => {
+                            let result = map(&Value::String(value.clone()))?;
+                            dictionary.insert(value, result.clone());
+                            result
+                        }
+                    };
+                    mapped.push(result);
+                }
+                mapped
+            }
+            _ => (0..self.len())
+                .map(|i| map(&self.get_value(i).unwrap_or(Value::Null)))
+                .collect::<Result<_, _>>()?,
+        };
+
+        let mut data_type: Option<DataType> = None;
+        for value in mapped.iter().filter(|value| **value != Value::Null) {
+            match &data_type {
+                None => data_type = Some(value.data_type()),
+                Some(existing) if *existing != value.data_type() => {
+                    return Err(VeloxxError::DataTypeMismatch(format!(
+                        "Recoding '{}' would mix {:?} and {:?} values; map every value to change the column type",
+                        self.name(),
+                        existing,
+                        value.data_type()
+                    )));
+                }
+                Some(_) => {}
+            }
+        }
+
+        let name = self.name();
+        Ok(match data_type.unwrap_or_else(|| self.data_type()) {
+            DataType::I32 => Series::new_i32(name, mapped.iter().map(Value::as_i32).collect()),
+            DataType::F64 => Series::new_f64(name, mapped.iter().map(Value::as_f64).collect()),
+            DataType::Bool => Series::new_bool(name, mapped.iter().map(Value::as_bool).collect()),
+            DataType::String => Series::new_string(
+                name,
+                mapped
+                    .into_iter()
+                    .map(|v| {
+                        if let Value::String(s) = v {
+                            Some(s)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            ),
+            DataType::DateTime => {
+                Series::new_datetime(name, mapped.iter().map(Value::as_datetime).collect())
+            }
+            DataType::Binary => Series::new_binary(
+                name,
+                mapped
+                    .into_iter()
+                    .map(|v| {
+                        if let Value::Binary(b) = v {
+                            Some(b)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            ),
+        })
+    }
+}
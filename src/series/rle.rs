@@ -0,0 +1,268 @@
+//! Run-length encoded columns.
+//!
+//! Sorted and constant-heavy columns hold long runs of equal values. An
+//! [`RleSeries`] stores each run once and its kernels work a run at a time.
+//! [`Series::rle_if_compact`] picks the encoding only when runs are long enough
+//! to pay off; `DataFrame::filter`, `DataFrame::group_by` and joins call it on
+//! their key columns, so frames fresh out of a sort take the run-based path
+//! without any opt-in.
+
+use crate::performance::specialized_structures::BitPackedArray;
+use crate::performance::vectorized_filter::ComparisonOp;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Columns whose runs average fewer rows than this stay plain
+pub const RLE_MIN_AVG_RUN: usize = 4;
+
+/// A column stored as runs of equal values.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::series::Series;
+/// use veloxx::types::Value;
+///
+/// let series = Series::new_i32("g", vec![Some(1), Some(1), Some(1), None, Some(2), Some(2)]);
+/// let rle = series.to_rle();
+/// assert_eq!(rle.run_count(), 3);
+/// assert_eq!(rle.get_value(4), Some(Value::I32(2)));
+/// assert_eq!(rle.to_series(), series);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RleSeries {
+    name: String,
+    data_type: DataType,
+    /// One entry per run; `None` is a run of nulls
+    values: Vec<Option<Value>>,
+    /// Exclusive end row of each run
+    run_ends: Vec<usize>,
+}
+
+impl Series {
+    /// Run-length encodes the series, however short its runs are.
+    pub fn to_rle(&self) -> RleSeries {
+        let run_ends = self.run_ends(usize::MAX).unwrap_or_default();
+        self.rle_from_ends(run_ends)
+    }
+
+    /// Run-length encodes the series if its runs average at least
+    /// [`RLE_MIN_AVG_RUN`] rows, and returns `None` otherwise.
+    ///
+    /// Counting stops as soon as the column has too many runs, so high-entropy
+    /// columns are rejected after a fraction of a scan.
+    pub fn rle_if_compact(&self) -> Option<RleSeries> {
+        if self.is_empty() {
+            return None;
+        }
+        let max_runs = (self.len() / RLE_MIN_AVG_RUN).max(1);
+        self.run_ends(max_runs)
+            .map(|run_ends| self.rle_from_ends(run_ends))
+    }
+
+    /// Exclusive end row of each run, or `None` once there are more than `max_runs`
+    fn run_ends(&self, max_runs: usize) -> Option<Vec<usize>> {
+        fn scan<T>(
+            data: &[T],
+            validity: &[bool],
+            max_runs: usize,
+            same: impl Fn(&T, &T) -> bool,
+        ) -> Option<Vec<usize>> {
+            let mut ends = Vec::new();
+            for i in 1..data.len() {
+                let equal = match (validity[i - 1], validity[i]) {
+                    (true, true) => same(&data[i - 1], &data[i]),
+                    (false, false) => true,
+                    _ => false,
+                };
+                if !equal {
+                    ends.push(i);
+                    if ends.len() >= max_runs {
+                        return None;
+                    }
+                }
+            }
+            if !data.is_empty() {
+                ends.push(data.len());
+            }
+            Some(ends)
+        }
+        match self {
+            Series::I32(_, data, validity) => scan(data, validity, max_runs, |a, b| a == b),
+            // Bitwise, matching `Value` equality
+            Series::F64(_, data, validity) => {
+                scan(data, validity, max_runs, |a, b| a.to_bits() == b.to_bits())
+            }
+            Series::Bool(_, data, validity) => scan(data, validity, max_runs, |a, b| a == b),
+            Series::String(_, data, validity) => scan(data, validity, max_runs, |a, b| a == b),
+            Series::DateTime(_, data, validity) => scan(data, validity, max_runs, |a, b| a == b),
+            Series::Binary(_, data, validity) => scan(data, validity, max_runs, |a, b| a == b),
+        }
+    }
+
+    fn rle_from_ends(&self, run_ends: Vec<usize>) -> RleSeries {
+        let mut start = 0;
+        let values = run_ends
+            .iter()
+            .map(|&end| {
+                let value = self.get_value(start);
+                start = end;
+                value
+            })
+            .collect();
+        RleSeries {
+            name: self.name().to_string(),
+            data_type: self.data_type(),
+            values,
+            run_ends,
+        }
+    }
+}
+
+impl RleSeries {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn data_type(&self) -> DataType {
+        self.data_type.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.run_ends.last().copied().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn run_count(&self) -> usize {
+        self.run_ends.len()
+    }
+
+    /// Each run's value (`None` for nulls) and the rows it covers
+    pub fn runs(&self) -> impl Iterator<Item = (Option<&Value>, Range<usize>)> + '_ {
+        self.values
+            .iter()
+            .zip(self.run_ends.iter())
+            .scan(0, |start, (value, &end)| {
+                let range = *start..end;
+                *start = end;
+                Some((value.as_ref(), range))
+            })
+    }
+
+    /// Value at `index`, found by binary search over the runs
+    pub fn get_value(&self, index: usize) -> Option<Value> {
+        if index >= self.len() {
+            return None;
+        }
+        let run = self.run_ends.partition_point(|&end| end <= index);
+        self.values[run].clone()
+    }
+
+    /// Expands the runs back into a plain `Series`.
+    pub fn to_series(&self) -> Series {
+        match self.data_type {
+            DataType::I32 => Series::new_i32(&self.name, self.expand(Value::as_i32)),
+            DataType::F64 => Series::new_f64(&self.name, self.expand(Value::as_f64)),
+            DataType::Bool => Series::new_bool(&self.name, self.expand(Value::as_bool)),
+            DataType::String => {
+                Series::new_string(&self.name, self.expand(|v| v.as_string().cloned()))
+            }
+            DataType::DateTime => Series::new_datetime(&self.name, self.expand(Value::as_datetime)),
+            DataType::Binary => Series::new_binary(
+                &self.name,
+                self.expand(|v| v.as_binary().map(<[u8]>::to_vec)),
+            ),
+        }
+    }
+
+    fn expand<T: Clone>(&self, get: impl Fn(&Value) -> Option<T>) -> Vec<Option<T>> {
+        let mut out = Vec::with_capacity(self.len());
+        for (value, rows) in self.runs() {
+            let value = value.and_then(&get);
+            out.extend(std::iter::repeat_n(value, rows.len()));
+        }
+        out
+    }
+
+    /// Row mask for `column <op> value`, comparing once per run.
+    ///
+    /// Follows [`VectorizedFilter::fast_filter_single_column`]: nulls never match,
+    /// `F64` equality is within `f64::EPSILON`, and only `I32`, `F64` and `String`
+    /// columns compared against a value of the same type are supported.
+    ///
+    /// [`VectorizedFilter::fast_filter_single_column`]: crate::performance::vectorized_filter::VectorizedFilter::fast_filter_single_column
+    pub fn comparison_mask(
+        &self,
+        value: &Value,
+        op: ComparisonOp,
+    ) -> Result<BitPackedArray, VeloxxError> {
+        let supported = matches!(
+            (&self.data_type, value),
+            (DataType::I32, Value::I32(_))
+                | (DataType::F64, Value::F64(_))
+                | (DataType::String, Value::String(_))
+        );
+        if !supported {
+            return Err(VeloxxError::Unsupported(
+                "Unsupported combination for fast filtering".to_string(),
+            ));
+        }
+        let mut mask = BitPackedArray::new(self.len());
+        for (cell, rows) in self.runs() {
+            let keep = cell.is_some_and(|cell| compare(cell, value, op));
+            for _ in rows {
+                mask.push(keep);
+            }
+        }
+        Ok(mask)
+    }
+
+    /// Rows of each distinct value (nulls included, as `None`), as the runs that
+    /// hold it, in order of first appearance.
+    pub fn group_ranges(&self) -> Vec<(Option<Value>, Vec<Range<usize>>)> {
+        let mut positions: HashMap<Option<&Value>, usize> = HashMap::new();
+        let mut groups: Vec<(Option<Value>, Vec<Range<usize>>)> = Vec::new();
+        for (value, rows) in self.runs() {
+            let position = *positions.entry(value).or_insert_with(|| {
+                groups.push((value.cloned(), Vec::new()));
+                groups.len() - 1
+            });
+            groups[position].1.push(rows);
+        }
+        groups
+    }
+}
+
+fn compare(cell: &Value, value: &Value, op: ComparisonOp) -> bool {
+    let ordering = match (cell, value) {
+        (Value::I32(a), Value::I32(b)) => a.cmp(b),
+        (Value::F64(a), Value::F64(b)) => {
+            // Written out so that NaN matches nothing, as in the vectorized kernels
+            match op {
+                ComparisonOp::Eq => return (a - b).abs() < f64::EPSILON,
+                ComparisonOp::Ne => return (a - b).abs() >= f64::EPSILON,
+                _ => match a.partial_cmp(b) {
+                    Some(ordering) => ordering,
+                    None => return false,
+                },
+            }
+        }
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        _ => return false,
+    };
+    match op {
+        ComparisonOp::Gt => ordering == Ordering::Greater,
+        ComparisonOp::Gte => ordering != Ordering::Less,
+        ComparisonOp::Lt => ordering == Ordering::Less,
+        ComparisonOp::Lte => ordering != Ordering::Greater,
+        ComparisonOp::Eq => ordering == Ordering::Equal,
+        ComparisonOp::Ne => ordering != Ordering::Equal,
+    }
+}
@@ -0,0 +1,179 @@
+//! Lightweight column statistics used for inspection and by the lazy optimizer.
+
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+/// Series up to this length count distinct values exactly
+const EXACT_DISTINCT_LIMIT: usize = 10_000;
+/// HyperLogLog precision: 2^12 registers, about 1.6% standard error
+const HLL_PRECISION: u32 = 12;
+
+/// Summary statistics of one column, from [`Series::stats`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    pub name: String,
+    pub data_type: DataType,
+    pub len: usize,
+    pub null_count: usize,
+    /// Number of distinct non-null values; exact for short columns and a
+    /// HyperLogLog estimate otherwise
+    pub n_unique: usize,
+    /// Smallest non-null value; `NaN`s are ignored
+    pub min: Option<Value>,
+    /// Largest non-null value; `NaN`s are ignored
+    pub max: Option<Value>,
+    /// Whether the non-null values are in ascending order
+    pub sorted: bool,
+}
+
+impl Series {
+    /// Computes null count, distinct count, min/max and sortedness in one pass.
+    pub fn stats(&self) -> ColumnStats {
+        match self {
+            Series::I32(name, data, validity) => {
+                column_stats(name, self, data, validity, |v| Value::I32(*v))
+            }
+            Series::F64(name, data, validity) => {
+                // Compare floats by value, skipping NaN for min/max
+                let mut stats = column_stats(name, self, data, validity, |v| {
+                    Value::F64(if *v == 0.0 { 0.0 } else { *v })
+                });
+                let finite = data
+                    .iter()
+                    .zip(validity)
+                    .filter(|(v, valid)| **valid && !v.is_nan())
+                    .map(|(v, _)| *v);
+                let (min, max) =
+                    finite.fold((None, None), |(min, max): (Option<f64>, Option<f64>), v| {
+                        (
+                            Some(min.map_or(v, |m| m.min(v))),
+                            Some(max.map_or(v, |m| m.max(v))),
+                        )
+                    });
+                stats.min = min.map(Value::F64);
+                stats.max = max.map(Value::F64);
+                stats.sorted = data
+                    .iter()
+                    .zip(validity)
+                    .filter(|(_, valid)| **valid)
+                    .map(|(v, _)| *v)
+                    .collect::<Vec<f64>>()
+                    .windows(2)
+                    .all(|pair| pair[0].total_cmp(&pair[1]).is_le());
+                stats
+            }
+            Series::Bool(name, data, validity) => {
+                column_stats(name, self, data, validity, |v| Value::Bool(*v))
+            }
+            Series::String(name, data, validity) => {
+                column_stats(name, self, data, validity, |v| Value::String(v.clone()))
+            }
+            Series::DateTime(name, data, validity) => {
+                column_stats(name, self, data, validity, |v| Value::DateTime(*v))
+            }
+            Series::Binary(name, data, validity) => {
+                column_stats(name, self, data, validity, |v| Value::Binary(v.clone()))
+            }
+        }
+    }
+}
+
+fn column_stats<T: PartialOrd>(
+    name: &str,
+    series: &Series,
+    data: &[T],
+    validity: &[bool],
+    to_value: impl Fn(&T) -> Value,
+) -> ColumnStats {
+    let mut min: Option<&T> = None;
+    let mut max: Option<&T> = None;
+    let mut previous: Option<&T> = None;
+    let mut sorted = true;
+    let mut null_count = 0;
+    let mut distinct = DistinctCounter::new(data.len() - validity.iter().filter(|v| !**v).count());
+    for (value, valid) in data.iter().zip(validity) {
+        if !*valid {
+            null_count += 1;
+            continue;
+        }
+        if min.is_none_or(|m| value < m) {
+            min = Some(value);
+        }
+        if max.is_none_or(|m| value > m) {
+            max = Some(value);
+        }
+        if previous.is_some_and(|p| value < p) {
+            sorted = false;
+        }
+        previous = Some(value);
+        distinct.insert(to_value(value));
+    }
+    ColumnStats {
+        name: name.to_string(),
+        data_type: series.data_type(),
+        len: data.len(),
+        null_count,
+        n_unique: distinct.count(),
+        min: min.map(&to_value),
+        max: max.map(&to_value),
+        sorted,
+    }
+}
+
+/// Exact distinct counting for short inputs, HyperLogLog beyond that
+enum DistinctCounter {
+    Exact(HashSet<Value>),
+    Sketch {
+        hasher: std::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>,
+        registers: Vec<u8>,
+    },
+}
+
+impl DistinctCounter {
+    fn new(values: usize) -> Self {
+        if values <= EXACT_DISTINCT_LIMIT {
+            DistinctCounter::Exact(HashSet::with_capacity(values))
+        } else {
+            DistinctCounter::Sketch {
+                hasher: Default::default(),
+                registers: vec![0; 1 << HLL_PRECISION],
+            }
+        }
+    }
+
+    fn insert(&mut self, value: Value) {
+        match self {
+            DistinctCounter::Exact(set) => {
+                set.insert(value);
+            }
+            DistinctCounter::Sketch { hasher, registers } => {
+                let hash = hasher.hash_one(&value);
+                let index = (hash >> (64 - HLL_PRECISION)) as usize;
+                let rank =
+                    ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros() + 1;
+                registers[index] = registers[index].max(rank as u8);
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        match self {
+            DistinctCounter::Exact(set) => set.len(),
+            DistinctCounter::Sketch { registers, .. } => {
+                let m = registers.len() as f64;
+                let alpha = 0.7213 / (1.0 + 1.079 / m);
+                let sum: f64 = registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+                let estimate = alpha * m * m / sum;
+                let zeros = registers.iter().filter(|&&r| r == 0).count();
+                if estimate <= 2.5 * m && zeros > 0 {
+                    // Linear counting is more accurate for small cardinalities
+                    (m * (m / zeros as f64).ln()).round() as usize
+                } else {
+                    estimate.round() as usize
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Cleaning helpers for messy text, e.g. values read from hand-edited CSV files.
+//!
+//! Every operation works value by value on a `String` series and keeps nulls.
+//! Widths are counted in chars, not bytes, so padding never splits a character.
+
+use crate::series::Series;
+use crate::VeloxxError;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Which side of a value [`Series::str_pad`] fills.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadSide {
+    /// Fill before the value, right-aligning it
+    Left,
+    /// Fill after the value, left-aligning it
+    Right,
+    /// Fill both sides, centering the value; odd fills put the extra char on the right
+    Both,
+}
+
+impl Series {
+    /// Removes leading and trailing whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let names = Series::new_string("name", vec![Some("  Alice \t".to_string()), None]);
+    /// let trimmed = names.str_trim().unwrap();
+    /// assert_eq!(trimmed.get_value(0), Some(Value::String("Alice".to_string())));
+    /// assert_eq!(trimmed.get_value(1), None);
+    /// ```
+    pub fn str_trim(&self) -> Result<Series, VeloxxError> {
+        self.map_strings("str_trim", |s| s.trim().to_string())
+    }
+
+    /// Removes any of the chars in `chars` from both ends, e.g. `"\"'"` for
+    /// stray quotes.
+    pub fn str_strip_chars(&self, chars: &str) -> Result<Series, VeloxxError> {
+        self.map_strings("str_strip_chars", |s| {
+            s.trim_matches(|c| chars.contains(c)).to_string()
+        })
+    }
+
+    /// Trims the value and collapses every inner run of whitespace, including
+    /// tabs and newlines, into a single space.
+    pub fn str_normalize_whitespace(&self) -> Result<Series, VeloxxError> {
+        self.map_strings("str_normalize_whitespace", |s| {
+            s.split_whitespace().collect::<Vec<_>>().join(" ")
+        })
+    }
+
+    /// Strips diacritics, so `"Zürich"` becomes `"Zurich"`.
+    ///
+    /// Values are decomposed (NFD) and combining marks dropped; letters with
+    /// no decomposition, such as `ß` or `ø`, are kept as they are.
+    pub fn str_remove_accents(&self) -> Result<Series, VeloxxError> {
+        self.map_strings("str_remove_accents", |s| {
+            if s.is_ascii() {
+                s.to_string()
+            } else {
+                s.nfd().filter(|&c| !is_combining_mark(c)).collect()
+            }
+        })
+    }
+
+    /// Left-pads values with zeros to `width` chars, keeping a leading `+` or
+    /// `-` sign in front; longer values are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let codes = Series::new_string("zip", vec![Some("501".to_string()), Some("-7".to_string())]);
+    /// let padded = codes.str_zfill(5).unwrap();
+    /// assert_eq!(padded.get_value(0), Some(Value::String("00501".to_string())));
+    /// assert_eq!(padded.get_value(1), Some(Value::String("-0007".to_string())));
+    /// ```
+    pub fn str_zfill(&self, width: usize) -> Result<Series, VeloxxError> {
+        self.map_strings("str_zfill", |s| {
+            let len = s.chars().count();
+            if len >= width {
+                return s.to_string();
+            }
+            let (sign, digits) = match s.strip_prefix(['+', '-']) {
+                Some(rest) => (&s[..1], rest),
+                None => ("", s),
+            };
+            format!("{sign}{}{digits}", "0".repeat(width - len))
+        })
+    }
+
+    /// Pads values with `fill` to `width` chars on the given side; longer
+    /// values are left unchanged.
+    pub fn str_pad(&self, width: usize, side: PadSide, fill: char) -> Result<Series, VeloxxError> {
+        self.map_strings("str_pad", |s| {
+            let missing = width.saturating_sub(s.chars().count());
+            let (left, right) = match side {
+                PadSide::Left => (missing, 0),
+                PadSide::Right => (0, missing),
+                PadSide::Both => (missing / 2, missing - missing / 2),
+            };
+            let mut padded = String::with_capacity(s.len() + missing * fill.len_utf8());
+            padded.extend(std::iter::repeat_n(fill, left));
+            padded.push_str(s);
+            padded.extend(std::iter::repeat_n(fill, right));
+            padded
+        })
+    }
+}
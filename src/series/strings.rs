@@ -0,0 +1,211 @@
+use crate::performance::simd_string::{
+    simd_contains, simd_ends_with, simd_starts_with, simd_to_lowercase, simd_to_uppercase,
+};
+use crate::series::Series;
+use crate::VeloxxError;
+
+impl Series {
+    /// Returns a new `String` series with every value lowercased.
+    ///
+    /// ASCII values take a vectorized path; other values use Unicode case mapping.
+    /// Nulls are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let levels = Series::new_string("level", vec![Some("WARN".to_string()), None]);
+    /// let lower = levels.str_to_lowercase().unwrap();
+    /// // Result: [Some("warn"), None]
+    /// ```
+    pub fn str_to_lowercase(&self) -> Result<Series, VeloxxError> {
+        self.map_strings("str_to_lowercase", simd_to_lowercase)
+    }
+
+    /// Returns a new `String` series with every value uppercased.
+    pub fn str_to_uppercase(&self) -> Result<Series, VeloxxError> {
+        self.map_strings("str_to_uppercase", simd_to_uppercase)
+    }
+
+    /// Returns a `Bool` series that is `true` where the value starts with `prefix`.
+    ///
+    /// Null values stay null in the result.
+    pub fn str_starts_with(&self, prefix: &str) -> Result<Series, VeloxxError> {
+        self.match_strings("str_starts_with", |s| simd_starts_with(s, prefix))
+    }
+
+    /// Returns a `Bool` series that is `true` where the value ends with `suffix`.
+    pub fn str_ends_with(&self, suffix: &str) -> Result<Series, VeloxxError> {
+        self.match_strings("str_ends_with", |s| simd_ends_with(s, suffix))
+    }
+
+    /// Returns a `Bool` series that is `true` where the value contains `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let lines = Series::new_string("line", vec![Some("GET /index".to_string()), Some("POST /login".to_string())]);
+    /// let hits = lines.str_contains("login").unwrap();
+    /// // Result: [Some(false), Some(true)]
+    /// ```
+    pub fn str_contains(&self, pattern: &str) -> Result<Series, VeloxxError> {
+        self.match_strings("str_contains", |s| simd_contains(s, pattern))
+    }
+
+    /// Returns an `I32` series with the length of each value in bytes of UTF-8.
+    ///
+    /// This is the storage size, not the number of characters; see
+    /// [`Series::str_len_chars`].
+    pub fn str_len_bytes(&self) -> Result<Series, VeloxxError> {
+        self.measure_strings("str_len_bytes", str::len)
+    }
+
+    /// Returns an `I32` series with the number of chars (Unicode scalar values)
+    /// in each value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let cities = Series::new_string("city", vec![Some("Zürich".to_string()), None]);
+    /// assert_eq!(cities.str_len_chars().unwrap().get_value(0), Some(Value::I32(6)));
+    /// assert_eq!(cities.str_len_bytes().unwrap().get_value(0), Some(Value::I32(7)));
+    /// ```
+    pub fn str_len_chars(&self) -> Result<Series, VeloxxError> {
+        self.measure_strings("str_len_chars", |s| s.chars().count())
+    }
+
+    /// Returns the substring of up to `length` chars starting at char `start`;
+    /// `None` takes the rest of the value.
+    ///
+    /// Offsets past the end give an empty string, so multi-byte text is never
+    /// cut inside a character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let words = Series::new_string("word", vec![Some("日本語テキスト".to_string())]);
+    /// let head = words.str_slice_chars(0, Some(3)).unwrap();
+    /// assert_eq!(head.get_value(0), Some(Value::String("日本語".to_string())));
+    /// ```
+    pub fn str_slice_chars(
+        &self,
+        start: usize,
+        length: Option<usize>,
+    ) -> Result<Series, VeloxxError> {
+        self.map_strings("str_slice_chars", |s| {
+            slice_chars(s, start, length).to_string()
+        })
+    }
+
+    /// Byte-offset variant of [`Series::str_slice_chars`], for ASCII data or
+    /// fixed-width byte layouts.
+    ///
+    /// Offsets are clamped to the value; an offset that falls inside a
+    /// multi-byte character is an error rather than a corrupted value.
+    pub fn str_slice_bytes(
+        &self,
+        start: usize,
+        length: Option<usize>,
+    ) -> Result<Series, VeloxxError> {
+        let Series::String(_, values, bitmap) = self else {
+            return self.map_strings("str_slice_bytes", str::to_string);
+        };
+        let bounds = |s: &str| {
+            let begin = start.min(s.len());
+            let end = length.map_or(s.len(), |len| begin.saturating_add(len).min(s.len()));
+            (begin, end)
+        };
+        for (value, _) in values.iter().zip(bitmap).filter(|(_, &valid)| valid) {
+            let (begin, end) = bounds(value);
+            if !value.is_char_boundary(begin) || !value.is_char_boundary(end) {
+                return Err(VeloxxError::InvalidOperation(format!(
+                    "str_slice_bytes({start}, {length:?}) would split a character in {value:?}; \
+                     use str_slice_chars for non-ASCII text"
+                )));
+            }
+        }
+        self.map_strings("str_slice_bytes", |s| {
+            let (begin, end) = bounds(s);
+            s[begin..end].to_string()
+        })
+    }
+
+    pub(super) fn map_strings(
+        &self,
+        op: &str,
+        f: impl Fn(&str) -> String,
+    ) -> Result<Series, VeloxxError> {
+        match self {
+            Series::String(name, values, bitmap) => {
+                let values = values
+                    .iter()
+                    .zip(bitmap)
+                    .map(|(v, &valid)| if valid { f(v) } else { String::new() })
+                    .collect();
+                Ok(Series::String(name.clone(), values, bitmap.clone()))
+            }
+            _ => Err(VeloxxError::DataTypeMismatch(format!(
+                "{op} requires a String series, got {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    fn measure_strings(&self, op: &str, f: impl Fn(&str) -> usize) -> Result<Series, VeloxxError> {
+        match self {
+            Series::String(name, values, bitmap) => {
+                let values = values
+                    .iter()
+                    .zip(bitmap)
+                    .map(|(v, &valid)| if valid { f(v) as i32 } else { 0 })
+                    .collect();
+                Ok(Series::I32(name.clone(), values, bitmap.clone()))
+            }
+            _ => Err(VeloxxError::DataTypeMismatch(format!(
+                "{op} requires a String series, got {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    fn match_strings(&self, op: &str, f: impl Fn(&str) -> bool) -> Result<Series, VeloxxError> {
+        match self {
+            Series::String(name, values, bitmap) => {
+                let values = values
+                    .iter()
+                    .zip(bitmap)
+                    .map(|(v, &valid)| valid && f(v))
+                    .collect();
+                Ok(Series::Bool(name.clone(), values, bitmap.clone()))
+            }
+            _ => Err(VeloxxError::DataTypeMismatch(format!(
+                "{op} requires a String series, got {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+}
+
+/// The substring of up to `length` chars starting at char `start`.
+pub(crate) fn slice_chars(s: &str, start: usize, length: Option<usize>) -> &str {
+    let mut offsets = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()));
+    let begin = offsets.nth(start).unwrap_or(s.len());
+    let end = match length {
+        Some(0) => begin,
+        Some(len) => offsets.nth(len - 1).unwrap_or(s.len()),
+        None => s.len(),
+    };
+    &s[begin..end]
+}
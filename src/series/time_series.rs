@@ -499,6 +499,134 @@ impl Series {
         }
     }
 
+    /// Rolling sample covariance between this series and `other` over windows of
+    /// `window_size` rows.
+    ///
+    /// Only rows where both series have a value count towards a window, so a
+    /// null in either series drops the pair; a window with fewer than two pairs
+    /// is null, as are the first `window_size - 1` rows. The result is an `F64`
+    /// series named `{self}_{other}_rolling_cov_{window_size}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VeloxxError::InvalidOperation` if either series is not numeric,
+    /// their lengths differ, or the window size is less than 2 or greater than
+    /// the series length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let x = Series::new_f64("x", vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0)]);
+    /// let y = Series::new_i32("y", vec![Some(2), Some(4), None, Some(8)]);
+    /// let cov = x.rolling_cov(&y, 3).unwrap();
+    /// assert_eq!(cov.get_value(1), None);
+    /// // Row 2 pairs (1, 2) and (2, 4): the null in `y` drops x = 3
+    /// assert_eq!(cov.get_value(2), Some(Value::F64(1.0)));
+    /// ```
+    pub fn rolling_cov(&self, other: &Series, window_size: usize) -> Result<Series, VeloxxError> {
+        self.rolling_pairwise(other, window_size, "cov", |pairs| {
+            let (mean_x, mean_y) = pair_means(pairs);
+            let sum: f64 = pairs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+            Some(sum / (pairs.len() - 1) as f64)
+        })
+    }
+
+    /// Rolling Pearson correlation between this series and `other` over windows
+    /// of `window_size` rows.
+    ///
+    /// Nulls are matched as for [`Series::rolling_cov`]; a window where either
+    /// series has no variance is also null. The result is an `F64` series named
+    /// `{self}_{other}_rolling_corr_{window_size}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let x = Series::new_f64("x", vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0)]);
+    /// let y = Series::new_f64("y", vec![Some(8.0), Some(6.0), Some(4.0), Some(4.0)]);
+    /// let corr = x.rolling_corr(&y, 3).unwrap();
+    /// assert_eq!(corr.get_value(2), Some(Value::F64(-1.0)));
+    /// ```
+    pub fn rolling_corr(&self, other: &Series, window_size: usize) -> Result<Series, VeloxxError> {
+        self.rolling_pairwise(other, window_size, "corr", |pairs| {
+            let (mean_x, mean_y) = pair_means(pairs);
+            let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+            for (x, y) in pairs {
+                let (dx, dy) = (x - mean_x, y - mean_y);
+                sxy += dx * dy;
+                sxx += dx * dx;
+                syy += dy * dy;
+            }
+            let denominator = (sxx * syy).sqrt();
+            (denominator != 0.0).then(|| (sxy / denominator).clamp(-1.0, 1.0))
+        })
+    }
+
+    /// Applies `stat` to the present `(self, other)` pairs of each window
+    fn rolling_pairwise(
+        &self,
+        other: &Series,
+        window_size: usize,
+        stat_name: &str,
+        stat: impl Fn(&[(f64, f64)]) -> Option<f64>,
+    ) -> Result<Series, VeloxxError> {
+        if !self.is_numeric() || !other.is_numeric() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Rolling {stat_name} is only supported for numeric series (I32, F64)"
+            )));
+        }
+        if self.len() != other.len() {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Series must have same length for rolling {stat_name}"
+            )));
+        }
+        if window_size < 2 {
+            return Err(VeloxxError::InvalidOperation(format!(
+                "Window size must be at least 2 for rolling {stat_name}"
+            )));
+        }
+        if window_size > self.len() {
+            return Err(VeloxxError::InvalidOperation(
+                "Window size cannot be greater than series length".to_string(),
+            ));
+        }
+
+        let name = format!(
+            "{}_{}_rolling_{}_{}",
+            self.name(),
+            other.name(),
+            stat_name,
+            window_size
+        );
+        let mut values = Vec::with_capacity(self.len());
+        let mut validity = Vec::with_capacity(self.len());
+        let mut pairs = Vec::with_capacity(window_size);
+        for i in 0..self.len() {
+            let result =
+                if i + 1 < window_size {
+                    None
+                } else {
+                    pairs.clear();
+                    pairs.extend((i + 1 - window_size..=i).filter_map(|j| {
+                        Some((self.get_numeric_f64(j)?, other.get_numeric_f64(j)?))
+                    }));
+                    if pairs.len() < 2 {
+                        None
+                    } else {
+                        stat(&pairs)
+                    }
+                };
+            values.push(result.unwrap_or(0.0));
+            validity.push(result.is_some());
+        }
+        Ok(Series::F64(name, values, validity))
+    }
+
     /// Calculates percentage change between consecutive values.
     ///
     /// This function computes the percentage change from one value to the next.
@@ -618,9 +746,288 @@ impl Series {
     }
 }
 
+/// Components of a series from [`stl_decompose`], each an `F64` series of the
+/// same length that add back up to the original
+#[derive(Debug, Clone, PartialEq)]
+pub struct StlDecomposition {
+    /// The smooth long-run level, named `{name}_trend`
+    pub trend: Series,
+    /// The repeating pattern of length `period`, named `{name}_seasonal`
+    pub seasonal: Series,
+    /// What is left after removing trend and seasonality, named `{name}_residual`
+    pub residual: Series,
+}
+
+/// Smoothing settings for [`stl_decompose_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StlOptions {
+    /// Number of rows in one season, at least 2
+    pub period: usize,
+    /// Span in seasons of the smoother across each cycle-subseries, odd and at
+    /// least 7; larger spans let the seasonal pattern change more slowly
+    pub seasonal: usize,
+    /// Whether to downweight outliers, so a few extreme values end up in the
+    /// residual rather than bending the trend and seasonal components
+    pub robust: bool,
+}
+
+impl StlOptions {
+    /// Defaults for a season of `period` rows: a seasonal span of 7 and no
+    /// outlier downweighting
+    pub fn new(period: usize) -> Self {
+        StlOptions {
+            period,
+            seasonal: 7,
+            robust: false,
+        }
+    }
+}
+
+/// Splits `series` into trend, seasonal and residual components by STL
+/// (Cleveland et al., 1990) with seasons of `period` rows; see
+/// [`stl_decompose_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::series::time_series::stl_decompose;
+/// use veloxx::series::Series;
+///
+/// // A rising line plus a repeating 0, 3, 0, -3 pattern
+/// let values = (0..48).map(|i| Some(i as f64 * 0.5 + [0.0, 3.0, 0.0, -3.0][i % 4]));
+/// let series = Series::new_f64("load", values.collect());
+/// let parts = stl_decompose(&series, 4).unwrap();
+///
+/// let seasonal = parts.seasonal.get_f64(21).unwrap();
+/// assert!((seasonal - 3.0).abs() < 0.1);
+/// let trend = parts.trend.get_f64(21).unwrap();
+/// assert!((trend - 10.5).abs() < 0.1);
+/// ```
+pub fn stl_decompose(series: &Series, period: usize) -> Result<StlDecomposition, VeloxxError> {
+    stl_decompose_with(series, &StlOptions::new(period))
+}
+
+/// Splits `series` into trend, seasonal and residual components by STL, the
+/// seasonal-trend decomposition using LOESS of Cleveland et al. (1990).
+///
+/// Each cycle-subseries (every `period`-th row) is smoothed to find the
+/// seasonal component, and the deseasonalized series is smoothed to find the
+/// trend, alternating twice; robust decomposition then repeats this 15 times
+/// with outliers downweighted. The residual is exactly `series - trend -
+/// seasonal`.
+///
+/// # Errors
+///
+/// Returns `VeloxxError::InvalidOperation` if the series is not `I32` or
+/// `F64`, holds nulls (fill them first, e.g. with [`Series::interpolate`]),
+/// is shorter than two periods, or the options are out of range.
+pub fn stl_decompose_with(
+    series: &Series,
+    options: &StlOptions,
+) -> Result<StlDecomposition, VeloxxError> {
+    if !series.is_numeric() {
+        return Err(VeloxxError::InvalidOperation(
+            "STL decomposition is only supported for numeric series (I32, F64)".to_string(),
+        ));
+    }
+    let y = (0..series.len())
+        .map(|i| series.get_numeric_f64(i))
+        .collect::<Option<Vec<f64>>>()
+        .ok_or_else(|| {
+            VeloxxError::InvalidOperation(format!(
+                "STL decomposition requires a series without nulls, '{}' has nulls",
+                series.name()
+            ))
+        })?;
+    let StlOptions {
+        period,
+        seasonal,
+        robust,
+    } = *options;
+    if period < 2 {
+        return Err(VeloxxError::InvalidOperation(
+            "STL period must be at least 2".to_string(),
+        ));
+    }
+    if seasonal < 7 || seasonal.is_multiple_of(2) {
+        return Err(VeloxxError::InvalidOperation(
+            "STL seasonal span must be odd and at least 7".to_string(),
+        ));
+    }
+    if y.len() < 2 * period {
+        return Err(VeloxxError::InvalidOperation(
+            "STL decomposition needs at least two periods of data".to_string(),
+        ));
+    }
+
+    let n = y.len();
+    let next_odd = |x: usize| if x.is_multiple_of(2) { x + 1 } else { x };
+    // Span defaults of the original paper for the trend and low-pass smoothers
+    let trend_span =
+        next_odd((1.5 * period as f64 / (1.0 - 1.5 / seasonal as f64)).ceil() as usize);
+    let low_pass_span = next_odd(period);
+    let (inner, outer) = if robust { (2, 15) } else { (2, 0) };
+
+    let mut weights = vec![1.0; n];
+    let mut trend = vec![0.0; n];
+    let mut season = vec![0.0; n];
+    for pass in 0..=outer {
+        for _ in 0..inner {
+            // Cycle-subseries smoothing, extended by a season at each end
+            let detrended: Vec<f64> = y.iter().zip(&trend).map(|(y, t)| y - t).collect();
+            let mut cycle = vec![0.0; n + 2 * period];
+            for phase in 0..period {
+                let rows: Vec<usize> = (phase..n).step_by(period).collect();
+                let values: Vec<f64> = rows.iter().map(|&i| detrended[i]).collect();
+                let row_weights: Vec<f64> = rows.iter().map(|&i| weights[i]).collect();
+                for k in 0..rows.len() + 2 {
+                    let x = k as f64 - 1.0;
+                    let fallback = values[(k.max(1) - 1).min(values.len() - 1)];
+                    cycle[phase + k * period] =
+                        loess(&values, &row_weights, seasonal, x).unwrap_or(fallback);
+                }
+            }
+
+            // Low-pass filter of the cycle-subseries, removed from the seasonal
+            let low_pass =
+                moving_average(&moving_average(&moving_average(&cycle, period), period), 3);
+            let unit = vec![1.0; n];
+            for i in 0..n {
+                let low = loess(&low_pass, &unit, low_pass_span, i as f64).unwrap_or(low_pass[i]);
+                season[i] = cycle[period + i] - low;
+            }
+
+            // Trend smoothing of the deseasonalized series
+            let deseasonalized: Vec<f64> = y.iter().zip(&season).map(|(y, s)| y - s).collect();
+            for i in 0..n {
+                trend[i] = loess(&deseasonalized, &weights, trend_span, i as f64)
+                    .unwrap_or(deseasonalized[i]);
+            }
+        }
+        if pass < outer {
+            weights = robustness_weights(&y, &trend, &season);
+        }
+    }
+
+    let residual: Vec<f64> = (0..n).map(|i| y[i] - trend[i] - season[i]).collect();
+    let component = |suffix: &str, values: Vec<f64>| {
+        Series::F64(
+            format!("{}_{}", series.name(), suffix),
+            values,
+            vec![true; n],
+        )
+    };
+    Ok(StlDecomposition {
+        trend: component("trend", trend),
+        seasonal: component("seasonal", season),
+        residual: component("residual", residual),
+    })
+}
+
+/// Locally linear fit to `y` (at positions `0..y.len()`) evaluated at `x`,
+/// using the `span` nearest points with tricube weights scaled by `weights`;
+/// `None` if all of them have zero weight
+fn loess(y: &[f64], weights: &[f64], span: usize, x: f64) -> Option<f64> {
+    let n = y.len();
+    let q = span.min(n);
+    // The window of the q nearest positions, clamped to the data
+    let centre = x.round().clamp(0.0, (n - 1) as f64) as usize;
+    let mut left = centre.saturating_sub(q / 2).min(n - q);
+    while left > 0 && x - (left - 1) as f64 <= (left + q - 1) as f64 - x {
+        left -= 1;
+    }
+    while left + q < n && (left + q) as f64 - x < x - left as f64 {
+        left += 1;
+    }
+    let right = left + q - 1;
+    let mut h = (x - left as f64).max(right as f64 - x);
+    if span > n {
+        h += ((span - n) / 2) as f64;
+    }
+    let h = h.max(0.5);
+
+    let (mut sw, mut swx, mut swy, mut swxx, mut swxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for j in left..=right {
+        let distance = (j as f64 - x).abs() / h;
+        if distance >= 1.0 {
+            continue;
+        }
+        let w = (1.0 - distance.powi(3)).powi(3) * weights[j];
+        let xj = j as f64;
+        sw += w;
+        swx += w * xj;
+        swy += w * y[j];
+        swxx += w * xj * xj;
+        swxy += w * xj * y[j];
+    }
+    if sw <= 0.0 {
+        return None;
+    }
+    let (mean_x, mean_y) = (swx / sw, swy / sw);
+    let variance = swxx / sw - mean_x * mean_x;
+    // Fall back to the weighted mean when the points barely spread along x
+    if variance <= 1e-9 * h * h {
+        return Some(mean_y);
+    }
+    let slope = (swxy / sw - mean_x * mean_y) / variance;
+    Some(mean_y + slope * (x - mean_x))
+}
+
+fn moving_average(values: &[f64], length: usize) -> Vec<f64> {
+    let mut sum: f64 = values[..length].iter().sum();
+    let mut averages = Vec::with_capacity(values.len() + 1 - length);
+    averages.push(sum / length as f64);
+    for i in length..values.len() {
+        sum += values[i] - values[i - length];
+        averages.push(sum / length as f64);
+    }
+    averages
+}
+
+/// Bisquare weights of the residuals, scaled by six times their median
+/// absolute value
+fn robustness_weights(y: &[f64], trend: &[f64], season: &[f64]) -> Vec<f64> {
+    let residuals: Vec<f64> = (0..y.len())
+        .map(|i| (y[i] - trend[i] - season[i]).abs())
+        .collect();
+    let mut sorted = residuals.clone();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    };
+    let scale = 6.0 * median;
+    residuals
+        .iter()
+        .map(|&r| {
+            if scale <= 0.0 {
+                1.0
+            } else {
+                let u = r / scale;
+                if u < 1.0 {
+                    (1.0 - u * u).powi(2)
+                } else {
+                    0.0
+                }
+            }
+        })
+        .collect()
+}
+
+fn pair_means(pairs: &[(f64, f64)]) -> (f64, f64) {
+    let n = pairs.len() as f64;
+    let (sum_x, sum_y) = pairs
+        .iter()
+        .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+    (sum_x / n, sum_y / n)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Value;
 
     #[test]
     fn test_rolling_mean_i32() {
@@ -746,4 +1153,91 @@ mod tests {
             Series::new_string("test", vec![Some("a".to_string()), Some("b".to_string())]);
         assert!(string_series.rolling_mean(2).is_err());
     }
+
+    #[test]
+    fn test_rolling_cov_corr_with_nulls() {
+        let x = Series::new_f64("x", vec![Some(1.0), Some(2.0), None, Some(4.0), Some(5.0)]);
+        let y = Series::new_i32("y", vec![Some(3), Some(5), Some(7), None, Some(11)]);
+
+        let cov = x.rolling_cov(&y, 3).unwrap();
+        assert_eq!(cov.name(), "x_y_rolling_cov_3");
+        assert_eq!(cov.get_value(1), None);
+        // Row 2 keeps (1, 3) and (2, 5); row 3 keeps only (2, 5)
+        assert_eq!(cov.get_value(2), Some(Value::F64(1.0)));
+        assert_eq!(cov.get_value(3), None);
+
+        let corr = x.rolling_corr(&y, 4).unwrap();
+        // Row 4 keeps (2, 5) and (5, 11), which lie on a line
+        assert_eq!(corr.get_value(4), Some(Value::F64(1.0)));
+
+        let flat = Series::new_f64("flat", vec![Some(1.0); 5]);
+        assert_eq!(x.rolling_corr(&flat, 2).unwrap().get_value(1), None);
+
+        assert!(x.rolling_corr(&y, 1).is_err());
+        assert!(x.rolling_cov(&y, 6).is_err());
+        assert!(x
+            .rolling_corr(&Series::new_f64("short", vec![Some(1.0)]), 2)
+            .is_err());
+        let names = Series::new_string("s", vec![Some("a".to_string()); 5]);
+        assert!(x.rolling_cov(&names, 2).is_err());
+    }
+
+    #[test]
+    fn test_stl_decompose() {
+        let pattern = [1.0, 4.0, 2.0, -1.0, -3.0, -3.0];
+        let values: Vec<Option<f64>> = (0..60)
+            .map(|i| Some(10.0 + 0.2 * i as f64 + pattern[i % 6]))
+            .collect();
+        let series = Series::new_f64("sales", values.clone());
+        let parts = stl_decompose(&series, 6).unwrap();
+        assert_eq!(parts.trend.name(), "sales_trend");
+        assert_eq!(parts.residual.len(), 60);
+
+        for (i, value) in values.iter().enumerate() {
+            let (t, s, r) = (
+                parts.trend.get_f64(i).unwrap(),
+                parts.seasonal.get_f64(i).unwrap(),
+                parts.residual.get_f64(i).unwrap(),
+            );
+            assert!((t + s + r - value.unwrap()).abs() < 1e-9);
+        }
+        for i in 12..48 {
+            let seasonal = parts.seasonal.get_f64(i).unwrap();
+            assert!(
+                (seasonal - pattern[i % 6]).abs() < 0.1,
+                "row {i}: {seasonal}"
+            );
+            let trend = parts.trend.get_f64(i).unwrap();
+            assert!(
+                (trend - (10.0 + 0.2 * i as f64)).abs() < 0.1,
+                "row {i}: {trend}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_stl_decompose_robust_to_outliers() {
+        let pattern = [2.0, 0.0, -2.0, 0.0];
+        let mut values: Vec<Option<f64>> = (0..40).map(|i| Some(5.0 + pattern[i % 4])).collect();
+        values[21] = Some(50.0);
+        let series = Series::new_f64("load", values);
+        let options = StlOptions {
+            robust: true,
+            ..StlOptions::new(4)
+        };
+        let parts = stl_decompose_with(&series, &options).unwrap();
+        // The spike stays in the residual instead of bending the trend
+        assert!(parts.residual.get_f64(21).unwrap() > 40.0);
+        assert!((parts.trend.get_f64(10).unwrap() - 5.0).abs() < 0.1);
+
+        assert!(stl_decompose(&series, 1).is_err());
+        assert!(stl_decompose(&series, 21).is_err());
+        let with_null = Series::new_f64("gap", vec![Some(1.0), None, Some(2.0), Some(3.0)]);
+        assert!(stl_decompose(&with_null, 2).is_err());
+        let even_span = StlOptions {
+            seasonal: 8,
+            ..StlOptions::new(4)
+        };
+        assert!(stl_decompose_with(&series, &even_span).is_err());
+    }
 }
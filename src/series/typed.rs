@@ -0,0 +1,196 @@
+//! Typed access to series values without matching on the [`Series`] variants.
+//!
+//! [`ColumnType`] links each Rust type to the variant holding it:
+//!
+//! | Rust | Series |
+//! |------|--------|
+//! | `i32` | `I32` |
+//! | `f64` | `F64` |
+//! | `bool` | `Bool` |
+//! | `String` | `String` |
+//! | `i64` | `DateTime` (seconds since the Unix epoch) |
+//! | `Vec<u8>` | `Binary` |
+//!
+//! Asking for the wrong type is a [`VeloxxError::DataTypeMismatch`] error, never a
+//! conversion, and the trait is sealed, so other types cannot be asked for.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use veloxx::df;
+//!
+//! let df = df!("price" => [Some(9.5), None, Some(2.0)]).unwrap();
+//! let total: f64 = df.column_iter::<f64>("price").unwrap().flatten().sum();
+//! assert_eq!(total, 11.5);
+//! assert!(df.column::<i32>("price").is_err());
+//! ```
+
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A Rust type stored by one of the [`Series`] variants
+pub trait ColumnType: sealed::Sealed + Sized {
+    /// The data type of series holding `Self`
+    const DATA_TYPE: DataType;
+
+    /// The values and validity of `series`, if it holds `Self`
+    fn parts(series: &Series) -> Option<(&[Self], &[bool])>;
+}
+
+macro_rules! column_type {
+    ($ty:ty, $variant:ident) => {
+        impl sealed::Sealed for $ty {}
+
+        impl ColumnType for $ty {
+            const DATA_TYPE: DataType = DataType::$variant;
+
+            fn parts(series: &Series) -> Option<(&[Self], &[bool])> {
+                match series {
+                    Series::$variant(_, values, validity) => Some((values, validity)),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+column_type!(i32, I32);
+column_type!(f64, F64);
+column_type!(bool, Bool);
+column_type!(String, String);
+column_type!(i64, DateTime);
+column_type!(Vec<u8>, Binary);
+
+/// Iterator over the values of a series as `Option<&T>`, `None` for nulls, from
+/// [`Series::iter_as`] or [`DataFrame::column_iter`](crate::dataframe::DataFrame::column_iter)
+#[derive(Debug, Clone)]
+pub struct ChunkedIterator<'a, T> {
+    values: std::slice::Iter<'a, T>,
+    validity: std::slice::Iter<'a, bool>,
+}
+
+impl<'a, T> Iterator for ChunkedIterator<'a, T> {
+    type Item = Option<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.next()?;
+        let valid = *self.validity.next()?;
+        Some(valid.then_some(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for ChunkedIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = self.values.next_back()?;
+        let valid = *self.validity.next_back()?;
+        Some(valid.then_some(value))
+    }
+}
+
+impl<T> ExactSizeIterator for ChunkedIterator<'_, T> {}
+
+impl Series {
+    /// The values as a slice of `T`, including the placeholders stored in null
+    /// slots; pair them with [`Series::validity`], or use [`Series::iter_as`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    ///
+    /// let prices = Series::new_f64("price", vec![Some(9.5), Some(2.0)]);
+    /// assert_eq!(prices.values::<f64>().unwrap(), &[9.5, 2.0]);
+    /// assert!(prices.values::<i32>().is_err());
+    /// ```
+    pub fn values<T: ColumnType>(&self) -> Result<&[T], VeloxxError> {
+        self.parts::<T>().map(|(values, _)| values)
+    }
+
+    /// Whether each value is present (`true`) or null (`false`).
+    pub fn validity(&self) -> &[bool] {
+        match self {
+            Series::I32(_, _, validity)
+            | Series::F64(_, _, validity)
+            | Series::Bool(_, _, validity)
+            | Series::String(_, _, validity)
+            | Series::DateTime(_, _, validity)
+            | Series::Binary(_, _, validity) => validity,
+        }
+    }
+
+    /// Iterates over the values as `Option<&T>`, with `None` for nulls.
+    pub fn iter_as<T: ColumnType>(&self) -> Result<ChunkedIterator<'_, T>, VeloxxError> {
+        let (values, validity) = self.parts::<T>()?;
+        Ok(ChunkedIterator {
+            values: values.iter(),
+            validity: validity.iter(),
+        })
+    }
+
+    /// Iterates over the values, with `None` for nulls; see [`Series::iter_as`]
+    /// and the typed `iter_*` methods to avoid building a [`Value`] per row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let ids = Series::new_i32("id", vec![Some(1), None, Some(3)]);
+    /// let present = ids.iter().flatten().collect::<Vec<_>>();
+    /// assert_eq!(present, vec![Value::I32(1), Value::I32(3)]);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Option<Value>> + ExactSizeIterator + '_ {
+        (0..self.len()).map(|i| self.get_value(i))
+    }
+
+    /// Iterates over an `I32` series.
+    pub fn iter_i32(&self) -> Result<impl Iterator<Item = Option<i32>> + '_, VeloxxError> {
+        Ok(self.iter_as::<i32>()?.map(|v| v.copied()))
+    }
+
+    /// Iterates over an `F64` series.
+    pub fn iter_f64(&self) -> Result<impl Iterator<Item = Option<f64>> + '_, VeloxxError> {
+        Ok(self.iter_as::<f64>()?.map(|v| v.copied()))
+    }
+
+    /// Iterates over a `Bool` series.
+    pub fn iter_bool(&self) -> Result<impl Iterator<Item = Option<bool>> + '_, VeloxxError> {
+        Ok(self.iter_as::<bool>()?.map(|v| v.copied()))
+    }
+
+    /// Iterates over a `String` series without copying the strings.
+    pub fn iter_str(&self) -> Result<impl Iterator<Item = Option<&str>> + '_, VeloxxError> {
+        Ok(self.iter_as::<String>()?.map(|v| v.map(String::as_str)))
+    }
+
+    /// Iterates over a `DateTime` series as seconds since the Unix epoch.
+    pub fn iter_datetime(&self) -> Result<impl Iterator<Item = Option<i64>> + '_, VeloxxError> {
+        Ok(self.iter_as::<i64>()?.map(|v| v.copied()))
+    }
+
+    /// Iterates over a `Binary` series without copying the bytes.
+    pub fn iter_binary(&self) -> Result<impl Iterator<Item = Option<&[u8]>> + '_, VeloxxError> {
+        Ok(self.iter_as::<Vec<u8>>()?.map(|v| v.map(Vec::as_slice)))
+    }
+
+    fn parts<T: ColumnType>(&self) -> Result<(&[T], &[bool]), VeloxxError> {
+        T::parts(self).ok_or_else(|| {
+            VeloxxError::DataTypeMismatch(format!(
+                "Column '{}' is {:?}, not {:?}",
+                self.name(),
+                self.data_type(),
+                T::DATA_TYPE
+            ))
+        })
+    }
+}
@@ -0,0 +1,184 @@
+//! Helpers for web-log analysis over `String` columns of URLs and user agents.
+//!
+//! Parsing is deliberately lenient: values that are not URLs, or lack the
+//! requested part, become null instead of failing the whole column. Request
+//! targets such as `/search?q=rust` are accepted as well as absolute URLs.
+
+use crate::series::Series;
+use crate::VeloxxError;
+
+/// Browser families recognized by [`Series::user_agent_family`], checked in
+/// order because most user agents also name the engines they imitate
+/// (Chrome's mentions Safari, Edge's mentions Chrome).
+const USER_AGENT_FAMILIES: &[(&str, &str)] = &[
+    ("bot", "Bot"),
+    ("spider", "Bot"),
+    ("crawl", "Bot"),
+    ("edg/", "Edge"),
+    ("edge/", "Edge"),
+    ("opr/", "Opera"),
+    ("opera", "Opera"),
+    ("samsungbrowser", "Samsung Internet"),
+    ("firefox/", "Firefox"),
+    ("fxios", "Firefox"),
+    ("chrome/", "Chrome"),
+    ("crios", "Chrome"),
+    ("chromium", "Chrome"),
+    ("msie ", "Internet Explorer"),
+    ("trident/", "Internet Explorer"),
+    ("safari/", "Safari"),
+    ("curl/", "curl"),
+    ("wget/", "Wget"),
+    ("python-requests", "Python Requests"),
+];
+
+impl Series {
+    /// Returns the lowercased host of each URL, without port or credentials.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let urls = Series::new_string(
+    ///     "url",
+    ///     vec![Some("https://user@Example.com:8080/a?b=1".to_string()), Some("/index".to_string())],
+    /// );
+    /// let hosts = urls.url_host().unwrap();
+    /// assert_eq!(hosts.get_value(0), Some(Value::String("example.com".to_string())));
+    /// assert_eq!(hosts.get_value(1), None);
+    /// ```
+    pub fn url_host(&self) -> Result<Series, VeloxxError> {
+        self.extract_strings("url_host", |url| {
+            let authority = split_url(url).0?;
+            let host = authority
+                .rsplit_once('@')
+                .map_or(authority, |(_, host)| host);
+            let host = match host.strip_prefix('[') {
+                // IPv6 literal, whose colons are not a port separator
+                Some(rest) => &host[..rest.find(']')? + 2],
+                None => host.split(':').next().unwrap_or(host),
+            };
+            (!host.is_empty()).then(|| host.to_ascii_lowercase())
+        })
+    }
+
+    /// Returns the path of each URL or request target, without query string
+    /// or fragment; a URL with a host but no path gives `"/"`.
+    pub fn url_path(&self) -> Result<Series, VeloxxError> {
+        self.extract_strings("url_path", |url| match split_url(url) {
+            (Some(_), "") => Some("/".to_string()),
+            (None, path) if !path.starts_with('/') => None,
+            (_, path) => Some(path.to_string()),
+        })
+    }
+
+    /// Returns the percent-decoded value of the first `key` parameter in each
+    /// URL's query string, or null when it is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::series::Series;
+    /// use veloxx::types::Value;
+    ///
+    /// let urls = Series::new_string("url", vec![Some("/search?q=data+frames%21&page=2".to_string())]);
+    /// let query = urls.url_query_param("q").unwrap();
+    /// assert_eq!(query.get_value(0), Some(Value::String("data frames!".to_string())));
+    /// ```
+    pub fn url_query_param(&self, key: &str) -> Result<Series, VeloxxError> {
+        self.extract_strings("url_query_param", |url| {
+            let query = url.split('#').next()?.split_once('?')?.1;
+            query.split('&').find_map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (percent_decode(name) == key).then(|| percent_decode(value))
+            })
+        })
+    }
+
+    /// Classifies each user-agent string into a browser family such as
+    /// `"Chrome"`, `"Firefox"`, `"Safari"` or `"Bot"`; unrecognized agents
+    /// give `"Other"`.
+    ///
+    /// This is a keyword match for quick breakdowns, not a full user-agent
+    /// parser; versions and operating systems are not extracted.
+    pub fn user_agent_family(&self) -> Result<Series, VeloxxError> {
+        self.extract_strings("user_agent_family", |agent| {
+            let agent = agent.to_ascii_lowercase();
+            let family = USER_AGENT_FAMILIES
+                .iter()
+                .find(|(keyword, _)| agent.contains(keyword))
+                .map_or("Other", |(_, family)| family);
+            Some(family.to_string())
+        })
+    }
+
+    fn extract_strings(
+        &self,
+        op: &str,
+        f: impl Fn(&str) -> Option<String>,
+    ) -> Result<Series, VeloxxError> {
+        match self {
+            Series::String(name, values, bitmap) => Ok(Series::new_string(
+                name,
+                values
+                    .iter()
+                    .zip(bitmap)
+                    .map(|(v, &valid)| if valid { f(v) } else { None })
+                    .collect(),
+            )),
+            _ => Err(VeloxxError::DataTypeMismatch(format!(
+                "{op} requires a String series, got {:?}",
+                self.data_type()
+            ))),
+        }
+    }
+}
+
+/// Splits a URL into its authority (if any) and its path.
+fn split_url(url: &str) -> (Option<&str>, &str) {
+    let url = url.trim();
+    let url = url.split(['?', '#']).next().unwrap_or(url);
+    let rest = match url.split_once("://") {
+        Some((scheme, rest)) if !scheme.is_empty() && !scheme.contains('/') => rest,
+        _ => match url.strip_prefix("//") {
+            Some(rest) => rest,
+            None => return (None, url),
+        },
+    };
+    match rest.find('/') {
+        Some(slash) => (Some(&rest[..slash]), &rest[slash..]),
+        None => (Some(rest), ""),
+    }
+}
+
+/// Decodes `%XX` escapes and `+` as a space; malformed escapes are kept as-is.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len()
+                && bytes[i + 1].is_ascii_hexdigit()
+                && bytes[i + 2].is_ascii_hexdigit() =>
+            {
+                decoded.push((hex_value(bytes[i + 1]) << 4) | hex_value(bytes[i + 2]));
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    }
+}
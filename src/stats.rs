@@ -0,0 +1,171 @@
+//! Resampling statistics: bootstrap distributions and permutation tests.
+//!
+//! Both run their iterations in parallel with rayon. Iteration `i` draws from
+//! its own generator seeded with `seed + i`, so results depend only on the
+//! seed, never on how the work is scheduled across threads.
+
+use crate::dataframe::DataFrame;
+use crate::VeloxxError;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+
+/// The outcome of a [`permutation_test`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermutationTest {
+    /// The statistic on the frame as given
+    pub observed: f64,
+    /// The statistic on each permuted frame, in iteration order
+    pub null_distribution: Vec<f64>,
+    /// Two-sided p-value: the share of permuted statistics at least as far
+    /// from zero as `observed`, counting the observed one itself
+    pub p_value: f64,
+}
+
+fn iteration_rng(seed: u64, iteration: usize) -> StdRng {
+    StdRng::seed_from_u64(seed.wrapping_add(iteration as u64))
+}
+
+/// Computes `statistic` on `n_iter` resamples of `df`, each drawing
+/// `df.row_count()` rows with replacement, and returns the values in
+/// iteration order.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::df;
+/// use veloxx::stats;
+/// use veloxx::types::Value;
+///
+/// let latencies = df!("ms" => [12.0, 15.0, 11.0, 40.0, 13.0]).unwrap();
+/// let means = stats::bootstrap(
+///     &latencies,
+///     |sample| match sample.get_column("ms").unwrap().mean()? {
+///         Value::F64(mean) => Ok(mean),
+///         _ => unreachable!(),
+///     },
+///     1000,
+///     42,
+/// )
+/// .unwrap();
+/// let (low, high) = stats::percentile_interval(&means, 0.95).unwrap();
+/// assert!(low <= 18.2 && 18.2 <= high);
+/// ```
+pub fn bootstrap<F>(
+    df: &DataFrame,
+    statistic_fn: F,
+    n_iter: usize,
+    seed: u64,
+) -> Result<Vec<f64>, VeloxxError>
+where
+    F: Fn(&DataFrame) -> Result<f64, VeloxxError> + Sync,
+{
+    let rows = df.row_count();
+    if rows == 0 {
+        return Err(VeloxxError::InvalidOperation(
+            "Cannot bootstrap an empty DataFrame".to_string(),
+        ));
+    }
+    (0..n_iter)
+        .into_par_iter()
+        .map(|iteration| {
+            let mut rng = iteration_rng(seed, iteration);
+            let indices: Vec<usize> = (0..rows).map(|_| rng.gen_range(0..rows)).collect();
+            statistic_fn(&df.filter_by_indices(&indices)?)
+        })
+        .collect()
+}
+
+/// Tests whether `statistic` depends on the pairing between `column` and the
+/// rest of the frame by recomputing it `n_iter` times with `column` shuffled.
+///
+/// The statistic should be zero when there is no effect, e.g. a difference
+/// in group means with `column` holding the group labels.
+///
+/// # Examples
+///
+/// ```rust
+/// use veloxx::df;
+/// use veloxx::stats;
+///
+/// let trial = df!(
+///     "group" => ["a", "a", "a", "a", "a", "b", "b", "b", "b", "b"],
+///     "score" => [1.0, 2.0, 3.0, 4.0, 5.0, 11.0, 12.0, 13.0, 14.0, 15.0],
+/// )
+/// .unwrap();
+/// let mean_difference = |frame: &veloxx::DataFrame| {
+///     let mut sums = [0.0; 2];
+///     for row in 0..frame.row_count() {
+///         let group = frame.get_column("group").unwrap().get_value(row);
+///         let score = frame.get_column("score").unwrap().get_value(row);
+///         let (Some(veloxx::Value::String(g)), Some(veloxx::Value::F64(s))) = (group, score) else {
+///             unreachable!()
+///         };
+///         sums[usize::from(g == "b")] += s;
+///     }
+///     Ok((sums[1] - sums[0]) / 5.0)
+/// };
+/// let result = stats::permutation_test(&trial, "group", mean_difference, 500, 7).unwrap();
+/// assert_eq!(result.observed, 10.0);
+/// assert!(result.p_value < 0.1);
+/// ```
+pub fn permutation_test<F>(
+    df: &DataFrame,
+    column: &str,
+    statistic_fn: F,
+    n_iter: usize,
+    seed: u64,
+) -> Result<PermutationTest, VeloxxError>
+where
+    F: Fn(&DataFrame) -> Result<f64, VeloxxError> + Sync,
+{
+    let series = df
+        .get_column(column)
+        .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+    let observed = statistic_fn(df)?;
+    let null_distribution = (0..n_iter)
+        .into_par_iter()
+        .map(|iteration| {
+            let mut order: Vec<usize> = (0..df.row_count()).collect();
+            order.shuffle(&mut iteration_rng(seed, iteration));
+            let mut columns = df.columns.clone();
+            columns.insert(column.to_string(), series.filter(&order)?);
+            statistic_fn(&DataFrame::new(columns)?)
+        })
+        .collect::<Result<Vec<f64>, VeloxxError>>()?;
+    let extreme = null_distribution
+        .iter()
+        .filter(|v| v.abs() >= observed.abs())
+        .count();
+    Ok(PermutationTest {
+        observed,
+        p_value: (extreme + 1) as f64 / (n_iter + 1) as f64,
+        null_distribution,
+    })
+}
+
+/// The central `level` interval of `samples`, e.g. a 95% bootstrap confidence
+/// interval for `level = 0.95`, using linearly interpolated percentiles.
+/// Non-finite samples are ignored.
+pub fn percentile_interval(samples: &[f64], level: f64) -> Result<(f64, f64), VeloxxError> {
+    if !(level > 0.0 && level < 1.0) {
+        return Err(VeloxxError::InvalidOperation(format!(
+            "Interval level must be between 0 and 1, got {level}"
+        )));
+    }
+    let mut sorted: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    if sorted.is_empty() {
+        return Err(VeloxxError::InvalidOperation(
+            "Cannot compute an interval without finite samples".to_string(),
+        ));
+    }
+    sorted.sort_by(f64::total_cmp);
+    let percentile = |q: f64| {
+        let position = q * (sorted.len() - 1) as f64;
+        let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+    };
+    let tail = (1.0 - level) / 2.0;
+    Ok((percentile(tail), percentile(1.0 - tail)))
+}
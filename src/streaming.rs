@@ -0,0 +1,308 @@
+//! Incremental aggregation over streaming data.
+//!
+//! [`IncrementalGroupBy`] keeps running sum/count/min/max/mean state per group, so
+//! each new batch costs time proportional to the batch rather than to everything
+//! seen so far.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use veloxx::dataframe::DataFrame;
+//! use veloxx::series::Series;
+//! use veloxx::streaming::IncrementalGroupBy;
+//! use veloxx::types::Value;
+//!
+//! let batch = |cities: &[&str], sales: &[f64]| {
+//!     let mut columns = HashMap::new();
+//!     columns.insert(
+//!         "city".to_string(),
+//!         Series::new_string("city", cities.iter().map(|c| Some(c.to_string())).collect()),
+//!     );
+//!     columns.insert(
+//!         "sales".to_string(),
+//!         Series::new_f64("sales", sales.iter().map(|s| Some(*s)).collect()),
+//!     );
+//!     DataFrame::new(columns).unwrap()
+//! };
+//!
+//! let mut totals =
+//!     IncrementalGroupBy::new(vec!["city".to_string()], vec![("sales", "sum"), ("sales", "count")])
+//!         .unwrap();
+//! totals.update(&batch(&["Paris", "Oslo"], &[10.0, 5.0])).unwrap();
+//! totals.update(&batch(&["Paris"], &[2.5])).unwrap();
+//!
+//! let snapshot = totals.snapshot().unwrap();
+//! assert_eq!(snapshot.row_count(), 2);
+//! assert_eq!(snapshot.get_column("sales_sum").unwrap().get_value(0), Some(Value::F64(12.5)));
+//! assert_eq!(snapshot.get_column("sales_count").unwrap().get_value(0), Some(Value::I32(2)));
+//! ```
+
+use crate::dataframe::DataFrame;
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use crate::VeloxxError;
+use std::collections::HashMap;
+
+const AGGREGATIONS: &[&str] = &["sum", "count", "min", "max", "mean"];
+
+/// Running state of one value column within one group
+#[derive(Debug, Clone, Default)]
+struct ColumnState {
+    count: usize,
+    sum: f64,
+    int_sum: i64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl ColumnState {
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.int_sum = self.int_sum.wrapping_add(value as i64);
+        self.min = Some(self.min.map_or(value, |m| m.min(value)));
+        self.max = Some(self.max.map_or(value, |m| m.max(value)));
+    }
+}
+
+/// Group-by aggregation whose state is updated batch by batch.
+///
+/// Output columns are named `{column}_{aggregation}` like
+/// [`GroupedDataFrame::agg`](crate::dataframe::group_by::GroupedDataFrame::agg), and
+/// groups appear in order of first appearance. `sum`, `min` and `max` keep the
+/// column's type (`I32` sums saturate at the `i32` range), `mean` is `F64` and
+/// `count` is the number of non-null values as `I32`. Groups without non-null
+/// values report a sum of 0 and null min, max and mean. Null keys form their own
+/// group.
+#[derive(Debug, Clone)]
+pub struct IncrementalGroupBy {
+    group_columns: Vec<String>,
+    aggregations: Vec<(String, String)>,
+    /// Distinct value columns, in order of first use
+    value_columns: Vec<String>,
+    key_types: Vec<Option<DataType>>,
+    value_types: Vec<Option<DataType>>,
+    lookup: HashMap<Vec<Value>, usize>,
+    keys: Vec<Vec<Value>>,
+    states: Vec<Vec<ColumnState>>,
+}
+
+impl IncrementalGroupBy {
+    /// Creates an empty aggregation grouped by `group_columns`, computing each
+    /// `(column, aggregation)` pair; aggregations are `sum`, `count`, `min`, `max`
+    /// and `mean`.
+    pub fn new(
+        group_columns: Vec<String>,
+        aggregations: Vec<(&str, &str)>,
+    ) -> Result<Self, VeloxxError> {
+        if group_columns.is_empty() {
+            return Err(VeloxxError::InvalidOperation(
+                "At least one group column is required".to_string(),
+            ));
+        }
+        let mut value_columns: Vec<String> = Vec::new();
+        for (column, aggregation) in &aggregations {
+            if !AGGREGATIONS.contains(aggregation) {
+                return Err(VeloxxError::Unsupported(format!(
+                    "Unsupported incremental aggregation '{}'",
+                    aggregation
+                )));
+            }
+            if !value_columns.iter().any(|c| c == column) {
+                value_columns.push(column.to_string());
+            }
+        }
+        Ok(Self {
+            key_types: vec![None; group_columns.len()],
+            value_types: vec![None; value_columns.len()],
+            group_columns,
+            aggregations: aggregations
+                .into_iter()
+                .map(|(column, aggregation)| (column.to_string(), aggregation.to_string()))
+                .collect(),
+            value_columns,
+            lookup: HashMap::new(),
+            keys: Vec::new(),
+            states: Vec::new(),
+        })
+    }
+
+    /// Number of groups seen so far
+    pub fn group_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Folds the rows of `batch` into the running state.
+    ///
+    /// The batch is validated first, so on error the state is unchanged.
+    pub fn update(&mut self, batch: &DataFrame) -> Result<(), VeloxxError> {
+        let key_series = checked_columns(batch, &self.group_columns, &self.key_types, false)?;
+        let value_series = checked_columns(batch, &self.value_columns, &self.value_types, true)?;
+        let numeric_only: Vec<bool> = self
+            .value_columns
+            .iter()
+            .map(|column| {
+                self.aggregations
+                    .iter()
+                    .any(|(c, aggregation)| c == column && aggregation != "count")
+            })
+            .collect();
+        for ((series, numeric), column) in value_series
+            .iter()
+            .zip(&numeric_only)
+            .zip(&self.value_columns)
+        {
+            if *numeric && !matches!(series.data_type(), DataType::I32 | DataType::F64) {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Cannot aggregate non-numeric column '{}'",
+                    column
+                )));
+            }
+        }
+
+        for (slot, series) in self.key_types.iter_mut().zip(&key_series) {
+            slot.get_or_insert(series.data_type());
+        }
+        for (slot, series) in self.value_types.iter_mut().zip(&value_series) {
+            // I32 columns are widened once an F64 batch arrives
+            if slot.is_none() || series.data_type() == DataType::F64 {
+                *slot = Some(series.data_type());
+            }
+        }
+
+        for row in 0..batch.row_count() {
+            let key: Vec<Value> = key_series
+                .iter()
+                .map(|series| series.get_value(row).unwrap_or(Value::Null))
+                .collect();
+            let group = match self.lookup.get(&key) {
+                Some(&group) => group,
+                None => {
+                    self.keys.push(key.clone());
+                    self.states
+                        .push(vec![ColumnState::default(); self.value_columns.len()]);
+                    self.lookup.insert(key, self.keys.len() - 1);
+                    self.keys.len() - 1
+                }
+            };
+            for (state, series) in self.states[group].iter_mut().zip(&value_series) {
+                match series.get_value(row) {
+                    Some(Value::I32(v)) => state.add(v as f64),
+                    Some(Value::F64(v)) => state.add(v),
+                    Some(Value::Null) | None => {}
+                    Some(_) => state.count += 1,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a `DataFrame` with one row per group from the current state.
+    pub fn snapshot(&self) -> Result<DataFrame, VeloxxError> {
+        let mut columns = HashMap::new();
+        for (i, name) in self.group_columns.iter().enumerate() {
+            let values = self.keys.iter().map(|key| &key[i]);
+            let series = match self.key_types[i].clone().unwrap_or(DataType::String) {
+                DataType::I32 => Series::new_i32(name, values.map(|v| v.as_i32()).collect()),
+                DataType::F64 => Series::new_f64(name, values.map(|v| v.as_f64()).collect()),
+                DataType::Bool => Series::new_bool(name, values.map(|v| v.as_bool()).collect()),
+                DataType::String => {
+                    Series::new_string(name, values.map(|v| v.as_string().cloned()).collect())
+                }
+                DataType::DateTime => {
+                    Series::new_datetime(name, values.map(|v| v.as_datetime()).collect())
+                }
+                DataType::Binary => Series::new_binary(
+                    name,
+                    values.map(|v| v.as_binary().map(<[u8]>::to_vec)).collect(),
+                ),
+            };
+            columns.insert(name.clone(), series);
+        }
+
+        for (column, aggregation) in &self.aggregations {
+            let index = self
+                .value_columns
+                .iter()
+                .position(|c| c == column)
+                .expect("value columns cover every aggregation");
+            let is_int = self.value_types[index] == Some(DataType::I32);
+            let states = self.states.iter().map(|group| &group[index]);
+            let name = format!("{}_{}", column, aggregation);
+            let series = match aggregation.as_str() {
+                "count" => Series::new_i32(&name, states.map(|s| Some(s.count as i32)).collect()),
+                "mean" => Series::new_f64(
+                    &name,
+                    states
+                        .map(|s| (s.count > 0).then(|| s.sum / s.count as f64))
+                        .collect(),
+                ),
+                "sum" if is_int => Series::new_i32(
+                    &name,
+                    states
+                        .map(|s| Some(s.int_sum.clamp(i32::MIN as i64, i32::MAX as i64) as i32))
+                        .collect(),
+                ),
+                "sum" => Series::new_f64(&name, states.map(|s| Some(s.sum)).collect()),
+                _ => {
+                    let extreme =
+                        |s: &ColumnState| if aggregation == "min" { s.min } else { s.max };
+                    if is_int {
+                        Series::new_i32(
+                            &name,
+                            states.map(|s| extreme(s).map(|v| v as i32)).collect(),
+                        )
+                    } else {
+                        Series::new_f64(&name, states.map(extreme).collect())
+                    }
+                }
+            };
+            columns.insert(name, series);
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Discards all groups, keeping the configuration.
+    pub fn reset(&mut self) {
+        self.lookup.clear();
+        self.keys.clear();
+        self.states.clear();
+    }
+}
+
+/// Looks up `names` in `batch`, checking they keep the types seen in earlier batches;
+/// `I32` and `F64` are interchangeable when `numeric` is set.
+fn checked_columns<'a>(
+    batch: &'a DataFrame,
+    names: &[String],
+    types: &[Option<DataType>],
+    numeric: bool,
+) -> Result<Vec<&'a Series>, VeloxxError> {
+    names
+        .iter()
+        .zip(types)
+        .map(|(name, expected)| {
+            let series = batch
+                .get_column(name)
+                .ok_or_else(|| VeloxxError::ColumnNotFound(name.clone()))?;
+            let actual = series.data_type();
+            let compatible = match expected {
+                None => true,
+                Some(DataType::I32 | DataType::F64) if numeric => {
+                    matches!(actual, DataType::I32 | DataType::F64)
+                }
+                Some(expected) => *expected == actual,
+            };
+            if !compatible {
+                return Err(VeloxxError::DataTypeMismatch(format!(
+                    "Column '{}' changed type from {:?} to {:?}",
+                    name,
+                    expected.as_ref().unwrap(),
+                    actual
+                )));
+            }
+            Ok(series)
+        })
+        .collect()
+}
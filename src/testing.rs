@@ -0,0 +1,347 @@
+//! Property-based testing support (feature `testing`).
+//!
+//! [`Series`] and [`DataFrame`] implement proptest's [`Arbitrary`], and the
+//! `check_*` functions run one of the optimized kernels and a naive reference
+//! implementation on the same input, failing the proptest case when they
+//! disagree. Together they turn the fast paths in [`crate::performance`] into
+//! differential tests:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use veloxx::testing::{self, i32_series};
+//!
+//! proptest! {
+//!     // `#[test]` in a test crate
+//!     fn join_matches_reference(left in i32_series("id", 0..40), right in i32_series("id", 0..40)) {
+//!         testing::check_ultra_fast_join(&left, &right)?;
+//!     }
+//! }
+//! # join_matches_reference();
+//! ```
+//!
+//! Generated values are chosen to exercise kernels rather than number
+//! formats: integers and timestamps come from small ranges so keys repeat,
+//! floats are multiples of 0.5 (so sums are exact whatever the summation
+//! order, and never NaN), strings use a three-letter alphabet and binary
+//! values are up to four arbitrary bytes. About one value in eight is null.
+
+use crate::dataframe::DataFrame;
+use crate::performance::fast_groupby::FastGroupBy;
+use crate::performance::ultra_fast_join::UltraFastJoin;
+use crate::performance::vectorized_filter::{ComparisonOp, VectorizedFilter};
+use crate::series::Series;
+use crate::types::{DataType, Value};
+use proptest::arbitrary::Arbitrary;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+
+/// Longest series generated by [`Arbitrary`]
+pub const MAX_ARBITRARY_LEN: usize = 64;
+
+/// Most columns in an arbitrary `DataFrame`
+pub const MAX_ARBITRARY_COLUMNS: usize = 4;
+
+fn nullable<T: std::fmt::Debug + Clone>(
+    values: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Option<T>> {
+    prop_oneof![1 => Just(None), 7 => values.prop_map(Some)]
+}
+
+fn f64_values() -> impl Strategy<Value = f64> {
+    (-200i32..200).prop_map(|half| half as f64 / 2.0)
+}
+
+/// A strategy for the data types [`series`] can generate.
+pub fn data_types() -> impl Strategy<Value = DataType> {
+    prop_oneof![
+        Just(DataType::I32),
+        Just(DataType::F64),
+        Just(DataType::Bool),
+        Just(DataType::String),
+        Just(DataType::DateTime),
+        Just(DataType::Binary),
+    ]
+}
+
+/// A strategy for series named `name` of `data_type` with exactly `len` values.
+pub fn series(name: &str, data_type: DataType, len: usize) -> BoxedStrategy<Series> {
+    let name = name.to_string();
+    match data_type {
+        DataType::I32 => prop::collection::vec(nullable(-20i32..20), len)
+            .prop_map(move |v| Series::new_i32(&name, v))
+            .boxed(),
+        DataType::F64 => prop::collection::vec(nullable(f64_values()), len)
+            .prop_map(move |v| Series::new_f64(&name, v))
+            .boxed(),
+        DataType::Bool => prop::collection::vec(nullable(any::<bool>()), len)
+            .prop_map(move |v| Series::new_bool(&name, v))
+            .boxed(),
+        DataType::String => prop::collection::vec(nullable("[abc]{0,3}"), len)
+            .prop_map(move |v| Series::new_string(&name, v))
+            .boxed(),
+        DataType::DateTime => prop::collection::vec(nullable(0i64..10 * 86_400), len)
+            .prop_map(move |v| Series::new_datetime(&name, v))
+            .boxed(),
+        DataType::Binary => {
+            prop::collection::vec(nullable(prop::collection::vec(any::<u8>(), 0..=4)), len)
+                .prop_map(move |v| Series::new_binary(&name, v))
+                .boxed()
+        }
+    }
+}
+
+/// A strategy for `I32` series named `name` whose length is drawn from `len`.
+pub fn i32_series(name: &str, len: Range<usize>) -> BoxedStrategy<Series> {
+    let name = name.to_string();
+    len.prop_flat_map(move |len| series(&name, DataType::I32, len))
+        .boxed()
+}
+
+/// A strategy for `I32` keys and `F64` values of equal length drawn from
+/// `len`, with keys spread over `key_range`.
+pub fn key_value_series(
+    len: Range<usize>,
+    key_range: Range<i32>,
+) -> BoxedStrategy<(Series, Series)> {
+    len.prop_flat_map(move |len| {
+        (
+            prop::collection::vec(nullable(key_range.clone()), len)
+                .prop_map(|v| Series::new_i32("key", v)),
+            series("value", DataType::F64, len),
+        )
+    })
+    .boxed()
+}
+
+/// A strategy for the comparison operators of the vectorized filters.
+pub fn comparison_ops() -> impl Strategy<Value = ComparisonOp> {
+    prop_oneof![
+        Just(ComparisonOp::Gt),
+        Just(ComparisonOp::Gte),
+        Just(ComparisonOp::Lt),
+        Just(ComparisonOp::Lte),
+        Just(ComparisonOp::Eq),
+        Just(ComparisonOp::Ne),
+    ]
+}
+
+impl Arbitrary for Series {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Series>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (data_types(), 0..=MAX_ARBITRARY_LEN)
+            .prop_flat_map(|(data_type, len)| series("series", data_type, len))
+            .boxed()
+    }
+}
+
+impl Arbitrary for DataFrame {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<DataFrame>;
+
+    /// Frames of 1 to [`MAX_ARBITRARY_COLUMNS`] columns named `c0`, `c1`, ...
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (
+            prop::collection::vec(data_types(), 1..=MAX_ARBITRARY_COLUMNS),
+            0..=MAX_ARBITRARY_LEN,
+        )
+            .prop_flat_map(|(types, len)| {
+                types
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, data_type)| series(&format!("c{i}"), data_type, len))
+                    .collect::<Vec<_>>()
+            })
+            .prop_map(|columns| {
+                DataFrame::new(
+                    columns
+                        .into_iter()
+                        .map(|series| (series.name().to_string(), series))
+                        .collect(),
+                )
+                .expect("generated columns have equal lengths")
+            })
+            .boxed()
+    }
+}
+
+fn fail(message: String) -> TestCaseError {
+    TestCaseError::fail(message)
+}
+
+/// Checks that two frames hold the same columns with the same values, in the
+/// same row order when `ordered`, or as the same multiset of rows otherwise.
+pub fn assert_frames_equal(
+    actual: &DataFrame,
+    expected: &DataFrame,
+    ordered: bool,
+) -> Result<(), TestCaseError> {
+    let mut names: Vec<&String> = actual.column_names();
+    let mut expected_names: Vec<&String> = expected.column_names();
+    names.sort();
+    expected_names.sort();
+    if names != expected_names {
+        return Err(fail(format!(
+            "columns differ: {names:?} vs expected {expected_names:?}"
+        )));
+    }
+    let rows = |df: &DataFrame| -> Vec<Vec<Option<Value>>> {
+        (0..df.row_count())
+            .map(|row| {
+                names
+                    .iter()
+                    .map(|name| df.get_column(name).and_then(|s| s.get_value(row)))
+                    .collect()
+            })
+            .collect()
+    };
+    let (mut actual_rows, mut expected_rows) = (rows(actual), rows(expected));
+    if !ordered {
+        actual_rows.sort();
+        expected_rows.sort();
+    }
+    if actual_rows != expected_rows {
+        return Err(fail(format!(
+            "rows differ for columns {names:?}:\n  actual:   {actual_rows:?}\n  expected: {expected_rows:?}"
+        )));
+    }
+    Ok(())
+}
+
+fn i32_parts(series: &Series) -> Result<(&[i32], &[bool]), TestCaseError> {
+    match series {
+        Series::I32(_, values, validity) => Ok((values, validity)),
+        other => Err(fail(format!(
+            "expected an I32 series, got {:?}",
+            other.data_type()
+        ))),
+    }
+}
+
+/// Compares [`FastGroupBy::simd_groupby_i32_sum`] with a sequential sum per
+/// key over the rows whose key and value are both non-null.
+pub fn check_fast_groupby(keys: &Series, values: &Series) -> Result<(), TestCaseError> {
+    let (key_values, key_valid) = i32_parts(keys)?;
+    let (Series::F64(_, value_values, value_valid), true) = (values, values.len() == keys.len())
+    else {
+        return Err(fail(
+            "expected an F64 series as long as the keys".to_string(),
+        ));
+    };
+    let mut expected: BTreeMap<i32, f64> = BTreeMap::new();
+    for row in 0..keys.len() {
+        if key_valid[row] && value_valid[row] {
+            *expected.entry(key_values[row]).or_default() += value_values[row];
+        }
+    }
+
+    let result = FastGroupBy::simd_groupby_i32_sum(
+        key_values,
+        key_valid,
+        value_values,
+        value_valid,
+        "key",
+        "sum",
+    )
+    .map_err(|e| fail(e.to_string()))?;
+    let expected = DataFrame::new(HashMap::from([
+        (
+            "key".to_string(),
+            Series::new_i32("key", expected.keys().map(|&k| Some(k)).collect()),
+        ),
+        (
+            "sum".to_string(),
+            Series::new_f64("sum", expected.values().map(|&s| Some(s)).collect()),
+        ),
+    ]))
+    .map_err(|e| fail(e.to_string()))?;
+    // The hash path returns groups in hash order
+    assert_frames_equal(&result, &expected, false)
+}
+
+fn reference_compare(value: &Value, threshold: &Value, op: ComparisonOp) -> bool {
+    let Some(ordering) = value.partial_cmp(threshold) else {
+        return false;
+    };
+    match op {
+        ComparisonOp::Gt => ordering.is_gt(),
+        ComparisonOp::Gte => ordering.is_ge(),
+        ComparisonOp::Lt => ordering.is_lt(),
+        ComparisonOp::Lte => ordering.is_le(),
+        ComparisonOp::Eq => ordering.is_eq(),
+        ComparisonOp::Ne => ordering.is_ne(),
+    }
+}
+
+/// Compares [`VectorizedFilter::fast_filter_single_column`] followed by
+/// [`VectorizedFilter::filter_series_with_mask`] with a row-by-row comparison
+/// of each value against `threshold`, where nulls never match.
+pub fn check_vectorized_filter(
+    series: &Series,
+    threshold: &Value,
+    op: ComparisonOp,
+) -> Result<(), TestCaseError> {
+    let mask = VectorizedFilter::fast_filter_single_column(series, threshold, op)
+        .map_err(|e| fail(e.to_string()))?;
+    let filtered = VectorizedFilter::filter_series_with_mask(series, &mask)
+        .map_err(|e| fail(e.to_string()))?;
+    let expected: Vec<Option<Value>> = (0..series.len())
+        .filter_map(|row| series.get_value(row))
+        .filter(|value| reference_compare(value, threshold, op))
+        .map(Some)
+        .collect();
+    let actual: Vec<Option<Value>> = (0..filtered.len())
+        .map(|row| filtered.get_value(row))
+        .collect();
+    if actual != expected {
+        return Err(fail(format!(
+            "{op:?} {threshold:?} kept {actual:?}, expected {expected:?}"
+        )));
+    }
+    Ok(())
+}
+
+/// Compares [`UltraFastJoin::inner_join_i32`] with a nested-loop inner join of
+/// two single-column frames on their `I32` keys, where null keys never match.
+///
+/// Output rows follow the left frame, and each left row's matches the right
+/// frame, for both.
+pub fn check_ultra_fast_join(left: &Series, right: &Series) -> Result<(), TestCaseError> {
+    i32_parts(left)?;
+    i32_parts(right)?;
+    let frame = |series: &Series| {
+        let mut series = series.clone();
+        series.set_name("id");
+        DataFrame::new(HashMap::from([("id".to_string(), series)])).map_err(|e| fail(e.to_string()))
+    };
+    let result = UltraFastJoin::inner_join_i32(&frame(left)?, &frame(right)?, "id", "id")
+        .map_err(|e| fail(e.to_string()))?;
+
+    let mut left_ids = Vec::new();
+    let mut right_ids = Vec::new();
+    for l in 0..left.len() {
+        for r in 0..right.len() {
+            match (left.get_value(l), right.get_value(r)) {
+                (Some(a), Some(b)) if a == b => {
+                    left_ids.push(Some(l));
+                    right_ids.push(Some(r));
+                }
+                _ => {}
+            }
+        }
+    }
+    let take = |series: &Series, name: &str, rows: &[Option<usize>]| {
+        let mut taken = crate::dataframe::join::take_optional(series, rows);
+        taken.set_name(name);
+        (name.to_string(), taken)
+    };
+    let expected = DataFrame::new(HashMap::from([
+        take(left, "left_id", &left_ids),
+        take(right, "right_id", &right_ids),
+    ]))
+    .map_err(|e| fail(e.to_string()))?;
+    assert_frames_equal(&result, &expected, true)
+}
@@ -40,6 +40,8 @@ pub enum DataType {
     String,
     /// DateTime type, represented as a Unix timestamp (i64).
     DateTime,
+    /// Raw bytes, e.g. hashes or serialized payloads.
+    Binary,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
@@ -78,6 +80,8 @@ pub enum Value {
     String(String),
     /// A DateTime value, represented as a Unix timestamp (i64).
     DateTime(i64),
+    /// Raw bytes, e.g. a hash or a serialized payload.
+    Binary(Vec<u8>),
 }
 
 impl Value {
@@ -105,6 +109,7 @@ impl Value {
             Value::Bool(_) => DataType::Bool,
             Value::String(_) => DataType::String,
             Value::DateTime(_) => DataType::DateTime,
+            Value::Binary(_) => DataType::Binary,
             Value::Null => panic!("Cannot get data type of a Null value"),
         }
     }
@@ -198,6 +203,24 @@ impl Value {
             _ => None,
         }
     }
+
+    /// Attempts to convert the `Value` into a byte slice.
+    /// Returns `Some(&[u8])` if the `Value` is `Binary`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use veloxx::types::Value;
+    ///
+    /// assert_eq!(Value::Binary(vec![0xca, 0xfe]).as_binary(), Some(&[0xca, 0xfe][..]));
+    /// assert_eq!(Value::String("cafe".to_string()).as_binary(), None);
+    /// ```
+    pub fn as_binary(&self) -> Option<&[u8]> {
+        match self {
+            Value::Binary(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq for Value {
@@ -233,6 +256,7 @@ impl PartialEq for Value {
             (Value::Bool(l), Value::Bool(r)) => l == r,
             (Value::String(l), Value::String(r)) => l == r,
             (Value::DateTime(l), Value::DateTime(r)) => l == r,
+            (Value::Binary(l), Value::Binary(r)) => l == r,
             _ => false,
         }
     }
@@ -251,12 +275,35 @@ impl std::fmt::Display for Value {
             Value::Bool(v) => write!(f, "{}", v),
             Value::String(v) => write!(f, "{}", v),
             Value::DateTime(v) => write!(f, "{}", v),
+            Value::Binary(v) => write!(f, "{}", hex_string(v)),
         }
     }
 }
 
 impl Eq for Value {}
 
+/// Formats bytes as `0x`-prefixed lowercase hex, the display form of binary values
+pub(crate) fn hex_string(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+/// Parses the `0x`-prefixed hex written by [`hex_string`]
+pub(crate) fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    let digits = text.strip_prefix("0x")?;
+    if digits.len() % 2 != 0 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).ok())
+        .collect()
+}
+
 impl Value {
     // Helper to get a discriminant for ordering incomparable types
     fn discriminant(&self) -> u8 {
@@ -267,6 +314,7 @@ impl Value {
             Value::Bool(_) => 3,
             Value::String(_) => 4,
             Value::DateTime(_) => 5,
+            Value::Binary(_) => 6,
         }
     }
 }
@@ -298,10 +346,12 @@ impl Hash for Value {
             Value::Bool(v) => v.hash(state),
             Value::String(v) => v.hash(state),
             Value::DateTime(v) => v.hash(state),
+            Value::Binary(v) => v.hash(state),
         }
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Value {
     /// Compares two `Value` instances for partial ordering.
     ///
@@ -319,7 +369,6 @@ impl PartialOrd for Value {
     /// assert!(Value::Null < Value::I32(1));
     /// assert_eq!(Value::I32(1).partial_cmp(&Value::String("a".to_string())), None);
     /// ```
-    #[allow(clippy::non_canonical_partial_ord_impl)]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         use std::cmp::Ordering;
 
@@ -335,6 +384,7 @@ impl PartialOrd for Value {
             (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
             (Value::String(a), Value::String(b)) => a.partial_cmp(b),
             (Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(b),
+            (Value::Binary(a), Value::Binary(b)) => a.partial_cmp(b),
 
             // Cross-type numeric comparisons
             (Value::I32(a), Value::F64(b)) => (*a as f64).partial_cmp(b),
@@ -393,6 +443,8 @@ pub enum FlatValue {
     String(Vec<u8>), // Store byte representation
     /// A DateTime value, represented as a Unix timestamp (i64).
     DateTime(i64),
+    /// Raw bytes.
+    Binary(Vec<u8>),
 }
 
 impl From<Value> for FlatValue {
@@ -422,6 +474,7 @@ impl From<Value> for FlatValue {
             Value::Bool(v) => FlatValue::Bool(v),
             Value::String(v) => FlatValue::String(v.into_bytes()),
             Value::DateTime(v) => FlatValue::DateTime(v),
+            Value::Binary(v) => FlatValue::Binary(v),
         }
     }
 }
@@ -453,6 +506,7 @@ impl From<FlatValue> for Value {
             FlatValue::Bool(v) => Value::Bool(v),
             FlatValue::String(v) => Value::String(String::from_utf8(v).unwrap_or_default()), // Handle potential UTF-8 errors
             FlatValue::DateTime(v) => Value::DateTime(v),
+            FlatValue::Binary(v) => Value::Binary(v),
         }
     }
 }
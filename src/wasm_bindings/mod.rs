@@ -1,13 +1,21 @@
 #![allow(clippy::boxed_local)]
 
 use crate::conditions::Condition;
+use crate::dataframe::join::JoinType;
 use crate::dataframe::DataFrame;
+use crate::expressions::Expr;
+use crate::io::csv::UltraFastCsvParser;
 use crate::series::Series;
 use crate::types::Value;
 use std::collections::HashMap;
 
+#[cfg(target_arch = "wasm32")]
+mod simd;
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsCast;
 
 // WASM DataFrame structure for high-performance data operations
 #[cfg(target_arch = "wasm32")]
@@ -92,6 +100,167 @@ impl WasmDataFrame {
         Ok(WasmDataFrame { df })
     }
 
+    /// Create a DataFrame from an object of `Float64Array` / `Int32Array` columns.
+    ///
+    /// The arrays live in JavaScript memory, so each column is copied into WASM memory
+    /// once, with a single bulk copy instead of being read one `JsValue` at a time.
+    /// `NaN` entries stay values rather than nulls.
+    #[wasm_bindgen(js_name = fromTypedArrays)]
+    pub fn from_typed_arrays(data: &js_sys::Object) -> Result<WasmDataFrame, JsValue> {
+        let mut rust_columns: HashMap<String, Series> = HashMap::new();
+
+        for entry in js_sys::Object::entries(data).iter() {
+            let arr = js_sys::Array::from(&entry);
+            let name = arr
+                .get(0)
+                .as_string()
+                .ok_or("Column name must be a string")?;
+            let values_js = arr.get(1);
+
+            let series = if let Some(values) = values_js.dyn_ref::<js_sys::Float64Array>() {
+                let values = values.to_vec();
+                let validity = vec![true; values.len()];
+                Series::F64(name.clone(), values, validity)
+            } else if let Some(values) = values_js.dyn_ref::<js_sys::Int32Array>() {
+                let values = values.to_vec();
+                let validity = vec![true; values.len()];
+                Series::I32(name.clone(), values, validity)
+            } else {
+                return Err(JsValue::from_str(&format!(
+                    "Column '{}' must be a Float64Array or Int32Array",
+                    name
+                )));
+            };
+
+            rust_columns.insert(name, series);
+        }
+
+        let df = DataFrame::new(rust_columns).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
+    /// Export the numeric columns as an object of typed arrays that view the frame's
+    /// buffers in WASM memory, without copying.
+    ///
+    /// `F64` columns become `Float64Array` views, with their null slots set to `NaN`.
+    /// `I32` columns without nulls become `Int32Array` views. `Int32Array` cannot hold
+    /// a null, so `I32` columns with nulls are copied into `Float64Array`s with nulls
+    /// as `NaN`. Non-numeric columns are rejected.
+    ///
+    /// A view is only valid until WASM memory grows, which any later call into the
+    /// module may do, or until the frame is modified or freed. After that it reads
+    /// as empty or stale. Call `slice()` on a view to keep a copy.
+    #[wasm_bindgen(js_name = toTypedArrays)]
+    pub fn to_typed_arrays(&mut self) -> Result<js_sys::Object, JsValue> {
+        let result = js_sys::Object::new();
+        let names: Vec<String> = self.df.column_names().into_iter().cloned().collect();
+        // Copying allocates, and a growing WASM memory detaches views made before it,
+        // so every copied column is exported before the first view is taken
+        let mut viewed = Vec::with_capacity(names.len());
+        for name in &names {
+            match self.df.columns.get_mut(name) {
+                Some(Series::F64(_, values, validity)) => {
+                    for (value, &valid) in values.iter_mut().zip(validity.iter()) {
+                        if !valid {
+                            *value = f64::NAN;
+                        }
+                    }
+                    viewed.push(name);
+                }
+                Some(Series::I32(_, _, validity)) if validity.iter().all(|&valid| valid) => {
+                    viewed.push(name);
+                }
+                Some(Series::I32(_, values, validity)) => {
+                    let masked: Vec<f64> = values
+                        .iter()
+                        .zip(validity.iter())
+                        .map(|(&v, &valid)| if valid { v as f64 } else { f64::NAN })
+                        .collect();
+                    let array = js_sys::Float64Array::from(masked.as_slice());
+                    js_sys::Reflect::set(&result, &JsValue::from_str(name), &array)?;
+                }
+                Some(series) => {
+                    return Err(JsValue::from_str(&format!(
+                        "Column '{}' of type {:?} cannot be exported as a typed array",
+                        name,
+                        series.data_type()
+                    )))
+                }
+                None => {}
+            }
+        }
+        for name in viewed {
+            // SAFETY: nothing from here on allocates in WASM memory, and the view's
+            // lifetime rules are documented above
+            let array: JsValue = match self.df.get_column(name) {
+                Some(Series::F64(_, values, _)) => {
+                    unsafe { js_sys::Float64Array::view(values) }.into()
+                }
+                Some(Series::I32(_, values, _)) => {
+                    unsafe { js_sys::Int32Array::view(values) }.into()
+                }
+                _ => continue,
+            };
+            js_sys::Reflect::set(&result, &JsValue::from_str(name), &array)?;
+        }
+        Ok(result)
+    }
+
+    /// Parse CSV text (a string, `ArrayBuffer` or `Uint8Array`) entirely client-side.
+    ///
+    /// `options` may set `delimiter` and `quote` (single characters) and `inferTypes`
+    /// (default `true`; when `false` every column is read as strings).
+    #[wasm_bindgen(js_name = fromCsvString)]
+    pub fn from_csv_string(data: JsValue, options: JsValue) -> Result<WasmDataFrame, JsValue> {
+        let bytes = js_bytes(&data)?;
+        let mut parser = UltraFastCsvParser::new();
+        if options.is_object() {
+            if let Some(delimiter) = js_option(&options, "delimiter")? {
+                parser = parser.delimiter(js_char(&delimiter, "delimiter")?);
+            }
+            if let Some(quote) = js_option(&options, "quote")? {
+                parser = parser.quote(js_char(&quote, "quote")?);
+            }
+            if let Some(infer) = js_option(&options, "inferTypes")? {
+                parser = parser.infer_types(infer.is_truthy());
+            }
+        }
+        let df = parser
+            .read_from_reader(bytes.as_slice())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
+    /// Parse a JSON array of row objects (a string, `ArrayBuffer` or `Uint8Array`)
+    #[wasm_bindgen(js_name = fromJson)]
+    pub fn from_json(data: JsValue) -> Result<WasmDataFrame, JsValue> {
+        let bytes = js_bytes(&data)?;
+        let text = std::str::from_utf8(&bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let df = DataFrame::from_json_str(text).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
+    /// Render as CSV text, with columns in sorted name order
+    #[wasm_bindgen(js_name = toCsvString)]
+    pub fn to_csv_string(&self) -> String {
+        self.df.to_csv_string()
+    }
+
+    /// Encode into the compact binary format, e.g. to post to a Web Worker as a
+    /// transferable buffer or to cache in IndexedDB
+    #[wasm_bindgen(js_name = serialize)]
+    pub fn serialize(&self) -> Vec<u8> {
+        self.df.to_bytes()
+    }
+
+    /// Decode bytes (a `Uint8Array` or `ArrayBuffer`) produced by `serialize`
+    #[wasm_bindgen(js_name = deserialize)]
+    pub fn deserialize(bytes: JsValue) -> Result<WasmDataFrame, JsValue> {
+        let df = DataFrame::from_bytes(&js_bytes(&bytes)?)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
     #[wasm_bindgen(js_name = rowCount)]
     pub fn row_count(&self) -> usize {
         self.df.row_count()
@@ -138,21 +307,89 @@ impl WasmDataFrame {
     /// High-performance group by with SIMD optimizations
     #[wasm_bindgen(js_name = groupBy)]
     pub fn group_by(&self, columns: Box<[JsValue]>) -> Result<WasmGroupedDataFrame, JsValue> {
-        let column_names: Result<Vec<String>, JsValue> = columns
-            .iter()
-            .map(|v| {
-                v.as_string()
-                    .ok_or_else(|| JsValue::from_str("Column name must be a string"))
-            })
-            .collect();
-
         // Store owned DataFrame and group columns, re-create GroupedDataFrame on demand
         Ok(WasmGroupedDataFrame {
             dataframe: self.df.clone(),
-            group_columns: column_names?,
+            group_columns: js_strings(&columns)?,
         })
     }
 
+    /// Filter rows with a condition built from `WasmCondition`
+    #[wasm_bindgen(js_name = filter)]
+    pub fn filter(&self, condition: &WasmCondition) -> Result<WasmDataFrame, JsValue> {
+        let condition = coerce_condition(&self.df, condition.inner.clone());
+        let filtered = self
+            .df
+            .filter(&condition)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df: filtered })
+    }
+
+    /// Keep only the named columns
+    #[wasm_bindgen(js_name = selectColumns)]
+    pub fn select_columns(&self, names: Box<[JsValue]>) -> Result<WasmDataFrame, JsValue> {
+        let selected = self
+            .df
+            .select_columns(js_strings(&names)?)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df: selected })
+    }
+
+    /// Sort rows by one or more columns
+    #[wasm_bindgen(js_name = sort)]
+    pub fn sort(
+        &self,
+        by_columns: Box<[JsValue]>,
+        ascending: bool,
+    ) -> Result<WasmDataFrame, JsValue> {
+        let sorted = self
+            .df
+            .sort(js_strings(&by_columns)?, ascending)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df: sorted })
+    }
+
+    /// Join with another DataFrame on a shared key column
+    #[wasm_bindgen(js_name = join)]
+    pub fn join(
+        &self,
+        other: &WasmDataFrame,
+        on_column: &str,
+        join_type: WasmJoinType,
+    ) -> Result<WasmDataFrame, JsValue> {
+        let join_type = match join_type {
+            WasmJoinType::Inner => JoinType::Inner,
+            WasmJoinType::Left => JoinType::Left,
+            WasmJoinType::Right => JoinType::Right,
+        };
+        let joined = self
+            .df
+            .join(&other.df, on_column, join_type)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df: joined })
+    }
+
+    /// Add (or replace) a column computed from an expression
+    #[wasm_bindgen(js_name = withColumn)]
+    pub fn with_column(&self, name: &str, expr: &WasmExpr) -> Result<WasmDataFrame, JsValue> {
+        let expr = coerce_expr(&self.df, expr.inner.clone());
+        let df = self
+            .df
+            .with_column(name, &expr)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
+    /// Summary statistics for the numeric columns
+    #[wasm_bindgen(js_name = describe)]
+    pub fn describe(&self) -> Result<WasmDataFrame, JsValue> {
+        let df = self
+            .df
+            .describe()
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(WasmDataFrame { df })
+    }
+
     /// Add a series to the DataFrame
     #[wasm_bindgen(js_name = addSeries)]
     pub fn add_series(&mut self, name: &str, series: &WasmSeries) -> Result<(), JsValue> {
@@ -175,37 +412,72 @@ impl WasmDataFrame {
             .map(|s| WasmSeries { inner: s.clone() })
     }
 
-    /// Convert to JSON string for JavaScript consumption
-    #[wasm_bindgen(js_name = toJson)]
-    pub fn to_json(&self) -> String {
-        // Simple JSON serialization - would be improved in production
-        let mut json = String::from("{");
-
-        for (i, name) in self.df.column_names().iter().enumerate() {
-            if i > 0 {
-                json.push(',');
-            }
-            json.push_str(&format!("\"{}\":[", name));
+    /// Convert to a plain JS object mapping column names to their values.
+    ///
+    /// Numeric columns without nulls become `Float64Array`/`Int32Array`; all other
+    /// columns become arrays with `null` for missing values.
+    #[wasm_bindgen(js_name = toObject)]
+    pub fn to_object(&self) -> Result<js_sys::Object, JsValue> {
+        let result = js_sys::Object::new();
+        for name in self.sorted_column_names() {
+            let series = &self.df.columns[name];
+            js_sys::Reflect::set(&result, &JsValue::from_str(name), &series_to_js(series)?)?;
+        }
+        Ok(result)
+    }
 
-            if let Some(series) = self.df.get_column(name) {
-                for j in 0..series.len() {
-                    if j > 0 {
-                        json.push(',');
-                    }
-                    match series.get_value(j) {
-                        Some(Value::I32(v)) => json.push_str(&v.to_string()),
-                        Some(Value::F64(v)) => json.push_str(&v.to_string()),
-                        Some(Value::String(v)) => json.push_str(&format!("\"{}\"", v)),
-                        Some(Value::Bool(v)) => json.push_str(&v.to_string()),
-                        _ => json.push_str("null"),
-                    }
-                }
+    /// Serialize to a JSON string.
+    ///
+    /// `orient` is `"columns"` (the default, `{"col": [...]}`) or `"records"`
+    /// (`[{"col": ...}, ...]`). Nulls and non-finite floats are written as `null`.
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self, orient: Option<String>) -> Result<String, JsValue> {
+        let names = self.sorted_column_names();
+        let json = match orient.as_deref().unwrap_or("columns") {
+            "columns" => serde_json::Value::Object(
+                names
+                    .iter()
+                    .map(|&name| {
+                        let series = &self.df.columns[name];
+                        let values = (0..series.len())
+                            .map(|i| value_to_json(series.get_value(i)))
+                            .collect();
+                        (name.clone(), serde_json::Value::Array(values))
+                    })
+                    .collect(),
+            ),
+            "records" => serde_json::Value::Array(
+                (0..self.df.row_count())
+                    .map(|i| {
+                        serde_json::Value::Object(
+                            names
+                                .iter()
+                                .map(|&name| {
+                                    let value = self.df.columns[name].get_value(i);
+                                    (name.clone(), value_to_json(value))
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            ),
+            other => {
+                return Err(JsValue::from_str(&format!(
+                    "Unknown orient '{}', expected 'columns' or 'records'",
+                    other
+                )))
             }
-            json.push(']');
-        }
+        };
+        serde_json::to_string(&json).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
 
-        json.push('}');
-        json
+#[cfg(target_arch = "wasm32")]
+impl WasmDataFrame {
+    fn sorted_column_names(&self) -> Vec<&String> {
+        let mut names: Vec<&String> = self.df.columns.keys().collect();
+        names.sort_unstable();
+        names
     }
 }
 
@@ -301,6 +573,73 @@ impl WasmSeries {
             Err(e) => Err(JsValue::from_str(&e.to_string())),
         }
     }
+
+    /// Length of each string in chars
+    #[wasm_bindgen(js_name = strLenChars)]
+    pub fn str_len_chars(&self) -> Result<WasmSeries, JsValue> {
+        match self.inner.str_len_chars() {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
+    /// Length of each string in UTF-8 bytes
+    #[wasm_bindgen(js_name = strLenBytes)]
+    pub fn str_len_bytes(&self) -> Result<WasmSeries, JsValue> {
+        match self.inner.str_len_bytes() {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
+    /// Substring of up to `length` chars starting at char `start`
+    #[wasm_bindgen(js_name = strSliceChars)]
+    pub fn str_slice_chars(
+        &self,
+        start: usize,
+        length: Option<usize>,
+    ) -> Result<WasmSeries, JsValue> {
+        match self.inner.str_slice_chars(start, length) {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
+    /// Length of each binary value in bytes
+    #[wasm_bindgen(js_name = binLen)]
+    pub fn bin_len(&self) -> Result<WasmSeries, JsValue> {
+        match self.inner.bin_len() {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
+    /// Whether each binary value starts with `prefix`
+    #[wasm_bindgen(js_name = binStartsWith)]
+    pub fn bin_starts_with(&self, prefix: &[u8]) -> Result<WasmSeries, JsValue> {
+        match self.inner.bin_starts_with(prefix) {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
+    /// Base64 text of each binary value
+    #[wasm_bindgen(js_name = base64Encode)]
+    pub fn base64_encode(&self) -> Result<WasmSeries, JsValue> {
+        match self.inner.base64_encode() {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
+
+    /// Bytes decoded from each base64 string
+    #[wasm_bindgen(js_name = base64Decode)]
+    pub fn base64_decode(&self) -> Result<WasmSeries, JsValue> {
+        match self.inner.base64_decode() {
+            Ok(result) => Ok(WasmSeries { inner: result }),
+            Err(e) => Err(JsValue::from_str(&e.to_string())),
+        }
+    }
 }
 
 /// WASM Grouped DataFrame for aggregations
@@ -383,26 +722,89 @@ impl WasmGroupedDataFrame {
     }
 }
 
-/// High-performance vectorized operations for JavaScript
+/// Whether this build was compiled with the wasm SIMD128 kernels
 #[cfg(target_arch = "wasm32")]
-#[wasm_bindgen(js_name = simdAddF64)]
-pub fn simd_add_f64(a: Box<[f64]>, b: Box<[f64]>) -> Result<Box<[f64]>, JsValue> {
+#[wasm_bindgen(js_name = simdSupported)]
+pub fn simd_supported() -> bool {
+    simd::SIMD_ENABLED
+}
+
+#[cfg(target_arch = "wasm32")]
+fn simd_arith(a: &[f64], b: &[f64], op: simd::ArithOp) -> Result<Vec<f64>, JsValue> {
     if a.len() != b.len() {
         return Err(JsValue::from_str("Arrays must have the same length"));
     }
-    let len = a.len();
-    let mut result = vec![0.0; len];
-    for i in 0..len {
-        result[i] = a[i] + b[i];
-    }
-    Ok(result.into_boxed_slice())
+    Ok(simd::arith_f64(a, b, op))
+}
+
+/// Elementwise `a + b`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdAddF64)]
+pub fn simd_add_f64(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    simd_arith(a, b, simd::ArithOp::Add)
+}
+
+/// Elementwise `a - b`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdSubF64)]
+pub fn simd_sub_f64(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    simd_arith(a, b, simd::ArithOp::Sub)
+}
+
+/// Elementwise `a * b`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdMulF64)]
+pub fn simd_mul_f64(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    simd_arith(a, b, simd::ArithOp::Mul)
+}
+
+/// Elementwise `a / b`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdDivF64)]
+pub fn simd_div_f64(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    simd_arith(a, b, simd::ArithOp::Div)
 }
 
-/// High-performance vectorized sum for JavaScript
+/// Sum of all values
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen(js_name = simdSumF64)]
-pub fn simd_sum_f64(data: Box<[f64]>) -> f64 {
-    data.iter().copied().sum::<f64>()
+pub fn simd_sum_f64(data: &[f64]) -> f64 {
+    simd::sum_f64(data)
+}
+
+/// Minimum value ignoring `NaN`s, or `undefined` for an empty array
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdMinF64)]
+pub fn simd_min_f64(data: &[f64]) -> Option<f64> {
+    simd::min_f64(data)
+}
+
+/// Maximum value ignoring `NaN`s, or `undefined` for an empty array
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdMaxF64)]
+pub fn simd_max_f64(data: &[f64]) -> Option<f64> {
+    simd::max_f64(data)
+}
+
+/// Mask of `data > value` as a `Uint8Array` of 0/1
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdGtF64)]
+pub fn simd_gt_f64(data: &[f64], value: f64) -> Vec<u8> {
+    simd::compare_f64(data, value, simd::CmpOp::Gt)
+}
+
+/// Mask of `data < value` as a `Uint8Array` of 0/1
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdLtF64)]
+pub fn simd_lt_f64(data: &[f64], value: f64) -> Vec<u8> {
+    simd::compare_f64(data, value, simd::CmpOp::Lt)
+}
+
+/// Mask of `data == value` as a `Uint8Array` of 0/1
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(js_name = simdEqF64)]
+pub fn simd_eq_f64(data: &[f64], value: f64) -> Vec<u8> {
+    simd::compare_f64(data, value, simd::CmpOp::Eq)
 }
 
 // Minimal placeholder exports to satisfy tests and TS definitions
@@ -418,8 +820,456 @@ pub enum WasmDataType {
     Bool = 2,
     String = 3,
     DateTime = 4,
+    Binary = 5,
+}
+
+/// Join types accepted by `WasmDataFrame.join`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum WasmJoinType {
+    Inner = 0,
+    Left = 1,
+    Right = 2,
+}
+
+/// Row filter built with `eq`/`gt`/`lt`/`isIn` and combined with `and`/`or`/`not`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub struct WasmCondition {
+    inner: Condition,
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl WasmCondition {
+    #[wasm_bindgen(js_name = eq)]
+    pub fn eq(column: &str, value: JsValue) -> Result<WasmCondition, JsValue> {
+        Ok(WasmCondition {
+            inner: Condition::Eq(column.to_string(), js_to_value(&value)?),
+        })
+    }
+
+    #[wasm_bindgen(js_name = gt)]
+    pub fn gt(column: &str, value: JsValue) -> Result<WasmCondition, JsValue> {
+        Ok(WasmCondition {
+            inner: Condition::Gt(column.to_string(), js_to_value(&value)?),
+        })
+    }
+
+    #[wasm_bindgen(js_name = lt)]
+    pub fn lt(column: &str, value: JsValue) -> Result<WasmCondition, JsValue> {
+        Ok(WasmCondition {
+            inner: Condition::Lt(column.to_string(), js_to_value(&value)?),
+        })
+    }
+
+    /// Matches rows whose value equals any of `values`
+    #[wasm_bindgen(js_name = isIn)]
+    pub fn is_in(column: &str, values: Box<[JsValue]>) -> Result<WasmCondition, JsValue> {
+        let mut inner: Option<Condition> = None;
+        for value in values.iter() {
+            let eq = Condition::Eq(column.to_string(), js_to_value(value)?);
+            inner = Some(match inner {
+                Some(acc) => Condition::Or(Box::new(acc), Box::new(eq)),
+                None => eq,
+            });
+        }
+        let inner = inner.ok_or_else(|| JsValue::from_str("isIn requires at least one value"))?;
+        Ok(WasmCondition { inner })
+    }
+
+    #[wasm_bindgen(js_name = and)]
+    pub fn and(&self, other: &WasmCondition) -> WasmCondition {
+        WasmCondition {
+            inner: Condition::And(Box::new(self.inner.clone()), Box::new(other.inner.clone())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = or)]
+    pub fn or(&self, other: &WasmCondition) -> WasmCondition {
+        WasmCondition {
+            inner: Condition::Or(Box::new(self.inner.clone()), Box::new(other.inner.clone())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = not)]
+    pub fn not(&self) -> WasmCondition {
+        WasmCondition {
+            inner: Condition::Not(Box::new(self.inner.clone())),
+        }
+    }
 }
 
+/// Column expression used by `WasmDataFrame.withColumn`
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
-pub struct WasmExpr {}
+pub struct WasmExpr {
+    inner: Expr,
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl WasmExpr {
+    #[wasm_bindgen(js_name = column)]
+    pub fn column(name: &str) -> WasmExpr {
+        WasmExpr {
+            inner: Expr::Column(name.to_string()),
+        }
+    }
+
+    #[wasm_bindgen(js_name = literal)]
+    pub fn literal(value: JsValue) -> Result<WasmExpr, JsValue> {
+        Ok(WasmExpr {
+            inner: Expr::Literal(js_to_value(&value)?),
+        })
+    }
+
+    #[wasm_bindgen(js_name = add)]
+    pub fn add(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::Add)
+    }
+
+    #[wasm_bindgen(js_name = subtract)]
+    pub fn subtract(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::Subtract)
+    }
+
+    #[wasm_bindgen(js_name = multiply)]
+    pub fn multiply(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::Multiply)
+    }
+
+    #[wasm_bindgen(js_name = divide)]
+    pub fn divide(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::Divide)
+    }
+
+    #[wasm_bindgen(js_name = equals)]
+    pub fn equals(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::Equals)
+    }
+
+    #[wasm_bindgen(js_name = notEquals)]
+    pub fn not_equals(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::NotEquals)
+    }
+
+    #[wasm_bindgen(js_name = greaterThan)]
+    pub fn greater_than(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::GreaterThan)
+    }
+
+    #[wasm_bindgen(js_name = lessThan)]
+    pub fn less_than(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::LessThan)
+    }
+
+    #[wasm_bindgen(js_name = greaterThanOrEqual)]
+    pub fn greater_than_or_equal(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::GreaterThanOrEqual)
+    }
+
+    #[wasm_bindgen(js_name = lessThanOrEqual)]
+    pub fn less_than_or_equal(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::LessThanOrEqual)
+    }
+
+    #[wasm_bindgen(js_name = and)]
+    pub fn and(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::And)
+    }
+
+    #[wasm_bindgen(js_name = or)]
+    pub fn or(&self, other: &WasmExpr) -> WasmExpr {
+        self.binary(other, Expr::Or)
+    }
+
+    #[wasm_bindgen(js_name = not)]
+    pub fn not(&self) -> WasmExpr {
+        WasmExpr {
+            inner: Expr::Not(Box::new(self.inner.clone())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = strLenChars)]
+    pub fn str_len_chars(&self) -> WasmExpr {
+        WasmExpr {
+            inner: Expr::StrLenChars(Box::new(self.inner.clone())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = strSliceChars)]
+    pub fn str_slice_chars(&self, start: usize, length: Option<usize>) -> WasmExpr {
+        WasmExpr {
+            inner: Expr::StrSliceChars(Box::new(self.inner.clone()), start, length),
+        }
+    }
+
+    #[wasm_bindgen(js_name = base64Encode)]
+    pub fn base64_encode(&self) -> WasmExpr {
+        WasmExpr {
+            inner: Expr::Base64Encode(Box::new(self.inner.clone())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = base64Decode)]
+    pub fn base64_decode(&self) -> WasmExpr {
+        WasmExpr {
+            inner: Expr::Base64Decode(Box::new(self.inner.clone())),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl WasmExpr {
+    fn binary(&self, other: &WasmExpr, op: fn(Box<Expr>, Box<Expr>) -> Expr) -> WasmExpr {
+        WasmExpr {
+            inner: op(Box::new(self.inner.clone()), Box::new(other.inner.clone())),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn series_to_js(series: &Series) -> Result<JsValue, JsValue> {
+    use serde::Serialize;
+
+    fn masked<'a, T>(values: &'a [T], validity: &[bool]) -> Vec<Option<&'a T>> {
+        values
+            .iter()
+            .zip(validity)
+            .map(|(value, &valid)| valid.then_some(value))
+            .collect()
+    }
+
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_missing_as_null(true);
+    let values = match series {
+        Series::F64(_, values, validity) if validity.iter().all(|&valid| valid) => {
+            return Ok(js_sys::Float64Array::from(values.as_slice()).into())
+        }
+        Series::I32(_, values, validity) if validity.iter().all(|&valid| valid) => {
+            return Ok(js_sys::Int32Array::from(values.as_slice()).into())
+        }
+        Series::F64(_, values, validity) => masked(values, validity).serialize(&serializer),
+        Series::I32(_, values, validity) => masked(values, validity).serialize(&serializer),
+        Series::Bool(_, values, validity) => masked(values, validity).serialize(&serializer),
+        Series::String(_, values, validity) => masked(values, validity).serialize(&serializer),
+        Series::DateTime(_, values, validity) => masked(values, validity).serialize(&serializer),
+        Series::Binary(_, values, validity) => {
+            let array = js_sys::Array::new_with_length(values.len() as u32);
+            for (i, (value, &valid)) in values.iter().zip(validity).enumerate() {
+                if valid {
+                    array.set(i as u32, js_sys::Uint8Array::from(value.as_slice()).into());
+                } else {
+                    array.set(i as u32, JsValue::NULL);
+                }
+            }
+            return Ok(array.into());
+        }
+    };
+    values.map_err(JsValue::from)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn value_to_json(value: Option<Value>) -> serde_json::Value {
+    match value {
+        Some(Value::I32(v)) => v.into(),
+        Some(Value::F64(v)) => serde_json::Number::from_f64(v)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Some(Value::Bool(v)) => v.into(),
+        Some(Value::String(v)) => v.into(),
+        Some(Value::DateTime(v)) => v.into(),
+        Some(v @ Value::Binary(_)) => v.to_string().into(),
+        Some(Value::Null) | None => serde_json::Value::Null,
+    }
+}
+
+/// Reads text input given as a string, `ArrayBuffer` or `Uint8Array`
+#[cfg(target_arch = "wasm32")]
+fn js_bytes(data: &JsValue) -> Result<Vec<u8>, JsValue> {
+    if let Some(text) = data.as_string() {
+        Ok(text.into_bytes())
+    } else if let Some(bytes) = data.dyn_ref::<js_sys::Uint8Array>() {
+        Ok(bytes.to_vec())
+    } else if let Some(buffer) = data.dyn_ref::<js_sys::ArrayBuffer>() {
+        Ok(js_sys::Uint8Array::new(buffer).to_vec())
+    } else {
+        Err(JsValue::from_str(
+            "Expected a string, ArrayBuffer or Uint8Array",
+        ))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn js_option(options: &JsValue, key: &str) -> Result<Option<JsValue>, JsValue> {
+    let value = js_sys::Reflect::get(options, &JsValue::from_str(key))?;
+    Ok((!value.is_undefined()).then_some(value))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn js_char(value: &JsValue, name: &str) -> Result<u8, JsValue> {
+    match value.as_string().as_deref().map(str::as_bytes) {
+        Some(&[byte]) => Ok(byte),
+        _ => Err(JsValue::from_str(&format!(
+            "Option '{}' must be a single ASCII character",
+            name
+        ))),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn js_strings(values: &[JsValue]) -> Result<Vec<String>, JsValue> {
+    values
+        .iter()
+        .map(|v| {
+            v.as_string()
+                .ok_or_else(|| JsValue::from_str("Column name must be a string"))
+        })
+        .collect()
+}
+
+/// Converts a JS scalar, treating integral numbers in `i32` range as `I32`
+#[cfg(target_arch = "wasm32")]
+fn js_to_value(value: &JsValue) -> Result<Value, JsValue> {
+    if value.is_null() || value.is_undefined() {
+        Ok(Value::Null)
+    } else if let Some(b) = value.as_bool() {
+        Ok(Value::Bool(b))
+    } else if let Some(num) = value.as_f64() {
+        if num.fract() == 0.0 && num.abs() <= i32::MAX as f64 {
+            Ok(Value::I32(num as i32))
+        } else {
+            Ok(Value::F64(num))
+        }
+    } else if let Some(s) = value.as_string() {
+        Ok(Value::String(s))
+    } else if let Some(bytes) = value.dyn_ref::<js_sys::Uint8Array>() {
+        Ok(Value::Binary(bytes.to_vec()))
+    } else {
+        Err(JsValue::from_str("Unsupported value type"))
+    }
+}
+
+// JS has a single number type, so `js_to_value` cannot tell `2` from `2.0`. These
+// widen integral literals compared against (or combined with) an F64 column.
+
+#[cfg(target_arch = "wasm32")]
+fn is_f64_column(df: &DataFrame, column: &str) -> bool {
+    matches!(df.get_column(column), Some(Series::F64(..)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn widen_for(df: &DataFrame, column: &str, value: Value) -> Value {
+    match value {
+        Value::I32(v) if is_f64_column(df, column) => Value::F64(v as f64),
+        other => other,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn coerce_condition(df: &DataFrame, condition: Condition) -> Condition {
+    match condition {
+        Condition::Eq(c, v) => {
+            let v = widen_for(df, &c, v);
+            Condition::Eq(c, v)
+        }
+        Condition::Gt(c, v) => {
+            let v = widen_for(df, &c, v);
+            Condition::Gt(c, v)
+        }
+        Condition::Lt(c, v) => {
+            let v = widen_for(df, &c, v);
+            Condition::Lt(c, v)
+        }
+        Condition::And(l, r) => Condition::And(
+            Box::new(coerce_condition(df, *l)),
+            Box::new(coerce_condition(df, *r)),
+        ),
+        Condition::Or(l, r) => Condition::Or(
+            Box::new(coerce_condition(df, *l)),
+            Box::new(coerce_condition(df, *r)),
+        ),
+        Condition::Not(c) => Condition::Not(Box::new(coerce_condition(df, *c))),
+        other @ Condition::InSubnet(..) => other,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn coerce_expr(df: &DataFrame, expr: Expr) -> Expr {
+    fn pair(df: &DataFrame, l: Box<Expr>, r: Box<Expr>) -> (Box<Expr>, Box<Expr>) {
+        let (l, r) = (coerce_expr(df, *l), coerce_expr(df, *r));
+        match (l, r) {
+            (Expr::Column(c), Expr::Literal(v)) => {
+                let v = widen_for(df, &c, v);
+                (Box::new(Expr::Column(c)), Box::new(Expr::Literal(v)))
+            }
+            (Expr::Literal(v), Expr::Column(c)) => {
+                let v = widen_for(df, &c, v);
+                (Box::new(Expr::Literal(v)), Box::new(Expr::Column(c)))
+            }
+            (l, r) => (Box::new(l), Box::new(r)),
+        }
+    }
+
+    match expr {
+        Expr::Add(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::Add(l, r)
+        }
+        Expr::Subtract(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::Subtract(l, r)
+        }
+        Expr::Multiply(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::Multiply(l, r)
+        }
+        Expr::Divide(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::Divide(l, r)
+        }
+        Expr::Equals(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::Equals(l, r)
+        }
+        Expr::NotEquals(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::NotEquals(l, r)
+        }
+        Expr::GreaterThan(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::GreaterThan(l, r)
+        }
+        Expr::LessThan(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::LessThan(l, r)
+        }
+        Expr::GreaterThanOrEqual(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::GreaterThanOrEqual(l, r)
+        }
+        Expr::LessThanOrEqual(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::LessThanOrEqual(l, r)
+        }
+        Expr::And(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::And(l, r)
+        }
+        Expr::Or(l, r) => {
+            let (l, r) = pair(df, l, r);
+            Expr::Or(l, r)
+        }
+        Expr::Not(e) => Expr::Not(Box::new(coerce_expr(df, *e))),
+        Expr::StrLenChars(e) => Expr::StrLenChars(Box::new(coerce_expr(df, *e))),
+        Expr::StrSliceChars(e, start, length) => {
+            Expr::StrSliceChars(Box::new(coerce_expr(df, *e)), start, length)
+        }
+        Expr::Base64Encode(e) => Expr::Base64Encode(Box::new(coerce_expr(df, *e))),
+        Expr::Base64Decode(e) => Expr::Base64Decode(Box::new(coerce_expr(df, *e))),
+        Expr::DtTruncate(e, every) => Expr::DtTruncate(Box::new(coerce_expr(df, *e)), every),
+        Expr::Alias(e, name) => Expr::Alias(Box::new(coerce_expr(df, *e)), name),
+        other => other,
+    }
+}
@@ -0,0 +1,199 @@
+//! Numeric kernels behind the `simd*` exports.
+//!
+//! WebAssembly has no runtime CPU feature detection: a module containing SIMD128
+//! instructions fails validation on engines without SIMD support, so the vector
+//! paths are selected at compile time (`RUSTFLAGS="-C target-feature=+simd128"`).
+//! `simdSupported()` reports which build is loaded, letting the host pick between a
+//! SIMD and a scalar package (e.g. with `wasm-feature-detect`). Both builds give the
+//! same results, except that sums may differ in the last bits due to a different
+//! summation order.
+
+#[cfg(target_feature = "simd128")]
+use core::arch::wasm32::*;
+
+pub(crate) const SIMD_ENABLED: bool = cfg!(target_feature = "simd128");
+
+#[derive(Clone, Copy)]
+pub(crate) enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum CmpOp {
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl ArithOp {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        match self {
+            ArithOp::Add => a + b,
+            ArithOp::Sub => a - b,
+            ArithOp::Mul => a * b,
+            ArithOp::Div => a / b,
+        }
+    }
+}
+
+impl CmpOp {
+    fn apply(self, a: f64, b: f64) -> bool {
+        match self {
+            CmpOp::Gt => a > b,
+            CmpOp::Lt => a < b,
+            CmpOp::Eq => a == b,
+        }
+    }
+}
+
+pub(crate) fn sum_f64(data: &[f64]) -> f64 {
+    #[cfg(target_feature = "simd128")]
+    {
+        let mut chunks = data.chunks_exact(4);
+        let (mut acc0, mut acc1) = (f64x2_splat(0.0), f64x2_splat(0.0));
+        for chunk in &mut chunks {
+            // SAFETY: each chunk holds four f64s; wasm loads have no alignment requirement.
+            unsafe {
+                let p = chunk.as_ptr() as *const v128;
+                acc0 = f64x2_add(acc0, v128_load(p));
+                acc1 = f64x2_add(acc1, v128_load(p.add(1)));
+            }
+        }
+        let acc = f64x2_add(acc0, acc1);
+        f64x2_extract_lane::<0>(acc)
+            + f64x2_extract_lane::<1>(acc)
+            + chunks.remainder().iter().sum::<f64>()
+    }
+    #[cfg(not(target_feature = "simd128"))]
+    {
+        data.iter().sum()
+    }
+}
+
+/// Smallest value, ignoring `NaN`s; `None` for empty input
+pub(crate) fn min_f64(data: &[f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    #[cfg(target_feature = "simd128")]
+    {
+        let mut chunks = data.chunks_exact(2);
+        let mut acc = f64x2_splat(f64::INFINITY);
+        for chunk in &mut chunks {
+            // SAFETY: each chunk holds two f64s. `pmin(acc, x)` keeps `acc` when `x` is NaN.
+            acc = f64x2_pmin(acc, unsafe { v128_load(chunk.as_ptr() as *const v128) });
+        }
+        let lanes = [f64x2_extract_lane::<0>(acc), f64x2_extract_lane::<1>(acc)];
+        Some(
+            lanes
+                .iter()
+                .chain(chunks.remainder())
+                .copied()
+                .fold(f64::INFINITY, f64::min),
+        )
+    }
+    #[cfg(not(target_feature = "simd128"))]
+    {
+        Some(data.iter().copied().fold(f64::INFINITY, f64::min))
+    }
+}
+
+/// Largest value, ignoring `NaN`s; `None` for empty input
+pub(crate) fn max_f64(data: &[f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    #[cfg(target_feature = "simd128")]
+    {
+        let mut chunks = data.chunks_exact(2);
+        let mut acc = f64x2_splat(f64::NEG_INFINITY);
+        for chunk in &mut chunks {
+            // SAFETY: as in `min_f64`; `pmax(acc, x)` keeps `acc` when `x` is NaN.
+            acc = f64x2_pmax(acc, unsafe { v128_load(chunk.as_ptr() as *const v128) });
+        }
+        let lanes = [f64x2_extract_lane::<0>(acc), f64x2_extract_lane::<1>(acc)];
+        Some(
+            lanes
+                .iter()
+                .chain(chunks.remainder())
+                .copied()
+                .fold(f64::NEG_INFINITY, f64::max),
+        )
+    }
+    #[cfg(not(target_feature = "simd128"))]
+    {
+        Some(data.iter().copied().fold(f64::NEG_INFINITY, f64::max))
+    }
+}
+
+/// Elementwise `a op b`; the slices must have equal lengths
+pub(crate) fn arith_f64(a: &[f64], b: &[f64], op: ArithOp) -> Vec<f64> {
+    debug_assert_eq!(a.len(), b.len());
+    let mut out = vec![0.0; a.len()];
+    #[cfg(target_feature = "simd128")]
+    {
+        let mut out_chunks = out.chunks_exact_mut(2);
+        for ((o, x), y) in (&mut out_chunks)
+            .zip(a.chunks_exact(2))
+            .zip(b.chunks_exact(2))
+        {
+            // SAFETY: all three chunks hold exactly two f64s.
+            unsafe {
+                let x = v128_load(x.as_ptr() as *const v128);
+                let y = v128_load(y.as_ptr() as *const v128);
+                let r = match op {
+                    ArithOp::Add => f64x2_add(x, y),
+                    ArithOp::Sub => f64x2_sub(x, y),
+                    ArithOp::Mul => f64x2_mul(x, y),
+                    ArithOp::Div => f64x2_div(x, y),
+                };
+                v128_store(o.as_mut_ptr() as *mut v128, r);
+            }
+        }
+        let done = a.len() - out_chunks.into_remainder().len();
+        for i in done..a.len() {
+            out[i] = op.apply(a[i], b[i]);
+        }
+    }
+    #[cfg(not(target_feature = "simd128"))]
+    {
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = op.apply(x, y);
+        }
+    }
+    out
+}
+
+/// Comparison mask of `data op scalar`, with one `0`/`1` byte per element
+pub(crate) fn compare_f64(data: &[f64], scalar: f64, op: CmpOp) -> Vec<u8> {
+    let mut mask = vec![0u8; data.len()];
+    #[cfg(target_feature = "simd128")]
+    {
+        let rhs = f64x2_splat(scalar);
+        let mut mask_chunks = mask.chunks_exact_mut(2);
+        for (m, chunk) in (&mut mask_chunks).zip(data.chunks_exact(2)) {
+            // SAFETY: each chunk holds two f64s.
+            let lhs = unsafe { v128_load(chunk.as_ptr() as *const v128) };
+            let bits = i64x2_bitmask(match op {
+                CmpOp::Gt => f64x2_gt(lhs, rhs),
+                CmpOp::Lt => f64x2_lt(lhs, rhs),
+                CmpOp::Eq => f64x2_eq(lhs, rhs),
+            });
+            m[0] = bits & 1;
+            m[1] = bits >> 1;
+        }
+        if let (Some(m), Some(&v)) = (mask_chunks.into_remainder().first_mut(), data.last()) {
+            *m = op.apply(v, scalar) as u8;
+        }
+    }
+    #[cfg(not(target_feature = "simd128"))]
+    {
+        for (m, &v) in mask.iter_mut().zip(data) {
+            *m = op.apply(v, scalar) as u8;
+        }
+    }
+    mask
+}
@@ -513,6 +513,18 @@ impl WindowFunction {
                     .collect();
                 Series::new_string(&column_name_result, string_values)
             }
+            Series::Binary(_, _, _) => {
+                let binary_values: Vec<Option<Vec<u8>>> = lag_lead_values
+                    .into_iter()
+                    .map(|v| {
+                        v.and_then(|val| match val {
+                            Value::Binary(b) => Some(b),
+                            _ => None,
+                        })
+                    })
+                    .collect();
+                Series::new_binary(&column_name_result, binary_values)
+            }
         };
 
         result_columns.insert(column_name_result, lag_lead_series);
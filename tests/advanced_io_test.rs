@@ -192,6 +192,228 @@ fn test_compression_type_all_variants() {
     }
 }
 
+#[cfg(all(feature = "advanced_io", feature = "arrow"))]
+#[test]
+fn test_parquet_metadata() {
+    use arrow::array::{ArrayRef, Int32Array, StringArray};
+    use arrow::record_batch::RecordBatch;
+    use parquet::file::properties::WriterProperties;
+    use std::sync::Arc;
+    use veloxx::types::Value;
+
+    let ids: ArrayRef = Arc::new(Int32Array::from(vec![
+        Some(3),
+        Some(1),
+        None,
+        Some(7),
+        Some(5),
+    ]));
+    let names: ArrayRef = Arc::new(StringArray::from(vec!["c", "a", "b", "e", "d"]));
+    let batch = RecordBatch::try_from_iter(vec![("id", ids), ("name", names)]).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.parquet");
+    let properties = WriterProperties::builder()
+        .set_max_row_group_size(3)
+        .build();
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer =
+        parquet::arrow::ArrowWriter::try_new(file, batch.schema(), Some(properties)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let metadata = ParquetReader::new().metadata(&path).unwrap();
+    assert_eq!(metadata.num_rows, 5);
+
+    let cell = |df: &veloxx::dataframe::DataFrame, column: &str, row: usize| {
+        df.get_column(column).unwrap().get_value(row)
+    };
+    let text = |s: &str| Some(Value::String(s.to_string()));
+    assert_eq!(metadata.schema.row_count(), 2);
+    assert_eq!(cell(&metadata.schema, "column", 0), text("id"));
+    assert_eq!(cell(&metadata.schema, "physical_type", 0), text("INT32"));
+    assert_eq!(
+        cell(&metadata.schema, "nullable", 0),
+        Some(Value::Bool(true))
+    );
+
+    assert_eq!(metadata.row_groups.row_count(), 2);
+    assert_eq!(
+        cell(&metadata.row_groups, "num_rows", 0),
+        Some(Value::F64(3.0))
+    );
+    assert_eq!(
+        cell(&metadata.row_groups, "num_rows", 1),
+        Some(Value::F64(2.0))
+    );
+
+    let statistics = &metadata.statistics;
+    assert_eq!(statistics.row_count(), 4);
+    assert_eq!(cell(statistics, "column", 0), text("id"));
+    assert_eq!(cell(statistics, "min", 0), text("1"));
+    assert_eq!(cell(statistics, "max", 0), text("3"));
+    assert_eq!(cell(statistics, "null_count", 0), Some(Value::F64(1.0)));
+    assert_eq!(cell(statistics, "row_group", 3), Some(Value::I32(1)));
+    assert_eq!(cell(statistics, "column", 3), text("name"));
+    assert_eq!(cell(statistics, "min", 3), text("d"));
+    assert_eq!(cell(statistics, "max", 3), text("e"));
+}
+
+#[cfg(feature = "odbc")]
+#[test]
+fn test_odbc_type_mapping() {
+    use veloxx::advanced_io::odbc::{default_data_type, OdbcOptions};
+    use veloxx::types::DataType;
+
+    // SQL_INTEGER, SQL_BIGINT, SQL_DECIMAL, SQL_TYPE_TIMESTAMP, SQL_VARBINARY, SQL_WVARCHAR
+    assert_eq!(default_data_type(4), DataType::I32);
+    assert_eq!(default_data_type(-5), DataType::F64);
+    assert_eq!(default_data_type(3), DataType::F64);
+    assert_eq!(default_data_type(93), DataType::DateTime);
+    assert_eq!(default_data_type(-3), DataType::Binary);
+    assert_eq!(default_data_type(-9), DataType::String);
+
+    let options = OdbcOptions::new()
+        .batch_size(100)
+        .column_type("account_id", DataType::String);
+    assert_eq!(options.data_type("account_id", -5), DataType::String);
+    assert_eq!(options.data_type("amount", -5), DataType::F64);
+}
+
+/// Records the statements a write issues, optionally failing inserts
+#[derive(Default)]
+struct RecordingConnection {
+    tables: Vec<String>,
+    fail_inserts: bool,
+    log: std::cell::RefCell<Vec<String>>,
+    batches: std::cell::RefCell<Vec<Vec<Vec<veloxx::types::Value>>>>,
+}
+
+impl veloxx::advanced_io::sql::SqlConnection for RecordingConnection {
+    fn execute(&self, sql: &str) -> Result<(), veloxx::VeloxxError> {
+        self.log.borrow_mut().push(sql.to_string());
+        Ok(())
+    }
+
+    fn execute_batch(
+        &self,
+        sql: &str,
+        rows: &[Vec<veloxx::types::Value>],
+    ) -> Result<(), veloxx::VeloxxError> {
+        if self.fail_inserts {
+            return Err(veloxx::VeloxxError::InvalidOperation(
+                "disk full".to_string(),
+            ));
+        }
+        self.log.borrow_mut().push(sql.to_string());
+        self.batches.borrow_mut().push(rows.to_vec());
+        Ok(())
+    }
+
+    fn table_exists(&self, table: &str) -> Result<bool, veloxx::VeloxxError> {
+        Ok(self.tables.iter().any(|t| t == table))
+    }
+
+    fn begin(&self) -> Result<(), veloxx::VeloxxError> {
+        self.execute("BEGIN")
+    }
+
+    fn commit(&self) -> Result<(), veloxx::VeloxxError> {
+        self.execute("COMMIT")
+    }
+
+    fn rollback(&self) -> Result<(), veloxx::VeloxxError> {
+        self.execute("ROLLBACK")
+    }
+}
+
+#[test]
+fn test_to_sql_modes_and_batches() {
+    use veloxx::advanced_io::sql::{SqlWriteMode, SqlWriteOptions};
+    use veloxx::dataframe::DataFrame;
+    use veloxx::series::Series;
+    use veloxx::types::Value;
+
+    let mut columns = std::collections::HashMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3)]),
+    );
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![Some("a".to_string()), None, Some("c".to_string())],
+        ),
+    );
+    let df = DataFrame::new(columns).unwrap();
+
+    // A new table is created, then filled in batches inside a transaction
+    let connection = RecordingConnection::default();
+    let options = SqlWriteOptions::new()
+        .batch_size(2)
+        .column_type("name", "VARCHAR(8)");
+    let rows = df
+        .to_sql_with_options(&connection, "etl.people", SqlWriteMode::Fail, &options)
+        .unwrap();
+    assert_eq!(rows, 3);
+    let insert = r#"INSERT INTO "etl"."people" ("id", "name") VALUES (?, ?)"#;
+    assert_eq!(
+        *connection.log.borrow(),
+        vec![
+            "BEGIN",
+            r#"CREATE TABLE "etl"."people" ("id" INTEGER, "name" VARCHAR(8))"#,
+            insert,
+            insert,
+            "COMMIT",
+        ]
+    );
+    let batches = connection.batches.borrow();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0][1], vec![Value::I32(2), Value::Null]);
+    assert_eq!(
+        batches[1],
+        vec![vec![Value::I32(3), Value::String("c".to_string())]]
+    );
+
+    // Existing tables: fail, replace or append
+    let connection = RecordingConnection {
+        tables: vec!["people".to_string()],
+        ..Default::default()
+    };
+    assert!(df
+        .to_sql(&connection, "people", SqlWriteMode::Fail)
+        .is_err());
+    assert!(connection.log.borrow().is_empty());
+    df.to_sql(&connection, "people", SqlWriteMode::Replace)
+        .unwrap();
+    assert_eq!(connection.log.borrow()[1], r#"DROP TABLE "people""#);
+    assert_eq!(
+        connection.log.borrow()[2],
+        r#"CREATE TABLE "people" ("id" INTEGER, "name" VARCHAR(4000))"#
+    );
+    connection.log.borrow_mut().clear();
+    df.to_sql(&connection, "people", SqlWriteMode::Append)
+        .unwrap();
+    assert_eq!(
+        *connection.log.borrow(),
+        vec![
+            "BEGIN",
+            r#"INSERT INTO "people" ("id", "name") VALUES (?, ?)"#,
+            "COMMIT"
+        ]
+    );
+
+    // A failed insert rolls the transaction back
+    let connection = RecordingConnection {
+        fail_inserts: true,
+        ..Default::default()
+    };
+    assert!(df
+        .to_sql(&connection, "people", SqlWriteMode::Fail)
+        .is_err());
+    assert_eq!(connection.log.borrow().last().unwrap(), "ROLLBACK");
+}
+
 // Note: These tests are basic scaffolding tests since the actual advanced I/O implementation
 // would require:
 // - Actual file system operations
@@ -0,0 +1,65 @@
+#![cfg(all(feature = "arrow", feature = "advanced_io"))]
+
+use arrow::array::{Array, TimestampMicrosecondArray, TimestampNanosecondArray};
+use arrow::datatypes::{DataType as ArrowDataType, Field, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use std::sync::Arc;
+use veloxx::dataframe::DataFrame;
+use veloxx::io::datetime::parse_datetime;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+#[test]
+fn test_datetime_arrow_round_trip_keeps_seconds() {
+    let new_year = parse_datetime("2024-01-01", "%Y-%m-%d").unwrap();
+    assert_eq!(new_year, 1_704_067_200);
+    let series = Series::new_datetime("when", vec![Some(new_year), None, Some(-1)]);
+
+    let array = series.to_arrow_array();
+    assert_eq!(
+        array.data_type(),
+        &ArrowDataType::Timestamp(TimeUnit::Second, None)
+    );
+    assert!(array.is_null(1));
+
+    let back = Series::from_arrow_array(array, "when".to_string()).unwrap();
+    assert_eq!(back, series);
+}
+
+#[test]
+fn test_sub_second_timestamps_import_as_seconds() {
+    let new_year = parse_datetime("2024-01-01", "%Y-%m-%d").unwrap();
+    let nanos = TimestampNanosecondArray::from(vec![
+        Some(new_year * 1_000_000_000 + 500_000_000),
+        Some(-1),
+        None,
+    ])
+    .with_timezone("UTC");
+
+    let series = Series::from_arrow_array(Arc::new(nanos), "when".to_string()).unwrap();
+    assert_eq!(series.get_value(0), Some(Value::DateTime(new_year)));
+    // Rounded down, so the last nanosecond of 1969 stays in 1969
+    assert_eq!(series.get_value(1), Some(Value::DateTime(-1)));
+    assert_eq!(series.get_value(2), None);
+}
+
+#[test]
+fn test_parquet_microsecond_timestamps_read_as_seconds() {
+    let new_year = parse_datetime("2024-01-01", "%Y-%m-%d").unwrap();
+    let micros = TimestampMicrosecondArray::from(vec![Some(new_year * 1_000_000), None]);
+    let schema = Schema::new(vec![Field::new("when", micros.data_type().clone(), true)]);
+    let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(micros)]).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.parquet");
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let df = DataFrame::from_arrow_parquet(path.to_str().unwrap()).unwrap();
+    let when = df.get_column("when").unwrap();
+    assert_eq!(when.get_value(0), Some(Value::DateTime(new_year)));
+    assert_eq!(when.get_value(1), None);
+}
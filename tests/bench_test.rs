@@ -0,0 +1,112 @@
+use veloxx::bench::{run_suite, BenchOptions, BenchReport, BenchResult, Dataset};
+use veloxx::types::Value;
+
+#[test]
+fn test_generated_datasets_are_reproducible() {
+    let taxi = Dataset::NycTaxi.generate(500, 7).unwrap();
+    assert_eq!(taxi.row_count(), 500);
+    assert_eq!(taxi.column_count(), 10);
+    assert_eq!(
+        taxi.get_column("fare_amount"),
+        Dataset::NycTaxi
+            .generate(500, 7)
+            .unwrap()
+            .get_column("fare_amount")
+    );
+    assert_ne!(
+        taxi.get_column("fare_amount"),
+        Dataset::NycTaxi
+            .generate(500, 8)
+            .unwrap()
+            .get_column("fare_amount")
+    );
+
+    let lineitem = Dataset::TpchLineitem.generate(1_000, 7).unwrap();
+    assert_eq!(lineitem.row_count(), 1_000);
+    let flags = lineitem.get_column("l_returnflag").unwrap();
+    assert_eq!(flags.unique_count().unwrap(), 3);
+    assert_eq!(Dataset::from_name("tpch"), Some(Dataset::TpchLineitem));
+    assert_eq!(Dataset::TpchLineitem.reference_rows(), 6_001_215);
+}
+
+#[test]
+fn test_suite_report_round_trips_through_csv() {
+    let taxi = Dataset::NycTaxi.generate(300, 1).unwrap();
+    let options = BenchOptions {
+        iterations: 2,
+        version: "0.3.1".to_string(),
+    };
+    let report = run_suite(Dataset::NycTaxi, &taxi, &options).unwrap();
+    let operations: Vec<&str> = report
+        .results
+        .iter()
+        .map(|r| r.operation.as_str())
+        .collect();
+    assert_eq!(
+        operations,
+        vec![
+            "filter",
+            "sort",
+            "group_by",
+            "with_column",
+            "join",
+            "csv_write",
+            "csv_read"
+        ]
+    );
+    assert!(report
+        .results
+        .iter()
+        .all(|r| r.rows == 300 && r.min_ms <= r.median_ms));
+
+    let path = std::env::temp_dir().join(format!("veloxx_bench_report_{}.csv", std::process::id()));
+    report.save_csv(&path).unwrap();
+    let loaded = BenchReport::load_csv(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.version, "0.3.1");
+    assert_eq!(loaded.results.len(), report.results.len());
+    assert_eq!(loaded.results[2].operation, "group_by");
+}
+
+#[test]
+fn test_compare_flags_regressions() {
+    let result = |operation: &str, median_ms: f64| BenchResult {
+        dataset: "tpch_lineitem".to_string(),
+        operation: operation.to_string(),
+        rows: 100,
+        min_ms: median_ms,
+        median_ms,
+    };
+    let baseline = BenchReport {
+        version: "0.3.1".to_string(),
+        results: vec![
+            result("sort", 10.0),
+            result("join", 10.0),
+            result("q1", 10.0),
+        ],
+    };
+    let current = BenchReport {
+        version: "0.3.2".to_string(),
+        results: vec![
+            result("sort", 15.0),
+            result("join", 5.0),
+            result("q1", 10.5),
+            result("q6", 1.0),
+        ],
+    };
+    let comparison = current.compare(&baseline, 10.0).unwrap();
+    let status = comparison.get_column("status").unwrap();
+    let statuses: Vec<Value> = (0..4).filter_map(|i| status.get_value(i)).collect();
+    assert_eq!(
+        statuses,
+        ["regressed", "improved", "unchanged", "new"].map(|s| Value::String(s.to_string()))
+    );
+    assert_eq!(
+        comparison.get_column("change_pct").unwrap().get_value(0),
+        Some(Value::F64(50.0))
+    );
+    assert_eq!(
+        comparison.get_column("baseline_ms").unwrap().get_value(3),
+        None
+    );
+}
@@ -0,0 +1,99 @@
+use veloxx::series::business_days::{HolidayCalendar, Holidays};
+use veloxx::series::Series;
+use veloxx::types::Value;
+use veloxx::VeloxxError;
+
+const DAY: i64 = 86_400;
+/// Monday 2024-01-01 at 12:00 UTC
+const MONDAY_NOON: i64 = 1_704_067_200 + 12 * 3_600;
+
+#[test]
+fn test_add_business_days() {
+    let days = Series::new_datetime(
+        "due",
+        vec![
+            Some(MONDAY_NOON + 4 * DAY), // Friday
+            Some(MONDAY_NOON + 5 * DAY), // Saturday
+            None,
+        ],
+    );
+    let calendar = Holidays::default();
+
+    let next = days.add_business_days(1, &calendar).unwrap();
+    assert_eq!(
+        next.get_value(0),
+        Some(Value::DateTime(MONDAY_NOON + 7 * DAY))
+    );
+    assert_eq!(
+        next.get_value(1),
+        Some(Value::DateTime(MONDAY_NOON + 7 * DAY))
+    );
+    assert_eq!(next.get_value(2), None);
+
+    let rolled = days.add_business_days(0, &calendar).unwrap();
+    assert_eq!(
+        rolled.get_value(0),
+        Some(Value::DateTime(MONDAY_NOON + 4 * DAY))
+    );
+    assert_eq!(
+        rolled.get_value(1),
+        Some(Value::DateTime(MONDAY_NOON + 7 * DAY))
+    );
+
+    let back = days.add_business_days(-5, &calendar).unwrap();
+    assert_eq!(
+        back.get_value(0),
+        Some(Value::DateTime(MONDAY_NOON - 3 * DAY))
+    );
+    assert_eq!(back.get_value(1), Some(Value::DateTime(MONDAY_NOON)));
+
+    // New Year's Day off: the Friday before plus one lands on Tuesday
+    let mut holidays = Holidays::default();
+    holidays.insert(2024, 1, 1);
+    let friday = Series::new_datetime("d", vec![Some(MONDAY_NOON - 3 * DAY)]);
+    let next = friday.add_business_days(1, &holidays).unwrap();
+    assert_eq!(next.get_value(0), Some(Value::DateTime(MONDAY_NOON + DAY)));
+
+    let closed = |_day: i64| true;
+    assert!(matches!(
+        friday.add_business_days(1, &closed),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        Series::new_i32("n", vec![Some(1)]).add_business_days(1, &calendar),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_is_business_day_and_diff() {
+    let holidays = Holidays::from_timestamps([MONDAY_NOON + 2 * DAY]);
+    assert!(holidays.is_holiday((MONDAY_NOON + 2 * DAY) / DAY));
+
+    let week: Vec<Option<i64>> = (0..7).map(|d| Some(MONDAY_NOON + d * DAY)).collect();
+    let week = Series::new_datetime("day", week);
+    let open = week.is_business_day(&holidays).unwrap();
+    let open: Vec<Option<Value>> = (0..7).map(|i| open.get_value(i)).collect();
+    let expected = [true, true, false, true, true, false, false];
+    assert_eq!(open, expected.map(|b| Some(Value::Bool(b))).to_vec());
+
+    let start = Series::new_datetime(
+        "start",
+        vec![Some(MONDAY_NOON), Some(MONDAY_NOON + 7 * DAY), None],
+    );
+    let end = Series::new_datetime(
+        "end",
+        vec![Some(MONDAY_NOON + 7 * DAY), Some(MONDAY_NOON), Some(0)],
+    );
+    let diff = start.business_day_diff(&end, &holidays).unwrap();
+    assert_eq!(diff.get_value(0), Some(Value::I32(4)));
+    assert_eq!(diff.get_value(1), Some(Value::I32(-4)));
+    assert_eq!(diff.get_value(2), None);
+
+    let weekends_only = |_day: i64| false;
+    let diff = start.business_day_diff(&end, &weekends_only).unwrap();
+    assert_eq!(diff.get_value(0), Some(Value::I32(5)));
+
+    let short = Series::new_datetime("short", vec![Some(0)]);
+    assert!(start.business_day_diff(&short, &holidays).is_err());
+}
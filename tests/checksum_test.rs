@@ -0,0 +1,53 @@
+use veloxx::df;
+use veloxx::types::{DataType, Value};
+
+#[test]
+fn test_hash_rows() {
+    let df = df!(
+        "id" => [Some(1), Some(2), Some(1), None],
+        "tag" => ["a", "b", "a", "a"],
+    )
+    .unwrap();
+    let hashes = df.hash_rows(0);
+    assert_eq!(hashes.name(), "row_hash");
+    assert_eq!(hashes.data_type(), DataType::DateTime);
+    assert_eq!(hashes.len(), 4);
+    assert_eq!(hashes.get_value(0), hashes.get_value(2));
+    assert_ne!(hashes.get_value(0), hashes.get_value(1));
+    // A null differs from every value
+    assert_ne!(hashes.get_value(0), hashes.get_value(3));
+    assert_ne!(df.hash_rows(1).get_value(0), hashes.get_value(0));
+
+    // Pinned, so a change to the encoding cannot go unnoticed
+    assert_eq!(
+        df!("id" => [7]).unwrap().hash_rows(0).get_value(0),
+        Some(Value::DateTime(0x9c10_b54f_1b64_c2cc_u64 as i64))
+    );
+    // Names are not hashed, only the values in column-name order
+    assert_eq!(
+        df!("key" => [7]).unwrap().hash_rows(0).get_value(0),
+        df!("id" => [7]).unwrap().hash_rows(0).get_value(0)
+    );
+}
+
+#[test]
+fn test_checksums() {
+    let df = df!("id" => [1, 2, 3], "score" => [0.5, f64::NAN, 2.0]).unwrap();
+    let same = df!("score" => [0.5, f64::NAN, 2.0], "id" => [1, 2, 3]).unwrap();
+    let reordered = df!("id" => [3, 1, 2], "score" => [2.0, 0.5, f64::NAN]).unwrap();
+    let changed = df!("id" => [1, 2, 3], "score" => [0.5, f64::NAN, 2.5]).unwrap();
+    let renamed = df!("key" => [1, 2, 3], "score" => [0.5, f64::NAN, 2.0]).unwrap();
+    let retyped = df!("id" => [1.0, 2.0, 3.0], "score" => [0.5, f64::NAN, 2.0]).unwrap();
+
+    assert_eq!(df.checksum(), same.checksum());
+    assert_ne!(df.checksum(), reordered.checksum());
+    assert_eq!(df.checksum_unordered(), reordered.checksum_unordered());
+    for other in [&changed, &renamed, &retyped] {
+        assert_ne!(df.checksum(), other.checksum());
+        assert_ne!(df.checksum_unordered(), other.checksum_unordered());
+    }
+
+    // Lost or duplicated rows change the unordered checksum too
+    let duplicated = df!("id" => [1, 1, 2, 3], "score" => [0.5, 0.5, f64::NAN, 2.0]).unwrap();
+    assert_ne!(df.checksum_unordered(), duplicated.checksum_unordered());
+}
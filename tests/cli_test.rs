@@ -0,0 +1,91 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn veloxx(args: &[&str], stdin: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_veloxx"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_cli_query_and_convert() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("sales.csv");
+    std::fs::write(&input, "city,sales\nParis,10\nOslo,5\nParis,2.5\n").unwrap();
+    let input = input.to_str().unwrap();
+
+    let (ok, stdout) = veloxx(
+        &[
+            "query",
+            input,
+            "select city, sum(sales) group by city order by city",
+        ],
+        "",
+    );
+    assert!(ok);
+    assert_eq!(stdout, "city,sales_sum\nOslo,5\nParis,12.5\n");
+
+    let (ok, stdout) = veloxx(
+        &["query", "-", "select * where sales > 4", "--format", "json"],
+        "city,sales\nParis,10\nOslo,3\n",
+    );
+    assert!(ok);
+    assert_eq!(stdout, "[{\"city\":\"Paris\",\"sales\":10}]\n");
+
+    let output = dir.path().join("sales.json");
+    let output = output.to_str().unwrap();
+    assert!(veloxx(&["convert", input, output], "").0);
+    let (ok, stdout) = veloxx(&["schema", output], "");
+    assert!(ok);
+    assert!(stdout.contains("(3 rows)"));
+
+    assert!(!veloxx(&["query", input, "select nope"], "").0);
+    assert!(!veloxx(&["frobnicate"], "").0);
+}
+
+#[test]
+fn test_cli_bench_against_baseline() {
+    let dir = tempfile::tempdir().unwrap();
+    let report = dir.path().join("baseline.csv");
+    let report = report.to_str().unwrap();
+    assert!(veloxx(&["bench", "tpch", "-n", "300", "-o", report], "").0);
+
+    let (ok, stdout) = veloxx(
+        &[
+            "bench",
+            "tpch",
+            "-n",
+            "300",
+            "--baseline",
+            report,
+            "-f",
+            "csv",
+        ],
+        "",
+    );
+    assert!(ok);
+    assert!(
+        stdout.starts_with("baseline_ms,baseline_version,"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("q1_group_by"));
+    assert!(!veloxx(&["bench", "imdb"], "").0);
+}
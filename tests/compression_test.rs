@@ -0,0 +1,55 @@
+#![cfg(feature = "compression")]
+
+use veloxx::dataframe::compressed::{ColumnCodec, CompressOptions};
+use veloxx::df;
+use veloxx::types::{DataType, Value};
+
+fn sample() -> veloxx::dataframe::DataFrame {
+    df!(
+        "id" => 0..20_000,
+        "city" => (0..20_000).map(|i| ["berlin", "paris", "rome"][i % 3]),
+        "score" => (0..20_000).map(|i| if i % 5 == 0 { None } else { Some(i as f64 / 4.0) }),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_compressed_frame_round_trips() {
+    let df = sample();
+    for codec in [ColumnCodec::Lz4, ColumnCodec::Zstd(3)] {
+        let options = CompressOptions {
+            codec,
+            hot_columns: vec!["id".to_string()],
+            cache_size: 1,
+        };
+        let compressed = df.compress(&options).unwrap();
+        assert!(!compressed.is_compressed("id"));
+        assert!(compressed.is_compressed("city"));
+        assert_eq!(compressed.column_type("score"), Some(DataType::F64));
+        assert!(compressed.compressed_size() * 2 < compressed.uncompressed_size());
+
+        let city = compressed.get_column("city").unwrap();
+        assert_eq!(city.get_value(4), Some(Value::String("paris".to_string())));
+        // A cached column is shared rather than decompressed again
+        assert!(std::sync::Arc::ptr_eq(
+            &city,
+            &compressed.get_column("city").unwrap()
+        ));
+        assert_eq!(compressed.get_column("score").unwrap().get_value(0), None);
+
+        let restored = compressed.decompress().unwrap();
+        assert_eq!(restored.to_csv_string(), df.to_csv_string());
+        assert_eq!(compressed.select(&["id"]).unwrap().column_count(), 1);
+    }
+}
+
+#[test]
+fn test_compress_rejects_unknown_columns() {
+    let options = CompressOptions {
+        hot_columns: vec!["missing".to_string()],
+        ..Default::default()
+    };
+    assert!(sample().compress(&options).is_err());
+    let compressed = sample().compress(&CompressOptions::default()).unwrap();
+    assert!(compressed.get_column("missing").is_err());
+}
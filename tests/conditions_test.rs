@@ -30,3 +30,147 @@ fn test_conditions() {
         "Or(Lt(\"col2\", I32(42)), Gt(\"col2\", I32(42)))"
     );
 }
+
+#[test]
+fn test_update_where() {
+    use veloxx::expressions::Expr;
+
+    let df = veloxx::df!(
+        "qty" => [1, 5, 10, 20],
+        "price" => [Some(2.0), Some(3.0), None, Some(5.0)],
+        "discounted" => [1.0, 2.5, 9.0, 4.0],
+    )
+    .unwrap();
+    let big = Condition::Gt("qty".to_string(), Value::I32(4));
+
+    let copied = df
+        .update_where(&big, "price", &Expr::Column("discounted".to_string()))
+        .unwrap();
+    let price = copied.get_column("price").unwrap();
+    assert_eq!(price.get_value(0), Some(Value::F64(2.0)));
+    assert_eq!(price.get_value(1), Some(Value::F64(2.5)));
+    assert_eq!(price.get_value(2), Some(Value::F64(9.0)));
+    // The source frame is untouched
+    assert_eq!(
+        df.get_column("price").unwrap().get_value(1),
+        Some(Value::F64(3.0))
+    );
+
+    let cleared = df
+        .update_where(
+            &Condition::Not(Box::new(big.clone())),
+            "qty",
+            &Expr::Literal(Value::Null),
+        )
+        .unwrap();
+    assert_eq!(cleared.get_column("qty").unwrap().get_value(0), None);
+    assert_eq!(cleared.get_column("qty").unwrap().count(), 3);
+
+    let doubled = df
+        .update_where(
+            &Condition::Lt("qty".to_string(), Value::I32(10)),
+            "qty",
+            &Expr::Multiply(
+                Box::new(Expr::Column("qty".to_string())),
+                Box::new(Expr::Literal(Value::I32(2))),
+            ),
+        )
+        .unwrap();
+    assert_eq!(
+        doubled.get_column("qty").unwrap().get_data_i32().unwrap(),
+        vec![Some(2), Some(10), Some(10), Some(20)]
+    );
+
+    assert!(df
+        .update_where(&big, "qty", &Expr::Literal(Value::F64(1.0)))
+        .is_err());
+    assert!(df
+        .update_where(&big, "qty", &Expr::Column("price".to_string()))
+        .is_err());
+    assert!(df
+        .update_where(&big, "missing", &Expr::Literal(Value::Null))
+        .is_err());
+
+    let blobs = df
+        .with_column("blob", &Expr::Literal(Value::Binary(vec![0xde, 0xad])))
+        .unwrap();
+    let replaced = blobs
+        .update_where(&big, "blob", &Expr::Literal(Value::Binary(vec![0xff])))
+        .unwrap();
+    assert_eq!(
+        replaced
+            .get_column("blob")
+            .unwrap()
+            .get_data_binary()
+            .unwrap(),
+        vec![
+            Some(vec![0xde, 0xad]),
+            Some(vec![0xff]),
+            Some(vec![0xff]),
+            Some(vec![0xff]),
+        ]
+    );
+}
+
+#[test]
+fn test_ip_addresses_and_subnets() {
+    use veloxx::series::ip::IpNetwork;
+
+    let df = veloxx::df!(
+        "ip" => [Some("10.0.0.9"), Some("9.255.1.1"), None, Some("10.20.0.1"), Some("2001:db8::1")],
+        "bytes" => [100, 200, 300, 400, 500],
+    )
+    .unwrap();
+
+    let private: IpNetwork = "10.0.0.0/8".parse().unwrap();
+    assert_eq!(private.to_string(), "10.0.0.0/8");
+    assert_eq!(
+        "10.1.2.3/16".parse::<IpNetwork>().unwrap().to_string(),
+        "10.1.0.0/16"
+    );
+    assert!("10.0.0.0/40".parse::<IpNetwork>().is_err());
+    assert!("not-an-ip/8".parse::<IpNetwork>().is_err());
+
+    let internal = df
+        .filter(&Condition::InSubnet("ip".to_string(), private))
+        .unwrap();
+    assert_eq!(
+        internal
+            .get_column("bytes")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![Some(100), Some(400)]
+    );
+    let v6 = Condition::InSubnet("ip".to_string(), "2001:db8::/32".parse().unwrap());
+    assert!(v6.evaluate(&df, 4).unwrap());
+    assert!(!v6.evaluate(&df, 0).unwrap());
+    assert!(!v6.evaluate(&df, 2).unwrap());
+    assert!(Condition::InSubnet("bytes".to_string(), private)
+        .evaluate(&df, 0)
+        .is_err());
+
+    let ips = df.get_column("ip").unwrap().to_ip().unwrap();
+    assert_eq!(
+        ips.in_subnet(&private).get_data_bool().unwrap(),
+        vec![Some(true), Some(false), None, Some(true), Some(false)]
+    );
+    assert_eq!(
+        ips.sort().to_series().get_data_string().unwrap(),
+        vec![
+            None,
+            Some("9.255.1.1".to_string()),
+            Some("10.0.0.9".to_string()),
+            Some("10.20.0.1".to_string()),
+            Some("2001:db8::1".to_string()),
+        ]
+    );
+    let sorted = df.sort_by_ip("ip", true).unwrap();
+    assert_eq!(
+        sorted.get_column("bytes").unwrap().get_data_i32().unwrap(),
+        vec![Some(300), Some(200), Some(100), Some(400), Some(500)]
+    );
+
+    let bad = veloxx::df!("ip" => ["10.0.0.1", "localhost"]).unwrap();
+    assert!(bad.get_column("ip").unwrap().to_ip().is_err());
+}
@@ -0,0 +1,230 @@
+use std::sync::{Mutex, MutexGuard};
+use veloxx::conditions::Condition;
+use veloxx::dataframe::constraints::{self, monotonic, non_null, unique, ColumnConstraint};
+use veloxx::dataframe::join::{JoinOptions, JoinType};
+use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::error::VeloxxError;
+use veloxx::expressions::Expr;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+/// Serializes tests, as the check switch is process-wide
+fn lock() -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn events() -> DataFrame {
+    df!("id" => [1, 2, 3], "ts" => [10, 10, 20])
+        .unwrap()
+        .with_constraints(vec![non_null("id"), unique("id"), monotonic("ts")])
+        .unwrap()
+}
+
+#[test]
+fn test_constraints_are_checked_when_declared() {
+    let _guard = lock();
+    let df = events();
+    assert_eq!(
+        df.constraints(),
+        &[non_null("id"), unique("id"), monotonic("ts")]
+    );
+
+    let err = df!("id" => [1, 1])
+        .unwrap()
+        .with_constraints(vec![unique("id")]);
+    assert!(matches!(err, Err(VeloxxError::InvalidOperation(msg)) if msg.contains("row 1")));
+
+    let mut columns = std::collections::HashMap::new();
+    columns.insert("id".to_string(), Series::new_i32("id", vec![Some(1), None]));
+    let with_null = DataFrame::new(columns).unwrap();
+    assert!(with_null
+        .clone()
+        .with_constraints(vec![non_null("id")])
+        .is_err());
+    // Nulls are ignored by unique and monotonic
+    assert!(with_null
+        .with_constraints(vec![unique("id"), monotonic("id")])
+        .is_ok());
+
+    let unsorted = df!("ts" => [2.0, 1.0]).unwrap();
+    assert!(unsorted.with_constraints(vec![monotonic("ts")]).is_err());
+    assert!(matches!(
+        df!("a" => [1]).unwrap().with_constraints(vec![unique("b")]),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_append_keeps_and_rechecks_constraints() {
+    let _guard = lock();
+    let appended = events()
+        .append(&df!("id" => [4], "ts" => [25]).unwrap())
+        .unwrap();
+    assert_eq!(appended.row_count(), 4);
+    assert_eq!(appended.constraints().len(), 3);
+
+    assert!(events()
+        .append(&df!("id" => [3], "ts" => [30]).unwrap())
+        .is_err());
+    assert!(events()
+        .append(&df!("id" => [4], "ts" => [5]).unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_derived_frames_keep_constraints() {
+    let _guard = lock();
+    let all = [non_null("id"), unique("id"), monotonic("ts")];
+
+    let filtered = events()
+        .filter(&Condition::Gt("id".to_string(), Value::I32(1)))
+        .unwrap();
+    assert_eq!(filtered.row_count(), 2);
+    assert_eq!(filtered.constraints(), &all);
+    // The filtered frame still rejects rows that break them
+    assert!(filtered
+        .append(&df!("id" => [2], "ts" => [30]).unwrap())
+        .is_err());
+    assert!(filtered
+        .append(&df!("id" => [4], "ts" => [5]).unwrap())
+        .is_err());
+    assert!(filtered
+        .append(&df!("id" => [4], "ts" => [30]).unwrap())
+        .is_ok());
+
+    // Constraints on removed columns go with them
+    let ids = events().select_columns(vec!["id".to_string()]).unwrap();
+    assert_eq!(ids.constraints(), &[non_null("id"), unique("id")]);
+    assert_eq!(events().shrink_dtypes().0.constraints(), &all);
+
+    // A sort keeps monotonic only if the new order satisfies it
+    let ascending = events().sort(vec!["id".to_string()], true).unwrap();
+    assert_eq!(ascending.constraints(), &all);
+    let descending = events().sort(vec!["id".to_string()], false).unwrap();
+    assert_eq!(descending.constraints(), &[non_null("id"), unique("id")]);
+    // Repeated rows no longer satisfy unique
+    let repeated = events().filter_by_indices(&[0, 0, 2]).unwrap();
+    assert_eq!(repeated.constraints(), &[non_null("id"), monotonic("ts")]);
+}
+
+#[test]
+fn test_in_place_edits_recheck_constraints() {
+    let _guard = lock();
+    let mut df = events();
+
+    // A rejected edit leaves the frame as it was
+    assert!(df.set_value(1, "id", Value::I32(1)).is_err());
+    assert!(df.set_value(0, "id", Value::Null).is_err());
+    assert!(df.set_value(2, "ts", Value::I32(5)).is_err());
+    assert_eq!(
+        df.get_column("id").unwrap().get_data_i32().unwrap(),
+        vec![Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        df.get_column("ts").unwrap().get_value(2),
+        Some(Value::I32(20))
+    );
+    df.set_value(2, "id", Value::I32(30)).unwrap();
+    assert_eq!(
+        df.get_column("id").unwrap().get_value(2),
+        Some(Value::I32(30))
+    );
+
+    let descending = Series::new_i32("ts", vec![Some(3), Some(2), Some(1)]);
+    assert!(df.replace_column(descending).is_err());
+    assert_eq!(
+        df.get_column("ts").unwrap().get_data_i32().unwrap(),
+        vec![Some(10), Some(10), Some(20)]
+    );
+    let shifted = Series::new_i32("ts", vec![Some(11), Some(11), Some(21)]);
+    assert_eq!(
+        df.replace_column(shifted).unwrap().get_value(0),
+        Some(Value::I32(10))
+    );
+
+    // A new column has no constraints, so the existing ones still hold
+    df.insert_column(Series::new_string("name", vec![None, None, None]))
+        .unwrap();
+    assert_eq!(df.constraints().len(), 3);
+
+    let repeated = df!("id" => [2], "ts" => [30], "name" => ["d"]).unwrap();
+    assert!(df.extend(&repeated).is_err());
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.get_column("name").unwrap().len(), 3);
+    df.extend(&df!("id" => [4], "ts" => [30], "name" => ["d"]).unwrap())
+        .unwrap();
+    assert_eq!(df.row_count(), 4);
+}
+
+#[test]
+fn test_update_where_rechecks_constraints() {
+    let _guard = lock();
+    let late = Condition::Gt("id".to_string(), Value::I32(2));
+    let updated = events()
+        .update_where(&late, "ts", &Expr::Literal(Value::I32(40)))
+        .unwrap();
+    assert_eq!(updated.constraints().len(), 3);
+    assert!(events()
+        .update_where(&late, "ts", &Expr::Literal(Value::I32(0)))
+        .is_err());
+    assert!(events()
+        .update_where(&late, "id", &Expr::Literal(Value::Null))
+        .is_err());
+}
+
+#[test]
+fn test_join_checks_constraints_of_both_sides() {
+    let _guard = lock();
+    let users = df!("id" => [1, 2], "ts" => [1, 2])
+        .unwrap()
+        .with_constraints(vec![unique("ts")])
+        .unwrap();
+    let joined = events().join(&users, "id", JoinType::Inner).unwrap();
+    // The right frame's ts is suffixed, and its constraint follows it
+    assert!(joined.constraints().contains(&unique("ts_right")));
+    assert!(joined.constraints().contains(&monotonic("ts")));
+
+    // Two events per user break the unique id declared on the left
+    let visits = df!("id" => [1, 1], "page" => ["a", "b"]).unwrap();
+    assert!(events().join(&visits, "id", JoinType::Inner).is_err());
+
+    // A left join leaves nulls in a right-side non_null column
+    let names = df!("id" => [1], "name" => ["a"])
+        .unwrap()
+        .with_constraints(vec![non_null("name")])
+        .unwrap();
+    let options = JoinOptions::default();
+    assert!(events()
+        .join_with_options(&names, "id", JoinType::Left, &options)
+        .is_err());
+    assert!(events()
+        .join_with_options(&names, "id", JoinType::Inner, &options)
+        .is_ok());
+}
+
+#[test]
+fn test_checks_can_be_switched_off() {
+    let _guard = lock();
+    constraints::set_check_constraints(false);
+    let duplicated = df!("id" => [1, 1])
+        .unwrap()
+        .with_constraints(vec![unique("id")]);
+    let missing = df!("id" => [1])
+        .unwrap()
+        .with_constraints(vec![unique("other")]);
+    constraints::set_check_constraints(true);
+
+    // Unchecked constraints are still carried as metadata
+    let duplicated = duplicated.unwrap();
+    assert_eq!(duplicated.constraints(), &[unique("id")]);
+    assert!(duplicated.validate_constraints().is_err());
+    assert!(missing.is_err());
+}
+
+#[test]
+fn test_constraint_display() {
+    assert_eq!(monotonic("ts").to_string(), "monotonic(\"ts\")");
+    assert_eq!(ColumnConstraint::NonNull("id".to_string()).column(), "id");
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::error::VeloxxError;
+use veloxx::series::Series;
+use veloxx::types::{DataType, Value};
+
+#[test]
+fn test_try_from_records() {
+    let rows = vec![
+        HashMap::from([
+            ("id".to_string(), Value::I32(1)),
+            ("name".to_string(), Value::String("a".to_string())),
+        ]),
+        HashMap::from([
+            ("id".to_string(), Value::F64(2.5)),
+            ("flag".to_string(), Value::Bool(true)),
+        ]),
+    ];
+    let df = DataFrame::try_from(rows).unwrap();
+
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(df.column_count(), 3);
+    let id = df.get_column("id").unwrap();
+    assert_eq!(id.data_type(), DataType::F64);
+    assert_eq!(id.get_value(0), Some(Value::F64(1.0)));
+    assert_eq!(df.get_column("name").unwrap().get_value(1), None);
+    assert_eq!(df.get_column("flag").unwrap().get_value(0), None);
+}
+
+#[test]
+fn test_try_from_records_type_conflict() {
+    let rows = vec![
+        HashMap::from([("a".to_string(), Value::I32(1))]),
+        HashMap::from([("a".to_string(), Value::String("x".to_string()))]),
+    ];
+    assert!(matches!(
+        DataFrame::try_from(rows),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_from_named_f64_columns() {
+    let df = DataFrame::try_from(vec![
+        ("x".to_string(), vec![1.0, 2.0]),
+        ("y".to_string(), vec![3.0, 4.0]),
+    ])
+    .unwrap();
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(
+        df.get_column("y").unwrap().get_value(1),
+        Some(Value::F64(4.0))
+    );
+
+    let ragged = vec![
+        ("x".to_string(), vec![1.0, 2.0]),
+        ("y".to_string(), vec![3.0]),
+    ];
+    assert!(DataFrame::try_from(ragged).is_err());
+    let repeated = vec![("x".to_string(), vec![1.0]), ("x".to_string(), vec![2.0])];
+    assert!(DataFrame::try_from(repeated).is_err());
+}
+
+#[test]
+fn test_collect_row_tuples() {
+    let df: DataFrame = vec![(1, "a", Some(0.5)), (2, "b", None)]
+        .into_iter()
+        .collect();
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(
+        df.get_column("column_1").unwrap().get_value(1),
+        Some(Value::String("b".to_string()))
+    );
+    assert_eq!(df.get_column("column_2").unwrap().get_value(1), None);
+}
+
+#[test]
+fn test_series_from_options() {
+    let mut series = Series::from(vec![Some(1), None, Some(3)]);
+    series.set_name("a");
+    assert_eq!(series.name(), "a");
+    assert_eq!(series.data_type(), DataType::I32);
+    assert_eq!(series.get_value(1), None);
+
+    let strings = Series::from(vec![Some("x"), None]);
+    assert_eq!(strings.data_type(), DataType::String);
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "crypto")]
+
+use veloxx::df;
+use veloxx::series::crypto::{EncryptionKey, HashAlgorithm};
+use veloxx::series::Series;
+use veloxx::types::{DataType, Value};
+use veloxx::VeloxxError;
+
+#[test]
+fn test_hash_values() {
+    let ids = Series::new_string(
+        "id",
+        vec![Some("abc".to_string()), None, Some("abc".to_string())],
+    );
+    let sha = ids.hash_values(HashAlgorithm::Sha256, b"").unwrap();
+    assert_eq!(sha.data_type(), DataType::String);
+    assert_eq!(
+        sha.get_value(0),
+        Some(Value::String(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad".to_string()
+        ))
+    );
+    assert_eq!(sha.get_value(1), None);
+    assert_eq!(sha.get_value(0), sha.get_value(2));
+
+    // The salt changes every hash
+    let salted = ids.hash_values(HashAlgorithm::Sha256, b"pepper").unwrap();
+    assert_ne!(salted.get_value(0), sha.get_value(0));
+
+    let xx = ids.hash_values(HashAlgorithm::XxHash64, b"").unwrap();
+    assert_eq!(
+        xx.get_value(0),
+        Some(Value::String("44bc2cf5ad770999".to_string()))
+    );
+
+    // Non-text values hash by their display text
+    let numbers = Series::new_i32("n", vec![Some(42)]);
+    let text = Series::new_string("n", vec![Some("42".to_string())]);
+    assert_eq!(
+        numbers.hash_values(HashAlgorithm::XxHash64, b"s").unwrap(),
+        text.hash_values(HashAlgorithm::XxHash64, b"s").unwrap()
+    );
+}
+
+#[test]
+fn test_encrypt_round_trips_every_type() {
+    let key = EncryptionKey::generate();
+    let columns = [
+        Series::new_i32("a", vec![Some(-7), None, Some(i32::MAX)]),
+        Series::new_f64("b", vec![Some(1.25), Some(f64::MIN), None]),
+        Series::new_bool("c", vec![None, Some(true), Some(false)]),
+        Series::new_string(
+            "d",
+            vec![Some("ünïcode".to_string()), Some(String::new()), None],
+        ),
+        Series::new_datetime("e", vec![Some(1_700_000_000), None, Some(-1)]),
+        Series::new_binary("f", vec![Some(vec![0, 255]), None, Some(Vec::new())]),
+    ];
+    for series in columns {
+        let encrypted = series.encrypt(&key).unwrap();
+        assert_eq!(encrypted.data_type(), DataType::Binary);
+        assert_eq!(encrypted.decrypt(&key).unwrap(), series);
+    }
+
+    // Fresh nonces make equal values encrypt differently
+    let same = Series::new_string("s", vec![Some("x".to_string()), Some("x".to_string())]);
+    let encrypted = same.encrypt(&key).unwrap();
+    assert_ne!(encrypted.get_value(0), encrypted.get_value(1));
+}
+
+#[test]
+fn test_decrypt_rejects_wrong_keys_and_tampering() {
+    let key = EncryptionKey::from_bytes(&[7; 32]).unwrap();
+    let restored = EncryptionKey::from_base64(&key.to_base64()).unwrap();
+    assert_eq!(format!("{:?}", key), "EncryptionKey(..)");
+    assert!(EncryptionKey::from_bytes(&[7; 16]).is_err());
+
+    let secrets = Series::new_string("secret", vec![Some("hunter2".to_string())]);
+    let encrypted = secrets.encrypt(&key).unwrap();
+    assert_eq!(encrypted.decrypt(&restored).unwrap(), secrets);
+    assert!(matches!(
+        encrypted.decrypt(&EncryptionKey::generate()),
+        Err(VeloxxError::Parsing(_))
+    ));
+
+    let Some(Value::Binary(mut envelope)) = encrypted.get_value(0) else {
+        panic!("expected a binary envelope");
+    };
+    // Claiming another source type fails authentication
+    envelope[1] = 5;
+    let tampered = Series::new_binary("secret", vec![Some(envelope)]);
+    assert!(tampered.decrypt(&key).is_err());
+    assert!(matches!(
+        secrets.decrypt(&key),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_dataframe_column_helpers() {
+    let users = df!(
+        "email" => ["a@x.io", "b@x.io", "a@x.io"],
+        "salary" => [50_000, 60_000, 70_000],
+        "visits" => [3, 1, 2],
+    )
+    .unwrap();
+    let key = EncryptionKey::generate();
+
+    let export = users
+        .hash_columns(&["email"], HashAlgorithm::Sha256, b"pepper")
+        .unwrap()
+        .encrypt_columns(&["salary"], &key)
+        .unwrap();
+    assert_eq!(
+        export.get_column("salary").unwrap().data_type(),
+        DataType::Binary
+    );
+    assert_eq!(export.get_column("visits"), users.get_column("visits"));
+    let email = export.get_column("email").unwrap();
+    assert_eq!(email.get_value(0), email.get_value(2));
+
+    let restored = export.decrypt_columns(&["salary"], &key).unwrap();
+    assert_eq!(restored.get_column("salary"), users.get_column("salary"));
+    assert!(matches!(
+        users.hash_columns(&["missing"], HashAlgorithm::XxHash64, b""),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
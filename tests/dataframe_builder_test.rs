@@ -0,0 +1,66 @@
+use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::error::VeloxxError;
+use veloxx::types::Value;
+
+#[test]
+fn test_builder_typed_columns() {
+    let df = DataFrame::builder()
+        .col_i32("a", [Some(1), None, Some(3)])
+        .col_str("b", ["x", "y", "z"])
+        .col_f64("c", vec![1.5, 2.5, 3.5])
+        .col_bool("d", [true, false, true])
+        .col_str("e", [Some("p"), None, Some("q")])
+        .col_str("f", vec![None, Some("r".to_string()), None])
+        .build()
+        .unwrap();
+
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.column_count(), 6);
+    assert_eq!(df.get_column("a").unwrap().get_value(1), None);
+    assert_eq!(df.get_column("e").unwrap().get_value(1), None);
+    assert_eq!(
+        df.get_column("f").unwrap().get_value(1),
+        Some(Value::String("r".to_string()))
+    );
+    assert_eq!(
+        df.get_column("b").unwrap().get_value(2),
+        Some(Value::String("z".to_string()))
+    );
+}
+
+#[test]
+fn test_builder_rejects_duplicates_and_mismatched_lengths() {
+    let err = DataFrame::builder()
+        .col_i32("a", [1])
+        .col_i32("a", [2])
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, VeloxxError::InvalidOperation(_)));
+
+    assert!(DataFrame::builder()
+        .col_i32("a", [1, 2])
+        .col_f64("b", [1.0])
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_df_macro() {
+    let df = df!(
+        "id" => [1, 2, 3],
+        "name" => [Some("a"), None, Some("c")],
+        "score" => vec![0.5, 1.5, 2.5],
+    )
+    .unwrap();
+
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(
+        df.get_column("id").unwrap().get_value(0),
+        Some(Value::I32(1))
+    );
+    assert_eq!(df.get_column("name").unwrap().get_value(1), None);
+
+    let empty = df!().unwrap();
+    assert_eq!(empty.column_count(), 0);
+}
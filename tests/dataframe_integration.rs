@@ -57,6 +57,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dataframe_with_columns() {
+        let mut columns = HashMap::new();
+        columns.insert(
+            "a".to_string(),
+            Series::new_i32("a", vec![Some(1), Some(2), Some(3)]),
+        );
+        columns.insert(
+            "b".to_string(),
+            Series::new_f64("b", vec![Some(0.5), Some(1.5), Some(2.5)]),
+        );
+        let df = DataFrame::new(columns).unwrap();
+        let col = |name: &str| Box::new(Expr::Column(name.to_string()));
+
+        let new_df = df
+            .with_columns(&[
+                ("a2", Expr::Add(col("a"), col("a"))),
+                (
+                    "b_big",
+                    Expr::GreaterThan(col("b"), Box::new(Expr::Literal(Value::F64(1.0)))),
+                ),
+                ("label", Expr::Literal(Value::String("x".to_string()))),
+            ])
+            .unwrap();
+        assert_eq!(new_df.column_count(), 5);
+        assert_eq!(
+            new_df.get_column("a2").unwrap().get_data_i32().unwrap(),
+            vec![Some(2), Some(4), Some(6)]
+        );
+        assert_eq!(
+            new_df.get_column("b_big").unwrap().get_data_bool().unwrap(),
+            vec![Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(
+            new_df.get_column("label").unwrap().get_value(2),
+            Some(Value::String("x".to_string()))
+        );
+
+        // Expressions see the input frame, not each other's outputs
+        assert!(matches!(
+            df.with_columns(&[("c", Expr::Add(col("a"), col("a"))), ("d", *col("c"))]),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+        assert!(matches!(
+            df.with_columns(&[("a", *col("b"))]),
+            Err(VeloxxError::InvalidOperation(_))
+        ));
+        assert!(matches!(
+            df.with_columns(&[("c", *col("a")), ("c", *col("b"))]),
+            Err(VeloxxError::InvalidOperation(_))
+        ));
+        assert_eq!(df.with_columns(&[]).unwrap().column_count(), 2);
+    }
+
     #[test]
     fn test_dataframe_join() {
         // Create left DataFrame
@@ -181,6 +235,206 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dataframe_merge_sorted() {
+        let frame = |ts: Vec<Option<i32>>, source: &str| {
+            let mut columns = HashMap::new();
+            columns.insert(
+                "source".to_string(),
+                Series::new_string("source", vec![Some(source.to_string()); ts.len()]),
+            );
+            columns.insert("ts".to_string(), Series::new_i32("ts", ts));
+            DataFrame::new(columns).unwrap()
+        };
+        let web = frame(vec![None, Some(1), Some(4), Some(6)], "web");
+        let app = frame(vec![Some(2), Some(4), Some(9), Some(10)], "app");
+
+        let merged = web.merge_sorted(&app, "ts").unwrap();
+        let rows: Vec<(Option<Value>, Option<Value>)> = (0..merged.row_count())
+            .map(|i| {
+                (
+                    merged.get_column("ts").unwrap().get_value(i),
+                    merged.get_column("source").unwrap().get_value(i),
+                )
+            })
+            .collect();
+        let row = |ts: Option<i32>, source: &str| {
+            (ts.map(Value::I32), Some(Value::String(source.to_string())))
+        };
+        assert_eq!(
+            rows,
+            vec![
+                row(None, "web"),
+                row(Some(1), "web"),
+                row(Some(2), "app"),
+                row(Some(4), "web"),
+                row(Some(4), "app"),
+                row(Some(6), "web"),
+                row(Some(9), "app"),
+                row(Some(10), "app"),
+            ]
+        );
+
+        let unsorted = frame(vec![Some(3), Some(1)], "app");
+        assert!(matches!(
+            web.merge_sorted(&unsorted, "ts"),
+            Err(VeloxxError::InvalidOperation(_))
+        ));
+        assert!(web.merge_sorted(&app, "missing").is_err());
+        let empty = frame(vec![], "none");
+        assert_eq!(web.merge_sorted(&empty, "ts").unwrap().row_count(), 4);
+    }
+
+    #[test]
+    fn test_dataframe_upsert() {
+        use veloxx::dataframe::upsert::{MergeStrategy, UpsertOptions};
+
+        let frame = |ids: Vec<Option<i32>>, names: Vec<Option<&str>>, scores: Vec<Option<f64>>| {
+            let mut columns = HashMap::new();
+            columns.insert("id".to_string(), Series::new_i32("id", ids));
+            let names = names.into_iter().map(|n| n.map(String::from)).collect();
+            columns.insert("name".to_string(), Series::new_string("name", names));
+            columns.insert("score".to_string(), Series::new_f64("score", scores));
+            DataFrame::new(columns).unwrap()
+        };
+        let current = frame(
+            vec![Some(1), Some(2), None, Some(2)],
+            vec![Some("a"), Some("b"), Some("n"), Some("b2")],
+            vec![Some(1.0), Some(2.0), Some(0.0), None],
+        );
+        let changes = frame(
+            vec![Some(2), Some(3), None],
+            vec![None, Some("c"), Some("m")],
+            vec![Some(20.0), None, Some(9.0)],
+        );
+        let column = |df: &DataFrame, name: &str| -> Vec<Option<Value>> {
+            let series = df.get_column(name).unwrap();
+            (0..df.row_count()).map(|i| series.get_value(i)).collect()
+        };
+        let s = |v: &str| Some(Value::String(v.to_string()));
+
+        // Matching rows take the other frame's values, nulls included
+        let merged = current.upsert(&changes, &["id"]).unwrap();
+        assert_eq!(
+            column(&merged, "id"),
+            vec![Some(1), Some(2), None, Some(2), Some(3), None]
+                .into_iter()
+                .map(|v| v.map(Value::I32))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            column(&merged, "name"),
+            vec![s("a"), None, s("n"), None, s("c"), s("m")]
+        );
+        assert_eq!(column(&merged, "score")[3], Some(Value::F64(20.0)));
+
+        let options = UpsertOptions::default()
+            .merge_strategy("name", MergeStrategy::Coalesce)
+            .merge_strategy("score", MergeStrategy::PreferLeft);
+        let merged = current
+            .upsert_with_options(&changes, &["id"], &options)
+            .unwrap();
+        assert_eq!(
+            column(&merged, "name")[..4],
+            [s("a"), s("b"), s("n"), s("b2")]
+        );
+        assert_eq!(column(&merged, "score")[1], Some(Value::F64(2.0)));
+        assert_eq!(column(&merged, "score")[3], None);
+
+        // Columns the other frame lacks are kept, and null on new rows
+        let mut partial = HashMap::new();
+        partial.insert(
+            "id".to_string(),
+            Series::new_i32("id", vec![Some(1), Some(5)]),
+        );
+        let partial = DataFrame::new(partial).unwrap();
+        let merged = current.upsert(&partial, &["id"]).unwrap();
+        assert_eq!(column(&merged, "name")[0], s("a"));
+        assert_eq!(column(&merged, "name")[4], None);
+
+        assert!(matches!(
+            current.upsert(&current, &["id"]),
+            Err(VeloxxError::InvalidOperation(_))
+        ));
+        assert!(matches!(
+            partial.upsert(&current, &["id"]),
+            Err(VeloxxError::ColumnNotFound(_))
+        ));
+        assert!(current.upsert(&changes, &[]).is_err());
+    }
+
+    #[test]
+    fn test_dataframe_explode() {
+        use veloxx::dataframe::explode::ExplodeOptions;
+
+        let strings = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(String::from)).collect()
+        };
+        let mut columns = HashMap::new();
+        columns.insert(
+            "id".to_string(),
+            Series::new_i32("id", vec![Some(1), Some(2), Some(3), Some(4)]),
+        );
+        columns.insert(
+            "sku".to_string(),
+            Series::new_string("sku", strings(&[Some("a;b"), None, Some(""), Some("c")])),
+        );
+        columns.insert(
+            "qty".to_string(),
+            Series::new_string("qty", strings(&[Some("1;2"), Some("5"), None, None])),
+        );
+        let orders = DataFrame::new(columns).unwrap();
+        let options = ExplodeOptions {
+            delimiter: ";".to_string(),
+            index_column: Some("row".to_string()),
+        };
+
+        let lines = orders
+            .explode_with_options(&["sku", "qty"], &options)
+            .unwrap();
+        let column = |name: &str| -> Vec<Option<Value>> {
+            let series = lines.get_column(name).unwrap();
+            (0..lines.row_count())
+                .map(|i| series.get_value(i))
+                .collect()
+        };
+        let s = |v: &str| Some(Value::String(v.to_string()));
+        let i = |v: i32| Some(Value::I32(v));
+        assert_eq!(column("id"), vec![i(1), i(1), i(2), i(3), i(4)]);
+        assert_eq!(column("row"), vec![i(0), i(0), i(1), i(2), i(3)]);
+        assert_eq!(column("sku"), vec![s("a"), s("b"), None, None, s("c")]);
+        assert_eq!(column("qty"), vec![s("1"), s("2"), s("5"), None, None]);
+
+        let mut columns = HashMap::new();
+        columns.insert(
+            "a".to_string(),
+            Series::new_string("a", strings(&[Some("x,y")])),
+        );
+        columns.insert(
+            "b".to_string(),
+            Series::new_string("b", strings(&[Some("z")])),
+        );
+        let uneven = DataFrame::new(columns).unwrap();
+        assert!(matches!(
+            uneven.explode(&["a", "b"]),
+            Err(VeloxxError::InvalidOperation(_))
+        ));
+        assert_eq!(uneven.explode(&["a"]).unwrap().row_count(), 2);
+        assert!(matches!(
+            orders.explode(&["id"]),
+            Err(VeloxxError::DataTypeMismatch(_))
+        ));
+        assert!(orders
+            .explode_with_options(
+                &["sku"],
+                &ExplodeOptions {
+                    index_column: Some("id".to_string()),
+                    ..ExplodeOptions::default()
+                }
+            )
+            .is_err());
+    }
+
     #[test]
     fn test_series_aggregations() {
         let series_i32 = Series::new_i32("col1", vec![Some(1), Some(2), Some(3), None]);
@@ -193,6 +447,18 @@ mod tests {
         let series_f64 = Series::new_f64("col2", vec![Some(1.0), Some(2.5), None, Some(3.5)]);
         assert_eq!(series_f64.sum().unwrap(), Value::F64(7.0));
         assert_eq!(series_f64.count(), 3);
+        assert_eq!(series_f64.sum_checked().unwrap(), Value::F64(7.0));
+        assert!(series_f64.sum_i64().is_err());
+
+        // Integer sums widen to F64 past i32 range; only the checked form fails
+        let big = Series::new_i32("big", vec![Some(i32::MAX); 4]);
+        assert_eq!(big.sum_i64().unwrap(), 4 * i32::MAX as i64);
+        assert_eq!(big.sum().unwrap(), Value::F64(4.0 * i32::MAX as f64));
+        assert!(big.sum_checked().is_err());
+        assert_eq!(big.mean().unwrap(), Value::F64(i32::MAX as f64));
+        assert_eq!(series_i32.sum_checked().unwrap(), Value::I32(6));
+        let huge = Series::new_f64("huge", vec![Some(f64::MAX), Some(f64::MAX)]);
+        assert!(huge.sum_checked().is_err());
     }
 
     #[test]
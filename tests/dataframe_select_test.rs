@@ -1,7 +1,10 @@
+use std::collections::HashMap;
 use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::error::VeloxxError;
+use veloxx::expressions::Expr;
 use veloxx::series::Series;
-// use veloxx::expressions::Expr;
-use std::collections::HashMap;
+use veloxx::types::Value;
 
 #[test]
 fn test_select() {
@@ -31,3 +34,125 @@ fn test_select() {
     // assert_eq!(c_series.len(), 3);
     // assert_eq!(c_series.get_value(0), Some(veloxx::types::Value::I32(10)));
 }
+
+#[test]
+fn test_select_exprs_with_aliases() {
+    let df = df!("a" => [1, 2, 3], "b" => [4, 5, 6], "unused" => ["x", "y", "z"]).unwrap();
+    let col = |name: &str| Box::new(Expr::Column(name.to_string()));
+
+    let projected = df
+        .select_exprs(&[
+            Expr::Column("a".to_string()),
+            Expr::Column("b".to_string()).alias("b_renamed"),
+            Expr::Multiply(col("a"), col("b")).alias("product"),
+            // Outputs may reuse an input name; expressions still see the input
+            Expr::Add(col("a"), col("a")).alias("b"),
+        ])
+        .unwrap();
+    assert_eq!(projected.column_count(), 4);
+    assert_eq!(projected.row_count(), 3);
+    assert!(projected.get_column("unused").is_none());
+    let values = |name: &str| projected.get_column(name).unwrap().get_data_i32().unwrap();
+    assert_eq!(values("a"), vec![Some(1), Some(2), Some(3)]);
+    assert_eq!(values("b_renamed"), vec![Some(4), Some(5), Some(6)]);
+    assert_eq!(values("product"), vec![Some(4), Some(10), Some(18)]);
+    assert_eq!(values("b"), vec![Some(2), Some(4), Some(6)]);
+    assert_eq!(
+        projected.get_column("b_renamed").unwrap().name(),
+        "b_renamed"
+    );
+
+    let plus_one = Expr::Add(col("a"), Box::new(Expr::Literal(Value::I32(1))));
+    assert_eq!(
+        plus_one.clone().alias("x").evaluate(&df, 0).unwrap(),
+        Value::I32(2)
+    );
+    assert!(matches!(
+        df.select_exprs(std::slice::from_ref(&plus_one)),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.select_exprs(&[Expr::Column("a".to_string()), plus_one.alias("a")]),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.select_exprs(&[Expr::Column("missing".to_string())]),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_column_selectors() {
+    use veloxx::dataframe::selectors::ColumnSelector;
+    use veloxx::types::DataType;
+
+    let df = df!(
+        "device" => ["a", "b"],
+        "sensor_temp" => [20.5, 21.0],
+        "sensor_rpm" => [900, 950],
+        "temp_max" => [30.0, 31.0],
+    )
+    .unwrap();
+    let names = |frame: &DataFrame| {
+        let mut names: Vec<String> = frame.column_names().into_iter().cloned().collect();
+        names.sort();
+        names
+    };
+
+    assert_eq!(
+        names(&df.select_matching("^sensor_").unwrap()),
+        vec!["sensor_rpm", "sensor_temp"]
+    );
+    assert_eq!(
+        names(&df.select_matching("temp").unwrap()),
+        vec!["sensor_temp", "temp_max"]
+    );
+    assert_eq!(
+        names(&df.select_dtypes(&[DataType::F64]).unwrap()),
+        vec!["sensor_temp", "temp_max"]
+    );
+    assert_eq!(
+        names(
+            &df.select_dtypes(&[DataType::I32, DataType::String])
+                .unwrap()
+        ),
+        vec!["device", "sensor_rpm"]
+    );
+    assert_eq!(
+        names(
+            &df.drop_by(&ColumnSelector::Prefix("sensor_".to_string()))
+                .unwrap()
+        ),
+        vec!["device", "temp_max"]
+    );
+    assert_eq!(
+        ColumnSelector::Suffix("_max".to_string())
+            .resolve(&df)
+            .unwrap(),
+        vec!["temp_max"]
+    );
+    assert!(ColumnSelector::Matching("^nothing".to_string())
+        .resolve(&df)
+        .unwrap()
+        .is_empty());
+    assert!(matches!(
+        df.select_matching("(unclosed"),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+
+    // Selected names feed aggregation specs directly
+    let sensors = df
+        .select_dtypes(&[DataType::F64])
+        .unwrap()
+        .column_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    let specs: Vec<(&str, &str)> = sensors.iter().map(|c| (c.as_str(), "max")).collect();
+    let maxima = df
+        .group_by(vec!["device".to_string()])
+        .unwrap()
+        .agg(specs)
+        .unwrap();
+    assert_eq!(maxima.row_count(), 2);
+}
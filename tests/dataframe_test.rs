@@ -76,23 +76,25 @@ fn test_from_csv_nonexistent_file() {
 
 #[test]
 fn test_from_csv_malformed() {
-    let path = "malformed.csv";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("malformed.csv");
+    let path = path.to_str().unwrap();
     std::fs::write(path, "col1,col2\n1,a\n2").unwrap(); // malformed: missing value in row 2
     let result = DataFrame::from_csv(path);
     println!("{:?}", result);
     assert!(result.is_err());
-    std::fs::remove_file(path).unwrap();
 }
 
 #[test]
 fn test_empty_dataframe_to_from_csv() {
     let columns = HashMap::new();
     let df = DataFrame::new(columns).unwrap();
-    let path = "empty.csv";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("empty.csv");
+    let path = path.to_str().unwrap();
     df.to_csv(path).unwrap();
     let df2 = DataFrame::from_csv(path).unwrap();
     assert_eq!(df2.column_names().len(), 0);
-    std::fs::remove_file(path).unwrap();
 }
 
 #[test]
@@ -102,3 +104,799 @@ fn test_get_nonexistent_column() {
     let df = DataFrame::new(columns).unwrap();
     assert!(df.get_column("colX").is_none());
 }
+
+#[test]
+fn test_group_by_count_of_float_column() {
+    let df = veloxx::df!(
+        "city" => ["a", "b", "a"],
+        "sales" => [Some(1.5), Some(2.0), None],
+    )
+    .unwrap();
+    let result = df
+        .group_by(vec!["city".to_string()])
+        .unwrap()
+        .agg(vec![("sales", "count"), ("sales", "sum")])
+        .unwrap()
+        .sort(vec!["city".to_string()], true)
+        .unwrap();
+
+    let counts = result.get_column("sales_count").unwrap();
+    assert_eq!(counts.get_value(0), Some(Value::I32(1)));
+    assert_eq!(counts.get_value(1), Some(Value::I32(1)));
+}
+
+#[test]
+fn test_group_by_rolling_and_expanding() {
+    let df = veloxx::df!(
+        "customer" => ["a", "b", "a", "a", "b", "a"],
+        "amount" => [Some(10), Some(1), None, Some(30), Some(2), Some(5)],
+    )
+    .unwrap();
+    let column = |result: &DataFrame, name: &str| -> Vec<Option<Value>> {
+        let series = result.get_column(name).unwrap();
+        (0..series.len()).map(|i| series.get_value(i)).collect()
+    };
+    let ints = |values: &[Option<i32>]| -> Vec<Option<Value>> {
+        values.iter().map(|v| v.map(Value::I32)).collect()
+    };
+
+    let expanding = df
+        .group_by(vec!["customer".to_string()])
+        .unwrap()
+        .expanding()
+        .agg(vec![
+            ("amount", "sum"),
+            ("amount", "max"),
+            ("amount", "count"),
+        ])
+        .unwrap();
+    assert_eq!(expanding.row_count(), 6);
+    assert_eq!(
+        column(&expanding, "customer"),
+        column(&df, "customer"),
+        "rows keep their order"
+    );
+    assert_eq!(
+        column(&expanding, "amount_expanding_sum"),
+        ints(&[Some(10), Some(1), Some(10), Some(40), Some(3), Some(45)])
+    );
+    assert_eq!(
+        column(&expanding, "amount_expanding_max"),
+        ints(&[Some(10), Some(1), Some(10), Some(30), Some(2), Some(30)])
+    );
+    assert_eq!(
+        column(&expanding, "amount_expanding_count"),
+        ints(&[Some(1), Some(1), Some(1), Some(2), Some(2), Some(3)])
+    );
+
+    let rolling = df
+        .group_by(vec!["customer".to_string()])
+        .unwrap()
+        .rolling(2)
+        .agg(vec![("amount", "mean"), ("amount", "min")])
+        .unwrap();
+    let mean = |v: f64| Some(Value::F64(v));
+    assert_eq!(
+        column(&rolling, "amount_rolling_mean_2"),
+        vec![None, None, mean(10.0), mean(30.0), mean(1.5), mean(17.5)]
+    );
+    assert_eq!(
+        column(&rolling, "amount_rolling_min_2"),
+        ints(&[None, None, Some(10), Some(30), Some(1), Some(5)])
+    );
+
+    let grouped = || df.group_by(vec!["customer".to_string()]).unwrap();
+    assert!(grouped().rolling(0).agg(vec![("amount", "sum")]).is_err());
+    assert!(grouped()
+        .expanding()
+        .agg(vec![("amount", "median")])
+        .is_err());
+    assert!(grouped()
+        .expanding()
+        .agg(vec![("customer", "sum")])
+        .is_err());
+}
+
+#[test]
+fn test_normalize_and_normalize_over() {
+    use veloxx::dataframe::normalize::NormalizeMethod;
+
+    let df = veloxx::df!(
+        "region" => [Some("n"), Some("n"), Some("s"), Some("s"), None],
+        "revenue" => [Some(10), Some(30), None, Some(60), Some(7)],
+        "label" => ["a", "b", "c", "d", "e"],
+    )
+    .unwrap();
+    let column = |result: &DataFrame| -> Vec<Option<f64>> {
+        result
+            .get_column("revenue")
+            .unwrap()
+            .get_data_f64()
+            .unwrap()
+    };
+
+    let min_max = df.normalize(&["revenue"], NormalizeMethod::MinMax).unwrap();
+    let expected = [3.0 / 53.0, 23.0 / 53.0, 53.0 / 53.0, 0.0];
+    let actual = column(&min_max);
+    assert_eq!(actual[2], None);
+    for (actual, expected) in [actual[0], actual[1], actual[3], actual[4]]
+        .iter()
+        .zip(expected)
+    {
+        assert!((actual.unwrap() - expected).abs() < 1e-12);
+    }
+    assert_eq!(min_max.get_column("label"), df.get_column("label"));
+
+    let z = column(&df.normalize(&["revenue"], NormalizeMethod::ZScore).unwrap());
+    let present: Vec<f64> = z.iter().flatten().copied().collect();
+    assert!(present.iter().sum::<f64>().abs() < 1e-12);
+
+    let shares = df
+        .normalize_over(&["revenue"], NormalizeMethod::PercentOfTotal, &["region"])
+        .unwrap();
+    assert_eq!(
+        column(&shares),
+        vec![Some(25.0), Some(75.0), None, Some(100.0), Some(100.0)]
+    );
+    // Single-value groups have no spread, so their z-scores are undefined
+    let grouped_z = df
+        .normalize_over(&["revenue"], NormalizeMethod::ZScore, &["region"])
+        .unwrap();
+    assert_eq!(column(&grouped_z)[3], None);
+
+    assert!(df.normalize(&["label"], NormalizeMethod::MinMax).is_err());
+    assert!(df
+        .normalize_over(&["revenue"], NormalizeMethod::MinMax, &["missing"])
+        .is_err());
+}
+
+#[test]
+fn test_cumulative_aggregations() {
+    use veloxx::dataframe::time_series::{CumulativeOp, CumulativeOptions};
+
+    let df = veloxx::df!(
+        "store" => ["a", "b", "a", "a", "b"],
+        "day" => [3, 2, 1, 2, 1],
+        "units" => [Some(5), Some(7), Some(2), None, Some(3)],
+        "price" => [1.5, 2.0, 0.5, 4.0, 1.0],
+    )
+    .unwrap();
+    let column = |result: &DataFrame, name: &str| -> Vec<Option<Value>> {
+        let series = result.get_column(name).unwrap();
+        (0..series.len()).map(|i| series.get_value(i)).collect()
+    };
+    let ints = |values: &[Option<i32>]| -> Vec<Option<Value>> {
+        values.iter().map(|v| v.map(Value::I32)).collect()
+    };
+
+    // Frame order, nulls skipped
+    let summed = df.cum_sum(&["units"]).unwrap();
+    assert_eq!(
+        column(&summed, "units_cum_sum"),
+        ints(&[Some(5), Some(12), Some(14), None, Some(17)])
+    );
+    assert_eq!(
+        column(&df.cum_max(&["units"]).unwrap(), "units_cum_max"),
+        ints(&[Some(5), Some(7), Some(7), None, Some(7)])
+    );
+    assert_eq!(
+        column(&df.cum_min(&["price"]).unwrap(), "price_cum_min"),
+        [1.5, 1.5, 0.5, 0.5, 0.5]
+            .map(|v| Some(Value::F64(v)))
+            .to_vec()
+    );
+
+    let options = CumulativeOptions {
+        order_by: Some("day".to_string()),
+        partition_by: vec!["store".to_string()],
+    };
+    let per_store = df
+        .cumulative(&["units", "price"], CumulativeOp::Prod, &options)
+        .unwrap();
+    // Store a runs days 1, 2, 3 and store b days 1, 2
+    assert_eq!(
+        column(&per_store, "units_cum_prod"),
+        ints(&[Some(10), Some(21), Some(2), None, Some(3)])
+    );
+    assert_eq!(
+        column(&per_store, "price_cum_prod"),
+        [3.0, 2.0, 0.5, 2.0, 1.0]
+            .map(|v| Some(Value::F64(v)))
+            .to_vec()
+    );
+    assert_eq!(column(&per_store, "store"), column(&df, "store"));
+
+    let big = veloxx::df!("n" => [i32::MAX, 1]).unwrap();
+    assert!(big.cum_sum(&["n"]).is_err());
+    assert!(df.cum_sum(&["store"]).is_err());
+}
+
+#[test]
+fn test_describe_typed_statistics() {
+    use veloxx::dataframe::describe::DescribeOptions;
+
+    let df = veloxx::df!(
+        "age" => [Some(20), Some(30), Some(25), None, Some(35)],
+        "score" => [Some(1.0), None, None, None, None],
+        "city" => [Some("NY"), Some("LA"), None, Some("SF"), None],
+    )
+    .unwrap();
+    let column = |result: &DataFrame, name: &str| -> Vec<Option<Value>> {
+        let series = result.get_column(name).unwrap();
+        (0..series.len()).map(|i| series.get_value(i)).collect()
+    };
+    let floats = |values: &[Option<f64>]| -> Vec<Option<Value>> {
+        values.iter().map(|v| v.map(Value::F64)).collect()
+    };
+
+    let summary = df
+        .describe_with_options(&DescribeOptions {
+            percentiles: vec![0.1, 0.5, 0.999],
+        })
+        .unwrap();
+    let labels: Vec<Option<Value>> = [
+        "count",
+        "null_count",
+        "mean",
+        "std",
+        "min",
+        "10%",
+        "50%",
+        "99.9%",
+        "max",
+    ]
+    .iter()
+    .map(|label| Some(Value::String(label.to_string())))
+    .collect();
+    assert_eq!(column(&summary, "statistic"), labels);
+
+    let age = column(&summary, "age");
+    assert_eq!(age[..3], floats(&[Some(4.0), Some(1.0), Some(27.5)]));
+    assert_eq!(age[4..7], floats(&[Some(20.0), Some(21.5), Some(27.5)]));
+    assert_eq!(age[8], Some(Value::F64(35.0)));
+    // A single value has no sample standard deviation
+    assert_eq!(
+        column(&summary, "score"),
+        floats(&[
+            Some(1.0),
+            Some(4.0),
+            Some(1.0),
+            None,
+            Some(1.0),
+            Some(1.0),
+            Some(1.0),
+            Some(1.0),
+            Some(1.0)
+        ])
+    );
+    assert_eq!(
+        column(&summary, "city"),
+        floats(&[
+            Some(3.0),
+            Some(2.0),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        ])
+    );
+
+    assert_eq!(df.describe().unwrap().row_count(), 9);
+    assert!(df
+        .describe_with_options(&DescribeOptions {
+            percentiles: vec![1.5],
+        })
+        .is_err());
+
+    let dtypes = df.dtype_summary().unwrap();
+    assert_eq!(
+        column(&dtypes, "dtype"),
+        ["F64", "I32", "String"]
+            .iter()
+            .map(|t| Some(Value::String(t.to_string())))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        column(&dtypes, "null_count"),
+        vec![
+            Some(Value::I32(4)),
+            Some(Value::I32(1)),
+            Some(Value::I32(2))
+        ]
+    );
+}
+
+#[test]
+fn test_to_html_truncates_and_escapes() {
+    let df = veloxx::df!(
+        "a" => (0..10).collect::<Vec<i32>>(),
+        "b" => (0..10).map(|i| if i == 0 { None } else { Some("<x>") }).collect::<Vec<_>>(),
+        "c" => [1.5; 10],
+    )
+    .unwrap();
+
+    let html = df.to_html(4, 2);
+    assert!(html.contains("<th>a</th><th>…</th><th>c</th>"));
+    assert!(html.contains("<th>I32</th><th>…</th><th>F64</th>"));
+    assert!(html.contains("<tr><th>1</th>"));
+    assert!(!html.contains("<tr><th>5</th>"));
+    assert!(html.contains("<tr><th>9</th>"));
+    assert!(html.contains("10 rows × 3 columns"));
+
+    let html = df.to_html(10, 10);
+    assert!(html.contains("<td class=\"null\">null</td>"));
+    assert!(html.contains("&lt;x&gt;"));
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let df = veloxx::df!(
+        "i" => [Some(1), None, Some(3)],
+        "f" => [Some(f64::NAN), Some(2.5), None],
+        "s" => [Some("a"), None, Some("c")],
+        "b" => [true, false, true],
+    )
+    .unwrap();
+
+    let bytes = df.to_bytes();
+    let restored = DataFrame::from_bytes(&bytes).unwrap();
+    assert_eq!(restored.row_count(), 3);
+    for name in ["i", "s", "b"] {
+        assert_eq!(restored.get_column(name), df.get_column(name));
+    }
+    let f = restored.get_column("f").unwrap();
+    assert!(matches!(f.get_value(0), Some(Value::F64(v)) if v.is_nan()));
+    assert_eq!(f.get_value(2), None);
+
+    assert!(DataFrame::from_bytes(b"not a frame").is_err());
+    assert!(DataFrame::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_dataframe_stats() {
+    let df = veloxx::df!(
+        "id" => [Some(3), Some(1), None, Some(3)],
+        "score" => [1.5, f64::NAN, 0.5, 2.0],
+    )
+    .unwrap();
+    let stats = df.stats();
+    assert_eq!(stats[0].name, "id");
+    assert_eq!(stats[0].null_count, 1);
+    assert_eq!(stats[0].n_unique, 2);
+    assert_eq!(stats[0].min, Some(Value::I32(1)));
+    assert_eq!(stats[0].max, Some(Value::I32(3)));
+    assert!(!stats[0].sorted);
+    assert_eq!(stats[1].min, Some(Value::F64(0.5)));
+    assert_eq!(stats[1].max, Some(Value::F64(2.0)));
+
+    // Long columns fall back to a HyperLogLog estimate
+    let values: Vec<Option<i32>> = (0..200_000).map(|i| Some(i % 50_000)).collect();
+    let stats = Series::new_i32("big", values).stats();
+    let error = (stats.n_unique as f64 - 50_000.0).abs() / 50_000.0;
+    assert!(error < 0.05, "estimate {}", stats.n_unique);
+    assert!(!stats.sorted);
+}
+
+#[test]
+fn test_shrink_dtypes() {
+    use veloxx::dataframe::shrink::ShrinkOptions;
+    use veloxx::types::DataType;
+
+    let df = veloxx::df!(
+        "whole" => [Some(1.0), None, Some(-3.0)],
+        "nearly" => [1.0000001, 2.0, 3.0],
+        "huge" => [1e12, 2.0, 3.0],
+        "name" => ["a", "b", "c"],
+    )
+    .unwrap();
+
+    let (shrunk, report) = df.shrink_dtypes();
+    assert_eq!(
+        shrunk.get_column("whole").unwrap().data_type(),
+        DataType::I32
+    );
+    assert_eq!(shrunk.get_column("whole").unwrap().get_value(1), None);
+    assert_eq!(
+        shrunk.get_column("whole").unwrap().get_value(2),
+        Some(Value::I32(-3))
+    );
+    assert_eq!(
+        shrunk.get_column("nearly").unwrap().data_type(),
+        DataType::F64
+    );
+    assert_eq!(
+        shrunk.get_column("huge").unwrap().data_type(),
+        DataType::F64
+    );
+    assert_eq!(report.columns.len(), 1);
+    assert_eq!(report.columns[0].from, DataType::F64);
+    assert!(report.bytes_saved() > 0);
+    assert_eq!(
+        report.bytes_saved(),
+        report.columns[0].bytes_before - report.columns[0].bytes_after
+    );
+
+    let options = ShrinkOptions {
+        float_tolerance: 1e-3,
+    };
+    let (shrunk, report) = df.shrink_dtypes_with_options(&options).unwrap();
+    assert_eq!(
+        shrunk.get_column("nearly").unwrap().get_value(0),
+        Some(Value::I32(1))
+    );
+    assert_eq!(report.columns.len(), 2);
+
+    let bad = ShrinkOptions {
+        float_tolerance: f64::NAN,
+    };
+    assert!(df.shrink_dtypes_with_options(&bad).is_err());
+}
+
+#[test]
+fn test_run_length_encoded_kernels() {
+    use veloxx::conditions::Condition;
+
+    let region: Vec<Option<&str>> = (0..40)
+        .map(|i| match i / 10 {
+            0 => Some("east"),
+            1 => None,
+            2 => Some("north"),
+            _ => Some("west"),
+        })
+        .collect();
+    let df = veloxx::df!(
+        "region" => region,
+        "sales" => (0..40).map(|i| i as f64).collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let keys = df.get_column("region").unwrap();
+    let runs = keys.rle_if_compact().unwrap();
+    assert_eq!(runs.run_count(), 4);
+    assert_eq!(runs.get_value(25), Some(Value::String("north".to_string())));
+    assert_eq!(&runs.to_series(), keys);
+    assert!(df.get_column("sales").unwrap().rle_if_compact().is_none());
+
+    // Filters and group-bys on the compact key agree with the row-wise results
+    let filtered = df
+        .filter(&Condition::Gt(
+            "region".to_string(),
+            Value::String("f".to_string()),
+        ))
+        .unwrap();
+    assert_eq!(filtered.row_count(), 20);
+    assert_eq!(
+        filtered.get_column("sales").unwrap().get_value(0),
+        Some(Value::F64(20.0))
+    );
+    let totals = df
+        .group_by(vec!["region".to_string()])
+        .unwrap()
+        .agg(vec![("sales", "sum")])
+        .unwrap()
+        .sort(vec!["region".to_string()], true)
+        .unwrap();
+    assert_eq!(totals.row_count(), 4);
+    let sums = totals.get_column("sales_sum").unwrap();
+    assert_eq!(sums.get_value(1), Some(Value::F64(45.0)));
+    assert_eq!(sums.get_value(3), Some(Value::F64(345.0)));
+}
+
+#[test]
+fn test_in_place_mutation() {
+    let mut df = veloxx::df!("id" => [1, 2], "name" => ["a", "b"]).unwrap();
+
+    df.set_value(0, "name", Value::String("z".to_string()))
+        .unwrap();
+    assert_eq!(
+        df.get_column("name").unwrap().get_value(0),
+        Some(Value::String("z".to_string()))
+    );
+    assert!(df.set_value(5, "name", Value::Null).is_err());
+    assert!(df.set_value(0, "missing", Value::Null).is_err());
+
+    let old = df
+        .replace_column(Series::new_f64("id", vec![Some(1.5), None]))
+        .unwrap();
+    assert_eq!(old.get_value(1), Some(Value::I32(2)));
+    assert_eq!(
+        df.get_column("id").unwrap().get_value(0),
+        Some(Value::F64(1.5))
+    );
+    assert!(df
+        .replace_column(Series::new_i32("id", vec![Some(1)]))
+        .is_err());
+
+    df.insert_column(Series::new_bool("flag", vec![Some(true), Some(false)]))
+        .unwrap();
+    assert_eq!(df.column_count(), 3);
+    assert!(df
+        .insert_column(Series::new_bool("flag", vec![None, None]))
+        .is_err());
+
+    let more = veloxx::df!(
+        "id" => [Some(3.0)],
+        "name" => ["c"],
+        "flag" => [true],
+    )
+    .unwrap();
+    df.extend(&more).unwrap();
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.get_column("flag").unwrap().len(), 3);
+    assert_eq!(
+        df.get_column("name").unwrap().get_value(2),
+        Some(Value::String("c".to_string()))
+    );
+
+    // A failed extend leaves the frame as it was
+    let wrong = veloxx::df!("id" => [1], "name" => ["d"], "flag" => [true]).unwrap();
+    assert!(df.extend(&wrong).is_err());
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.get_column("name").unwrap().len(), 3);
+}
+
+#[test]
+fn test_boolean_mask_filtering() {
+    let df = veloxx::df!(
+        "score" => [10, 20, 30, 40],
+        "active" => [Some(true), None, Some(true), Some(false)],
+    )
+    .unwrap();
+    let active = df.get_column("active").unwrap();
+    let high = Series::new_bool(
+        "high",
+        vec![Some(false), Some(true), Some(true), Some(true)],
+    );
+
+    let either = (active | &high).unwrap();
+    assert_eq!(
+        either.get_data_bool().unwrap(),
+        vec![Some(true), Some(true), Some(true), Some(true)]
+    );
+    let both = (active & &high).unwrap();
+    assert_eq!(
+        both.get_data_bool().unwrap(),
+        vec![Some(false), None, Some(true), Some(false)]
+    );
+    assert_eq!((!active).unwrap().get_value(1), None);
+    assert!(both.any().unwrap());
+    assert!(!both.all().unwrap());
+    assert!(either.all().unwrap());
+    assert_eq!(both.sum().unwrap(), Value::I32(1));
+    assert_eq!(high.count_true().unwrap(), 3);
+
+    // Null mask entries drop the row; the mask is reusable across frames
+    let filtered = df.filter_mask(&both).unwrap();
+    assert_eq!(filtered.row_count(), 1);
+    assert_eq!(
+        filtered.get_column("score").unwrap().get_value(0),
+        Some(Value::I32(30))
+    );
+    let other = veloxx::df!("id" => [1, 2, 3, 4]).unwrap();
+    assert_eq!(other.filter_mask(&either).unwrap().row_count(), 4);
+
+    assert!(df.filter_mask(df.get_column("score").unwrap()).is_err());
+    assert!(df
+        .filter_mask(&Series::new_bool("short", vec![Some(true)]))
+        .is_err());
+    assert!((active & df.get_column("score").unwrap()).is_err());
+}
+
+#[test]
+fn test_replace_and_map_values() {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "status".to_string(),
+        Series::new_i32("status", vec![Some(1), Some(2), None, Some(1)]),
+    );
+    columns.insert(
+        "city".to_string(),
+        Series::new_string(
+            "city",
+            vec![
+                Some("NYC".to_string()),
+                Some("nyc".to_string()),
+                None,
+                Some("LA".to_string()),
+            ],
+        ),
+    );
+    let df = DataFrame::new(columns).unwrap();
+
+    // Recoding integer codes to labels changes the column type
+    let labelled = df
+        .replace(
+            "status",
+            &[
+                (Value::I32(1), Value::String("open".to_string())),
+                (Value::I32(2), Value::String("closed".to_string())),
+            ],
+        )
+        .unwrap();
+    let status = labelled.get_column("status").unwrap();
+    assert_eq!(status.get_value(0), Some(Value::String("open".to_string())));
+    assert_eq!(
+        status.get_value(1),
+        Some(Value::String("closed".to_string()))
+    );
+    assert_eq!(status.get_value(2), None);
+
+    // Unmapped values keep their type, so a partial recode to strings fails
+    assert!(df
+        .replace(
+            "status",
+            &[(Value::I32(1), Value::String("open".to_string()))]
+        )
+        .is_err());
+    assert!(df.replace("missing", &[]).is_err());
+
+    // A Null key fills nulls; unmapped strings pass through
+    let city = df
+        .get_column("city")
+        .unwrap()
+        .replace(&[
+            (
+                Value::String("nyc".to_string()),
+                Value::String("NYC".to_string()),
+            ),
+            (Value::Null, Value::String("unknown".to_string())),
+        ])
+        .unwrap();
+    assert_eq!(
+        city.get_data_string().unwrap(),
+        vec![
+            Some("NYC".to_string()),
+            Some("NYC".to_string()),
+            Some("unknown".to_string()),
+            Some("LA".to_string()),
+        ]
+    );
+
+    let lengths = df
+        .get_column("city")
+        .unwrap()
+        .map_values(|v| match v {
+            Value::String(s) => Value::I32(s.len() as i32),
+            _ => Value::Null,
+        })
+        .unwrap();
+    assert_eq!(
+        lengths.get_data_i32().unwrap(),
+        vec![Some(3), Some(3), None, Some(2)]
+    );
+}
+
+#[test]
+fn test_geo_distance_and_filters() {
+    use veloxx::dataframe::geo::{haversine_km, BoundingBox, GeoPoint};
+
+    let df = veloxx::df!(
+        "city" => ["Paris", "London", "Berlin", "Unknown", "Suva"],
+        "lat" => [Some(48.8566), Some(51.5074), Some(52.52), None, Some(-18.1248)],
+        "lon" => [Some(2.3522), Some(-0.1278), Some(13.405), Some(0.0), Some(178.4501)],
+    )
+    .unwrap();
+    let paris = GeoPoint::new(48.8566, 2.3522).unwrap();
+    assert!(GeoPoint::new(91.0, 0.0).is_err());
+    assert!((haversine_km(0.0, 0.0, 0.0, 180.0) - std::f64::consts::PI * 6371.0088).abs() < 1e-6);
+
+    let distances = df.geo_distance_km("lat", "lon", &paris).unwrap();
+    assert_eq!(distances.get_value(0), Some(Value::F64(0.0)));
+    match distances.get_value(1) {
+        Some(Value::F64(km)) => assert!((km - 343.5).abs() < 1.0),
+        other => panic!("unexpected distance {other:?}"),
+    }
+    assert_eq!(distances.get_value(3), None);
+
+    let near = df.within_radius("lat", "lon", &paris, 500.0).unwrap();
+    assert_eq!(
+        near.get_data_bool().unwrap(),
+        vec![Some(true), Some(true), Some(false), None, Some(false)]
+    );
+    assert_eq!(df.filter_mask(&near).unwrap().row_count(), 2);
+
+    // Crosses the antimeridian
+    let pacific = BoundingBox {
+        min_lat: -25.0,
+        min_lon: 170.0,
+        max_lat: -10.0,
+        max_lon: -170.0,
+    };
+    let inside = df.in_bounding_box("lat", "lon", &pacific).unwrap();
+    assert_eq!(
+        inside.get_data_bool().unwrap(),
+        vec![Some(false), Some(false), Some(false), None, Some(true)]
+    );
+
+    assert!(df.within_radius("city", "lon", &paris, 1.0).is_err());
+    assert!(df.within_radius("missing", "lon", &paris, 1.0).is_err());
+}
+
+#[test]
+fn test_rename_and_rename_with() {
+    use veloxx::error::VeloxxError;
+    use veloxx::types::DataType;
+
+    let df = veloxx::df!("A" => [1, 2], "B" => [1.5, 2.5], "Order Total" => [10, 20]).unwrap();
+
+    // Renames apply together, so columns can be swapped
+    let swapped = df.rename(&[("A", "B"), ("B", "A")]).unwrap();
+    assert_eq!(swapped.get_column("B").unwrap().data_type(), DataType::I32);
+    assert_eq!(swapped.get_column("A").unwrap().data_type(), DataType::F64);
+    assert_eq!(swapped.get_column("A").unwrap().name(), "A");
+    assert_eq!(swapped.column_count(), 3);
+
+    assert!(matches!(
+        df.rename(&[("missing", "x")]),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+    assert!(matches!(
+        df.rename(&[("A", "B")]),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.rename(&[("A", "x"), ("A", "y")]),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+
+    let snake = df
+        .rename_with(|name| name.to_lowercase().replace(' ', "_"))
+        .unwrap();
+    let mut names: Vec<&String> = snake.column_names();
+    names.sort();
+    assert_eq!(names, vec!["a", "b", "order_total"]);
+    assert_eq!(
+        snake.get_column("order_total").unwrap().get_value(1),
+        Some(Value::I32(20))
+    );
+    assert!(matches!(
+        df.rename_with(|_| "same".to_string()),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn test_deterministic_group_by_orders_groups_by_key() {
+    // Keys spread over more than the dense fast path's range use its hash path
+    let keys: Vec<Option<i32>> = (0..200)
+        .map(|i| match i % 7 {
+            0 => None,
+            k => Some(k * 400_000 - (i % 3)),
+        })
+        .collect();
+    let values: Vec<Option<f64>> = (0..200).map(|i| Some(i as f64)).collect();
+    let mut columns = HashMap::new();
+    columns.insert("k".to_string(), Series::new_i32("k", keys));
+    columns.insert("v".to_string(), Series::new_f64("v", values));
+    let df = DataFrame::new(columns).unwrap();
+
+    let run = || {
+        df.group_by(vec!["k".to_string()])
+            .unwrap()
+            .deterministic(true)
+            .agg(vec![("v", "sum")])
+            .unwrap()
+    };
+    let first = run();
+    let k = first.get_column("k").unwrap();
+    assert_eq!(k.get_value(0), None);
+    let sorted: Vec<Option<Value>> = (1..first.row_count()).map(|i| k.get_value(i)).collect();
+    assert!(sorted.windows(2).all(|w| w[0] < w[1]));
+    for _ in 0..5 {
+        let again = run();
+        assert_eq!(again.get_column("k"), first.get_column("k"));
+        assert_eq!(again.get_column("v_sum"), first.get_column("v_sum"));
+    }
+
+    veloxx::dataframe::set_deterministic(true);
+    let global = df
+        .group_by(vec!["k".to_string()])
+        .unwrap()
+        .agg(vec![("v", "sum")])
+        .unwrap();
+    veloxx::dataframe::set_deterministic(false);
+    assert_eq!(global.get_column("k"), first.get_column("k"));
+    assert_eq!(global.get_column("v_sum"), first.get_column("v_sum"));
+}
@@ -0,0 +1,251 @@
+#![cfg(all(feature = "advanced_io", feature = "arrow"))]
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, Int32Array, Int64Array, ListBuilder, MapBuilder, StringArray, StringBuilder,
+    StructArray,
+};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{Field, Fields};
+use arrow::record_batch::RecordBatch;
+use veloxx::advanced_io::delta::DeltaTable;
+use veloxx::conditions::Condition;
+use veloxx::dataframe::DataFrame;
+use veloxx::io::PartitionFormat;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+const SCHEMA: &str = r#"{"type":"struct","fields":[{"name":"id","type":"integer","nullable":true,"metadata":{}},{"name":"amount","type":"double","nullable":true,"metadata":{}},{"name":"year","type":"long","nullable":true,"metadata":{}}]}"#;
+
+fn add(path: &str, year: &str) -> String {
+    format!(
+        r#"{{"add":{{"path":"{}","partitionValues":{{"year":"{}"}},"size":1,"modificationTime":0,"dataChange":true}}}}"#,
+        path, year
+    )
+}
+
+fn commit(table: &Path, version: u64, actions: &[String]) {
+    let log = table.join("_delta_log");
+    std::fs::create_dir_all(&log).unwrap();
+    std::fs::write(
+        log.join(format!("{:020}.json", version)),
+        actions.join("\n"),
+    )
+    .unwrap();
+}
+
+/// Writes `year=2023` and `year=2024` Parquet files and a three-commit log:
+/// v0 adds 2023, v1 adds 2024, v2 removes 2023.
+fn build_table(table: &Path) {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3)]),
+    );
+    columns.insert(
+        "amount".to_string(),
+        Series::new_f64("amount", vec![Some(1.5), Some(2.5), Some(3.5)]),
+    );
+    columns.insert(
+        "year".to_string(),
+        Series::new_i32("year", vec![Some(2023), Some(2023), Some(2024)]),
+    );
+    DataFrame::new(columns)
+        .unwrap()
+        .write_partitioned(table.to_str().unwrap(), &["year"], PartitionFormat::Parquet)
+        .unwrap();
+
+    commit(
+        table,
+        0,
+        &[
+            r#"{"commitInfo":{"operation":"WRITE"}}"#.to_string(),
+            r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#.to_string(),
+            format!(
+                r#"{{"metaData":{{"id":"t","format":{{"provider":"parquet","options":{{}}}},"schemaString":{},"partitionColumns":["year"],"configuration":{{}}}}}}"#,
+                serde_json::to_string(SCHEMA).unwrap()
+            ),
+            add("year=2023/part-00000.parquet", "2023"),
+        ],
+    );
+    commit(table, 1, &[add("year=2024/part-00000.parquet", "2024")]);
+    commit(
+        table,
+        2,
+        &[r#"{"remove":{"path":"year=2023/part-00000.parquet","dataChange":true}}"#.to_string()],
+    );
+}
+
+#[test]
+fn test_delta_snapshots_and_time_travel() {
+    let dir = tempfile::tempdir().unwrap();
+    build_table(dir.path());
+    let root = dir.path().to_str().unwrap();
+
+    let latest = DeltaTable::open(root).unwrap();
+    assert_eq!(latest.version(), 2);
+    assert_eq!(latest.partition_columns(), ["year".to_string()]);
+    assert_eq!(latest.files().len(), 1);
+    let df = latest.to_dataframe().unwrap();
+    assert_eq!(df.row_count(), 1);
+    assert_eq!(
+        df.get_column("year").unwrap().get_value(0),
+        Some(Value::I32(2024))
+    );
+
+    let v1 = DeltaTable::open_version(root, 1)
+        .unwrap()
+        .to_dataframe()
+        .unwrap();
+    assert_eq!(v1.row_count(), 3);
+    assert_eq!(v1.column_count(), 3);
+    assert_eq!(
+        DeltaTable::open_version(root, 0)
+            .unwrap()
+            .to_dataframe()
+            .unwrap()
+            .row_count(),
+        2
+    );
+    assert!(DeltaTable::open_version(root, 3).is_err());
+    assert!(DeltaTable::open(dir.path().join("year=2023").to_str().unwrap()).is_err());
+}
+
+#[test]
+fn test_delta_partition_pruning() {
+    let dir = tempfile::tempdir().unwrap();
+    build_table(dir.path());
+    let root = dir.path().to_str().unwrap();
+    let table = DeltaTable::open_version(root, 1).unwrap();
+
+    let by_id = table
+        .to_dataframe_filtered(&Condition::Eq("id".to_string(), Value::I32(2)))
+        .unwrap();
+    assert_eq!(by_id.row_count(), 1);
+
+    // With the 2024 file gone, only a pruned read can succeed
+    std::fs::remove_dir_all(dir.path().join("year=2024")).unwrap();
+    let early = table
+        .to_dataframe_filtered(&Condition::Lt("year".to_string(), Value::I32(2024)))
+        .unwrap();
+    assert_eq!(early.row_count(), 2);
+    assert!(table.to_dataframe().is_err());
+
+    let none = table
+        .to_dataframe_filtered(&Condition::Eq("year".to_string(), Value::I32(1999)))
+        .unwrap();
+    assert_eq!(none.row_count(), 0);
+    assert_eq!(none.column_count(), 3);
+}
+
+/// A checkpoint at version 1 holding the protocol, metadata and both `add` actions
+fn write_checkpoint(table: &Path) {
+    let nullable_struct = |fields: Vec<(&str, ArrayRef)>, valid: [bool; 4]| -> ArrayRef {
+        let (fields, arrays): (Vec<Field>, Vec<ArrayRef>) = fields
+            .into_iter()
+            .map(|(name, array)| (Field::new(name, array.data_type().clone(), true), array))
+            .unzip();
+        Arc::new(StructArray::new(
+            Fields::from(fields),
+            arrays,
+            Some(NullBuffer::from(valid.to_vec())),
+        ))
+    };
+
+    let protocol = nullable_struct(
+        vec![(
+            "minReaderVersion",
+            Arc::new(Int32Array::from(vec![Some(1), None, None, None])),
+        )],
+        [true, false, false, false],
+    );
+
+    let mut partition_columns = ListBuilder::new(StringBuilder::new());
+    let mut configuration = MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+    for row in 0..4 {
+        if row == 1 {
+            partition_columns.values().append_value("year");
+        }
+        partition_columns.append(row == 1);
+        configuration.append(row == 1).unwrap();
+    }
+    let metadata = nullable_struct(
+        vec![
+            (
+                "schemaString",
+                Arc::new(StringArray::from(vec![None, Some(SCHEMA), None, None])),
+            ),
+            ("partitionColumns", Arc::new(partition_columns.finish())),
+            ("configuration", Arc::new(configuration.finish())),
+        ],
+        [false, true, false, false],
+    );
+
+    let mut partition_values = MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+    for row in 0..4 {
+        if row >= 2 {
+            partition_values.keys().append_value("year");
+            partition_values
+                .values()
+                .append_value(if row == 2 { "2023" } else { "2024" });
+        }
+        partition_values.append(row >= 2).unwrap();
+    }
+    let add = nullable_struct(
+        vec![
+            (
+                "path",
+                Arc::new(StringArray::from(vec![
+                    None,
+                    None,
+                    Some("year=2023/part-00000.parquet"),
+                    Some("year=2024/part-00000.parquet"),
+                ])),
+            ),
+            ("partitionValues", Arc::new(partition_values.finish())),
+            (
+                "size",
+                Arc::new(Int64Array::from(vec![None, None, Some(1), Some(1)])),
+            ),
+        ],
+        [false, false, true, true],
+    );
+
+    let batch = RecordBatch::try_from_iter(vec![
+        ("protocol", protocol),
+        ("metaData", metadata),
+        ("add", add),
+    ])
+    .unwrap();
+    let file =
+        std::fs::File::create(table.join("_delta_log/00000000000000000001.checkpoint.parquet"))
+            .unwrap();
+    let mut writer = parquet::arrow::ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+}
+
+#[test]
+fn test_delta_checkpoint() {
+    let dir = tempfile::tempdir().unwrap();
+    build_table(dir.path());
+    write_checkpoint(dir.path());
+    for version in [0, 1] {
+        std::fs::remove_file(dir.path().join(format!("_delta_log/{:020}.json", version))).unwrap();
+    }
+    let root = dir.path().to_str().unwrap();
+
+    let v1 = DeltaTable::open_version(root, 1).unwrap();
+    assert_eq!(v1.partition_columns(), ["year".to_string()]);
+    assert_eq!(v1.files().len(), 2);
+    assert_eq!(v1.to_dataframe().unwrap().row_count(), 3);
+
+    let latest = DeltaTable::open(root).unwrap();
+    assert_eq!(latest.version(), 2);
+    assert_eq!(latest.to_dataframe().unwrap().row_count(), 1);
+
+    assert!(DeltaTable::open_version(root, 0).is_err());
+}
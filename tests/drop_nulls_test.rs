@@ -42,3 +42,191 @@ fn test_drop_nulls_with_mixed_data() {
         vec![Some("a".to_string()), Some("d".to_string())]
     );
 }
+
+#[test]
+fn test_coalesce_and_fill_null_with_column() {
+    use veloxx::error::VeloxxError;
+
+    let primary = Series::new_f64("price", vec![Some(1.0), None, None, Some(4.0)]);
+    let fallback = Series::new_f64("list_price", vec![Some(9.0), Some(2.0), None, None]);
+    let coalesced = primary.coalesce(&fallback).unwrap();
+    assert_eq!(coalesced.name(), "price");
+    assert_eq!(
+        coalesced.get_data_f64().unwrap(),
+        vec![Some(1.0), Some(2.0), None, Some(4.0)]
+    );
+    let last_resort = Series::new_f64("zero", vec![Some(0.0); 4]);
+    assert_eq!(
+        coalesced
+            .coalesce(&last_resort)
+            .unwrap()
+            .get_data_f64()
+            .unwrap(),
+        vec![Some(1.0), Some(2.0), Some(0.0), Some(4.0)]
+    );
+    assert!(matches!(
+        primary.coalesce(&Series::new_i32("n", vec![Some(1); 4])),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+    assert!(matches!(
+        primary.coalesce(&Series::new_f64("short", vec![Some(1.0)])),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+
+    let mut columns = HashMap::new();
+    columns.insert("price".to_string(), primary);
+    columns.insert("list_price".to_string(), fallback.clone());
+    let df = DataFrame::new(columns).unwrap();
+    let filled = df.fill_null_with_column("price", "list_price").unwrap();
+    assert_eq!(
+        filled.get_column("price").unwrap().get_data_f64().unwrap(),
+        vec![Some(1.0), Some(2.0), None, Some(4.0)]
+    );
+    assert_eq!(filled.get_column("list_price").unwrap(), &fallback);
+    assert!(matches!(
+        df.fill_null_with_column("price", "missing"),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_interpolation_methods() {
+    use veloxx::error::VeloxxError;
+    use veloxx::series::interpolate::InterpolationMethod;
+    use veloxx::types::Value;
+
+    let s = Series::new_f64(
+        "v",
+        vec![None, Some(0.0), None, None, None, Some(8.0), None],
+    );
+    let fill = |method| s.interpolate(method).unwrap().get_data_f64().unwrap();
+
+    assert_eq!(
+        fill(InterpolationMethod::Linear),
+        vec![
+            None,
+            Some(0.0),
+            Some(2.0),
+            Some(4.0),
+            Some(6.0),
+            Some(8.0),
+            None
+        ]
+    );
+    assert_eq!(
+        fill(InterpolationMethod::Nearest),
+        vec![
+            None,
+            Some(0.0),
+            Some(0.0),
+            Some(0.0),
+            Some(8.0),
+            Some(8.0),
+            None
+        ]
+    );
+    assert_eq!(
+        fill(InterpolationMethod::ForwardFill { limit: None }),
+        vec![
+            None,
+            Some(0.0),
+            Some(0.0),
+            Some(0.0),
+            Some(0.0),
+            Some(8.0),
+            Some(8.0)
+        ]
+    );
+    assert_eq!(
+        fill(InterpolationMethod::ForwardFill { limit: Some(2) }),
+        vec![
+            None,
+            Some(0.0),
+            Some(0.0),
+            Some(0.0),
+            None,
+            Some(8.0),
+            Some(8.0)
+        ]
+    );
+    assert_eq!(
+        fill(InterpolationMethod::BackwardFill { limit: Some(1) }),
+        vec![Some(0.0), Some(0.0), None, None, Some(8.0), Some(8.0), None]
+    );
+    // With two known points the natural spline is the straight line
+    assert_eq!(
+        fill(InterpolationMethod::Spline),
+        fill(InterpolationMethod::Linear)
+    );
+
+    // A spline through points of y = x^2 bends where linear does not
+    let squares = Series::new_f64(
+        "sq",
+        vec![Some(0.0), Some(1.0), None, Some(9.0), Some(16.0)],
+    );
+    let spline = squares
+        .interpolate(InterpolationMethod::Spline)
+        .unwrap()
+        .get_data_f64()
+        .unwrap()[2]
+        .unwrap();
+    assert!((spline - 4.0).abs() < 0.5, "spline gave {spline}");
+    assert_ne!(spline, 5.0);
+
+    // Time-aware: the gap is weighted by timestamp spacing, not row count
+    let ts = Series::new_datetime("ts", vec![Some(0), Some(30), Some(40)]);
+    let level = Series::new_i32("level", vec![Some(0), None, Some(40)]);
+    assert_eq!(
+        level
+            .interpolate_by(InterpolationMethod::Linear, &ts)
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![Some(0), Some(30), Some(40)]
+    );
+    assert_eq!(
+        level
+            .interpolate_by(InterpolationMethod::Nearest, &ts)
+            .unwrap()
+            .get_value(1),
+        Some(Value::I32(40))
+    );
+    let unsorted = Series::new_datetime("ts", vec![Some(10), Some(0), Some(20)]);
+    assert!(matches!(
+        level.interpolate_by(InterpolationMethod::Linear, &unsorted),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+
+    // Fills copy values of any type; numeric methods need numbers
+    let status = Series::new_string("status", vec![Some("up".to_string()), None]);
+    assert_eq!(
+        status
+            .interpolate(InterpolationMethod::ForwardFill { limit: None })
+            .unwrap()
+            .get_value(1),
+        Some(Value::String("up".to_string()))
+    );
+    assert!(status.interpolate(InterpolationMethod::Linear).is_err());
+
+    let mut columns = HashMap::new();
+    columns.insert("ts".to_string(), ts);
+    columns.insert("level".to_string(), level);
+    let df = DataFrame::new(columns).unwrap();
+    let by_time = df
+        .interpolate_by("level", InterpolationMethod::Linear, "ts")
+        .unwrap();
+    assert_eq!(
+        by_time.get_column("level").unwrap().get_value(1),
+        Some(Value::I32(30))
+    );
+    let by_row = df
+        .interpolate("level", InterpolationMethod::Linear)
+        .unwrap();
+    assert_eq!(
+        by_row.get_column("level").unwrap().get_value(1),
+        Some(Value::I32(20))
+    );
+    assert!(df
+        .interpolate_by("level", InterpolationMethod::Linear, "missing")
+        .is_err());
+}
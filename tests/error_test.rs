@@ -22,3 +22,80 @@ fn test_column_not_found() {
     let err = df.select_columns(vec!["a".to_string()]).unwrap_err();
     assert_eq!(err, VeloxxError::ColumnNotFound("a".to_string()));
 }
+
+#[test]
+fn test_error_context_display_and_source() {
+    use std::error::Error;
+    use veloxx::error::ErrorContext;
+
+    let err = VeloxxError::Parsing("bad value".to_string()).with_context(
+        ErrorContext::new()
+            .operation("read_csv")
+            .column("price")
+            .row(3)
+            .file("data.csv")
+            .line(5),
+    );
+    assert_eq!(
+        err.to_string(),
+        "Parsing error: bad value (operation 'read_csv', column 'price', row 3, file data.csv:5)"
+    );
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "Parsing error: bad value"
+    );
+    assert_eq!(
+        err.root_cause(),
+        &VeloxxError::Parsing("bad value".to_string())
+    );
+    assert_eq!(err.context().unwrap().column.as_deref(), Some("price"));
+}
+
+#[test]
+fn test_lazy_pipeline_error_has_context() {
+    use veloxx::lazy::{col, LazyDataFrame};
+
+    let mut columns = HashMap::new();
+    columns.insert("a".to_string(), Series::new_i32("a", vec![Some(1)]));
+    let df = DataFrame::new(columns).unwrap();
+
+    let err = LazyDataFrame::from_dataframe(df)
+        .select(vec![col("missing")])
+        .collect_unoptimized()
+        .unwrap_err();
+    assert_eq!(
+        err.context().and_then(|c| c.operation.as_deref()),
+        Some("projection")
+    );
+    assert_eq!(
+        err.root_cause(),
+        &VeloxxError::ColumnNotFound("missing".to_string())
+    );
+}
+
+#[test]
+fn test_csv_errors_report_file_and_line() {
+    use veloxx::io::UltraFastCsvParser;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("orders.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, "id,amount\n1,9.5\n2\n3,1.0\n").unwrap();
+
+    let err = DataFrame::from_csv(path).unwrap_err();
+    let context = err.context().unwrap();
+    assert_eq!(context.file.as_deref(), Some(path));
+    assert_eq!(context.line, Some(3));
+    assert!(matches!(err.root_cause(), VeloxxError::Parsing(_)));
+    assert!(err.to_string().ends_with(&format!("(file {}:3)", path)));
+
+    let err = UltraFastCsvParser::new().read_file(path).unwrap_err();
+    let context = err.context().unwrap();
+    assert_eq!(context.file.as_deref(), Some(path));
+    assert_eq!(context.line, Some(3));
+
+    let missing = dir.path().join("missing.csv");
+    let err = DataFrame::from_csv(missing.to_str().unwrap()).unwrap_err();
+    assert!(matches!(err.root_cause(), VeloxxError::FileIO(_)));
+    assert!(err.context().unwrap().file.is_some());
+}
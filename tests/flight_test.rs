@@ -0,0 +1,76 @@
+#![cfg(feature = "flight")]
+
+use std::collections::HashMap;
+
+use veloxx::advanced_io::flight::{FlightClient, FlightServer};
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
+use veloxx::types::Value;
+use veloxx::VeloxxError;
+
+fn features(users: Vec<Option<i32>>, scores: Vec<Option<f64>>) -> DataFrame {
+    let mut columns = HashMap::new();
+    columns.insert("user".to_string(), Series::new_i32("user", users));
+    columns.insert("score".to_string(), Series::new_f64("score", scores));
+    DataFrame::new(columns).unwrap()
+}
+
+#[tokio::test]
+async fn test_flight_round_trip() {
+    let server = FlightServer::new();
+    server.insert(
+        "features/daily",
+        features(
+            vec![Some(1), Some(2), Some(3)],
+            vec![Some(0.5), None, Some(0.9)],
+        ),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let serving = tokio::spawn(server.clone().serve_with_listener(listener, async {
+        let _ = stopped.await;
+    }));
+
+    let mut client = FlightClient::connect(&format!("grpc://{}", addr))
+        .await
+        .unwrap();
+    client.add_header("authorization", "Bearer token").unwrap();
+
+    let df = client.do_get("features/daily").await.unwrap();
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.get_column("score").unwrap().get_value(1), None);
+
+    let by_path = client.fetch_path(&["features", "daily"]).await.unwrap();
+    assert_eq!(by_path.row_count(), 3);
+    assert_eq!(
+        by_path.get_column("user").unwrap().get_value(2),
+        Some(Value::I32(3))
+    );
+    let by_command = client.fetch_command("features/daily").await.unwrap();
+    assert_eq!(by_command.column_count(), 2);
+
+    // Frames inserted while serving are visible straight away
+    server.insert("empty", features(vec![], vec![]));
+    let empty = client.fetch_path(&["empty"]).await.unwrap();
+    assert_eq!(empty.row_count(), 0);
+    assert_eq!(empty.column_count(), 2);
+
+    assert!(matches!(
+        client.do_get("missing").await,
+        Err(VeloxxError::FileIO(_))
+    ));
+    assert!(server.remove("features/daily").is_some());
+    assert!(client.fetch_path(&["features", "daily"]).await.is_err());
+
+    stop.send(()).unwrap();
+    serving.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_flight_rejects_tls_endpoints() {
+    assert!(matches!(
+        FlightClient::connect("grpc+tls://localhost:50051").await,
+        Err(VeloxxError::Unsupported(_))
+    ));
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "gpu")]
+
+use std::collections::HashMap;
+use veloxx::conditions::Condition;
+use veloxx::dataframe::DataFrame;
+use veloxx::performance::gpu::{gpu_adapter_name, gpu_available, set_gpu_threshold};
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+// Spans several workgroups with a partial one at the end.
+const LEN: usize = 100_003;
+
+fn i32_series(name: &str, offset: i32) -> Series {
+    Series::new_i32(
+        name,
+        (0..LEN as i32)
+            .map(|i| {
+                if i % 7 == 0 {
+                    None
+                } else {
+                    Some(i % 1000 + offset)
+                }
+            })
+            .collect(),
+    )
+}
+
+fn f64_series(name: &str) -> Series {
+    Series::new_f64(
+        name,
+        (0..LEN)
+            .map(|i| {
+                if i % 5 == 0 {
+                    None
+                } else {
+                    Some(i as f64 * 0.5)
+                }
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_gpu_paths_match_cpu() {
+    if !gpu_available() {
+        // No adapter here; `test_cpu_fallback_without_gpu` covers this machine
+        return;
+    }
+    assert!(gpu_adapter_name().is_some());
+    check_results_match_cpu();
+}
+
+#[test]
+fn test_cpu_fallback_without_gpu() {
+    if gpu_available() {
+        return;
+    }
+    assert_eq!(gpu_adapter_name(), None);
+    check_results_match_cpu();
+}
+
+/// Runs the GPU-routed operations with every column above the threshold and
+/// compares them with values computed directly.
+fn check_results_match_cpu() {
+    set_gpu_threshold(1);
+
+    let a = i32_series("a", 0);
+    let b = i32_series("b", -3);
+    let sum = a.add(&b).unwrap();
+    let product = a.multiply(&b).unwrap();
+    for i in 0..LEN {
+        let expected = a.get_i32(i).zip(b.get_i32(i));
+        assert_eq!(sum.get_i32(i), expected.map(|(x, y)| x + y));
+        assert_eq!(product.get_i32(i), expected.map(|(x, y)| x * y));
+    }
+
+    let expected: i32 = (0..LEN).filter_map(|i| a.get_i32(i)).sum();
+    assert_eq!(a.sum().unwrap(), Value::I32(expected));
+    let count = a.count() as f64;
+    assert_eq!(a.mean().unwrap(), Value::F64(expected as f64 / count));
+
+    let f = f64_series("f");
+    let expected: f64 = (0..LEN).filter_map(|i| f.get_f64(i)).sum();
+    match f.sum().unwrap() {
+        Value::F64(total) => assert!((total - expected).abs() < 1e-6 * expected),
+        other => panic!("unexpected sum {other:?}"),
+    }
+    let doubled = f.add(&f).unwrap();
+    assert_eq!(doubled.get_f64(3), Some(3.0));
+    assert_eq!(doubled.get_f64(5), None);
+
+    let mut columns = HashMap::new();
+    columns.insert("a".to_string(), a.clone());
+    let df = DataFrame::new(columns).unwrap();
+    let filtered = df
+        .filter(&Condition::Gt("a".to_string(), Value::I32(900)))
+        .unwrap();
+    let expected = (0..LEN)
+        .filter(|&i| a.get_i32(i).is_some_and(|v| v > 900))
+        .count();
+    assert_eq!(filtered.row_count(), expected);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "http")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use veloxx::io::http::{CachePolicy, HttpCache};
+use veloxx::types::Value;
+
+const BODY: &str = "city,sales\nParis,10\nOslo,5\n";
+
+/// Serves `BODY` with an ETag, answering matching conditional requests with 304.
+/// Returns the base URL plus counters of full and not-modified responses.
+fn serve() -> (String, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (full, not_modified) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+    let counters = (full.clone(), not_modified.clone());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut revalidating = false;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                revalidating |= line.eq_ignore_ascii_case("if-none-match: \"v1\"\r\n");
+            }
+            let response = if request_line.starts_with("GET /missing") {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string()
+            } else if revalidating {
+                counters.1.fetch_add(1, Ordering::SeqCst);
+                "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n".to_string()
+            } else {
+                counters.0.fetch_add(1, Ordering::SeqCst);
+                format!(
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    BODY.len(),
+                    BODY
+                )
+            };
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    (url, full, not_modified)
+}
+
+#[test]
+fn test_http_cache_revalidates_with_etag() {
+    let (base, full, not_modified) = serve();
+    let dir = tempfile::tempdir().unwrap();
+    let cache = HttpCache::new(dir.path());
+    let url = format!("{}/sales.csv", base);
+
+    let df = cache.read_csv(&url, CachePolicy::Revalidate).unwrap();
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(
+        df.get_column("city").unwrap().get_value(1),
+        Some(Value::String("Oslo".to_string()))
+    );
+    assert!(cache.cached_path(&url).is_some());
+
+    let again = cache.read_csv(&url, CachePolicy::Revalidate).unwrap();
+    assert_eq!(again.row_count(), 2);
+    assert_eq!(full.load(Ordering::SeqCst), 1);
+    assert_eq!(not_modified.load(Ordering::SeqCst), 1);
+
+    // Fresh entries and offline reads never touch the network
+    cache
+        .read_csv(&url, CachePolicy::MaxAge(Duration::from_secs(3600)))
+        .unwrap();
+    cache.read_csv(&url, CachePolicy::Offline).unwrap();
+    assert_eq!(
+        full.load(Ordering::SeqCst) + not_modified.load(Ordering::SeqCst),
+        2
+    );
+
+    cache.read_csv(&url, CachePolicy::NoCache).unwrap();
+    assert_eq!(full.load(Ordering::SeqCst), 2);
+
+    cache.clear().unwrap();
+    assert!(cache.cached_path(&url).is_none());
+    assert!(cache.read_csv(&url, CachePolicy::Offline).is_err());
+    assert!(cache
+        .read_csv(&format!("{}/missing.csv", base), CachePolicy::Revalidate)
+        .is_err());
+}
@@ -0,0 +1,362 @@
+#![cfg(all(feature = "advanced_io", feature = "arrow"))]
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use apache_avro::types::Value as Avro;
+use apache_avro::{Codec, DeflateSettings, Schema, Writer};
+
+use veloxx::advanced_io::iceberg::IcebergTable;
+use veloxx::conditions::Condition;
+use veloxx::dataframe::DataFrame;
+use veloxx::io::PartitionFormat;
+use veloxx::series::Series;
+use veloxx::types::{DataType, Value};
+
+const LOCATION: &str = "s3://lake/events";
+
+const MANIFEST_LIST_SCHEMA: &str = r#"{"type":"record","name":"manifest_file","fields":[
+    {"name":"manifest_path","type":"string"},
+    {"name":"manifest_length","type":"long"},
+    {"name":"partition_spec_id","type":"int"},
+    {"name":"content","type":"int"},
+    {"name":"added_snapshot_id","type":"long"}]}"#;
+
+const MANIFEST_SCHEMA: &str = r#"{"type":"record","name":"manifest_entry","fields":[
+    {"name":"status","type":"int"},
+    {"name":"snapshot_id","type":["null","long"]},
+    {"name":"data_file","type":{"type":"record","name":"r2","fields":[
+        {"name":"content","type":"int"},
+        {"name":"file_path","type":"string"},
+        {"name":"file_format","type":"string"},
+        {"name":"partition","type":{"type":"record","name":"r102","fields":[
+            {"name":"year","type":["null","int"]}]}},
+        {"name":"record_count","type":"long"},
+        {"name":"file_size_in_bytes","type":"long"},
+        {"name":"value_counts","type":["null",{"type":"array","items":{"type":"record",
+            "name":"k117_v118","fields":[{"name":"key","type":"int"},{"name":"value","type":"long"}]}}]},
+        {"name":"null_value_counts","type":["null",{"type":"array","items":"k117_v118"}]},
+        {"name":"lower_bounds","type":["null",{"type":"array","items":{"type":"record",
+            "name":"k126_v127","fields":[{"name":"key","type":"int"},{"name":"value","type":"bytes"}]}}]},
+        {"name":"upper_bounds","type":["null",{"type":"array","items":"k126_v127"}]}]}}]}"#;
+
+/// Writes an Avro object container file holding `records`
+fn write_avro(path: &Path, schema: &str, records: Vec<Avro>, deflate: bool) {
+    let schema = Schema::parse_str(schema).unwrap();
+    let codec = if deflate {
+        Codec::Deflate(DeflateSettings::default())
+    } else {
+        Codec::Null
+    };
+    let mut writer = Writer::with_codec(&schema, Vec::new(), codec).unwrap();
+    for record in records {
+        writer.append_value(record).unwrap();
+    }
+    std::fs::write(path, writer.into_inner().unwrap()).unwrap();
+}
+
+fn record(fields: Vec<(&str, Avro)>) -> Avro {
+    Avro::Record(
+        fields
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    )
+}
+
+fn some(value: Avro) -> Avro {
+    Avro::Union(1, Box::new(value))
+}
+
+fn manifest_file(path: &str, snapshot: i64) -> Avro {
+    record(vec![
+        (
+            "manifest_path",
+            Avro::String(format!("{}/metadata/{}", LOCATION, path)),
+        ),
+        ("manifest_length", Avro::Long(1024)),
+        ("partition_spec_id", Avro::Int(0)),
+        ("content", Avro::Int(0)),
+        ("added_snapshot_id", Avro::Long(snapshot)),
+    ])
+}
+
+/// A manifest entry whose `id` column (field 1) spans `ids`
+fn manifest_entry(status: i32, file: &str, year: i32, rows: i64, ids: (i32, i32)) -> Avro {
+    let by_id = |value: Avro| {
+        some(Avro::Array(vec![record(vec![
+            ("key", Avro::Int(1)),
+            ("value", value),
+        ])]))
+    };
+    let bound = |id: i32| by_id(Avro::Bytes(id.to_le_bytes().to_vec()));
+    record(vec![
+        ("status", Avro::Int(status)),
+        ("snapshot_id", some(Avro::Long(1))),
+        (
+            "data_file",
+            record(vec![
+                ("content", Avro::Int(0)),
+                (
+                    "file_path",
+                    Avro::String(format!("{}/data/{}", LOCATION, file)),
+                ),
+                ("file_format", Avro::String("PARQUET".to_string())),
+                ("partition", record(vec![("year", some(Avro::Int(year)))])),
+                ("record_count", Avro::Long(rows)),
+                ("file_size_in_bytes", Avro::Long(1024)),
+                ("value_counts", by_id(Avro::Long(rows))),
+                ("null_value_counts", by_id(Avro::Long(0))),
+                ("lower_bounds", bound(ids.0)),
+                ("upper_bounds", bound(ids.1)),
+            ]),
+        ),
+    ])
+}
+
+fn write_metadata(table: &Path, version: u32, snapshot_ids: &[i64]) {
+    let snapshots: Vec<String> = snapshot_ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"snapshot-id":{id},"timestamp-ms":0,"manifest-list":"{LOCATION}/metadata/snap-{id}.avro","schema-id":0}}"#
+            )
+        })
+        .collect();
+    let metadata = format!(
+        r#"{{"format-version":2,"table-uuid":"0","location":"{}","last-sequence-number":2,
+        "last-updated-ms":0,"last-column-id":3,"current-schema-id":0,
+        "schemas":[{{"type":"struct","schema-id":0,"fields":[
+            {{"id":1,"name":"id","required":false,"type":"int"}},
+            {{"id":2,"name":"amount","required":false,"type":"double"}},
+            {{"id":3,"name":"year","required":false,"type":"int"}}]}}],
+        "default-spec-id":0,"partition-specs":[{{"spec-id":0,"fields":[
+            {{"name":"year","transform":"identity","source-id":3,"field-id":1000}}]}}],
+        "last-partition-id":1000,"current-snapshot-id":{},"snapshots":[{}]}}"#,
+        LOCATION,
+        snapshot_ids.last().unwrap(),
+        snapshots.join(",")
+    );
+    std::fs::write(
+        table.join(format!("metadata/v{}.metadata.json", version)),
+        metadata,
+    )
+    .unwrap();
+}
+
+/// Writes a table whose snapshot 1 holds `part=a` (ids 1 and 2, year 2023) and
+/// snapshot 2 adds `part=b` (id 3, year 2024). The table metadata records it at
+/// `s3://lake/events`, so every file is found through the relocated root.
+fn build_table(table: &Path) {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "id".to_string(),
+        Series::new_i32("id", vec![Some(1), Some(2), Some(3)]),
+    );
+    columns.insert(
+        "amount".to_string(),
+        Series::new_f64("amount", vec![Some(1.5), Some(2.5), Some(3.5)]),
+    );
+    columns.insert(
+        "year".to_string(),
+        Series::new_i32("year", vec![Some(2023), Some(2023), Some(2024)]),
+    );
+    // Not part of the table schema, so left out of every read
+    columns.insert(
+        "part".to_string(),
+        Series::new_string(
+            "part",
+            vec![
+                Some("a".to_string()),
+                Some("a".to_string()),
+                Some("b".to_string()),
+            ],
+        ),
+    );
+    DataFrame::new(columns)
+        .unwrap()
+        .write_partitioned(
+            table.join("data").to_str().unwrap(),
+            &["part"],
+            PartitionFormat::Parquet,
+        )
+        .unwrap();
+
+    let metadata = table.join("metadata");
+    std::fs::create_dir_all(&metadata).unwrap();
+    write_avro(
+        &metadata.join("m1.avro"),
+        MANIFEST_SCHEMA,
+        vec![manifest_entry(
+            1,
+            "part=a/part-00000.parquet",
+            2023,
+            2,
+            (1, 2),
+        )],
+        false,
+    );
+    write_avro(
+        &metadata.join("m2.avro"),
+        MANIFEST_SCHEMA,
+        vec![
+            manifest_entry(1, "part=b/part-00000.parquet", 2024, 1, (3, 3)),
+            manifest_entry(2, "part=c/part-00000.parquet", 2025, 1, (4, 4)),
+        ],
+        true,
+    );
+    write_avro(
+        &metadata.join("snap-1.avro"),
+        MANIFEST_LIST_SCHEMA,
+        vec![manifest_file("m1.avro", 1)],
+        false,
+    );
+    write_avro(
+        &metadata.join("snap-2.avro"),
+        MANIFEST_LIST_SCHEMA,
+        vec![manifest_file("m1.avro", 1), manifest_file("m2.avro", 2)],
+        true,
+    );
+    write_metadata(table, 1, &[1]);
+    write_metadata(table, 2, &[1, 2]);
+}
+
+#[test]
+fn test_iceberg_snapshots_and_time_travel() {
+    let dir = tempfile::tempdir().unwrap();
+    build_table(dir.path());
+    let root = dir.path().to_str().unwrap();
+
+    let table = IcebergTable::open(root).unwrap();
+    assert_eq!(table.snapshot_id(), Some(2));
+    assert_eq!(table.snapshots(), [1, 2]);
+    assert_eq!(table.partition_fields(), ["year".to_string()]);
+    assert_eq!(
+        table.schema(),
+        vec![
+            ("id".to_string(), DataType::I32),
+            ("amount".to_string(), DataType::F64),
+            ("year".to_string(), DataType::I32),
+        ]
+    );
+    assert_eq!(table.files().len(), 2);
+    assert_eq!(
+        table.files()[1].partition_values["year"],
+        Some(Value::I32(2024))
+    );
+    let df = table.to_dataframe().unwrap();
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.column_count(), 3);
+
+    let first = IcebergTable::open_snapshot(root, 1).unwrap();
+    assert_eq!(first.files().len(), 1);
+    assert_eq!(first.to_dataframe().unwrap().row_count(), 2);
+    assert!(IcebergTable::open_snapshot(root, 9).is_err());
+    assert!(IcebergTable::open(dir.path().join("data").to_str().unwrap()).is_err());
+}
+
+#[test]
+fn test_iceberg_pruning() {
+    let dir = tempfile::tempdir().unwrap();
+    build_table(dir.path());
+    let table = IcebergTable::open(dir.path().to_str().unwrap()).unwrap();
+
+    let late = table
+        .to_dataframe_filtered(&Condition::Gt("id".to_string(), Value::I32(2)))
+        .unwrap();
+    assert_eq!(late.row_count(), 1);
+
+    // With the part=b file gone, only pruned reads can succeed
+    std::fs::remove_dir_all(dir.path().join("data/part=b")).unwrap();
+    assert!(table.to_dataframe().is_err());
+    // Pruned by the identity partition on year
+    let by_year = table
+        .to_dataframe_filtered(&Condition::Eq("year".to_string(), Value::I32(2023)))
+        .unwrap();
+    assert_eq!(by_year.row_count(), 2);
+    // Pruned by the id column bounds
+    let by_id = table
+        .to_dataframe_filtered(&Condition::Lt("id".to_string(), Value::I32(3)))
+        .unwrap();
+    assert_eq!(by_id.row_count(), 2);
+    assert_eq!(
+        by_id.get_column("amount").unwrap().get_value(1),
+        Some(Value::F64(2.5))
+    );
+
+    let none = table
+        .to_dataframe_filtered(&Condition::Eq("year".to_string(), Value::I32(1999)))
+        .unwrap();
+    assert_eq!(none.row_count(), 0);
+    assert_eq!(none.column_count(), 3);
+}
+
+/// `tests/data/iceberg/sales`: Iceberg v2 manifests partitioned by `region`, where
+/// snapshot 1 appends EU and US files and snapshot 2 replaces the US file and adds
+/// APAC and a null region. See `tests/data/iceberg/README.md`.
+#[test]
+fn test_iceberg_fixture_table() {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/iceberg/sales");
+    let ids = |df: &DataFrame| {
+        let mut ids: Vec<i32> = (0..df.row_count())
+            .filter_map(|row| df.get_column("id").unwrap().get_value(row)?.as_i32())
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    let table = IcebergTable::open(root).unwrap();
+    assert_eq!(table.snapshot_id(), Some(5937117119577207079));
+    assert_eq!(
+        table.snapshots(),
+        [3051729675574597004, 5937117119577207079]
+    );
+    assert_eq!(table.partition_fields(), ["region".to_string()]);
+    assert_eq!(
+        table.schema(),
+        vec![
+            ("id".to_string(), DataType::I32),
+            ("region".to_string(), DataType::String),
+            ("amount".to_string(), DataType::F64),
+            ("quantity".to_string(), DataType::I32),
+        ]
+    );
+    let regions: Vec<_> = table
+        .files()
+        .iter()
+        .map(|file| file.partition_values["region"].clone())
+        .collect();
+    assert_eq!(
+        regions,
+        [
+            Some(Value::String("APAC".to_string())),
+            Some(Value::String("EU".to_string())),
+            Some(Value::String("US".to_string())),
+            None,
+        ]
+    );
+    assert!(table.files().iter().all(|file| file.format == "PARQUET"));
+    assert_eq!(table.files()[1].record_count, 3);
+    let df = table.to_dataframe().unwrap();
+    assert_eq!(ids(&df), [1, 2, 3, 5, 6, 7, 9]);
+
+    let us = table
+        .to_dataframe_filtered(&Condition::Eq(
+            "region".to_string(),
+            Value::String("US".to_string()),
+        ))
+        .unwrap();
+    assert_eq!(ids(&us), [5]);
+    assert_eq!(
+        us.get_column("amount").unwrap().get_value(0),
+        Some(Value::F64(55.0))
+    );
+    let large = table
+        .to_dataframe_filtered(&Condition::Gt("amount".to_string(), Value::F64(60.0)))
+        .unwrap();
+    assert_eq!(ids(&large), [6, 7, 9]);
+
+    let first = IcebergTable::open_snapshot(root, 3051729675574597004).unwrap();
+    assert_eq!(first.files().len(), 2);
+    assert_eq!(ids(&first.to_dataframe().unwrap()), [1, 2, 3, 4, 5]);
+}
@@ -25,12 +25,10 @@ fn test_json_reader_read_file() {
     let _reader = CsvReader::new();
     // Test with non-existent file - should return an error
     let result = _reader.read_file("nonexistent.json");
-    assert!(result.is_err());
-    if let Err(VeloxxError::FileIO(_)) = result {
-        // Test passes if it's a FileIO error
-    } else {
-        panic!("Expected a FileIO error");
-    }
+    assert!(matches!(
+        result.unwrap_err().root_cause(),
+        VeloxxError::FileIO(_)
+    ));
 }
 
 #[test]
@@ -134,20 +132,22 @@ fn test_to_csv() {
 
 #[test]
 fn test_from_csv_nonexistent_file() {
-    let result = DataFrame::from_csv("nonexistent.csv");
-    assert!(result.is_err());
-    if let Err(VeloxxError::FileIO(_)) = result {
-        // Test passes if it's a FileIO error
-    } else {
-        panic!("Expected a FileIO error");
-    }
+    let err = DataFrame::from_csv("nonexistent.csv").unwrap_err();
+    assert!(matches!(err.root_cause(), VeloxxError::FileIO(_)));
+    assert_eq!(
+        err.context().unwrap().file.as_deref(),
+        Some("nonexistent.csv")
+    );
 }
 
 #[test]
 fn test_empty_dataframe_to_from_csv() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("empty.csv");
+    let path = path.to_str().unwrap();
     let df = DataFrame::new(HashMap::new()).unwrap();
-    df.to_csv("empty.csv").unwrap();
-    let read_df = DataFrame::from_csv("empty.csv").unwrap();
+    df.to_csv(path).unwrap();
+    let read_df = DataFrame::from_csv(path).unwrap();
     assert_eq!(read_df.row_count(), 0);
     assert_eq!(read_df.column_count(), 0);
 }
@@ -155,14 +155,897 @@ fn test_empty_dataframe_to_from_csv() {
 #[test]
 fn test_from_csv_malformed() {
     // Create a malformed CSV file
-    std::fs::write("malformed.csv", "col1,col2\n1\n").unwrap();
-    let result = DataFrame::from_csv("malformed.csv");
-    assert!(result.is_err());
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("malformed.csv");
+    let path = path.to_str().unwrap();
+    std::fs::write(path, "col1,col2\n1\n").unwrap();
+    let err = DataFrame::from_csv(path).unwrap_err();
+    assert_eq!(err.context().unwrap().file.as_deref(), Some(path));
+    assert_eq!(err.context().unwrap().line, Some(2));
     assert_eq!(
-        result.unwrap_err(),
-        VeloxxError::Parsing(
+        err.root_cause(),
+        &VeloxxError::Parsing(
             "CSV row 1 has 1 columns, expected 2 (header: [\"col1\", \"col2\"], row: [\"1\"])"
                 .to_string()
         )
     );
 }
+
+#[test]
+fn test_to_csv_string_quotes_fields() {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "name".to_string(),
+        Series::new_string(
+            "name",
+            vec![
+                Some("Smith, J".to_string()),
+                Some("say \"hi\"".to_string()),
+                None,
+            ],
+        ),
+    );
+    columns.insert(
+        "age".to_string(),
+        Series::new_i32("age", vec![Some(30), None, Some(41)]),
+    );
+    let df = DataFrame::new(columns).unwrap();
+    assert_eq!(
+        df.to_csv_string(),
+        "age,name\n30,\"Smith, J\"\n,\"say \"\"hi\"\"\"\n41,\n"
+    );
+}
+
+#[test]
+fn test_from_json_str() {
+    let df = DataFrame::from_json_str(r#"[{"a": 1, "b": "x"}, {"a": 2, "b": null}]"#).unwrap();
+    assert_eq!(df.row_count(), 2);
+    assert_eq!(
+        df.get_column("a").unwrap().get_value(1),
+        Some(veloxx::types::Value::F64(2.0))
+    );
+    assert_eq!(df.get_column("b").unwrap().get_value(1), None);
+    assert!(DataFrame::from_json_str("{}").is_err());
+}
+
+#[test]
+fn test_from_json_str_flattens_nested_values() {
+    use veloxx::io::JsonReadOptions;
+    use veloxx::types::Value;
+
+    let json = r#"[
+        {"id": 1, "user": {"name": "ann", "geo": {"lat": 1.5}}, "tags": ["x", 2, true]},
+        {"id": 2, "user": null, "extra": "late key", "tags": [{"k": "v"}, [1]]},
+        {"id": 3, "user": {"geo": {"lat": -2}}, "tags": []}
+    ]"#;
+    let df = DataFrame::from_json_str(json).unwrap();
+    let cell = |column: &str, row: usize| df.get_column(column).unwrap().get_value(row);
+    let text = |s: &str| Some(Value::String(s.to_string()));
+
+    let mut names: Vec<&String> = df.column_names();
+    names.sort();
+    assert_eq!(names, ["extra", "id", "tags", "user.geo.lat", "user.name"]);
+    assert_eq!(cell("user.name", 0), text("ann"));
+    // Null objects and missing keys both give nulls
+    assert_eq!(cell("user.name", 1), None);
+    assert_eq!(cell("user.name", 2), None);
+    assert_eq!(cell("user.geo.lat", 2), Some(Value::F64(-2.0)));
+    // Keys first seen after the first row are kept
+    assert_eq!(cell("extra", 0), None);
+    assert_eq!(cell("extra", 1), text("late key"));
+    assert_eq!(cell("tags", 0), text("x,2,true"));
+    assert_eq!(cell("tags", 1), text(r#"[{"k":"v"},[1]]"#));
+    assert_eq!(cell("tags", 2), text(""));
+
+    let options = JsonReadOptions::new()
+        .separator("__")
+        .max_depth(0)
+        .list_delimiter("|");
+    let df = DataFrame::from_json_str_with_options(json, &options).unwrap();
+    let cell = |column: &str, row: usize| df.get_column(column).unwrap().get_value(row);
+    assert_eq!(cell("user", 0), text(r#"{"name":"ann","geo":{"lat":1.5}}"#));
+    assert_eq!(cell("user", 1), None);
+    assert_eq!(cell("tags", 0), text("x|2|true"));
+
+    let df =
+        DataFrame::from_json_str_with_options(json, &JsonReadOptions::new().max_depth(1)).unwrap();
+    assert_eq!(
+        df.get_column("user.geo").unwrap().get_value(2),
+        text(r#"{"lat":-2}"#)
+    );
+}
+
+#[test]
+fn test_csv_schema_inference() {
+    use veloxx::io::UltraFastCsvParser;
+    use veloxx::types::{DataType, Value};
+
+    let csv = "id,flag,when,local,price,empty\n\
+               1,1,2024-01-15T10:30:00Z,03/04/2024,9.5,\n\
+               2,0,2024-01-16T08:00:00+02:00,05/06/2024,10,\n\
+               3,1,2024-01-17T00:00:00Z,07/08/2024,x,\n";
+    let parser = UltraFastCsvParser::new().sample_rows(2);
+
+    let schema = parser.infer_schema_from_reader(csv.as_bytes()).unwrap();
+    assert_eq!(schema.column("id").unwrap().data_type, DataType::I32);
+    assert_eq!(schema.column("flag").unwrap().ambiguities.len(), 1);
+    let when = schema.column("when").unwrap();
+    assert_eq!(when.data_type, DataType::DateTime);
+    assert_eq!(when.datetime_format.as_deref(), Some("%Y-%m-%dT%H:%M:%S%z"));
+    let local = schema.column("local").unwrap();
+    assert_eq!(local.datetime_format.as_deref(), Some("%d/%m/%Y"));
+    assert!(local.ambiguities[0].contains("%m/%d/%Y"));
+    assert_eq!(schema.column("price").unwrap().data_type, DataType::F64);
+    assert_eq!(schema.column("empty").unwrap().data_type, DataType::String);
+
+    // The third row does not fit the sampled type of "price", so it is widened.
+    let (df, schema) = parser.read_from_reader_with_schema(csv.as_bytes()).unwrap();
+    assert_eq!(schema.column("price").unwrap().data_type, DataType::String);
+    assert!(schema.has_ambiguities());
+    let when = df.get_column("when").unwrap();
+    assert_eq!(when.get_value(0), Some(Value::DateTime(1705314600)));
+    assert_eq!(when.get_value(1), Some(Value::DateTime(1705384800)));
+    assert_eq!(df.get_column("empty").unwrap().len(), 3);
+
+    let df = UltraFastCsvParser::new()
+        .column_type("flag", DataType::Bool)
+        .column_type("id", DataType::F64)
+        .read_from_reader(csv.as_bytes())
+        .unwrap();
+    assert_eq!(
+        df.get_column("flag").unwrap().get_value(1),
+        Some(Value::Bool(false))
+    );
+    assert_eq!(
+        df.get_column("id").unwrap().get_value(0),
+        Some(Value::F64(1.0))
+    );
+
+    let result = UltraFastCsvParser::new()
+        .column_type("price", DataType::F64)
+        .read_from_reader(csv.as_bytes());
+    assert!(matches!(result, Err(VeloxxError::Parsing(_))));
+}
+
+#[test]
+fn test_parse_modes() {
+    use std::io::Write;
+    use veloxx::io::{ParseMode, UltraFastCsvParser, UltraFastJsonParser};
+    use veloxx::types::{DataType, Value};
+
+    let csv = "id,qty\n1,5\n2,five\n3,7,extra\n4,\n";
+    let strict = UltraFastCsvParser::new().column_type("qty", DataType::I32);
+    let error = strict.read_from_reader(csv.as_bytes()).unwrap_err();
+    assert!(error.to_string().contains("Row 3 has 3 fields"), "{error}");
+    assert!(strict.bad_rows().is_none());
+
+    let parser = strict.parse_mode(ParseMode::Permissive);
+    let df = parser.read_from_reader(csv.as_bytes()).unwrap();
+    assert_eq!(df.row_count(), 2);
+    let qty = df.get_column("qty").unwrap();
+    assert_eq!(qty.get_value(0), Some(Value::I32(5)));
+    assert_eq!(qty.get_value(1), None);
+    let bad = parser.bad_rows().unwrap();
+    let cell = |column: &str, row: usize| bad.get_column(column).unwrap().get_value(row);
+    assert_eq!(bad.row_count(), 2);
+    assert_eq!(cell("row", 0), Some(Value::I32(2)));
+    assert_eq!(cell("column", 0), Some(Value::String("qty".to_string())));
+    assert_eq!(cell("record", 0), Some(Value::String("2,five".to_string())));
+    assert_eq!(cell("row", 1), Some(Value::I32(3)));
+    assert_eq!(cell("column", 1), None);
+    assert_eq!(
+        cell("record", 1),
+        Some(Value::String("3,7,extra".to_string()))
+    );
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    write!(
+        file,
+        r#"[{{"id": 1, "score": 10}}, {{"id": 2, "score": "n/a"}}, {{"id": 3, "score": 12}},
+            {{"id": 4, "score": 13}}, {{"id": 5, "score": 14}}, {{"id": 6, "score": 15}}]"#
+    )
+    .unwrap();
+    let path = file.path().to_str().unwrap();
+    let error = UltraFastJsonParser::new().read_file(path).unwrap_err();
+    assert!(matches!(error, VeloxxError::Parsing(_)));
+    assert!(error.to_string().contains("'score' row 2"), "{error}");
+
+    let parser = UltraFastJsonParser::new().parse_mode(ParseMode::Permissive);
+    let df = parser.read_file(path).unwrap();
+    assert_eq!(df.row_count(), 5);
+    assert_eq!(
+        df.get_column("score").unwrap().get_value(1),
+        Some(Value::I32(12))
+    );
+    let bad = parser.bad_rows().unwrap();
+    assert_eq!(bad.row_count(), 1);
+    assert_eq!(
+        bad.get_column("record").unwrap().get_value(0),
+        Some(Value::String(r#"{"id":2,"score":"n/a"}"#.to_string()))
+    );
+}
+
+#[test]
+fn test_fixed_width_reader() {
+    use veloxx::io::FixedWidthReader;
+    use veloxx::types::{DataType, Value};
+
+    let spec = "# name  start width type\n\
+                account  1    5     I32\n\
+                holder   6    8\n\
+                opened   14   10    datetime\n\
+                flag     24   1     bool\n";
+    let reader = FixedWidthReader::from_spec(spec).unwrap().skip_rows(1);
+    assert_eq!(reader.columns()[2].start, 13);
+
+    let data = "ACCOUNT HOLDER  OPENED    F\n\
+                00001Zoë     2024-01-15Y\n\
+                \n\
+                00002Bob\n";
+    let df = reader.read_from_reader(data.as_bytes()).unwrap();
+    assert_eq!(df.row_count(), 2);
+    let cell = |column: &str, row: usize| df.get_column(column).unwrap().get_value(row);
+    assert_eq!(cell("account", 1), Some(Value::I32(2)));
+    assert_eq!(cell("holder", 0), Some(Value::String("Zoë".to_string())));
+    assert_eq!(cell("opened", 0), Some(Value::DateTime(1705276800)));
+    assert_eq!(cell("opened", 1), None);
+    assert_eq!(cell("flag", 0), Some(Value::Bool(true)));
+    assert_eq!(df.get_column("flag").unwrap().data_type(), DataType::Bool);
+
+    let bad = "00003Carol   2024-13-45N\n";
+    let reader = FixedWidthReader::from_spec(spec).unwrap();
+    assert!(matches!(
+        reader.read_from_reader(bad.as_bytes()),
+        Err(VeloxxError::Parsing(_))
+    ));
+    assert!(FixedWidthReader::from_spec("id 0 5").is_err());
+    assert!(FixedWidthReader::from_spec("id 1 5 decimal").is_err());
+    assert!(FixedWidthReader::new()
+        .read_from_reader("x".as_bytes())
+        .is_err());
+}
+
+#[test]
+fn test_from_xml() {
+    use veloxx::types::{DataType, Value};
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE report>
+<report xmlns:fx="urn:fx">
+  <!-- <trade id="0"> inside a comment is ignored -->
+  <fx:trade id="1" side='buy'>
+    <fx:price>1.25</fx:price>
+    <note><![CDATA[a < b]]></note>
+    <party lei="L1"><name>Acme</name></party>
+  </fx:trade>
+  <fx:trade id="2" side="sell">
+    <fx:price>1.5</fx:price>
+    <settled/>
+  </fx:trade>
+  <archive><fx:trade id="3"><fx:price>2</fx:price></fx:trade></archive>
+</report>"#;
+
+    let df = DataFrame::from_xml_str(
+        xml,
+        "/report/trade",
+        &[
+            ("id", "@id"),
+            ("price", "price"),
+            ("party", "party/name"),
+            ("note", "note"),
+        ],
+    )
+    .unwrap();
+    assert_eq!(df.row_count(), 2);
+    let cell =
+        |df: &DataFrame, column: &str, row: usize| df.get_column(column).unwrap().get_value(row);
+    assert_eq!(df.get_column("price").unwrap().data_type(), DataType::F64);
+    assert_eq!(cell(&df, "price", 1), Some(Value::F64(1.5)));
+    assert_eq!(
+        cell(&df, "party", 0),
+        Some(Value::String("Acme".to_string()))
+    );
+    assert_eq!(cell(&df, "party", 1), None);
+    assert_eq!(
+        cell(&df, "note", 0),
+        Some(Value::String("a < b".to_string()))
+    );
+
+    // Unanchored paths match at any depth
+    let all = DataFrame::from_xml_str(xml, "//fx:trade", &[("id", "@id")]).unwrap();
+    assert_eq!(all.row_count(), 3);
+    assert_eq!(cell(&all, "id", 2), Some(Value::I32(3)));
+
+    // Without mappings, attributes and child elements become columns
+    let discovered = DataFrame::from_xml_str(xml, "/report/trade", &[]).unwrap();
+    let mut names: Vec<&String> = discovered.column_names();
+    names.sort();
+    assert_eq!(
+        names,
+        ["fx:price", "id", "note", "party", "settled", "side"]
+    );
+    assert_eq!(
+        cell(&discovered, "side", 1),
+        Some(Value::String("sell".to_string()))
+    );
+    assert_eq!(cell(&discovered, "settled", 1), None);
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    std::io::Write::write_all(&mut file, xml.as_bytes()).unwrap();
+    let from_file =
+        DataFrame::from_xml(file.path().to_str().unwrap(), "trade", &[("id", "@id")]).unwrap();
+    assert_eq!(from_file.row_count(), 3);
+
+    assert!(matches!(
+        DataFrame::from_xml_str("<a><b></a>", "b", &[]),
+        Err(VeloxxError::Parsing(_))
+    ));
+    assert!(DataFrame::from_xml_str("<a><b>", "b", &[]).is_err());
+    assert!(DataFrame::from_xml_str(xml, "trade", &[("x", "party/@")]).is_err());
+}
+
+#[test]
+fn test_parse_dates_on_load() {
+    use std::io::Write;
+    use veloxx::io::{CsvReadOptions, DateTimeFormat, JsonReadOptions};
+    use veloxx::types::Value;
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "iso,local,epoch,epoch_ms,label").unwrap();
+    writeln!(
+        file,
+        "2023-01-01T00:00:00Z,2023-01-01 01:30:00,1672531200,1672531200999,a"
+    )
+    .unwrap();
+    writeln!(file, "2023-01-02,,1672617600,1672617600000,b").unwrap();
+    let path = file.path().to_str().unwrap();
+
+    let df = DataFrame::from_csv(path).unwrap();
+    assert_eq!(
+        df.get_column("iso").unwrap().get_value(1),
+        Some(Value::DateTime(1672617600))
+    );
+    assert!(matches!(df.get_column("local"), Some(Series::String(..))));
+    assert!(matches!(df.get_column("label"), Some(Series::String(..))));
+
+    let options = CsvReadOptions::new()
+        .parse_dates(["local"], "%Y-%m-%d %H:%M:%S")
+        .parse_dates(["epoch"], DateTimeFormat::EpochSeconds)
+        .parse_dates(["epoch_ms"], DateTimeFormat::EpochMillis);
+    let df = DataFrame::from_csv_with_options(path, &options).unwrap();
+    let local = df.get_column("local").unwrap();
+    assert_eq!(local.get_value(0), Some(Value::DateTime(1672536600)));
+    assert_eq!(local.get_value(1), None);
+    assert_eq!(
+        df.get_column("epoch").unwrap().get_value(0),
+        Some(Value::DateTime(1672531200))
+    );
+    assert_eq!(
+        df.get_column("epoch_ms").unwrap().get_value(0),
+        Some(Value::DateTime(1672531200))
+    );
+
+    let df =
+        DataFrame::from_csv_with_options(path, &CsvReadOptions::new().infer_dates(false)).unwrap();
+    assert!(matches!(df.get_column("iso"), Some(Series::String(..))));
+
+    let bad = CsvReadOptions::new().parse_dates(["label"], DateTimeFormat::Rfc3339);
+    assert!(matches!(
+        DataFrame::from_csv_with_options(path, &bad)
+            .unwrap_err()
+            .root_cause(),
+        VeloxxError::Parsing(_)
+    ));
+    let missing = CsvReadOptions::new().parse_dates(["nope"], DateTimeFormat::Iso8601);
+    assert!(matches!(
+        DataFrame::from_csv_with_options(path, &missing)
+            .unwrap_err()
+            .root_cause(),
+        VeloxxError::ColumnNotFound(_)
+    ));
+
+    let json = r#"[{"ts": "2023-01-01T00:00:00+01:00", "ms": 1672531200000}]"#;
+    let options = JsonReadOptions::new().parse_dates(["ms"], DateTimeFormat::EpochMillis);
+    let df = DataFrame::from_json_str_with_options(json, &options).unwrap();
+    assert_eq!(
+        df.get_column("ts").unwrap().get_value(0),
+        Some(Value::DateTime(1672527600))
+    );
+    assert_eq!(
+        df.get_column("ms").unwrap().get_value(0),
+        Some(Value::DateTime(1672531200))
+    );
+}
+
+#[test]
+fn test_partitioned_round_trip() {
+    use veloxx::io::PartitionFormat;
+    use veloxx::types::Value;
+
+    let mut columns = HashMap::new();
+    columns.insert(
+        "year".to_string(),
+        Series::new_i32("year", vec![Some(2023), Some(2023), Some(2024), None]),
+    );
+    columns.insert(
+        "region".to_string(),
+        Series::new_string(
+            "region",
+            vec![
+                Some("EU".to_string()),
+                Some("US/East".to_string()),
+                Some("EU".to_string()),
+                Some("EU".to_string()),
+            ],
+        ),
+    );
+    columns.insert(
+        "amount".to_string(),
+        Series::new_f64("amount", vec![Some(1.5), Some(2.5), Some(3.5), Some(4.5)]),
+    );
+    let df = DataFrame::new(columns).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("sales");
+    let root = root.to_str().unwrap();
+    df.write_partitioned(root, &["year", "region"], PartitionFormat::Csv)
+        .unwrap();
+    assert!(dir
+        .path()
+        .join("sales/year=2023/region=US%2FEast/part-00000.csv")
+        .is_file());
+    assert!(dir
+        .path()
+        .join("sales/year=__HIVE_DEFAULT_PARTITION__/region=EU/part-00000.csv")
+        .is_file());
+
+    let read = DataFrame::read_partitioned(root).unwrap();
+    assert_eq!(read.row_count(), 4);
+    assert_eq!(read.column_count(), 3);
+    let sorted = read.sort(vec!["amount".to_string()], true).unwrap();
+    assert_eq!(
+        sorted.get_column("year").unwrap().get_value(2),
+        Some(Value::I32(2024))
+    );
+    assert_eq!(sorted.get_column("year").unwrap().get_value(3), None);
+    assert_eq!(
+        sorted.get_column("region").unwrap().get_value(1),
+        Some(Value::String("US/East".to_string()))
+    );
+
+    let eu = DataFrame::read_partitioned(&format!("{}/year=*/region=EU/*.csv", root)).unwrap();
+    assert_eq!(eu.row_count(), 3);
+    assert!(DataFrame::read_partitioned(&format!("{}/missing/*.csv", root)).is_err());
+    assert!(df
+        .write_partitioned(root, &["nope"], PartitionFormat::Csv)
+        .is_err());
+
+    #[cfg(all(feature = "advanced_io", feature = "arrow"))]
+    {
+        let root = dir.path().join("parquet");
+        let root = root.to_str().unwrap();
+        df.write_partitioned(root, &["region"], PartitionFormat::Parquet)
+            .unwrap();
+        let read = DataFrame::read_partitioned(root).unwrap();
+        assert_eq!(read.row_count(), 4);
+        assert!(matches!(
+            read.get_column("region"),
+            Some(Series::String(..))
+        ));
+        assert!(matches!(read.get_column("amount"), Some(Series::F64(..))));
+    }
+}
+
+#[test]
+fn test_read_glob() {
+    use veloxx::io::GlobReadOptions;
+    use veloxx::types::{DataType, Value};
+
+    let dir = tempfile::tempdir().unwrap();
+    let write =
+        |name: &str, contents: &str| std::fs::write(dir.path().join(name), contents).unwrap();
+    write("2024-01.csv", "id,amount\n1,10\n2,20\n");
+    write("2024-02.csv", "amount,id\n2.5,3\n");
+    write("2023-12.csv", "id,amount\n0,5\n");
+    let pattern = format!("{}/2024-*.csv", dir.path().display());
+
+    let df = DataFrame::from_csv_glob(&pattern).unwrap();
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(df.column_count(), 2);
+    let amount = df.get_column("amount").unwrap();
+    assert_eq!(amount.data_type(), DataType::F64);
+    assert_eq!(amount.get_value(2), Some(Value::F64(2.5)));
+
+    let options = GlobReadOptions::new().source_column("source_file");
+    let df = DataFrame::from_csv_glob_with_options(&pattern, &options).unwrap();
+    let source = df.get_column("source_file").unwrap();
+    let file = |row: usize| match source.get_value(row) {
+        Some(Value::String(path)) => path,
+        other => panic!("unexpected source {other:?}"),
+    };
+    assert!(file(1).ends_with("2024-01.csv"));
+    assert!(file(2).ends_with("2024-02.csv"));
+
+    write("2024-03.csv", "id,amount\n4,unknown\n");
+    let error = DataFrame::from_csv_glob(&pattern).unwrap_err();
+    assert!(matches!(error, VeloxxError::DataTypeMismatch(_)));
+    assert!(error.to_string().contains("2024-03.csv"), "{error}");
+    write("2024-03.csv", "id,total\n4,40\n");
+    assert!(DataFrame::from_csv_glob(&pattern).is_err());
+    assert!(DataFrame::from_csv_glob(&format!("{}/2025-*.csv", dir.path().display())).is_err());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_stdin_stdout_csv_pipeline() {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    use veloxx::types::Value;
+
+    // Re-runs this test binary as a filter: CSV on stdin, filtered CSV on stdout
+    if std::env::var_os("VELOXX_STDIO_CHILD").is_some() {
+        let df = DataFrame::from_stdin_csv().unwrap();
+        let indices: Vec<usize> = (0..df.row_count())
+            .filter(|&i| df.get_column("qty").unwrap().get_value(i) != Some(Value::I32(0)))
+            .collect();
+        df.filter_by_indices(&indices)
+            .unwrap()
+            .to_stdout_csv()
+            .unwrap();
+        return;
+    }
+
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "test_stdin_stdout_csv_pipeline",
+            "--exact",
+            "--nocapture",
+            "--quiet",
+        ])
+        .env("VELOXX_STDIO_CHILD", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"name,qty\n\"Smith, J\",3\nLee,0\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // The test harness adds its own progress lines around the CSV
+    assert!(stdout.contains("name,qty\n\"Smith, J\",3\n"));
+    assert!(!stdout.contains("Lee"));
+}
+
+#[test]
+fn test_binary_column_round_trips() {
+    use veloxx::expressions::Expr;
+    use veloxx::types::Value;
+
+    let payload = Series::new_binary(
+        "payload",
+        vec![
+            Some(b"\x89PNG\r\n".to_vec()),
+            None,
+            Some(vec![]),
+            Some(vec![0xff, 0x00]),
+        ],
+    );
+    assert_eq!(
+        payload.bin_len().unwrap().get_data_i32().unwrap(),
+        vec![Some(6), None, Some(0), Some(2)]
+    );
+    assert_eq!(
+        payload
+            .bin_starts_with(b"\x89PNG")
+            .unwrap()
+            .get_data_bool()
+            .unwrap(),
+        vec![Some(true), None, Some(false), Some(false)]
+    );
+    assert_eq!(
+        payload.bin_prefix(1).unwrap().get_value(3),
+        Some(Value::Binary(vec![0xff]))
+    );
+    let encoded = payload.base64_encode().unwrap();
+    assert_eq!(
+        encoded.get_value(3),
+        Some(Value::String("/wA=".to_string()))
+    );
+    assert_eq!(encoded.base64_decode().unwrap(), payload);
+    assert!(
+        Series::new_string("bad", vec![Some("not base64!".to_string())])
+            .base64_decode()
+            .is_err()
+    );
+    assert!(encoded.bin_len().is_err());
+
+    let mut columns = HashMap::new();
+    columns.insert("payload".to_string(), payload.clone());
+    columns.insert(
+        "kind".to_string(),
+        Series::new_string(
+            "kind",
+            vec![
+                Some("png".to_string()),
+                Some("png".to_string()),
+                Some("empty".to_string()),
+                Some("raw".to_string()),
+            ],
+        ),
+    );
+    let df = DataFrame::new(columns).unwrap();
+    let decoded = Expr::Base64Decode(Box::new(Expr::Base64Encode(Box::new(Expr::Column(
+        "payload".to_string(),
+    )))));
+    assert_eq!(
+        decoded.evaluate(&df, 0).unwrap(),
+        Value::Binary(b"\x89PNG\r\n".to_vec())
+    );
+    assert_eq!(
+        Expr::Base64Encode(Box::new(Expr::Column("payload".to_string())))
+            .evaluate(&df, 3)
+            .unwrap(),
+        Value::String("/wA=".to_string())
+    );
+    assert!(df.to_csv_string().contains("0xff00"));
+
+    #[cfg(all(feature = "advanced_io", feature = "arrow"))]
+    {
+        use veloxx::io::PartitionFormat;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("blobs");
+        let root = root.to_str().unwrap();
+        df.write_partitioned(root, &["kind"], PartitionFormat::Parquet)
+            .unwrap();
+        let read = DataFrame::read_partitioned(root).unwrap();
+        let read_payload = read.get_column("payload").unwrap();
+        assert!(matches!(read_payload, Series::Binary(..)));
+        let mut values: Vec<_> = (0..read.row_count())
+            .map(|i| read_payload.get_value(i))
+            .collect();
+        values.sort();
+        let mut expected: Vec<_> = (0..payload.len()).map(|i| payload.get_value(i)).collect();
+        expected.sort();
+        assert_eq!(values, expected);
+    }
+}
+
+#[cfg(feature = "proto")]
+mod proto {
+    use prost_reflect::prost::Message;
+    use prost_reflect::prost_types::field_descriptor_proto::{Label, Type};
+    use prost_reflect::prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet,
+    };
+    use prost_reflect::{DescriptorPool, DynamicMessage};
+    use veloxx::error::VeloxxError;
+    use veloxx::io::proto::{
+        read_messages, LengthPrefix, ProtoDescriptor, ProtoType, RecordDecoder,
+    };
+    use veloxx::types::{DataType, Value};
+
+    const LOG_PROTO: &str = r#"
+        syntax = "proto3";
+        package logs;
+        // A single request
+        message Log {
+            string service = 1;
+            sint32 delta = 2;
+            Latency latency = 3;
+            Level level = 4;
+            repeated string tags = 5;
+            map<string, string> labels = 6;
+            message Latency {
+                double p50 = 1;
+                fixed32 samples = 2 [deprecated = true];
+            }
+        }
+        enum Level { INFO = 0; WARN = 1; ERROR = 2; }
+    "#;
+
+    fn field(
+        name: &str,
+        number: i32,
+        field_type: Type,
+        type_name: Option<&str>,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(field_type as i32),
+            type_name: type_name.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    /// `LOG_PROTO` compiled to a descriptor set, as `protoc --descriptor_set_out` writes it
+    fn log_descriptor_set() -> Vec<u8> {
+        let tags = FieldDescriptorProto {
+            label: Some(Label::Repeated as i32),
+            ..field("tags", 5, Type::String, None)
+        };
+        let latency = DescriptorProto {
+            name: Some("Latency".to_string()),
+            field: vec![
+                field("p50", 1, Type::Double, None),
+                field("samples", 2, Type::Fixed32, None),
+            ],
+            ..Default::default()
+        };
+        let log = DescriptorProto {
+            name: Some("Log".to_string()),
+            field: vec![
+                field("service", 1, Type::String, None),
+                field("delta", 2, Type::Sint32, None),
+                field("latency", 3, Type::Message, Some(".logs.Log.Latency")),
+                field("level", 4, Type::Enum, Some(".logs.Level")),
+                tags,
+            ],
+            nested_type: vec![latency],
+            ..Default::default()
+        };
+        let level = EnumDescriptorProto {
+            name: Some("Level".to_string()),
+            value: ["INFO", "WARN", "ERROR"]
+                .iter()
+                .zip(0..)
+                .map(|(name, number)| EnumValueDescriptorProto {
+                    name: Some(name.to_string()),
+                    number: Some(number),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("logs.proto".to_string()),
+                package: Some("logs".to_string()),
+                message_type: vec![log],
+                enum_type: vec![level],
+                syntax: Some("proto3".to_string()),
+                ..Default::default()
+            }],
+        }
+        .encode_to_vec()
+    }
+
+    /// Two `Log` messages encoded by prost
+    fn log_messages() -> Vec<Vec<u8>> {
+        let pool = DescriptorPool::decode(log_descriptor_set().as_slice()).unwrap();
+        let log = pool.get_message_by_name("logs.Log").unwrap();
+        [
+            r#"service: "api" delta: -2 latency { p50: 2.5 } level: ERROR tags: "edge""#,
+            r#"service: "db" delta: 2"#,
+        ]
+        .iter()
+        .map(|text| {
+            DynamicMessage::parse_text_format(log.clone(), text)
+                .unwrap()
+                .encode_to_vec()
+        })
+        .collect()
+    }
+
+    fn framed(messages: &[Vec<u8>], prefix: LengthPrefix) -> Vec<u8> {
+        let mut stream = Vec::new();
+        for message in messages {
+            match prefix {
+                LengthPrefix::Varint => stream.push(message.len() as u8),
+                LengthPrefix::U32LittleEndian => {
+                    stream.extend((message.len() as u32).to_le_bytes())
+                }
+            }
+            stream.extend(message);
+        }
+        stream
+    }
+
+    #[test]
+    fn test_read_proto_messages_by_field_number() {
+        let stream = framed(&log_messages(), LengthPrefix::Varint);
+        let descriptor = ProtoDescriptor::new()
+            .field("service", &[1], ProtoType::String)
+            .field("delta", &[2], ProtoType::SInt32)
+            .field("p50", &[3, 1], ProtoType::Double);
+        let df = read_messages(stream.as_slice(), LengthPrefix::Varint, &descriptor).unwrap();
+        assert_eq!(df.row_count(), 2);
+        assert_eq!(
+            df.get_column("service").unwrap().get_value(1),
+            Some(Value::String("db".to_string()))
+        );
+        assert_eq!(
+            df.get_column("delta").unwrap().get_value(0),
+            Some(Value::I32(-2))
+        );
+        assert_eq!(
+            df.get_column("delta").unwrap().get_value(1),
+            Some(Value::I32(2))
+        );
+        assert_eq!(
+            df.get_column("p50").unwrap().get_value(0),
+            Some(Value::F64(2.5))
+        );
+        assert_eq!(df.get_column("p50").unwrap().get_value(1), None);
+        assert_eq!(df.get_column("p50").unwrap().data_type(), DataType::F64);
+
+        // Reading a string field as a number fails and names the message
+        let wrong = ProtoDescriptor::new().field("service", &[1], ProtoType::Int32);
+        let error = read_messages(stream.as_slice(), LengthPrefix::Varint, &wrong).unwrap_err();
+        assert!(error.to_string().contains("Message 1"));
+        assert!(read_messages(&stream[..5], LengthPrefix::Varint, &descriptor).is_err());
+
+        // A field cannot be both a scalar and a message
+        let clash = ProtoDescriptor::new()
+            .field("latency", &[3], ProtoType::Bytes)
+            .field("p50", &[3, 1], ProtoType::Double);
+        assert!(read_messages(stream.as_slice(), LengthPrefix::Varint, &clash).is_err());
+    }
+
+    #[test]
+    fn test_read_proto_messages_from_schema() {
+        let descriptor = ProtoDescriptor::from_proto(LOG_PROTO, "Log").unwrap();
+        let columns = vec![
+            "service",
+            "delta",
+            "latency.p50",
+            "latency.samples",
+            "level",
+        ];
+        assert_eq!(descriptor.column_names(), columns);
+        let compiled =
+            ProtoDescriptor::from_descriptor_set(&log_descriptor_set(), "logs.Log").unwrap();
+        assert_eq!(compiled.column_names(), columns);
+        assert!(ProtoDescriptor::from_descriptor_set(&log_descriptor_set(), "Log").is_err());
+
+        let stream = framed(&log_messages(), LengthPrefix::U32LittleEndian);
+        for descriptor in [descriptor, compiled] {
+            let descriptor = descriptor.select(&["level", "latency.p50"]).unwrap();
+            let df = read_messages(
+                stream.as_slice(),
+                LengthPrefix::U32LittleEndian,
+                &descriptor,
+            )
+            .unwrap();
+            assert_eq!(df.column_count(), 2);
+            assert_eq!(
+                df.get_column("level").unwrap().get_value(0),
+                Some(Value::I32(2))
+            );
+            assert_eq!(df.get_column("level").unwrap().get_value(1), None);
+            assert_eq!(
+                df.get_column("latency.p50").unwrap().get_value(0),
+                Some(Value::F64(2.5))
+            );
+            assert!(descriptor.clone().select(&["missing"]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_read_messages_with_custom_decoder() {
+        struct Lengths;
+        impl RecordDecoder for Lengths {
+            fn columns(&self) -> Vec<(String, DataType)> {
+                vec![("bytes".to_string(), DataType::I32)]
+            }
+            fn decode(&self, message: &[u8]) -> Result<Vec<Option<Value>>, VeloxxError> {
+                Ok(vec![Some(Value::I32(message.len() as i32))])
+            }
+        }
+        let messages = log_messages();
+        let stream = framed(&messages, LengthPrefix::Varint);
+        let df = read_messages(stream.as_slice(), LengthPrefix::Varint, &Lengths).unwrap();
+        assert_eq!(
+            df.get_column("bytes").unwrap().get_value(1),
+            Some(Value::I32(messages[1].len() as i32))
+        );
+    }
+}
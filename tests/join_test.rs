@@ -1,7 +1,8 @@
 use std::collections::HashMap;
-use veloxx::dataframe::join::JoinType;
+use veloxx::dataframe::join::{JoinAlgorithm, JoinOptions, JoinType, JoinValidation};
 use veloxx::dataframe::DataFrame;
 use veloxx::series::Series;
+use veloxx::types::Value;
 
 #[test]
 fn test_inner_join() {
@@ -218,3 +219,439 @@ fn test_join_nonexistent_column() {
     let result = df1.join(&df2, "nonexistent", JoinType::Inner);
     assert!(result.is_err());
 }
+
+fn keyed(name: &str, keys: Vec<Option<&str>>) -> DataFrame {
+    let mut columns = HashMap::new();
+    let rows = keys.len() as i32;
+    columns.insert(
+        "key".to_string(),
+        Series::new_string(
+            "key",
+            keys.into_iter().map(|k| k.map(String::from)).collect(),
+        ),
+    );
+    columns.insert(
+        name.to_string(),
+        Series::new_i32(name, (0..rows).map(Some).collect()),
+    );
+    DataFrame::new(columns).unwrap()
+}
+
+fn rows(df: &DataFrame) -> Vec<Vec<Option<Value>>> {
+    let mut names = df.column_names();
+    names.sort();
+    (0..df.row_count())
+        .map(|i| {
+            names
+                .iter()
+                .map(|name| df.get_column(name).unwrap().get_value(i))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_join_algorithms_agree() {
+    let left = keyed(
+        "l",
+        vec![Some("c"), Some("a"), None, Some("b"), Some("a"), Some("d")],
+    );
+    let right = keyed("r", vec![Some("a"), Some("e"), None, Some("a"), Some("c")]);
+    let sorted_left = keyed("l", vec![Some("a"), Some("a"), Some("b"), Some("c")]);
+    let sorted_right = keyed("r", vec![Some("a"), Some("c"), Some("c")]);
+
+    for (left, right) in [(&left, &right), (&sorted_left, &sorted_right)] {
+        for join_type in [JoinType::Inner, JoinType::Left, JoinType::Right] {
+            let expected = rows(
+                &left
+                    .join_with_algorithm(right, "key", join_type, JoinAlgorithm::Hash)
+                    .unwrap(),
+            );
+            for algorithm in [
+                JoinAlgorithm::Auto,
+                JoinAlgorithm::SortMerge,
+                JoinAlgorithm::Broadcast,
+                JoinAlgorithm::BroadcastLeft,
+                JoinAlgorithm::RunLength,
+            ] {
+                let actual = left
+                    .join_with_algorithm(right, "key", join_type, algorithm)
+                    .unwrap();
+                assert_eq!(rows(&actual), expected, "{:?} {:?}", join_type, algorithm);
+            }
+        }
+    }
+
+    // Rows follow the driving frame; duplicate matches follow the other frame
+    let inner = left
+        .join_with_algorithm(&right, "key", JoinType::Inner, JoinAlgorithm::SortMerge)
+        .unwrap();
+    let pairs: Vec<(Option<Value>, Option<Value>)> = (0..inner.row_count())
+        .map(|i| {
+            (
+                inner.get_column("l").unwrap().get_value(i),
+                inner.get_column("r").unwrap().get_value(i),
+            )
+        })
+        .collect();
+    let pair = |l, r| (Some(Value::I32(l)), Some(Value::I32(r)));
+    assert_eq!(
+        pairs,
+        vec![pair(0, 4), pair(1, 0), pair(1, 3), pair(4, 0), pair(4, 3)]
+    );
+
+    let right_join = left
+        .join_with_algorithm(&right, "key", JoinType::Right, JoinAlgorithm::Broadcast)
+        .unwrap();
+    assert_eq!(right_join.row_count(), 7);
+    assert_eq!(right_join.get_column("l").unwrap().get_value(2), None);
+}
+
+#[test]
+fn test_join_options() {
+    let left = keyed("v", vec![Some("a"), None, Some("b")]);
+    let right = keyed("v", vec![None, Some("a"), None]);
+
+    // Clashing columns are suffixed; null keys match nothing by default
+    let joined = left.join(&right, "key", JoinType::Inner).unwrap();
+    assert_eq!(joined.row_count(), 1);
+    assert_eq!(joined.column_count(), 3);
+    assert_eq!(
+        joined.get_column("v_right").unwrap().get_value(0),
+        Some(Value::I32(1))
+    );
+
+    for algorithm in [
+        JoinAlgorithm::Hash,
+        JoinAlgorithm::SortMerge,
+        JoinAlgorithm::Broadcast,
+        JoinAlgorithm::BroadcastLeft,
+        JoinAlgorithm::RunLength,
+    ] {
+        let options = JoinOptions {
+            join_nulls: true,
+            suffix: "_r".to_string(),
+            algorithm,
+            ..JoinOptions::default()
+        };
+        let joined = left
+            .join_with_options(&right, "key", JoinType::Left, &options)
+            .unwrap();
+        assert_eq!(
+            rows(&joined),
+            vec![
+                vec![
+                    Some(Value::String("a".into())),
+                    Some(Value::I32(0)),
+                    Some(Value::I32(1))
+                ],
+                vec![None, Some(Value::I32(1)), Some(Value::I32(0))],
+                vec![None, Some(Value::I32(1)), Some(Value::I32(2))],
+                vec![Some(Value::String("b".into())), Some(Value::I32(2)), None],
+            ]
+        );
+    }
+
+    let validate = |validate, join_nulls| {
+        let options = JoinOptions {
+            validate,
+            join_nulls,
+            ..JoinOptions::default()
+        };
+        left.join_with_options(&right, "key", JoinType::Inner, &options)
+    };
+    assert!(validate(JoinValidation::OneToOne, false).is_ok());
+    assert!(validate(JoinValidation::OneToOne, true).is_err());
+    assert!(validate(JoinValidation::OneToMany, true).is_ok());
+    assert!(validate(JoinValidation::ManyToOne, true).is_err());
+
+    let taken = keyed("v_right", vec![Some("a")]).join(&right, "key", JoinType::Inner);
+    assert!(taken.is_ok());
+    let mut columns = HashMap::new();
+    columns.insert(
+        "key".to_string(),
+        Series::new_string("key", vec![Some("a".to_string())]),
+    );
+    columns.insert("v".to_string(), Series::new_i32("v", vec![Some(0)]));
+    columns.insert(
+        "v_right".to_string(),
+        Series::new_i32("v_right", vec![Some(0)]),
+    );
+    let clashing = DataFrame::new(columns).unwrap();
+    assert!(clashing.join(&right, "key", JoinType::Inner).is_err());
+}
+
+#[test]
+fn test_uuid_join() {
+    let ids = Series::uuid_v4(3).get_data_string().unwrap();
+    let id = |i: usize| ids[i].as_deref();
+    assert!(id(0)
+        .unwrap()
+        .chars()
+        .nth(14)
+        .is_some_and(|version| version == '4'));
+
+    // Other spellings of the same UUID match on the parsed value
+    let upper = ids[1].as_ref().unwrap().to_uppercase();
+    let braced = format!("{{{}}}", ids[2].as_ref().unwrap());
+    let left = keyed("l", vec![id(0), id(1), None, id(2)]);
+    let right = keyed(
+        "r",
+        vec![Some(upper.as_str()), Some(braced.as_str()), id(0)],
+    );
+    for algorithm in [JoinAlgorithm::Auto, JoinAlgorithm::Uuid] {
+        let joined = left
+            .join_with_algorithm(&right, "key", JoinType::Left, algorithm)
+            .unwrap();
+        let matched: Vec<Option<Value>> = (0..joined.row_count())
+            .map(|i| joined.get_column("r").unwrap().get_value(i))
+            .collect();
+        assert_eq!(
+            matched,
+            vec![
+                Some(Value::I32(2)),
+                Some(Value::I32(0)),
+                None,
+                Some(Value::I32(1))
+            ]
+        );
+    }
+
+    // Auto falls back to comparing strings; an explicit UUID join refuses
+    let mixed = keyed("r", vec![id(0), Some("not-a-uuid")]);
+    let joined = left.join(&mixed, "key", JoinType::Inner).unwrap();
+    assert_eq!(joined.row_count(), 1);
+    assert!(left
+        .join_with_algorithm(&mixed, "key", JoinType::Inner, JoinAlgorithm::Uuid)
+        .is_err());
+
+    let validity = Series::new_string(
+        "id",
+        vec![
+            Some("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()),
+            Some("67e5504410b1426f9247bb680e5fe0c8".to_string()),
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c".to_string()),
+            None,
+        ],
+    );
+    assert_eq!(
+        validity.is_uuid().unwrap().get_data_bool().unwrap(),
+        vec![Some(true), Some(true), Some(false), None]
+    );
+    assert!(validity.to_uuid().is_err());
+    let parsed = validity.filter(&[0, 1, 3]).unwrap().to_uuid().unwrap();
+    assert_eq!(parsed.get(0), parsed.get(1));
+    assert_eq!(parsed.get(2), None);
+    assert_eq!(
+        parsed.to_series().get_value(1),
+        Some(Value::String(
+            "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_join_range() {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "value".to_string(),
+        Series::new_i32("value", vec![Some(5), Some(15), None, Some(40), Some(-1)]),
+    );
+    columns.insert(
+        "label".to_string(),
+        Series::new_string("label", (0..5).map(|i| Some(format!("v{i}"))).collect()),
+    );
+    let values = DataFrame::new(columns).unwrap();
+    // Overlapping, inverted and null intervals, listed out of order
+    let mut columns = HashMap::new();
+    columns.insert(
+        "low".to_string(),
+        Series::new_i32("low", vec![Some(10), Some(0), Some(30), None, Some(12)]),
+    );
+    columns.insert(
+        "high".to_string(),
+        Series::new_i32(
+            "high",
+            vec![Some(20), Some(100), Some(20), Some(50), Some(15)],
+        ),
+    );
+    columns.insert(
+        "label".to_string(),
+        Series::new_string("label", (0..5).map(|i| Some(format!("r{i}"))).collect()),
+    );
+    let ranges = DataFrame::new(columns).unwrap();
+
+    let joined = values.join_range(&ranges, "value", "low", "high").unwrap();
+    let pairs: Vec<(Option<Value>, Option<Value>)> = (0..joined.row_count())
+        .map(|i| {
+            (
+                joined.get_column("value").unwrap().get_value(i),
+                joined.get_column("label_right").unwrap().get_value(i),
+            )
+        })
+        .collect();
+    let pair = |v, r: &str| (Some(Value::I32(v)), Some(Value::String(r.to_string())));
+    assert_eq!(
+        pairs,
+        vec![
+            pair(5, "r1"),
+            pair(15, "r0"),
+            pair(15, "r1"),
+            pair(15, "r4"),
+            pair(40, "r1"),
+        ]
+    );
+
+    assert!(values
+        .join_range(&ranges, "value", "low", "missing")
+        .is_err());
+    assert!(values.join_range(&ranges, "label", "low", "high").is_err());
+}
+
+#[test]
+fn test_join_where() {
+    use veloxx::dataframe::conditional_join::{JoinOp, JoinPredicate};
+
+    let mut columns = HashMap::new();
+    columns.insert(
+        "ts".to_string(),
+        Series::new_i32("ts", vec![Some(5), Some(12), None, Some(30)]),
+    );
+    let events = DataFrame::new(columns).unwrap();
+    let mut columns = HashMap::new();
+    columns.insert(
+        "start".to_string(),
+        Series::new_i32("start", vec![Some(10), Some(0), Some(40)]),
+    );
+    columns.insert(
+        "end".to_string(),
+        Series::new_i32("end", vec![Some(20), Some(12), Some(50)]),
+    );
+    let windows = DataFrame::new(columns).unwrap();
+    let between = JoinPredicate::between("ts", "start", "end");
+    let rows = |df: &DataFrame| -> Vec<(Option<Value>, Option<Value>)> {
+        (0..df.row_count())
+            .map(|i| {
+                (
+                    df.get_column("ts").unwrap().get_value(i),
+                    df.get_column("start").unwrap().get_value(i),
+                )
+            })
+            .collect()
+    };
+    let i = |v: i32| Some(Value::I32(v));
+
+    let inner = events
+        .join_where(&windows, &between, JoinType::Inner)
+        .unwrap();
+    assert_eq!(
+        rows(&inner),
+        vec![(i(5), i(0)), (i(12), i(10)), (i(12), i(0))]
+    );
+    let left = events
+        .join_where(&windows, &between, JoinType::Left)
+        .unwrap();
+    assert_eq!(
+        rows(&left),
+        vec![
+            (i(5), i(0)),
+            (i(12), i(10)),
+            (i(12), i(0)),
+            (None, None),
+            (i(30), None),
+        ]
+    );
+    let right = events
+        .join_where(&windows, &between, JoinType::Right)
+        .unwrap();
+    assert_eq!(
+        rows(&right),
+        vec![(i(12), i(10)), (i(5), i(0)), (i(12), i(0)), (None, i(40))]
+    );
+
+    assert!(events.join_where(&windows, &[], JoinType::Inner).is_err());
+    let missing = [JoinPredicate::new("ts", JoinOp::Lt, "missing")];
+    assert!(events
+        .join_where(&windows, &missing, JoinType::Inner)
+        .is_err());
+}
+
+#[test]
+fn test_join_where_matches_nested_loop() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use veloxx::dataframe::conditional_join::{JoinOp, JoinPredicate};
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut frame = |prefix: &str, rows: usize| {
+        let mut columns = HashMap::new();
+        for name in ["a", "b", "g"] {
+            let name = format!("{prefix}{name}");
+            let range = if name.ends_with('g') { 0..3 } else { 0..20 };
+            let values = (0..rows)
+                .map(|_| (rng.gen_ratio(9, 10)).then(|| rng.gen_range(range.clone())))
+                .collect();
+            columns.insert(name.clone(), Series::new_i32(&name, values));
+        }
+        DataFrame::new(columns).unwrap()
+    };
+    let left = frame("l", 150);
+    let right = frame("r", 130);
+    let value =
+        |df: &DataFrame, column: &str, row: usize| df.get_column(column).unwrap().get_value(row);
+
+    let ops = [JoinOp::Lt, JoinOp::Lte, JoinOp::Gt, JoinOp::Gte, JoinOp::Ne];
+    for &x in &ops {
+        for &y in &ops {
+            for grouped in [false, true] {
+                let mut predicates = vec![
+                    JoinPredicate::new("la", x, "ra"),
+                    JoinPredicate::new("lb", y, "rb"),
+                ];
+                if grouped {
+                    predicates.push(JoinPredicate::new("lg", JoinOp::Eq, "rg"));
+                }
+                let mut expected = Vec::new();
+                for l in 0..left.row_count() {
+                    for r in 0..right.row_count() {
+                        let holds = predicates.iter().all(|p| {
+                            match (value(&left, &p.left, l), value(&right, &p.right, r)) {
+                                (Some(a), Some(b)) => match p.op {
+                                    JoinOp::Eq => a == b,
+                                    JoinOp::Ne => a != b,
+                                    JoinOp::Lt => a < b,
+                                    JoinOp::Lte => a <= b,
+                                    JoinOp::Gt => a > b,
+                                    JoinOp::Gte => a >= b,
+                                },
+                                _ => false,
+                            }
+                        });
+                        if holds {
+                            expected.push((
+                                value(&left, "la", l),
+                                value(&right, "ra", r),
+                                value(&left, "lb", l),
+                                value(&right, "rb", r),
+                            ));
+                        }
+                    }
+                }
+                let joined = left
+                    .join_where(&right, &predicates, JoinType::Inner)
+                    .unwrap();
+                let actual: Vec<_> = (0..joined.row_count())
+                    .map(|i| {
+                        (
+                            value(&joined, "la", i),
+                            value(&joined, "ra", i),
+                            value(&joined, "lb", i),
+                            value(&joined, "rb", i),
+                        )
+                    })
+                    .collect();
+                assert_eq!(actual, expected, "{x:?} {y:?} grouped={grouped}");
+            }
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use veloxx::dataframe::join::JoinType;
+use veloxx::df;
+use veloxx::lazy::{binary_op, col, lit, BinaryOperator, LazyDataFrame};
+use veloxx::types::Value;
+
+fn sample() -> veloxx::dataframe::DataFrame {
+    df!(
+        "city" => ["a", "b", "a", "c"],
+        "sales" => [10, 20, 30, 40],
+        "price" => [1.0, 2.0, 3.0, 4.0],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_lazy_filter_is_applied() {
+    let result = sample()
+        .lazy()
+        .filter(binary_op(
+            col("sales"),
+            BinaryOperator::Gt,
+            lit(Value::I32(15)),
+        ))
+        .select(vec![col("sales")])
+        .collect()
+        .unwrap();
+
+    assert_eq!(result.row_count(), 3);
+    assert_eq!(result.column_count(), 1);
+}
+
+#[test]
+fn test_lazy_with_column_and_sort() {
+    let result = LazyDataFrame::from_dataframe(sample())
+        .with_column(
+            "revenue",
+            binary_op(col("price"), BinaryOperator::Multiply, lit(Value::F64(2.0))),
+        )
+        .sort(vec!["revenue".to_string()], false)
+        .collect()
+        .unwrap();
+
+    assert_eq!(
+        result.get_column("revenue").unwrap().get_value(0),
+        Some(Value::F64(8.0))
+    );
+}
+
+#[test]
+fn test_lazy_group_by_agg() {
+    let result = sample()
+        .lazy()
+        .group_by(vec!["city".to_string()])
+        .agg(vec![veloxx::lazy::Aggregation::Sum("sales".to_string())])
+        .sort(vec!["city".to_string()], true)
+        .collect()
+        .unwrap();
+
+    assert_eq!(result.row_count(), 3);
+    assert_eq!(
+        result.get_column("sales_sum").unwrap().get_value(0),
+        Some(Value::I32(40))
+    );
+}
+
+#[test]
+fn test_lazy_join_and_explain() {
+    let regions = df!("city" => ["a", "b"], "region" => ["north", "south"]).unwrap();
+    let lazy = sample()
+        .lazy()
+        .join(regions.lazy(), "city", JoinType::Inner)
+        .filter(binary_op(
+            col("sales"),
+            BinaryOperator::Lt,
+            lit(Value::I32(25)),
+        ));
+
+    let plan = lazy.explain(false);
+    assert!(plan.starts_with("FILTER (col(\"sales\") < 25)"));
+    assert!(plan.contains("Inner JOIN ON \"city\""));
+
+    let result = lazy.collect().unwrap();
+    assert_eq!(result.row_count(), 2);
+}
+
+#[test]
+fn test_lazy_join_reordering_uses_stats() {
+    let n = 2_000;
+    let orders = df!(
+        "order" => 0..n,
+        "customer" => (0..n).map(|i| i % 100),
+        "product" => (0..n).map(|i| i % 1_000),
+    )
+    .unwrap();
+    let customers = df!("customer" => 0..100, "tier" => (0..100).map(|i| i % 3)).unwrap();
+    let products = df!("product" => [1, 2, 3], "label" => ["x", "y", "z"]).unwrap();
+
+    let lazy = orders
+        .lazy()
+        .join(customers.lazy(), "customer", JoinType::Inner)
+        .join(products.lazy(), "product", JoinType::Inner);
+
+    // The selective product join runs first and broadcasts the small side
+    let plan = lazy.explain(true);
+    let outer = plan.find("JOIN ON \"customer\"").unwrap();
+    let inner = plan.find("JOIN ON \"product\" USING Broadcast").unwrap();
+    assert!(outer < inner, "{}", plan);
+
+    let optimized = lazy.clone().collect().unwrap();
+    let unoptimized = lazy.collect_unoptimized().unwrap();
+    assert_eq!(optimized.row_count(), 6);
+    assert_eq!(optimized.to_csv_string(), unoptimized.to_csv_string());
+}
+
+#[test]
+fn test_lazy_with_column_chain_is_fused() {
+    let n = 10_000;
+    let df = df!("x" => 0..n, "y" => (0..n).map(|i| i as f64 * 0.5)).unwrap();
+    let doubled = binary_op(col("x"), BinaryOperator::Multiply, lit(Value::I32(2)));
+    let shifted = binary_op(col("doubled"), BinaryOperator::Add, lit(Value::I32(1)));
+    let big = binary_op(col("shifted"), BinaryOperator::Gt, lit(Value::I32(10_000)));
+
+    let fused = df
+        .clone()
+        .lazy()
+        .with_column("doubled", doubled.clone())
+        .with_column("shifted", shifted.clone())
+        .with_column("big", big.clone())
+        .collect()
+        .unwrap();
+    let eager = df
+        .with_column("doubled", &(&doubled).into())
+        .and_then(|df| df.with_column("shifted", &(&shifted).into()))
+        .and_then(|df| df.with_column("big", &(&big).into()))
+        .unwrap();
+    assert_eq!(fused.to_csv_string(), eager.to_csv_string());
+    assert_eq!(
+        fused
+            .get_column("shifted")
+            .unwrap()
+            .get_value(n as usize - 1),
+        Some(Value::I32(2 * (n - 1) + 1))
+    );
+
+    let clash = sample()
+        .lazy()
+        .with_column("extra", col("sales"))
+        .with_column("extra", col("price"))
+        .collect();
+    assert!(clash.is_err());
+}
+
+#[test]
+fn test_scan_csv_aggregate_pushdown() {
+    use veloxx::lazy::Aggregation;
+
+    let path = std::env::temp_dir().join(format!("veloxx_scan_agg_{}.csv", std::process::id()));
+    let mut csv = String::from("city,sales,price\n");
+    for i in 0..5_000 {
+        let sales = if i % 10 == 0 {
+            String::new()
+        } else {
+            (i % 7).to_string()
+        };
+        csv.push_str(&format!("c{},{},{}\n", i % 3, sales, i as f64 * 0.5));
+    }
+    std::fs::write(&path, csv).unwrap();
+    let path = path.to_str().unwrap();
+
+    let aggregations = vec![
+        Aggregation::Sum("sales".to_string()),
+        Aggregation::Count("sales".to_string()),
+        Aggregation::Min("price".to_string()),
+        Aggregation::Max("price".to_string()),
+    ];
+    let lazy = LazyDataFrame::scan_csv(path).agg(aggregations);
+    let plan = lazy.explain(true);
+    assert!(plan.starts_with("CSV SCAN"), "{}", plan);
+    assert!(plan.contains("AGG [sum(col(\"sales\"))"), "{}", plan);
+
+    let pushed = lazy.clone().collect().unwrap();
+    let materialized = lazy.collect_unoptimized().unwrap();
+    assert_eq!(pushed.to_csv_string(), materialized.to_csv_string());
+    assert_eq!(pushed.row_count(), 1);
+    assert_eq!(
+        pushed.get_column("sales_count").unwrap().get_value(0),
+        Some(Value::I32(4_500))
+    );
+    assert_eq!(
+        pushed.get_column("price_max").unwrap().get_value(0),
+        Some(Value::F64(2_499.5))
+    );
+
+    // Non-numeric columns fall back to reading the file
+    let cities = LazyDataFrame::scan_csv(path)
+        .agg(vec![Aggregation::Max("city".to_string())])
+        .collect()
+        .unwrap();
+    assert_eq!(
+        cities.get_column("city_max").unwrap().get_value(0),
+        Some(Value::String("c2".to_string()))
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_group_by_computed_keys() {
+    use veloxx::expressions::Expr;
+    use veloxx::lazy::{dt_truncate, Aggregation};
+
+    let day = 86_400i64;
+    let ts = [60, day + 5, 3_600, 2 * day, day + 7_200]
+        .map(Some)
+        .to_vec();
+    let mut df = df!("sales" => [1, 2, 3, 4, 5]).unwrap();
+    df.insert_column(veloxx::series::Series::new_datetime("ts", ts))
+        .unwrap();
+    let sorted_by_ts = |df: veloxx::dataframe::DataFrame| df.sort(vec!["ts".to_string()], true);
+
+    let eager = df
+        .group_by_exprs(&[Expr::dt_truncate(Expr::Column("ts".to_string()), "1d")])
+        .unwrap()
+        .agg(vec![("sales", "sum")])
+        .unwrap();
+    let eager = sorted_by_ts(eager).unwrap();
+    assert_eq!(
+        eager.get_column("ts").unwrap().get_data_datetime().unwrap(),
+        vec![Some(0), Some(day), Some(2 * day)]
+    );
+    assert_eq!(
+        eager
+            .get_column("sales_sum")
+            .unwrap()
+            .get_data_i32()
+            .unwrap(),
+        vec![Some(4), Some(7), Some(4)]
+    );
+    // The frame itself is left without helper columns
+    assert_eq!(df.column_count(), 2);
+
+    let lazy = df
+        .clone()
+        .lazy()
+        .group_by_exprs(vec![("ts", dt_truncate(col("ts"), "1d"))])
+        .agg(vec![Aggregation::Sum("sales".to_string())]);
+    let plan = lazy.explain(true);
+    assert!(
+        plan.starts_with("GROUP BY [ts = dt_truncate(col(\"ts\"), \"1d\")]"),
+        "{}",
+        plan
+    );
+    let lazy = sorted_by_ts(lazy.collect().unwrap()).unwrap();
+    assert_eq!(lazy.to_csv_string(), eager.to_csv_string());
+
+    // Unnamed computed keys and bad intervals are rejected
+    let doubled = Expr::Add(
+        Box::new(Expr::Column("sales".to_string())),
+        Box::new(Expr::Column("sales".to_string())),
+    );
+    assert!(df.group_by_exprs(&[doubled]).is_err());
+    assert!(df
+        .group_by_exprs(&[Expr::dt_truncate(Expr::Column("ts".to_string()), "1 day")])
+        .is_err());
+}
+
+#[test]
+fn test_lazy_profile_reports_each_operator() {
+    let regions = df!("city" => ["a", "b", "c"], "region" => ["n", "s", "n"]).unwrap();
+    let lazy = sample()
+        .lazy()
+        .with_column(
+            "doubled",
+            binary_op(col("sales"), BinaryOperator::Multiply, lit(Value::I32(2))),
+        )
+        .with_column(
+            "big",
+            binary_op(col("doubled"), BinaryOperator::Gt, lit(Value::I32(30))),
+        )
+        .join(regions.lazy(), "city", JoinType::Inner)
+        .sort(vec!["sales".to_string()], false);
+
+    let (result, profile) = lazy.clone().profile().unwrap();
+    assert_eq!(
+        result.to_csv_string(),
+        lazy.collect().unwrap().to_csv_string()
+    );
+
+    let text = |column: &str, row: usize| match profile.get_column(column).unwrap().get_value(row) {
+        Some(Value::String(s)) => s,
+        other => panic!("unexpected {:?}", other),
+    };
+    let int = |column: &str, row: usize| profile.get_column(column).unwrap().get_value(row);
+    // Inputs come before the operators reading them; the sort runs last
+    let last = profile.row_count() - 1;
+    assert_eq!(profile.row_count(), 5);
+    assert!(text("operator", last).starts_with("SORT"));
+    assert_eq!(int("depth", last), Some(Value::I32(0)));
+    assert_eq!(int("rows_in", last), Some(Value::I32(4)));
+    assert!(text("operator", 1).starts_with("WITH COLUMNS [\"doubled\", \"big\"] (fused)"));
+    assert_eq!(int("depth", 1), Some(Value::I32(2)));
+    let join = (0..=last)
+        .find(|&row| text("operator", row).contains(" JOIN ON "))
+        .unwrap();
+    assert_eq!(int("rows_in", join), Some(Value::I32(7)));
+    assert_eq!(int("rows_out", join), Some(Value::I32(4)));
+
+    for row in 0..=last {
+        let time = profile.get_column("time_ms").unwrap().get_value(row);
+        let total = profile.get_column("total_time_ms").unwrap().get_value(row);
+        let (Some(Value::F64(time)), Some(Value::F64(total))) = (time, total) else {
+            panic!("missing timings on row {}", row);
+        };
+        assert!(time >= 0.0 && time <= total);
+        assert!(matches!(
+            profile.get_column("peak_memory_mb").unwrap().get_value(row),
+            Some(Value::F64(mb)) if mb > 0.0
+        ));
+    }
+}
+
+#[test]
+fn test_estimate_rows() {
+    let dir = std::env::temp_dir();
+    let small = dir.join(format!("veloxx_estimate_small_{}.csv", std::process::id()));
+    // A quoted newline is one row; trailing blank lines are none
+    std::fs::write(&small, "id,note\n1,\"two\nlines\"\n2,plain\n\n\n").unwrap();
+    let estimate = LazyDataFrame::scan_csv(small.to_str().unwrap())
+        .estimate_rows()
+        .unwrap();
+    assert_eq!(estimate.rows, 2);
+    assert!(estimate.exact);
+    assert_eq!(estimate.bytes, std::fs::metadata(&small).unwrap().len());
+    std::fs::remove_file(&small).unwrap();
+
+    let large = dir.join(format!("veloxx_estimate_large_{}.csv", std::process::id()));
+    let mut csv = String::from("id,city,score\n");
+    for i in 0..50_000 {
+        csv.push_str(&format!("{},c{},{}\n", i, i % 7, i as f64 * 0.25));
+    }
+    std::fs::write(&large, csv).unwrap();
+    let scan = LazyDataFrame::scan_csv(large.to_str().unwrap());
+    let estimate = scan.estimate_rows().unwrap();
+    assert!(!estimate.exact);
+    assert!((47_500..=52_500).contains(&estimate.rows), "{:?}", estimate);
+    let filtered = scan
+        .filter(binary_op(
+            col("id"),
+            BinaryOperator::Lt,
+            lit(Value::I32(10)),
+        ))
+        .estimate_rows()
+        .unwrap();
+    assert_eq!(filtered.rows, estimate.rows);
+    assert!(!filtered.exact);
+    std::fs::remove_file(&large).unwrap();
+
+    let in_memory = sample().lazy();
+    assert_eq!(in_memory.estimate_rows().unwrap().rows, 4);
+    let grouped = in_memory.agg(vec![veloxx::lazy::Aggregation::Sum("sales".to_string())]);
+    assert_eq!(
+        grouped.estimate_rows().unwrap(),
+        veloxx::lazy::RowEstimate {
+            rows: 1,
+            exact: true,
+            ..sample().lazy().estimate_rows().unwrap()
+        }
+    );
+    assert!(LazyDataFrame::scan_csv("/no/such/file.csv")
+        .estimate_rows()
+        .is_err());
+}
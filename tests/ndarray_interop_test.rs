@@ -0,0 +1,52 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::array;
+use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::error::VeloxxError;
+use veloxx::types::Value;
+
+#[test]
+fn test_to_ndarray_f64() {
+    let df = df!(
+        "x" => [Some(1.0), None],
+        "y" => [3, 4],
+    )
+    .unwrap();
+    let matrix = df.to_ndarray::<f64>(&["y", "x"]).unwrap();
+    assert_eq!(matrix.shape(), &[2, 2]);
+    assert_eq!(matrix[[0, 0]], 3.0);
+    assert_eq!(matrix[[0, 1]], 1.0);
+    assert!(matrix[[1, 1]].is_nan());
+}
+
+#[test]
+fn test_to_ndarray_errors() {
+    let df = df!("a" => [Some(1), None], "s" => ["x", "y"]).unwrap();
+    assert!(matches!(
+        df.to_ndarray::<i32>(&["a"]),
+        Err(VeloxxError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        df.to_ndarray::<f64>(&["s"]),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+    assert!(matches!(
+        df.to_ndarray::<f64>(&["missing"]),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_from_ndarray_round_trip() {
+    let array = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    let df = DataFrame::from_ndarray(array.view(), &["a", "b"]).unwrap();
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(
+        df.get_column("b").unwrap().get_value(2),
+        Some(Value::F64(6.0))
+    );
+    assert_eq!(df.to_ndarray::<f64>(&["a", "b"]).unwrap(), array);
+
+    assert!(DataFrame::from_ndarray(array.view(), &["a"]).is_err());
+}
@@ -0,0 +1,463 @@
+#![cfg(feature = "advanced_io")]
+
+use std::path::Path;
+
+use veloxx::advanced_io::orc::OrcFile;
+use veloxx::conditions::Condition;
+use veloxx::error::VeloxxError;
+use veloxx::types::{DataType, Value};
+
+/// Protobuf encoder for the ORC file tail
+#[derive(Default)]
+struct Message(Vec<u8>);
+
+impl Message {
+    fn uint(mut self, field: u64, value: u64) -> Self {
+        varint(&mut self.0, field << 3);
+        varint(&mut self.0, value);
+        self
+    }
+
+    fn bytes(mut self, field: u64, value: &[u8]) -> Self {
+        varint(&mut self.0, field << 3 | 2);
+        varint(&mut self.0, value.len() as u64);
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    fn message(self, field: u64, message: Message) -> Self {
+        self.bytes(field, &message.0)
+    }
+}
+
+fn varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Stores `data` as a single ZLIB chunk flagged as uncompressed
+fn chunk(data: &[u8]) -> Vec<u8> {
+    let mut out = ((data.len() << 1 | 1) as u32).to_le_bytes()[..3].to_vec();
+    out.extend_from_slice(data);
+    out
+}
+
+fn doubles(values: &[f64]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn int_statistics(values: u64, has_null: bool, min: i64, max: i64) -> Message {
+    Message::default()
+        .uint(1, values)
+        .message(
+            2,
+            Message::default().uint(1, zigzag(min)).uint(2, zigzag(max)),
+        )
+        .uint(10, has_null as u64)
+}
+
+/// Writes a two-stripe file with columns `id int`, `name string`, `score double` and
+/// `tags array<string>`, returning the byte range of the first stripe
+fn write_orc(path: &Path) -> std::ops::Range<usize> {
+    // (stream kind, column, bytes) and (encoding kind, dictionary size) per column
+    type Stripe = (u64, Vec<(u64, u64, Vec<u8>)>, Vec<(u64, u64)>);
+    let stripes: Vec<Stripe> = vec![
+        (
+            3,
+            vec![
+                // id: a run of 1, 2, 3
+                (1, 1, vec![0x00, 0x01, 0x02]),
+                // name: "a", null, "c"
+                (0, 2, vec![0xff, 0xa0]),
+                (1, 2, b"ac".to_vec()),
+                (2, 2, vec![0xfe, 0x01, 0x01]),
+                // score: 1.5, 2.5, null
+                (0, 3, vec![0xff, 0xc0]),
+                (1, 3, doubles(&[1.5, 2.5])),
+            ],
+            vec![(0, 0), (0, 0), (0, 0), (0, 0), (0, 0), (0, 0)],
+        ),
+        (
+            2,
+            vec![
+                // id: 10, 11 as a fixed delta run
+                (1, 1, vec![0xc0, 0x01, 0x14, 0x02]),
+                // name: dictionary ["x", "y"], indices 0, 1
+                (1, 2, vec![0xc0, 0x01, 0x00, 0x02]),
+                (2, 2, vec![0xc0, 0x01, 0x01, 0x00]),
+                (3, 2, b"xy".to_vec()),
+                (1, 3, doubles(&[9.0, 10.0])),
+            ],
+            vec![(2, 0), (2, 0), (3, 2), (2, 0), (2, 0), (2, 0)],
+        ),
+    ];
+
+    let mut file = b"ORC".to_vec();
+    let mut footer = Message::default().uint(1, 3);
+    let mut first_stripe = 0..0;
+    for (rows, streams, encodings) in &stripes {
+        let offset = file.len();
+        let mut stripe_footer = Message::default();
+        for (kind, column, data) in streams {
+            let data = chunk(data);
+            stripe_footer = stripe_footer.message(
+                1,
+                Message::default()
+                    .uint(1, *kind)
+                    .uint(2, *column)
+                    .uint(3, data.len() as u64),
+            );
+            file.extend(data);
+        }
+        let data_length = file.len() - offset;
+        for (kind, size) in encodings {
+            stripe_footer =
+                stripe_footer.message(2, Message::default().uint(1, *kind).uint(2, *size));
+        }
+        let stripe_footer = chunk(&stripe_footer.0);
+        file.extend(&stripe_footer);
+        if first_stripe.is_empty() {
+            first_stripe = offset..file.len();
+        }
+        footer = footer.message(
+            3,
+            Message::default()
+                .uint(1, offset as u64)
+                .uint(2, 0)
+                .uint(3, data_length as u64)
+                .uint(4, stripe_footer.len() as u64)
+                .uint(5, *rows),
+        );
+    }
+    footer = footer
+        .uint(2, file.len() as u64 - 3)
+        .message(
+            4,
+            Message::default()
+                .uint(1, 12)
+                .bytes(2, &[1, 2, 3, 4])
+                .bytes(3, b"id")
+                .bytes(3, b"name")
+                .bytes(3, b"score")
+                .bytes(3, b"tags"),
+        )
+        .message(4, Message::default().uint(1, 3))
+        .message(4, Message::default().uint(1, 7))
+        .message(4, Message::default().uint(1, 6))
+        .message(4, Message::default().uint(1, 10).bytes(2, &[5]))
+        .message(4, Message::default().uint(1, 7))
+        .uint(6, 5);
+
+    let string_statistics = Message::default()
+        .uint(1, 2)
+        .message(4, Message::default().bytes(1, b"a").bytes(2, b"c"))
+        .uint(10, 1);
+    let metadata = Message::default()
+        .message(
+            1,
+            Message::default()
+                .message(1, Message::default())
+                .message(1, int_statistics(3, false, 1, 3))
+                .message(1, string_statistics),
+        )
+        .message(
+            1,
+            Message::default()
+                .message(1, Message::default())
+                .message(1, int_statistics(2, false, 10, 11)),
+        );
+
+    let metadata = chunk(&metadata.0);
+    let footer = chunk(&footer.0);
+    file.extend(&metadata);
+    file.extend(&footer);
+    let postscript = Message::default()
+        .uint(1, footer.len() as u64)
+        .uint(2, 1)
+        .uint(3, 262_144)
+        .uint(5, metadata.len() as u64)
+        .bytes(8000, b"ORC");
+    file.extend(&postscript.0);
+    file.push(postscript.0.len() as u8);
+    std::fs::write(path, file).unwrap();
+    first_stripe
+}
+
+/// Stripe statistics and pruning, which the fixtures do not have
+#[test]
+fn test_orc_reader() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("people.orc");
+    let first_stripe = write_orc(&path);
+
+    let file = OrcFile::open(&path).unwrap();
+    assert_eq!(file.num_rows(), 5);
+    assert_eq!(file.stripe_count(), 2);
+    assert_eq!(
+        file.schema(),
+        &[
+            ("id".to_string(), DataType::I32),
+            ("name".to_string(), DataType::String),
+            ("score".to_string(), DataType::F64),
+        ]
+    );
+
+    let df = file.to_dataframe().unwrap();
+    assert_eq!(df.row_count(), 5);
+    let id = df.get_column("id").unwrap();
+    let ids: Vec<_> = (0..5).map(|i| id.get_value(i)).collect();
+    assert_eq!(ids, [1, 2, 3, 10, 11].map(|v| Some(Value::I32(v))).to_vec());
+    let name = df.get_column("name").unwrap();
+    assert_eq!(name.get_value(0), Some(Value::String("a".to_string())));
+    assert_eq!(name.get_value(1), None);
+    assert_eq!(name.get_value(2), Some(Value::String("c".to_string())));
+    assert_eq!(name.get_value(4), Some(Value::String("y".to_string())));
+    let score = df.get_column("score").unwrap();
+    assert_eq!(score.get_value(1), Some(Value::F64(2.5)));
+    assert_eq!(score.get_value(2), None);
+    assert_eq!(score.get_value(3), Some(Value::F64(9.0)));
+
+    // Projection: the filter column is read for filtering but not returned
+    let condition = Condition::Gt("id".to_string(), Value::I32(10));
+    let df = file.read(&["name"], Some(&condition)).unwrap();
+    assert_eq!(df.column_count(), 1);
+    assert_eq!(df.row_count(), 1);
+    assert_eq!(
+        df.get_column("name").unwrap().get_value(0),
+        Some(Value::String("y".to_string()))
+    );
+
+    // Corrupt the first stripe: reads that cannot prune it now fail
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[first_stripe].fill(0xff);
+    std::fs::write(&path, bytes).unwrap();
+    assert!(file.to_dataframe().is_err());
+    let df = file
+        .to_dataframe_filtered(&Condition::Gt("id".to_string(), Value::I32(5)))
+        .unwrap();
+    assert_eq!(df.row_count(), 2);
+    let df = file
+        .to_dataframe_filtered(&Condition::Gt("id".to_string(), Value::I32(20)))
+        .unwrap();
+    assert_eq!(df.row_count(), 0);
+    assert_eq!(df.column_count(), 3);
+
+    assert!(matches!(
+        file.read(&["tags"], None),
+        Err(VeloxxError::Unsupported(_))
+    ));
+    assert!(matches!(
+        file.read(&["missing"], None),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+    assert!(OrcFile::open(dir.path().join("missing.orc")).is_err());
+}
+
+/// The value of each column in row `i` of the `tests/data/orc/events_*.orc` fixtures
+fn expected_event(i: i64) -> Vec<(&'static str, Option<Value>)> {
+    vec![
+        ("id", (i % 97 != 5).then_some(Value::I32(i as i32 - 100))),
+        (
+            "big",
+            Some(Value::F64((i * i * 1_000_003 - 5_000_000_000) as f64)),
+        ),
+        ("score", (i % 7 != 3).then_some(Value::F64(i as f64 * 0.25))),
+        ("ratio", Some(Value::F64((i as f32 / 8.0) as f64))),
+        (
+            "name",
+            (i % 11 != 4).then(|| Value::String(format!("user{}", i % 13))),
+        ),
+        ("flag", Some(Value::Bool(i % 3 == 0))),
+        (
+            "payload",
+            Some(Value::Binary((i as u32).to_le_bytes().to_vec())),
+        ),
+        ("day", Some(Value::DateTime((19_000 + i / 10) * 86_400))),
+        // Written with a quarter second, which is truncated
+        (
+            "at",
+            (i % 5 != 2).then_some(Value::DateTime(1_704_067_200 + i * 90)),
+        ),
+        ("at_utc", Some(Value::DateTime(1_500_000_000 - i * 3_601))),
+    ]
+}
+
+/// Files written by orc-rust's `ArrowWriter` (see `tests/data/orc/README.md`), one
+/// per compression codec
+#[test]
+fn test_orc_fixtures() {
+    for codec in ["none", "zlib", "snappy", "lz4", "zstd"] {
+        let path = format!(
+            "{}/tests/data/orc/events_{}.orc",
+            env!("CARGO_MANIFEST_DIR"),
+            codec
+        );
+        let file = OrcFile::open(&path).unwrap();
+        assert_eq!(file.num_rows(), 1500, "{}", codec);
+        assert_eq!(file.stripe_count(), 3, "{}", codec);
+        let types: Vec<_> = file.schema().iter().map(|(_, t)| t.clone()).collect();
+        assert_eq!(
+            types,
+            [
+                DataType::I32,
+                DataType::F64,
+                DataType::F64,
+                DataType::F64,
+                DataType::String,
+                DataType::Bool,
+                DataType::Binary,
+                DataType::DateTime,
+                DataType::DateTime,
+                DataType::DateTime,
+            ]
+        );
+
+        let df = file.to_dataframe().unwrap();
+        assert_eq!(df.row_count(), 1500);
+        for i in 0..1500 {
+            for (column, expected) in expected_event(i) {
+                let value = df.get_column(column).unwrap().get_value(i as usize);
+                assert_eq!(value, expected, "{} row {} of {}", column, i, codec);
+            }
+        }
+
+        let condition = Condition::Eq("name".to_string(), Value::String("user7".to_string()));
+        let df = file.read(&["id", "day"], Some(&condition)).unwrap();
+        let expected: Vec<_> = (0..1500).filter(|i| i % 11 != 4 && i % 13 == 7).collect();
+        assert_eq!(df.column_count(), 2);
+        assert_eq!(df.row_count(), expected.len());
+        let day = df.get_column("day").unwrap();
+        for (row, i) in expected.into_iter().enumerate() {
+            assert_eq!(day.get_value(row), expected_event(i)[7].1);
+        }
+    }
+}
+
+/// A truncated or corrupted tail is a parsing error, never a panic or an
+/// allocation sized by garbage
+#[test]
+fn test_orc_corrupt_tail() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("people.orc");
+    write_orc(&path);
+    let bytes = std::fs::read(&path).unwrap();
+
+    let truncated = dir.path().join("truncated.orc");
+    for length in 0..bytes.len() {
+        std::fs::write(&truncated, &bytes[..length]).unwrap();
+        assert!(
+            matches!(OrcFile::open(&truncated), Err(VeloxxError::Parsing(_))),
+            "truncated to {} bytes",
+            length
+        );
+    }
+
+    // Overwrite each byte of the stripe footers, metadata, footer and postscript
+    let corrupt = dir.path().join("corrupt.orc");
+    for position in 3..bytes.len() {
+        for value in [0x00, 0x7f, 0xff] {
+            let mut bytes = bytes.clone();
+            bytes[position] = value;
+            std::fs::write(&corrupt, &bytes).unwrap();
+            if let Ok(file) = OrcFile::open(&corrupt) {
+                let _ = file.to_dataframe();
+            }
+        }
+    }
+
+    // A footer length pointing before the start of the file
+    let mut bytes = bytes.clone();
+    let postscript_length = *bytes.last().unwrap() as usize;
+    let postscript_start = bytes.len() - 1 - postscript_length;
+    let postscript = Message::default()
+        .uint(1, u64::MAX - 1)
+        .uint(2, 1)
+        .uint(5, 2)
+        .bytes(8000, b"ORC");
+    bytes.truncate(postscript_start);
+    bytes.extend(&postscript.0);
+    bytes.push(postscript.0.len() as u8);
+    std::fs::write(&corrupt, &bytes).unwrap();
+    assert!(matches!(
+        OrcFile::open(&corrupt),
+        Err(VeloxxError::Parsing(message)) if message.contains("footer is larger")
+    ));
+}
+
+/// `bigint` values are read as `F64` only while it holds them exactly
+#[test]
+fn test_orc_bigint_precision() {
+    let dir = tempfile::tempdir().unwrap();
+    let write = |name: &str, values: &[i64]| {
+        // RLE v1 literals of zigzag varints
+        let mut data = vec![(values.len() as i8).wrapping_neg() as u8];
+        for &value in values {
+            varint(&mut data, zigzag(value));
+        }
+        let mut file = b"ORC".to_vec();
+        let data = chunk(&data);
+        file.extend(&data);
+        let stripe_footer = Message::default()
+            .message(
+                1,
+                Message::default()
+                    .uint(1, 1)
+                    .uint(2, 1)
+                    .uint(3, data.len() as u64),
+            )
+            .message(2, Message::default())
+            .message(2, Message::default());
+        let stripe_footer = chunk(&stripe_footer.0);
+        file.extend(&stripe_footer);
+        let footer = Message::default()
+            .message(
+                3,
+                Message::default()
+                    .uint(1, 3)
+                    .uint(3, data.len() as u64)
+                    .uint(4, stripe_footer.len() as u64)
+                    .uint(5, values.len() as u64),
+            )
+            .message(
+                4,
+                Message::default()
+                    .uint(1, 12)
+                    .bytes(2, &[1])
+                    .bytes(3, b"big"),
+            )
+            .message(4, Message::default().uint(1, 4))
+            .uint(6, values.len() as u64);
+        let footer = chunk(&footer.0);
+        file.extend(&footer);
+        let postscript = Message::default()
+            .uint(1, footer.len() as u64)
+            .uint(2, 1)
+            .bytes(8000, b"ORC");
+        file.extend(&postscript.0);
+        file.push(postscript.0.len() as u8);
+        let path = dir.path().join(name);
+        std::fs::write(&path, file).unwrap();
+        OrcFile::open(path).unwrap()
+    };
+
+    let exact = write("exact.orc", &[-(1 << 53), 1 << 53]);
+    assert_eq!(exact.schema(), &[("big".to_string(), DataType::F64)]);
+    let big = exact.to_dataframe().unwrap();
+    assert_eq!(
+        big.get_column("big").unwrap().get_value(1),
+        Some(Value::F64(9_007_199_254_740_992.0))
+    );
+
+    let rounded = write("rounded.orc", &[1, (1 << 53) + 1]);
+    assert!(matches!(
+        rounded.to_dataframe(),
+        Err(VeloxxError::Unsupported(message)) if message.contains("9007199254740993")
+    ));
+}
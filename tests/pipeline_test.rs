@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::error::VeloxxError;
+use veloxx::pipeline::Pipeline;
+use veloxx::types::Value;
+
+/// A pipeline counting how often each of its three steps runs, whose last
+/// step fails while `fail` is set
+fn counted_pipeline(
+    dir: &std::path::Path,
+    calls: &Arc<[AtomicUsize; 3]>,
+    fail: &Arc<AtomicBool>,
+) -> Pipeline {
+    let (first, second, third) = (calls.clone(), calls.clone(), calls.clone());
+    let fail = fail.clone();
+    Pipeline::new()
+        .step("clean", move |df: &DataFrame| {
+            first[0].fetch_add(1, Ordering::SeqCst);
+            df.drop_nulls(None)
+        })
+        .step("sort", move |df: &DataFrame| {
+            second[1].fetch_add(1, Ordering::SeqCst);
+            df.sort(vec!["x".to_string()], false)
+        })
+        .step("top", move |df: &DataFrame| {
+            third[2].fetch_add(1, Ordering::SeqCst);
+            if fail.load(Ordering::SeqCst) {
+                return Err(VeloxxError::InvalidOperation("flaky".to_string()));
+            }
+            df.filter_by_indices(&[0])
+        })
+        .checkpoint_dir(dir)
+}
+
+#[test]
+fn test_pipeline_resumes_from_last_checkpoint() {
+    let dir = std::env::temp_dir().join(format!("veloxx_pipeline_{}", std::process::id()));
+    let calls = Arc::new([
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+    ]);
+    let fail = Arc::new(AtomicBool::new(true));
+    let pipeline = counted_pipeline(&dir, &calls, &fail);
+    assert_eq!(pipeline.step_names(), vec!["clean", "sort", "top"]);
+    let count = |i: usize| calls[i].load(Ordering::SeqCst);
+
+    let input = df!("x" => [Some(4), None, Some(9), Some(1)]).unwrap();
+    let err = pipeline.run(&input).unwrap_err();
+    assert!(err.to_string().contains("pipeline step 'top'"), "{}", err);
+    assert_eq!((count(0), count(1), count(2)), (1, 1, 1));
+
+    // The rerun starts after the checkpointed "sort" step
+    fail.store(false, Ordering::SeqCst);
+    let output = pipeline.run(&input).unwrap();
+    assert_eq!(
+        output.get_column("x").unwrap().get_value(0),
+        Some(Value::I32(9))
+    );
+    assert_eq!((count(0), count(1), count(2)), (1, 1, 2));
+
+    // A finished pipeline is read straight from the last checkpoint
+    let cached = pipeline.run(&input).unwrap();
+    assert_eq!(cached.get_column("x"), output.get_column("x"));
+    assert_eq!((count(0), count(1), count(2)), (1, 1, 2));
+
+    // Another input does not reuse the checkpoints
+    let other = df!("x" => [Some(2), Some(7)]).unwrap();
+    let output = pipeline.run(&other).unwrap();
+    assert_eq!(
+        output.get_column("x").unwrap().get_value(0),
+        Some(Value::I32(7))
+    );
+    assert_eq!((count(0), count(1), count(2)), (2, 2, 3));
+
+    pipeline.clear_checkpoints().unwrap();
+    pipeline.run(&other).unwrap();
+    assert_eq!((count(0), count(1), count(2)), (3, 3, 4));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_pipeline_without_checkpoints() {
+    let input = df!("x" => [1, 2]).unwrap();
+    assert_eq!(Pipeline::new().run(&input).unwrap().row_count(), 2);
+
+    let duplicated = Pipeline::new()
+        .step("a", |df: &DataFrame| Ok(df.clone()))
+        .step("a", |df: &DataFrame| Ok(df.clone()));
+    assert!(duplicated.run(&input).is_err());
+}
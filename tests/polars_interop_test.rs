@@ -0,0 +1,71 @@
+#![cfg(feature = "polars")]
+
+use veloxx::df;
+use veloxx::types::Value;
+
+#[test]
+fn test_from_polars() {
+    let pl = polars::df!(
+        "id" => &[1i64, 2, 3],
+        "score" => &[Some(1.5), None, Some(3.5)],
+        "name" => &["a", "b", "c"],
+        "flag" => &[true, false, true],
+    )
+    .unwrap();
+
+    let df = veloxx::dataframe::DataFrame::from_polars(&pl).unwrap();
+    assert_eq!(df.row_count(), 3);
+    assert_eq!(
+        df.get_column("id").unwrap().get_value(2),
+        Some(Value::I32(3))
+    );
+    assert_eq!(df.get_column("score").unwrap().get_value(1), None);
+    assert_eq!(
+        df.get_column("name").unwrap().get_value(0),
+        Some(Value::String("a".to_string()))
+    );
+    assert_eq!(
+        df.get_column("flag").unwrap().get_value(1),
+        Some(Value::Bool(false))
+    );
+}
+
+#[test]
+fn test_polars_round_trip() {
+    let df = df!(
+        "a" => [Some(1), None, Some(3)],
+        "b" => [Some("x"), Some("y"), None],
+    )
+    .unwrap();
+
+    let pl = df.to_polars().unwrap();
+    assert_eq!(pl.shape(), (3, 2));
+    assert_eq!(pl.column("a").unwrap().null_count(), 1);
+
+    let back = veloxx::dataframe::DataFrame::from_polars(&pl).unwrap();
+    assert_eq!(back.get_column("a").unwrap(), df.get_column("a").unwrap());
+    assert_eq!(back.get_column("b").unwrap(), df.get_column("b").unwrap());
+}
+
+#[test]
+fn test_polars_datetime_round_trip_keeps_seconds() {
+    let new_year = veloxx::io::datetime::parse_datetime("2024-01-01", "%Y-%m-%d").unwrap();
+    let df = veloxx::dataframe::DataFrame::builder()
+        .col_datetime("when", [Some(new_year), None])
+        .build()
+        .unwrap();
+
+    let pl = df.to_polars().unwrap();
+    let when = pl.column("when").unwrap();
+    assert_eq!(
+        when.dtype(),
+        &polars::prelude::DataType::Datetime(polars::prelude::TimeUnit::Milliseconds, None)
+    );
+
+    let back = veloxx::dataframe::DataFrame::from_polars(&pl).unwrap();
+    assert_eq!(
+        back.get_column("when").unwrap().get_value(0),
+        Some(Value::DateTime(1_704_067_200))
+    );
+    assert_eq!(back.get_column("when").unwrap().get_value(1), None);
+}
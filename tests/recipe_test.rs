@@ -0,0 +1,122 @@
+#![cfg(feature = "recipes")]
+
+use std::collections::HashMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::recipe::{Recipe, RecipeExpr, RecipeStep};
+use veloxx::types::Value;
+
+fn inputs() -> HashMap<String, DataFrame> {
+    let orders = df!(
+        "customer" => [1, 2, 1, 3, 2],
+        "amount" => [10.0, 25.0, 40.0, 5.0, 15.0],
+        "status" => ["paid", "paid", "paid", "void", "paid"],
+    )
+    .unwrap();
+    let customers = df!("customer" => [1, 2, 3], "region" => ["north", "south", "north"]).unwrap();
+    HashMap::from([
+        ("orders".to_string(), orders),
+        ("customers".to_string(), customers),
+    ])
+}
+
+const JSON_RECIPE: &str = r#"{
+    "input": "orders",
+    "steps": [
+        {"filter": {"and": [
+            {"eq": [{"col": "status"}, {"lit": "paid"}]},
+            {"gt_eq": [{"col": "amount"}, {"lit": 12.5}]}
+        ]}},
+        {"join": {"right": "customers", "on": "customer"}},
+        {"group_by": {
+            "keys": ["region"],
+            "aggregations": [
+                {"column": "amount", "function": "sum"},
+                {"column": "amount", "function": "count"}
+            ]
+        }},
+        {"sort": {"by": ["region"]}}
+    ]
+}"#;
+
+#[test]
+fn test_json_recipe_matches_lazy_plan() {
+    let recipe = Recipe::from_json(JSON_RECIPE).unwrap();
+    assert_eq!(recipe.steps.len(), 4);
+    let result = recipe.execute(&inputs()).unwrap();
+
+    assert_eq!(
+        result
+            .get_column("region")
+            .unwrap()
+            .get_data_string()
+            .unwrap(),
+        vec![Some("north".to_string()), Some("south".to_string())]
+    );
+    assert_eq!(
+        result
+            .get_column("amount_sum")
+            .unwrap()
+            .get_data_f64()
+            .unwrap(),
+        vec![Some(40.0), Some(40.0)]
+    );
+
+    // Serializing and parsing again gives the same recipe
+    let round_trip = Recipe::from_json(&recipe.to_json().unwrap()).unwrap();
+    assert_eq!(round_trip, recipe);
+}
+
+#[test]
+fn test_yaml_recipe_with_computed_key_and_select() {
+    let recipe = Recipe::from_yaml(
+        r#"
+input: orders
+steps:
+  - with_column:
+      name: big
+      expr: {gt: [{col: amount}, {lit: 20.0}]}
+  - select: [big, amount]
+  - group_by:
+      keys: [{name: is_big, expr: {col: big}}]
+      aggregations: [{column: amount, function: max}]
+  - sort: {by: [is_big], ascending: false}
+"#,
+    )
+    .unwrap();
+    assert!(matches!(recipe.steps[1], RecipeStep::Select(ref c) if c.len() == 2));
+    let result = recipe.execute(&inputs()).unwrap();
+    assert_eq!(result.row_count(), 2);
+    assert_eq!(
+        result.get_column("is_big").unwrap().get_value(0),
+        Some(Value::Bool(true))
+    );
+    assert_eq!(
+        result.get_column("amount_max").unwrap().get_value(0),
+        Some(Value::F64(40.0))
+    );
+}
+
+#[test]
+fn test_invalid_recipes_are_rejected() {
+    assert!(Recipe::from_json(r#"{"input": "orders", "steps": [{"explode": "x"}]}"#).is_err());
+    assert!(Recipe::from_yaml("steps: []").is_err());
+
+    let missing_input = Recipe::from_yaml("input: invoices").unwrap();
+    assert!(missing_input.execute(&inputs()).is_err());
+
+    let bad_aggregation = Recipe::from_yaml(
+        "input: orders\nsteps:\n  - aggregate: [{column: amount, function: mode}]",
+    )
+    .unwrap();
+    assert!(bad_aggregation.to_lazy(&inputs()).is_err());
+
+    let not_paid = RecipeExpr::Not(Box::new(RecipeExpr::Eq(Box::new([
+        RecipeExpr::Col("status".to_string()),
+        RecipeExpr::Lit(veloxx::recipe::RecipeLiteral::String("paid".to_string())),
+    ]))));
+    assert_eq!(
+        not_paid.to_expr().to_string(),
+        "NOT (col(\"status\") == paid)"
+    );
+}
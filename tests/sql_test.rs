@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::query::sql::{self, Predicate, SqlQuery};
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+fn sales() -> DataFrame {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "city".to_string(),
+        Series::new_string(
+            "city",
+            ["Paris", "Oslo", "Paris", "Rome"]
+                .iter()
+                .map(|c| Some(c.to_string()))
+                .collect(),
+        ),
+    );
+    columns.insert(
+        "sales".to_string(),
+        Series::new_f64("sales", vec![Some(10.0), Some(5.0), Some(2.5), None]),
+    );
+    columns.insert(
+        "qty".to_string(),
+        Series::new_i32("qty", vec![Some(1), Some(2), None, Some(4)]),
+    );
+    DataFrame::new(columns).unwrap()
+}
+
+fn column(df: &DataFrame, name: &str) -> Vec<Option<Value>> {
+    let series = df.get_column(name).unwrap();
+    (0..series.len()).map(|i| series.get_value(i)).collect()
+}
+
+#[test]
+fn test_sql_group_by_with_order_and_limit() {
+    let df = sql::execute(
+        &sales(),
+        "select city, sum(sales) as total, count(*), max(qty) from sales \
+         group by city order by total desc limit 2;",
+    )
+    .unwrap();
+    assert_eq!(df.column_count(), 4);
+    assert_eq!(
+        column(&df, "city"),
+        vec![
+            Some(Value::String("Paris".into())),
+            Some(Value::String("Oslo".into()))
+        ]
+    );
+    assert_eq!(
+        column(&df, "total"),
+        vec![Some(Value::F64(12.5)), Some(Value::F64(5.0))]
+    );
+    assert_eq!(
+        column(&df, "count"),
+        vec![Some(Value::I32(2)), Some(Value::I32(1))]
+    );
+    assert_eq!(
+        column(&df, "qty_max"),
+        vec![Some(Value::I32(1)), Some(Value::I32(2))]
+    );
+
+    let overall = sql::execute(&sales(), "SELECT avg(sales), count(qty)").unwrap();
+    assert_eq!(
+        column(&overall, "sales_mean"),
+        vec![Some(Value::F64(17.5 / 3.0))]
+    );
+    assert_eq!(column(&overall, "qty_count"), vec![Some(Value::I32(3))]);
+}
+
+#[test]
+fn test_sql_where_uses_three_valued_logic() {
+    let df = sales();
+    let cities = |query: &str| column(&sql::execute(&df, query).unwrap(), "city");
+
+    // Null sales never compare, in either direction
+    assert_eq!(
+        cities("select city where sales >= 5 order by city").len(),
+        2
+    );
+    assert_eq!(cities("select city where not sales >= 5").len(), 1);
+    assert_eq!(
+        cities("select city where sales is null or qty > 3"),
+        vec![Some(Value::String("Rome".into()))]
+    );
+    assert_eq!(
+        cities("select city where city not in ('Paris', 'Rome') and qty <> 1"),
+        vec![Some(Value::String("Oslo".into()))]
+    );
+    // Integer literals compare with float columns
+    assert_eq!(
+        cities("select city as city where (sales = 10 or sales < 3) and qty is not null"),
+        vec![Some(Value::String("Paris".into()))]
+    );
+
+    let query = SqlQuery::parse("select * from t where qty > -1").unwrap();
+    assert_eq!(
+        query.predicate,
+        Some(Predicate::Compare(
+            "qty".to_string(),
+            sql::CompareOp::Gt,
+            Value::I32(-1)
+        ))
+    );
+    assert_eq!(query.execute(&df).unwrap().row_count(), 3);
+}
+
+#[test]
+fn test_sql_errors() {
+    let df = sales();
+    assert!(sql::execute(&df, "select city, sum(sales)").is_err());
+    assert!(sql::execute(&df, "select median(sales)").is_err());
+    assert!(sql::execute(&df, "select missing").is_err());
+    assert!(sql::execute(&df, "select city where sales > 'x'").is_err());
+    assert!(sql::execute(&df, "select city order by sales").is_err());
+    assert!(SqlQuery::parse("select city where").is_err());
+    assert!(SqlQuery::parse("select 'city").is_err());
+    assert!(SqlQuery::parse("select city limit 2 extra").is_err());
+}
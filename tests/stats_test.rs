@@ -0,0 +1,89 @@
+use veloxx::dataframe::DataFrame;
+use veloxx::df;
+use veloxx::error::VeloxxError;
+use veloxx::stats;
+use veloxx::types::Value;
+
+fn column_mean(df: &DataFrame, column: &str) -> Result<f64, VeloxxError> {
+    let series = df
+        .get_column(column)
+        .ok_or_else(|| VeloxxError::ColumnNotFound(column.to_string()))?;
+    match series.mean()? {
+        Value::F64(mean) => Ok(mean),
+        other => panic!("Expected F64 mean, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_bootstrap_is_reproducible() {
+    let df = df!("x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap();
+    let means = stats::bootstrap(&df, |s| column_mean(s, "x"), 500, 11).unwrap();
+    assert_eq!(means.len(), 500);
+    assert!(means.iter().all(|m| (1.0..=8.0).contains(m)));
+    assert_eq!(
+        means,
+        stats::bootstrap(&df, |s| column_mean(s, "x"), 500, 11).unwrap()
+    );
+    assert_ne!(
+        means,
+        stats::bootstrap(&df, |s| column_mean(s, "x"), 500, 12).unwrap()
+    );
+
+    let (low, high) = stats::percentile_interval(&means, 0.9).unwrap();
+    assert!(low < 4.5 && 4.5 < high);
+    assert_eq!(
+        stats::percentile_interval(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.5).unwrap(),
+        (2.0, 4.0)
+    );
+    assert!(stats::percentile_interval(&means, 1.0).is_err());
+    assert!(stats::percentile_interval(&[f64::NAN], 0.9).is_err());
+
+    let empty = df!("x" => Vec::<f64>::new()).unwrap();
+    assert!(stats::bootstrap(&empty, |s| column_mean(s, "x"), 10, 0).is_err());
+    // Errors from the statistic are returned
+    assert!(stats::bootstrap(&df, |s| column_mean(s, "missing"), 10, 0).is_err());
+}
+
+#[test]
+fn test_permutation_test() {
+    let df = df!(
+        "treated" => [0, 0, 0, 0, 1, 1, 1, 1],
+        "outcome" => [1.0, 2.0, 1.5, 2.5, 9.0, 8.0, 9.5, 8.5],
+    )
+    .unwrap();
+    let mean_difference = |frame: &DataFrame| {
+        let (mut sums, mut counts) = ([0.0; 2], [0.0; 2]);
+        for row in 0..frame.row_count() {
+            let treated = frame.get_column("treated").unwrap().get_value(row);
+            let outcome = frame.get_column("outcome").unwrap().get_value(row);
+            if let (Some(Value::I32(t)), Some(Value::F64(y))) = (treated, outcome) {
+                sums[t as usize] += y;
+                counts[t as usize] += 1.0;
+            }
+        }
+        Ok(sums[1] / counts[1] - sums[0] / counts[0])
+    };
+
+    let result = stats::permutation_test(&df, "treated", mean_difference, 999, 3).unwrap();
+    assert_eq!(result.observed, 7.0);
+    assert_eq!(result.null_distribution.len(), 999);
+    // Only the observed labelling and its mirror image reach |7|: 2 of 70
+    assert!(result.p_value < 0.05);
+    assert_eq!(
+        result,
+        stats::permutation_test(&df, "treated", mean_difference, 999, 3).unwrap()
+    );
+
+    let no_effect = df!(
+        "treated" => [0, 1, 0, 1],
+        "outcome" => [1.0, 1.0, 2.0, 2.0],
+    )
+    .unwrap();
+    let result = stats::permutation_test(&no_effect, "treated", mean_difference, 99, 3).unwrap();
+    assert_eq!(result.p_value, 1.0);
+
+    assert!(matches!(
+        stats::permutation_test(&df, "missing", mean_difference, 10, 0),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
@@ -0,0 +1,143 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use veloxx::io::stream::{
+    ChannelSource, IteratorSource, RollingTable, StreamIngestor, StreamRecord,
+};
+use veloxx::series::Series;
+use veloxx::types::{DataType, Value};
+
+fn record(fields: &[(&str, Value)]) -> StreamRecord {
+    fields
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.clone()))
+        .collect()
+}
+
+#[test]
+fn test_batches_by_count_with_stable_schema() {
+    let records = vec![
+        record(&[("id", Value::I32(1)), ("price", Value::I32(10))]),
+        record(&[("id", Value::I32(2)), ("price", Value::F64(10.5))]),
+        record(&[("id", Value::I32(3)), ("tag", Value::String("a".into()))]),
+        record(&[("id", Value::I32(4)), ("price", Value::Null)]),
+        record(&[("id", Value::I32(5))]),
+    ];
+    let mut ingestor = StreamIngestor::new(IteratorSource::new(records))
+        .batch_size(2)
+        .column_type("ts", DataType::DateTime);
+
+    let mut sizes = Vec::new();
+    let mut last = None;
+    ingestor
+        .run(|batch| {
+            sizes.push(batch.row_count());
+            last = Some(batch);
+            Ok(true)
+        })
+        .unwrap();
+    assert_eq!(sizes, vec![2, 2, 1]);
+
+    let last = last.unwrap();
+    assert_eq!(last.column_count(), 4);
+    assert!(matches!(last.get_column("price"), Some(Series::F64(..))));
+    assert!(matches!(last.get_column("ts"), Some(Series::DateTime(..))));
+    assert_eq!(last.get_column("tag").unwrap().get_value(0), None);
+    assert!(ingestor.next_batch().unwrap().is_none());
+}
+
+#[test]
+fn test_type_conflict_is_an_error() {
+    let records = vec![
+        record(&[("id", Value::I32(1))]),
+        record(&[("id", Value::String("x".into()))]),
+    ];
+    let mut ingestor = StreamIngestor::new(IteratorSource::new(records));
+    assert!(ingestor.next_batch().is_err());
+}
+
+#[test]
+fn test_batches_by_time_and_stops_on_request() {
+    let (sender, receiver) = mpsc::channel();
+    let producer = std::thread::spawn(move || {
+        for i in 0..3 {
+            sender.send(record(&[("n", Value::I32(i))])).unwrap();
+        }
+        std::thread::sleep(Duration::from_millis(300));
+        for i in 3..5 {
+            sender.send(record(&[("n", Value::I32(i))])).unwrap();
+        }
+    });
+
+    let start = Instant::now();
+    let mut ingestor = StreamIngestor::new(ChannelSource::new(receiver))
+        .batch_size(100)
+        .batch_interval(Duration::from_millis(100));
+    let first = ingestor.next_batch().unwrap().unwrap();
+    assert_eq!(first.row_count(), 3);
+    assert!(start.elapsed() < Duration::from_millis(290));
+
+    let mut batches = 0;
+    ingestor
+        .run(|batch| {
+            batches += 1;
+            assert_eq!(batch.row_count(), 2);
+            Ok(false)
+        })
+        .unwrap();
+    assert_eq!(batches, 1);
+    producer.join().unwrap();
+    assert!(ingestor.next_batch().unwrap().is_none());
+}
+
+#[test]
+fn test_rolling_table_keeps_latest_rows() {
+    let table = RollingTable::new(3);
+    let reader = table.clone();
+    let records = (0..5).map(|i| {
+        let value = if i < 2 {
+            Value::I32(i)
+        } else {
+            Value::F64(i as f64)
+        };
+        HashMap::from([("v".to_string(), value)])
+    });
+    StreamIngestor::new(IteratorSource::new(records))
+        .batch_size(2)
+        .run_into(&table)
+        .unwrap();
+
+    assert_eq!(reader.len(), 3);
+    let snapshot = reader.snapshot();
+    let values: Vec<Option<Value>> = (0..3)
+        .map(|i| snapshot.get_column("v").unwrap().get_value(i))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            Some(Value::F64(2.0)),
+            Some(Value::F64(3.0)),
+            Some(Value::F64(4.0))
+        ]
+    );
+
+    reader.clear();
+    assert!(table.is_empty());
+    assert_eq!(table.snapshot().row_count(), 0);
+}
+
+#[cfg(feature = "kafka")]
+#[test]
+fn test_kafka_json_decoder() {
+    use veloxx::io::stream::kafka::decode_json;
+
+    let decoded =
+        decode_json(br#"{"id": 7, "ok": true, "name": "x", "tags": [1], "none": null}"#).unwrap();
+    assert_eq!(decoded["id"], Value::F64(7.0));
+    assert_eq!(decoded["ok"], Value::Bool(true));
+    assert_eq!(decoded["tags"], Value::String("[1]".to_string()));
+    assert_eq!(decoded["none"], Value::Null);
+    assert!(decode_json(b"[1, 2]").is_err());
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
+use veloxx::streaming::IncrementalGroupBy;
+use veloxx::types::Value;
+
+fn batch(keys: Vec<Option<&str>>, qty: Vec<Option<i32>>) -> DataFrame {
+    let mut columns = HashMap::new();
+    columns.insert(
+        "key".to_string(),
+        Series::new_string(
+            "key",
+            keys.into_iter().map(|k| k.map(String::from)).collect(),
+        ),
+    );
+    columns.insert("qty".to_string(), Series::new_i32("qty", qty));
+    DataFrame::new(columns).unwrap()
+}
+
+fn column(df: &DataFrame, name: &str) -> Vec<Option<Value>> {
+    let series = df.get_column(name).unwrap();
+    (0..series.len()).map(|i| series.get_value(i)).collect()
+}
+
+#[test]
+fn test_incremental_group_by_accumulates_batches() {
+    let mut agg = IncrementalGroupBy::new(
+        vec!["key".to_string()],
+        vec![
+            ("qty", "sum"),
+            ("qty", "count"),
+            ("qty", "min"),
+            ("qty", "max"),
+            ("qty", "mean"),
+        ],
+    )
+    .unwrap();
+    agg.update(&batch(
+        vec![Some("a"), Some("b"), Some("a")],
+        vec![Some(1), Some(5), Some(3)],
+    ))
+    .unwrap();
+    let first = agg.snapshot().unwrap();
+    assert_eq!(
+        column(&first, "qty_sum"),
+        vec![Some(Value::I32(4)), Some(Value::I32(5))]
+    );
+
+    agg.update(&batch(
+        vec![Some("b"), None, Some("c")],
+        vec![Some(-1), Some(7), None],
+    ))
+    .unwrap();
+    assert_eq!(agg.group_count(), 4);
+    let df = agg.snapshot().unwrap();
+    assert_eq!(
+        column(&df, "key"),
+        vec![
+            Some(Value::String("a".into())),
+            Some(Value::String("b".into())),
+            None,
+            Some(Value::String("c".into())),
+        ]
+    );
+    assert_eq!(
+        column(&df, "qty_sum"),
+        vec![
+            Some(Value::I32(4)),
+            Some(Value::I32(4)),
+            Some(Value::I32(7)),
+            Some(Value::I32(0))
+        ]
+    );
+    assert_eq!(
+        column(&df, "qty_count"),
+        vec![
+            Some(Value::I32(2)),
+            Some(Value::I32(2)),
+            Some(Value::I32(1)),
+            Some(Value::I32(0))
+        ]
+    );
+    assert_eq!(
+        column(&df, "qty_min"),
+        vec![
+            Some(Value::I32(1)),
+            Some(Value::I32(-1)),
+            Some(Value::I32(7)),
+            None
+        ]
+    );
+    assert_eq!(
+        column(&df, "qty_max"),
+        vec![
+            Some(Value::I32(3)),
+            Some(Value::I32(5)),
+            Some(Value::I32(7)),
+            None
+        ]
+    );
+    assert_eq!(
+        column(&df, "qty_mean"),
+        vec![
+            Some(Value::F64(2.0)),
+            Some(Value::F64(2.0)),
+            Some(Value::F64(7.0)),
+            None
+        ]
+    );
+
+    agg.reset();
+    assert_eq!(agg.snapshot().unwrap().row_count(), 0);
+}
+
+#[test]
+fn test_incremental_group_by_widens_and_validates() {
+    let mut agg = IncrementalGroupBy::new(vec!["key".to_string()], vec![("qty", "sum")]).unwrap();
+    agg.update(&batch(vec![Some("a")], vec![Some(2)])).unwrap();
+
+    let mut columns = HashMap::new();
+    columns.insert(
+        "key".to_string(),
+        Series::new_string("key", vec![Some("a".to_string())]),
+    );
+    columns.insert("qty".to_string(), Series::new_f64("qty", vec![Some(0.5)]));
+    agg.update(&DataFrame::new(columns).unwrap()).unwrap();
+    assert_eq!(
+        column(&agg.snapshot().unwrap(), "qty_sum"),
+        vec![Some(Value::F64(2.5))]
+    );
+
+    let mut columns = HashMap::new();
+    columns.insert("key".to_string(), Series::new_i32("key", vec![Some(1)]));
+    columns.insert("qty".to_string(), Series::new_i32("qty", vec![Some(1)]));
+    assert!(agg.update(&DataFrame::new(columns).unwrap()).is_err());
+    assert!(agg
+        .update(
+            &batch(vec![Some("a")], vec![Some(1)])
+                .select_columns(vec!["key".to_string()])
+                .unwrap()
+        )
+        .is_err());
+    assert_eq!(agg.group_count(), 1);
+
+    assert!(IncrementalGroupBy::new(vec!["key".to_string()], vec![("qty", "median")]).is_err());
+    assert!(IncrementalGroupBy::new(vec![], vec![("qty", "sum")]).is_err());
+}
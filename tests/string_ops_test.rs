@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use veloxx::dataframe::DataFrame;
+use veloxx::expressions::Expr;
+use veloxx::performance::simd_string::{
+    simd_contains, simd_ends_with, simd_find, simd_starts_with, simd_to_lowercase,
+    simd_to_uppercase,
+};
+use veloxx::series::string_ops::PadSide;
+use veloxx::series::Series;
+use veloxx::types::Value;
+
+#[test]
+fn test_simd_string_kernels_match_std() {
+    let haystacks = [
+        "",
+        "a",
+        "Hello, World!",
+        "2024-01-15T10:32:07Z ERROR [auth] login failed for user=alice",
+        "GET /api/v1/users?id=42 HTTP/1.1 200 OK (12ms) - Mozilla/5.0 Zürich",
+        "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxy",
+        "ÄÖÜ straße ΑΒΓ",
+    ];
+    let needles = [
+        "",
+        "a",
+        "ERROR",
+        "user=alice",
+        "y",
+        "xy",
+        "Zürich",
+        "ß",
+        "missing",
+    ];
+
+    for h in haystacks {
+        assert_eq!(simd_to_lowercase(h), h.to_lowercase());
+        assert_eq!(simd_to_uppercase(h), h.to_uppercase());
+        for n in needles {
+            assert_eq!(simd_find(h, n), h.find(n), "find {n:?} in {h:?}");
+            assert_eq!(simd_contains(h, n), h.contains(n));
+            assert_eq!(simd_starts_with(h, n), h.starts_with(n));
+            assert_eq!(simd_ends_with(h, n), h.ends_with(n));
+        }
+    }
+
+    // Matches at every offset relative to the 16-byte blocks.
+    let line = "abcdefghijklmnopqrstuvwxyz0123456789".repeat(3);
+    for start in 0..line.len() - 5 {
+        let needle = &line[start..start + 5];
+        assert_eq!(simd_find(&line, needle), line.find(needle));
+    }
+    for len in 0..64 {
+        let haystack = format!("{}needle", "-".repeat(len));
+        assert_eq!(simd_find(&haystack, "needle"), Some(len));
+        assert_eq!(simd_find(&haystack, "needles"), None);
+    }
+    let long_needle = "x".repeat(40) + "y";
+    let haystack = "x".repeat(100) + "y";
+    assert!(simd_contains(&haystack, &long_needle));
+    assert!(!simd_contains(&haystack[..100], &long_needle));
+}
+
+#[test]
+fn test_series_string_operations() {
+    let series = Series::new_string(
+        "msg",
+        vec![
+            Some("ERROR disk full".to_string()),
+            None,
+            Some("info: Started".to_string()),
+        ],
+    );
+
+    let lower = series.str_to_lowercase().unwrap();
+    assert_eq!(
+        lower.get_value(0),
+        Some(Value::String("error disk full".to_string()))
+    );
+    assert_eq!(lower.get_value(1), None);
+    let upper = series.str_to_uppercase().unwrap();
+    assert_eq!(
+        upper.get_value(2),
+        Some(Value::String("INFO: STARTED".to_string()))
+    );
+
+    let errors = series.str_starts_with("ERROR").unwrap();
+    assert_eq!(errors.get_value(0), Some(Value::Bool(true)));
+    assert_eq!(errors.get_value(1), None);
+    assert_eq!(errors.get_value(2), Some(Value::Bool(false)));
+    let started = series.str_ends_with("Started").unwrap();
+    assert_eq!(started.get_value(2), Some(Value::Bool(true)));
+    let disk = series.str_contains("disk").unwrap();
+    assert_eq!(disk.get_value(0), Some(Value::Bool(true)));
+    assert_eq!(disk.get_value(2), Some(Value::Bool(false)));
+
+    let numbers = Series::new_i32("n", vec![Some(1)]);
+    assert!(numbers.str_contains("1").is_err());
+}
+
+#[test]
+fn test_string_cleaning_helpers() {
+    let messy = Series::new_string(
+        "city",
+        vec![
+            Some("  São\t Paulo \n".to_string()),
+            None,
+            Some("\"Zürich\"".to_string()),
+        ],
+    );
+
+    let trimmed = messy.str_trim().unwrap();
+    assert_eq!(
+        trimmed.get_value(0),
+        Some(Value::String("São\t Paulo".to_string()))
+    );
+    assert_eq!(trimmed.get_value(1), None);
+    let normalized = messy.str_normalize_whitespace().unwrap();
+    assert_eq!(
+        normalized.get_value(0),
+        Some(Value::String("São Paulo".to_string()))
+    );
+    let unquoted = messy.str_strip_chars("\"'").unwrap();
+    assert_eq!(
+        unquoted.get_value(2),
+        Some(Value::String("Zürich".to_string()))
+    );
+    let plain = unquoted.str_remove_accents().unwrap();
+    assert_eq!(
+        plain.get_value(2),
+        Some(Value::String("Zurich".to_string()))
+    );
+    assert_eq!(
+        normalized.str_remove_accents().unwrap().get_value(0),
+        Some(Value::String("Sao Paulo".to_string()))
+    );
+
+    let codes = Series::new_string(
+        "code",
+        vec![Some("42".to_string()), Some("+7".to_string()), None],
+    );
+    assert_eq!(
+        codes.str_zfill(4).unwrap().get_data_string().unwrap(),
+        vec![Some("0042".to_string()), Some("+007".to_string()), None]
+    );
+    assert_eq!(
+        codes.str_zfill(1).unwrap().get_value(0),
+        Some(Value::String("42".to_string()))
+    );
+    assert_eq!(
+        codes.str_pad(5, PadSide::Both, '*').unwrap().get_value(0),
+        Some(Value::String("*42**".to_string()))
+    );
+    assert_eq!(
+        codes.str_pad(3, PadSide::Right, 'é').unwrap().get_value(1),
+        Some(Value::String("+7é".to_string()))
+    );
+
+    assert!(Series::new_i32("n", vec![Some(1)]).str_trim().is_err());
+}
+
+#[test]
+fn test_unicode_length_and_slicing() {
+    let words = Series::new_string(
+        "word",
+        vec![
+            Some("naïve".to_string()),
+            None,
+            Some("東京タワー".to_string()),
+            Some("abc".to_string()),
+        ],
+    );
+
+    assert_eq!(
+        words.str_len_chars().unwrap().get_data_i32().unwrap(),
+        vec![Some(5), None, Some(5), Some(3)]
+    );
+    assert_eq!(
+        words.str_len_bytes().unwrap().get_data_i32().unwrap(),
+        vec![Some(6), None, Some(15), Some(3)]
+    );
+    assert_eq!(
+        words
+            .str_slice_chars(1, Some(2))
+            .unwrap()
+            .get_data_string()
+            .unwrap(),
+        vec![
+            Some("aï".to_string()),
+            None,
+            Some("京タ".to_string()),
+            Some("bc".to_string()),
+        ]
+    );
+    assert_eq!(
+        words.str_slice_chars(3, None).unwrap().get_value(3),
+        Some(Value::String(String::new()))
+    );
+    // Byte offsets that land inside 'ï' or a kanji are rejected
+    assert!(words.str_slice_bytes(0, Some(3)).is_err());
+    let ascii = Series::new_string("code", vec![Some("AB-123".to_string())]);
+    assert_eq!(
+        ascii.str_slice_bytes(3, Some(10)).unwrap().get_value(0),
+        Some(Value::String("123".to_string()))
+    );
+
+    let mut columns = HashMap::new();
+    columns.insert("word".to_string(), words);
+    let df = DataFrame::new(columns).unwrap();
+    let prefix = Expr::StrSliceChars(Box::new(Expr::Column("word".to_string())), 0, Some(2));
+    assert_eq!(
+        prefix.evaluate(&df, 2).unwrap(),
+        Value::String("東京".to_string())
+    );
+    let length = Expr::StrLenChars(Box::new(Expr::Column("word".to_string())));
+    assert_eq!(length.evaluate(&df, 0).unwrap(), Value::I32(5));
+    assert_eq!(
+        veloxx::lazy::Expr::from(&prefix).to_string(),
+        "str_slice_chars(col(\"word\"), 0, 2)"
+    );
+}
+
+#[test]
+fn test_url_and_user_agent_helpers() {
+    let urls = Series::new_string(
+        "url",
+        vec![
+            Some("https://Shop.Example.com:8443/cart/items?id=42&q=red%20shoes#top".to_string()),
+            Some("/search?q=data+frames&q=ignored".to_string()),
+            Some("http://[::1]:8080".to_string()),
+            None,
+            Some("not a url".to_string()),
+        ],
+    );
+
+    assert_eq!(
+        urls.url_host().unwrap().get_data_string().unwrap(),
+        vec![
+            Some("shop.example.com".to_string()),
+            None,
+            Some("[::1]".to_string()),
+            None,
+            None,
+        ]
+    );
+    assert_eq!(
+        urls.url_path().unwrap().get_data_string().unwrap(),
+        vec![
+            Some("/cart/items".to_string()),
+            Some("/search".to_string()),
+            Some("/".to_string()),
+            None,
+            None,
+        ]
+    );
+    let query = urls.url_query_param("q").unwrap();
+    assert_eq!(
+        query.get_value(0),
+        Some(Value::String("red shoes".to_string()))
+    );
+    assert_eq!(
+        query.get_value(1),
+        Some(Value::String("data frames".to_string()))
+    );
+    assert_eq!(query.get_value(2), None);
+    assert_eq!(
+        urls.url_query_param("id").unwrap().get_value(0),
+        Some(Value::String("42".to_string()))
+    );
+
+    let agents = Series::new_string(
+        "agent",
+        vec![
+            Some("Mozilla/5.0 (Windows NT 10.0) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Safari/537.36".to_string()),
+            Some("Mozilla/5.0 (Windows NT 10.0) AppleWebKit/537.36 Chrome/120.0 Safari/537.36 Edg/120.0".to_string()),
+            Some("Mozilla/5.0 (Macintosh) AppleWebKit/605.1.15 Version/17.0 Safari/605.1.15".to_string()),
+            Some("Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0".to_string()),
+            Some("Googlebot/2.1 (+http://www.google.com/bot.html)".to_string()),
+            Some("MyApp/1.0".to_string()),
+            None,
+        ],
+    );
+    assert_eq!(
+        agents
+            .user_agent_family()
+            .unwrap()
+            .get_data_string()
+            .unwrap(),
+        vec![
+            Some("Chrome".to_string()),
+            Some("Edge".to_string()),
+            Some("Safari".to_string()),
+            Some("Firefox".to_string()),
+            Some("Bot".to_string()),
+            Some("Other".to_string()),
+            None,
+        ]
+    );
+
+    assert!(Series::new_i32("n", vec![Some(1)]).url_host().is_err());
+}
@@ -42,3 +42,49 @@ fn test_or_condition() {
         "Or(Eq(\"col1\", I32(10)), Lt(\"col2\", F64(5.0)))"
     );
 }
+
+#[test]
+fn test_and_conditions_run_most_selective_first() {
+    use veloxx::performance::adaptive_filter::{conjuncts, rank_conjuncts};
+    use veloxx::query::{QueryBuilder, UltraFastQueryEngine};
+
+    let df = veloxx::df!(
+        "a" => 0..10_000,
+        "b" => (0..10_000).map(|i| i % 100),
+        "name" => (0..10_000).map(|i| if i % 2 == 0 { "even" } else { "odd" }),
+    )
+    .unwrap();
+    let broad = Condition::Gt("a".to_string(), Value::I32(-1));
+    let narrow = Condition::Eq("b".to_string(), Value::I32(7));
+    let half = Condition::Not(Box::new(Condition::Eq(
+        "name".to_string(),
+        Value::String("even".to_string()),
+    )));
+    let condition = Condition::And(
+        Box::new(Condition::And(
+            Box::new(broad.clone()),
+            Box::new(half.clone()),
+        )),
+        Box::new(narrow.clone()),
+    );
+
+    let ranked = rank_conjuncts(&df, &conjuncts(&condition));
+    let order: Vec<String> = ranked
+        .iter()
+        .map(|c| format!("{:?}", c.condition))
+        .collect();
+    assert_eq!(order[0], format!("{:?}", narrow));
+    assert_eq!(order[2], format!("{:?}", broad));
+    assert!(ranked[0].selectivity < 0.05);
+
+    let filtered = df.filter(&condition).unwrap();
+    assert_eq!(filtered.row_count(), 100);
+    assert!((0..100)
+        .all(|row| { filtered.get_column("b").unwrap().get_value(row) == Some(Value::I32(7)) }));
+
+    let query = QueryBuilder::new()
+        .where_condition(broad)
+        .where_condition(Condition::Or(Box::new(narrow), Box::new(half)));
+    let result = UltraFastQueryEngine::new().query(&df, query).unwrap();
+    assert_eq!(result.row_count(), 5_000);
+}
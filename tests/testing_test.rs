@@ -0,0 +1,71 @@
+#![cfg(feature = "testing")]
+
+use proptest::prelude::*;
+use veloxx::dataframe::DataFrame;
+use veloxx::series::Series;
+use veloxx::testing::{self, comparison_ops, i32_series, key_value_series, series};
+use veloxx::types::{DataType, Value};
+
+/// A column of a type the vectorized filter supports with a matching threshold
+fn filter_inputs() -> impl Strategy<Value = (Series, Value)> {
+    (0..64usize).prop_flat_map(|len| {
+        prop_oneof![
+            (series("x", DataType::I32, len), -20i32..20).prop_map(|(s, t)| (s, Value::I32(t))),
+            (series("x", DataType::F64, len), -200i32..200)
+                .prop_map(|(s, t)| (s, Value::F64(t as f64 / 2.0))),
+            (series("x", DataType::String, len), "[abc]{0,3}")
+                .prop_map(|(s, t)| (s, Value::String(t))),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn fast_groupby_matches_reference((keys, values) in key_value_series(0..200, -50..50)) {
+        testing::check_fast_groupby(&keys, &values)?;
+    }
+
+    #[test]
+    fn dense_fast_groupby_matches_reference((keys, values) in key_value_series(1_000..1_500, 0..40)) {
+        // At least 1000 valid rows over a small key range take the dense path
+        testing::check_fast_groupby(&keys, &values)?;
+    }
+
+    #[test]
+    fn vectorized_filter_matches_reference(
+        (column, threshold) in filter_inputs(),
+        op in comparison_ops(),
+    ) {
+        testing::check_vectorized_filter(&column, &threshold, op)?;
+    }
+
+    #[test]
+    fn ultra_fast_join_matches_reference(left in i32_series("id", 0..40), right in i32_series("id", 0..40)) {
+        testing::check_ultra_fast_join(&left, &right)?;
+    }
+
+    #[test]
+    fn arbitrary_frames_round_trip_through_binary(df in any::<DataFrame>()) {
+        let restored = DataFrame::from_bytes(&df.to_bytes()).unwrap();
+        testing::assert_frames_equal(&restored, &df, true)?;
+    }
+
+    #[test]
+    fn arbitrary_series_have_their_declared_type(s in any::<Series>()) {
+        prop_assert!(s.len() <= testing::MAX_ARBITRARY_LEN);
+    }
+
+    #[test]
+    fn binary_series_have_the_requested_length(s in series("b", DataType::Binary, 9)) {
+        prop_assert_eq!(s.data_type(), DataType::Binary);
+        prop_assert_eq!(s.len(), 9);
+        prop_assert!(s.get_data_binary().unwrap().iter().flatten().all(|v| v.len() <= 4));
+    }
+}
+
+#[test]
+fn test_join_reference_ignores_null_keys() {
+    let left = Series::new_i32("id", vec![Some(0), None, Some(2)]);
+    let right = Series::new_i32("id", vec![None, Some(0), Some(2), Some(2)]);
+    testing::check_ultra_fast_join(&left, &right).unwrap();
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "tracing")]
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::format::FmtSpan;
+use veloxx::dataframe::join::JoinType;
+use veloxx::df;
+
+/// Collects formatted trace output
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs `f` with a subscriber logging closed spans, returning the log
+fn trace(f: impl FnOnce()) -> String {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+    let bytes = captured.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn test_operations_emit_spans_with_row_counts() {
+    let orders = df!("id" => [1, 2, 2, 3], "amount" => [5, 10, 15, 20]).unwrap();
+    let names = df!("id" => [2, 3], "name" => ["b", "c"]).unwrap();
+    let path = std::env::temp_dir().join(format!("veloxx_tracing_{}.csv", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    let log = trace(|| {
+        let joined = orders.join(&names, "id", JoinType::Inner).unwrap();
+        let sorted = joined.sort(vec!["amount".to_string()], false).unwrap();
+        sorted
+            .group_by(vec!["name".to_string()])
+            .unwrap()
+            .agg(vec![("amount", "sum")])
+            .unwrap();
+        sorted.to_csv(path).unwrap();
+        veloxx::dataframe::DataFrame::from_csv(path).unwrap();
+    });
+    std::fs::remove_file(path).unwrap();
+
+    let line = |name: &str| {
+        log.lines()
+            .find(|line| line.contains(&format!(" {name}{{")))
+            .unwrap_or_else(|| panic!("no {name} span in:\n{log}"))
+    };
+    assert!(line("join").contains("rows_out=3"), "{}", line("join"));
+    assert!(line("join").contains("rows_in=4"));
+    assert!(line("join").contains("duration_ms="));
+    assert!(line("sort").contains("rows_in=3"));
+    assert!(line("group_by").contains("groups=2"));
+    assert!(line("group_by").contains("rows_out=2"));
+    assert!(line("write_csv").contains("rows_in=3"));
+    assert!(line("read_csv").contains("rows_out=3"));
+}
+
+#[test]
+fn test_failed_operation_warns_inside_span() {
+    let df = df!("x" => [1]).unwrap();
+    let log = trace(|| {
+        assert!(df.sort(vec!["missing".to_string()], true).is_err());
+    });
+    assert!(log.contains("WARN sort{"), "{}", log);
+    assert!(log.contains("operation failed"));
+}
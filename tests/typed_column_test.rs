@@ -0,0 +1,97 @@
+use veloxx::df;
+use veloxx::series::Series;
+use veloxx::types::Value;
+use veloxx::VeloxxError;
+
+#[test]
+fn test_typed_columns() {
+    let df = df!(
+        "id" => [1, 2, 3],
+        "price" => [Some(9.5), None, Some(2.0)],
+        "name" => ["a", "b", "c"],
+        "active" => [true, false, true],
+    )
+    .unwrap();
+
+    assert_eq!(df.column::<i32>("id").unwrap(), &[1, 2, 3]);
+    assert_eq!(df.column::<bool>("active").unwrap(), &[true, false, true]);
+    assert_eq!(df.column::<String>("name").unwrap()[2], "c");
+    let prices: Vec<Option<f64>> = df
+        .column_iter::<f64>("price")
+        .unwrap()
+        .map(|v| v.copied())
+        .collect();
+    assert_eq!(prices, vec![Some(9.5), None, Some(2.0)]);
+    assert_eq!(df.column_iter::<f64>("price").unwrap().len(), 3);
+    assert_eq!(
+        df.column_iter::<f64>("price").unwrap().next_back(),
+        Some(Some(&2.0))
+    );
+
+    assert!(matches!(
+        df.column::<f64>("id"),
+        Err(VeloxxError::DataTypeMismatch(_))
+    ));
+    assert!(matches!(
+        df.column_iter::<i32>("missing"),
+        Err(VeloxxError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_typed_series_access() {
+    let times = Series::new_datetime("ts", vec![Some(1_700_000_000), None]);
+    assert_eq!(times.values::<i64>().unwrap()[0], 1_700_000_000);
+    assert_eq!(times.validity(), &[true, false]);
+    assert!(times.values::<i32>().is_err());
+
+    let blobs = Series::new_binary("blob", vec![None, Some(vec![1, 2])]);
+    let lengths: Vec<Option<usize>> = blobs
+        .iter_as::<Vec<u8>>()
+        .unwrap()
+        .map(|v| v.map(Vec::len))
+        .collect();
+    assert_eq!(lengths, vec![None, Some(2)]);
+}
+
+#[test]
+fn test_series_and_row_iterators() {
+    let ids = Series::new_i32("id", vec![Some(1), None, Some(3)]);
+    assert_eq!(ids.iter().len(), 3);
+    assert_eq!(ids.iter().filter(Option::is_none).count(), 1);
+    assert_eq!(ids.iter_i32().unwrap().flatten().sum::<i32>(), 4);
+    assert!(ids.iter_f64().is_err());
+
+    let names = Series::new_string("name", vec![Some("a".to_string()), None]);
+    let names: Vec<Option<&str>> = names.iter_str().unwrap().collect();
+    assert_eq!(names, vec![Some("a"), None]);
+
+    let df = df!(
+        "id" => [1, 2, 3],
+        "price" => [Some(9.5), None, Some(2.0)],
+    )
+    .unwrap();
+    let rows = df.iter_rows();
+    assert_eq!(rows.len(), 3);
+    let last = df.iter_rows().next_back().unwrap();
+    assert_eq!(last.index(), 2);
+    assert_eq!(last.get("id"), Some(Value::I32(3)));
+    assert_eq!(last.get("missing"), None);
+    assert_eq!(last.get_as::<f64>("price").unwrap(), Some(&2.0));
+    assert_eq!(
+        df.iter_rows()
+            .nth(1)
+            .unwrap()
+            .get_as::<f64>("price")
+            .unwrap(),
+        None
+    );
+    assert!(last.get_as::<i32>("price").is_err());
+    assert_eq!(
+        last.to_vec(),
+        vec![
+            ("id", Some(Value::I32(3))),
+            ("price", Some(Value::F64(2.0)))
+        ]
+    );
+}
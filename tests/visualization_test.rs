@@ -12,7 +12,9 @@ fn test_save_histogram() {
     );
     let df = DataFrame::new(columns).unwrap();
     let plot = Plot::new(&df, ChartType::Histogram).with_columns("a", "");
-    assert!(plot.save("test_histogram.svg").is_ok());
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test_histogram.svg");
+    assert!(plot.save(path.to_str().unwrap()).is_ok());
 }
 
 #[test]
@@ -28,5 +30,7 @@ fn test_save_scatter_plot() {
     );
     let df = DataFrame::new(columns).unwrap();
     let plot = Plot::new(&df, ChartType::Scatter).with_columns("a", "b");
-    assert!(plot.save("test_scatter.svg").is_ok());
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("test_scatter.svg");
+    assert!(plot.save(path.to_str().unwrap()).is_ok());
 }
@@ -128,6 +128,133 @@ describe('WASM Bindings - Real Module Tests', () => {
     }
   });
 
+  test('should round-trip typed array columns', () => {
+    if (wasmModule && wasmModule.WasmDataFrame && wasmModule.WasmDataFrame.fromTypedArrays) {
+      const df = wasmModule.WasmDataFrame.fromTypedArrays({
+        'x': new Float64Array([1.5, 2.5, 3.5]),
+        'y': new Int32Array([1, 2, 3])
+      });
+      expect(df.rowCount()).toBe(3);
+
+      const arrays = df.toTypedArrays();
+      expect(arrays.x).toBeInstanceOf(Float64Array);
+      expect(Array.from(arrays.x)).toEqual([1.5, 2.5, 3.5]);
+      expect(arrays.y).toBeInstanceOf(Int32Array);
+      expect(Array.from(arrays.y)).toEqual([1, 2, 3]);
+
+      expect(() => wasmModule.WasmDataFrame.fromTypedArrays({ 'x': [1, 2] })).toThrow();
+    } else {
+      console.log('⚠️  Typed array interop not available in this build');
+      expect(true).toBe(true);
+    }
+  });
+
+  test('should filter, sort, select, join and derive columns', () => {
+    if (wasmModule && wasmModule.WasmCondition && wasmModule.WasmExpr) {
+      const { WasmDataFrame, WasmCondition, WasmExpr, WasmJoinType } = wasmModule;
+      const df = WasmDataFrame.fromObject({
+        'id': [1, 2, 3, 4],
+        'score': [1.5, 2.5, 3.5, 4.5],
+        'city': ['a', 'b', 'a', 'c']
+      });
+
+      const condition = WasmCondition.gt('score', 2).and(WasmCondition.isIn('city', ['a', 'c']));
+      expect(df.filter(condition).rowCount()).toBe(2);
+      expect(df.filter(WasmCondition.eq('city', 'a').not()).rowCount()).toBe(2);
+
+      const sorted = df.sort(['score'], false).selectColumns(['id']);
+      expect(JSON.parse(sorted.toJson())).toEqual({ 'id': [4, 3, 2, 1] });
+
+      const doubled = df.withColumn('double', WasmExpr.column('score').multiply(WasmExpr.literal(2)));
+      expect(JSON.parse(doubled.toJson()).double).toEqual([3, 5, 7, 9]);
+
+      const names = WasmDataFrame.fromObject({ 'id': [1, 3], 'name': ['x', 'y'] });
+      expect(df.join(names, 'id', WasmJoinType.Inner).rowCount()).toBe(2);
+      expect(df.describe().columnNames()).toContain('mean');
+    } else {
+      console.log('⚠️  Condition and expression builders not available in this build');
+      expect(true).toBe(true);
+    }
+  });
+
+  test('should parse and write CSV and JSON in memory', () => {
+    if (wasmModule && wasmModule.WasmDataFrame && wasmModule.WasmDataFrame.fromCsvString) {
+      const { WasmDataFrame } = wasmModule;
+      const csv = 'name;score\n"Smith; J";1.5\nBo;\n';
+      const df = WasmDataFrame.fromCsvString(csv, { delimiter: ';' });
+      expect(JSON.parse(df.toJson())).toEqual({ 'name': ['Smith; J', 'Bo'], 'score': [1.5, null] });
+      expect(df.toCsvString()).toBe('name,score\n"Smith; J",1.5\nBo,\n');
+
+      const bytes = new TextEncoder().encode('a,b\n1,x\n2,y\n');
+      expect(WasmDataFrame.fromCsvString(bytes.buffer).rowCount()).toBe(2);
+
+      const fromJson = WasmDataFrame.fromJson('[{"a": 1}, {"a": 2}]');
+      expect(fromJson.rowCount()).toBe(2);
+    } else {
+      console.log('⚠️  In-memory CSV/JSON parsing not available in this build');
+      expect(true).toBe(true);
+    }
+  });
+
+  test('should export structured objects and escaped JSON', () => {
+    if (wasmModule && wasmModule.WasmDataFrame && wasmModule.WasmDataFrame.prototype.toObject) {
+      const df = wasmModule.WasmDataFrame.fromObject({
+        'id': [1, 2],
+        'name': ['say "hi"', null]
+      });
+
+      const obj = df.toObject();
+      expect(obj.id).toBeInstanceOf(Int32Array);
+      expect(obj.name).toEqual(['say "hi"', null]);
+
+      expect(JSON.parse(df.toJson())).toEqual({ 'id': [1, 2], 'name': ['say "hi"', null] });
+      expect(JSON.parse(df.toJson('records'))).toEqual([
+        { 'id': 1, 'name': 'say "hi"' },
+        { 'id': 2, 'name': null }
+      ]);
+      expect(() => df.toJson('index')).toThrow();
+    } else {
+      console.log('⚠️  Structured export not available in this build');
+      expect(true).toBe(true);
+    }
+  });
+
+  test('should round-trip through the binary format', () => {
+    if (wasmModule && wasmModule.WasmDataFrame && wasmModule.WasmDataFrame.deserialize) {
+      const df = wasmModule.WasmDataFrame.fromObject({ 'id': [1, 2], 'name': ['a', null] });
+      const bytes = df.serialize();
+      expect(bytes).toBeInstanceOf(Uint8Array);
+
+      const restored = wasmModule.WasmDataFrame.deserialize(bytes.buffer);
+      expect(restored.toJson()).toBe(df.toJson());
+      expect(() => wasmModule.WasmDataFrame.deserialize(new Uint8Array([1, 2, 3]))).toThrow();
+    } else {
+      console.log('⚠️  Binary serialization not available in this build');
+      expect(true).toBe(true);
+    }
+  });
+
+  test('should run the SIMD kernels with scalar semantics', () => {
+    if (wasmModule && wasmModule.simdSupported) {
+      const data = new Float64Array([3, NaN, -1, 7, 2]);
+      expect(wasmModule.simdMinF64(data)).toBe(-1);
+      expect(wasmModule.simdMaxF64(data)).toBe(7);
+      expect(wasmModule.simdMinF64(new Float64Array([]))).toBeUndefined();
+      expect(Array.from(wasmModule.simdGtF64(data, 2))).toEqual([1, 0, 0, 1, 0]);
+      expect(Array.from(wasmModule.simdEqF64(data, 2))).toEqual([0, 0, 0, 0, 1]);
+
+      const a = new Float64Array([1, 2, 3]);
+      const b = new Float64Array([4, 5, 6]);
+      expect(Array.from(wasmModule.simdSubF64(b, a))).toEqual([3, 3, 3]);
+      expect(Array.from(wasmModule.simdMulF64(a, b))).toEqual([4, 10, 18]);
+      expect(wasmModule.simdSumF64(a)).toBe(6);
+      console.log(`SIMD128 kernels ${wasmModule.simdSupported() ? 'enabled' : 'disabled'} in this build`);
+    } else {
+      console.log('⚠️  SIMD kernels not available in this build');
+      expect(true).toBe(true);
+    }
+  });
+
   test('should verify enhanced WASM package exports', () => {
     if (wasmModule) {
       const expectedExports = [